@@ -0,0 +1,230 @@
+//! RLP encoding, keccak hashing, signing and JSON round trips for every
+//! payload type.
+//!
+//! Run with `cargo bench --bench payloads`. Each group compares the payload
+//! types on one stage, so a change to a shared encoder shows up across the
+//! group while a regression in one payload stands out from the rest.
+
+use alloy_primitives::{Address, U256, keccak256};
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main};
+use onemoney_protocol::requests::{
+    BlacklistAction, PauseAction, TransactionPayload, WhitelistAction,
+};
+use onemoney_protocol::responses::MetadataKVPair;
+use onemoney_protocol::{
+    Authority, AuthorityAction, NativePaymentPayload, PaymentPayload, SecretKeyMaterial,
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload,
+    TokenMintPayload, TokenPausePayload, TokenWhitelistPayload, sign_hash,
+};
+use serde::de::DeserializeOwned;
+use std::hint::black_box;
+
+const CHAIN_ID: u64 = 1212101;
+const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+/// Applies a stage to each payload type in turn.
+trait Stage {
+    fn run<P: TransactionPayload + DeserializeOwned>(
+        &self,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+        name: &str,
+        payload: &P,
+    );
+}
+
+fn for_each_payload<S: Stage>(c: &mut Criterion, stage_name: &str, stage: S) {
+    let recipient = Address::repeat_byte(0x11);
+    let token = Address::repeat_byte(0x22);
+    let value = U256::from(1_000_000u64);
+    let mut group = c.benchmark_group(stage_name);
+
+    stage.run(
+        &mut group,
+        "payment",
+        &PaymentPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            recipient,
+            value,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "native_payment",
+        &NativePaymentPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            recipient,
+            value,
+        },
+    );
+    stage.run(
+        &mut group,
+        "mint",
+        &TokenMintPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            recipient,
+            value,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "burn",
+        &TokenBurnPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            recipient,
+            value,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "authority",
+        &TokenAuthorityPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            action: AuthorityAction::Grant,
+            authority_type: Authority::MintBurnTokens,
+            authority_address: recipient,
+            token,
+            value,
+        },
+    );
+    stage.run(
+        &mut group,
+        "pause",
+        &TokenPausePayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            action: PauseAction::Pause,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "blacklist",
+        &TokenBlacklistPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            action: BlacklistAction::Add,
+            address: recipient,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "whitelist",
+        &TokenWhitelistPayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            action: WhitelistAction::Add,
+            address: recipient,
+            token,
+        },
+    );
+    stage.run(
+        &mut group,
+        "metadata_update",
+        &TokenMetadataUpdatePayload {
+            chain_id: CHAIN_ID,
+            nonce: 7,
+            name: "Benchmark Token".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            token,
+            additional_metadata: vec![MetadataKVPair {
+                key: "issuer".to_string(),
+                value: "Example".to_string(),
+            }],
+        },
+    );
+    group.finish();
+}
+
+struct Encode;
+
+impl Stage for Encode {
+    fn run<P: TransactionPayload + DeserializeOwned>(
+        &self,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+        name: &str,
+        payload: &P,
+    ) {
+        group.bench_with_input(BenchmarkId::from_parameter(name), payload, |b, p| {
+            b.iter(|| black_box(p.to_rlp()))
+        });
+    }
+}
+
+struct Hash;
+
+impl Stage for Hash {
+    fn run<P: TransactionPayload + DeserializeOwned>(
+        &self,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+        name: &str,
+        payload: &P,
+    ) {
+        let rlp = payload.to_rlp();
+        group.bench_with_input(BenchmarkId::from_parameter(name), &rlp, |b, rlp| {
+            b.iter(|| black_box(keccak256(rlp)))
+        });
+    }
+}
+
+struct Sign(SecretKeyMaterial);
+
+impl Stage for Sign {
+    fn run<P: TransactionPayload + DeserializeOwned>(
+        &self,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+        name: &str,
+        payload: &P,
+    ) {
+        group.bench_with_input(BenchmarkId::from_parameter(name), payload, |b, p| {
+            b.iter(|| black_box(sign_hash(&p.signature_hash(), &self.0).expect("Signing works")))
+        });
+    }
+}
+
+struct JsonRoundTrip;
+
+impl Stage for JsonRoundTrip {
+    fn run<P: TransactionPayload + DeserializeOwned>(
+        &self,
+        group: &mut BenchmarkGroup<'_, WallTime>,
+        name: &str,
+        payload: &P,
+    ) {
+        let json = serde_json::to_string(payload).expect("Payload serializes");
+        group.bench_with_input(BenchmarkId::new("serialize", name), payload, |b, p| {
+            b.iter(|| black_box(serde_json::to_string(p).expect("Payload serializes")))
+        });
+        group.bench_with_input(BenchmarkId::new("deserialize", name), &json, |b, json| {
+            b.iter(|| black_box(serde_json::from_str::<P>(json).expect("Payload deserializes")))
+        });
+    }
+}
+
+fn bench_encode(c: &mut Criterion) {
+    for_each_payload(c, "rlp_encode", Encode);
+}
+
+fn bench_hash(c: &mut Criterion) {
+    for_each_payload(c, "keccak", Hash);
+}
+
+fn bench_sign(c: &mut Criterion) {
+    for_each_payload(c, "sign", Sign(SecretKeyMaterial::from(PRIVATE_KEY)));
+}
+
+fn bench_json(c: &mut Criterion) {
+    for_each_payload(c, "json", JsonRoundTrip);
+}
+
+criterion_group!(benches, bench_encode, bench_hash, bench_sign, bench_json);
+criterion_main!(benches);
@@ -0,0 +1,129 @@
+//! Encoding, hashing and signing throughput for batches of payloads.
+//!
+//! Run with `cargo bench --bench signing`. The `two_pass` cases reproduce
+//! the scratch-buffer encoding the payloads used before their lengths were
+//! computed up front, as a baseline for the `single_pass` cases.
+
+use alloy_primitives::{Address, B256, U256, keccak256};
+use alloy_rlp::{BufMut, Encodable, Header};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use onemoney_protocol::{PaymentPayload, SecretKeyMaterial, Wallet, sign_hash};
+use std::hint::black_box;
+
+const BATCH_SIZES: [usize; 2] = [64, 1024];
+const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+fn batch(size: usize) -> Vec<PaymentPayload> {
+    (0..size as u64)
+        .map(|nonce| PaymentPayload {
+            chain_id: 1212101,
+            nonce,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000_000u64 + nonce),
+            token: Address::repeat_byte(0x22),
+        })
+        .collect()
+}
+
+fn two_pass_encode(payload: &PaymentPayload, out: &mut dyn BufMut) {
+    let mut fields = Vec::new();
+    payload.chain_id.encode(&mut fields);
+    payload.nonce.encode(&mut fields);
+    payload.recipient.encode(&mut fields);
+    payload.value.encode(&mut fields);
+    payload.token.encode(&mut fields);
+    Header {
+        list: true,
+        payload_length: fields.len(),
+    }
+    .encode(out);
+    out.put_slice(&fields);
+}
+
+fn two_pass_hash(payload: &PaymentPayload) -> B256 {
+    let mut encoded = Vec::new();
+    two_pass_encode(payload, &mut encoded);
+    keccak256(&encoded)
+}
+
+fn bench_signature_hash(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signature_hash");
+    for size in BATCH_SIZES {
+        let payloads = batch(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("two_pass", size), &payloads, |b, p| {
+            b.iter(|| {
+                for payload in p {
+                    black_box(two_pass_hash(payload));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("single_pass", size), &payloads, |b, p| {
+            b.iter(|| {
+                for payload in p {
+                    black_box(payload.signature_hash());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_encode_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode_batch");
+    for size in BATCH_SIZES {
+        let payloads = batch(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("two_pass", size), &payloads, |b, p| {
+            b.iter(|| {
+                let mut out = Vec::new();
+                for payload in p {
+                    two_pass_encode(payload, &mut out);
+                }
+                black_box(out)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("single_pass", size), &payloads, |b, p| {
+            b.iter(|| {
+                let mut out = Vec::with_capacity(p.iter().map(Encodable::length).sum());
+                for payload in p {
+                    payload.encode(&mut out);
+                }
+                black_box(out)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_sign_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sign_batch");
+    group.sample_size(20);
+    let secret = SecretKeyMaterial::from(PRIVATE_KEY);
+    let wallet = Wallet::from_private_key(&secret).expect("Benchmark key is valid");
+    let payloads = batch(BATCH_SIZES[0]);
+    group.throughput(Throughput::Elements(payloads.len() as u64));
+    group.bench_function("sign_hash", |b| {
+        b.iter(|| {
+            for payload in &payloads {
+                black_box(sign_hash(&payload.signature_hash(), &secret).expect("Signing works"));
+            }
+        })
+    });
+    group.bench_function("wallet", |b| {
+        b.iter(|| {
+            for payload in &payloads {
+                black_box(wallet.sign(payload).expect("Signing works"));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_signature_hash,
+    bench_encode_batch,
+    bench_sign_batch
+);
+criterion_main!(benches);
@@ -96,13 +96,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("\n2. Mint Tokens");
     println!("==============");
 
-    let mint_payload = TokenMintPayload {
+    // Mint 1 token to the sender's own account
+    let mint_payload = TokenMintPayload::new(
         chain_id,
-        nonce: current_nonce,
-        recipient: sender_address, // Mint to sender's own account
-        value: U256::from(1000000000000000000u64), // 1 token
-        token: token_address,
-    };
+        current_nonce,
+        sender_address,
+        "1000000000000000000",
+        token_address,
+    )?;
     current_nonce += 1; // Increment for next transaction
 
     match client.mint_token(mint_payload, private_key).await {
@@ -119,13 +120,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("\n3. Burn Tokens");
     println!("==============");
 
-    let burn_payload = TokenBurnPayload {
+    // Burn 0.5 tokens from the sender's own account
+    let burn_payload = TokenBurnPayload::new(
         chain_id,
-        nonce: current_nonce,
-        recipient: sender_address, // Burn from sender's own account
-        value: U256::from(500000000000000000u64), // 0.5 tokens
-        token: token_address,
-    };
+        current_nonce,
+        sender_address,
+        500_000_000_000_000_000u64,
+        token_address,
+    )?;
     current_nonce += 1; // Increment for next transaction
 
     match client.burn_token(burn_payload, private_key).await {
@@ -117,13 +117,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     };
 
-    let payment_payload = PaymentPayload {
-        chain_id,
-        nonce,
-        recipient: recipient_address,
-        value: amount,
-        token: token_address,
-    };
+    let payment_payload =
+        PaymentPayload::new(chain_id, nonce, recipient_address, amount, token_address)?;
 
     println!("Payment payload created:");
     println!("{}", payment_payload);
@@ -3,8 +3,13 @@
 use crate::client::Client;
 use crate::client::config::api_path;
 use crate::client::config::endpoints::accounts::{BBNONCE, NONCE, TOKEN_ACCOUNT};
-use crate::{AccountBBNonce, AccountNonce, AssociatedTokenAccount, Result};
-use alloy_primitives::Address;
+use crate::{AccountBBNonce, AccountNonce, AssociatedTokenAccount, Error, Result, TokenBalance};
+use alloy_primitives::{Address, U256};
+use std::collections::{BTreeMap, BTreeSet};
+use tokio::task::JoinSet;
+
+/// Accounts [`Client::get_account_nonces`] looks up at once.
+pub const DEFAULT_NONCE_FETCH_CONCURRENCY: usize = 8;
 
 impl Client {
     /// Get the nonce for an account.
@@ -40,6 +45,98 @@ impl Client {
         self.get(&path).await
     }
 
+    /// Get the nonces of many accounts, looking up to
+    /// [`DEFAULT_NONCE_FETCH_CONCURRENCY`] of them at once.
+    ///
+    /// The API has no batch nonce endpoint, so each account is looked up on
+    /// its own. Every nonce fetched seeds the client's
+    /// [`NonceManager`](crate::client::NonceManager), so
+    /// [`Client::next_nonce`] can hand them out without another lookup;
+    /// nonces already reserved locally past the fetched value are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `addresses` - The account addresses to query; duplicates are looked up once
+    ///
+    /// # Returns
+    ///
+    /// Each account's nonce.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let senders = vec![Address::repeat_byte(0x11), Address::repeat_byte(0x22)];
+    ///
+    ///     let nonces = client.get_account_nonces(senders).await?;
+    ///     for (address, nonce) in &nonces {
+    ///         println!("{}: {}", address, nonce);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_account_nonces(
+        &self,
+        addresses: Vec<Address>,
+    ) -> Result<BTreeMap<Address, u64>> {
+        self.get_account_nonces_with_concurrency(addresses, DEFAULT_NONCE_FETCH_CONCURRENCY)
+            .await
+    }
+
+    /// Get the nonces of many accounts, looking up to `concurrency` of them
+    /// at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `addresses` - The account addresses to query
+    /// * `concurrency` - Most lookups in flight at once; at least 1
+    ///
+    /// # Returns
+    ///
+    /// Each account's nonce.
+    pub async fn get_account_nonces_with_concurrency(
+        &self,
+        addresses: Vec<Address>,
+        concurrency: usize,
+    ) -> Result<BTreeMap<Address, u64>> {
+        if concurrency == 0 {
+            return Err(Error::invalid_parameter(
+                "concurrency",
+                "At least one lookup must be allowed",
+            ));
+        }
+
+        let mut addresses = addresses.into_iter().collect::<BTreeSet<_>>().into_iter();
+        let mut pending = JoinSet::new();
+        let mut nonces = BTreeMap::new();
+        loop {
+            while pending.len() < concurrency {
+                let Some(address) = addresses.next() else {
+                    break;
+                };
+                let client = self.clone();
+                pending.spawn(async move {
+                    let nonce = client.get_account_nonce(address).await?.nonce;
+                    Ok::<_, Error>((address, nonce))
+                });
+            }
+            let Some(joined) = pending.join_next().await else {
+                break;
+            };
+            let (address, nonce) =
+                joined.map_err(|e| Error::custom(format!("Nonce lookup task failed: {}", e)))??;
+            self.inner.nonces.advance(address, nonce);
+            nonces.insert(address, nonce);
+        }
+        Ok(nonces)
+    }
+
     /// Get the BB nonce for an account.
     ///
     /// # Arguments
@@ -114,6 +211,52 @@ impl Client {
         let path = api_path(&format!("{TOKEN_ACCOUNT}?address={address}&token={token}"));
         self.get(&path).await
     }
+
+    /// Get the balance of a token held by an address, scaled by the token's decimals.
+    ///
+    /// This combines the associated token account lookup with the token
+    /// metadata so the result can be rendered as a human-readable amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The wallet address
+    /// * `token` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The balance in base units together with the token decimals.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let owner = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let balance = client.get_balance(owner, token).await?;
+    ///     println!("Balance: {}", balance.to_decimal_string());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_balance(&self, owner: Address, token: Address) -> Result<TokenBalance> {
+        let account = self.get_associated_token_account(owner, token).await?;
+        let info = self.token_info(token).await?;
+        let amount = parse_balance(&account.balance)?;
+        Ok(TokenBalance::new(token, amount, info.decimals))
+    }
+}
+
+/// Parse a decimal balance string returned by the server.
+pub(crate) fn parse_balance(balance: &str) -> Result<U256> {
+    U256::from_str_radix(balance, 10)
+        .map_err(|e| Error::response_deserialization("decimal", e.to_string(), balance))
 }
 
 #[cfg(test)]
@@ -215,4 +358,17 @@ mod tests {
         assert_eq!(account.balance, String::default());
         assert_eq!(account.nonce, 0);
     }
+
+    #[test]
+    fn test_parse_balance() {
+        assert_eq!(parse_balance("0").expect("Valid balance"), U256::ZERO);
+        assert_eq!(
+            parse_balance("1000000000000000000").expect("Valid balance"),
+            U256::from(1_000_000_000_000_000_000u64)
+        );
+        assert!(matches!(
+            parse_balance("12abc"),
+            Err(Error::ResponseDeserialization { .. })
+        ));
+    }
 }
@@ -2,14 +2,10 @@
 
 use crate::Result;
 use crate::client::Client;
-use crate::client::config::api_path;
-use crate::client::config::endpoints::bridge::{BRIDGE_AND_MINT, BURN_AND_BRIDGE};
-use crate::crypto::sign_transaction_payload;
-use crate::requests::{
-    TokenBridgeAndMintPayload, TokenBridgeAndMintRequest, TokenBurnAndBridgePayload,
-    TokenBurnAndBridgeRequest,
-};
+use crate::error::Error;
+use crate::requests::{TokenBridgeAndMintPayload, TokenBurnAndBridgePayload};
 use crate::responses::TransactionResponse;
+use alloy_primitives::{B256, U256};
 
 impl Client {
     /// Bridge and mint tokens from another chain.
@@ -27,13 +23,7 @@ impl Client {
         payload: TokenBridgeAndMintPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = TokenBridgeAndMintRequest {
-            data: payload,
-            signature,
-        };
-
-        self.post(&api_path(BRIDGE_AND_MINT), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Burn and bridge tokens to another chain.
@@ -51,23 +41,170 @@ impl Client {
         payload: TokenBurnAndBridgePayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = TokenBurnAndBridgeRequest {
-            data: payload,
-            signature,
-        };
+        self.sign_and_submit(payload, private_key).await
+    }
+
+    /// Deposit tokens bridged from another chain onto the L1.
+    ///
+    /// Checks the payload before signing and submits it as a bridge-and-mint
+    /// transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Deposit parameters, including the source chain lock transaction
+    /// * `private_key` - Private key of a bridge mint authority
+    ///
+    /// # Returns
+    ///
+    /// The transaction response containing the L1 transaction hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, TokenBridgeAndMintPayload};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let payload = TokenBridgeAndMintPayload {
+    ///         chain_id: 1212101,
+    ///         nonce: 0,
+    ///         recipient: Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?,
+    ///         value: U256::from(1000000u64),
+    ///         token: Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?,
+    ///         source_chain_id: 1,
+    ///         source_tx_hash: "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
+    ///             .to_string(),
+    ///         bridge_metadata: None,
+    ///     };
+    ///
+    ///     let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///     let deposit = client.bridge_deposit(payload, private_key).await?;
+    ///     println!("Deposit submitted: {}", deposit.hash);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn bridge_deposit(
+        &self,
+        payload: TokenBridgeAndMintPayload,
+        private_key: &str,
+    ) -> Result<TransactionResponse> {
+        validate_amount(payload.value)?;
+        validate_counterpart_chain(payload.chain_id, payload.source_chain_id, "source_chain_id")?;
+        if payload.source_tx_hash.parse::<B256>().is_err() {
+            return Err(Error::validation(
+                "source_tx_hash",
+                format!(
+                    "Expected a 32-byte hex transaction hash, got '{}'",
+                    payload.source_tx_hash
+                ),
+            ));
+        }
+
+        self.bridge_and_mint(payload, private_key).await
+    }
+
+    /// Withdraw tokens from the L1 to another chain.
+    ///
+    /// Checks the payload before signing and submits it as a burn-and-bridge
+    /// transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Withdrawal parameters, including the destination address
+    /// * `private_key` - Private key of the token holder
+    ///
+    /// # Returns
+    ///
+    /// The transaction response containing the L1 transaction hash.
+    pub async fn bridge_withdraw(
+        &self,
+        payload: TokenBurnAndBridgePayload,
+        private_key: &str,
+    ) -> Result<TransactionResponse> {
+        validate_amount(payload.value)?;
+        validate_counterpart_chain(
+            payload.chain_id,
+            payload.destination_chain_id,
+            "destination_chain_id",
+        )?;
+        if payload.destination_address.trim().is_empty() {
+            return Err(Error::validation(
+                "destination_address",
+                "Destination address must not be empty",
+            ));
+        }
+
+        self.burn_and_bridge(payload, private_key).await
+    }
+}
+
+fn validate_amount(value: U256) -> Result<()> {
+    if value.is_zero() {
+        return Err(Error::validation("value", "Bridge amount must be positive"));
+    }
+    Ok(())
+}
 
-        self.post(&api_path(BURN_AND_BRIDGE), &request).await
+fn validate_counterpart_chain(chain_id: u64, counterpart: u64, field: &str) -> Result<()> {
+    if chain_id == counterpart {
+        return Err(Error::validation(
+            field,
+            format!(
+                "Counterpart chain must differ from the L1 chain {}",
+                chain_id
+            ),
+        ));
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::config::api_path;
     use crate::client::config::endpoints::bridge::{BRIDGE_AND_MINT, BURN_AND_BRIDGE};
-    use alloy_primitives::{Address, U256};
+    use crate::requests::TokenBridgeAndMintRequest;
+    use alloy_primitives::Address;
     use std::str::FromStr;
 
+    #[test]
+    fn test_bridge_validation_helpers() {
+        assert!(validate_amount(U256::ZERO).is_err());
+        assert!(validate_amount(U256::from(1u64)).is_ok());
+        assert!(validate_counterpart_chain(1212101, 1212101, "source_chain_id").is_err());
+        assert!(validate_counterpart_chain(1212101, 1, "source_chain_id").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_bridge_deposit_rejects_malformed_source_hash() {
+        let client = Client::local().expect("Local client should build");
+        let payload = TokenBridgeAndMintPayload {
+            chain_id: 1212101,
+            nonce: 1,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(5u64),
+            token: Address::repeat_byte(0x22),
+            source_chain_id: 1,
+            source_tx_hash: "not-a-hash".to_string(),
+            bridge_metadata: None,
+        };
+
+        let result = client
+            .bridge_deposit(
+                payload,
+                "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+            )
+            .await;
+        assert!(
+            matches!(result, Err(Error::Validation { field, .. }) if field == "source_tx_hash")
+        );
+    }
+
     #[test]
     fn test_bridge_and_mint_api_path_construction() {
         let path = api_path(BRIDGE_AND_MINT);
@@ -1,10 +1,10 @@
 //! Chain-related API operations.
 
-use crate::Result;
 use crate::client::Client;
-use crate::client::config::api_path;
 use crate::client::config::endpoints::chains::CHAIN_ID;
+use crate::client::config::{ApiVersion, api_path};
 use crate::responses::ChainIdResponse;
+use crate::{Error, Result};
 
 impl Client {
     /// Get the predefined chain ID for this network.
@@ -25,8 +25,8 @@ impl Client {
     /// let chain_id = client.predefined_chain_id();
     /// assert_eq!(chain_id, 21210);
     /// ```
-    pub const fn predefined_chain_id(&self) -> u64 {
-        self.network.predefined_chain_id()
+    pub fn predefined_chain_id(&self) -> u64 {
+        self.inner.network.predefined_chain_id()
     }
 
     /// Fetch the current chain ID from the network API.
@@ -61,6 +61,57 @@ impl Client {
         let response: ChainIdResponse = self.get(&api_path(CHAIN_ID)).await?;
         Ok(response.chain_id)
     }
+
+    /// Probe the gateway for the newest API version it serves.
+    ///
+    /// Versions are tried newest first against the chain ID endpoint; a 404
+    /// means the version is not served and the next one is tried. The probe
+    /// ignores the version the client is configured for, so its result can
+    /// be passed to [`ClientBuilder::api_version`](crate::ClientBuilder::api_version).
+    ///
+    /// # Returns
+    ///
+    /// The newest supported version, or [`ApiVersion::V1`] if none of the
+    /// newer versions respond.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{ClientBuilder, Network};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let probe = ClientBuilder::new().network(Network::Testnet).build()?;
+    ///     let version = probe.get_api_version().await?;
+    ///
+    ///     let client = ClientBuilder::new()
+    ///         .network(Network::Testnet)
+    ///         .api_version(version)
+    ///         .build()?;
+    ///     println!("Talking to the {} API", client.api_version());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_api_version(&self) -> Result<ApiVersion> {
+        for version in ApiVersion::ALL.into_iter().rev() {
+            if version == ApiVersion::V1 {
+                break;
+            }
+            let path = format!("{}{}", version.prefix(), CHAIN_ID);
+            match self.get_unversioned::<ChainIdResponse>(&path).await {
+                Ok(_) => return Ok(version),
+                Err(error) if is_unsupported(&error) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(ApiVersion::V1)
+    }
+}
+
+/// Whether a probe failure means the version is not served at all.
+fn is_unsupported(error: &Error) -> bool {
+    matches!(error.root(), Error::ResourceNotFound { .. }) || error.status_code() == Some(404)
 }
 
 #[cfg(test)]
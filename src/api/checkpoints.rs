@@ -5,6 +5,23 @@ use crate::client::config::api_path;
 use crate::client::config::endpoints::checkpoints::{BY_HASH, BY_NUMBER, NUMBER};
 use crate::{Checkpoint, CheckpointNumber, Result};
 
+#[cfg(feature = "checkpoints")]
+use crate::client::JsonArrayStream;
+#[cfg(feature = "checkpoints")]
+use crate::client::config::API_VERSION;
+#[cfg(feature = "checkpoints")]
+use crate::client::config::endpoints::transactions;
+#[cfg(feature = "checkpoints")]
+use crate::watcher::Reorg;
+#[cfg(feature = "checkpoints")]
+use crate::{Error, Transaction};
+#[cfg(feature = "checkpoints")]
+use tokio::task::JoinSet;
+
+/// Checkpoints [`Client::get_checkpoints_range`] fetches at once.
+#[cfg(feature = "checkpoints")]
+pub const DEFAULT_CHECKPOINT_FETCH_CONCURRENCY: usize = 8;
+
 impl Client {
     /// Get a specific checkpoint by number.
     ///
@@ -95,6 +112,183 @@ impl Client {
     }
 }
 
+#[cfg(feature = "checkpoints")]
+impl Client {
+    /// Stream the full transactions of a checkpoint.
+    ///
+    /// Transactions are decoded one at a time as the response arrives, so
+    /// checkpoints with very many transactions do not have to fit in memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The checkpoint number
+    ///
+    /// # Returns
+    ///
+    /// A stream yielding the checkpoint's transactions in order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let mut transactions = client.stream_checkpoint_transactions(456).await?;
+    ///     while let Some(tx) = transactions.next().await {
+    ///         println!("Transaction: {}", tx?.hash);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_checkpoint_transactions(
+        &self,
+        number: u64,
+    ) -> Result<JsonArrayStream<Transaction>> {
+        let path = api_path(&format!("{}?number={}&full=true", BY_NUMBER, number));
+        self.get_stream(&path, "transactions").await
+    }
+
+    /// Get every checkpoint from `from` to `to`, both included.
+    ///
+    /// Up to [`DEFAULT_CHECKPOINT_FETCH_CONCURRENCY`] checkpoints are fetched
+    /// at a time. The first failed fetch fails the whole range and cancels
+    /// the fetches still in flight.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first checkpoint number
+    /// * `to` - The last checkpoint number
+    /// * `full` - Whether to include full transaction details
+    ///
+    /// # Returns
+    ///
+    /// The checkpoints in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let checkpoints = client.get_checkpoints_range(100, 199, false).await?;
+    ///     println!("Fetched {} checkpoints", checkpoints.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_checkpoints_range(
+        &self,
+        from: u64,
+        to: u64,
+        full: bool,
+    ) -> Result<Vec<Checkpoint>> {
+        self.get_checkpoints_range_with_concurrency(
+            from,
+            to,
+            full,
+            DEFAULT_CHECKPOINT_FETCH_CONCURRENCY,
+        )
+        .await
+    }
+
+    /// Get every checkpoint from `from` to `to`, both included, fetching up
+    /// to `concurrency` at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first checkpoint number
+    /// * `to` - The last checkpoint number
+    /// * `full` - Whether to include full transaction details
+    /// * `concurrency` - Most fetches in flight at once; at least 1
+    ///
+    /// # Returns
+    ///
+    /// The checkpoints in ascending order.
+    pub async fn get_checkpoints_range_with_concurrency(
+        &self,
+        from: u64,
+        to: u64,
+        full: bool,
+        concurrency: usize,
+    ) -> Result<Vec<Checkpoint>> {
+        if from > to {
+            return Err(Error::invalid_parameter(
+                "to",
+                format!("Range end {} is before its start {}", to, from),
+            ));
+        }
+        if concurrency == 0 {
+            return Err(Error::invalid_parameter(
+                "concurrency",
+                "At least one fetch must be allowed",
+            ));
+        }
+
+        let mut numbers = from..=to;
+        let mut pending = JoinSet::new();
+        let mut checkpoints = Vec::new();
+        loop {
+            while pending.len() < concurrency {
+                let Some(number) = numbers.next() else {
+                    break;
+                };
+                let client = self.clone();
+                pending.spawn(async move { client.get_checkpoint_by_number(number, full).await });
+            }
+            let Some(joined) = pending.join_next().await else {
+                break;
+            };
+            let checkpoint = joined
+                .map_err(|e| Error::custom(format!("Checkpoint fetch task failed: {}", e)))??;
+            checkpoints.push(checkpoint);
+        }
+
+        // Fetches finish in any order.
+        checkpoints.sort_by_key(|checkpoint| checkpoint.number);
+        Ok(checkpoints)
+    }
+
+    /// Drop cached responses a reorg made stale.
+    ///
+    /// Evicts the replaced checkpoints, looked up by number or hash, and the
+    /// transactions, receipts and finalized transactions of their orphaned
+    /// transactions. Has no effect when response caching is not enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `reorg` - A reorg reported by a [`ReorgDetector`](crate::watcher::ReorgDetector)
+    pub fn invalidate_reorg(&self, reorg: &Reorg) {
+        for checkpoint in &reorg.replaced {
+            for full in [false, true] {
+                self.evict_cached(&api_path(&format!(
+                    "{}?number={}&full={}",
+                    BY_NUMBER, checkpoint.number, full
+                )));
+                self.evict_cached(&api_path(&format!(
+                    "{}?hash={}&full={}",
+                    BY_HASH, checkpoint.hash, full
+                )));
+            }
+        }
+        for hash in reorg.orphaned() {
+            for endpoint in [
+                transactions::BY_HASH,
+                transactions::RECEIPT_BY_HASH,
+                transactions::FINALIZED_BY_HASH,
+            ] {
+                self.evict_cached(&format!("{}{}?hash={}", API_VERSION, endpoint, hash));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
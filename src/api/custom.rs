@@ -0,0 +1,187 @@
+//! Requests to endpoints the SDK has no wrapper for yet.
+
+use crate::client::config::api_path;
+use crate::client::{Client, PageStream};
+use crate::{Error, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use url::form_urlencoded::Serializer;
+
+impl Client {
+    /// Call a GET endpoint the SDK does not wrap, decoding the response as `T`.
+    ///
+    /// The request goes through the same pipeline as the built-in methods:
+    /// authentication, rate limiting, retries, hooks, response caching and
+    /// error classification all apply, and the version prefix follows
+    /// [`ClientBuilder::api_version`](crate::ClientBuilder::api_version).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Endpoint path below the version prefix, such as `/tokens/holders`
+    /// * `query` - Query parameters, percent-encoded by the client
+    ///
+    /// # Returns
+    ///
+    /// The decoded response body.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use serde_json::Value;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let holders: Value = client
+    ///         .get_custom("/tokens/holders", &[("token", "0x1234567890abcdef1234567890abcdef12345678")])
+    ///         .await?;
+    ///     println!("{}", holders);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_custom<T>(&self, path: &str, query: &[(&str, &str)]) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.get(&custom_query_path(path, query)?).await
+    }
+
+    /// Iterate over a cursor-paginated list endpoint the SDK does not wrap,
+    /// fetching pages as they are consumed.
+    ///
+    /// Each page is decoded as a [`Page`](crate::Page) of `T` and requested
+    /// like [`Client::get_custom`], with the `next_cursor` of the previous
+    /// page added to the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Endpoint path below the version prefix, such as `/tokens/holders`
+    /// * `query` - Query parameters, percent-encoded by the client
+    ///
+    /// # Returns
+    ///
+    /// A stream of the items across every page.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use serde_json::Value;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let mut holders = client.paginate_custom::<Value>(
+    ///         "/tokens/holders",
+    ///         &[("token", "0x1234567890abcdef1234567890abcdef12345678")],
+    ///     )?;
+    ///     while let Some(holder) = holders.next().await {
+    ///         println!("{}", holder?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn paginate_custom<T>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<PageStream<'_, T>>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(PageStream::new(self, custom_query_path(path, query)?))
+    }
+
+    /// Call a POST endpoint the SDK does not wrap, sending `body` as JSON and
+    /// decoding the response as `T`.
+    ///
+    /// Like [`Client::get_custom`], the request reuses the client's
+    /// authentication, retries, hooks and error classification. The body is
+    /// sent as given: sign payloads with [`Signable`](crate::Signable) first
+    /// when the endpoint expects a signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Endpoint path below the version prefix
+    /// * `body` - Request body, serialized as JSON
+    ///
+    /// # Returns
+    ///
+    /// The decoded response body.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use serde_json::{Value, json};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let estimate: Value = client
+    ///         .post_custom("/transactions/simulate", &json!({ "nonce": 0 }))
+    ///         .await?;
+    ///     println!("{}", estimate);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn post_custom<B, T>(&self, path: &str, body: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        self.post(&custom_path(path)?, body).await
+    }
+}
+
+/// Prefix a caller-supplied endpoint path with the API version.
+fn custom_path(path: &str) -> Result<String> {
+    if !path.starts_with('/') {
+        return Err(Error::validation("path", "Path must start with '/'"));
+    }
+    if path.contains(['?', '#']) {
+        return Err(Error::validation(
+            "path",
+            "Pass query parameters separately instead of in the path",
+        ));
+    }
+    Ok(api_path(path))
+}
+
+/// [`custom_path`] with `query` percent-encoded onto it.
+fn custom_query_path(path: &str, query: &[(&str, &str)]) -> Result<String> {
+    let path = custom_path(path)?;
+    if query.is_empty() {
+        return Ok(path);
+    }
+    let encoded = Serializer::new(String::new())
+        .extend_pairs(query.iter().copied())
+        .finish();
+    Ok(format!("{}?{}", path, encoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_path_validation() {
+        assert_eq!(
+            custom_path("/tokens/holders").expect("Valid path"),
+            "/v1/tokens/holders"
+        );
+        for invalid in ["tokens/holders", "/tokens/holders?token=0x1", "/a#b"] {
+            assert!(matches!(
+                custom_path(invalid),
+                Err(Error::Validation { ref field, .. }) if field == "path"
+            ));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Transaction explanation API methods.
+
+use crate::Result;
+use crate::client::Client;
+use crate::decode::{DetailedTransaction, TransactionSummary};
+use crate::responses::Transaction;
+
+impl Client {
+    /// Decode a transaction into a display-ready summary.
+    ///
+    /// Amounts are formatted with the token's decimals, fetched from its
+    /// metadata. Native transfers keep their amount in base units.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - The transaction to explain
+    ///
+    /// # Returns
+    ///
+    /// The transaction summary.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let tx = client.get_transaction_by_hash("0x1234...").await?;
+    ///
+    ///     let summary = client.explain_transaction(&tx).await?;
+    ///     println!("{}", summary);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn explain_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<TransactionSummary> {
+        let summary = TransactionSummary::from_transaction(transaction)?;
+        match (summary.token, summary.amount) {
+            (Some(token), Some(_)) => {
+                let decimals = self.token_info(token).await?.decimals;
+                Ok(summary.with_decimals(decimals))
+            }
+            _ => Ok(summary),
+        }
+    }
+
+    /// Fetch a transaction by hash and decode it into a display-ready summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Transaction hash
+    ///
+    /// # Returns
+    ///
+    /// The transaction summary.
+    pub async fn explain_transaction_by_hash(&self, hash: &str) -> Result<TransactionSummary> {
+        let transaction = self.get_transaction_by_hash(hash).await?;
+        self.explain_transaction(&transaction).await
+    }
+
+    /// Fetch a transaction by hash together with the token it references.
+    ///
+    /// The token's metadata is fetched once and used to resolve the symbol
+    /// and decimals, so amounts are ready for display or reconciliation.
+    /// Native transfers and token creations reference no existing token
+    /// and cost a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Transaction hash
+    ///
+    /// # Returns
+    ///
+    /// The transaction with its summary and resolved token.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let detailed = client.get_transaction_detailed("0x1234...").await?;
+    ///     if let Some(amount) = detailed.display_amount() {
+    ///         println!("{}: {}", detailed.summary.kind, amount);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_transaction_detailed(&self, hash: &str) -> Result<DetailedTransaction> {
+        let transaction = self.get_transaction_by_hash(hash).await?;
+        let token = TransactionSummary::from_transaction(&transaction)?.token;
+        let mint = match token {
+            Some(token) => Some(self.get_token_metadata(token).await?),
+            None => None,
+        };
+        DetailedTransaction::new(transaction, mint.as_ref())
+    }
+}
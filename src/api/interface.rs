@@ -0,0 +1,379 @@
+//! Trait abstraction over the OneMoney API.
+//!
+//! [`OneMoneyApi`] mirrors the single-endpoint operations of [`Client`], so
+//! application code can be written against the trait and handed a test
+//! double or an alternative transport instead of a live client. Composite
+//! helpers such as [`Client::send_payment_and_wait`] stay inherent: they are
+//! built from these operations and can be reimplemented generically where
+//! needed.
+
+use crate::Result;
+use crate::client::Client;
+use crate::requests::{FeeEstimateRequest, NativePaymentPayload, PaymentPayload};
+use crate::responses::{
+    AccountBBNonce, AccountNonce, AssociatedTokenAccount, Checkpoint, CheckpointNumber,
+    EpochResponse, FeeEstimate, FinalizedTransaction, MintInfo, Transaction, TransactionReceipt,
+    TransactionResponse,
+};
+use alloy_primitives::Address;
+use std::future::Future;
+
+#[cfg(feature = "tokens")]
+use crate::requests::{
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload,
+    TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
+};
+
+/// Operations of the OneMoney REST API.
+///
+/// Every method corresponds to the [`Client`] method of the same name; see
+/// there for details. Returned futures are `Send` so implementations can be
+/// driven from spawned tasks.
+pub trait OneMoneyApi: Send + Sync {
+    /// Get the nonce of an account.
+    fn get_account_nonce(
+        &self,
+        address: Address,
+    ) -> impl Future<Output = Result<AccountNonce>> + Send;
+
+    /// Get the bridge nonce of an account.
+    fn get_account_bbonce(
+        &self,
+        address: Address,
+    ) -> impl Future<Output = Result<AccountBBNonce>> + Send;
+
+    /// Get the associated token account of an owner for a token.
+    fn get_associated_token_account(
+        &self,
+        address: Address,
+        token: Address,
+    ) -> impl Future<Output = Result<AssociatedTokenAccount>> + Send;
+
+    /// Get the chain ID reported by the network.
+    fn fetch_chain_id_from_network(&self) -> impl Future<Output = Result<u64>> + Send;
+
+    /// Get a checkpoint by number.
+    fn get_checkpoint_by_number(
+        &self,
+        number: u64,
+        full: bool,
+    ) -> impl Future<Output = Result<Checkpoint>> + Send;
+
+    /// Get a checkpoint by hash.
+    fn get_checkpoint_by_hash(
+        &self,
+        hash: &str,
+        full: bool,
+    ) -> impl Future<Output = Result<Checkpoint>> + Send;
+
+    /// Get the latest checkpoint number.
+    fn get_checkpoint_number(&self) -> impl Future<Output = Result<CheckpointNumber>> + Send;
+
+    /// Get the current governance epoch.
+    fn get_current_epoch(&self) -> impl Future<Output = Result<EpochResponse>> + Send;
+
+    /// Get a governance epoch by ID.
+    fn get_epoch_by_id(&self, epoch_id: u64) -> impl Future<Output = Result<EpochResponse>> + Send;
+
+    /// Get a token's metadata.
+    fn get_token_metadata(
+        &self,
+        mint_address: Address,
+    ) -> impl Future<Output = Result<MintInfo>> + Send;
+
+    /// Mint tokens.
+    #[cfg(feature = "tokens")]
+    fn mint_token(
+        &self,
+        payload: TokenMintPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Burn tokens.
+    #[cfg(feature = "tokens")]
+    fn burn_token(
+        &self,
+        payload: TokenBurnPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Grant a token authority.
+    #[cfg(feature = "tokens")]
+    fn grant_authority(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Revoke a token authority.
+    #[cfg(feature = "tokens")]
+    fn revoke_authority(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Pause or unpause a token.
+    #[cfg(feature = "tokens")]
+    fn pause_token(
+        &self,
+        payload: TokenPausePayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Add or remove an address on a token's blacklist.
+    #[cfg(feature = "tokens")]
+    fn manage_blacklist(
+        &self,
+        payload: TokenBlacklistPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Add or remove an address on a token's whitelist.
+    #[cfg(feature = "tokens")]
+    fn manage_whitelist(
+        &self,
+        payload: TokenWhitelistPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Update a token's metadata.
+    #[cfg(feature = "tokens")]
+    fn update_token_metadata(
+        &self,
+        payload: TokenMetadataUpdatePayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Submit a payment.
+    fn send_payment(
+        &self,
+        payload: PaymentPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Submit a payment of the native asset.
+    fn send_native(
+        &self,
+        payload: NativePaymentPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Broadcast a transaction signed elsewhere, encoded as a [`RawTransaction`].
+    ///
+    /// [`RawTransaction`]: crate::RawTransaction
+    fn send_raw_transaction(
+        &self,
+        raw: &[u8],
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send;
+
+    /// Get a transaction by hash.
+    fn get_transaction_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<Transaction>> + Send;
+
+    /// Get a transaction receipt by hash.
+    fn get_transaction_receipt_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<TransactionReceipt>> + Send;
+
+    /// Get a finalized transaction by hash.
+    fn get_finalized_transaction_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<FinalizedTransaction>> + Send;
+
+    /// Estimate the fee of a payment.
+    fn estimate_fee(
+        &self,
+        request: FeeEstimateRequest,
+    ) -> impl Future<Output = Result<FeeEstimate>> + Send;
+}
+
+impl OneMoneyApi for Client {
+    fn get_account_nonce(
+        &self,
+        address: Address,
+    ) -> impl Future<Output = Result<AccountNonce>> + Send {
+        Client::get_account_nonce(self, address)
+    }
+
+    fn get_account_bbonce(
+        &self,
+        address: Address,
+    ) -> impl Future<Output = Result<AccountBBNonce>> + Send {
+        Client::get_account_bbonce(self, address)
+    }
+
+    fn get_associated_token_account(
+        &self,
+        address: Address,
+        token: Address,
+    ) -> impl Future<Output = Result<AssociatedTokenAccount>> + Send {
+        Client::get_associated_token_account(self, address, token)
+    }
+
+    fn fetch_chain_id_from_network(&self) -> impl Future<Output = Result<u64>> + Send {
+        Client::fetch_chain_id_from_network(self)
+    }
+
+    fn get_checkpoint_by_number(
+        &self,
+        number: u64,
+        full: bool,
+    ) -> impl Future<Output = Result<Checkpoint>> + Send {
+        Client::get_checkpoint_by_number(self, number, full)
+    }
+
+    fn get_checkpoint_by_hash(
+        &self,
+        hash: &str,
+        full: bool,
+    ) -> impl Future<Output = Result<Checkpoint>> + Send {
+        Client::get_checkpoint_by_hash(self, hash, full)
+    }
+
+    fn get_checkpoint_number(&self) -> impl Future<Output = Result<CheckpointNumber>> + Send {
+        Client::get_checkpoint_number(self)
+    }
+
+    fn get_current_epoch(&self) -> impl Future<Output = Result<EpochResponse>> + Send {
+        Client::get_current_epoch(self)
+    }
+
+    fn get_epoch_by_id(&self, epoch_id: u64) -> impl Future<Output = Result<EpochResponse>> + Send {
+        Client::get_epoch_by_id(self, epoch_id)
+    }
+
+    fn get_token_metadata(
+        &self,
+        mint_address: Address,
+    ) -> impl Future<Output = Result<MintInfo>> + Send {
+        Client::get_token_metadata(self, mint_address)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn mint_token(
+        &self,
+        payload: TokenMintPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::mint_token(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn burn_token(
+        &self,
+        payload: TokenBurnPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::burn_token(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn grant_authority(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::grant_authority(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn revoke_authority(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::revoke_authority(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn pause_token(
+        &self,
+        payload: TokenPausePayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::pause_token(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn manage_blacklist(
+        &self,
+        payload: TokenBlacklistPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::manage_blacklist(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn manage_whitelist(
+        &self,
+        payload: TokenWhitelistPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::manage_whitelist(self, payload, private_key)
+    }
+
+    #[cfg(feature = "tokens")]
+    fn update_token_metadata(
+        &self,
+        payload: TokenMetadataUpdatePayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::update_token_metadata(self, payload, private_key)
+    }
+
+    fn send_payment(
+        &self,
+        payload: PaymentPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::send_payment(self, payload, private_key)
+    }
+
+    fn send_native(
+        &self,
+        payload: NativePaymentPayload,
+        private_key: &str,
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::send_native(self, payload, private_key)
+    }
+
+    fn send_raw_transaction(
+        &self,
+        raw: &[u8],
+    ) -> impl Future<Output = Result<TransactionResponse>> + Send {
+        Client::send_raw_transaction(self, raw)
+    }
+
+    fn get_transaction_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<Transaction>> + Send {
+        Client::get_transaction_by_hash(self, hash)
+    }
+
+    fn get_transaction_receipt_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<TransactionReceipt>> + Send {
+        Client::get_transaction_receipt_by_hash(self, hash)
+    }
+
+    fn get_finalized_transaction_by_hash(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<FinalizedTransaction>> + Send {
+        Client::get_finalized_transaction_by_hash(self, hash)
+    }
+
+    fn estimate_fee(
+        &self,
+        request: FeeEstimateRequest,
+    ) -> impl Future<Output = Result<FeeEstimate>> + Send {
+        Client::estimate_fee(self, request)
+    }
+}
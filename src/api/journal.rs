@@ -0,0 +1,216 @@
+//! Journaled submission and crash recovery.
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::requests::TransactionPayload;
+use crate::responses::TransactionReceipt;
+use crate::storage::Storage;
+use crate::{JournalEntry, JournalRecovery, JournalState, Result};
+use alloy_primitives::B256;
+
+impl Client {
+    /// Submit a payload at least once, recording each step in a journal.
+    ///
+    /// The entry for `key` is saved before signing, after signing and after
+    /// submission, so a process that crashes at any point can finish the
+    /// work with [`recover_journal`](Self::recover_journal). If `key`
+    /// already has a signed entry, that entry is resumed: calling again with
+    /// the same key never signs a second transaction. Calling with a
+    /// different payload for a signed key fails with a validation error.
+    ///
+    /// A resubmitted transaction that already landed is only recognised as
+    /// such when the server reports the duplicate, so enable
+    /// [`ClientBuilder::idempotency_keys`](crate::ClientBuilder::idempotency_keys)
+    /// on clients that journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - Where the journal is kept
+    /// * `key` - Idempotency key of the operation, such as an order ID
+    /// * `payload` - The payload to submit
+    /// * `private_key` - Private key for signing the payload
+    ///
+    /// # Returns
+    ///
+    /// The receipt of the transaction, successful or not.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::storage::FileStorage;
+    /// use onemoney_protocol::{ClientBuilder, Network, PaymentPayload};
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ClientBuilder::new()
+    ///         .network(Network::Testnet)
+    ///         .idempotency_keys(true)
+    ///         .build()?;
+    ///     let journal = FileStorage::new("./journal")?;
+    ///
+    ///     let recovery = client.recover_journal(&journal).await?;
+    ///     println!("{}", recovery);
+    ///
+    ///     let payload = PaymentPayload::new(
+    ///         1212101,
+    ///         7,
+    ///         Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?,
+    ///         "1000000",
+    ///         Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?,
+    ///     )?;
+    ///     let receipt = client
+    ///         .submit_journaled(&journal, "order-42", payload, "your_private_key_here")
+    ///         .await?;
+    ///     println!("Landed: {}", receipt.success);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn submit_journaled<P>(
+        &self,
+        storage: &dyn Storage,
+        key: &str,
+        payload: P,
+        private_key: &str,
+    ) -> Result<TransactionReceipt>
+    where
+        P: TransactionPayload,
+    {
+        let mut entry = match JournalEntry::load(storage, key)? {
+            Some(entry) if entry.state != JournalState::Intent => {
+                if entry.endpoint != P::ENDPOINT || entry.payload != alloy_rlp::encode(&payload) {
+                    return Err(Error::validation(
+                        "payload",
+                        format!("Journal entry {} was signed for a different payload", key),
+                    ));
+                }
+                entry
+            }
+            _ => {
+                let mut entry = JournalEntry::intent(key, &payload)?;
+                entry.save(storage)?;
+                let (signature, _) = self.sign_payload(&payload, private_key)?;
+                entry.mark_signed(signature);
+                entry.save(storage)?;
+                entry
+            }
+        };
+        self.advance_journal_entry(storage, &mut entry).await
+    }
+
+    /// Resume every journal entry a previous process left unfinished.
+    ///
+    /// Signed entries are submitted, or recognised as already submitted, and
+    /// submitted entries are awaited until their receipt arrives. Entries that
+    /// were never signed are reported but not touched, since no key is at
+    /// hand to sign them. Run this on startup before new submissions.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - Where the journal is kept
+    ///
+    /// # Returns
+    ///
+    /// The entries sorted by how far they got. Network errors leave an entry
+    /// pending, and a duplicate rejection that names no transaction leaves it
+    /// unresolved; only storage errors are returned.
+    pub async fn recover_journal(&self, storage: &dyn Storage) -> Result<JournalRecovery> {
+        let mut recovery = JournalRecovery::default();
+        for mut entry in JournalEntry::incomplete(storage)? {
+            if entry.state == JournalState::Intent {
+                recovery.unsigned.push(entry);
+                continue;
+            }
+            let duplicate = match self.advance_journal_entry(storage, &mut entry).await {
+                Err(error) if matches!(error.root(), Error::Storage(_)) => return Err(error),
+                Err(error) => error.is_duplicate_submission(),
+                Ok(_) => false,
+            };
+            match entry.state {
+                JournalState::Confirmed => recovery.confirmed.push(entry),
+                JournalState::Failed => recovery.failed.push(entry),
+                JournalState::Signed if duplicate => recovery.unresolved.push(entry),
+                _ => recovery.pending.push(entry),
+            }
+        }
+        Ok(recovery)
+    }
+
+    /// Take a signed entry as far as it goes, saving every step.
+    async fn advance_journal_entry(
+        &self,
+        storage: &dyn Storage,
+        entry: &mut JournalEntry,
+    ) -> Result<TransactionReceipt> {
+        if entry.state == JournalState::Signed {
+            let raw = entry.raw_transaction().ok_or_else(|| {
+                Error::storage(format!(
+                    "Journal entry {} is signed but has no signature",
+                    entry.key
+                ))
+            })?;
+            match self.send_raw_transaction(&raw.to_rlp()).await {
+                Ok(response) => entry.mark_submitted(response.hash),
+                Err(error) => {
+                    match original_hash(&error) {
+                        Some(hash) => entry.mark_submitted(hash),
+                        // A retryable failure or a duplicate without its hash
+                        // may still have landed, so the entry stays signed.
+                        None if error.is_retryable() || error.is_duplicate_submission() => {
+                            entry.error = Some(error.to_string());
+                        }
+                        None => entry.mark_failed(error.to_string()),
+                    }
+                    if entry.state != JournalState::Submitted {
+                        entry.save(storage)?;
+                        return Err(error);
+                    }
+                }
+            }
+            entry.save(storage)?;
+        }
+
+        let Some(hash) = entry.transaction_hash else {
+            return Err(Error::business_logic(
+                "journal",
+                format!(
+                    "Entry {} is {} without a transaction: {}",
+                    entry.key,
+                    entry.state,
+                    entry.error.as_deref().unwrap_or("not submitted")
+                ),
+            ));
+        };
+        if entry.state.is_final() {
+            return self
+                .get_transaction_receipt_by_hash(&hash.to_string())
+                .await;
+        }
+        match self.wait_for_transaction_receipt(&hash.to_string()).await {
+            Ok(receipt) => {
+                if receipt.success {
+                    entry.mark_confirmed();
+                } else {
+                    entry.mark_failed("Transaction failed on chain");
+                }
+                entry.save(storage)?;
+                Ok(receipt)
+            }
+            Err(error) => {
+                entry.error = Some(error.to_string());
+                entry.save(storage)?;
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Transaction hash named by a duplicate submission error.
+fn original_hash(error: &Error) -> Option<B256> {
+    match error.root() {
+        Error::DuplicateSubmission { original_hash } => *original_hash,
+        _ => None,
+    }
+}
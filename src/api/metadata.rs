@@ -0,0 +1,106 @@
+//! Uploading off-chain token metadata documents.
+
+use crate::client::Client;
+use crate::crypto::private_key_to_address;
+use crate::requests::TokenMetadataUpdatePayload;
+use crate::{ConfigError, Error, MetadataUpload, Result, TokenMetadataDocument};
+use alloy_primitives::{Address, keccak256};
+use std::str::FromStr;
+
+impl Client {
+    /// Store a metadata document and point the token's metadata URI at it.
+    ///
+    /// The document is validated, stored through the
+    /// [`MetadataStore`](crate::client::MetadataStore) set with
+    /// [`ClientBuilder::metadata_store`](crate::ClientBuilder::metadata_store)
+    /// under its content hash, and the returned URI is submitted in a
+    /// metadata update that keeps the token's existing additional metadata.
+    /// A [dry-run](crate::ClientBuilder::dry_run) client stores nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token address
+    /// * `document` - The metadata document to store
+    /// * `private_key` - Key of an account allowed to update the token's metadata
+    ///
+    /// # Returns
+    ///
+    /// The stored URI, the document hash and the update transaction hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::client::HttpPutStore;
+    /// use onemoney_protocol::{ClientBuilder, Network, TokenMetadataDocument};
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ClientBuilder::new()
+    ///         .network(Network::Testnet)
+    ///         .metadata_store(HttpPutStore::new("https://metadata.example.com/tokens/")?)
+    ///         .build()?;
+    ///
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let document = TokenMetadataDocument::new("Dollar", "USD1")
+    ///         .description("A dollar-backed stablecoin")
+    ///         .image("https://example.com/usd1.png");
+    ///     let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///
+    ///     let upload = client.upload_token_metadata(token, &document, private_key).await?;
+    ///     println!("{}", upload);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_token_metadata(
+        &self,
+        token: Address,
+        document: &TokenMetadataDocument,
+        private_key: &str,
+    ) -> Result<MetadataUpload> {
+        let store = self.inner.metadata_store.as_deref().ok_or_else(|| {
+            ConfigError::missing_config(
+                "Uploading token metadata requires ClientBuilder::metadata_store",
+            )
+        })?;
+        let bytes = document.to_json_bytes()?;
+        let document_hash = keccak256(&bytes);
+
+        let key = format!("{}.json", hex::encode(document_hash));
+        // A dry run must not publish the document either; the update it
+        // returns names the storage key in place of the URI.
+        let uri = if self.inner.dry_run {
+            key
+        } else {
+            store.store(&key, &bytes).await?
+        };
+        let additional_metadata = self
+            .get_token_metadata(token)
+            .await?
+            .meta
+            .map(|meta| meta.additional_metadata)
+            .unwrap_or_default();
+
+        let signer = Address::from_str(&private_key_to_address(private_key)?)
+            .map_err(|e| Error::address(e.to_string()))?;
+        let chain_id = self.fetch_chain_id_from_network().await?;
+        let nonce = self.get_account_nonce(signer).await?.nonce;
+        let payload = TokenMetadataUpdatePayload {
+            chain_id,
+            nonce,
+            name: document.name.clone(),
+            uri: uri.clone(),
+            token,
+            additional_metadata,
+        };
+        let response = self.update_token_metadata(payload, private_key).await?;
+
+        Ok(MetadataUpload {
+            uri,
+            document_hash,
+            hash: response.hash,
+        })
+    }
+}
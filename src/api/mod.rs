@@ -3,15 +3,28 @@
 pub mod accounts;
 pub mod chains;
 pub mod checkpoints;
+pub mod custom;
+pub mod decode;
 pub mod governance;
+pub mod interface;
+pub mod journal;
+pub mod resubmit;
 pub mod tokens;
 pub mod transactions;
 
+#[cfg(feature = "tokens")]
+pub mod metadata;
+#[cfg(feature = "tokens")]
+pub mod rotation;
+#[cfg(feature = "tokens")]
+pub mod supply;
+
 #[cfg(feature = "bridge")]
 pub mod bridge;
 
 // Re-export client types from the new client module
 pub use crate::client::{Client, ClientBuilder, Network};
+pub use interface::OneMoneyApi;
 
 // Re-export commonly used API types now from types module
 pub use crate::requests::{
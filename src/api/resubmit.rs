@@ -0,0 +1,261 @@
+//! Recovery of transactions that were accepted but never landed.
+
+use crate::client::Client;
+use crate::client::config::api_path;
+use crate::crypto::{SecretKeyMaterial, signing_key_address};
+use crate::error::Error;
+use crate::requests::{SignedSubmission, TransactionPayload};
+use crate::responses::TransactionResponse;
+use crate::{AttemptOutcome, ResubmitAttempt, ResubmitPolicy, ResubmitReport, Result};
+use alloy_primitives::B256;
+use tokio::time::sleep;
+
+impl Client {
+    /// Submit a payload and keep resubmitting it until it lands.
+    ///
+    /// After each submission the receipt is polled until it appears or the
+    /// chain advances `policy.wait_checkpoints` checkpoints. A dropped
+    /// submission is followed by a nonce check before it is sent again. The
+    /// payload is signed once, so every attempt resends the same signed
+    /// bytes and the L1 can execute it at most once. Resubmission never
+    /// changes the payload: if the
+    /// account's nonce moved past the payload's without a receipt, some other
+    /// transaction used it and the payload is abandoned rather than risk
+    /// executing twice. Submitting a payload that was already accepted earlier
+    /// is fine; the duplicate is detected and its original hash is awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The payload to submit
+    /// * `private_key` - Private key for signing the payload
+    /// * `policy` - Attempt limit, checkpoint window and poll interval
+    ///
+    /// # Returns
+    ///
+    /// The history of attempts. Check [`ResubmitReport::receipt`] for the
+    /// outcome. Errors are returned only for problems found before the first
+    /// submission, such as an invalid key or a chain ID mismatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, PaymentPayload, ResubmitPolicy};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let payload = PaymentPayload {
+    ///         chain_id: 1212101,
+    ///         nonce: 7,
+    ///         recipient: Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?,
+    ///         value: U256::from(1000000000000000000u64),
+    ///         token: Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?,
+    ///     };
+    ///
+    ///     let report = client
+    ///         .resubmit(payload, "your_private_key_here", ResubmitPolicy::new())
+    ///         .await?;
+    ///     println!("{}", report);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn resubmit<P>(
+        &self,
+        payload: P,
+        private_key: &str,
+        policy: ResubmitPolicy,
+    ) -> Result<ResubmitReport>
+    where
+        P: TransactionPayload,
+    {
+        let signer = signing_key_address(&SecretKeyMaterial::from(private_key).signing_key()?);
+        let (signature, envelope) = self.sign_payload(&payload, private_key)?;
+        let nonce = payload.nonce();
+        let request = SignedSubmission {
+            payload: &payload,
+            signature,
+        };
+
+        let mut report = ResubmitReport::default();
+        let mut last_hash = None;
+        for attempt in 1..=policy.max_attempts.max(1) {
+            if attempt > 1 {
+                match self.get_account_nonce(signer).await {
+                    Ok(account) if account.nonce == nonce => {}
+                    Ok(account) => {
+                        let outcome = match last_hash {
+                            Some(hash) if account.nonce > nonce => {
+                                self.final_receipt_check(hash, nonce, account.nonce).await
+                            }
+                            _ if account.nonce > nonce => AttemptOutcome::NonceConsumed {
+                                nonce,
+                                account_nonce: account.nonce,
+                            },
+                            _ => AttemptOutcome::NonceGap {
+                                nonce,
+                                account_nonce: account.nonce,
+                            },
+                        };
+                        let retry = matches!(
+                            outcome,
+                            AttemptOutcome::Failed {
+                                retryable: true,
+                                ..
+                            }
+                        );
+                        report.attempts.push(ResubmitAttempt {
+                            attempt,
+                            nonce,
+                            hash: None,
+                            outcome,
+                        });
+                        if retry {
+                            sleep(policy.poll_interval).await;
+                            continue;
+                        }
+                        break;
+                    }
+                    Err(error) => {
+                        let retryable = error.is_retryable();
+                        report.attempts.push(failed_attempt(attempt, nonce, error));
+                        if retryable {
+                            sleep(policy.poll_interval).await;
+                            continue;
+                        }
+                        break;
+                    }
+                }
+            }
+
+            let submitted: Result<TransactionResponse> = self
+                .submit(&api_path(P::ENDPOINT), &request, &envelope)
+                .await;
+            let hash = match submitted {
+                Ok(response) => response.hash,
+                Err(error) if error.is_duplicate_submission() => {
+                    let original = match error.root() {
+                        Error::DuplicateSubmission { original_hash } => *original_hash,
+                        _ => None,
+                    };
+                    match original.or(last_hash) {
+                        Some(hash) => hash,
+                        None => {
+                            report.attempts.push(failed_attempt(attempt, nonce, error));
+                            break;
+                        }
+                    }
+                }
+                Err(error) => {
+                    let retryable = error.is_retryable();
+                    report.attempts.push(failed_attempt(attempt, nonce, error));
+                    if retryable {
+                        sleep(policy.poll_interval).await;
+                        continue;
+                    }
+                    break;
+                }
+            };
+            last_hash = Some(hash);
+
+            let outcome = self.await_landing(hash, &policy).await;
+            let stop = !matches!(
+                outcome,
+                AttemptOutcome::Dropped { .. }
+                    | AttemptOutcome::Failed {
+                        retryable: true,
+                        ..
+                    }
+            );
+            report.attempts.push(ResubmitAttempt {
+                attempt,
+                nonce,
+                hash: Some(hash),
+                outcome,
+            });
+            if stop {
+                break;
+            }
+        }
+        Ok(report)
+    }
+
+    /// Poll for the receipt of `hash` until it appears or the checkpoint window passes.
+    async fn await_landing(&self, hash: B256, policy: &ResubmitPolicy) -> AttemptOutcome {
+        let start = match self.refresh_chain_state().await {
+            Ok(state) => state.checkpoint,
+            Err(error) => return failed_outcome(error),
+        };
+        let hash_str = hash.to_string();
+        loop {
+            match self.get_transaction_receipt_by_hash(&hash_str).await {
+                Ok(receipt) => {
+                    return AttemptOutcome::Landed {
+                        receipt: Box::new(receipt),
+                    };
+                }
+                Err(error) if matches!(error.root(), Error::ResourceNotFound { .. }) => {}
+                Err(error) if error.is_retryable() => {}
+                Err(error) => return failed_outcome(error),
+            }
+            match self.chain_state().await {
+                Ok(state) => {
+                    let waited = state.checkpoint.saturating_sub(start);
+                    if waited >= policy.wait_checkpoints {
+                        return AttemptOutcome::Dropped {
+                            waited_checkpoints: waited,
+                        };
+                    }
+                }
+                Err(error) if error.is_retryable() => {}
+                Err(error) => return failed_outcome(error),
+            }
+            sleep(policy.poll_interval).await;
+        }
+    }
+
+    /// Look for a late receipt once the account's nonce moved on.
+    ///
+    /// Only a missing receipt means the nonce went to another transaction;
+    /// other errors leave the outcome open.
+    async fn final_receipt_check(
+        &self,
+        hash: B256,
+        nonce: u64,
+        account_nonce: u64,
+    ) -> AttemptOutcome {
+        match self
+            .get_transaction_receipt_by_hash(&hash.to_string())
+            .await
+        {
+            Ok(receipt) => AttemptOutcome::Landed {
+                receipt: Box::new(receipt),
+            },
+            Err(error) if matches!(error.root(), Error::ResourceNotFound { .. }) => {
+                AttemptOutcome::NonceConsumed {
+                    nonce,
+                    account_nonce,
+                }
+            }
+            Err(error) => failed_outcome(error),
+        }
+    }
+}
+
+fn failed_outcome(error: Error) -> AttemptOutcome {
+    AttemptOutcome::Failed {
+        retryable: error.is_retryable(),
+        reason: error.to_string(),
+    }
+}
+
+fn failed_attempt(attempt: u32, nonce: u64, error: Error) -> ResubmitAttempt {
+    ResubmitAttempt {
+        attempt,
+        nonce,
+        hash: None,
+        outcome: failed_outcome(error),
+    }
+}
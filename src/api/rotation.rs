@@ -0,0 +1,502 @@
+//! Guided rotation of an operational signer's token authorities.
+
+use crate::client::Client;
+use crate::crypto::private_key_to_address;
+use crate::requests::TokenAuthorityPayload;
+use crate::requests::authorities::{Authority, AuthorityAction};
+use crate::responses::MintInfo;
+use crate::{
+    AuthorityRotationReport, DEFAULT_ROTATION_RECEIPT_TIMEOUT, Error, Result, RotationFailure,
+    RotationOptions, RotationPhase, RotationStep, SignerRotation, SignerRotationReport,
+};
+use alloy_primitives::{Address, U256};
+use std::str::FromStr;
+use std::time::Duration;
+
+impl Client {
+    /// Rotate an operational signer by moving its token authorities to a new address.
+    ///
+    /// The flow is strictly sequential so that the token never ends up without
+    /// a holder for a role:
+    ///
+    /// 1. Grant every role to the new signer, waiting for each receipt.
+    /// 2. Verify on-chain that the new signer holds every role.
+    /// 3. Revoke every role the old signer still holds, waiting for each
+    ///    receipt. Single-holder roles such as `MasterMintBurn` move with the
+    ///    grant, so there is nothing left to revoke for them.
+    /// 4. Verify on-chain that the old signer holds none of the roles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - The roles to move and the addresses involved
+    /// * `authority_private_key` - Key of the account allowed to grant and revoke the roles
+    ///
+    /// # Returns
+    ///
+    /// A report of the confirmed steps. Errors before the first submission
+    /// are returned as `Err`; once a transaction has been submitted, a
+    /// failure is recorded in [`SignerRotationReport::failure`] together
+    /// with what to submit to finish or roll back the rotation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Authority, Client, SignerRotation};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let old_signer = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///
+    ///     let (rotation, new_wallet) = SignerRotation::generate(token, old_signer)?;
+    ///     let rotation = rotation.role(Authority::Pause, U256::ZERO);
+    ///     // Persist `new_wallet` securely before submitting anything.
+    ///
+    ///     let master_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///     let report = client.rotate_signer(&rotation, master_key).await?;
+    ///     println!("{}", report);
+    ///     # let _ = new_wallet;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn rotate_signer(
+        &self,
+        rotation: &SignerRotation,
+        authority_private_key: &str,
+    ) -> Result<SignerRotationReport> {
+        if rotation.roles.is_empty() {
+            return Err(Error::invalid_parameter(
+                "roles",
+                "Signer rotation requires at least one role",
+            ));
+        }
+        if rotation.old_signer == rotation.new_signer {
+            return Err(Error::invalid_parameter(
+                "new_signer",
+                "New signer must differ from the old signer",
+            ));
+        }
+
+        let authority_address = Address::from_str(&private_key_to_address(authority_private_key)?)
+            .map_err(|e| Error::address(e.to_string()))?;
+        let chain_id = self.fetch_chain_id_from_network().await?;
+        let nonce = self.get_account_nonce(authority_address).await?.nonce;
+        let old_signer = rotation.old_signer;
+        let new_signer = rotation.new_signer;
+        let mut report = SignerRotationReport {
+            token: rotation.token,
+            retired_signer: old_signer,
+            new_signer,
+            steps: Vec::with_capacity(rotation.roles.len() * 2),
+            failure: None,
+        };
+
+        let grants = rotation
+            .roles
+            .iter()
+            .zip(nonce..)
+            .map(|(role, nonce)| TokenAuthorityPayload {
+                chain_id,
+                nonce,
+                action: AuthorityAction::Grant,
+                authority_type: role.authority,
+                authority_address: new_signer,
+                token: rotation.token,
+                value: role.value,
+            })
+            .collect::<Vec<_>>();
+        let next_nonce = nonce + grants.len() as u64;
+        let granted = match self
+            .submit_rotation_steps(grants, authority_private_key, &mut report.steps)
+            .await
+        {
+            Ok(()) => self.verify_rotation_roles(rotation, new_signer, true).await,
+            Err(e) => Err(e),
+        };
+        let info = match granted {
+            Ok(info) => info,
+            Err(e) => {
+                report.failure = Some(RotationFailure {
+                    phase: RotationPhase::Grant,
+                    reason: e.to_string(),
+                    guidance: format!(
+                        "{old_signer} still holds every role. Revoke the roles granted so far \
+                         from {new_signer} to roll back, or retry the rotation."
+                    ),
+                });
+                return Ok(report);
+            }
+        };
+
+        let revokes = rotation
+            .roles
+            .iter()
+            .filter(|role| info.has_authority(role.authority, old_signer))
+            .zip(next_nonce..)
+            .map(|(role, nonce)| TokenAuthorityPayload {
+                chain_id,
+                nonce,
+                action: AuthorityAction::Revoke,
+                authority_type: role.authority,
+                authority_address: old_signer,
+                token: rotation.token,
+                value: U256::ZERO,
+            })
+            .collect::<Vec<_>>();
+        let revoked = match self
+            .submit_rotation_steps(revokes, authority_private_key, &mut report.steps)
+            .await
+        {
+            Ok(()) => self
+                .verify_rotation_roles(rotation, old_signer, false)
+                .await
+                .map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = revoked {
+            report.failure = Some(RotationFailure {
+                phase: RotationPhase::Revoke,
+                reason: e.to_string(),
+                guidance: format!(
+                    "{new_signer} holds every role and {old_signer} may still hold some. \
+                     Revoke the remaining roles from {old_signer} to finish the rotation."
+                ),
+            });
+        }
+        Ok(report)
+    }
+
+    /// Submit authority changes of [`Client::rotate_signer`] in order,
+    /// recording each confirmed one in `steps`.
+    async fn submit_rotation_steps(
+        &self,
+        payloads: Vec<TokenAuthorityPayload>,
+        private_key: &str,
+        steps: &mut Vec<RotationStep>,
+    ) -> Result<()> {
+        for payload in payloads {
+            steps.push(
+                self.submit_rotation_step(payload, private_key, DEFAULT_ROTATION_RECEIPT_TIMEOUT)
+                    .await?,
+            );
+        }
+        Ok(())
+    }
+
+    /// Move one token authority from one address to another in two
+    /// confirmed phases.
+    ///
+    /// 1. Grant the authority to `new_authority`, wait for the receipt and
+    ///    verify on-chain that it holds the authority.
+    /// 2. Unless [`RotationOptions::keep_old`] is set, revoke the authority
+    ///    from `old_authority`, wait for the receipt and verify it no longer
+    ///    holds it. Single-holder authorities such as `MasterMintBurn` move
+    ///    with the grant, in which case there is nothing left to revoke.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token the authority belongs to
+    /// * `old_authority` - Address currently holding the authority
+    /// * `new_authority` - Address taking over the authority
+    /// * `authority` - The authority to rotate
+    /// * `options` - Allowance, revocation and receipt timeout settings
+    /// * `private_key` - Key of the account allowed to grant and revoke the authority
+    ///
+    /// # Returns
+    ///
+    /// A report of the confirmed steps. Errors before the first submission
+    /// are returned as `Err`; once a transaction has been submitted, a
+    /// failure is recorded in [`AuthorityRotationReport::failure`] together
+    /// with what to submit to finish or roll back the rotation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Authority, Client, RotationOptions};
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let old = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///     let new = Address::from_str("0x8ba1f109551bD432803012645Aac136c22C4c3b0")?;
+    ///
+    ///     let master_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///     let report = client
+    ///         .rotate_authority(
+    ///             token,
+    ///             old,
+    ///             new,
+    ///             Authority::MasterMintBurn,
+    ///             RotationOptions::new(),
+    ///             master_key,
+    ///         )
+    ///         .await?;
+    ///     println!("{}", report);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn rotate_authority(
+        &self,
+        token: Address,
+        old_authority: Address,
+        new_authority: Address,
+        authority: Authority,
+        options: RotationOptions,
+        private_key: &str,
+    ) -> Result<AuthorityRotationReport> {
+        if old_authority == new_authority {
+            return Err(Error::invalid_parameter(
+                "new_authority",
+                "New authority must differ from the old authority",
+            ));
+        }
+        let info = self.get_token_metadata(token).await?;
+        if !info.has_authority(authority, old_authority) {
+            return Err(Error::invalid_parameter(
+                "old_authority",
+                format!("{old_authority} does not hold {authority}"),
+            ));
+        }
+
+        let signer = Address::from_str(&private_key_to_address(private_key)?)
+            .map_err(|e| Error::address(e.to_string()))?;
+        let chain_id = self.fetch_chain_id_from_network().await?;
+        let nonce = self.get_account_nonce(signer).await?.nonce;
+        let mut report = AuthorityRotationReport {
+            token,
+            authority,
+            old_authority,
+            new_authority,
+            steps: Vec::with_capacity(2),
+            failure: None,
+        };
+
+        let grant = TokenAuthorityPayload {
+            chain_id,
+            nonce,
+            action: AuthorityAction::Grant,
+            authority_type: authority,
+            authority_address: new_authority,
+            token,
+            value: options.value,
+        };
+        let granted = self
+            .rotation_phase(grant, private_key, options.receipt_timeout, &mut report)
+            .await
+            .and_then(|info| {
+                if info.has_authority(authority, new_authority) {
+                    Ok(info)
+                } else {
+                    Err(Error::business_logic(
+                        "authority_rotation",
+                        format!("{new_authority} does not hold {authority} after the grant"),
+                    ))
+                }
+            });
+        let info = match granted {
+            Ok(info) => info,
+            Err(e) => {
+                report.failure = Some(RotationFailure {
+                    phase: RotationPhase::Grant,
+                    reason: e.to_string(),
+                    guidance: format!(
+                        "{old_authority} still holds {authority}. If the grant to \
+                         {new_authority} landed, revoke it from {new_authority} to roll back; \
+                         otherwise retry the rotation."
+                    ),
+                });
+                return Ok(report);
+            }
+        };
+        if !options.revoke_old || !info.has_authority(authority, old_authority) {
+            return Ok(report);
+        }
+
+        let revoke = TokenAuthorityPayload {
+            chain_id,
+            nonce: nonce + 1,
+            action: AuthorityAction::Revoke,
+            authority_type: authority,
+            authority_address: old_authority,
+            token,
+            value: U256::ZERO,
+        };
+        let revoked = self
+            .rotation_phase(revoke, private_key, options.receipt_timeout, &mut report)
+            .await
+            .and_then(|info| {
+                if info.has_authority(authority, old_authority) {
+                    Err(Error::business_logic(
+                        "authority_rotation",
+                        format!("{old_authority} still holds {authority} after the revoke"),
+                    ))
+                } else {
+                    Ok(())
+                }
+            });
+        if let Err(e) = revoked {
+            report.failure = Some(RotationFailure {
+                phase: RotationPhase::Revoke,
+                reason: e.to_string(),
+                guidance: format!(
+                    "{new_authority} holds {authority} and {old_authority} may still hold it. \
+                     Revoke it from {old_authority} to finish the rotation, or from \
+                     {new_authority} to roll it back."
+                ),
+            });
+        }
+        Ok(report)
+    }
+
+    /// Submit one step of [`Client::rotate_authority`], record it in the
+    /// report and read back the token's authorities.
+    async fn rotation_phase(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+        receipt_timeout: Duration,
+        report: &mut AuthorityRotationReport,
+    ) -> Result<MintInfo> {
+        let token = payload.token;
+        let step = self
+            .submit_rotation_step(payload, private_key, receipt_timeout)
+            .await?;
+        report.steps.push(step);
+        self.get_token_metadata(token).await
+    }
+
+    async fn submit_rotation_step(
+        &self,
+        payload: TokenAuthorityPayload,
+        private_key: &str,
+        receipt_timeout: Duration,
+    ) -> Result<RotationStep> {
+        let action = payload.action;
+        let authority = payload.authority_type;
+        let address = payload.authority_address;
+
+        let response = match action {
+            AuthorityAction::Grant => self.grant_authority(payload, private_key).await?,
+            AuthorityAction::Revoke => self.revoke_authority(payload, private_key).await?,
+        };
+        let receipt = self
+            .wait_for_transaction_receipt_with_timeout(&response.hash.to_string(), receipt_timeout)
+            .await?;
+        if !receipt.success {
+            return Err(Error::business_logic(
+                "signer_rotation",
+                format!(
+                    "{} {} for {} failed in transaction {}",
+                    action, authority, address, response.hash
+                ),
+            ));
+        }
+
+        Ok(RotationStep {
+            action,
+            authority,
+            address,
+            transaction_hash: response.hash,
+        })
+    }
+
+    async fn verify_rotation_roles(
+        &self,
+        rotation: &SignerRotation,
+        address: Address,
+        expect_held: bool,
+    ) -> Result<MintInfo> {
+        let info = self.get_token_metadata(rotation.token).await?;
+        let mismatched: Vec<Authority> = rotation
+            .roles
+            .iter()
+            .map(|role| role.authority)
+            .filter(|authority| info.has_authority(*authority, address) != expect_held)
+            .collect();
+
+        if mismatched.is_empty() {
+            return Ok(info);
+        }
+
+        let roles = mismatched
+            .iter()
+            .map(Authority::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let state = if expect_held {
+            "missing"
+        } else {
+            "still holds"
+        };
+        Err(Error::business_logic(
+            "signer_rotation",
+            format!("{address} {state} authorities after rotation step: {roles}"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_KEY: &str = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[tokio::test]
+    async fn test_rotate_signer_requires_roles() {
+        let client = Client::local().expect("Client should build");
+        let rotation = SignerRotation::new(
+            Address::from([0x01; 20]),
+            Address::from([0x02; 20]),
+            Address::from([0x03; 20]),
+        );
+
+        let result = client.rotate_signer(&rotation, TEST_KEY).await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidParameter { ref parameter, .. }) if parameter == "roles"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_signer_rejects_same_address() {
+        let client = Client::local().expect("Client should build");
+        let signer = Address::from([0x02; 20]);
+        let rotation = SignerRotation::new(Address::from([0x01; 20]), signer, signer)
+            .role(Authority::Pause, U256::ZERO);
+
+        let result = client.rotate_signer(&rotation, TEST_KEY).await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidParameter { ref parameter, .. }) if parameter == "new_signer"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_authority_rejects_same_address() {
+        let client = Client::local().expect("Client should build");
+        let holder = Address::from([0x02; 20]);
+
+        let result = client
+            .rotate_authority(
+                Address::from([0x01; 20]),
+                holder,
+                holder,
+                Authority::MasterMintBurn,
+                RotationOptions::new(),
+                TEST_KEY,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidParameter { ref parameter, .. }) if parameter == "new_authority"
+        ));
+    }
+}
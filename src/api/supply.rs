@@ -0,0 +1,192 @@
+//! Token supply queries.
+//!
+//! The L1 reports only a token's current supply, as part of its metadata.
+//! Supply at an earlier checkpoint is reconstructed by undoing the
+//! successful mints and burns recorded in the checkpoints since.
+
+use super::accounts::parse_balance;
+use crate::client::Client;
+use crate::responses::TokenSupply;
+use crate::{Error, Result};
+use alloy_primitives::Address;
+
+#[cfg(feature = "checkpoints")]
+use crate::responses::{SupplyHistory, Transaction, TxPayload};
+#[cfg(feature = "checkpoints")]
+use alloy_primitives::U256;
+
+/// Times the supply is re-read when the chain advances mid-read.
+const SNAPSHOT_ATTEMPTS: usize = 3;
+
+impl Client {
+    /// Get the current circulating supply of a token.
+    ///
+    /// The supply is read between two checkpoint number queries and re-read
+    /// if the chain advanced in between, so the reported checkpoint is the
+    /// one the supply was observed at. With response caching enabled for
+    /// token metadata the supply can be up to one cache TTL old.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The supply in base units with the token's decimals and checkpoint.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let supply = client.get_token_supply(token).await?;
+    ///     println!("{}", supply);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_token_supply(&self, token: Address) -> Result<TokenSupply> {
+        for _ in 0..SNAPSHOT_ATTEMPTS {
+            let before = self.get_checkpoint_number().await?.number;
+            let mint = self.get_token_metadata(token).await?;
+            let after = self.get_checkpoint_number().await?.number;
+            if before == after {
+                return Ok(TokenSupply {
+                    token,
+                    circulating: parse_balance(&mint.supply)?,
+                    decimals: mint.decimals,
+                    checkpoint: after,
+                });
+            }
+        }
+        Err(Error::business_logic(
+            "get_token_supply",
+            format!(
+                "Checkpoint advanced during each of {} supply reads",
+                SNAPSHOT_ATTEMPTS
+            ),
+        ))
+    }
+
+    /// Get the supply of a token at a past checkpoint.
+    ///
+    /// Every checkpoint after `checkpoint` is scanned for mints and burns of
+    /// the token, and the receipt of each is fetched to skip failed ones,
+    /// so the cost grows with the distance to the latest checkpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    /// * `checkpoint` - The checkpoint to report the supply at
+    ///
+    /// # Returns
+    ///
+    /// The supply at `checkpoint` and now, with the amounts minted and
+    /// burned in between.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let history = client.get_token_supply_at(token, 1_000).await?;
+    ///     println!("Supply then: {}", history.at.to_decimal_string());
+    ///     println!("Minted since: {}", history.minted);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "checkpoints")]
+    pub async fn get_token_supply_at(
+        &self,
+        token: Address,
+        checkpoint: u64,
+    ) -> Result<SupplyHistory> {
+        let current = self.get_token_supply(token).await?;
+        if checkpoint > current.checkpoint {
+            return Err(Error::invalid_parameter(
+                "checkpoint",
+                format!(
+                    "Checkpoint {} is after the latest checkpoint {}",
+                    checkpoint, current.checkpoint
+                ),
+            ));
+        }
+
+        let mut minted = U256::ZERO;
+        let mut burned = U256::ZERO;
+        for number in checkpoint + 1..=current.checkpoint {
+            let mut transactions = self.stream_checkpoint_transactions(number).await?;
+            while let Some(transaction) = transactions.next().await {
+                let transaction = transaction?;
+                let Some((is_mint, amount)) = supply_change(&transaction, token)? else {
+                    continue;
+                };
+                let receipt = self
+                    .get_transaction_receipt_by_hash(&transaction.hash.to_string())
+                    .await?;
+                if !receipt.success {
+                    continue;
+                }
+                let total = if is_mint { &mut minted } else { &mut burned };
+                *total = total.saturating_add(amount);
+            }
+        }
+
+        let circulating = current
+            .circulating
+            .checked_add(burned)
+            .and_then(|supply| supply.checked_sub(minted))
+            .ok_or_else(|| {
+                Error::business_logic(
+                    "get_token_supply_at",
+                    "Mints since the checkpoint exceed the current supply",
+                )
+            })?;
+        Ok(SupplyHistory {
+            at: TokenSupply {
+                circulating,
+                checkpoint,
+                ..current
+            },
+            current,
+            minted,
+            burned,
+        })
+    }
+}
+
+/// The mint (`true`) or burn (`false`) of `token` a transaction performs.
+#[cfg(feature = "checkpoints")]
+fn supply_change(transaction: &Transaction, token: Address) -> Result<Option<(bool, U256)>> {
+    let (is_mint, value) = match &transaction.data {
+        TxPayload::TokenMint {
+            value, token: mint, ..
+        }
+        | TxPayload::TokenBridgeAndMint {
+            value, token: mint, ..
+        } if *mint == token => (true, value),
+        TxPayload::TokenBurn {
+            value, token: mint, ..
+        }
+        | TxPayload::TokenBurnAndBridge {
+            value, token: mint, ..
+        } if *mint == token => (false, value),
+        _ => return Ok(None),
+    };
+    Ok(Some((is_mint, parse_balance(value)?)))
+}
@@ -1,23 +1,76 @@
 //! Token-related API operations.
+//!
+//! The L1 has no delegated spend: holders cannot approve a third party to
+//! transfer their tokens, so there are no approve or transfer-from
+//! operations. The only allowance model is the mint allowance attached to the
+//! `MintBurnTokens` authority, granted with [`Client::grant_authority`] and
+//! queried with [`Client::get_mint_allowance`].
+//!
+//! Token accounts are looked up by owner and token, so the holders of a token
+//! cannot be listed through the API.
+//!
+//! Nor is there an instruction that freezes one account's balance or moves
+//! tokens out of an account by administrative order, so there are no freeze
+//! or seizure payloads. The per-account restriction the L1 does offer is the
+//! blacklist, managed with [`Client::manage_blacklist`] under the
+//! `ManageList` authority.
 
 use crate::Result;
 use crate::client::Client;
 use crate::client::config::api_path;
-use crate::client::config::endpoints::tokens::{
-    BURN, GRANT_AUTHORITY, MANAGE_BLACKLIST, MANAGE_WHITELIST, MINT, PAUSE, TOKEN_METADATA,
-    UPDATE_METADATA,
-};
-use crate::crypto::sign_transaction_payload;
-use crate::requests::{
-    BlacklistTokenRequest, BurnTokenRequest, MintTokenRequest, PauseTokenRequest,
-    TokenAuthorityPayload, TokenAuthorityRequest, TokenBlacklistPayload, TokenBurnPayload,
-    TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
-    UpdateMetadataRequest, WhitelistTokenRequest,
-};
+use crate::client::config::endpoints::tokens::TOKEN_METADATA;
 use crate::responses::MintInfo;
-use crate::responses::TransactionResponse;
 use alloy_primitives::Address;
 
+#[cfg(feature = "tokens")]
+use crate::requests::{
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload,
+    TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
+};
+#[cfg(feature = "tokens")]
+use crate::responses::{
+    AuthorizationDenial, ListMembership, TokenAuthorities, TokenOperation, TransactionResponse,
+};
+#[cfg(feature = "tokens")]
+use alloy_primitives::U256;
+
+impl Client {
+    /// Get token metadata by mint address.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint_address` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The token metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let mint = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let mint_info = client.get_token_metadata(mint).await?;
+    ///     println!("Token: {}", mint_info.symbol);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_token_metadata(&self, mint_address: Address) -> Result<MintInfo> {
+        let path = api_path(&format!("{}?token={}", TOKEN_METADATA, mint_address));
+        let response: MintInfo = self.get(&path).await?;
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "tokens")]
 impl Client {
     /// Mint tokens to an account.
     ///
@@ -34,10 +87,7 @@ impl Client {
         payload: TokenMintPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = MintTokenRequest { payload, signature };
-
-        self.post(&api_path(MINT), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Burn tokens from an account.
@@ -55,10 +105,7 @@ impl Client {
         payload: TokenBurnPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = BurnTokenRequest { payload, signature };
-
-        self.post(&api_path(BURN), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Grant authority for a token to an address.
@@ -76,10 +123,7 @@ impl Client {
         payload: TokenAuthorityPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = TokenAuthorityRequest { payload, signature };
-
-        self.post(&api_path(GRANT_AUTHORITY), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Revoke authority for a token from an address.
@@ -100,21 +144,122 @@ impl Client {
         payload: TokenAuthorityPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = TokenAuthorityRequest { payload, signature };
+        self.sign_and_submit(payload, private_key).await
+    }
+
+    /// Get who holds which authority on a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The token's authorities with mint allowances parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let minter = Address::from_str("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf")?;
+    ///
+    ///     let authorities = client.get_authorities(token).await?;
+    ///     if authorities.can_mint(minter, U256::from(1_000u64)) {
+    ///         println!("{} may mint 1000 base units", minter);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_authorities(&self, token: Address) -> Result<TokenAuthorities> {
+        let mint = self.get_token_metadata(token).await?;
+        TokenAuthorities::from_mint_info(token, &mint)
+    }
+
+    /// Check that a signer may perform a token operation before submitting it.
+    ///
+    /// The L1 rejects an unauthorized mint, pause or list change only after
+    /// the transaction is submitted and its nonce spent; checking the current
+    /// [`MintInfo`] first turns that into a typed reason. Authorities can
+    /// change between the check and the submission, so a pass is not a
+    /// guarantee.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    /// * `signer` - The address that will sign the operation
+    /// * `operation` - The operation to check
+    ///
+    /// # Returns
+    ///
+    /// `None` if the signer holds the needed authority and allowance,
+    /// otherwise why it does not.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, TokenOperation};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let minter = Address::from_str("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf")?;
+    ///
+    ///     let operation = TokenOperation::Mint(U256::from(1_000u64));
+    ///     if let Some(denial) = client.check_authorization(token, minter, operation).await? {
+    ///         eprintln!("Not submitting: {}", denial);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn check_authorization(
+        &self,
+        token: Address,
+        signer: Address,
+        operation: TokenOperation,
+    ) -> Result<Option<AuthorizationDenial>> {
+        Ok(self.get_authorities(token).await?.denial(signer, operation))
+    }
 
-        self.post(&api_path(GRANT_AUTHORITY), &request).await
+    /// Get the remaining mint allowance of a minter.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    /// * `minter` - The address holding the `MintBurnTokens` authority
+    ///
+    /// # Returns
+    ///
+    /// The amount the minter may still mint, or zero if it is not a minter.
+    pub async fn get_mint_allowance(&self, token: Address, minter: Address) -> Result<U256> {
+        self.get_token_metadata(token).await?.mint_allowance(minter)
     }
 
-    /// Get token metadata by mint address.
+    /// Check an address against a token's blacklist and whitelist.
+    ///
+    /// The L1 exposes the lists only as part of the token metadata, so this
+    /// reads [`MintInfo`] and answers from it. With response caching enabled,
+    /// repeated checks against the same token reuse one download.
     ///
     /// # Arguments
     ///
-    /// * `mint_address` - The token mint address
+    /// * `token` - The token mint address
+    /// * `address` - The address to check
     ///
     /// # Returns
     ///
-    /// The token metadata.
+    /// The address's list membership and whether the token is private.
     ///
     /// # Example
     ///
@@ -126,18 +271,36 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = Client::mainnet()?;
-    ///     let mint = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let address = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
     ///
-    ///     let mint_info = client.get_token_metadata(mint).await?;
-    ///     println!("Token: {}", mint_info.symbol);
+    ///     let membership = client.get_list_membership(token, address).await?;
+    ///     if !membership.is_allowed() {
+    ///         println!("{} may not use this token", address);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get_token_metadata(&self, mint_address: Address) -> Result<MintInfo> {
-        let path = api_path(&format!("{}?token={}", TOKEN_METADATA, mint_address));
-        let response: MintInfo = self.get(&path).await?;
-        Ok(response)
+    pub async fn get_list_membership(
+        &self,
+        token: Address,
+        address: Address,
+    ) -> Result<ListMembership> {
+        Ok(self
+            .get_token_metadata(token)
+            .await?
+            .list_membership(address))
+    }
+
+    /// Whether `address` is on the token's blacklist.
+    pub async fn is_blacklisted(&self, token: Address, address: Address) -> Result<bool> {
+        Ok(self.get_list_membership(token, address).await?.blacklisted)
+    }
+
+    /// Whether `address` is on the token's whitelist.
+    pub async fn is_whitelisted(&self, token: Address, address: Address) -> Result<bool> {
+        Ok(self.get_list_membership(token, address).await?.whitelisted)
     }
 
     /// Pause or unpause a token.
@@ -155,10 +318,7 @@ impl Client {
         payload: TokenPausePayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = PauseTokenRequest { payload, signature };
-
-        self.post(&api_path(PAUSE), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Manage token blacklist (add or remove addresses).
@@ -176,10 +336,7 @@ impl Client {
         payload: TokenBlacklistPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = BlacklistTokenRequest { payload, signature };
-
-        self.post(&api_path(MANAGE_BLACKLIST), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Manage token whitelist (add or remove addresses).
@@ -197,10 +354,7 @@ impl Client {
         payload: TokenWhitelistPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = WhitelistTokenRequest { payload, signature };
-
-        self.post(&api_path(MANAGE_WHITELIST), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 
     /// Update token metadata.
@@ -218,16 +372,16 @@ impl Client {
         payload: TokenMetadataUpdatePayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = UpdateMetadataRequest { payload, signature };
-
-        self.post(&api_path(UPDATE_METADATA), &request).await
+        self.sign_and_submit(payload, private_key).await
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::requests::{
+        TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMintPayload,
+        TokenPausePayload, TokenWhitelistPayload,
+    };
     use crate::{Authority, AuthorityAction, BlacklistAction, PauseAction, WhitelistAction};
     use alloy_primitives::{Address, U256};
     use std::str::FromStr;
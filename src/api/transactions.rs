@@ -1,23 +1,39 @@
 //! Transaction-related API operations.
 
+use crate::api::accounts::parse_balance;
 use crate::client::Client;
+use crate::client::audit::SignedEnvelope;
+use crate::client::config::endpoints::tokens;
 use crate::client::config::endpoints::transactions::{
     BY_HASH, ESTIMATE_FEE, FINALIZED_BY_HASH, PAYMENT, RECEIPT_BY_HASH,
 };
 use crate::client::config::{API_VERSION, api_path};
-use crate::crypto::sign_transaction_payload;
+use crate::crypto::private_key_to_address;
 use crate::error::Error;
-use crate::requests::{FeeEstimateRequest, PaymentPayload, PaymentRequest};
+use crate::requests::{
+    FeeEstimateRequest, NativePaymentPayload, PaymentPayload, RawTransaction, SignedSubmission,
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload,
+    TokenMintPayload, TokenPausePayload, TokenWhitelistPayload, TransactionPayload,
+};
 use crate::responses::FeeEstimate;
 use crate::responses::TransactionReceipt;
 use crate::responses::TransactionResponse;
-use crate::{FinalizedTransaction, Result, Transaction};
+use crate::storage::Storage;
+use crate::transport::BackoffStrategy;
+use crate::{
+    BatchReport, DEFAULT_RECEIPT_TIMEOUT, FinalizedTransaction, PaymentOptions, PaymentOutcome,
+    PaymentReference, ReceiptPolling, Result, Signature, Transaction, TxStatus,
+};
+
+#[cfg(feature = "bridge")]
+use crate::client::config::endpoints::bridge;
+#[cfg(feature = "bridge")]
+use crate::requests::{TokenBridgeAndMintPayload, TokenBurnAndBridgePayload};
+use alloy_primitives::{Address, U256};
+use std::str::FromStr;
 use std::time::Duration;
 use tokio::time::{Instant, sleep};
 
-const DEFAULT_RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
-const DEFAULT_RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
-
 impl Client {
     /// Send a payment transaction.
     ///
@@ -61,11 +77,411 @@ impl Client {
         payload: PaymentPayload,
         private_key: &str,
     ) -> Result<TransactionResponse> {
-        let signature = sign_transaction_payload(&payload, private_key)?;
-        let request = PaymentRequest { payload, signature };
+        self.sign_and_submit(payload, private_key).await
+    }
+
+    /// Send a payment and record an external reference for it.
+    ///
+    /// The L1 has no memo field, so the reference is kept in `storage`
+    /// under the payment's signature hash rather than sent on-chain. It is
+    /// recorded before submitting and updated with the transaction hash
+    /// once the network accepts the payment; a submission the network
+    /// definitely did not process removes it again. Look it up with
+    /// [`PaymentReference::load`] or, for a transaction fetched later,
+    /// [`PaymentReference::for_transaction`].
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Payment parameters
+    /// * `reference` - External reference, at most [`MAX_PAYMENT_REFERENCE_BYTES`](crate::MAX_PAYMENT_REFERENCE_BYTES) bytes
+    /// * `storage` - Where references are recorded
+    /// * `private_key` - Private key for signing the transaction
+    ///
+    /// # Returns
+    ///
+    /// The payment response containing the transaction hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::storage::MemoryStorage;
+    /// use onemoney_protocol::{Client, PaymentPayload, PaymentReference};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let storage = MemoryStorage::new();
+    ///
+    ///     let payload = PaymentPayload::new(
+    ///         1212101,
+    ///         0,
+    ///         "0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0",
+    ///         1000000u64,
+    ///         "0x1234567890abcdef1234567890abcdef12345678",
+    ///     )?;
+    ///     let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///     let result = client
+    ///         .send_payment_with_reference(payload, "INV-2024-0042", &storage, private_key)
+    ///         .await?;
+    ///
+    ///     let transaction = client.get_transaction_by_hash(&result.hash.to_string()).await?;
+    ///     if let Some(record) = PaymentReference::for_transaction(&storage, &transaction)? {
+    ///         println!("{}", record);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_payment_with_reference(
+        &self,
+        payload: PaymentPayload,
+        reference: &str,
+        storage: &dyn Storage,
+        private_key: &str,
+    ) -> Result<TransactionResponse> {
+        let mut record = PaymentReference::new(reference, payload.signature_hash())?;
+        record.save(storage)?;
+
+        match self.sign_and_submit(payload, private_key).await {
+            Ok(response) => {
+                record.transaction_hash = Some(response.hash);
+                record.save(storage)?;
+                Ok(response)
+            }
+            Err(e) => {
+                // A timed-out submission may still land, so keep its reference.
+                if !e.is_timeout() {
+                    PaymentReference::remove(storage, record.signature_hash)?;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Send a payment of the chain's native asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Recipient and amount of the native transfer
+    /// * `private_key` - Private key for signing the transaction
+    ///
+    /// # Returns
+    ///
+    /// The payment response containing the transaction hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, NativePaymentPayload};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let payload = NativePaymentPayload::new(
+    ///         1212101,
+    ///         0,
+    ///         "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+    ///         "1000000",
+    ///     )?;
+    ///     let result = client.send_native(payload, "your_private_key_here").await?;
+    ///     println!("Transaction hash: {}", result.hash);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_native(
+        &self,
+        payload: NativePaymentPayload,
+        private_key: &str,
+    ) -> Result<TransactionResponse> {
+        self.sign_and_submit(payload, private_key).await
+    }
+
+    /// Broadcast a transaction signed by another tool or SDK.
+    ///
+    /// The blob is decoded into the payload type its endpoint names and
+    /// submitted like a transaction signed by this client, including the
+    /// chain ID check and the audit record. The signature is checked for
+    /// well-formedness only; whether it authorizes the transaction is up to
+    /// the L1.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A [`RawTransaction`] blob, as produced by [`RawTransaction::to_rlp`]
+    ///
+    /// # Returns
+    ///
+    /// The transaction response containing the transaction hash.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let blob = std::fs::read("signed.tx")?;
+    ///     let result = client.send_raw_transaction(&blob).await?;
+    ///     println!("Transaction hash: {}", result.hash);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_raw_transaction(&self, raw: &[u8]) -> Result<TransactionResponse> {
+        let raw = RawTransaction::from_rlp(raw)?;
+        raw.signature.validate_canonical()?;
+        let signature = raw.signature.clone();
+
+        match raw.endpoint.as_str() {
+            // Token and native payments share the endpoint; only the token
+            // field tells them apart.
+            PAYMENT => match raw.payload_as::<PaymentPayload>() {
+                Ok(payload) => self.submit_presigned(payload, signature).await,
+                Err(_) => {
+                    let payload = raw.payload_as::<NativePaymentPayload>()?;
+                    self.submit_presigned(payload, signature).await
+                }
+            },
+            tokens::MINT => {
+                let payload = raw.payload_as::<TokenMintPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::BURN => {
+                let payload = raw.payload_as::<TokenBurnPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::GRANT_AUTHORITY => {
+                let payload = raw.payload_as::<TokenAuthorityPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::PAUSE => {
+                let payload = raw.payload_as::<TokenPausePayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::MANAGE_BLACKLIST => {
+                let payload = raw.payload_as::<TokenBlacklistPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::MANAGE_WHITELIST => {
+                let payload = raw.payload_as::<TokenWhitelistPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            tokens::UPDATE_METADATA => {
+                let payload = raw.payload_as::<TokenMetadataUpdatePayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            #[cfg(feature = "bridge")]
+            bridge::BRIDGE_AND_MINT => {
+                let payload = raw.payload_as::<TokenBridgeAndMintPayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            #[cfg(feature = "bridge")]
+            bridge::BURN_AND_BRIDGE => {
+                let payload = raw.payload_as::<TokenBurnAndBridgePayload>()?;
+                self.submit_presigned(payload, signature).await
+            }
+            other => Err(Error::invalid_parameter(
+                "endpoint",
+                format!("No transaction type is submitted to {}", other),
+            )),
+        }
+    }
+
+    /// Submit `payload` with a signature made elsewhere.
+    async fn submit_presigned<P>(
+        &self,
+        payload: P,
+        signature: Signature,
+    ) -> Result<TransactionResponse>
+    where
+        P: TransactionPayload,
+    {
+        self.check_payload(&payload)?;
+        let envelope = SignedEnvelope::new(&payload, vec![signature.clone()])?;
+        let request = SignedSubmission {
+            payload: &payload,
+            signature,
+        };
+        self.submit(&api_path(P::ENDPOINT), &request, &envelope)
+            .await
+    }
+
+    /// Send several payments signed by the same key.
+    ///
+    /// Payments are submitted in order, each exactly as given, so every
+    /// payload needs its own nonce. A failed payment does not stop the batch;
+    /// note that once one nonce is rejected, payments with higher nonces from
+    /// the same sender are usually rejected too.
+    ///
+    /// # Arguments
+    ///
+    /// * `payments` - Payment payloads with chain ID and nonce set
+    /// * `private_key` - Private key for signing every payment
+    ///
+    /// # Returns
+    ///
+    /// A report of submitted, retryable and rejected payments. Feed
+    /// [`BatchReport::take_retryable`] back into this method to re-drive them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, PaymentPayload};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let private_key = "your_private_key_here";
+    ///
+    ///     let payments = (0..3)
+    ///         .map(|nonce| PaymentPayload {
+    ///             chain_id: 1212101,
+    ///             nonce,
+    ///             recipient: Address::repeat_byte(0x11),
+    ///             value: U256::from(1000000000000000000u64),
+    ///             token,
+    ///         })
+    ///         .collect();
+    ///
+    ///     let report = client.pay_many(payments, private_key).await;
+    ///     println!("{}", report);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn pay_many(
+        &self,
+        payments: Vec<PaymentPayload>,
+        private_key: &str,
+    ) -> BatchReport<PaymentPayload, TransactionResponse> {
+        let mut report = BatchReport::new();
+        for (index, payload) in payments.into_iter().enumerate() {
+            let result = self.send_payment(payload.clone(), private_key).await;
+            report.record(index, payload, result);
+        }
+        report
+    }
+
+    /// Send a payment and wait for its receipt.
+    ///
+    /// Covers the common payment flow in one call: checks the signer's
+    /// balance, fills the chain ID and nonce from the network unless set in
+    /// `options`, signs, submits and polls for the receipt.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - Recipient address
+    /// * `token` - Token to transfer
+    /// * `amount` - Amount in the token's smallest unit
+    /// * `private_key` - Private key of the sender
+    /// * `options` - Preflight and waiting options
+    ///
+    /// # Returns
+    ///
+    /// The payment outcome. Once the payment is submitted, a receipt that
+    /// does not arrive, whether the timeout elapses or polling fails, is
+    /// reported as [`PaymentOutcome::Pending`] rather than an error, since
+    /// the payment may still be included and the hash is needed to find out.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, PaymentOptions, PaymentOutcome};
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let to = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+    ///
+    ///     let outcome = client
+    ///         .send_payment_and_wait(to, token, U256::from(1000u64), private_key, PaymentOptions::new())
+    ///         .await?;
+    ///     match outcome {
+    ///         PaymentOutcome::Confirmed { receipt, .. } => println!("Paid in {}", receipt.transaction_hash),
+    ///         PaymentOutcome::Failed { receipt, .. } => println!("Reverted: {}", receipt.transaction_hash),
+    ///         PaymentOutcome::Pending { hash, .. } => println!("Still pending: {}", hash),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_payment_and_wait(
+        &self,
+        to: Address,
+        token: Address,
+        amount: U256,
+        private_key: &str,
+        options: PaymentOptions,
+    ) -> Result<PaymentOutcome> {
+        if amount.is_zero() {
+            return Err(Error::validation(
+                "amount",
+                "Payment amount must be positive",
+            ));
+        }
+        let sender = Address::from_str(&private_key_to_address(private_key)?)
+            .map_err(|e| Error::address(e.to_string()))?;
+
+        if options.check_balance {
+            let balance = match self.get_associated_token_account(sender, token).await {
+                Ok(account) => parse_balance(&account.balance)?,
+                Err(err) if matches!(err.root(), Error::ResourceNotFound { .. }) => U256::ZERO,
+                Err(err) => return Err(err),
+            };
+            if balance < amount {
+                return Err(Error::business_logic(
+                    "payment",
+                    format!(
+                        "Insufficient balance: {} holds {} of {}, payment needs {}",
+                        sender, balance, token, amount
+                    ),
+                ));
+            }
+        }
+
+        let chain_id = match options.chain_id {
+            Some(chain_id) => chain_id,
+            None => self.fetch_chain_id_from_network().await?,
+        };
+        let nonce = match options.nonce {
+            Some(nonce) => nonce,
+            None => self.get_account_nonce(sender).await?.nonce,
+        };
 
-        let path = api_path(PAYMENT);
-        self.post(&path, &request).await
+        let payload = PaymentPayload {
+            chain_id,
+            nonce,
+            recipient: to,
+            value: amount,
+            token,
+        };
+        let hash = self.send_payment(payload.clone(), private_key).await?.hash;
+
+        let hash_string = hash.to_string();
+        let request_path = format!("{}{}?hash={}", API_VERSION, RECEIPT_BY_HASH, hash_string);
+        let receipt = poll_for_transaction_receipt(
+            || async { self.get_transaction_receipt_by_hash(&hash_string).await },
+            request_path,
+            &ReceiptPolling::fixed(options.poll_interval).timeout(options.receipt_timeout),
+        )
+        .await;
+
+        match receipt {
+            Ok(receipt) if receipt.success => Ok(PaymentOutcome::Confirmed { payload, receipt }),
+            Ok(receipt) => Ok(PaymentOutcome::Failed { payload, receipt }),
+            Err(_) => Ok(PaymentOutcome::Pending { payload, hash }),
+        }
     }
 
     /// Get transaction by hash.
@@ -113,6 +529,52 @@ impl Client {
         &self,
         hash: &str,
         timeout: Duration,
+    ) -> Result<TransactionReceipt> {
+        self.wait_for_transaction_receipt_with_polling(
+            hash,
+            &ReceiptPolling::new().timeout(timeout),
+        )
+        .await
+    }
+
+    /// Wait for a transaction receipt, polling on a custom schedule.
+    ///
+    /// Interactive flows want a short fixed interval; background settlement
+    /// can back off and add jitter so that many waiters do not hit the node
+    /// at the same moments.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Transaction hash
+    /// * `polling` - Intervals, jitter and limits of the polls
+    ///
+    /// # Returns
+    ///
+    /// The receipt, or [`Error::RequestTimeout`] once the schedule's timeout
+    /// or poll budget is spent.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, ReceiptPolling};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let polling = ReceiptPolling::exponential(Duration::from_millis(100), 1.5).jitter(0.3);
+    ///
+    ///     let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    ///     let receipt = client.wait_for_transaction_receipt_with_polling(hash, &polling).await?;
+    ///     println!("Included in checkpoint {:?}", receipt.checkpoint_number);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_for_transaction_receipt_with_polling(
+        &self,
+        hash: &str,
+        polling: &ReceiptPolling,
     ) -> Result<TransactionReceipt> {
         let hash_owned = hash.to_string();
         let request_path = format!("{}{}?hash={}", API_VERSION, RECEIPT_BY_HASH, hash);
@@ -120,8 +582,7 @@ impl Client {
         poll_for_transaction_receipt(
             || async { self.get_transaction_receipt_by_hash(&hash_owned).await },
             request_path,
-            timeout,
-            DEFAULT_RECEIPT_POLL_INTERVAL,
+            polling,
         )
         .await
     }
@@ -165,25 +626,93 @@ impl Client {
         let path = format!("{}{}?hash={}", API_VERSION, FINALIZED_BY_HASH, hash);
         self.get(&path).await
     }
+
+    /// Get where a transaction stands in its lifecycle.
+    ///
+    /// The status is derived from the receipt, whether it succeeded and how
+    /// many checkpoints followed the one it was included in; see
+    /// [`ClientBuilder::finality_depth`](crate::ClientBuilder::finality_depth).
+    ///
+    /// The gateway exposes no pending pool, so there is no
+    /// `get_pending_transactions`: a transaction that has not reached the
+    /// node and one the node dropped both report [`TxStatus::Unknown`].
+    /// Callers that need to tell them apart have to track when they
+    /// submitted and give up after their own deadline.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Transaction hash
+    ///
+    /// # Returns
+    ///
+    /// The transaction status.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    ///     let status = client.get_transaction_status(hash).await?;
+    ///     if status.is_final() {
+    ///         println!("{}", status);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_transaction_status(&self, hash: &str) -> Result<TxStatus> {
+        let receipt = match self.get_transaction_receipt_by_hash(hash).await {
+            Ok(receipt) => receipt,
+            Err(err) if matches!(err.root(), Error::ResourceNotFound { .. }) => {
+                let known = match self.get_transaction_by_hash(hash).await {
+                    Ok(_) => true,
+                    Err(err) if matches!(err.root(), Error::ResourceNotFound { .. }) => false,
+                    Err(err) => return Err(err),
+                };
+                return Ok(TxStatus::from_receipt(
+                    None,
+                    known,
+                    0,
+                    self.inner.finality_depth,
+                ));
+            }
+            Err(err) => return Err(err),
+        };
+        let latest = match (receipt.success, receipt.checkpoint_number) {
+            (true, Some(_)) => self.get_checkpoint_number().await?.number,
+            _ => 0,
+        };
+        Ok(TxStatus::from_receipt(
+            Some(&receipt),
+            true,
+            latest,
+            self.inner.finality_depth,
+        ))
+    }
 }
 
 async fn poll_for_transaction_receipt<F, Fut>(
     mut fetch_receipt: F,
     request_path: String,
-    timeout: Duration,
-    poll_interval: Duration,
+    polling: &ReceiptPolling,
 ) -> Result<TransactionReceipt>
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<TransactionReceipt>>,
 {
+    let timeout = polling.timeout;
     if timeout.is_zero() {
         return Err(Error::invalid_parameter(
             "timeout",
             "Timeout must be greater than zero",
         ));
     }
-    if poll_interval.is_zero() {
+    if polling.backoff.initial.is_zero() {
         return Err(Error::invalid_parameter(
             "poll_interval",
             "Poll interval must be greater than zero",
@@ -191,19 +720,21 @@ where
     }
 
     let start = Instant::now();
+    let mut polls = 0;
 
     loop {
+        polls += 1;
         match fetch_receipt().await {
             Ok(receipt) => return Ok(receipt),
             Err(err) => {
-                if !matches!(err, Error::ResourceNotFound { .. }) {
+                if !matches!(err.root(), Error::ResourceNotFound { .. }) {
                     return Err(err);
                 }
             }
         }
 
         let elapsed = start.elapsed();
-        if elapsed >= timeout {
+        if elapsed >= timeout || polling.max_polls.is_some_and(|max| polls >= max) {
             return Err(Error::request_timeout(
                 request_path.clone(),
                 duration_to_millis(timeout),
@@ -211,7 +742,7 @@ where
         }
 
         if let Some(remaining) = timeout.checked_sub(elapsed) {
-            let sleep_duration = poll_interval.min(remaining);
+            let sleep_duration = polling.delay(polls).min(remaining);
             sleep(sleep_duration).await;
         } else {
             return Err(Error::request_timeout(
@@ -415,8 +946,7 @@ mod tests {
                 async move { result }
             },
             request_path,
-            Duration::from_millis(100),
-            Duration::from_millis(10),
+            &ReceiptPolling::fixed(Duration::from_millis(10)).timeout(Duration::from_millis(100)),
         )
         .await
         .expect("should eventually succeed");
@@ -447,8 +977,7 @@ mod tests {
                 async move { result }
             },
             request_path,
-            Duration::from_millis(50),
-            Duration::from_millis(10),
+            &ReceiptPolling::fixed(Duration::from_millis(10)).timeout(Duration::from_millis(50)),
         )
         .await
         .expect_err("should propagate error");
@@ -456,6 +985,29 @@ mod tests {
         assert!(matches!(err, Error::HttpTransport { .. }));
     }
 
+    #[tokio::test]
+    async fn test_wait_for_transaction_receipt_stops_at_poll_budget() {
+        let polls = Mutex::new(0u32);
+        let polling = ReceiptPolling::exponential(Duration::from_millis(1), 2.0)
+            .jitter(0.5)
+            .timeout(Duration::from_secs(5))
+            .max_polls(3);
+
+        let err = poll_for_transaction_receipt(
+            || {
+                *polls.lock().expect("lock poisoned") += 1;
+                async { Err(Error::resource_not_found("receipt", "pending")) }
+            },
+            "/v1/transactions/receipt/by_hash?hash=0xdd".to_string(),
+            &polling,
+        )
+        .await
+        .expect_err("budget should run out");
+
+        assert!(matches!(err, Error::RequestTimeout { .. }));
+        assert_eq!(*polls.lock().expect("lock poisoned"), 3);
+    }
+
     #[tokio::test]
     async fn test_wait_for_transaction_receipt_with_zero_timeout_is_rejected() {
         let err = poll_for_transaction_receipt(
@@ -465,8 +1017,7 @@ mod tests {
                 ))
             },
             "/v1/transactions/receipt/by_hash?hash=0xcc".to_string(),
-            Duration::from_secs(0),
-            Duration::from_millis(10),
+            &ReceiptPolling::fixed(Duration::from_millis(10)).timeout(Duration::ZERO),
         )
         .await
         .expect_err("zero timeout invalid");
@@ -0,0 +1,392 @@
+//! Audit trail of signed submissions.
+//!
+//! A client configured with [`ClientBuilder::audit_sink`] hands every
+//! submission to the sink: the exact RLP bytes that were signed, the
+//! signatures, the payload as JSON and the outcome, including the
+//! transaction hash when the L1 accepted it. [`JsonlAuditLog`] is the
+//! file-backed sink; implement [`AuditSink`] to forward records elsewhere.
+//!
+//! [`ClientBuilder::audit_sink`]: super::ClientBuilder::audit_sink
+
+use crate::requests::TransactionPayload;
+use crate::{DryRunResult, Error, Result, Signature};
+use alloy_primitives::{B256, Bytes, keccak256};
+use alloy_rlp::encode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Destination for audit records.
+///
+/// `record` runs on the submitting task after the L1 has answered, so slow
+/// sinks delay the submission's result.
+pub trait AuditSink: Send + Sync {
+    /// Persist one record.
+    fn record(&self, record: &AuditRecord) -> Result<()>;
+}
+
+/// Everything signed and sent for one submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Endpoint the submission was posted to.
+    pub endpoint: String,
+    /// Unix time in milliseconds at which the outcome was known.
+    pub timestamp_ms: u64,
+    /// RLP encoding of the payload; its keccak-256 is `signature_hash`.
+    pub signed_bytes: Bytes,
+    /// Digest covered by the signatures.
+    pub signature_hash: B256,
+    /// Signatures sent with the payload.
+    pub signatures: Vec<Signature>,
+    /// The payload as it appears in the request body.
+    pub payload: Value,
+    /// How the L1 answered.
+    pub outcome: AuditOutcome,
+}
+
+/// Result of an audited submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The L1 accepted the transaction under `hash`.
+    Accepted { hash: B256 },
+    /// The submission failed. The transaction may still land when the
+    /// failure happened after the request was sent, e.g. on a timeout.
+    Failed { error: String },
+}
+
+impl AuditOutcome {
+    /// Transaction hash of an accepted submission.
+    pub fn hash(&self) -> Option<B256> {
+        match self {
+            AuditOutcome::Accepted { hash } => Some(*hash),
+            AuditOutcome::Failed { .. } => None,
+        }
+    }
+}
+
+/// A signed payload awaiting the outcome of its submission.
+#[derive(Debug, Clone)]
+pub(crate) struct SignedEnvelope {
+    pub(crate) signature_hash: B256,
+    signed_bytes: Bytes,
+    signatures: Vec<Signature>,
+    payload: Value,
+}
+
+impl SignedEnvelope {
+    pub(crate) fn new<P>(payload: &P, signatures: Vec<Signature>) -> Result<Self>
+    where
+        P: TransactionPayload,
+    {
+        Ok(Self {
+            signature_hash: payload.signature_hash(),
+            signed_bytes: encode(payload).into(),
+            signatures,
+            payload: serde_json::to_value(payload)?,
+        })
+    }
+
+    pub(crate) fn dry_run(&self, endpoint: &str, body: Value) -> DryRunResult {
+        DryRunResult {
+            endpoint: endpoint.to_string(),
+            body,
+            signature_hash: self.signature_hash,
+            signed_bytes: self.signed_bytes.clone(),
+        }
+    }
+
+    pub(crate) fn record(&self, endpoint: &str, outcome: AuditOutcome) -> AuditRecord {
+        AuditRecord {
+            endpoint: endpoint.to_string(),
+            timestamp_ms: unix_now_ms(),
+            signed_bytes: self.signed_bytes.clone(),
+            signature_hash: self.signature_hash,
+            signatures: self.signatures.clone(),
+            payload: self.payload.clone(),
+            outcome,
+        }
+    }
+}
+
+/// One line of a [`JsonlAuditLog`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    /// Position in the log, starting at zero.
+    pub sequence: u64,
+    /// Keccak-256 of the previous line's bytes; zero for the first entry.
+    pub previous: B256,
+    /// The audited submission.
+    pub record: AuditRecord,
+}
+
+/// Append-only JSON Lines audit log.
+///
+/// Each line is an [`AuditLogEntry`] carrying the hash of the line before
+/// it, so editing, reordering or removing an entry breaks the chain at that
+/// point and [`JsonlAuditLog::verify`] reports it. Truncating the tail is
+/// only detectable against an externally kept head, see
+/// [`JsonlAuditLog::head`]. Every append is synced to disk before the
+/// submission's result is returned.
+pub struct JsonlAuditLog {
+    path: PathBuf,
+    tail: Mutex<LogTail>,
+}
+
+struct LogTail {
+    file: File,
+    sequence: u64,
+    previous: B256,
+}
+
+impl JsonlAuditLog {
+    /// Open the log at `path`, creating it if needed and continuing the
+    /// chain of an existing log.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self> {
+        let path = path.into();
+        let (sequence, previous) = match File::open(&path) {
+            Ok(file) => scan(&path, file, false)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => (0, B256::ZERO),
+            Err(e) => return Err(io_error("open", &path, e)),
+        };
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| io_error("open", &path, e))?;
+        Ok(Self {
+            path,
+            tail: Mutex::new(LogTail {
+                file,
+                sequence,
+                previous,
+            }),
+        })
+    }
+
+    /// The file the log appends to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of entries and the hash of the last line.
+    ///
+    /// Keeping this pair outside the log lets a later
+    /// [`verify`](Self::verify) detect a truncated tail.
+    pub fn head(&self) -> Result<(u64, B256)> {
+        let tail = self
+            .tail
+            .lock()
+            .map_err(|_| Error::storage("Audit log lock poisoned"))?;
+        Ok((tail.sequence, tail.previous))
+    }
+
+    /// Check the hash chain of the log at `path`.
+    ///
+    /// # Returns
+    ///
+    /// The number of entries and the hash of the last line, or
+    /// [`Error::Storage`] naming the first line that does not chain onto
+    /// its predecessor.
+    pub fn verify<P: AsRef<Path>>(path: P) -> Result<(u64, B256)> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| io_error("open", path, e))?;
+        scan(path, file, true)
+    }
+}
+
+impl AuditSink for JsonlAuditLog {
+    fn record(&self, record: &AuditRecord) -> Result<()> {
+        let mut tail = self
+            .tail
+            .lock()
+            .map_err(|_| Error::storage("Audit log lock poisoned"))?;
+        let entry = AuditLogEntry {
+            sequence: tail.sequence,
+            previous: tail.previous,
+            record: record.clone(),
+        };
+        let line = serde_json::to_string(&entry)?;
+        writeln!(tail.file, "{}", line).map_err(|e| io_error("append to", &self.path, e))?;
+        tail.file
+            .sync_data()
+            .map_err(|e| io_error("sync", &self.path, e))?;
+        tail.sequence += 1;
+        tail.previous = keccak256(line.as_bytes());
+        Ok(())
+    }
+}
+
+impl Debug for JsonlAuditLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("JsonlAuditLog")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// Walk the lines of a log, returning the next sequence number and the
+/// hash of the last line. With `check` set every entry must chain onto the
+/// one before it; otherwise only the last line is parsed.
+fn scan(path: &Path, file: File, check: bool) -> Result<(u64, B256)> {
+    let mut sequence = 0;
+    let mut previous = B256::ZERO;
+    let mut last = None;
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| io_error("read", path, e))?;
+        if check {
+            let entry = parse_entry(path, sequence, &line)?;
+            if entry.sequence != sequence || entry.previous != previous {
+                return Err(Error::storage(format!(
+                    "Audit log {} is broken at line {}: expected sequence {} after {}",
+                    path.display(),
+                    sequence + 1,
+                    sequence,
+                    previous
+                )));
+            }
+        }
+        sequence += 1;
+        previous = keccak256(line.as_bytes());
+        last = Some(line);
+    }
+    if let Some(line) = last {
+        let entry = parse_entry(path, sequence - 1, &line)?;
+        sequence = entry.sequence + 1;
+    }
+    Ok((sequence, previous))
+}
+
+fn parse_entry(path: &Path, index: u64, line: &str) -> Result<AuditLogEntry> {
+    serde_json::from_str(line).map_err(|e| {
+        Error::storage(format!(
+            "Audit log {} has an invalid entry at line {}: {}",
+            path.display(),
+            index + 1,
+            e
+        ))
+    })
+}
+
+fn io_error(operation: &str, path: &Path, error: std::io::Error) -> Error {
+    Error::storage(format!(
+        "Failed to {} audit log {}: {}",
+        operation,
+        path.display(),
+        error
+    ))
+}
+
+fn unix_now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PaymentPayload;
+    use alloy_primitives::{Address, U256};
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default();
+        env::temp_dir().join(format!(
+            "onemoney-audit-{}-{}-{}.jsonl",
+            name,
+            process::id(),
+            nanos
+        ))
+    }
+
+    fn sample_record(nonce: u64) -> AuditRecord {
+        let payload = PaymentPayload {
+            chain_id: 1212101,
+            nonce,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1000u64),
+            token: Address::repeat_byte(0x22),
+        };
+        SignedEnvelope::new(&payload, vec![Signature::default()])
+            .expect("Envelope should build")
+            .record(
+                "/v1/transactions/payment",
+                AuditOutcome::Accepted {
+                    hash: B256::repeat_byte(0x33),
+                },
+            )
+    }
+
+    #[test]
+    fn test_envelope_captures_signed_bytes() {
+        let record = sample_record(4);
+        assert_eq!(keccak256(&record.signed_bytes), record.signature_hash);
+        assert_eq!(record.payload["nonce"], 4);
+        assert_eq!(record.outcome.hash(), Some(B256::repeat_byte(0x33)));
+        assert_eq!(
+            PaymentPayload::from_rlp(&record.signed_bytes)
+                .expect("Signed bytes should decode")
+                .nonce,
+            4
+        );
+    }
+
+    #[test]
+    fn test_jsonl_audit_log_chains_across_reopen() {
+        let path = temp_path("chain");
+        let log = JsonlAuditLog::open(&path).expect("Log should open");
+        log.record(&sample_record(0)).expect("Record should append");
+        log.record(&sample_record(1)).expect("Record should append");
+        drop(log);
+
+        let log = JsonlAuditLog::open(&path).expect("Log should reopen");
+        log.record(&sample_record(2)).expect("Record should append");
+        let head = log.head().expect("Head should be readable");
+        assert_eq!(head.0, 3);
+        assert_eq!(JsonlAuditLog::verify(&path).ok(), Some(head));
+
+        let content = fs::read_to_string(&path).expect("Log should be readable");
+        let entries: Vec<AuditLogEntry> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("Entry should parse"))
+            .collect();
+        assert_eq!(entries[0].previous, B256::ZERO);
+        assert_eq!(entries[2].sequence, 2);
+        assert_eq!(entries[2].record.payload, sample_record(2).payload);
+
+        fs::remove_file(&path).expect("Cleanup should succeed");
+    }
+
+    #[test]
+    fn test_jsonl_audit_log_detects_tampering() {
+        let path = temp_path("tamper");
+        let log = JsonlAuditLog::open(&path).expect("Log should open");
+        for nonce in 0..3 {
+            log.record(&sample_record(nonce))
+                .expect("Record should append");
+        }
+        drop(log);
+
+        let content = fs::read_to_string(&path).expect("Log should be readable");
+        let tampered = content.replacen("\"nonce\":1", "\"nonce\":9", 1);
+        assert_ne!(tampered, content);
+        fs::write(&path, tampered).expect("Log should be writable");
+
+        let error = JsonlAuditLog::verify(&path).expect_err("Tampering should be detected");
+        assert!(error.to_string().contains("line 3"), "{}", error);
+
+        fs::remove_file(&path).expect("Cleanup should succeed");
+    }
+}
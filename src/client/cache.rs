@@ -0,0 +1,364 @@
+//! Response caching for immutable API data.
+
+use super::config::ApiVersion;
+use super::config::endpoints::checkpoints;
+use alloy_primitives::keccak256;
+use hex::encode as hex_encode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Default number of entries kept by [`MemoryCache`].
+pub const DEFAULT_CACHE_MAX_ENTRIES: usize = 1024;
+
+/// Default time-to-live for cached responses.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Endpoints cached by default: checkpoints addressed by hash.
+///
+/// Transactions and token metadata are left out: a pending transaction
+/// changes once it lands, and token authorities, lists and pause state change
+/// at any time, so verification reads must see them fresh. Opt in with
+/// [`ClientBuilder::cache_endpoint`](super::ClientBuilder::cache_endpoint).
+pub const DEFAULT_CACHED_ENDPOINTS: [&str; 1] = [checkpoints::BY_HASH];
+
+/// Storage backend for cached response bodies.
+///
+/// Implementations own expiry: [`CacheStore::get`] must not return entries
+/// whose time-to-live has elapsed.
+pub trait CacheStore: Send + Sync {
+    /// Look up a cached response body.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store a response body for at most `ttl`.
+    fn put(&self, key: &str, value: String, ttl: Duration);
+
+    /// Drop a cached entry.
+    fn remove(&self, key: &str);
+
+    /// Drop every cached entry.
+    fn clear(&self);
+}
+
+struct MemoryEntry {
+    value: String,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct MemoryState {
+    entries: HashMap<String, MemoryEntry>,
+    clock: u64,
+}
+
+/// In-memory least-recently-used cache.
+pub struct MemoryCache {
+    max_entries: usize,
+    state: Mutex<MemoryState>,
+}
+
+impl Debug for MemoryCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("MemoryCache")
+            .field("max_entries", &self.max_entries)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `max_entries` responses.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+
+    /// Number of entries currently held, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MemoryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for MemoryCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_MAX_ENTRIES)
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+
+        let expired = match state.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > Instant::now() => {
+                entry.last_used = clock;
+                return Some(entry.value.clone());
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if expired {
+            state.entries.remove(key);
+        }
+        None
+    }
+
+    fn put(&self, key: &str, value: String, ttl: Duration) {
+        if self.max_entries == 0 {
+            return;
+        }
+
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+
+        if !state.entries.contains_key(key) && state.entries.len() >= self.max_entries {
+            let now = Instant::now();
+            state.entries.retain(|_, entry| entry.expires_at > now);
+        }
+        while !state.entries.contains_key(key) && state.entries.len() >= self.max_entries {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            match oldest {
+                Some(oldest) => state.entries.remove(&oldest),
+                None => break,
+            };
+        }
+
+        state.entries.insert(
+            key.to_string(),
+            MemoryEntry {
+                value,
+                expires_at: Instant::now() + ttl,
+                last_used: clock,
+            },
+        );
+    }
+
+    fn remove(&self, key: &str) {
+        self.lock().entries.remove(key);
+    }
+
+    fn clear(&self) {
+        self.lock().entries.clear();
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    /// Unix timestamp in milliseconds after which the entry is stale.
+    expires_at: u64,
+    value: String,
+}
+
+/// Cache persisting each response as a JSON file in a directory.
+///
+/// Entries survive process restarts. I/O failures are treated as cache misses
+/// so a broken cache directory never fails an API call.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+impl DiskCache {
+    /// Create a disk cache rooted at `directory`, creating it if needed.
+    pub fn new<P: Into<PathBuf>>(directory: P) -> std::io::Result<Self> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory })
+    }
+
+    /// The directory holding the cache files.
+    pub fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{}.json", hex_encode(keccak256(key.as_bytes()))))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let path = self.entry_path(key);
+        let contents = fs::read_to_string(&path).ok()?;
+        let entry: DiskEntry = serde_json::from_str(&contents).ok()?;
+        if entry.expires_at > unix_millis() {
+            Some(entry.value)
+        } else {
+            let _ = fs::remove_file(path);
+            None
+        }
+    }
+
+    fn put(&self, key: &str, value: String, ttl: Duration) {
+        let ttl_millis = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        let entry = DiskEntry {
+            expires_at: unix_millis().saturating_add(ttl_millis),
+            value,
+        };
+        if let Ok(contents) = serde_json::to_string(&entry) {
+            let _ = fs::write(self.entry_path(key), contents);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = fs::remove_file(self.entry_path(key));
+    }
+
+    fn clear(&self) {
+        if let Ok(entries) = fs::read_dir(&self.directory) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().is_some_and(|ext| ext == "json") {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
+    }
+}
+
+/// Response cache attached to a client.
+pub(crate) struct ResponseCache {
+    pub(crate) store: Box<dyn CacheStore>,
+    pub(crate) ttl: Duration,
+    pub(crate) endpoints: Vec<String>,
+}
+
+impl ResponseCache {
+    /// Whether responses for `path` (including version prefix and query) may be cached.
+    pub(crate) fn is_cacheable(&self, path: &str) -> bool {
+        let endpoint = path.split('?').next().unwrap_or(path);
+        let endpoint = ApiVersion::strip(endpoint);
+        self.endpoints.iter().any(|cached| cached == endpoint)
+    }
+}
+
+impl Debug for ResponseCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl)
+            .field("endpoints", &self.endpoints)
+            .finish()
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_memory_cache_round_trip_and_expiry() {
+        let cache = MemoryCache::new(4);
+        cache.put("a", "1".to_string(), Duration::from_secs(60));
+        cache.put("b", "2".to_string(), Duration::ZERO);
+
+        assert_eq!(cache.get("a"), Some("1".to_string()));
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.len(), 1);
+
+        cache.remove("a");
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        let ttl = Duration::from_secs(60);
+        cache.put("a", "1".to_string(), ttl);
+        cache.put("b", "2".to_string(), ttl);
+
+        // Touch "a" so that "b" becomes the eviction candidate.
+        assert!(cache.get("a").is_some());
+        cache.put("c", "3".to_string(), ttl);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_memory_cache_zero_capacity_stores_nothing() {
+        let cache = MemoryCache::new(0);
+        cache.put("a", "1".to_string(), Duration::from_secs(60));
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn test_disk_cache_round_trip() {
+        let directory = env::temp_dir().join(format!(
+            "onemoney-disk-cache-test-{}-{}",
+            std::process::id(),
+            unix_millis()
+        ));
+        let cache = DiskCache::new(&directory).expect("Cache directory should be created");
+
+        cache.put("key", "value".to_string(), Duration::from_secs(60));
+        assert_eq!(cache.get("key"), Some("value".to_string()));
+
+        let reopened = DiskCache::new(&directory).expect("Cache directory should reopen");
+        assert_eq!(reopened.get("key"), Some("value".to_string()));
+
+        cache.put("stale", "old".to_string(), Duration::ZERO);
+        assert_eq!(cache.get("stale"), None);
+
+        cache.clear();
+        assert_eq!(cache.get("key"), None);
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn test_response_cache_endpoint_matching() {
+        let cache = ResponseCache {
+            store: Box::new(MemoryCache::default()),
+            ttl: DEFAULT_CACHE_TTL,
+            endpoints: DEFAULT_CACHED_ENDPOINTS
+                .iter()
+                .map(|endpoint| endpoint.to_string())
+                .collect(),
+        };
+
+        assert!(!cache.is_cacheable("/v1/transactions/by_hash?hash=0x01"));
+        assert!(!cache.is_cacheable("/v1/tokens/token_metadata?token=0x02"));
+        assert!(cache.is_cacheable("/v1/checkpoints/by_hash?hash=0x03&full=false"));
+        assert!(!cache.is_cacheable("/v1/accounts/nonce?address=0x04"));
+        assert!(!cache.is_cacheable("/v1/transactions/receipt/by_hash?hash=0x05"));
+        assert!(cache.is_cacheable("/v2/checkpoints/by_hash?hash=0x06"));
+    }
+}
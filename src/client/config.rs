@@ -1,6 +1,14 @@
 //! Network configuration and API endpoints.
 
-use std::{borrow::Cow, time::Duration};
+use crate::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+    time::Duration,
+};
+use url::Url;
 
 /// Default mainnet API URL.
 pub const MAINNET_URL: &str = "https://api.mainnet.1money.network";
@@ -29,12 +37,102 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 /// API version prefix.
 pub const API_VERSION: &str = "/v1";
 
+/// HTTP header carrying the idempotency key of a transaction submission.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Response header carrying the server-assigned request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Response header carrying the request quota of the current window.
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+
+/// Response header carrying the requests left in the current window.
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+
+/// Response header carrying the seconds until the current window resets.
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Response header carrying the version of the answering node.
+pub const SERVER_VERSION_HEADER: &str = "x-server-version";
+
+/// Response header marking the endpoint as deprecated.
+pub const DEPRECATION_HEADER: &str = "deprecation";
+
+/// Response header carrying the date a deprecated endpoint is removed.
+pub const SUNSET_HEADER: &str = "sunset";
+
 /// Build an API path with version prefix.
 pub fn api_path(path: &str) -> String {
     format!("{}{}", API_VERSION, path)
 }
 
+/// Version of the gateway API a client talks to.
+///
+/// Endpoint paths are written against [`API_VERSION`]; a client configured
+/// for another version rewrites the prefix before sending. Response types
+/// are shared across versions until a version changes a schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ApiVersion {
+    /// The `/v1` API.
+    #[default]
+    V1,
+    /// The `/v2` API.
+    V2,
+}
+
+impl ApiVersion {
+    /// Every known version, oldest first.
+    pub const ALL: [ApiVersion; 2] = [ApiVersion::V1, ApiVersion::V2];
+
+    /// Path prefix of this version.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            ApiVersion::V1 => API_VERSION,
+            ApiVersion::V2 => "/v2",
+        }
+    }
+
+    /// Rewrite a path built with [`api_path`] for this version.
+    ///
+    /// Paths without the default prefix are returned unchanged.
+    pub fn rewrite<'a>(self, path: &'a str) -> Cow<'a, str> {
+        match ApiVersion::V1.strip_from(path) {
+            Some(rest) if self != ApiVersion::V1 => {
+                Cow::Owned(format!("{}{}", self.prefix(), rest))
+            }
+            _ => Cow::Borrowed(path),
+        }
+    }
+
+    /// Strip a known version prefix from `path`.
+    pub fn strip(path: &str) -> &str {
+        Self::ALL
+            .iter()
+            .find_map(|version| version.strip_from(path))
+            .unwrap_or(path)
+    }
+
+    /// The rest of `path` after this version's prefix, if it has one.
+    fn strip_from(self, path: &str) -> Option<&str> {
+        path.strip_prefix(self.prefix())
+            .filter(|rest| rest.is_empty() || rest.starts_with(['/', '?']))
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.prefix().trim_start_matches('/'))
+    }
+}
+
+/// Name given to custom networks created from a URL alone.
+pub const CUSTOM_NETWORK_NAME: &str = "custom";
+
 /// Network environment options.
+///
+/// Networks parse from `mainnet`, `testnet` or `local`, or from an `http`
+/// or `https` URL, which becomes a custom network. They serialize to those
+/// names, and custom networks to an object with their fields.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Network {
     /// Mainnet environment.
@@ -44,27 +142,65 @@ pub enum Network {
     Testnet,
     /// Local development environment.
     Local,
-    /// Custom network environment.
-    Custom(Cow<'static, str>),
+    /// Any other deployment, such as a staging gateway.
+    Custom {
+        /// Name shown for the network, such as `staging`.
+        name: Cow<'static, str>,
+        /// Base URL of the gateway.
+        base_url: Cow<'static, str>,
+        /// Chain ID of the network, when known ahead of time.
+        ///
+        /// Clients check payloads against it as they do for mainnet and
+        /// testnet.
+        chain_id: Option<u64>,
+    },
 }
 
 impl Network {
+    /// A custom network reached at `base_url`, with no name or chain ID.
+    pub fn custom<U: Into<Cow<'static, str>>>(base_url: U) -> Self {
+        Network::Custom {
+            name: Cow::Borrowed(CUSTOM_NETWORK_NAME),
+            base_url: base_url.into(),
+            chain_id: None,
+        }
+    }
+
     /// Get the base URL for this network.
     pub fn url(&self) -> &str {
         match self {
             Network::Mainnet => MAINNET_URL,
             Network::Testnet => TESTNET_URL,
             Network::Local => LOCAL_URL,
-            Network::Custom(s) => s,
+            Network::Custom { base_url, .. } => base_url,
         }
     }
 
-    pub const fn predefined_chain_id(&self) -> u64 {
+    /// Name of the network: `mainnet`, `testnet`, `local` or a custom
+    /// network's name.
+    pub fn name(&self) -> &str {
         match self {
-            Network::Mainnet => MAINNET_CHAIN_ID,
-            Network::Testnet => TESTNET_CHAIN_ID,
-            Network::Local => LOCAL_CHAIN_ID,
-            Network::Custom(_) => panic!(
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+            Network::Local => "local",
+            Network::Custom { name, .. } => name,
+        }
+    }
+
+    /// The network's chain ID, if it is known without asking the network.
+    pub const fn chain_id(&self) -> Option<u64> {
+        match self {
+            Network::Mainnet => Some(MAINNET_CHAIN_ID),
+            Network::Testnet => Some(TESTNET_CHAIN_ID),
+            Network::Local => Some(LOCAL_CHAIN_ID),
+            Network::Custom { chain_id, .. } => *chain_id,
+        }
+    }
+
+    pub const fn predefined_chain_id(&self) -> u64 {
+        match self.chain_id() {
+            Some(chain_id) => chain_id,
+            None => panic!(
                 "Custom network does not have a predefined chain ID. Must fetch from network instead."
             ),
         }
@@ -81,6 +217,93 @@ impl Network {
     }
 }
 
+impl Display for Network {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Network::Custom { name, base_url, .. } => write!(f, "{} ({})", name, base_url),
+            other => f.write_str(other.name()),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" => Ok(Network::Mainnet),
+            "testnet" => Ok(Network::Testnet),
+            "local" => Ok(Network::Local),
+            lower if lower.starts_with("http://") || lower.starts_with("https://") => {
+                Url::parse(s).map_err(|e| {
+                    Error::invalid_parameter("network", format!("Invalid URL {}: {}", s, e))
+                })?;
+                Ok(Network::custom(s.to_string()))
+            }
+            _ => Err(Error::invalid_parameter(
+                "network",
+                format!(
+                    "Unknown network {:?}; expected mainnet, testnet, local or a URL",
+                    s
+                ),
+            )),
+        }
+    }
+}
+
+/// Serialized form of a custom network.
+#[derive(Serialize, Deserialize)]
+struct CustomNetwork<'a> {
+    #[serde(default = "default_custom_name")]
+    name: Cow<'a, str>,
+    base_url: Cow<'a, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    chain_id: Option<u64>,
+}
+
+fn default_custom_name() -> Cow<'static, str> {
+    Cow::Borrowed(CUSTOM_NETWORK_NAME)
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NetworkRepr {
+    Named(String),
+    Custom(CustomNetwork<'static>),
+}
+
+impl Serialize for Network {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Network::Custom {
+                name,
+                base_url,
+                chain_id,
+            } => CustomNetwork {
+                name: Cow::Borrowed(name),
+                base_url: Cow::Borrowed(base_url),
+                chain_id: *chain_id,
+            }
+            .serialize(serializer),
+            other => serializer.serialize_str(other.name()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Network {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match NetworkRepr::deserialize(deserializer)? {
+            NetworkRepr::Named(name) => name.parse().map_err(serde::de::Error::custom),
+            NetworkRepr::Custom(custom) => Ok(Network::Custom {
+                name: Cow::Owned(custom.name.into_owned()),
+                base_url: Cow::Owned(custom.base_url.into_owned()),
+                chain_id: custom.chain_id,
+            }),
+        }
+    }
+}
+
 /// API endpoint paths.
 pub mod endpoints {
     /// Account-related endpoints.
@@ -176,6 +399,23 @@ mod tests {
         assert_eq!(path_without_slash, "/v1chains/chain_id");
     }
 
+    #[test]
+    fn test_api_version_rewrite() {
+        let path = api_path("/accounts/nonce?address=0x01");
+        assert_eq!(ApiVersion::default(), ApiVersion::V1);
+        assert_eq!(ApiVersion::V1.rewrite(&path), path);
+        assert_eq!(
+            ApiVersion::V2.rewrite(&path),
+            "/v2/accounts/nonce?address=0x01"
+        );
+        assert_eq!(ApiVersion::V2.rewrite("/health"), "/health");
+        assert_eq!(ApiVersion::V2.rewrite("/v10/accounts"), "/v10/accounts");
+
+        assert_eq!(ApiVersion::strip("/v2/accounts/nonce"), "/accounts/nonce");
+        assert_eq!(ApiVersion::strip("/v1/accounts/nonce"), "/accounts/nonce");
+        assert_eq!(ApiVersion::V2.to_string(), "v2");
+    }
+
     #[test]
     fn test_endpoint_constants() {
         // Test account endpoints
@@ -252,10 +492,41 @@ mod tests {
     #[test]
     #[should_panic(expected = "Custom network does not have a predefined chain ID")]
     fn test_predefined_chain_id_panics_for_custom() {
-        let n = Network::Custom("http://localhost:18555".into());
+        let n = Network::custom("http://localhost:18555");
         let _ = n.predefined_chain_id();
     }
 
+    #[test]
+    fn test_network_parsing_and_serde() {
+        assert_eq!("Testnet".parse::<Network>().ok(), Some(Network::Testnet));
+        assert_eq!(
+            "https://staging.example.com".parse::<Network>().ok(),
+            Some(Network::custom("https://staging.example.com"))
+        );
+        assert!("staging".parse::<Network>().is_err());
+        assert!("http://".parse::<Network>().is_err());
+
+        let staging = Network::Custom {
+            name: "staging".into(),
+            base_url: "https://staging.example.com".into(),
+            chain_id: Some(7),
+        };
+        assert_eq!(staging.chain_id(), Some(7));
+        assert_eq!(staging.to_string(), "staging (https://staging.example.com)");
+
+        let json = serde_json::to_string(&vec![Network::Mainnet, staging.clone()])
+            .expect("Networks should serialize");
+        assert_eq!(
+            json,
+            r#"["mainnet",{"name":"staging","base_url":"https://staging.example.com","chain_id":7}]"#
+        );
+        let decoded: Vec<Network> = serde_json::from_str(&json).expect("Networks should parse");
+        assert_eq!(decoded, vec![Network::Mainnet, staging]);
+        let bare: Network = serde_json::from_str(r#"{"base_url":"http://127.0.0.1:9000"}"#)
+            .expect("Name should default");
+        assert_eq!(bare, Network::custom("http://127.0.0.1:9000"));
+    }
+
     #[test]
     fn test_constants() {
         assert_eq!(API_VERSION, "/v1");
@@ -0,0 +1,164 @@
+//! Round trip and clock skew against the node.
+//!
+//! The L1 has no clock endpoint, so the `Date` header of the response stands
+//! in for the server's clock. It has one-second resolution, which is precise
+//! enough to spot a host whose clock has drifted far enough to make
+//! submissions look stale.
+
+use super::config::{api_path, endpoints::checkpoints::NUMBER};
+use super::http::Client;
+use super::warnings::Warning;
+use crate::Result;
+use crate::responses::CheckpointNumber;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Default clock skew past which [`Client::health_check`] warns.
+pub const DEFAULT_MAX_CLOCK_SKEW: Duration = Duration::from_secs(10);
+
+/// Outcome of a [`Client::health_check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientHealth {
+    /// Latest checkpoint number at the time of the check.
+    pub checkpoint: u64,
+    /// Time the checkpoint request took.
+    pub round_trip: Duration,
+    /// Server clock from the response's `Date` header minus the local clock
+    /// at the middle of the request, in milliseconds: positive when the
+    /// server is ahead. `None` when the response carried no `Date` header.
+    pub skew_ms: Option<i64>,
+    /// Skew past which the client warns, from
+    /// [`ClientBuilder::max_clock_skew`](crate::ClientBuilder::max_clock_skew).
+    pub max_skew: Duration,
+}
+
+impl ClientHealth {
+    /// Size of the skew, whichever clock is ahead.
+    pub fn skew(&self) -> Option<Duration> {
+        self.skew_ms
+            .map(|skew_ms| Duration::from_millis(skew_ms.unsigned_abs()))
+    }
+
+    /// Whether the skew is known and exceeds the client's limit.
+    pub fn is_skewed(&self) -> bool {
+        self.skew().is_some_and(|skew| skew > self.max_skew)
+    }
+}
+
+impl Display for ClientHealth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Checkpoint #{}, round trip {:?}, ",
+            self.checkpoint, self.round_trip
+        )?;
+        match self.skew_ms {
+            Some(skew_ms) => write!(f, "clock skew {:+}ms", skew_ms)?,
+            None => write!(f, "clock skew unknown")?,
+        }
+        if self.is_skewed() {
+            write!(f, " (over the {:?} limit)", self.max_skew)?;
+        }
+        Ok(())
+    }
+}
+
+impl Client {
+    /// Measure the round trip to the node and the skew between its clock
+    /// and the local one.
+    ///
+    /// When the skew exceeds the client's limit, every hook's
+    /// [`Hook::on_clock_skew`](crate::client::Hook::on_clock_skew) is called
+    /// and a [`Warning::ClockSkew`] is raised before the result is returned;
+    /// a skewed clock is reported, not treated as an error.
+    ///
+    /// # Returns
+    ///
+    /// The latest checkpoint number, the round trip and the clock skew.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///
+    ///     let health = client.health_check().await?;
+    ///     if let Some(skew) = health.skew().filter(|_| health.is_skewed()) {
+    ///         eprintln!("Clock is off by {:?}; check NTP", skew);
+    ///     }
+    ///     println!("{}", health);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn health_check(&self) -> Result<ClientHealth> {
+        let sent_at = SystemTime::now();
+        let started = Instant::now();
+        let (latest, date) = self
+            .get_dated::<CheckpointNumber>(&api_path(NUMBER))
+            .await?;
+        let round_trip = started.elapsed();
+
+        let unix_ms = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_millis() as i64)
+        };
+        let local_ms = unix_ms(sent_at + round_trip / 2);
+        // The header is truncated to the second, so its middle is the best
+        // estimate of the server's clock.
+        let skew_ms = date.map(|date| (unix_ms(date) + 500).saturating_sub(local_ms));
+
+        let health = ClientHealth {
+            checkpoint: latest.number,
+            round_trip,
+            skew_ms,
+            max_skew: self.inner.max_clock_skew,
+        };
+        if let Some(skew_ms) = health.skew_ms.filter(|_| health.is_skewed()) {
+            self.emit_clock_skew(&health);
+            self.emit_warning(Warning::ClockSkew {
+                skew_ms,
+                max_skew: health.max_skew,
+            });
+        }
+        Ok(health)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_health_skew() {
+        let health = ClientHealth {
+            checkpoint: 42,
+            round_trip: Duration::from_millis(80),
+            skew_ms: Some(-12_500),
+            max_skew: DEFAULT_MAX_CLOCK_SKEW,
+        };
+        assert_eq!(health.skew(), Some(Duration::from_millis(12_500)));
+        assert!(health.is_skewed());
+        assert_eq!(
+            health.to_string(),
+            "Checkpoint #42, round trip 80ms, clock skew -12500ms (over the 10s limit)"
+        );
+
+        let healthy = ClientHealth {
+            skew_ms: Some(400),
+            ..health
+        };
+        assert!(!healthy.is_skewed());
+        assert!(healthy.to_string().ends_with("clock skew +400ms"));
+
+        let undated = ClientHealth {
+            skew_ms: None,
+            ..health
+        };
+        assert!(!undated.is_skewed());
+        assert!(undated.to_string().ends_with("clock skew unknown"));
+    }
+}
@@ -1,6 +1,16 @@
 //! Hook and logging system for request/response middleware.
 
+use super::config::{
+    DEPRECATION_HEADER, RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER,
+    RATE_LIMIT_RESET_HEADER, REQUEST_ID_HEADER, SERVER_VERSION_HEADER, SUNSET_HEADER,
+};
+use super::health::ClientHealth;
+use super::recovery::RecoveryEvent;
+use super::warnings::Warning;
+use reqwest::header::{DATE, HeaderMap};
+use serde_json::Value;
 use std::str;
+use std::time::SystemTime;
 
 /// Type alias for redaction callback function.
 /// Takes the original body and returns a redacted version.
@@ -13,6 +23,78 @@ pub trait Hook: Send + Sync {
 
     /// Called after receiving a response.
     fn after_response(&self, method: &str, url: &str, status: u16, body: Option<&str>);
+
+    /// Called when the client repairs a rejected submission and retries it.
+    fn on_recovery(&self, _event: &RecoveryEvent) {}
+
+    /// Called with the operational headers of every response received,
+    /// before its body is read.
+    fn on_response_meta(&self, _method: &str, _url: &str, _meta: &ResponseMeta) {}
+
+    /// Called when [`Client::health_check`](crate::Client::health_check)
+    /// finds the local clock further from the node's than the client allows.
+    fn on_clock_skew(&self, _health: &ClientHealth) {}
+
+    /// Called for every non-fatal condition the client notices, such as a
+    /// deprecated endpoint or response fields the SDK does not know.
+    fn on_warning(&self, _warning: &Warning) {}
+}
+
+/// Operational headers of a response, for monitoring request quotas and
+/// correlating requests with server logs.
+///
+/// Headers the server did not send, or sent in an unexpected format, are
+/// `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status code.
+    pub status: u16,
+    /// Server-assigned request ID, from `x-request-id`.
+    pub request_id: Option<String>,
+    /// Requests allowed in the current window, from `x-ratelimit-limit`.
+    pub rate_limit_limit: Option<u64>,
+    /// Requests left in the current window, from `x-ratelimit-remaining`.
+    pub rate_limit_remaining: Option<u64>,
+    /// Seconds until the window resets, from `x-ratelimit-reset`.
+    pub rate_limit_reset: Option<u64>,
+    /// Version of the answering node, from `x-server-version`.
+    pub server_version: Option<String>,
+    /// Deprecation marker of the endpoint, from `deprecation`.
+    pub deprecation: Option<String>,
+    /// Removal date of a deprecated endpoint, from `sunset`.
+    pub sunset: Option<String>,
+    /// The server's clock when it answered, from `date`, to the second.
+    pub date: Option<SystemTime>,
+}
+
+impl ResponseMeta {
+    /// Read the operational headers out of a response's headers.
+    pub fn from_headers(status: u16, headers: &HeaderMap) -> Self {
+        let text = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+        };
+        let number = |name: &str| text(name).and_then(|value| value.parse().ok());
+        Self {
+            status,
+            request_id: text(REQUEST_ID_HEADER),
+            rate_limit_limit: number(RATE_LIMIT_LIMIT_HEADER),
+            rate_limit_remaining: number(RATE_LIMIT_REMAINING_HEADER),
+            rate_limit_reset: number(RATE_LIMIT_RESET_HEADER),
+            server_version: text(SERVER_VERSION_HEADER),
+            deprecation: text(DEPRECATION_HEADER),
+            sunset: text(SUNSET_HEADER),
+            date: response_date(headers),
+        }
+    }
+}
+
+/// The `date` header of a response, if it holds a valid HTTP date.
+pub(crate) fn response_date(headers: &HeaderMap) -> Option<SystemTime> {
+    let value = headers.get(DATE)?.to_str().ok()?;
+    httpdate::parse_http_date(value.trim()).ok()
 }
 
 /// Logger trait for pluggable logging.
@@ -46,52 +128,202 @@ impl Logger for ConsoleLogger {
     }
 }
 
+/// Number of characters of a body logged under the default [`LogPolicy`].
+pub const DEFAULT_MAX_LOGGED_BODY_CHARS: usize = 100;
+
+/// Placeholder written in place of a masked value.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Fields masked by the default [`LogPolicy`]: signature components and
+/// token metadata values.
+pub const DEFAULT_MASKED_FIELDS: &[&str] = &[
+    "signature.r",
+    "signature.s",
+    "signatures.*.r",
+    "signatures.*.s",
+    "additional_metadata.*.value",
+    "private_key",
+];
+
+/// What a [`LoggingHook`] strips from bodies before logging them.
+///
+/// Masks are dot-separated paths of JSON keys; `*` stands for any key or
+/// array index. A mask matches wherever its path ends a value's path, so
+/// `signature.r` masks the `r` of every `signature` object, however deeply
+/// it is nested. Masked values are replaced with [`REDACTED`]. Bodies that
+/// are not JSON are only truncated.
+///
+/// The default masks [`DEFAULT_MASKED_FIELDS`] and truncates bodies to
+/// [`DEFAULT_MAX_LOGGED_BODY_CHARS`] characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogPolicy {
+    masks: Vec<Vec<String>>,
+    max_body_chars: Option<usize>,
+}
+
+impl LogPolicy {
+    /// The default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that logs bodies in full, masking nothing.
+    pub fn unredacted() -> Self {
+        Self {
+            masks: Vec::new(),
+            max_body_chars: None,
+        }
+    }
+
+    /// Also mask the values at `path`.
+    pub fn mask_field(mut self, path: &str) -> Self {
+        let segments = split_path(path);
+        if !self.masks.contains(&segments) {
+            self.masks.push(segments);
+        }
+        self
+    }
+
+    /// Stop masking `path`, if it was masked.
+    pub fn unmask_field(mut self, path: &str) -> Self {
+        let segments = split_path(path);
+        self.masks.retain(|mask| *mask != segments);
+        self
+    }
+
+    /// Truncate bodies to `max` characters, or not at all with `None`.
+    pub fn max_body_chars(mut self, max: Option<usize>) -> Self {
+        self.max_body_chars = max;
+        self
+    }
+
+    /// Mask `body`, leaving it unchanged unless a mask matches.
+    pub fn redact(&self, body: &str) -> String {
+        if self.masks.is_empty() {
+            return body.to_string();
+        }
+        let Ok(mut value) = serde_json::from_str::<Value>(body) else {
+            return body.to_string();
+        };
+        let mut path = Vec::new();
+        if self.mask_value(&mut value, &mut path) {
+            value.to_string()
+        } else {
+            body.to_string()
+        }
+    }
+
+    /// Cut `body` to the policy's length, marking a cut with `...`.
+    ///
+    /// Counts characters rather than bytes, so multi-byte characters are
+    /// never split.
+    pub fn truncate(&self, body: String) -> String {
+        match self.max_body_chars {
+            Some(max) if body.chars().count() > max => {
+                format!("{}...", body.chars().take(max).collect::<String>())
+            }
+            _ => body,
+        }
+    }
+
+    fn mask_value(&self, value: &mut Value, path: &mut Vec<String>) -> bool {
+        if self.is_masked(path) {
+            *value = Value::String(REDACTED.to_string());
+            return true;
+        }
+        let mut masked = false;
+        match value {
+            Value::Object(fields) => {
+                for (key, field) in fields.iter_mut() {
+                    path.push(key.clone());
+                    masked |= self.mask_value(field, path);
+                    path.pop();
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    path.push(index.to_string());
+                    masked |= self.mask_value(item, path);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+        masked
+    }
+
+    fn is_masked(&self, path: &[String]) -> bool {
+        self.masks.iter().any(|mask| {
+            mask.len() <= path.len()
+                && mask
+                    .iter()
+                    .zip(&path[path.len() - mask.len()..])
+                    .all(|(segment, key)| segment == "*" || segment == key)
+        })
+    }
+}
+
+impl Default for LogPolicy {
+    fn default() -> Self {
+        DEFAULT_MASKED_FIELDS
+            .iter()
+            .fold(Self::unredacted(), |policy, path| policy.mask_field(path))
+            .max_body_chars(Some(DEFAULT_MAX_LOGGED_BODY_CHARS))
+    }
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split('.').map(str::to_string).collect()
+}
+
 /// Simple request/response logging hook.
+///
+/// Bodies are passed through the hook's [`LogPolicy`], then its redaction
+/// callback if it has one, and truncated last.
 pub struct LoggingHook {
     logger: Box<dyn Logger>,
+    policy: LogPolicy,
     redaction_callback: Option<RedactionCallback>,
 }
 
 impl LoggingHook {
+    /// Create a LoggingHook applying the default [`LogPolicy`].
     pub fn new(logger: Box<dyn Logger>) -> Self {
+        Self::with_policy(logger, LogPolicy::default())
+    }
+
+    /// Create a LoggingHook applying `policy`.
+    pub fn with_policy(logger: Box<dyn Logger>, policy: LogPolicy) -> Self {
         Self {
             logger,
+            policy,
             redaction_callback: None,
         }
     }
 
-    /// Create a new LoggingHook with a redaction callback.
+    /// Create a new LoggingHook with a redaction callback, applied after
+    /// the default [`LogPolicy`].
     pub fn with_redaction(logger: Box<dyn Logger>, redaction_callback: RedactionCallback) -> Self {
         Self {
-            logger,
             redaction_callback: Some(redaction_callback),
+            ..Self::new(logger)
         }
     }
 
     /// Create a safe preview of the body content for logging.
-    /// Applies redaction if configured, then truncates to 100 characters.
+    /// Applies the policy's masks and the redaction callback, then truncates.
     fn create_safe_preview(&self, body: &str) -> String {
         if body.is_empty() {
             return String::new();
         }
 
-        // Apply redaction callback if provided
+        let masked = self.policy.redact(body);
         let processed_body = if let Some(ref redactor) = self.redaction_callback {
-            redactor(body)
+            redactor(&masked)
         } else {
-            body.to_string()
+            masked
         };
-
-        // Create safe preview - first 100 characters with ellipsis if truncated
-        // Use character-aware truncation to avoid panics on UTF-8 boundaries
-        if processed_body.chars().count() <= 100 {
-            processed_body
-        } else {
-            format!(
-                "{}...",
-                processed_body.chars().take(100).collect::<String>()
-            )
-        }
+        self.policy.truncate(processed_body)
     }
 }
 
@@ -179,6 +411,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_response_meta_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, "req-42".parse().expect("Valid header"));
+        headers.insert(
+            RATE_LIMIT_LIMIT_HEADER,
+            "100".parse().expect("Valid header"),
+        );
+        headers.insert(
+            RATE_LIMIT_REMAINING_HEADER,
+            "7".parse().expect("Valid header"),
+        );
+        headers.insert(
+            RATE_LIMIT_RESET_HEADER,
+            "soon".parse().expect("Valid header"),
+        );
+        headers.insert(
+            DATE,
+            "Sun, 06 Nov 1994 08:49:37 GMT"
+                .parse()
+                .expect("Valid header"),
+        );
+
+        let meta = ResponseMeta::from_headers(200, &headers);
+        assert_eq!(meta.request_id.as_deref(), Some("req-42"));
+        assert_eq!(meta.rate_limit_limit, Some(100));
+        assert_eq!(meta.rate_limit_remaining, Some(7));
+        assert_eq!(meta.rate_limit_reset, None);
+        assert_eq!(meta.server_version, None);
+        assert_eq!(meta.deprecation, None);
+        assert_eq!(
+            meta.date,
+            Some(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(784_111_777))
+        );
+    }
+
     #[test]
     fn test_safe_preview_short_body() {
         let logger = Box::new(TestLogger::new());
@@ -230,6 +498,49 @@ mod tests {
         assert!(preview.contains("***REDACTED***"));
     }
 
+    #[test]
+    fn test_default_policy_masks_signatures_and_metadata() {
+        let policy = LogPolicy::default().max_body_chars(None);
+        let body = r#"{"nonce": 3, "signature": {"r": "0xaaaa", "s": "0xbbbb", "v": 1},
+            "additional_metadata": [{"key": "issuer", "value": "0xcccc"}]}"#;
+        let redacted = policy.redact(body);
+        for secret in ["0xaaaa", "0xbbbb", "0xcccc"] {
+            assert!(!redacted.contains(secret));
+        }
+        assert!(redacted.contains(r#""key":"issuer""#));
+        assert!(redacted.contains(r#""v":1"#));
+        assert!(redacted.contains(REDACTED));
+
+        let multisig = r#"{"signatures": [{"r": "0xaaaa", "s": "0xbbbb", "v": 0}]}"#;
+        assert!(!policy.redact(multisig).contains("0xaaaa"));
+
+        // Bodies without masked fields are logged verbatim.
+        let plain = r#"{"nonce": 3,  "r": "0xaaaa"}"#;
+        assert_eq!(policy.redact(plain), plain);
+        assert_eq!(policy.redact("not json"), "not json");
+    }
+
+    #[test]
+    fn test_custom_log_policy() {
+        let logger = Arc::new(TestLogger::new());
+        let policy = LogPolicy::unredacted().mask_field("account.*");
+        let hook = LoggingHook::with_policy(Box::new(logger.clone()), policy.clone());
+
+        let body = format!(
+            r#"{{"account": {{"balance": "42"}}, "signature": {{"r": "0xaaaa"}}, "pad": "{}"}}"#,
+            "x".repeat(200)
+        );
+        hook.after_response("GET", "https://api.example.com", 200, Some(&body));
+        let messages = logger.get_messages();
+        assert!(!messages[0].1.contains("42"));
+        assert!(messages[0].1.contains("0xaaaa"));
+        assert!(messages[0].1.contains(&"x".repeat(200)));
+
+        let policy = policy.unmask_field("account.*").max_body_chars(Some(10));
+        assert_eq!(policy.redact(&body), body);
+        assert_eq!(policy.truncate(body).chars().count(), 13);
+    }
+
     #[test]
     fn test_before_request_with_empty_body() {
         let logger = Arc::new(TestLogger::new());
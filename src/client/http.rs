@@ -1,28 +1,180 @@
 //! HTTP client implementation.
 
-use super::{builder::ClientBuilder, config::Network, hooks::Hook};
-use crate::{Error, Result, error::ErrorResponse};
-use reqwest::{Client as HttpClient, header};
+use super::{
+    audit::{AuditOutcome, AuditSink, SignedEnvelope},
+    builder::ClientBuilder,
+    cache::ResponseCache,
+    config::{ApiVersion, IDEMPOTENCY_KEY_HEADER, Network, REQUEST_ID_HEADER},
+    health::{ClientHealth, DEFAULT_MAX_CLOCK_SKEW},
+    hooks::{Hook, ResponseMeta, response_date},
+    nonces::NonceManager,
+    recovery::{RecoveryEvent, RecoveryPolicy},
+    state::StateTracker,
+    strict::{decode_error, from_str_strict, from_str_tracked},
+    token_info::TokenInfoCache,
+    warnings::{self, Warning},
+};
+use crate::crypto::sign_transaction_payload;
+use crate::registry::{AddressBook, TokenRegistry};
+use crate::requests::TransactionPayload;
+use crate::responses::TransactionResponse;
+use crate::transport::{
+    AuthScheme, BucketState, CircuitBreaker, CircuitState, EndpointClass, EndpointSelector,
+    EndpointStats, HttpTransport, RateLimiter, RetryBudgetTracker, RetryPolicy, TimeoutProfile,
+};
+use crate::{
+    DEFAULT_FINALITY_DEPTH, Error, Result, Signature,
+    error::{ErrorResponse, RequestContext},
+};
+use alloy_primitives::B256;
+use reqwest::{Client as HttpClient, RequestBuilder, header};
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json;
 use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::future::Future;
+use std::result::Result as StdResult;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::time::sleep;
 use url::Url;
 
+#[cfg(feature = "tokens")]
+use super::metadata::MetadataStore;
+#[cfg(feature = "checkpoints")]
+use super::stream::{ArrayScanner, JsonArrayStream};
+#[cfg(feature = "gzip")]
+use crate::transport::compression::gzip;
+
 /// OneMoney API client.
+///
+/// The client is a handle to shared state: cloning it is cheap and every
+/// clone uses the same connection pool, response cache, rate limiter, chain
+/// state and token registry. `Client` is `Send + Sync`, so one instance can
+/// be stored in application state and cloned into each task or request
+/// handler instead of building a client per task.
+#[derive(Clone)]
 pub struct Client {
+    pub(crate) inner: Arc<ClientInner>,
+    timeout: Option<Duration>,
+}
+
+/// Configuration and state shared by all clones of a [`Client`].
+pub(crate) struct ClientInner {
     pub(crate) base_url: Url,
     pub(crate) network: Network,
     http_client: HttpClient,
+    pub(crate) transport: Arc<dyn HttpTransport>,
     hooks: Vec<Box<dyn Hook>>,
+    pub(crate) idempotency_keys: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) strict_responses: bool,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) endpoints: Option<EndpointSelector>,
+    pub(crate) max_response_bytes: Option<usize>,
+    pub(crate) expected_chain_id: Option<u64>,
+    pub(crate) finality_depth: u64,
+    pub(crate) state: StateTracker,
+    pub(crate) nonces: NonceManager,
+    pub(crate) tokens: Mutex<TokenRegistry>,
+    pub(crate) token_info: TokenInfoCache,
+    pub(crate) max_clock_skew: Duration,
+    pub(crate) address_book: AddressBook,
+    pub(crate) retry: Option<RetryPolicy>,
+    pub(crate) retry_budget: Option<RetryBudgetTracker>,
+    pub(crate) circuit: Option<CircuitBreaker>,
+    pub(crate) error_context: bool,
+    pub(crate) auth: Option<AuthScheme>,
+    pub(crate) api_version: ApiVersion,
+    pub(crate) audit: Option<Box<dyn AuditSink>>,
+    pub(crate) timeouts: TimeoutProfile,
+    pub(crate) recovery: Option<RecoveryPolicy>,
+    #[cfg(feature = "tokens")]
+    pub(crate) metadata_store: Option<Box<dyn MetadataStore>>,
+    #[cfg(feature = "gzip")]
+    pub(crate) compress_requests_over: Option<usize>,
 }
 
 impl Debug for Client {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_struct("Client")
-            .field("base_url", &self.base_url)
-            .field("network", &self.network)
-            .field("hooks_count", &self.hooks.len())
-            .finish()
+        let inner = &self.inner;
+        let mut debug = f.debug_struct("Client");
+        debug
+            .field("base_url", &inner.base_url)
+            .field("network", &inner.network)
+            .field("hooks_count", &inner.hooks.len())
+            .field("idempotency_keys", &inner.idempotency_keys)
+            .field("dry_run", &inner.dry_run)
+            .field("strict_responses", &inner.strict_responses)
+            .field("cache", &inner.cache)
+            .field("rate_limiter", &inner.rate_limiter)
+            .field("endpoints", &inner.endpoints)
+            .field("max_response_bytes", &inner.max_response_bytes)
+            .field("expected_chain_id", &inner.expected_chain_id)
+            .field("finality_depth", &inner.finality_depth)
+            .field("state", &inner.state)
+            .field("nonces", &inner.nonces)
+            .field("tokens", &inner.tokens)
+            .field("token_info", &inner.token_info)
+            .field("max_clock_skew", &inner.max_clock_skew)
+            .field("address_book", &inner.address_book.len())
+            .field("retry", &inner.retry)
+            .field("retry_budget", &inner.retry_budget)
+            .field("circuit", &inner.circuit)
+            .field("error_context", &inner.error_context)
+            .field("auth", &inner.auth)
+            .field("api_version", &inner.api_version)
+            .field("audit", &inner.audit.is_some())
+            .field("timeouts", &inner.timeouts)
+            .field("recovery", &inner.recovery);
+        #[cfg(feature = "tokens")]
+        debug.field("metadata_store", &inner.metadata_store.is_some());
+        debug.field("timeout_override", &self.timeout).finish()
+    }
+}
+
+impl ClientInner {
+    /// Create the shared state for a client with default settings.
+    pub(crate) fn new(
+        network: Network,
+        http_client: HttpClient,
+        hooks: Vec<Box<dyn Hook>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            base_url: Url::parse(network.url())?,
+            network,
+            transport: Arc::new(http_client.clone()),
+            http_client,
+            hooks,
+            idempotency_keys: false,
+            dry_run: false,
+            strict_responses: false,
+            cache: None,
+            rate_limiter: None,
+            endpoints: None,
+            max_response_bytes: None,
+            expected_chain_id: None,
+            finality_depth: DEFAULT_FINALITY_DEPTH,
+            state: StateTracker::default(),
+            nonces: NonceManager::default(),
+            tokens: Mutex::new(TokenRegistry::new()),
+            token_info: TokenInfoCache::default(),
+            max_clock_skew: DEFAULT_MAX_CLOCK_SKEW,
+            address_book: AddressBook::new(),
+            retry: None,
+            retry_budget: None,
+            circuit: None,
+            error_context: false,
+            auth: None,
+            api_version: ApiVersion::default(),
+            audit: None,
+            timeouts: TimeoutProfile::default(),
+            recovery: None,
+            #[cfg(feature = "tokens")]
+            metadata_store: None,
+            #[cfg(feature = "gzip")]
+            compress_requests_over: None,
+        })
     }
 }
 
@@ -45,26 +197,66 @@ impl Client {
     /// Create a new client for custom network.
     pub fn custom(base_url: String) -> Result<Self> {
         ClientBuilder::new()
-            .network(Network::Custom(base_url.into()))
+            .network(Network::custom(base_url))
             .build()
     }
 
     pub fn base_url(&self) -> &Url {
-        &self.base_url
+        &self.inner.base_url
     }
 
-    /// Create a new client instance.
-    pub(crate) fn new(
-        network: Network,
-        http_client: HttpClient,
-        hooks: Vec<Box<dyn Hook>>,
-    ) -> Result<Self> {
-        Ok(Self {
-            base_url: Url::parse(network.url())?,
-            network,
-            http_client,
-            hooks,
-        })
+    /// Wrap fully configured shared state.
+    pub(crate) fn from_inner(inner: ClientInner) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            timeout: None,
+        }
+    }
+
+    /// A handle to the same client that applies `timeout` to every request,
+    /// whatever its endpoint class.
+    ///
+    /// The handle shares all state with this client; only the time limit
+    /// differs. Use it for one call or keep it for a group of calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Limit for each request made through the handle
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let number = client
+    ///         .with_timeout(Duration::from_millis(500))
+    ///         .get_checkpoint_number()
+    ///         .await?;
+    ///     println!("Checkpoint {}", number.number);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            timeout: Some(timeout),
+        }
+    }
+
+    /// Time limits applied to requests without an override.
+    pub fn timeouts(&self) -> TimeoutProfile {
+        self.inner.timeouts
+    }
+
+    /// Limit for one request: the handle's override, else the profile's.
+    fn timeout_for(&self, method: &str, path: &str) -> Duration {
+        self.timeout
+            .unwrap_or_else(|| self.inner.timeouts.for_request(method, path))
     }
 
     /// Perform a GET request.
@@ -72,30 +264,203 @@ impl Client {
     where
         T: DeserializeOwned,
     {
-        let url = self.base_url.join(path)?;
+        let path = self.inner.api_version.rewrite(path);
+        self.with_retries("GET", &path, || self.get_once(&path))
+            .await
+    }
+
+    /// API version the client is configured for.
+    pub fn api_version(&self) -> ApiVersion {
+        self.inner.api_version
+    }
+
+    /// Perform a GET request against `path` exactly as given, without
+    /// rewriting its version prefix.
+    pub(crate) async fn get_unversioned<T>(&self, path: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.with_retries("GET", path, || self.get_once(path)).await
+    }
+
+    /// Perform a GET request and return the `date` header of the response
+    /// alongside it; responses served from the cache have no date.
+    pub(crate) async fn get_dated<T>(&self, path: &str) -> Result<(T, Option<SystemTime>)>
+    where
+        T: DeserializeOwned,
+    {
+        let path = self.inner.api_version.rewrite(path);
+        self.with_retries("GET", &path, || self.get_once_dated(&path))
+            .await
+    }
+
+    async fn get_once<T>(&self, path: &str) -> StdResult<T, AttemptFailure>
+    where
+        T: DeserializeOwned,
+    {
+        self.get_once_dated(path).await.map(|(result, _)| result)
+    }
+
+    async fn get_once_dated<T>(
+        &self,
+        path: &str,
+    ) -> StdResult<(T, Option<SystemTime>), AttemptFailure>
+    where
+        T: DeserializeOwned,
+    {
+        let cache = self
+            .inner
+            .cache
+            .as_ref()
+            .filter(|cache| cache.is_cacheable(path));
+        let cache_key = self
+            .inner
+            .base_url
+            .join(path)
+            .map_err(Error::from)?
+            .to_string();
+        if let Some(cached) = cache.and_then(|cache| cache.store.get(&cache_key)) {
+            return Ok((self.decode(path, &cached)?, None));
+        }
+
+        let (endpoint, url) = self.route(path)?;
         let url_str = url.as_str().to_string();
 
         // Execute hooks
-        for hook in &self.hooks {
+        for hook in &self.inner.hooks {
             hook.before_request("GET", &url_str, None);
         }
 
-        let response = self.http_client.get(url).send().await?;
+        self.throttle(EndpointClass::Read).await;
+        let timeout = self.timeout_for("GET", path);
+        let request = self.authorize(self.inner.http_client.get(url.clone()).timeout(timeout));
+        let started = Instant::now();
+        let response = self.execute(request).await;
+        let response = self
+            .observe(endpoint, started, response)
+            .map_err(|e| timed_out(e, &url, started, timeout))?;
         let status = response.status();
+        self.report_meta("GET", &url_str, &response);
+        let date = response_date(response.headers());
+        let retry_after = retry_after_seconds(&response);
+        let responded = AttemptFailure::responded(status.as_u16(), request_id(&response));
 
-        let response_text = response.text().await?;
+        let response_text = self
+            .read_body(&url_str, response)
+            .await
+            .map_err(|e| timed_out(e, &url, started, timeout))
+            .map_err(&responded)?;
 
         // Execute hooks
-        for hook in &self.hooks {
+        for hook in &self.inner.hooks {
             hook.after_response("GET", &url_str, status.as_u16(), Some(&response_text));
         }
 
         if !status.is_success() {
-            return Err(self.handle_error_response(status.as_u16(), &response_text));
+            return Err(responded(timed_out(
+                self.rejected(
+                    EndpointClass::Read,
+                    status.as_u16(),
+                    &response_text,
+                    retry_after,
+                    false,
+                ),
+                &url,
+                started,
+                timeout,
+            )));
         }
 
-        let result: T = serde_json::from_str(&response_text)?;
-        Ok(result)
+        let result: T = self
+            .decode(url.path(), &response_text)
+            .map_err(&responded)?;
+        if let Some(cache) = cache {
+            cache.store.put(&cache_key, response_text, cache.ttl);
+        }
+        Ok((result, date))
+    }
+
+    /// Perform a GET request whose response holds a large list under `key`.
+    ///
+    /// The list's elements are decoded incrementally; the rest of the
+    /// response is skipped. Responses are never cached and hooks receive no
+    /// body for them.
+    #[cfg(feature = "checkpoints")]
+    pub(crate) async fn get_stream<T>(
+        &self,
+        path: &str,
+        key: &'static str,
+    ) -> Result<JsonArrayStream<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let (endpoint, url) = self.route(&self.inner.api_version.rewrite(path))?;
+        let url_str = url.as_str().to_string();
+
+        for hook in &self.inner.hooks {
+            hook.before_request("GET", &url_str, None);
+        }
+
+        self.throttle(EndpointClass::Read).await;
+        let timeout = self.timeout_for("GET", url.path());
+        let request = self.authorize(self.inner.http_client.get(url.clone()).timeout(timeout));
+        let started = Instant::now();
+        let response = self.execute(request).await;
+        let response = self
+            .observe(endpoint, started, response)
+            .map_err(|e| timed_out(e, &url, started, timeout))?;
+        let status = response.status();
+        self.report_meta("GET", &url_str, &response);
+
+        if !status.is_success() {
+            let retry_after = retry_after_seconds(&response);
+            let response_text = self
+                .read_body(&url_str, response)
+                .await
+                .map_err(|e| timed_out(e, &url, started, timeout))?;
+            for hook in &self.inner.hooks {
+                hook.after_response("GET", &url_str, status.as_u16(), Some(&response_text));
+            }
+            return Err(timed_out(
+                self.rejected(
+                    EndpointClass::Read,
+                    status.as_u16(),
+                    &response_text,
+                    retry_after,
+                    false,
+                ),
+                &url,
+                started,
+                timeout,
+            ));
+        }
+
+        for hook in &self.inner.hooks {
+            hook.after_response("GET", &url_str, status.as_u16(), None);
+        }
+        Ok(JsonArrayStream::new(
+            response,
+            ArrayScanner::new(key, self.inner.max_response_bytes),
+        ))
+    }
+
+    /// Drop every cached response.
+    ///
+    /// Has no effect when response caching is not enabled.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.inner.cache {
+            cache.store.clear();
+        }
+    }
+
+    /// Drop the cached response for `path`, if there is one.
+    #[cfg(feature = "checkpoints")]
+    pub(crate) fn evict_cached(&self, path: &str) {
+        if let Some(cache) = &self.inner.cache
+            && let Ok(key) = self.inner.base_url.join(path)
+        {
+            cache.store.remove(key.as_str());
+        }
     }
 
     /// Perform a POST request.
@@ -104,42 +469,536 @@ impl Client {
         B: Serialize,
         T: DeserializeOwned,
     {
-        let url = self.base_url.join(path)?;
+        self.send_post(path, body, None, false).await
+    }
+
+    /// Check that a payload's chain ID matches the client's network.
+    ///
+    /// Mainnet and testnet clients know their chain ID; other networks are
+    /// only checked when one is configured with
+    /// [`ClientBuilder::chain_id`]. Always passes when enforcement was turned
+    /// off with [`ClientBuilder::enforce_chain_id`].
+    ///
+    /// # Arguments
+    ///
+    /// * `chain_id` - Chain ID the payload is signed for
+    ///
+    /// # Returns
+    ///
+    /// [`Error::ChainIdMismatch`] if the payload targets another chain.
+    pub fn ensure_chain_id(&self, chain_id: u64) -> Result<()> {
+        match self.inner.expected_chain_id {
+            Some(expected) if expected != chain_id => {
+                Err(Error::chain_id_mismatch(expected, chain_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the payload's chain ID and contents.
+    pub(crate) fn check_payload<P>(&self, payload: &P) -> Result<()>
+    where
+        P: TransactionPayload,
+    {
+        self.ensure_chain_id(payload.chain_id())?;
+        payload.validate()
+    }
+
+    /// Check the payload's chain ID and contents, then sign it.
+    ///
+    /// Returns the signature together with the envelope that
+    /// [`submit`](Self::submit) uses for the idempotency key and the audit
+    /// record.
+    pub(crate) fn sign_payload<P>(
+        &self,
+        payload: &P,
+        private_key: &str,
+    ) -> Result<(Signature, SignedEnvelope)>
+    where
+        P: TransactionPayload,
+    {
+        self.check_payload(payload)?;
+        let signature = sign_transaction_payload(payload, private_key)?;
+        let envelope = SignedEnvelope::new(payload, vec![signature.clone()])?;
+        Ok((signature, envelope))
+    }
+
+    /// Decode a response body, rejecting unknown fields in strict mode and
+    /// warning about them otherwise.
+    fn decode<T>(&self, endpoint: &str, body: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if self.inner.strict_responses {
+            return from_str_strict(endpoint, body);
+        }
+        if !self.wants_warnings() {
+            return serde_json::from_str(body).map_err(|error| decode_error::<T>(body, error));
+        }
+        let (result, fields) = from_str_tracked::<T>(body)?;
+        if !fields.is_empty() {
+            self.emit_warning(Warning::UnknownFields {
+                endpoint: endpoint.to_string(),
+                fields,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Tell every hook about the operational headers of a response, and
+    /// warn if they mark the endpoint as deprecated.
+    fn report_meta(&self, method: &str, url: &str, response: &reqwest::Response) {
+        if !self.wants_warnings() {
+            return;
+        }
+        let meta = ResponseMeta::from_headers(response.status().as_u16(), response.headers());
+        for hook in &self.inner.hooks {
+            hook.on_response_meta(method, url, &meta);
+        }
+        if meta.deprecation.is_some() || meta.sunset.is_some() {
+            self.emit_warning(Warning::DeprecatedEndpoint {
+                url: url.to_string(),
+                deprecation: meta.deprecation,
+                sunset: meta.sunset,
+            });
+        }
+    }
+
+    /// Tell every hook that the local clock is skewed.
+    pub(crate) fn emit_clock_skew(&self, health: &ClientHealth) {
+        for hook in &self.inner.hooks {
+            hook.on_clock_skew(health);
+        }
+    }
+
+    /// Hand a warning to every hook and to the enclosing
+    /// [`with_warnings`](Self::with_warnings), if any.
+    pub(crate) fn emit_warning(&self, warning: Warning) {
+        for hook in &self.inner.hooks {
+            hook.on_warning(&warning);
+        }
+        warnings::collect(warning);
+    }
+
+    /// Whether anyone would receive a warning, so that work done only to
+    /// detect one can be skipped.
+    pub(crate) fn wants_warnings(&self) -> bool {
+        !self.inner.hooks.is_empty() || warnings::collecting()
+    }
+
+    /// Tell every hook about a recovery.
+    pub(crate) fn emit_recovery(&self, event: &RecoveryEvent) {
+        for hook in &self.inner.hooks {
+            hook.on_recovery(event);
+        }
+    }
+
+    /// Submit a signed transaction request.
+    ///
+    /// When idempotency keys are enabled on the client, the payload's
+    /// signature hash is attached as the `Idempotency-Key` header so that a
+    /// retried submission is recognised by the server instead of being
+    /// executed twice.
+    ///
+    /// With an audit sink configured, the outcome is recorded before it is
+    /// returned. A sink failure after an accepted submission is reported as
+    /// [`Error::Storage`] naming the transaction hash; after a failed
+    /// submission the submission's own error is returned.
+    ///
+    /// A dry-run client sends nothing and audits nothing; it fails with
+    /// [`Error::DryRun`] describing the request instead.
+    pub(crate) async fn submit<B>(
+        &self,
+        path: &str,
+        body: &B,
+        envelope: &SignedEnvelope,
+    ) -> Result<TransactionResponse>
+    where
+        B: Serialize,
+    {
+        if self.inner.dry_run {
+            let endpoint = self.inner.api_version.rewrite(path);
+            return Err(Error::dry_run(
+                envelope.dry_run(&endpoint, serde_json::to_value(body)?),
+            ));
+        }
+        let result = self
+            .submit_unaudited(path, body, envelope.signature_hash)
+            .await;
+        let Some(audit) = &self.inner.audit else {
+            return result;
+        };
+        match result {
+            Ok(response) => {
+                let record = envelope.record(
+                    path,
+                    AuditOutcome::Accepted {
+                        hash: response.hash,
+                    },
+                );
+                audit.record(&record).map_err(|e| {
+                    Error::storage(format!(
+                        "Transaction {} was submitted but not audited: {}",
+                        response.hash, e
+                    ))
+                })?;
+                Ok(response)
+            }
+            Err(error) => {
+                let record = envelope.record(
+                    path,
+                    AuditOutcome::Failed {
+                        error: error.to_string(),
+                    },
+                );
+                let _ = audit.record(&record);
+                Err(error)
+            }
+        }
+    }
+
+    async fn submit_unaudited<B, T>(&self, path: &str, body: &B, signature_hash: B256) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let key = self
+            .inner
+            .idempotency_keys
+            .then(|| signature_hash.to_string());
+        self.send_post(path, body, key.as_deref(), true).await
+    }
+
+    /// POST `body` to `path`. A bare `409 Conflict` only means the
+    /// transaction was already accepted when `submission` is set.
+    async fn send_post<B, T>(
+        &self,
+        path: &str,
+        body: &B,
+        idempotency_key: Option<&str>,
+        submission: bool,
+    ) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let path = self.inner.api_version.rewrite(path);
+        self.with_retries("POST", &path, || {
+            self.send_post_once(&path, body, idempotency_key, submission)
+        })
+        .await
+    }
+
+    async fn send_post_once<B, T>(
+        &self,
+        path: &str,
+        body: &B,
+        idempotency_key: Option<&str>,
+        submission: bool,
+    ) -> StdResult<T, AttemptFailure>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let (endpoint, url) = self.route(path)?;
         let url_str = url.as_str().to_string();
 
-        let body_json = serde_json::to_string(body)?;
+        let body_json = serde_json::to_string(body).map_err(Error::from)?;
 
         // Execute hooks
-        for hook in &self.hooks {
+        for hook in &self.inner.hooks {
             hook.before_request("POST", &url_str, Some(&body_json));
         }
 
-        let response = self
+        let timeout = self.timeout_for("POST", path);
+        let mut request = self
+            .inner
             .http_client
-            .post(url)
-            .header(header::CONTENT_TYPE, "application/json")
-            .body(body_json)
-            .send()
-            .await?;
+            .post(url.clone())
+            .timeout(timeout)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(key) = idempotency_key {
+            request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+
+        let (body, compressed) = self.request_body(body_json)?;
+        if compressed {
+            request = request.header(header::CONTENT_ENCODING, "gzip");
+        }
+
+        self.throttle(EndpointClass::Write).await;
+        let request = self.authorize(request);
+        let started = Instant::now();
+        let response = self.execute(request.body(body)).await;
+        let response = self
+            .observe(endpoint, started, response)
+            .map_err(|e| timed_out(e, &url, started, timeout))?;
 
         let status = response.status();
-        let response_text = response.text().await?;
+        self.report_meta("POST", &url_str, &response);
+        let retry_after = retry_after_seconds(&response);
+        let responded = AttemptFailure::responded(status.as_u16(), request_id(&response));
+        let response_text = self
+            .read_body(&url_str, response)
+            .await
+            .map_err(|e| timed_out(e, &url, started, timeout))
+            .map_err(&responded)?;
 
         // Execute hooks
-        for hook in &self.hooks {
+        for hook in &self.inner.hooks {
             hook.after_response("POST", &url_str, status.as_u16(), Some(&response_text));
         }
 
         if !status.is_success() {
-            return Err(self.handle_error_response(status.as_u16(), &response_text));
+            return Err(responded(timed_out(
+                self.rejected(
+                    EndpointClass::Write,
+                    status.as_u16(),
+                    &response_text,
+                    retry_after,
+                    submission,
+                ),
+                &url,
+                started,
+                timeout,
+            )));
         }
 
-        let result: T = serde_json::from_str(&response_text)?;
+        let result: T = self
+            .decode(url.path(), &response_text)
+            .map_err(&responded)?;
         Ok(result)
     }
 
+    /// State of the circuit breaker, or `None` when the client has none.
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner.circuit.as_ref().map(CircuitBreaker::state)
+    }
+
+    /// Current state of the client-side rate limit bucket for an endpoint class.
+    ///
+    /// Returns `None` when the class is not rate limited.
+    pub fn rate_limit_state(&self, class: EndpointClass) -> Option<BucketState> {
+        self.inner
+            .rate_limiter
+            .as_ref()
+            .and_then(|limiter| limiter.state(class))
+    }
+
+    /// Read a response body, enforcing the configured size limit.
+    ///
+    /// A declared `Content-Length` above the limit is rejected before any of
+    /// the body is read; otherwise reading stops as soon as the limit is
+    /// crossed.
+    pub(crate) async fn read_body(
+        &self,
+        url: &str,
+        mut response: reqwest::Response,
+    ) -> Result<String> {
+        let Some(limit) = self.inner.max_response_bytes else {
+            return Ok(response.text().await?);
+        };
+        if response
+            .content_length()
+            .is_some_and(|length| length > limit as u64)
+        {
+            return Err(Error::response_too_large(url, limit));
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if body.len() + chunk.len() > limit {
+                return Err(Error::response_too_large(url, limit));
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+
+    /// Latency and error statistics of every configured base URL.
+    ///
+    /// Empty unless additional endpoints were configured with
+    /// [`ClientBuilder::endpoint`].
+    pub fn endpoint_stats(&self) -> Vec<EndpointStats> {
+        self.inner
+            .endpoints
+            .as_ref()
+            .map(EndpointSelector::stats)
+            .unwrap_or_default()
+    }
+
+    /// Resolve `path` against the base URL chosen for this request.
+    fn route(&self, path: &str) -> Result<(Option<usize>, Url)> {
+        match self
+            .inner
+            .endpoints
+            .as_ref()
+            .and_then(EndpointSelector::select)
+        {
+            Some((index, base)) => Ok((Some(index), base.join(path)?)),
+            None => Ok((None, self.inner.base_url.join(path)?)),
+        }
+    }
+
+    /// Send a prepared request through the configured transport.
+    async fn execute(&self, request: RequestBuilder) -> Result<reqwest::Response> {
+        self.inner.transport.execute(request.build()?).await
+    }
+
+    /// Feed the outcome of a request into endpoint selection.
+    ///
+    /// Transport failures and 5xx responses count against the endpoint; other
+    /// statuses reflect the request itself rather than the endpoint's health.
+    fn observe(
+        &self,
+        endpoint: Option<usize>,
+        started: Instant,
+        response: Result<reqwest::Response>,
+    ) -> Result<reqwest::Response> {
+        if let (Some(selector), Some(index)) = (&self.inner.endpoints, endpoint) {
+            let healthy = response
+                .as_ref()
+                .is_ok_and(|response| !response.status().is_server_error());
+            selector.record(index, started.elapsed(), healthy);
+        }
+        response
+    }
+
+    /// Run `request`, retrying failures as the configured retry policy allows.
+    ///
+    /// When error context is enabled the final error carries the request's
+    /// path, last status and request ID, attempt count and total time.
+    async fn with_retries<T, F, Fut>(
+        &self,
+        method: &'static str,
+        path: &str,
+        mut request: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = StdResult<T, AttemptFailure>>,
+    {
+        let class = if method == "GET" {
+            EndpointClass::Read
+        } else {
+            EndpointClass::Write
+        };
+        let should_retry = |error: &Error, retried: u32| {
+            self.inner.retry.as_ref().is_some_and(|policy| match class {
+                EndpointClass::Read => policy.should_retry(error, retried),
+                EndpointClass::Write => policy.should_retry_write(error, retried),
+            })
+        };
+
+        let started = Instant::now();
+        let mut retried = 0;
+        let failure = loop {
+            match self.attempt(&mut request).await {
+                Ok(result) => return Ok(result),
+                Err(failure)
+                    if should_retry(&failure.error, retried)
+                        && self
+                            .inner
+                            .retry_budget
+                            .as_ref()
+                            .is_none_or(RetryBudgetTracker::try_spend) =>
+                {
+                    retried += 1;
+                    if let Some(policy) = &self.inner.retry {
+                        sleep(policy.delay(retried, &failure.error)).await;
+                    }
+                }
+                Err(failure) => break failure,
+            }
+        };
+
+        if !self.inner.error_context {
+            return Err(failure.error);
+        }
+        Err(failure.error.with_context(RequestContext {
+            method,
+            path: path.to_string(),
+            status_code: failure.status_code,
+            request_id: failure.request_id,
+            elapsed: started.elapsed(),
+            attempts: retried + 1,
+        }))
+    }
+
+    /// Make one attempt of a request, passing it through the circuit
+    /// breaker when one is configured.
+    async fn attempt<T, F, Fut>(&self, request: &mut F) -> StdResult<T, AttemptFailure>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = StdResult<T, AttemptFailure>>,
+    {
+        let Some(circuit) = &self.inner.circuit else {
+            return request().await;
+        };
+        let permit = circuit.acquire()?;
+        let result = request().await;
+        permit.record(result.as_ref().err().map(|failure| &failure.error));
+        result
+    }
+
+    /// The bytes to send for a JSON body and whether they are gzipped.
+    ///
+    /// Bodies are only compressed when the client was built with
+    /// `ClientBuilder::compress_requests` and the body reaches its threshold.
+    fn request_body(&self, body: String) -> Result<(Vec<u8>, bool)> {
+        #[cfg(feature = "gzip")]
+        if let Some(threshold) = self.inner.compress_requests_over
+            && body.len() >= threshold
+        {
+            return Ok((gzip(body.as_bytes())?, true));
+        }
+        Ok((body.into_bytes(), false))
+    }
+
+    /// Attach the configured credentials to a request.
+    fn authorize(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(auth) = &self.inner.auth {
+            for (name, value) in auth.headers() {
+                request = request.header(name, value);
+            }
+        }
+        request
+    }
+
+    async fn throttle(&self, class: EndpointClass) {
+        if let Some(limiter) = &self.inner.rate_limiter {
+            limiter.acquire(class).await;
+        }
+    }
+
+    /// Build the error for a non-success response, backing off the rate
+    /// limiter and recording `Retry-After` when the server throttled us.
+    fn rejected(
+        &self,
+        class: EndpointClass,
+        status_code: u16,
+        body: &str,
+        retry_after: Option<u64>,
+        submission: bool,
+    ) -> Error {
+        let mut error = self.handle_error_response(status_code, body, submission);
+        if let Error::RateLimitExceeded {
+            retry_after_seconds,
+        } = &mut error
+        {
+            *retry_after_seconds = retry_after.or(*retry_after_seconds);
+            if let Some(limiter) = &self.inner.rate_limiter {
+                limiter.pause(class, Duration::from_secs(retry_after.unwrap_or(1)));
+            }
+        }
+        error
+    }
+
     /// Handle error responses from the API.
-    fn handle_error_response(&self, status_code: u16, body: &str) -> Error {
+    ///
+    /// A `409 Conflict` is read as a duplicate submission only for transaction
+    /// submissions; elsewhere it stays a plain API error.
+    fn handle_error_response(&self, status_code: u16, body: &str, submission: bool) -> Error {
         // Try to parse as structured error response first (L1 compatible)
         if let Ok(error_response) = serde_json::from_str::<ErrorResponse>(body) {
             // Classify error based on status code and error code
@@ -147,15 +1006,17 @@ impl Client {
                 status_code,
                 &error_response.error_code,
                 &error_response.message,
+                submission,
             )
         } else {
             // Fallback based on status code
             match status_code {
                 400 => Error::invalid_parameter("request", body),
+                409 if submission => Error::duplicate_submission(extract_transaction_hash(body)),
                 401 => Error::authentication(body),
                 403 => Error::authorization(body),
                 404 => Error::resource_not_found("unknown", body),
-                408 => Error::request_timeout("unknown", 0),
+                408 => Error::server_timeout("unknown", 0),
                 422 => Error::business_logic("validation", body),
                 429 => Error::rate_limit_exceeded(None),
                 500..=599 => Error::http_transport(body, Some(status_code)),
@@ -165,8 +1026,44 @@ impl Client {
     }
 
     /// Classify structured errors based on L1 error patterns.
-    fn classify_error(status_code: u16, error_code: &str, message: &str) -> Error {
+    fn classify_error(
+        status_code: u16,
+        error_code: &str,
+        message: &str,
+        submission: bool,
+    ) -> Error {
         match (status_code, error_code) {
+            // Explicit duplicate codes, or a 409 Conflict on a submission -
+            // transaction already submitted
+            (_, "business_duplicate_transaction" | "duplicate_submission") => {
+                Error::duplicate_submission(extract_transaction_hash(message))
+            }
+            (409, _) if submission => {
+                Error::duplicate_submission(extract_transaction_hash(message))
+            }
+
+            // Nonce out of sequence, reported as either a validation or a business error
+            (
+                _,
+                "business_invalid_nonce"
+                | "business_nonce_too_low"
+                | "business_nonce_too_high"
+                | "validation_nonce",
+            ) => Error::nonce_mismatch(message),
+
+            // The L1's view of the chain moved on during submission. These codes
+            // are not part of a documented L1 contract; they are matched on a
+            // best-effort basis so such rejections get a typed error.
+            (_, "business_stale_checkpoint" | "business_checkpoint_expired") => {
+                Error::stale_checkpoint(message)
+            }
+            (_, code)
+                if code.starts_with("business_")
+                    && message.to_ascii_lowercase().contains("checkpoint too old") =>
+            {
+                Error::stale_checkpoint(message)
+            }
+
             // 400 Bad Request - Validation Errors
             (400, code) if code.starts_with("validation_") => {
                 let param = code.strip_prefix("validation_").unwrap_or("unknown");
@@ -186,7 +1083,7 @@ impl Client {
             }
 
             // 408 Request Timeout
-            (408, "request_timeout") => Error::request_timeout(message, 0),
+            (408, "request_timeout") => Error::server_timeout("unknown", 0),
 
             // 422 Unprocessable Entity - Business Logic
             (422, code) if code.starts_with("business_") => {
@@ -212,7 +1109,7 @@ impl Client {
     /// **This method is intended only for testing and should not be used in production code.**
     #[doc(hidden)]
     pub fn test_handle_error_response(&self, status_code: u16, body: &str) -> Error {
-        self.handle_error_response(status_code, body)
+        self.handle_error_response(status_code, body, false)
     }
 
     /// Test helper method to expose classify_error for comprehensive testing.
@@ -220,10 +1117,78 @@ impl Client {
     /// **This method is intended only for testing and should not be used in production code.**
     #[doc(hidden)]
     pub fn test_classify_error(status_code: u16, error_code: &str, message: &str) -> Error {
-        Self::classify_error(status_code, error_code, message)
+        Self::classify_error(status_code, error_code, message, false)
+    }
+}
+
+/// An error from a single request attempt with what is known of its response.
+struct AttemptFailure {
+    error: Error,
+    status_code: Option<u16>,
+    request_id: Option<String>,
+}
+
+impl AttemptFailure {
+    /// Wrap errors raised after a response with `status_code` was received.
+    fn responded(status_code: u16, request_id: Option<String>) -> impl Fn(Error) -> Self {
+        move |error| Self {
+            error,
+            status_code: Some(status_code),
+            request_id: request_id.clone(),
+        }
     }
 }
 
+impl From<Error> for AttemptFailure {
+    fn from(error: Error) -> Self {
+        Self {
+            error,
+            status_code: None,
+            request_id: None,
+        }
+    }
+}
+
+fn request_id(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Report a timed-out request against its endpoint, the time spent on it
+/// and, for client timeouts, the limit that applied.
+fn timed_out(error: Error, url: &Url, started: Instant, timeout: Duration) -> Error {
+    let millis = |duration: Duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+    match error {
+        Error::ClientTimeout { .. } => {
+            Error::client_timeout(url.path(), millis(started.elapsed()), millis(timeout))
+        }
+        Error::ServerTimeout { .. } => Error::server_timeout(url.path(), millis(started.elapsed())),
+        other => other,
+    }
+}
+
+/// Parse a `Retry-After` header expressed in seconds.
+fn retry_after_seconds(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// Extract the first `0x`-prefixed 32-byte hash mentioned in a server message.
+///
+/// Returns `None` when the message does not reference a hash.
+fn extract_transaction_hash(message: &str) -> Option<B256> {
+    message
+        .match_indices("0x")
+        .filter_map(|(start, _)| message.get(start..start + 66))
+        .find_map(|candidate| candidate.parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -248,19 +1213,19 @@ mod tests {
         let mainnet_client = Client::mainnet();
         assert!(mainnet_client.is_ok());
         let client = mainnet_client.unwrap();
-        assert!(client.base_url.as_str().contains("mainnet"));
+        assert!(client.inner.base_url.as_str().contains("mainnet"));
 
         // Test testnet client creation
         let testnet_client = Client::testnet();
         assert!(testnet_client.is_ok());
         let client = testnet_client.unwrap();
-        assert!(client.base_url.as_str().contains("testnet"));
+        assert!(client.inner.base_url.as_str().contains("testnet"));
 
         // Test local client creation
         let local_client = Client::local();
         assert!(local_client.is_ok());
         let client = local_client.unwrap();
-        assert!(client.base_url.as_str().contains("127.0.0.1"));
+        assert!(client.inner.base_url.as_str().contains("127.0.0.1"));
     }
 
     #[test]
@@ -337,7 +1302,7 @@ mod tests {
     #[test]
     fn test_error_classification_timeout_errors() {
         let error = Client::test_classify_error(408, "request_timeout", "Request timed out");
-        assert!(matches!(error, Error::RequestTimeout { .. }));
+        assert!(matches!(error, Error::ServerTimeout { .. }));
     }
 
     #[test]
@@ -429,7 +1394,7 @@ mod tests {
         assert!(matches!(error, Error::ResourceNotFound { .. }));
 
         let error = client.test_handle_error_response(408, invalid_json);
-        assert!(matches!(error, Error::RequestTimeout { .. }));
+        assert!(matches!(error, Error::ServerTimeout { .. }));
 
         let error = client.test_handle_error_response(422, invalid_json);
         assert!(matches!(error, Error::BusinessLogic { .. }));
@@ -454,13 +1419,23 @@ mod tests {
     fn test_network_url_configuration() {
         // Test that different networks use correct base URLs
         let mainnet = Client::mainnet().unwrap();
-        assert!(mainnet.base_url.as_str().contains("mainnet.1money.network"));
+        assert!(
+            mainnet
+                .base_url()
+                .as_str()
+                .contains("mainnet.1money.network")
+        );
 
         let testnet = Client::testnet().unwrap();
-        assert!(testnet.base_url.as_str().contains("testnet.1money.network"));
+        assert!(
+            testnet
+                .base_url()
+                .as_str()
+                .contains("testnet.1money.network")
+        );
 
         let local = Client::local().unwrap();
-        assert!(local.base_url.as_str().contains("127.0.0.1:18555"));
+        assert!(local.base_url().as_str().contains("127.0.0.1:18555"));
     }
 
     #[test]
@@ -472,14 +1447,69 @@ mod tests {
         let http_client = HttpClient::new();
         let hooks: Vec<Box<dyn Hook>> = vec![];
 
-        let client = Client::new(
-            Network::Custom(base_url.to_string().into()),
-            http_client,
-            hooks,
-        )
-        .unwrap();
+        let client = Client::from_inner(
+            ClientInner::new(Network::custom(base_url.to_string()), http_client, hooks).unwrap(),
+        );
 
-        assert_eq!(client.base_url, base_url);
-        assert_eq!(client.hooks.len(), 0);
+        assert_eq!(client.inner.base_url, base_url);
+        assert_eq!(client.inner.hooks.len(), 0);
+    }
+
+    #[test]
+    fn test_client_clones_share_state() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Client>();
+
+        let client = Client::local().expect("Client should build");
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.inner, &clone.inner));
+        assert_eq!(Arc::strong_count(&client.inner), 2);
+        drop(clone);
+        assert_eq!(Arc::strong_count(&client.inner), 1);
+    }
+
+    #[test]
+    fn test_classify_duplicate_submission() {
+        let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+        let error = Client::test_classify_error(
+            409,
+            "business_duplicate_transaction",
+            &format!("transaction {hash} already exists"),
+        );
+        match error {
+            Error::DuplicateSubmission { original_hash } => {
+                assert_eq!(original_hash.map(|h| h.to_string()).as_deref(), Some(hash))
+            }
+            other => panic!("Expected DuplicateSubmission, got {other:?}"),
+        }
+
+        let error = Client::test_classify_error(422, "duplicate_submission", "seen before");
+        assert!(matches!(
+            error,
+            Error::DuplicateSubmission {
+                original_hash: None
+            }
+        ));
+
+        // A bare conflict is only a duplicate when a transaction was submitted.
+        let error = Client::classify_error(409, "conflict", "already exists", true);
+        assert!(error.is_duplicate_submission());
+        let error = Client::classify_error(409, "conflict", "already exists", false);
+        assert_eq!(error.status_code(), Some(409));
+        assert!(!error.is_duplicate_submission());
+    }
+
+    #[test]
+    fn test_extract_transaction_hash() {
+        assert_eq!(extract_transaction_hash("no hash here"), None);
+        assert_eq!(extract_transaction_hash("short 0x1234"), None);
+        assert_eq!(
+            extract_transaction_hash(
+                "dup of 0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f."
+            )
+            .map(|hash| hash.to_string())
+            .as_deref(),
+            Some("0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f")
+        );
     }
 }
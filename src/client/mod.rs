@@ -1,17 +1,78 @@
 //! Client core functionality and configuration.
+//!
+//! Network and endpoint configuration in [`config`] is always available; the
+//! client itself requires the `client` feature.
 
-pub mod builder;
 pub mod config;
+
+#[cfg(feature = "client")]
+pub mod audit;
+#[cfg(feature = "client")]
+pub mod builder;
+#[cfg(feature = "client")]
+pub mod cache;
+#[cfg(feature = "client")]
+pub mod health;
+#[cfg(feature = "client")]
 pub mod hooks;
+#[cfg(feature = "client")]
 pub mod http;
+#[cfg(feature = "tokens")]
+pub mod metadata;
+#[cfg(feature = "client")]
+pub mod nonces;
+#[cfg(feature = "client")]
+pub mod pagination;
+#[cfg(feature = "client")]
+pub mod recovery;
+#[cfg(feature = "client")]
+pub mod settings;
+#[cfg(feature = "client")]
+pub mod state;
+#[cfg(feature = "checkpoints")]
+pub mod stream;
+#[cfg(feature = "client")]
+mod strict;
+#[cfg(feature = "client")]
+pub mod token_info;
+#[cfg(feature = "client")]
+pub mod warnings;
 
 // Re-export public interfaces
+pub use config::{ApiVersion, Network, api_path, endpoints};
+
+#[cfg(feature = "client")]
+pub use audit::{AuditLogEntry, AuditOutcome, AuditRecord, AuditSink, JsonlAuditLog};
+#[cfg(feature = "client")]
 pub use builder::ClientBuilder;
-pub use config::{Network, api_path, endpoints};
-pub use hooks::{ConsoleLogger, Hook, LogLevel, Logger, LoggingHook};
+#[cfg(feature = "client")]
+pub use cache::{CacheStore, DiskCache, MemoryCache};
+#[cfg(feature = "client")]
+pub use health::{ClientHealth, DEFAULT_MAX_CLOCK_SKEW};
+#[cfg(feature = "client")]
+pub use hooks::{ConsoleLogger, Hook, LogLevel, LogPolicy, Logger, LoggingHook, ResponseMeta};
+#[cfg(feature = "client")]
 pub use http::Client;
-
-#[cfg(test)]
+#[cfg(feature = "tokens")]
+pub use metadata::{HttpPutStore, IpfsStore, MetadataStore, MetadataStoreFuture, S3Store};
+#[cfg(feature = "client")]
+pub use nonces::NonceManager;
+#[cfg(feature = "client")]
+pub use pagination::PageStream;
+#[cfg(feature = "client")]
+pub use recovery::{RecoveryEvent, RecoveryPolicy, SubmitOptions};
+#[cfg(feature = "client")]
+pub use settings::ClientSettings;
+#[cfg(feature = "client")]
+pub use state::{ChainState, DEFAULT_STATE_MAX_STALENESS, StateTracker};
+#[cfg(feature = "checkpoints")]
+pub use stream::JsonArrayStream;
+#[cfg(feature = "client")]
+pub use token_info::{DEFAULT_TOKEN_INFO_TTL, TokenInfoCache};
+#[cfg(feature = "client")]
+pub use warnings::{Warning, WithWarnings};
+
+#[cfg(all(test, feature = "client"))]
 mod tests {
     use super::*;
     use std::time::Duration;
@@ -0,0 +1,153 @@
+//! Locally tracked account nonces.
+//!
+//! Signing many transactions from one account would otherwise need a nonce
+//! lookup per transaction, and concurrent lookups return the same value.
+//! [`NonceManager`] hands out consecutive nonces from the last value seen
+//! on chain instead, seeded by [`Client::next_nonce`] and
+//! [`Client::get_account_nonces`].
+
+use super::http::Client;
+use crate::Result;
+use alloy_primitives::Address;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// Next unused nonce of each account the client signs for.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    next: Mutex<HashMap<Address, u64>>,
+}
+
+impl NonceManager {
+    /// Create an empty manager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `nonce` as the next unused nonce of `address`, replacing any
+    /// value handed out locally.
+    pub fn seed(&self, address: Address, nonce: u64) {
+        self.lock().insert(address, nonce);
+    }
+
+    /// Record `nonce` as the next unused nonce of `address` unless a later
+    /// one was already handed out locally, so nonces reserved for
+    /// transactions not yet on chain are not handed out again.
+    pub fn advance(&self, address: Address, nonce: u64) {
+        let mut next = self.lock();
+        let current = next.entry(address).or_insert(nonce);
+        *current = (*current).max(nonce);
+    }
+
+    /// The next nonce of `address` without reserving it.
+    pub fn peek(&self, address: Address) -> Option<u64> {
+        self.lock().get(&address).copied()
+    }
+
+    /// Take the next nonce of `address`, or `None` if it was never seeded.
+    pub fn reserve(&self, address: Address) -> Option<u64> {
+        let mut next = self.lock();
+        let nonce = next.get_mut(&address)?;
+        let reserved = *nonce;
+        *nonce += 1;
+        Some(reserved)
+    }
+
+    /// Forget `address`, e.g. after a submission failed with a nonce
+    /// mismatch, so its next nonce is fetched again.
+    pub fn invalidate(&self, address: Address) {
+        self.lock().remove(&address);
+    }
+
+    /// Forget every account.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Address, u64>> {
+        self.next
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Client {
+    /// The nonces this client hands out locally.
+    pub fn nonce_manager(&self) -> &NonceManager {
+        &self.inner.nonces
+    }
+
+    /// Reserve the next nonce of `address`.
+    ///
+    /// The first call for an account fetches its nonce from the network;
+    /// later calls count up locally, so transactions signed concurrently
+    /// from one account get distinct nonces. Nonces reserved for
+    /// transactions that are never submitted leave a gap: call
+    /// [`NonceManager::invalidate`] to resynchronize.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The signing account
+    ///
+    /// # Returns
+    ///
+    /// A nonce no earlier call has returned for the account.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let sender = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///
+    ///     let first = client.next_nonce(sender).await?;
+    ///     let second = client.next_nonce(sender).await?;
+    ///     assert_eq!(second, first + 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn next_nonce(&self, address: Address) -> Result<u64> {
+        if let Some(nonce) = self.inner.nonces.reserve(address) {
+            return Ok(nonce);
+        }
+        let fetched = self.get_account_nonce(address).await?.nonce;
+        let mut next = self.inner.nonces.lock();
+        // A concurrent call may have seeded the account meanwhile.
+        let nonce = next.entry(address).or_insert(fetched);
+        let reserved = *nonce;
+        *nonce += 1;
+        Ok(reserved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonce_manager_reserves_consecutive_nonces() {
+        let manager = NonceManager::new();
+        let account = Address::repeat_byte(0x11);
+        assert_eq!(manager.reserve(account), None);
+
+        manager.seed(account, 7);
+        assert_eq!(manager.reserve(account), Some(7));
+        assert_eq!(manager.reserve(account), Some(8));
+        assert_eq!(manager.peek(account), Some(9));
+
+        // A lookup racing the local reservations must not rewind them.
+        manager.advance(account, 7);
+        assert_eq!(manager.peek(account), Some(9));
+        manager.advance(account, 12);
+        assert_eq!(manager.peek(account), Some(12));
+
+        manager.invalidate(account);
+        assert_eq!(manager.peek(account), None);
+    }
+}
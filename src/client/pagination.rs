@@ -0,0 +1,151 @@
+//! Auto-pagination over cursor-based list endpoints.
+
+use super::http::Client;
+use crate::responses::Page;
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use url::form_urlencoded::byte_serialize;
+
+/// Query parameter carrying the cursor of the requested page.
+pub const CURSOR_PARAM: &str = "cursor";
+
+/// Items of a paginated list, fetched one page at a time as they are consumed.
+///
+/// Pages are requested lazily: the next page is only fetched once every
+/// item of the current one has been returned.
+pub struct PageStream<'a, T> {
+    client: &'a Client,
+    path: String,
+    cursor: Option<String>,
+    buffered: VecDeque<T>,
+    total: Option<u64>,
+    pages: usize,
+    finished: bool,
+}
+
+impl<T> Debug for PageStream<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("PageStream")
+            .field("path", &self.path)
+            .field("cursor", &self.cursor)
+            .field("buffered", &self.buffered.len())
+            .field("total", &self.total)
+            .field("pages", &self.pages)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<'a, T: DeserializeOwned> PageStream<'a, T> {
+    /// Paginate the list at `path`, which may already carry a query string.
+    pub(crate) fn new(client: &'a Client, path: String) -> Self {
+        Self {
+            client,
+            path,
+            cursor: None,
+            buffered: VecDeque::new(),
+            total: None,
+            pages: 0,
+            finished: false,
+        }
+    }
+
+    /// Continue from a `next_cursor` returned by an earlier page.
+    ///
+    /// Must be called before the first page is fetched.
+    pub fn resume_from<C: Into<String>>(&mut self, cursor: C) {
+        self.cursor = Some(cursor.into());
+    }
+
+    /// Fetch the next page, or return `None` once the last page was fetched.
+    ///
+    /// Items still buffered from a previous [`PageStream::next`] call are
+    /// dropped.
+    pub async fn next_page(&mut self) -> Option<Result<Page<T>>> {
+        if self.finished {
+            return None;
+        }
+        self.buffered.clear();
+
+        let path = match &self.cursor {
+            Some(cursor) => page_path(&self.path, cursor),
+            None => self.path.clone(),
+        };
+        let page: Page<T> = match self.client.get(&path).await {
+            Ok(page) => page,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+
+        self.pages += 1;
+        self.total = page.total.or(self.total);
+        if page.next_cursor.is_some() && page.next_cursor == self.cursor {
+            self.finished = true;
+            return Some(Err(Error::response_deserialization(
+                "JSON",
+                "Server returned the requested cursor as the next cursor",
+                self.cursor.clone().unwrap_or_default(),
+            )));
+        }
+        self.cursor = page.next_cursor.clone();
+        self.finished = self.cursor.is_none();
+        Some(Ok(page))
+    }
+
+    /// Return the next item, fetching the following page when needed.
+    pub async fn next(&mut self) -> Option<Result<T>> {
+        loop {
+            if let Some(item) = self.buffered.pop_front() {
+                return Some(Ok(item));
+            }
+            match self.next_page().await? {
+                Ok(page) => self.buffered.extend(page.items),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+
+    /// Fetch every remaining item.
+    pub async fn collect(mut self) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while let Some(item) = self.next().await {
+            items.push(item?);
+        }
+        Ok(items)
+    }
+
+    /// Total item count reported by the most recent page that included one.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Number of pages fetched so far.
+    pub fn pages_fetched(&self) -> usize {
+        self.pages
+    }
+}
+
+/// Append a cursor to a list path.
+fn page_path(path: &str, cursor: &str) -> String {
+    let separator = if path.contains('?') { '&' } else { '?' };
+    let cursor: String = byte_serialize(cursor.as_bytes()).collect();
+    format!("{path}{separator}{CURSOR_PARAM}={cursor}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_path() {
+        assert_eq!(page_path("/v1/list", "abc"), "/v1/list?cursor=abc");
+        assert_eq!(
+            page_path("/v1/list?address=0x01", "a b&c"),
+            "/v1/list?address=0x01&cursor=a+b%26c"
+        );
+    }
+}
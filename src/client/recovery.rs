@@ -0,0 +1,271 @@
+//! Automatic recovery from submissions rejected for a stale nonce.
+
+use super::http::Client;
+use crate::Result;
+use crate::client::config::api_path;
+use crate::crypto::{SecretKeyMaterial, signing_key_address};
+use crate::error::Error;
+use crate::requests::{SignedSubmission, TransactionPayload};
+use crate::responses::TransactionResponse;
+
+/// Which rejected submissions the client repairs and retries once.
+///
+/// Enable with [`ClientBuilder::auto_recover`](crate::ClientBuilder::auto_recover).
+/// Recovery applies to the methods that sign a payload themselves, such as
+/// [`Client::send_payment`] and [`Client::mint_token`]; payloads signed
+/// elsewhere cannot be re-signed and are never changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecoveryPolicy {
+    /// On [`Error::NonceMismatch`], fetch the signer's current nonce, sign
+    /// the payload again with it and resubmit.
+    ///
+    /// Off by default. A nonce that is too low may mean this very payload
+    /// already landed, for instance when a POST timed out after the L1
+    /// executed it; signing it again with the current nonce would then send
+    /// the transfer twice. The transaction that lands also carries a
+    /// different nonce and hash than the payload passed in. Enable it only
+    /// where a payload is known not to have been accepted before, and never
+    /// where nonces are assigned deliberately, e.g. to order transactions.
+    pub refresh_nonce: bool,
+}
+
+impl RecoveryPolicy {
+    /// A policy with every repair off; enable nonce repairs with
+    /// [`refresh_nonce`](Self::refresh_nonce).
+    ///
+    /// Stale-checkpoint rejections are not repaired: no payload carries an
+    /// epoch or checkpoint, so resubmitting after a state refresh would send
+    /// the same signed bytes again.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether nonce mismatches are recovered from.
+    pub fn refresh_nonce(mut self, enabled: bool) -> Self {
+        self.refresh_nonce = enabled;
+        self
+    }
+}
+
+/// How far [`Client::submit_with_options`] goes to get a rejected
+/// submission accepted.
+///
+/// Each repair refreshes what the rejection says is out of date, signs the
+/// payload again and resubmits it, up to `max_refreshes` times in total.
+/// [`Error::StaleCheckpoint`] rejections are returned as they are: no
+/// payload carries an epoch or checkpoint, so there is nothing to re-sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmitOptions {
+    /// On [`Error::NonceMismatch`], sign again with the signer's current
+    /// nonce. Off by default: the transaction that lands then has a
+    /// different nonce and hash than the payload passed in.
+    pub refresh_nonce: bool,
+    /// Most repairs made for one submission.
+    pub max_refreshes: u32,
+}
+
+impl SubmitOptions {
+    /// Allow up to two repairs, with nonce repairs off.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether nonce mismatches are repaired.
+    pub fn refresh_nonce(mut self, enabled: bool) -> Self {
+        self.refresh_nonce = enabled;
+        self
+    }
+
+    /// Set the most repairs made for one submission.
+    pub fn max_refreshes(mut self, max: u32) -> Self {
+        self.max_refreshes = max;
+        self
+    }
+}
+
+impl Default for SubmitOptions {
+    fn default() -> Self {
+        Self {
+            refresh_nonce: false,
+            max_refreshes: 2,
+        }
+    }
+}
+
+/// A client-wide policy allows a single repair.
+impl From<RecoveryPolicy> for SubmitOptions {
+    fn from(policy: RecoveryPolicy) -> Self {
+        Self {
+            refresh_nonce: policy.refresh_nonce,
+            max_refreshes: 1,
+        }
+    }
+}
+
+/// A recovery the client performed, reported to
+/// [`Hook::on_recovery`](crate::client::Hook::on_recovery) before the retry
+/// is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryEvent {
+    /// The payload was re-signed with the account's current nonce.
+    NonceRefreshed {
+        /// Endpoint the payload is submitted to.
+        endpoint: &'static str,
+        /// Nonce the payload was rejected with.
+        rejected: u64,
+        /// Nonce it is resubmitted with.
+        current: u64,
+    },
+}
+
+impl Client {
+    /// Sign `payload`, submit it and apply the client's recovery policy if
+    /// the L1 rejects it.
+    ///
+    /// At most one retry is made. If the repair itself fails, for instance
+    /// because the nonce lookup errors or the nonce did not actually move,
+    /// the original rejection is returned.
+    pub(crate) async fn sign_and_submit<P>(
+        &self,
+        payload: P,
+        private_key: &str,
+    ) -> Result<TransactionResponse>
+    where
+        P: TransactionPayload,
+    {
+        let options = self
+            .inner
+            .recovery
+            .map(SubmitOptions::from)
+            .unwrap_or_else(|| SubmitOptions::new().max_refreshes(0));
+        self.submit_with_options(payload, private_key, options)
+            .await
+    }
+
+    /// Sign and submit any payload, repairing rejections as `options` allow.
+    ///
+    /// Each time the L1 rejects the submission for a reason `options`
+    /// covers, the stale state is refreshed, the payload signed again and
+    /// resubmitted, until it is accepted or `max_refreshes` repairs were
+    /// made. Every repair is reported to
+    /// [`Hook::on_recovery`](crate::client::Hook::on_recovery) first. If a
+    /// repair cannot be made, for instance because the nonce lookup errors or
+    /// the nonce did not actually move, the last rejection is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The payload to sign and submit
+    /// * `private_key` - Private key of the signing account
+    /// * `options` - Which rejections to repair, and how often
+    ///
+    /// # Returns
+    ///
+    /// The hash of the accepted transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{Client, PaymentPayload};
+    /// use onemoney_protocol::client::SubmitOptions;
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let payload = PaymentPayload {
+    ///         chain_id: 1212101,
+    ///         nonce: 0,
+    ///         recipient: Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?,
+    ///         value: U256::from(1000u64),
+    ///         token: Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?,
+    ///     };
+    ///
+    ///     let options = SubmitOptions::new().max_refreshes(3);
+    ///     let response = client
+    ///         .submit_with_options(payload, "0x...", options)
+    ///         .await?;
+    ///     println!("Submitted {}", response.hash);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn submit_with_options<P>(
+        &self,
+        mut payload: P,
+        private_key: &str,
+        options: SubmitOptions,
+    ) -> Result<TransactionResponse>
+    where
+        P: TransactionPayload,
+    {
+        let path = api_path(P::ENDPOINT);
+        let mut refreshes = 0;
+        loop {
+            let (signature, envelope) = self.sign_payload(&payload, private_key)?;
+            let request = SignedSubmission {
+                payload: &payload,
+                signature,
+            };
+            let error = match self.submit(&path, &request, &envelope).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+            if refreshes >= options.max_refreshes {
+                return Err(error);
+            }
+
+            match error.root() {
+                Error::NonceMismatch { .. } if options.refresh_nonce => {
+                    let signer =
+                        signing_key_address(&SecretKeyMaterial::from(private_key).signing_key()?);
+                    let Ok(account) = self.get_account_nonce(signer).await else {
+                        return Err(error);
+                    };
+                    if account.nonce == payload.nonce() {
+                        return Err(error);
+                    }
+                    self.emit_recovery(&RecoveryEvent::NonceRefreshed {
+                        endpoint: P::ENDPOINT,
+                        rejected: payload.nonce(),
+                        current: account.nonce,
+                    });
+                    payload.set_nonce(account.nonce);
+                }
+                _ => return Err(error),
+            }
+            refreshes += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_policy_defaults_and_setters() {
+        assert!(!RecoveryPolicy::new().refresh_nonce);
+        let policy = RecoveryPolicy::new().refresh_nonce(true);
+        assert!(policy.refresh_nonce);
+
+        let options = SubmitOptions::from(policy);
+        assert!(options.refresh_nonce);
+        assert_eq!(options.max_refreshes, 1);
+        assert_eq!(SubmitOptions::new().max_refreshes, 2);
+    }
+
+    #[test]
+    fn test_recovery_error_codes_are_typed() {
+        let nonce = Client::test_classify_error(422, "business_nonce_too_low", "Nonce 3 < 5");
+        assert!(nonce.is_nonce_mismatch());
+        let nonce = Client::test_classify_error(400, "validation_nonce", "Bad nonce");
+        assert!(matches!(nonce, Error::NonceMismatch { .. }));
+
+        let stale = Client::test_classify_error(422, "business_stale_checkpoint", "Too old");
+        assert!(stale.is_stale_checkpoint());
+        assert!(!stale.is_nonce_mismatch());
+        let stale = Client::test_classify_error(422, "business_rejected", "Checkpoint too old");
+        assert!(stale.is_stale_checkpoint());
+    }
+}
@@ -0,0 +1,1023 @@
+//! Client settings read from configuration files and the environment.
+//!
+//! Files and environment variables use the same keys: `retry.max_retries` in
+//! a file is `ONEMONEY_RETRY_MAX_RETRIES` in the environment. Durations are
+//! given in milliseconds.
+//!
+//! ```toml
+//! network = "testnet"
+//! endpoints = ["https://fallback.example.com"]
+//!
+//! [timeouts]
+//! request_ms = 10000
+//! submit_ms = 30000
+//!
+//! [retry]
+//! max_retries = 5
+//! initial_delay_ms = 200
+//!
+//! [rate_limit]
+//! reads_per_second = 20
+//!
+//! [auth]
+//! api_key = "..."
+//! ```
+//!
+//! [`ClientBuilder::from_config`] loads a file and the environment in a
+//! fixed order, each layer winning key by key over the one before:
+//!
+//! 1. the builder's defaults,
+//! 2. the settings file,
+//! 3. `ONEMONEY_*` environment variables,
+//! 4. builder methods called on the returned builder.
+//!
+//! Other layerings can be built with [`ClientSettings::merge`] and
+//! [`ClientSettings::apply`].
+
+use super::builder::ClientBuilder;
+use super::config::Network;
+use super::http::Client;
+use crate::transport::{
+    AuthScheme, ExponentialBackoff, RateLimit, RateLimitConfig, RetryPolicy, TimeoutProfile,
+};
+use crate::{ConfigError, Error, Result};
+use reqwest::header::HeaderName;
+use serde_json::Value;
+use std::env;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::Path;
+use std::result::Result as StdResult;
+use std::str::FromStr;
+use std::time::Duration;
+use url::Url;
+use zeroize::Zeroizing;
+
+/// Prefix of the environment variables read by [`ClientSettings::from_env`].
+pub const ENV_PREFIX: &str = "ONEMONEY_";
+
+/// Every setting key, as written in configuration files.
+pub const SETTING_KEYS: [&str; 22] = [
+    "network",
+    "base_url",
+    "endpoints",
+    "chain_id",
+    "proxy",
+    "timeouts.request_ms",
+    "timeouts.read_ms",
+    "timeouts.checkpoint_ms",
+    "timeouts.submit_ms",
+    "timeouts.connect_ms",
+    "retry.max_retries",
+    "retry.initial_delay_ms",
+    "retry.max_delay_ms",
+    "retry.multiplier",
+    "retry.retry_writes",
+    "rate_limit.reads_per_second",
+    "rate_limit.reads_burst",
+    "rate_limit.writes_per_second",
+    "rate_limit.writes_burst",
+    "auth.bearer_token",
+    "auth.api_key",
+    "auth.api_key_header",
+];
+
+/// Older name of `ONEMONEY_BASE_URL`.
+const API_URL_VAR: &str = "ONEMONEY_API_URL";
+
+/// Request timeout in whole seconds, read as `timeouts.request_ms`.
+const TIMEOUT_SECS_VAR: &str = "ONEMONEY_TIMEOUT";
+
+/// Shorter name of `ONEMONEY_TIMEOUTS_REQUEST_MS`.
+const TIMEOUT_MS_VAR: &str = "ONEMONEY_TIMEOUT_MS";
+
+/// Environment variable holding the setting `key`.
+pub fn env_var_name(key: &str) -> String {
+    format!(
+        "{}{}",
+        ENV_PREFIX,
+        key.replace('.', "_").to_ascii_uppercase()
+    )
+}
+
+/// Client configuration loaded from a file or the environment.
+///
+/// Every setting is optional; unset settings keep the builder's defaults.
+#[derive(Clone, Default)]
+pub struct ClientSettings {
+    network: Option<Network>,
+    endpoints: Option<Vec<String>>,
+    chain_id: Option<u64>,
+    proxy: Option<String>,
+    request_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    checkpoint_timeout: Option<Duration>,
+    submit_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    max_retries: Option<u32>,
+    retry_initial_delay: Option<Duration>,
+    retry_max_delay: Option<Duration>,
+    retry_multiplier: Option<f64>,
+    retry_writes: Option<bool>,
+    reads_per_second: Option<u32>,
+    reads_burst: Option<u32>,
+    writes_per_second: Option<u32>,
+    writes_burst: Option<u32>,
+    auth: AuthSettings,
+}
+
+#[derive(Clone, Default)]
+struct AuthSettings {
+    bearer_token: Option<Zeroizing<String>>,
+    api_key: Option<Zeroizing<String>>,
+    api_key_header: Option<String>,
+}
+
+impl AuthSettings {
+    fn is_empty(&self) -> bool {
+        self.bearer_token.is_none() && self.api_key.is_none() && self.api_key_header.is_none()
+    }
+
+    fn scheme(self) -> Result<Option<AuthScheme>> {
+        let bearer = self.bearer_token.is_some();
+        let api_key = self.api_key.is_some() || self.api_key_header.is_some();
+        if bearer && api_key {
+            return Err(ConfigError::invalid_setting(
+                "auth",
+                "Set only one of bearer_token or api_key",
+            )
+            .into());
+        }
+
+        if let Some(token) = self.bearer_token {
+            return Ok(Some(AuthScheme::bearer(token.as_str())));
+        }
+        if !api_key {
+            return Ok(None);
+        }
+        let key = self.api_key.ok_or_else(|| {
+            ConfigError::invalid_setting("auth.api_key_header", "Requires auth.api_key")
+        })?;
+        Ok(Some(match self.api_key_header {
+            Some(header) => AuthScheme::api_key_header(header, key.as_str()),
+            None => AuthScheme::api_key(key.as_str()),
+        }))
+    }
+}
+
+impl Debug for ClientSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ClientSettings")
+            .field("network", &self.network)
+            .field("endpoints", &self.endpoints)
+            .field("chain_id", &self.chain_id)
+            .field("proxy", &self.proxy.as_ref().map(|_| "<redacted>"))
+            .field("request_timeout", &self.request_timeout)
+            .field("read_timeout", &self.read_timeout)
+            .field("checkpoint_timeout", &self.checkpoint_timeout)
+            .field("submit_timeout", &self.submit_timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_initial_delay", &self.retry_initial_delay)
+            .field("retry_max_delay", &self.retry_max_delay)
+            .field("retry_multiplier", &self.retry_multiplier)
+            .field("retry_writes", &self.retry_writes)
+            .field("reads_per_second", &self.reads_per_second)
+            .field("reads_burst", &self.reads_burst)
+            .field("writes_per_second", &self.writes_per_second)
+            .field("writes_burst", &self.writes_burst)
+            .field("auth", &!self.auth.is_empty())
+            .finish()
+    }
+}
+
+impl ClientSettings {
+    /// Settings with nothing set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read settings from a file, parsed by its extension.
+    ///
+    /// `.json` files are always accepted; `.toml` files need the `toml`
+    /// feature.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|e| Error::storage(format!("Failed to read {}: {}", path.display(), e)))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(Error::invalid_parameter(
+                "path",
+                format!("Unsupported configuration format: {}", path.display()),
+            )),
+        }
+    }
+
+    /// Parse settings from a JSON object.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let document: Value = serde_json::from_str(json)
+            .map_err(|e| Error::invalid_parameter("config", e.to_string()))?;
+        Self::from_document(document)
+    }
+
+    /// Parse settings from a TOML document.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let document: Value =
+            toml::from_str(toml).map_err(|e| Error::invalid_parameter("config", e.to_string()))?;
+        Self::from_document(document)
+    }
+
+    /// Read settings from the process environment.
+    ///
+    /// See [`ClientSettings::from_vars`].
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(env_vars()?)
+    }
+
+    /// Read settings from environment-style variables.
+    ///
+    /// Each setting is read from [`env_var_name`] of its key; list settings
+    /// are comma-separated. `ONEMONEY_API_URL` is accepted for
+    /// `ONEMONEY_BASE_URL`, and `ONEMONEY_TIMEOUT_MS` or `ONEMONEY_TIMEOUT`,
+    /// in whole seconds, for `ONEMONEY_TIMEOUTS_REQUEST_MS`. The canonical
+    /// names win when both are set, and `ONEMONEY_TIMEOUT_MS` wins over
+    /// `ONEMONEY_TIMEOUT`. Other variables, including unknown ones with the
+    /// prefix, are ignored.
+    pub fn from_vars<I, K, V>(vars: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut aliases = Vec::new();
+        let mut precise = Vec::new();
+        let mut entries = Vec::new();
+        for (name, value) in vars {
+            let (name, value) = (name.as_ref(), value.as_ref());
+            match name {
+                API_URL_VAR => {
+                    aliases.push((name.to_string(), "base_url".to_string(), Value::from(value)))
+                }
+                TIMEOUT_SECS_VAR => {
+                    let seconds: u64 = value.trim().parse().map_err(|e| {
+                        ConfigError::invalid_setting(name, format!("Expected whole seconds: {}", e))
+                    })?;
+                    aliases.push((
+                        name.to_string(),
+                        "timeouts.request_ms".to_string(),
+                        Value::from(seconds.saturating_mul(1000)),
+                    ));
+                }
+                TIMEOUT_MS_VAR => precise.push((
+                    name.to_string(),
+                    "timeouts.request_ms".to_string(),
+                    Value::from(value),
+                )),
+                _ => {
+                    if let Some(key) = SETTING_KEYS.iter().find(|key| env_var_name(key) == name) {
+                        entries.push((name.to_string(), key.to_string(), Value::from(value)));
+                    }
+                }
+            }
+        }
+        aliases.extend(precise);
+        aliases.extend(entries);
+        Self::from_entries(aliases)
+    }
+
+    /// Layer `other` over these settings: every setting `other` sets wins.
+    ///
+    /// The network and base URL replace each other, and authentication is
+    /// taken as a whole from `other` when it sets any part of it.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            network: other.network.or(self.network),
+            endpoints: other.endpoints.or(self.endpoints),
+            chain_id: other.chain_id.or(self.chain_id),
+            proxy: other.proxy.or(self.proxy),
+            request_timeout: other.request_timeout.or(self.request_timeout),
+            read_timeout: other.read_timeout.or(self.read_timeout),
+            checkpoint_timeout: other.checkpoint_timeout.or(self.checkpoint_timeout),
+            submit_timeout: other.submit_timeout.or(self.submit_timeout),
+            connect_timeout: other.connect_timeout.or(self.connect_timeout),
+            max_retries: other.max_retries.or(self.max_retries),
+            retry_initial_delay: other.retry_initial_delay.or(self.retry_initial_delay),
+            retry_max_delay: other.retry_max_delay.or(self.retry_max_delay),
+            retry_multiplier: other.retry_multiplier.or(self.retry_multiplier),
+            retry_writes: other.retry_writes.or(self.retry_writes),
+            reads_per_second: other.reads_per_second.or(self.reads_per_second),
+            reads_burst: other.reads_burst.or(self.reads_burst),
+            writes_per_second: other.writes_per_second.or(self.writes_per_second),
+            writes_burst: other.writes_burst.or(self.writes_burst),
+            auth: if other.auth.is_empty() {
+                self.auth
+            } else {
+                other.auth
+            },
+        }
+    }
+
+    /// Configure `builder` with every setting that is set.
+    ///
+    /// Fails on combinations no single key can be blamed for, such as a
+    /// burst without a rate or two authentication schemes.
+    pub fn apply(self, mut builder: ClientBuilder) -> Result<ClientBuilder> {
+        if let Some(network) = self.network {
+            builder = builder.network(network);
+        }
+        for endpoint in self.endpoints.unwrap_or_default() {
+            builder = builder.endpoint(endpoint);
+        }
+        if let Some(chain_id) = self.chain_id {
+            builder = builder.chain_id(chain_id);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+
+        let specific = [
+            self.read_timeout,
+            self.checkpoint_timeout,
+            self.submit_timeout,
+            self.connect_timeout,
+        ];
+        if specific.iter().any(Option::is_some) {
+            let base = self
+                .request_timeout
+                .map(TimeoutProfile::uniform)
+                .unwrap_or_default();
+            builder = builder.timeouts(TimeoutProfile {
+                read: self.read_timeout.unwrap_or(base.read),
+                checkpoint: self.checkpoint_timeout.unwrap_or(base.checkpoint),
+                submit: self.submit_timeout.unwrap_or(base.submit),
+                connect: self.connect_timeout.unwrap_or(base.connect),
+            });
+        } else if let Some(timeout) = self.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let backoff_set = self.retry_initial_delay.is_some()
+            || self.retry_max_delay.is_some()
+            || self.retry_multiplier.is_some();
+        if backoff_set || self.max_retries.is_some() || self.retry_writes.is_some() {
+            let mut policy = RetryPolicy::new();
+            if let Some(retries) = self.max_retries {
+                policy = policy.max_retries(retries);
+            }
+            if backoff_set {
+                let defaults = ExponentialBackoff::default();
+                policy = policy.backoff(ExponentialBackoff {
+                    initial: self.retry_initial_delay.unwrap_or(defaults.initial),
+                    multiplier: self.retry_multiplier.unwrap_or(defaults.multiplier),
+                    max: self.retry_max_delay.unwrap_or(defaults.max),
+                });
+            }
+            if let Some(enabled) = self.retry_writes {
+                policy = policy.retry_writes(enabled);
+            }
+            builder = builder.retry_policy(policy);
+        }
+
+        let reads = rate_limit("rate_limit.reads", self.reads_per_second, self.reads_burst)?;
+        let writes = rate_limit(
+            "rate_limit.writes",
+            self.writes_per_second,
+            self.writes_burst,
+        )?;
+        if reads.is_some() || writes.is_some() {
+            builder = builder.rate_limit(RateLimitConfig { reads, writes });
+        }
+
+        if let Some(scheme) = self.auth.scheme()? {
+            builder = builder.auth(scheme);
+        }
+        Ok(builder)
+    }
+
+    fn from_document(document: Value) -> Result<Self> {
+        if !document.is_object() {
+            return Err(Error::invalid_parameter(
+                "config",
+                "Expected a table of settings",
+            ));
+        }
+        let mut entries = Vec::new();
+        flatten("", document, &mut entries);
+        Self::from_entries(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.clone(), key, value))
+                .collect(),
+        )
+    }
+
+    /// Apply `(name, key, value)` entries in order, reporting errors against
+    /// `name`, the key as the caller wrote it.
+    fn from_entries(entries: Vec<(String, String, Value)>) -> Result<Self> {
+        let mut settings = Self::default();
+        let mut network_name = None;
+        let mut base_url_name = None;
+        for (name, key, value) in entries {
+            settings
+                .set(&key, &value)
+                .map_err(|message| ConfigError::invalid_setting(&name, message))?;
+            match key.as_str() {
+                "network" => network_name = Some(name),
+                "base_url" => base_url_name = Some(name),
+                _ => {}
+            }
+        }
+        if let (Some(network), Some(base_url)) = (network_name, base_url_name) {
+            return Err(ConfigError::invalid_setting(
+                base_url,
+                format!("Conflicts with {}; set only one", network),
+            )
+            .into());
+        }
+        Ok(settings)
+    }
+
+    fn set(&mut self, key: &str, value: &Value) -> StdResult<(), String> {
+        match key {
+            "network" => {
+                self.network = Some(match text(value)?.to_ascii_lowercase().as_str() {
+                    "mainnet" => Network::Mainnet,
+                    "testnet" => Network::Testnet,
+                    "local" => Network::Local,
+                    other => {
+                        return Err(format!(
+                            "Unknown network {:?}; expected mainnet, testnet or local",
+                            other
+                        ));
+                    }
+                })
+            }
+            "base_url" => self.network = Some(Network::custom(url(text(value)?)?)),
+            "endpoints" => {
+                self.endpoints = Some(
+                    list(value)?
+                        .into_iter()
+                        .map(|endpoint| url(&endpoint))
+                        .collect::<StdResult<_, _>>()?,
+                )
+            }
+            "chain_id" => self.chain_id = Some(number(value)?),
+            "proxy" => self.proxy = Some(url(text(value)?)?),
+            "timeouts.request_ms" => self.request_timeout = Some(millis(value)?),
+            "timeouts.read_ms" => self.read_timeout = Some(millis(value)?),
+            "timeouts.checkpoint_ms" => self.checkpoint_timeout = Some(millis(value)?),
+            "timeouts.submit_ms" => self.submit_timeout = Some(millis(value)?),
+            "timeouts.connect_ms" => self.connect_timeout = Some(millis(value)?),
+            "retry.max_retries" => self.max_retries = Some(number(value)?),
+            "retry.initial_delay_ms" => self.retry_initial_delay = Some(millis(value)?),
+            "retry.max_delay_ms" => self.retry_max_delay = Some(millis(value)?),
+            "retry.multiplier" => {
+                let multiplier: f64 = number(value)?;
+                if !multiplier.is_finite() || multiplier < 1.0 {
+                    return Err("Must be a finite number of at least 1".to_string());
+                }
+                self.retry_multiplier = Some(multiplier);
+            }
+            "retry.retry_writes" => self.retry_writes = Some(boolean(value)?),
+            "rate_limit.reads_per_second" => self.reads_per_second = Some(positive(value)?),
+            "rate_limit.reads_burst" => self.reads_burst = Some(positive(value)?),
+            "rate_limit.writes_per_second" => self.writes_per_second = Some(positive(value)?),
+            "rate_limit.writes_burst" => self.writes_burst = Some(positive(value)?),
+            "auth.bearer_token" => {
+                self.auth.bearer_token = Some(Zeroizing::new(non_empty(value)?.to_string()))
+            }
+            "auth.api_key" => {
+                self.auth.api_key = Some(Zeroizing::new(non_empty(value)?.to_string()))
+            }
+            "auth.api_key_header" => {
+                let header = text(value)?;
+                HeaderName::from_bytes(header.as_bytes())
+                    .map_err(|e| format!("{:?} is not a header name: {}", header, e))?;
+                self.auth.api_key_header = Some(header.to_string());
+            }
+            _ => return Err("Unknown setting".to_string()),
+        }
+        Ok(())
+    }
+}
+
+impl ClientBuilder {
+    /// Create a builder configured from a settings file alone.
+    ///
+    /// Methods called on the returned builder override the file. Use
+    /// [`ClientBuilder::from_config`] to let the environment override it
+    /// too.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `.json` file, or a `.toml` file with the `toml` feature
+    ///
+    /// # Returns
+    ///
+    /// A builder with every setting from the file applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::ClientBuilder;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ClientBuilder::from_config_file("onemoney.json")?.build()?;
+    ///     println!("Connected to {:?}", client);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        ClientSettings::from_file(path)?.apply(Self::new())
+    }
+
+    /// Create a builder configured from `ONEMONEY_*` environment variables
+    /// alone.
+    ///
+    /// Methods called on the returned builder override the environment.
+    ///
+    /// # Returns
+    ///
+    /// A builder with every setting from the environment applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::ClientBuilder;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     // ONEMONEY_NETWORK=testnet ONEMONEY_RETRY_MAX_RETRIES=5
+    ///     let client = ClientBuilder::from_env()?.build()?;
+    ///     println!("Connected to {:?}", client);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        ClientSettings::from_env()?.apply(Self::new())
+    }
+
+    /// Create a builder configured from a settings file overridden by
+    /// `ONEMONEY_*` environment variables.
+    ///
+    /// Settings apply in this order, each winning over the ones before:
+    /// the builder's defaults, the file, the environment, then methods
+    /// called on the returned builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `.json` file, or a `.toml` file with the `toml` feature
+    ///
+    /// # Returns
+    ///
+    /// A builder with the file and the environment applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     // ONEMONEY_RETRY_MAX_RETRIES=5 overrides the file's retry.max_retries.
+    ///     let client = ClientBuilder::from_config("onemoney.json")?
+    ///         .timeout(Duration::from_secs(5))
+    ///         .build()?;
+    ///     println!("Connected to {:?}", client);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_config<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_config_and_vars(path, env_vars()?)
+    }
+
+    /// [`ClientBuilder::from_config`] over the given variables instead of
+    /// the process environment.
+    pub fn from_config_and_vars<P, I, K, V>(path: P, vars: I) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        ClientSettings::from_file(path)?
+            .merge(ClientSettings::from_vars(vars)?)
+            .apply(Self::new())
+    }
+}
+
+impl Client {
+    /// Build a client from `ONEMONEY_*` environment variables and check
+    /// that the gateway serves the expected chain.
+    ///
+    /// Every variable [`ClientSettings::from_vars`] reads applies, including
+    /// `ONEMONEY_TIMEOUT_MS` and the `ONEMONEY_AUTH_*` credentials. Unlike
+    /// [`ClientBuilder::from_env`], which falls back to mainnet, a network
+    /// must be chosen with `ONEMONEY_NETWORK` or `ONEMONEY_BASE_URL`, and
+    /// the gateway is asked for its chain ID before the client is returned.
+    /// When neither the network nor `ONEMONEY_CHAIN_ID` fixes the chain, the
+    /// chain the gateway reports becomes the expected one, so payloads
+    /// signed for another chain are rejected before submission.
+    ///
+    /// # Returns
+    ///
+    /// A client for the verified chain. Missing or inconsistent settings
+    /// fail with a [`ConfigError`] naming the variable to fix.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     // ONEMONEY_NETWORK=testnet ONEMONEY_TIMEOUT_MS=5000
+    ///     let client = Client::from_env().await?;
+    ///     println!("Connected to chain {}", client.predefined_chain_id());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn from_env() -> Result<Self> {
+        Self::from_vars(env_vars()?).await
+    }
+
+    /// [`Client::from_env`] over the given variables instead of the process
+    /// environment.
+    pub async fn from_vars<I, K, V>(vars: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let settings = ClientSettings::from_vars(vars)?;
+        let network_var = match &settings.network {
+            Some(Network::Custom { .. }) => env_var_name("base_url"),
+            Some(_) => env_var_name("network"),
+            None => {
+                return Err(ConfigError::missing_config(format!(
+                    "No network selected; set {} to mainnet, testnet or local, or {} to a gateway URL",
+                    env_var_name("network"),
+                    env_var_name("base_url")
+                ))
+                .into());
+            }
+        };
+        let chain_var = if settings.chain_id.is_some() {
+            env_var_name("chain_id")
+        } else {
+            network_var.clone()
+        };
+
+        let client = settings.clone().apply(ClientBuilder::new())?.build()?;
+        let gateway = client.base_url().to_string();
+        let chain_id = client.fetch_chain_id_from_network().await.map_err(|error| {
+            ConfigError::invalid_setting(
+                &network_var,
+                format!(
+                    "Could not read the chain ID from {}: {}; check the URL, credentials and network access",
+                    gateway, error
+                ),
+            )
+        })?;
+        match client.inner.expected_chain_id {
+            Some(expected) if expected != chain_id => Err(ConfigError::invalid_setting(
+                chain_var,
+                format!(
+                    "{} serves chain {}, not the expected chain {}; use a gateway for chain {} or correct the setting",
+                    gateway, chain_id, expected, expected
+                ),
+            )
+            .into()),
+            Some(_) => Ok(client),
+            None => settings
+                .apply(ClientBuilder::new())?
+                .chain_id(chain_id)
+                .build(),
+        }
+    }
+}
+
+/// The `ONEMONEY_*` variables of the process environment.
+fn env_vars() -> Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+    for (name, value) in env::vars_os() {
+        let Some(name) = name.to_str().filter(|name| name.starts_with(ENV_PREFIX)) else {
+            continue;
+        };
+        let value = value
+            .into_string()
+            .map_err(|_| ConfigError::invalid_setting(name, "Value is not valid UTF-8"))?;
+        vars.push((name.to_string(), value));
+    }
+    Ok(vars)
+}
+
+/// Flatten nested tables into dotted keys.
+fn flatten(prefix: &str, value: Value, entries: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields {
+                let key = if prefix.is_empty() {
+                    name
+                } else {
+                    format!("{}.{}", prefix, name)
+                };
+                flatten(&key, field, entries);
+            }
+        }
+        other => entries.push((prefix.to_string(), other)),
+    }
+}
+
+fn rate_limit(
+    prefix: &str,
+    per_second: Option<u32>,
+    burst: Option<u32>,
+) -> Result<Option<RateLimit>> {
+    match (per_second, burst) {
+        (Some(requests), burst) => {
+            let limit = RateLimit::per_second(requests);
+            Ok(Some(match burst {
+                Some(burst) => limit.burst(burst),
+                None => limit,
+            }))
+        }
+        (None, Some(_)) => Err(ConfigError::invalid_setting(
+            format!("{}_burst", prefix),
+            format!("Requires {}_per_second", prefix),
+        )
+        .into()),
+        (None, None) => Ok(None),
+    }
+}
+
+fn text(value: &Value) -> StdResult<&str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("Expected a string, got {}", value))
+}
+
+fn non_empty(value: &Value) -> StdResult<&str, String> {
+    let text = text(value)?;
+    if text.trim().is_empty() {
+        return Err("Must not be empty".to_string());
+    }
+    Ok(text)
+}
+
+fn number<T>(value: &Value) -> StdResult<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let text = match value {
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => text.trim().to_string(),
+        other => return Err(format!("Expected a number, got {}", other)),
+    };
+    text.parse()
+        .map_err(|e| format!("{:?} is not a valid number: {}", text, e))
+}
+
+fn positive(value: &Value) -> StdResult<u32, String> {
+    match number(value)? {
+        0 => Err("Must be greater than zero".to_string()),
+        count => Ok(count),
+    }
+}
+
+fn millis(value: &Value) -> StdResult<Duration, String> {
+    match number(value)? {
+        0 => Err("Must be greater than zero".to_string()),
+        millis => Ok(Duration::from_millis(millis)),
+    }
+}
+
+fn boolean(value: &Value) -> StdResult<bool, String> {
+    match value {
+        Value::Bool(flag) => Ok(*flag),
+        Value::String(text) => match text.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" => Ok(true),
+            "false" | "0" | "no" => Ok(false),
+            _ => Err(format!("{:?} is not a boolean", text)),
+        },
+        other => Err(format!("Expected a boolean, got {}", other)),
+    }
+}
+
+fn list(value: &Value) -> StdResult<Vec<String>, String> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| text(item).map(str::to_string))
+            .collect(),
+        Value::String(text) => Ok(text
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()),
+        other => Err(format!("Expected a list, got {}", other)),
+    }
+}
+
+fn url(text: &str) -> StdResult<String, String> {
+    let parsed = Url::parse(text).map_err(|e| format!("{:?} is not a URL: {}", text, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("{:?} must be an http or https URL", text));
+    }
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    fn setting_error(result: Result<ClientSettings>) -> (String, String) {
+        match result {
+            Err(Error::Config(ConfigError::InvalidSetting { key, message })) => (key, message),
+            other => panic!("Expected an invalid setting, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_environment_overrides_file() {
+        let file = ClientSettings::from_json(
+            r#"{
+                "network": "testnet",
+                "timeouts": {"request_ms": 10000, "submit_ms": 30000},
+                "retry": {"max_retries": 5},
+                "auth": {"api_key": "file-key"}
+            }"#,
+        )
+        .expect("File should parse");
+        let env = ClientSettings::from_vars([
+            ("ONEMONEY_API_URL", "https://old.example.com"),
+            ("ONEMONEY_BASE_URL", "https://gateway.example.com"),
+            (
+                "ONEMONEY_ENDPOINTS",
+                "https://a.example.com, https://b.example.com",
+            ),
+            ("ONEMONEY_RETRY_MAX_RETRIES", "2"),
+            ("ONEMONEY_AUTH_BEARER_TOKEN", "env-token"),
+            ("ONEMONEY_EXAMPLE_ENV", "ignored"),
+            ("PATH", "/usr/bin"),
+        ])
+        .expect("Variables should parse");
+
+        let merged = file.merge(env);
+        assert_eq!(
+            merged.network,
+            Some(Network::custom("https://gateway.example.com"))
+        );
+        assert_eq!(merged.endpoints.as_ref().map(Vec::len), Some(2));
+        assert_eq!(merged.request_timeout, Some(Duration::from_secs(10)));
+        assert_eq!(merged.submit_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(merged.max_retries, Some(2));
+        assert!(merged.auth.api_key.is_none());
+        assert!(merged.auth.bearer_token.is_some());
+
+        let client = merged
+            .apply(ClientBuilder::new())
+            .expect("Settings should apply")
+            .build()
+            .expect("Client should build");
+        assert!(format!("{:?}", client).contains("gateway.example.com"));
+        for key in SETTING_KEYS {
+            assert!(env_var_name(key).starts_with(ENV_PREFIX));
+        }
+    }
+
+    #[test]
+    fn test_from_config_layers_file_env_and_builder() {
+        let path = env::temp_dir().join(format!("onemoney-settings-{}.json", process::id()));
+        fs::write(&path, r#"{"network": "testnet"}"#).expect("File should write");
+        let vars = [("ONEMONEY_BASE_URL", "https://gateway.example.com")];
+
+        let from_file = ClientBuilder::from_config_and_vars(&path, Vec::<(&str, &str)>::new())
+            .expect("Settings should load")
+            .build()
+            .expect("Client should build");
+        let from_env = ClientBuilder::from_config_and_vars(&path, vars)
+            .expect("Settings should load")
+            .build()
+            .expect("Client should build");
+        let overridden = ClientBuilder::from_config_and_vars(&path, vars)
+            .expect("Settings should load")
+            .network(Network::Local)
+            .build()
+            .expect("Client should build");
+        fs::remove_file(&path).expect("File should be removed");
+
+        let url = |network: Network| Url::parse(network.url()).expect("Network URL should parse");
+        assert_eq!(*from_file.base_url(), url(Network::Testnet));
+        assert_eq!(from_env.base_url().as_str(), "https://gateway.example.com/");
+        assert_eq!(*overridden.base_url(), url(Network::Local));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_settings_from_toml() {
+        let settings = ClientSettings::from_toml(
+            "network = \"local\"\n[rate_limit]\nreads_per_second = 20\nreads_burst = 40\n",
+        )
+        .expect("TOML should parse");
+        assert_eq!(settings.network, Some(Network::Local));
+        assert_eq!(settings.reads_burst, Some(40));
+        assert!(
+            ClientSettings::from_toml("network = [")
+                .expect_err("Invalid TOML")
+                .to_string()
+                .contains("config")
+        );
+    }
+
+    #[test]
+    fn test_errors_name_the_offending_key() {
+        assert_eq!(
+            setting_error(ClientSettings::from_json(r#"{"timeouts": {"read_ms": 0}}"#)).0,
+            "timeouts.read_ms"
+        );
+        assert_eq!(
+            setting_error(ClientSettings::from_json(r#"{"retry": {"max_retry": 1}}"#)),
+            ("retry.max_retry".to_string(), "Unknown setting".to_string())
+        );
+        assert_eq!(
+            setting_error(ClientSettings::from_json(
+                r#"{"network": "mainnet", "base_url": "https://gateway.example.com"}"#
+            ))
+            .0,
+            "base_url"
+        );
+        assert_eq!(
+            setting_error(ClientSettings::from_vars([(
+                "ONEMONEY_RATE_LIMIT_READS_PER_SECOND",
+                "many"
+            )]))
+            .0,
+            "ONEMONEY_RATE_LIMIT_READS_PER_SECOND"
+        );
+        assert_eq!(
+            setting_error(ClientSettings::from_vars([("ONEMONEY_TIMEOUT", "1.5")])).0,
+            "ONEMONEY_TIMEOUT"
+        );
+
+        let burst_only =
+            ClientSettings::from_json(r#"{"rate_limit": {"writes_burst": 5}}"#).expect("Parses");
+        match burst_only.apply(ClientBuilder::new()) {
+            Err(Error::Config(ConfigError::InvalidSetting { key, .. })) => {
+                assert_eq!(key, "rate_limit.writes_burst")
+            }
+            other => panic!("Expected an invalid setting, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_from_vars_verifies_chain() {
+        let mut server = mockito::Server::new_async().await;
+        let _chain = server
+            .mock("GET", "/v1/chains/chain_id")
+            .with_status(200)
+            .with_body(r#"{"chain_id": 8}"#)
+            .create_async()
+            .await;
+
+        match Client::from_vars([("ONEMONEY_TIMEOUT_MS", "250")]).await {
+            Err(Error::Config(ConfigError::MissingConfig(message))) => {
+                assert!(message.contains("ONEMONEY_NETWORK"))
+            }
+            other => panic!("Expected missing configuration, got {:?}", other),
+        }
+
+        let url = server.url();
+        let client = Client::from_vars([
+            ("ONEMONEY_BASE_URL", url.as_str()),
+            ("ONEMONEY_TIMEOUT", "9"),
+            ("ONEMONEY_TIMEOUT_MS", "250"),
+        ])
+        .await
+        .expect("Gateway is reachable");
+        assert_eq!(
+            client.timeouts(),
+            TimeoutProfile::uniform(Duration::from_millis(250))
+        );
+        assert!(client.ensure_chain_id(8).is_ok());
+        assert!(client.ensure_chain_id(9).is_err());
+
+        let (key, message) = match Client::from_vars([
+            ("ONEMONEY_BASE_URL", url.as_str()),
+            ("ONEMONEY_CHAIN_ID", "7"),
+        ])
+        .await
+        {
+            Err(Error::Config(ConfigError::InvalidSetting { key, message })) => (key, message),
+            other => panic!("Expected an invalid setting, got {:?}", other),
+        };
+        assert_eq!(key, "ONEMONEY_CHAIN_ID");
+        assert!(message.contains("serves chain 8, not the expected chain 7"));
+    }
+}
@@ -0,0 +1,192 @@
+//! Cached view of the chain's latest epoch and checkpoint.
+//!
+//! Transaction payloads of the current protocol version are bound by chain ID
+//! and nonce only, so they do not expire with the checkpoint. The tracker
+//! serves code that reasons about chain progress, such as waiting a number of
+//! checkpoints for a transaction to land, without a round trip per check.
+
+use super::http::Client;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, interval};
+
+/// Default age after which cached chain state is refreshed.
+pub const DEFAULT_STATE_MAX_STALENESS: Duration = Duration::from_secs(5);
+
+/// Latest epoch and checkpoint as last seen by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainState {
+    /// Current epoch identifier.
+    pub epoch: u64,
+    /// Latest checkpoint number.
+    pub checkpoint: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CachedState {
+    state: ChainState,
+    fetched_at: Instant,
+}
+
+/// Keeps the latest epoch and checkpoint no older than a staleness window.
+///
+/// Reads within the window are served from memory; the first read after it
+/// refreshes from the network while concurrent readers wait for that single
+/// refresh instead of issuing their own.
+#[derive(Debug)]
+pub struct StateTracker {
+    max_staleness: Duration,
+    cached: Mutex<Option<CachedState>>,
+}
+
+impl StateTracker {
+    /// Create a tracker whose values may be at most `max_staleness` old.
+    pub fn new(max_staleness: Duration) -> Self {
+        Self {
+            max_staleness,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// The staleness window.
+    pub fn max_staleness(&self) -> Duration {
+        self.max_staleness
+    }
+
+    /// The cached state with its age, without touching the network.
+    pub async fn cached(&self) -> Option<(ChainState, Duration)> {
+        self.cached
+            .lock()
+            .await
+            .map(|cached| (cached.state, cached.fetched_at.elapsed()))
+    }
+
+    /// Drop the cached state so the next read refreshes.
+    pub async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+}
+
+impl Default for StateTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATE_MAX_STALENESS)
+    }
+}
+
+impl Client {
+    /// The tracker caching this client's view of the chain state.
+    pub fn state_tracker(&self) -> &StateTracker {
+        &self.inner.state
+    }
+
+    /// Latest epoch and checkpoint, refreshed when older than the staleness window.
+    ///
+    /// # Returns
+    ///
+    /// The cached chain state, or a freshly fetched one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let state = client.chain_state().await?;
+    ///     println!("Epoch {} at checkpoint {}", state.epoch, state.checkpoint);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn chain_state(&self) -> Result<ChainState> {
+        let mut cached = self.inner.state.cached.lock().await;
+        if let Some(current) = *cached
+            && current.fetched_at.elapsed() <= self.inner.state.max_staleness
+        {
+            return Ok(current.state);
+        }
+        let state = self.fetch_chain_state().await?;
+        *cached = Some(CachedState {
+            state,
+            fetched_at: Instant::now(),
+        });
+        Ok(state)
+    }
+
+    /// Fetch the latest epoch and checkpoint and replace the cached state.
+    ///
+    /// # Returns
+    ///
+    /// The freshly fetched chain state.
+    pub async fn refresh_chain_state(&self) -> Result<ChainState> {
+        let mut cached = self.inner.state.cached.lock().await;
+        let state = self.fetch_chain_state().await?;
+        *cached = Some(CachedState {
+            state,
+            fetched_at: Instant::now(),
+        });
+        Ok(state)
+    }
+
+    /// Refresh the chain state every `period` on a background task.
+    ///
+    /// Keeps [`Client::chain_state`] answering from memory for callers on the
+    /// hot path. Failed refreshes are skipped and retried on the next tick;
+    /// abort the returned handle to stop refreshing. The task holds a clone
+    /// of the client, so the refreshed state is visible to every clone.
+    pub fn spawn_state_refresh(&self, period: Duration) -> JoinHandle<()> {
+        let client = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            loop {
+                ticker.tick().await;
+                let _ = client.refresh_chain_state().await;
+            }
+        })
+    }
+
+    async fn fetch_chain_state(&self) -> Result<ChainState> {
+        let epoch = self.get_current_epoch().await?;
+        let checkpoint = self.get_checkpoint_number().await?;
+        Ok(ChainState {
+            epoch: epoch.epoch_id,
+            checkpoint: checkpoint.number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_state_tracker_cache() {
+        let tracker = StateTracker::new(Duration::from_secs(1));
+        assert_eq!(tracker.max_staleness(), Duration::from_secs(1));
+        assert!(tracker.cached().await.is_none());
+
+        let state = ChainState {
+            epoch: 3,
+            checkpoint: 120,
+        };
+        *tracker.cached.lock().await = Some(CachedState {
+            state,
+            fetched_at: Instant::now(),
+        });
+        let (cached, age) = tracker.cached().await.expect("State was cached");
+        assert_eq!(cached, state);
+        assert!(age < Duration::from_secs(1));
+
+        tracker.invalidate().await;
+        assert!(tracker.cached().await.is_none());
+        assert_eq!(
+            StateTracker::default().max_staleness(),
+            DEFAULT_STATE_MAX_STALENESS
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Incremental deserialization of large JSON list responses.
+
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanState {
+    /// Looking for the array under the wanted key.
+    Seeking,
+    /// Inside the array, collecting elements.
+    InArray,
+    /// The array has been closed.
+    Done,
+}
+
+/// Splits the elements of one array, found under a top-level object key,
+/// out of a JSON document fed in arbitrary chunks.
+#[derive(Debug)]
+pub(crate) struct ArrayScanner {
+    key: &'static str,
+    state: ScanState,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    token: Vec<u8>,
+    last_key: Option<Vec<u8>>,
+    element: Vec<u8>,
+    max_element_bytes: Option<usize>,
+}
+
+impl ArrayScanner {
+    pub(crate) fn new(key: &'static str, max_element_bytes: Option<usize>) -> Self {
+        Self {
+            key,
+            state: ScanState::Seeking,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            token: Vec::new(),
+            last_key: None,
+            element: Vec::new(),
+            max_element_bytes,
+        }
+    }
+
+    /// Whether the array has been read completely.
+    pub(crate) fn is_done(&self) -> bool {
+        self.state == ScanState::Done
+    }
+
+    /// Consume a chunk, appending every element completed by it to `out`.
+    pub(crate) fn feed(&mut self, chunk: &[u8], out: &mut VecDeque<Vec<u8>>) -> Result<()> {
+        for &byte in chunk {
+            match self.state {
+                ScanState::Seeking => self.seek(byte),
+                ScanState::InArray => self.collect(byte, out)?,
+                ScanState::Done => break,
+            }
+        }
+        Ok(())
+    }
+
+    fn seek(&mut self, byte: u8) {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+                if self.depth == 1 {
+                    self.last_key = Some(std::mem::take(&mut self.token));
+                }
+                return;
+            }
+            if self.depth == 1 {
+                self.token.push(byte);
+            }
+            return;
+        }
+
+        match byte {
+            b'"' => {
+                self.in_string = true;
+                self.token.clear();
+            }
+            b'[' if self.depth == 1 && self.last_key.as_deref() == Some(self.key.as_bytes()) => {
+                self.state = ScanState::InArray;
+                self.depth = 0;
+            }
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+            b',' if self.depth == 1 => self.last_key = None,
+            _ => {}
+        }
+    }
+
+    fn collect(&mut self, byte: u8, out: &mut VecDeque<Vec<u8>>) -> Result<()> {
+        if self.in_string {
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return self.push(byte);
+        }
+
+        match byte {
+            b',' if self.depth == 0 => self.finish_element(out),
+            b']' if self.depth == 0 => {
+                self.finish_element(out);
+                self.state = ScanState::Done;
+            }
+            b' ' | b'\n' | b'\r' | b'\t' if self.depth == 0 && self.element.is_empty() => {}
+            b'{' | b'[' => {
+                self.depth += 1;
+                self.push(byte)?;
+            }
+            b'}' | b']' => {
+                self.depth = self.depth.saturating_sub(1);
+                self.push(byte)?;
+            }
+            b'"' => {
+                self.in_string = true;
+                self.push(byte)?;
+            }
+            _ => self.push(byte)?,
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, byte: u8) -> Result<()> {
+        self.element.push(byte);
+        match self.max_element_bytes {
+            Some(limit) if self.element.len() > limit => Err(Error::response_too_large(
+                format!("element of '{}'", self.key),
+                limit,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn finish_element(&mut self, out: &mut VecDeque<Vec<u8>>) {
+        if !self.element.is_empty() {
+            out.push_back(std::mem::take(&mut self.element));
+        }
+    }
+}
+
+/// Elements of a JSON array response, deserialized one at a time as the body
+/// arrives.
+///
+/// Only the element being decoded is held in memory, so lists far larger
+/// than [`ClientBuilder::max_response_bytes`](super::ClientBuilder::max_response_bytes)
+/// can be processed; the limit applies to each element instead.
+pub struct JsonArrayStream<T> {
+    response: reqwest::Response,
+    scanner: ArrayScanner,
+    pending: VecDeque<Vec<u8>>,
+    finished: bool,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T> Debug for JsonArrayStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("JsonArrayStream")
+            .field("url", &self.response.url().as_str())
+            .field("pending", &self.pending.len())
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<T: DeserializeOwned> JsonArrayStream<T> {
+    pub(crate) fn new(response: reqwest::Response, scanner: ArrayScanner) -> Self {
+        Self {
+            response,
+            scanner,
+            pending: VecDeque::new(),
+            finished: false,
+            _element: PhantomData,
+        }
+    }
+
+    /// Decode the next element, or return `None` once the array is exhausted.
+    ///
+    /// A body that ends before the array is closed yields an error.
+    pub async fn next(&mut self) -> Option<Result<T>> {
+        loop {
+            if let Some(element) = self.pending.pop_front() {
+                return Some(serde_json::from_slice(&element).map_err(Error::from));
+            }
+            if self.finished || self.scanner.is_done() {
+                return None;
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => {
+                    if let Err(error) = self.scanner.feed(&chunk, &mut self.pending) {
+                        self.finished = true;
+                        return Some(Err(error));
+                    }
+                }
+                Ok(None) => {
+                    self.finished = true;
+                    if !self.scanner.is_done() {
+                        return Some(Err(Error::response_deserialization(
+                            "JSON",
+                            format!(
+                                "Response ended before the '{}' list was closed",
+                                self.scanner.key
+                            ),
+                            "",
+                        )));
+                    }
+                }
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error.into()));
+                }
+            }
+        }
+    }
+
+    /// Decode every remaining element.
+    pub async fn collect(mut self) -> Result<Vec<T>> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.next().await {
+            elements.push(element?);
+        }
+        Ok(elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(chunks: &[&str], limit: Option<usize>) -> Result<(Vec<String>, bool)> {
+        let mut scanner = ArrayScanner::new("items", limit);
+        let mut out = VecDeque::new();
+        for chunk in chunks {
+            scanner.feed(chunk.as_bytes(), &mut out)?;
+        }
+        let elements = out
+            .into_iter()
+            .map(|element| String::from_utf8(element).expect("Valid UTF-8"))
+            .collect();
+        Ok((elements, scanner.is_done()))
+    }
+
+    #[test]
+    fn test_scanner_splits_elements_across_chunks() {
+        let (elements, done) = scan(
+            &[
+                r#"{"name":"items","nested":{"items":[9]},"it"#,
+                r#"ems": [ {"a":[1,2],"b":"x,]\"y"}, "#,
+                r#""tail", 3 ], "after": []}"#,
+            ],
+            None,
+        )
+        .expect("Scan should succeed");
+
+        assert!(done);
+        assert_eq!(
+            elements,
+            vec![r#"{"a":[1,2],"b":"x,]\"y"}"#, r#""tail""#, "3 "]
+        );
+    }
+
+    #[test]
+    fn test_scanner_empty_and_missing_array() {
+        let (elements, done) = scan(&[r#"{"items":[]}"#], None).expect("Scan should succeed");
+        assert!(elements.is_empty());
+        assert!(done);
+
+        let (elements, done) = scan(&[r#"{"other":[1,2]}"#], None).expect("Scan should succeed");
+        assert!(elements.is_empty());
+        assert!(!done);
+    }
+
+    #[test]
+    fn test_scanner_enforces_element_limit() {
+        let result = scan(&[r#"{"items":["short","much longer element"]}"#], Some(10));
+        assert!(matches!(
+            result,
+            Err(Error::ResponseTooLarge { limit: 10, .. })
+        ));
+    }
+}
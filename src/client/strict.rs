@@ -0,0 +1,405 @@
+//! Detection of response fields the SDK does not know about, and of the
+//! field a response failed to decode at.
+//!
+//! Response types ignore unknown fields so that older SDK releases keep
+//! working as the server grows its responses. In strict mode the client
+//! decodes through [`Tracked`], which records every field a type skipped, and
+//! fails with [`Error::UnknownFields`] when there are any.
+//!
+//! Tracking reaches every struct decoded directly from the JSON tree. Fields
+//! of flattened structs and of untagged or internally tagged enums are
+//! buffered by serde before they are decoded and are not checked.
+//!
+//! When a response does not decode at all, [`decode_error`] decodes it again
+//! through [`Tracked`] to find the innermost value that was rejected.
+
+use crate::{DecodeLocation, Error, Result};
+use serde::de::value::StringDeserializer;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess,
+    Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::iter::Enumerate;
+use std::vec::IntoIter;
+
+type DeResult<T> = std::result::Result<T, serde_json::Error>;
+
+/// Decode `body` as `T`, failing with [`Error::UnknownFields`] if `T` had to
+/// skip any of its fields.
+pub(crate) fn from_str_strict<T>(endpoint: &str, body: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let (result, fields) = from_str_tracked::<T>(body)?;
+    if fields.is_empty() {
+        Ok(result)
+    } else {
+        Err(Error::unknown_fields(endpoint, fields))
+    }
+}
+
+/// Decode `body` as `T` together with the paths of the fields `T` skipped.
+pub(crate) fn from_str_tracked<T>(body: &str) -> Result<(T, Vec<String>)>
+where
+    T: DeserializeOwned,
+{
+    let value: Value =
+        serde_json::from_str(body).map_err(|error| decode_error::<T>(body, error))?;
+    let unknown = RefCell::new(Vec::new());
+    let failed = RefCell::new(None);
+    let result = T::deserialize(Tracked {
+        value,
+        path: Vec::new(),
+        unknown: &unknown,
+        failed: &failed,
+    })
+    .map_err(|error| decode_error::<T>(body, error))?;
+    Ok((result, unknown.into_inner()))
+}
+
+/// Explain why `body` did not decode as `T`.
+///
+/// Data errors are located by decoding `body` again, which only happens on
+/// this failure path; syntax errors are located by line and column.
+pub(crate) fn decode_error<T>(body: &str, error: serde_json::Error) -> Error
+where
+    T: DeserializeOwned,
+{
+    let message = error.to_string();
+    let (pointer, excerpt) = match serde_json::from_str::<Value>(body) {
+        Ok(value) if error.is_data() => {
+            let pointer = locate::<T>(value.clone());
+            let excerpt = pointer
+                .as_deref()
+                .and_then(|pointer| value.pointer(pointer))
+                .map(|found| truncate(&found.to_string(), EXCERPT_CHARS));
+            (pointer, excerpt)
+        }
+        _ => (None, excerpt_at(body, error.line(), error.column())),
+    };
+    Error::ResponseDeserialization {
+        format: "JSON".to_string(),
+        location: Some(Box::new(DecodeLocation {
+            pointer,
+            expected: expected(&message),
+            excerpt,
+        })),
+        error: message,
+        response: truncate(body, RESPONSE_CHARS),
+    }
+}
+
+/// Longest excerpt of a rejected value kept in an error.
+const EXCERPT_CHARS: usize = 80;
+
+/// Longest part of a response body kept in an error.
+const RESPONSE_CHARS: usize = 1024;
+
+/// JSON pointer to the innermost value `T` rejects in `value`.
+fn locate<T: DeserializeOwned>(value: Value) -> Option<String> {
+    let unknown = RefCell::new(Vec::new());
+    let failed = RefCell::new(None);
+    let tracked = Tracked {
+        value,
+        path: Vec::new(),
+        unknown: &unknown,
+        failed: &failed,
+    };
+    if T::deserialize(tracked).is_ok() {
+        return None;
+    }
+    // Errors raised by the top-level value itself have no parent to record them.
+    Some(failed.into_inner().unwrap_or_default())
+}
+
+/// What serde expected, from messages such as `invalid type: string "x",
+/// expected u64` or ``missing field `fee` ``.
+fn expected(message: &str) -> Option<String> {
+    let message = message
+        .rfind(" at line ")
+        .map_or(message, |position| &message[..position]);
+    if let Some((_, expected)) = message.split_once(", expected ") {
+        return Some(expected.to_string());
+    }
+    message
+        .strip_prefix("missing ")
+        .filter(|field| field.starts_with("field "))
+        .map(str::to_string)
+}
+
+/// The text around `line` and `column` of `body`, both starting at 1.
+fn excerpt_at(body: &str, line: usize, column: usize) -> Option<String> {
+    let text = body.lines().nth(line.checked_sub(1)?)?;
+    let start = column.saturating_sub(EXCERPT_CHARS / 2);
+    let excerpt: String = text.chars().skip(start).take(EXCERPT_CHARS).collect();
+    (!excerpt.is_empty()).then_some(excerpt)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// Escape a key for use as a JSON pointer segment.
+fn pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// A JSON value that reports the paths of the fields it is asked to ignore
+/// and of the first value that fails to decode.
+struct Tracked<'a> {
+    value: Value,
+    path: Vec<String>,
+    unknown: &'a RefCell<Vec<String>>,
+    failed: &'a RefCell<Option<String>>,
+}
+
+impl<'a> Tracked<'a> {
+    fn child(&self, value: Value, segment: &str) -> Self {
+        let mut path = self.path.clone();
+        path.push(segment.to_string());
+        Self {
+            value,
+            path,
+            unknown: self.unknown,
+            failed: self.failed,
+        }
+    }
+
+    /// Record that the child at `segment` failed, unless a value inside it
+    /// already did.
+    fn child_failed(&self, segment: &str) {
+        let mut failed = self.failed.borrow_mut();
+        if failed.is_none() {
+            let pointer: String = self
+                .path
+                .iter()
+                .map(String::as_str)
+                .chain([segment])
+                .map(|segment| format!("/{}", pointer_segment(segment)))
+                .collect();
+            *failed = Some(pointer);
+        }
+    }
+
+    fn detached(
+        path: Vec<String>,
+        unknown: &'a RefCell<Vec<String>>,
+        failed: &'a RefCell<Option<String>>,
+    ) -> Self {
+        Self {
+            value: Value::Null,
+            path,
+            unknown,
+            failed,
+        }
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for Tracked<'a> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        match self.value {
+            Value::Object(fields) => visitor.visit_map(TrackedMap {
+                fields: fields.into_iter(),
+                pending: None,
+                parent: Tracked::detached(self.path, self.unknown, self.failed),
+            }),
+            Value::Array(items) => visitor.visit_seq(TrackedSeq {
+                items: items.into_iter().enumerate(),
+                parent: Tracked::detached(self.path, self.unknown, self.failed),
+            }),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.unknown.borrow_mut().push(self.path.join("."));
+        visitor.visit_unit()
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        if self.value.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> DeResult<V::Value> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.value.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> DeResult<V::Value> {
+        self.value.deserialize_byte_buf(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TrackedMap<'a> {
+    fields: <Map<String, Value> as IntoIterator>::IntoIter,
+    pending: Option<(String, Value)>,
+    parent: Tracked<'a>,
+}
+
+impl<'de, 'a> MapAccess<'de> for TrackedMap<'a> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> DeResult<Option<K::Value>> {
+        let Some((key, value)) = self.fields.next() else {
+            return Ok(None);
+        };
+        let deserializer: StringDeserializer<serde_json::Error> = key.clone().into_deserializer();
+        self.pending = Some((key, value));
+        seed.deserialize(deserializer).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> DeResult<S::Value> {
+        let (key, value) = self.pending.take().unwrap_or_default();
+        seed.deserialize(self.parent.child(value, &key))
+            .inspect_err(|_| self.parent.child_failed(&key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+struct TrackedSeq<'a> {
+    items: Enumerate<IntoIter<Value>>,
+    parent: Tracked<'a>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for TrackedSeq<'a> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> DeResult<Option<S::Value>> {
+        match self.items.next() {
+            Some((index, value)) => {
+                let segment = index.to_string();
+                seed.deserialize(self.parent.child(value, &segment))
+                    .inspect_err(|_| self.parent.child_failed(&segment))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::{MintInfo, TransactionReceipt};
+
+    #[test]
+    fn test_strict_decoding_reports_unknown_field_paths() {
+        let receipt = r#"{"success":true,"transaction_hash":"0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"0x1111111111111111111111111111111111111111","recipient":null,"token_address":null}"#;
+        let decoded: TransactionReceipt =
+            from_str_strict("/v1/transactions/receipt/by_hash", receipt)
+                .expect("Known fields should decode");
+        assert_eq!(decoded.checkpoint_number, Some(1));
+
+        let drifted = receipt.replace(r#""success":true"#, r#""success":true,"gas_price":"7""#);
+        match from_str_strict::<TransactionReceipt>("/v1/transactions/receipt/by_hash", &drifted) {
+            Err(Error::UnknownFields { fields, .. }) => assert_eq!(fields, vec!["gas_price"]),
+            other => panic!("Expected unknown fields, got {:?}", other),
+        }
+        assert!(serde_json::from_str::<TransactionReceipt>(&drifted).is_ok());
+    }
+
+    #[test]
+    fn test_strict_decoding_tracks_nested_fields_and_defaults() {
+        let mint = r#"{"symbol":"TEST","master_authority":"0x1111111111111111111111111111111111111111","master_mint_burn_authority":"0x1111111111111111111111111111111111111111","supply":"0","decimals":6,"meta":{"name":"Test","uri":"","additional_metadata":[],"logo":"x"}}"#;
+        match from_str_strict::<MintInfo>("/v1/tokens/token_metadata", mint) {
+            Err(Error::UnknownFields { fields, .. }) => assert_eq!(fields, vec!["meta.logo"]),
+            other => panic!("Expected unknown fields, got {:?}", other),
+        }
+
+        let tolerant: MintInfo =
+            serde_json::from_str(mint).expect("Missing lists should default to empty");
+        assert!(tolerant.pause_authorities.is_empty());
+        assert!(!tolerant.is_paused);
+    }
+
+    #[test]
+    fn test_decode_error_points_at_rejected_value() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Page {
+            #[allow(dead_code)]
+            items: Vec<Item>,
+        }
+        #[derive(Debug, serde::Deserialize)]
+        struct Item {
+            #[allow(dead_code)]
+            fee: u64,
+        }
+        let diagnose = |body: &str| {
+            let error = serde_json::from_str::<Page>(body).expect_err("Body should not decode");
+            match decode_error::<Page>(body, error) {
+                Error::ResponseDeserialization {
+                    location: Some(location),
+                    ..
+                } => (location.pointer, location.expected, location.excerpt),
+                other => panic!("Expected a deserialization error, got {:?}", other),
+            }
+        };
+
+        let body = format!(
+            r#"{{"items":[{{"fee":1}},{{"fee":"{}"}}]}}"#,
+            "9".repeat(100)
+        );
+        let (pointer, expected, excerpt) = diagnose(&body);
+        assert_eq!(pointer.as_deref(), Some("/items/1/fee"));
+        assert_eq!(expected.as_deref(), Some("u64"));
+        let excerpt = excerpt.expect("Rejected value is quoted");
+        assert!(excerpt.starts_with("\"999") && excerpt.ends_with("..."));
+
+        let (pointer, expected, _) = diagnose(r#"{"items":[{}]}"#);
+        assert_eq!(pointer.as_deref(), Some("/items/0"));
+        assert_eq!(expected.as_deref(), Some("field `fee`"));
+
+        let (pointer, _, excerpt) = diagnose(r#"{"items":[{"fee":1},"#);
+        assert_eq!(pointer, None);
+        assert!(excerpt.is_some_and(|excerpt| excerpt.contains(r#""fee":1"#)));
+
+        let body = r#""nothing""#;
+        let error = decode_error::<Page>(body, serde_json::from_str::<Page>(body).unwrap_err());
+        assert!(error.to_string().starts_with(
+            "Failed to deserialize JSON response at the top level: invalid type: string \"nothing\", expected struct Page"
+        ));
+    }
+}
@@ -0,0 +1,195 @@
+//! Memoized token symbols and decimals for amount formatting.
+//!
+//! Rendering or parsing an amount needs the token's decimals, which would
+//! otherwise cost a metadata request per amount. The cache keeps what the
+//! client has fetched for a configurable time; decimals do not change once a
+//! token exists, so the limit mostly bounds how long a renamed symbol stays
+//! stale.
+
+use super::http::Client;
+use crate::Result;
+use crate::registry::KnownToken;
+use crate::utils::{format_units, parse_units};
+use alloy_primitives::{Address, U256};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Default time a fetched token's symbol and decimals are reused.
+pub const DEFAULT_TOKEN_INFO_TTL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: KnownToken,
+    fetched_at: Instant,
+}
+
+/// Symbols and decimals of tokens, keyed by mint address.
+///
+/// A zero time to live disables the cache, so every lookup fetches.
+#[derive(Debug)]
+pub struct TokenInfoCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<Address, CachedToken>>,
+}
+
+impl TokenInfoCache {
+    /// Create a cache whose entries are reused for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long entries are reused.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The cached entry for `token`, if it is younger than the time to live.
+    pub fn get(&self, token: &Address) -> Option<KnownToken> {
+        self.lock()
+            .get(token)
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .map(|cached| cached.token.clone())
+    }
+
+    /// Store an entry, replacing any earlier one for the same address.
+    pub fn insert(&self, token: KnownToken) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        self.lock().insert(
+            token.address,
+            CachedToken {
+                token,
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Forget `token`, so the next lookup fetches it again.
+    pub fn invalidate(&self, token: &Address) {
+        self.lock().remove(token);
+    }
+
+    /// Forget every entry.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Address, CachedToken>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for TokenInfoCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TOKEN_INFO_TTL)
+    }
+}
+
+impl Client {
+    /// The cache holding this client's token symbols and decimals.
+    pub fn token_info_cache(&self) -> &TokenInfoCache {
+        &self.inner.token_info
+    }
+
+    /// Symbol and decimals of a token.
+    ///
+    /// Tokens registered with [`ClientBuilder::token`](crate::ClientBuilder::token)
+    /// are answered from the registry; others come from the token info cache,
+    /// which is filled from the token's metadata on a miss.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The token's symbol and decimals.
+    pub async fn token_info(&self, token: Address) -> Result<KnownToken> {
+        if let Some(known) = self.token_registry().by_address(&token).cloned() {
+            return Ok(known);
+        }
+        if let Some(cached) = self.inner.token_info.get(&token) {
+            return Ok(cached);
+        }
+        let metadata = self.get_token_metadata(token).await?;
+        let known = KnownToken::new(metadata.symbol, token, metadata.decimals);
+        self.inner.token_info.insert(known.clone());
+        Ok(known)
+    }
+
+    /// Format a base-unit amount of `token` as a decimal string.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    /// * `amount` - The amount in base units
+    ///
+    /// # Returns
+    ///
+    /// The amount scaled by the token's decimals, such as `1.5`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::{Address, U256};
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let amount = client.parse_amount(token, "12.5").await?;
+    ///     println!("{} is {} base units", client.format_amount(token, amount).await?, amount);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn format_amount(&self, token: Address, amount: U256) -> Result<String> {
+        let info = self.token_info(token).await?;
+        Ok(format_units(amount, info.decimals))
+    }
+
+    /// Parse a decimal amount of `token`, such as `12.5`, into base units.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token mint address
+    /// * `amount` - The amount as a person would write it
+    ///
+    /// # Returns
+    ///
+    /// The amount in base units, or a validation error when it is malformed
+    /// or more precise than the token's decimals.
+    pub async fn parse_amount(&self, token: Address, amount: &str) -> Result<U256> {
+        let info = self.token_info(token).await?;
+        parse_units(amount, info.decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_info_cache_expiry() {
+        let token = KnownToken::new("USD1", Address::repeat_byte(0x11), 6);
+        let cache = TokenInfoCache::default();
+        cache.insert(token.clone());
+        assert_eq!(cache.get(&token.address), Some(token.clone()));
+        cache.invalidate(&token.address);
+        assert_eq!(cache.get(&token.address), None);
+
+        let disabled = TokenInfoCache::new(Duration::ZERO);
+        disabled.insert(token.clone());
+        assert_eq!(disabled.get(&token.address), None);
+    }
+}
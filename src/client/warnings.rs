@@ -0,0 +1,215 @@
+//! Non-fatal conditions noticed while serving a request.
+//!
+//! A warning never changes a result. Every [`Hook`](super::Hook) receives
+//! warnings through [`Hook::on_warning`](super::Hook::on_warning);
+//! [`Client::with_warnings`] additionally attaches the warnings raised while
+//! one operation ran to its result.
+
+use super::http::Client;
+use crate::Result;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::future::Future;
+use std::time::Duration;
+
+tokio::task_local! {
+    static COLLECTED: RefCell<Vec<Warning>>;
+}
+
+/// A condition worth reporting that did not stop the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// The local clock is further from the node's than the client allows.
+    ClockSkew {
+        /// Server clock minus the local clock, in milliseconds.
+        skew_ms: i64,
+        /// Skew past which the client warns.
+        max_skew: Duration,
+    },
+    /// A response had fields the SDK does not know, which it ignored.
+    ///
+    /// The server is newer than this SDK release; a strict-mode client
+    /// fails with [`Error::UnknownFields`](crate::Error::UnknownFields)
+    /// instead.
+    UnknownFields {
+        /// Path of the response.
+        endpoint: String,
+        /// Dotted paths of the ignored fields.
+        fields: Vec<String>,
+    },
+    /// The server marked the endpoint as deprecated.
+    DeprecatedEndpoint {
+        /// URL of the request.
+        url: String,
+        /// Value of the `deprecation` header, if sent.
+        deprecation: Option<String>,
+        /// Value of the `sunset` header, if sent.
+        sunset: Option<String>,
+    },
+    /// A list was returned in part; the rest is on further pages.
+    TruncatedList {
+        /// Path of the response.
+        endpoint: String,
+        /// Items returned.
+        returned: usize,
+        /// Items across every page, when the server reports it.
+        total: Option<u64>,
+    },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Warning::ClockSkew { skew_ms, max_skew } => write!(
+                f,
+                "Clock skew of {:+}ms exceeds the {:?} limit",
+                skew_ms, max_skew
+            ),
+            Warning::UnknownFields { endpoint, fields } => write!(
+                f,
+                "Response from {} has unknown fields: {}",
+                endpoint,
+                fields.join(", ")
+            ),
+            Warning::DeprecatedEndpoint { url, sunset, .. } => {
+                write!(f, "{} is deprecated", url)?;
+                if let Some(sunset) = sunset {
+                    write!(f, " and will be removed {}", sunset)?;
+                }
+                Ok(())
+            }
+            Warning::TruncatedList {
+                endpoint,
+                returned,
+                total,
+            } => {
+                write!(f, "{} returned {} items", endpoint, returned)?;
+                if let Some(total) = total {
+                    write!(f, " of {}", total)?;
+                }
+                write!(f, "; more are on further pages")
+            }
+        }
+    }
+}
+
+/// A result together with the warnings raised while producing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithWarnings<T> {
+    /// The result.
+    pub value: T,
+    /// Warnings in the order they were raised.
+    pub warnings: Vec<Warning>,
+}
+
+impl<T> WithWarnings<T> {
+    /// Whether no warning was raised.
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Drop the warnings.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl Client {
+    /// Run `operation` and collect the warnings it raises.
+    ///
+    /// Warnings are collected from requests made on the current task while
+    /// `operation` runs, including those of other clients; work spawned onto
+    /// other tasks is not covered. Hooks receive the warnings as usual. If
+    /// `operation` fails its warnings are discarded with the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation to run, typically a client call
+    ///
+    /// # Returns
+    ///
+    /// The operation's result with its warnings.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let owner = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///
+    ///     let nonce = client.with_warnings(client.get_account_nonce(owner)).await?;
+    ///     for warning in &nonce.warnings {
+    ///         eprintln!("Warning: {}", warning);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn with_warnings<F, T>(&self, operation: F) -> Result<WithWarnings<T>>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        COLLECTED
+            .scope(RefCell::new(Vec::new()), async {
+                let value = operation.await?;
+                let warnings = COLLECTED.with(|collected| collected.take());
+                Ok(WithWarnings { value, warnings })
+            })
+            .await
+    }
+}
+
+/// Whether the current task runs inside [`Client::with_warnings`].
+pub(crate) fn collecting() -> bool {
+    COLLECTED.try_with(|_| ()).is_ok()
+}
+
+/// Add a warning to the enclosing [`Client::with_warnings`], if any.
+pub(crate) fn collect(warning: Warning) {
+    let _ = COLLECTED.try_with(|collected| collected.borrow_mut().push(warning));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_with_warnings_collects_on_current_task() {
+        let client = Client::local().expect("Local client should build");
+        assert!(!client.wants_warnings());
+        client.emit_warning(Warning::UnknownFields {
+            endpoint: "/v1/ignored".to_string(),
+            fields: vec!["extra".to_string()],
+        });
+
+        let result = client
+            .with_warnings(async {
+                assert!(client.wants_warnings());
+                client.emit_warning(Warning::TruncatedList {
+                    endpoint: "/v1/tokens/holders".to_string(),
+                    returned: 50,
+                    total: Some(120),
+                });
+                Ok(7)
+            })
+            .await
+            .expect("Operation succeeds");
+        assert_eq!(result.value, 7);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            result.warnings[0].to_string(),
+            "/v1/tokens/holders returned 50 items of 120; more are on further pages"
+        );
+
+        let clean = client
+            .with_warnings(async { Ok(()) })
+            .await
+            .expect("Operation succeeds");
+        assert!(clean.is_clean());
+    }
+}
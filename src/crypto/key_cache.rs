@@ -0,0 +1,282 @@
+//! Encrypted in-memory cache for unlocked private keys.
+
+use super::hashing::Signable;
+use super::signing::sign_transaction_payload;
+use crate::{CryptoError, Result, Signature};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use k256::elliptic_curve::rand_core::{OsRng, RngCore};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::str::from_utf8;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use zeroize::{Zeroize, Zeroizing};
+
+const NONCE_LENGTH: usize = 24;
+
+struct SealedKey {
+    nonce: [u8; NONCE_LENGTH],
+    /// Ciphertext followed by the Poly1305 tag.
+    ciphertext: Vec<u8>,
+    expires_at: Instant,
+}
+
+impl Drop for SealedKey {
+    fn drop(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+/// Holds unlocked private keys encrypted under a per-process session key.
+///
+/// Keys are sealed with XChaCha20-Poly1305 under a random nonce, with the
+/// entry name as associated data so an entry cannot be moved to another
+/// name. They are only decrypted for the duration of a
+/// [`KeyCache::with_key`] or [`KeyCache::sign`] call and the plaintext buffer
+/// is zeroized afterwards. Every entry expires `ttl` after it was inserted;
+/// an expired or relocked key has to be unlocked (inserted) again before it
+/// can be used.
+pub struct KeyCache {
+    session_key: Zeroizing<[u8; 32]>,
+    ttl: Duration,
+    entries: Mutex<HashMap<String, SealedKey>>,
+}
+
+impl Debug for KeyCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("KeyCache")
+            .field("ttl", &self.ttl)
+            .field("unlocked_keys", &self.lock().len())
+            .finish()
+    }
+}
+
+impl KeyCache {
+    /// Create an empty cache whose entries stay unlocked for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        let mut session_key = Zeroizing::new([0u8; 32]);
+        OsRng.fill_bytes(session_key.as_mut());
+
+        Self {
+            session_key,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// How long an inserted key stays unlocked.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Unlock a key under `name`, replacing any key previously stored there.
+    pub fn insert(&self, name: &str, private_key: &str) -> Result<()> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher()
+            .encrypt(
+                &XNonce::from(nonce),
+                Payload {
+                    msg: private_key.as_bytes(),
+                    aad: name.as_bytes(),
+                },
+            )
+            .map_err(|_| CryptoError::key_derivation(format!("could not seal key {name}")))?;
+
+        self.lock().insert(
+            name.to_string(),
+            SealedKey {
+                nonce,
+                ciphertext,
+                expires_at: Instant::now() + self.ttl,
+            },
+        );
+        Ok(())
+    }
+
+    /// Whether `name` is currently unlocked and not expired.
+    pub fn is_unlocked(&self, name: &str) -> bool {
+        self.lock()
+            .get(name)
+            .is_some_and(|sealed| sealed.expires_at > Instant::now())
+    }
+
+    /// Relock a single key, returning whether it was unlocked.
+    pub fn relock(&self, name: &str) -> bool {
+        self.lock().remove(name).is_some()
+    }
+
+    /// Relock every key.
+    pub fn relock_all(&self) {
+        self.lock().clear();
+    }
+
+    /// Drop every entry whose time-to-live has elapsed.
+    pub fn purge_expired(&self) {
+        let now = Instant::now();
+        self.lock().retain(|_, sealed| sealed.expires_at > now);
+    }
+
+    /// Run `f` with the decrypted private key stored under `name`.
+    ///
+    /// The plaintext is zeroized as soon as `f` returns.
+    pub fn with_key<R, F>(&self, name: &str, f: F) -> Result<R>
+    where
+        F: FnOnce(&str) -> R,
+    {
+        let plaintext = {
+            let mut entries = self.lock();
+            let sealed = entries
+                .get(name)
+                .ok_or_else(|| CryptoError::key_locked(format!("no unlocked key named {name}")))?;
+
+            if sealed.expires_at <= Instant::now() {
+                entries.remove(name);
+                return Err(CryptoError::key_locked(format!("key {name} has expired")).into());
+            }
+
+            self.cipher()
+                .decrypt(
+                    &XNonce::from(sealed.nonce),
+                    Payload {
+                        msg: &sealed.ciphertext,
+                        aad: name.as_bytes(),
+                    },
+                )
+                .map(Zeroizing::new)
+                .map_err(|_| {
+                    CryptoError::verification_failed(format!(
+                        "cached key {name} failed authentication"
+                    ))
+                })?
+        };
+
+        let private_key = from_utf8(&plaintext)
+            .map_err(|_| CryptoError::invalid_private_key("cached key is not valid UTF-8"))?;
+        Ok(f(private_key))
+    }
+
+    /// Sign a payload with the key stored under `name`.
+    pub fn sign<T: Signable>(&self, name: &str, payload: &T) -> Result<Signature> {
+        self.with_key(name, |private_key| {
+            sign_transaction_payload(payload, private_key)
+        })?
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(self.session_key.as_slice().into())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SealedKey>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PaymentPayload;
+
+    const TEST_KEY: &str = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn test_key_cache_round_trip() {
+        let cache = KeyCache::new(Duration::from_secs(60));
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+        assert!(cache.is_unlocked("operator"));
+
+        let length = cache
+            .with_key("operator", |key| {
+                assert_eq!(key, TEST_KEY);
+                key.len()
+            })
+            .expect("Key should be unlocked");
+        assert_eq!(length, TEST_KEY.len());
+    }
+
+    #[test]
+    fn test_key_cache_does_not_store_plaintext() {
+        let cache = KeyCache::new(Duration::from_secs(60));
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+
+        let entries = cache.lock();
+        let sealed = entries.get("operator").expect("Entry should exist");
+        assert_ne!(sealed.ciphertext.as_slice(), TEST_KEY.as_bytes());
+        drop(entries);
+
+        assert!(!format!("{:?}", cache).contains(&TEST_KEY[2..]));
+    }
+
+    #[test]
+    fn test_key_cache_relock_and_expiry() {
+        let cache = KeyCache::new(Duration::from_secs(60));
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+        assert!(cache.relock("operator"));
+        assert!(!cache.relock("operator"));
+        assert!(matches!(
+            cache.with_key("operator", |_| ()),
+            Err(crate::Error::Crypto(CryptoError::KeyLocked(_)))
+        ));
+
+        let expired = KeyCache::new(Duration::ZERO);
+        expired
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+        assert!(!expired.is_unlocked("operator"));
+        assert!(expired.with_key("operator", |_| ()).is_err());
+        expired.purge_expired();
+        assert!(!expired.relock("operator"));
+    }
+
+    #[test]
+    fn test_key_cache_detects_tampering() {
+        let cache = KeyCache::new(Duration::from_secs(60));
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+        if let Some(sealed) = cache.lock().get_mut("operator") {
+            sealed.ciphertext[0] ^= 0xff;
+        }
+
+        assert!(matches!(
+            cache.with_key("operator", |_| ()),
+            Err(crate::Error::Crypto(CryptoError::VerificationFailed(_)))
+        ));
+
+        // An entry moved under another name no longer opens.
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+        let mut entries = cache.lock();
+        let moved = entries.remove("operator").expect("Entry should exist");
+        entries.insert("treasury".to_string(), moved);
+        drop(entries);
+        assert!(cache.with_key("treasury", |_| ()).is_err());
+    }
+
+    #[test]
+    fn test_key_cache_sign_matches_direct_signing() {
+        let cache = KeyCache::new(Duration::from_secs(60));
+        cache
+            .insert("operator", TEST_KEY)
+            .expect("Key should be stored");
+
+        let payload = PaymentPayload::default();
+        let cached = cache
+            .sign("operator", &payload)
+            .expect("Signing should succeed");
+        let direct = sign_transaction_payload(&payload, TEST_KEY).expect("Signing should succeed");
+        assert_eq!(cached, direct);
+    }
+}
@@ -1,9 +1,11 @@
 //! Cryptographic key operations and address derivation.
 
+use super::secret::SecretKeyMaterial;
 use crate::{CryptoError, Result};
 use alloy_primitives::{Address, keccak256};
-use hex::decode as hex_decode;
+use hex::{decode as hex_decode, encode as hex_encode};
 use k256::ecdsa::{SigningKey, VerifyingKey};
+use zeroize::Zeroizing;
 
 /// Convert a private key hex string to an address.
 ///
@@ -14,12 +16,65 @@ use k256::ecdsa::{SigningKey, VerifyingKey};
 /// # Returns
 ///
 /// The corresponding Ethereum-style address as a hex string.
-pub fn private_key_to_address(private_key_hex: &str) -> Result<String> {
+pub fn private_key_to_address<K: Into<SecretKeyMaterial>>(private_key_hex: K) -> Result<String> {
+    let signing_key = private_key_hex.into().signing_key()?;
+    Ok(signing_key_address(&signing_key).to_checksum(None))
+}
+
+/// Compute a stable fingerprint for a private key.
+///
+/// The fingerprint is the first 8 bytes of the keccak256 hash of the
+/// compressed public key, so it identifies the key without revealing it and
+/// is safe to log or compare across deployments.
+///
+/// # Arguments
+///
+/// * `private_key_hex` - The private key as a hex string (with or without 0x prefix)
+///
+/// # Returns
+///
+/// The fingerprint as 16 lowercase hex characters.
+pub fn key_fingerprint<K: Into<SecretKeyMaterial>>(private_key_hex: K) -> Result<String> {
+    let signing_key = private_key_hex.into().signing_key()?;
+    let compressed = VerifyingKey::from(&signing_key).to_encoded_point(true);
+    let hash = keccak256(compressed.as_bytes());
+    Ok(hex_encode(&hash[..8]))
+}
+
+/// Check that a private key belongs to the expected address.
+///
+/// Intended as a deployment guard before enabling transaction submission.
+///
+/// # Arguments
+///
+/// * `private_key_hex` - The private key as a hex string (with or without 0x prefix)
+/// * `expected` - The address the key is supposed to control
+///
+/// # Returns
+///
+/// `Ok(())` when the key derives `expected`, otherwise a
+/// [`CryptoError::KeyMismatch`] error.
+pub fn verify_key_matches<K: Into<SecretKeyMaterial>>(
+    private_key_hex: K,
+    expected: Address,
+) -> Result<()> {
+    let signing_key = private_key_hex.into().signing_key()?;
+    let actual = signing_key_address(&signing_key);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(CryptoError::key_mismatch(expected.to_checksum(None), actual.to_checksum(None)).into())
+    }
+}
+
+pub(crate) fn parse_signing_key(private_key_hex: &str) -> Result<SigningKey> {
     let private_key_hex = private_key_hex
         .strip_prefix("0x")
         .unwrap_or(private_key_hex);
-    let private_key_bytes = hex_decode(private_key_hex)
-        .map_err(|e| CryptoError::invalid_private_key(format!("Invalid hex format: {}", e)))?;
+    let private_key_bytes = Zeroizing::new(
+        hex_decode(private_key_hex)
+            .map_err(|e| CryptoError::invalid_private_key(format!("Invalid hex format: {}", e)))?,
+    );
 
     if private_key_bytes.len() != 32 {
         return Err(
@@ -27,15 +82,13 @@ pub fn private_key_to_address(private_key_hex: &str) -> Result<String> {
         );
     }
 
-    let key_array: [u8; 32] = private_key_bytes
-        .try_into()
-        .map_err(|_| CryptoError::invalid_private_key("Private key must be exactly 32 bytes"))?;
-
-    let signing_key = SigningKey::from_bytes(&key_array.into()).map_err(|e| {
+    Ok(SigningKey::from_slice(&private_key_bytes).map_err(|e| {
         CryptoError::invalid_private_key(format!("Invalid private key format: {}", e))
-    })?;
+    })?)
+}
 
-    let verifying_key = VerifyingKey::from(&signing_key);
+pub(crate) fn signing_key_address(signing_key: &SigningKey) -> Address {
+    let verifying_key = VerifyingKey::from(signing_key);
     let public_key_point = verifying_key.to_encoded_point(false);
     let public_key_bytes = public_key_point.as_bytes();
 
@@ -43,10 +96,7 @@ pub fn private_key_to_address(private_key_hex: &str) -> Result<String> {
     let hash = keccak256(&public_key_bytes[1..]);
 
     // Take the last 20 bytes as the address
-    let address_bytes = &hash[12..];
-    let address = Address::from_slice(address_bytes);
-
-    Ok(address.to_checksum(None))
+    Address::from_slice(&hash[12..])
 }
 
 /// Derive a token account address from wallet and mint addresses.
@@ -160,4 +210,40 @@ mod tests {
         // Different wallets should produce different token accounts
         assert_ne!(account1, account2);
     }
+
+    #[test]
+    fn test_key_fingerprint_is_stable() {
+        let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+        let fingerprint = key_fingerprint(private_key).expect("Valid fingerprint");
+        assert_eq!(fingerprint.len(), 16);
+        assert_eq!(
+            fingerprint,
+            key_fingerprint(&private_key[2..]).expect("Valid fingerprint")
+        );
+        assert!(!private_key.contains(&fingerprint));
+
+        let other = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        assert_ne!(
+            fingerprint,
+            key_fingerprint(other).expect("Valid fingerprint")
+        );
+        assert!(key_fingerprint("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_verify_key_matches() {
+        let private_key = "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+        let address = Address::from_str(
+            &private_key_to_address(private_key).expect("Valid address conversion"),
+        )
+        .expect("Valid address");
+
+        assert!(verify_key_matches(private_key, address).is_ok());
+
+        let result = verify_key_matches(private_key, Address::ZERO);
+        assert!(matches!(
+            result,
+            Err(crate::Error::Crypto(CryptoError::KeyMismatch { .. }))
+        ));
+    }
 }
@@ -1,10 +1,16 @@
 //! Cryptographic utilities for signing and address derivation.
 
 pub mod hashing;
+pub mod key_cache;
 pub mod keys;
+pub mod multisig;
+pub mod secret;
 pub mod signing;
 
 // Re-export public interfaces
 pub use hashing::*;
+pub use key_cache::*;
 pub use keys::*;
+pub use multisig::*;
+pub use secret::*;
 pub use signing::*;
@@ -0,0 +1,245 @@
+//! Multi-signature coordination for transaction payloads.
+//!
+//! Co-signers agree on a payload, each signs its digest independently, and
+//! one party collects the partial signatures and checks them against a
+//! [`MultisigPolicy`]. The L1 API documents no multi-signature submission
+//! format, so the aggregate is for off-chain approval flows and is not
+//! submitted by the client.
+
+use super::hashing::Signable;
+use super::signing::{recover_signer, sign_hash};
+use crate::{CryptoError, Error, Result, Signature};
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Digest co-signers sign for `payload`.
+///
+/// This is the payload's signature hash; sharing it lets signers that only
+/// hold the digest produce a [`PartialSignature`] without the full payload.
+pub fn payload_digest<T: Signable>(payload: &T) -> B256 {
+    payload.signature_hash()
+}
+
+/// A signature contributed by one co-signer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    /// Address of the co-signer.
+    pub signer: Address,
+    /// Signature over the shared digest.
+    pub signature: Signature,
+}
+
+impl PartialSignature {
+    /// Sign a shared digest with a co-signer's private key.
+    pub fn sign(digest: &B256, private_key: &str) -> Result<Self> {
+        let signature = sign_hash(digest, private_key)?;
+        let signer = recover_signer(digest, &signature)?;
+        Ok(Self { signer, signature })
+    }
+
+    /// Check that the signature over `digest` was produced by `signer`.
+    pub fn verify(&self, digest: &B256) -> Result<()> {
+        let recovered = recover_signer(digest, &self.signature)?;
+        if recovered == self.signer {
+            Ok(())
+        } else {
+            Err(CryptoError::verification_failed(format!(
+                "Signature claims signer {} but was produced by {}",
+                self.signer, recovered
+            ))
+            .into())
+        }
+    }
+}
+
+impl Display for PartialSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "PartialSignature(signer: {})", self.signer)
+    }
+}
+
+/// Signatures checked against a policy, ready for submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatedSignatures {
+    /// Digest every signature covers.
+    pub digest: B256,
+    /// Signatures ordered by signer address, as the L1 expects.
+    pub signatures: Vec<Signature>,
+}
+
+/// Threshold policy over a fixed set of co-signers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigPolicy {
+    /// Minimum number of distinct signers required.
+    pub threshold: usize,
+    /// Addresses allowed to sign.
+    pub signers: Vec<Address>,
+}
+
+impl MultisigPolicy {
+    /// Create a policy, rejecting thresholds the signer set cannot meet.
+    pub fn new(threshold: usize, signers: Vec<Address>) -> Result<Self> {
+        let mut unique = signers.clone();
+        unique.sort();
+        unique.dedup();
+        if unique.len() != signers.len() {
+            return Err(Error::validation(
+                "signers",
+                "Signer set contains duplicates",
+            ));
+        }
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::validation(
+                "threshold",
+                format!(
+                    "Threshold must be between 1 and {}, got {}",
+                    signers.len(),
+                    threshold
+                ),
+            ));
+        }
+        Ok(Self { threshold, signers })
+    }
+
+    /// Whether `address` belongs to the signer set.
+    pub fn is_signer(&self, address: &Address) -> bool {
+        self.signers.contains(address)
+    }
+
+    /// Verify partial signatures over `digest` and aggregate them.
+    ///
+    /// Every signature must verify and come from a member of the signer set.
+    /// Repeated signatures from the same signer count once. Fails if fewer
+    /// than `threshold` distinct signers contributed.
+    pub fn aggregate(
+        &self,
+        digest: &B256,
+        partials: &[PartialSignature],
+    ) -> Result<AggregatedSignatures> {
+        let mut by_signer = BTreeMap::new();
+        for partial in partials {
+            if !self.is_signer(&partial.signer) {
+                return Err(Error::validation(
+                    "signatures",
+                    format!("{} is not a member of the signer set", partial.signer),
+                ));
+            }
+            partial.verify(digest)?;
+            by_signer
+                .entry(partial.signer)
+                .or_insert_with(|| partial.signature.clone());
+        }
+
+        if by_signer.len() < self.threshold {
+            return Err(Error::validation(
+                "signatures",
+                format!(
+                    "Collected {} of {} required signatures",
+                    by_signer.len(),
+                    self.threshold
+                ),
+            ));
+        }
+
+        Ok(AggregatedSignatures {
+            digest: *digest,
+            signatures: by_signer.into_values().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PaymentPayload;
+    use alloy_primitives::U256;
+
+    const KEYS: [&str; 3] = [
+        "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+        "0x5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+    ];
+
+    fn digest() -> B256 {
+        payload_digest(&PaymentPayload {
+            chain_id: 1212101,
+            nonce: 7,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1000u64),
+            token: Address::repeat_byte(0x22),
+        })
+    }
+
+    fn partials(digest: &B256) -> Vec<PartialSignature> {
+        KEYS.iter()
+            .map(|key| PartialSignature::sign(digest, key).expect("Signing should succeed"))
+            .collect()
+    }
+
+    fn policy(partials: &[PartialSignature], threshold: usize) -> MultisigPolicy {
+        let signers = partials.iter().map(|partial| partial.signer).collect();
+        MultisigPolicy::new(threshold, signers).expect("Policy should be valid")
+    }
+
+    #[test]
+    fn test_partial_signature_verify() {
+        let digest = digest();
+        let mut partial = PartialSignature::sign(&digest, KEYS[0]).expect("Signing should succeed");
+        assert!(partial.verify(&digest).is_ok());
+        assert!(partial.verify(&B256::repeat_byte(0x01)).is_err());
+
+        partial.signer = Address::repeat_byte(0x33);
+        assert!(partial.verify(&digest).is_err());
+    }
+
+    #[test]
+    fn test_policy_rejects_invalid_threshold() {
+        let signers = vec![Address::repeat_byte(1), Address::repeat_byte(2)];
+        assert!(MultisigPolicy::new(0, signers.clone()).is_err());
+        assert!(MultisigPolicy::new(3, signers.clone()).is_err());
+        assert!(MultisigPolicy::new(2, signers).is_ok());
+        assert!(MultisigPolicy::new(1, vec![Address::repeat_byte(1); 2]).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_sorts_and_dedupes() {
+        let digest = digest();
+        let partials = partials(&digest);
+        let policy = policy(&partials, 2);
+
+        let mut collected = vec![
+            partials[2].clone(),
+            partials[0].clone(),
+            partials[2].clone(),
+        ];
+        let aggregated = policy
+            .aggregate(&digest, &collected)
+            .expect("Two distinct signers meet the threshold");
+        assert_eq!(aggregated.digest, digest);
+        assert_eq!(aggregated.signatures.len(), 2);
+
+        let mut expected = vec![partials[0].clone(), partials[2].clone()];
+        expected.sort_by_key(|partial| partial.signer);
+        let expected: Vec<Signature> = expected.into_iter().map(|p| p.signature).collect();
+        assert_eq!(aggregated.signatures, expected);
+
+        collected.truncate(1);
+        assert!(policy.aggregate(&digest, &collected).is_err());
+    }
+
+    #[test]
+    fn test_aggregate_rejects_outsiders_and_bad_signatures() {
+        let digest = digest();
+        let partials = partials(&digest);
+        let policy = policy(&partials[..2], 1);
+
+        let outsider = policy.aggregate(&digest, &partials[2..]);
+        assert!(matches!(outsider, Err(Error::Validation { .. })));
+
+        let other_digest = B256::repeat_byte(0x42);
+        let forged = policy.aggregate(&other_digest, &partials[..1]);
+        assert!(matches!(forged, Err(Error::Crypto(_))));
+    }
+}
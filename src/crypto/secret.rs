@@ -0,0 +1,127 @@
+//! Private keys held in memory that is wiped on drop.
+
+use super::keys::parse_signing_key;
+use crate::Result;
+use hex::encode as hex_encode;
+use k256::ecdsa::SigningKey;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use zeroize::Zeroizing;
+
+/// A private key that never appears in `Debug` output and is zeroized when
+/// dropped.
+///
+/// The signing functions and [`Wallet`](crate::Wallet) accept anything that
+/// converts into it, so existing callers passing a hex `&str` keep working;
+/// the conversion copies the key into zeroizing storage. Hold the key as
+/// `SecretKeyMaterial` from the point it is loaded to avoid plain copies
+/// lingering elsewhere. The type deliberately has no `Display` impl.
+///
+/// Conversion never fails; a malformed key is reported when it is first
+/// used.
+#[derive(Clone)]
+pub struct SecretKeyMaterial {
+    hex: Zeroizing<String>,
+}
+
+impl SecretKeyMaterial {
+    /// Wrap a hex private key, with or without the `0x` prefix.
+    pub fn from_hex<S: AsRef<str>>(private_key_hex: S) -> Self {
+        let private_key_hex = private_key_hex.as_ref();
+        Self {
+            hex: Zeroizing::new(
+                private_key_hex
+                    .strip_prefix("0x")
+                    .unwrap_or(private_key_hex)
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// Wrap the 32 raw bytes of a private key.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+        Self {
+            hex: Zeroizing::new(hex_encode(bytes)),
+        }
+    }
+
+    /// The key as unprefixed hex.
+    ///
+    /// Every use of the returned string is a chance to leak the key, so keep
+    /// it to handing the key to other signing code.
+    pub fn expose_secret(&self) -> &str {
+        &self.hex
+    }
+
+    /// Parse the key, rejecting anything that is not a valid secp256k1
+    /// scalar.
+    pub(crate) fn signing_key(&self) -> Result<SigningKey> {
+        parse_signing_key(&self.hex)
+    }
+}
+
+impl Debug for SecretKeyMaterial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("SecretKeyMaterial([REDACTED])")
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> From<&S> for SecretKeyMaterial {
+    fn from(private_key_hex: &S) -> Self {
+        Self::from_hex(private_key_hex)
+    }
+}
+
+impl From<String> for SecretKeyMaterial {
+    fn from(private_key_hex: String) -> Self {
+        let private_key_hex = Zeroizing::new(private_key_hex);
+        Self::from_hex(private_key_hex.as_str())
+    }
+}
+
+impl From<Zeroizing<String>> for SecretKeyMaterial {
+    fn from(private_key_hex: Zeroizing<String>) -> Self {
+        Self::from_hex(private_key_hex.as_str())
+    }
+}
+
+impl From<&SecretKeyMaterial> for SecretKeyMaterial {
+    fn from(secret: &SecretKeyMaterial) -> Self {
+        secret.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_secret_key_material_normalizes_and_redacts() {
+        let secret = SecretKeyMaterial::from(KEY);
+        assert_eq!(secret.expose_secret(), &KEY[2..]);
+        assert_eq!(
+            SecretKeyMaterial::from(KEY[2..].to_string()).expose_secret(),
+            secret.expose_secret()
+        );
+
+        let debug = format!("{:?}", secret);
+        assert_eq!(debug, "SecretKeyMaterial([REDACTED])");
+        assert!(!debug.contains(&KEY[2..10]));
+
+        let bytes: [u8; 32] = hex::decode(&KEY[2..])
+            .expect("Key is hex")
+            .try_into()
+            .expect("Key is 32 bytes");
+        assert_eq!(
+            SecretKeyMaterial::from_bytes(&bytes).expose_secret(),
+            &KEY[2..]
+        );
+    }
+
+    #[test]
+    fn test_secret_key_material_validates_on_use() {
+        assert!(SecretKeyMaterial::from(KEY).signing_key().is_ok());
+        assert!(SecretKeyMaterial::from("0x1234").signing_key().is_err());
+    }
+}
@@ -1,44 +1,37 @@
 //! Digital signature operations.
 
 use super::hashing::Signable;
+use super::secret::SecretKeyMaterial;
 use crate::{CryptoError, Result, Signature};
-use alloy_primitives::B256;
-use hex::decode as hex_decode;
+use alloy_primitives::{Address, B256, Signature as PrimitiveSignature};
 use k256::ecdsa::SigningKey;
 
 /// Sign a transaction payload using the same method as L1.
 /// This function matches the L1 implementation's sign_transaction_payload.
-pub fn sign_transaction_payload<T>(payload: &T, private_key_hex: &str) -> Result<Signature>
+///
+/// The key may be a hex `&str` or a [`SecretKeyMaterial`].
+pub fn sign_transaction_payload<T, K>(payload: &T, private_key_hex: K) -> Result<Signature>
 where
     T: Signable,
+    K: Into<SecretKeyMaterial>,
 {
     let signature_hash = payload.signature_hash();
     sign_hash(&signature_hash, private_key_hex)
 }
 
 /// Sign a pre-computed hash using ECDSA.
-pub fn sign_hash(message_hash: &B256, private_key_hex: &str) -> Result<Signature> {
-    use alloy::signers::{SignerSync, local::LocalSigner};
-
-    let private_key_hex = private_key_hex
-        .strip_prefix("0x")
-        .unwrap_or(private_key_hex);
-    let private_key_bytes = hex_decode(private_key_hex)
-        .map_err(|e| CryptoError::invalid_private_key(format!("Invalid hex format: {}", e)))?;
-
-    if private_key_bytes.len() != 32 {
-        return Err(
-            CryptoError::invalid_private_key("Private key must be exactly 32 bytes").into(),
-        );
-    }
-
-    let key_array: [u8; 32] = private_key_bytes
-        .try_into()
-        .map_err(|_| CryptoError::invalid_private_key("Private key must be exactly 32 bytes"))?;
+pub fn sign_hash<K: Into<SecretKeyMaterial>>(
+    message_hash: &B256,
+    private_key_hex: K,
+) -> Result<Signature> {
+    sign_hash_with_key(message_hash, private_key_hex.into().signing_key()?)
+}
 
-    let signing_key = SigningKey::from_bytes(&key_array.into()).map_err(|e| {
-        CryptoError::invalid_private_key(format!("Invalid private key format: {}", e))
-    })?;
+pub(crate) fn sign_hash_with_key(
+    message_hash: &B256,
+    signing_key: SigningKey,
+) -> Result<Signature> {
+    use alloy::signers::{SignerSync, local::LocalSigner};
 
     let local_signer = LocalSigner::from(signing_key);
 
@@ -53,7 +46,97 @@ pub fn sign_hash(message_hash: &B256, private_key_hex: &str) -> Result<Signature
     // L1 expects: v=0 (false/even parity) or v=1 (true/odd parity)
     let v = if alloy_signature.v() { 1 } else { 0 };
 
-    let our_signature = Signature::new(r, s, v);
+    // The L1 only accepts low-s signatures; never rely on the signer for it.
+    Signature::new(r, s, v).normalize()
+}
+
+/// Recover the address that produced `signature` over a pre-computed hash.
+///
+/// Accepts every `v` encoding [`Signature::recovery_id`] understands: the L1
+/// parity encoding (0 or 1), legacy Ethereum (27 or 28) and EIP-155. Rejects
+/// signatures with a high `s`, as the L1 validator does; see
+/// [`Signature::is_low_s`].
+pub fn recover_signer(message_hash: &B256, signature: &Signature) -> Result<Address> {
+    if !signature.is_low_s() {
+        return Err(CryptoError::verification_failed(
+            "High s value; the L1 only accepts low-s signatures",
+        )
+        .into());
+    }
+    let parity = signature.recovery_id().map_err(|_| {
+        CryptoError::verification_failed(format!("Invalid recovery id {}", signature.v))
+    })? == 1;
+
+    PrimitiveSignature::new(signature.r, signature.s, parity)
+        .recover_address_from_prehash(message_hash)
+        .map_err(|e| {
+            CryptoError::verification_failed(format!("Failed to recover signer: {}", e)).into()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SECP256K1_ORDER;
+    use crate::crypto::private_key_to_address;
+    use alloy_primitives::keccak256;
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_recover_signer_round_trip() {
+        let hash = keccak256(b"recover me");
+        let signature = sign_hash(&hash, PRIVATE_KEY).expect("Signing should succeed");
+        let expected: Address = private_key_to_address(PRIVATE_KEY)
+            .expect("Key should be valid")
+            .parse()
+            .expect("Derived address should parse");
+
+        let recovered = recover_signer(&hash, &signature).expect("Recovery should succeed");
+        assert_eq!(recovered, expected);
+
+        let legacy = Signature::new(signature.r, signature.s, signature.v + 27);
+        assert_eq!(
+            recover_signer(&hash, &legacy).expect("Legacy recovery id should be accepted"),
+            recovered
+        );
+
+        let eip155 = signature.to_eip155(1212101).expect("Chain ID should fit");
+        assert_eq!(
+            recover_signer(&hash, &eip155).expect("EIP-155 recovery id should be accepted"),
+            recovered
+        );
+
+        let other_hash = keccak256(b"something else");
+        let other = recover_signer(&other_hash, &signature).expect("Recovery should succeed");
+        assert_ne!(other, recovered);
+    }
+
+    #[test]
+    fn test_signatures_are_low_s_and_high_s_is_rejected() {
+        for message in [b"first".as_slice(), b"second", b"third", b"fourth"] {
+            let hash = keccak256(message);
+            let signature = sign_hash(&hash, PRIVATE_KEY).expect("Signing should succeed");
+            assert!(signature.is_low_s());
+            let signer = recover_signer(&hash, &signature).expect("Recovery should succeed");
 
-    Ok(our_signature)
+            let malleated =
+                Signature::new(signature.r, SECP256K1_ORDER - signature.s, signature.v ^ 1);
+            assert!(recover_signer(&hash, &malleated).is_err());
+            let restored = malleated.normalize().expect("High s should normalize");
+            assert_eq!(restored, signature);
+            assert_eq!(
+                recover_signer(&hash, &restored).expect("Recovery should succeed"),
+                signer
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_signer_rejects_invalid_recovery_id() {
+        let hash = keccak256(b"recover me");
+        let mut signature = sign_hash(&hash, PRIVATE_KEY).expect("Signing should succeed");
+        signature.v = 5;
+        assert!(recover_signer(&hash, &signature).is_err());
+    }
 }
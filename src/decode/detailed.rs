@@ -0,0 +1,119 @@
+//! Transactions resolved against the metadata of the token they touch.
+
+use super::summary::TransactionSummary;
+use crate::Result;
+use crate::responses::{MintInfo, Transaction};
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The token a transaction refers to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenDetails {
+    /// Token mint address.
+    pub address: Address,
+    /// Token symbol.
+    pub symbol: String,
+    /// Number of decimals the token uses.
+    pub decimals: u8,
+}
+
+/// A transaction with its summary and the token it references.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetailedTransaction {
+    /// The transaction as returned by the L1.
+    pub transaction: Transaction,
+    /// Decoded summary, with amounts formatted in the token's decimals.
+    pub summary: TransactionSummary,
+    /// The referenced token; `None` for native transfers and token creation.
+    pub token: Option<TokenDetails>,
+}
+
+impl DetailedTransaction {
+    /// Decode `transaction` and resolve it against `mint`, the metadata of
+    /// the token its payload references.
+    pub fn new(transaction: Transaction, mint: Option<&MintInfo>) -> Result<Self> {
+        let mut summary = TransactionSummary::from_transaction(&transaction)?;
+        let token = match (summary.token, mint) {
+            (Some(address), Some(mint)) => {
+                summary = summary.with_decimals(mint.decimals);
+                Some(TokenDetails {
+                    address,
+                    symbol: mint.symbol.clone(),
+                    decimals: mint.decimals,
+                })
+            }
+            _ => None,
+        };
+        Ok(Self {
+            transaction,
+            summary,
+            token,
+        })
+    }
+
+    /// The amount followed by the token symbol, e.g. `2.5 USDX`.
+    ///
+    /// Without a resolved token the amount is given in base units.
+    pub fn display_amount(&self) -> Option<String> {
+        let amount = self.summary.amount?;
+        Some(match &self.token {
+            Some(token) => format!("{} {}", amount, token.symbol),
+            None => amount.to_string(),
+        })
+    }
+}
+
+impl Display for DetailedTransaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.summary)?;
+        if let Some(token) = &self.token {
+            writeln!(f, "  Symbol: {}", token.symbol)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::TxPayload;
+
+    #[test]
+    fn test_detailed_transaction_resolves_token() {
+        let token = Address::repeat_byte(0x22);
+        let transaction = Transaction {
+            data: TxPayload::TokenBurn {
+                value: "1500".to_string(),
+                recipient: Address::repeat_byte(0x11),
+                token,
+            },
+            ..Transaction::default()
+        };
+        let mint = MintInfo {
+            symbol: "USDX".to_string(),
+            decimals: 3,
+            ..MintInfo::default()
+        };
+
+        let detailed =
+            DetailedTransaction::new(transaction.clone(), Some(&mint)).expect("Should decode");
+        assert_eq!(
+            detailed.token,
+            Some(TokenDetails {
+                address: token,
+                symbol: "USDX".to_string(),
+                decimals: 3,
+            })
+        );
+        assert_eq!(detailed.display_amount().as_deref(), Some("1.5 USDX"));
+        assert!(detailed.to_string().contains("Symbol: USDX"));
+
+        let unresolved = DetailedTransaction::new(transaction, None).expect("Should decode");
+        assert_eq!(unresolved.token, None);
+        assert_eq!(
+            unresolved.display_amount().as_deref(),
+            Some("1500 base units")
+        );
+    }
+}
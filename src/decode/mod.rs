@@ -0,0 +1,8 @@
+//! Human-readable decoding of transactions.
+
+pub mod detailed;
+pub mod summary;
+
+// Re-export public interfaces
+pub use detailed::*;
+pub use summary::*;
@@ -0,0 +1,527 @@
+//! Structured, display-ready summaries of what a transaction does.
+
+use crate::Result;
+use crate::error::Error;
+use crate::requests::authorities::AuthorityAction;
+use crate::responses::{Transaction, TxPayload};
+use crate::utils::format_units;
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The operation a transaction performs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    /// Token creation.
+    TokenCreate,
+    /// Token transfer.
+    Transfer,
+    /// Authority grant.
+    GrantAuthority,
+    /// Authority revocation.
+    RevokeAuthority,
+    /// Account blacklisted.
+    Blacklist,
+    /// Account whitelisted.
+    Whitelist,
+    /// Token minting.
+    Mint,
+    /// Token burning.
+    Burn,
+    /// Token account closed.
+    CloseAccount,
+    /// Token paused.
+    Pause,
+    /// Token unpaused.
+    Unpause,
+    /// Token metadata update.
+    UpdateMetadata,
+    /// Tokens bridged in and minted.
+    BridgeAndMint,
+    /// Tokens burned and bridged out.
+    BurnAndBridge,
+    /// Instruction this SDK version cannot decode.
+    Raw,
+}
+
+impl Display for OperationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            OperationKind::TokenCreate => "Create Token",
+            OperationKind::Transfer => "Transfer",
+            OperationKind::GrantAuthority => "Grant Authority",
+            OperationKind::RevokeAuthority => "Revoke Authority",
+            OperationKind::Blacklist => "Blacklist",
+            OperationKind::Whitelist => "Whitelist",
+            OperationKind::Mint => "Mint",
+            OperationKind::Burn => "Burn",
+            OperationKind::CloseAccount => "Close Account",
+            OperationKind::Pause => "Pause",
+            OperationKind::Unpause => "Unpause",
+            OperationKind::UpdateMetadata => "Update Metadata",
+            OperationKind::BridgeAndMint => "Bridge and Mint",
+            OperationKind::BurnAndBridge => "Burn and Bridge",
+            OperationKind::Raw => "Raw",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The part an address plays in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartyRole {
+    /// Signer of the transaction.
+    Signer,
+    /// Account receiving tokens.
+    Recipient,
+    /// Account tokens are taken from.
+    Source,
+    /// Account whose authority or list membership changes.
+    Subject,
+    /// Master authority assigned at token creation.
+    MasterAuthority,
+}
+
+impl Display for PartyRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let role = match self {
+            PartyRole::Signer => "Signer",
+            PartyRole::Recipient => "Recipient",
+            PartyRole::Source => "Source",
+            PartyRole::Subject => "Subject",
+            PartyRole::MasterAuthority => "Master Authority",
+        };
+        write!(f, "{}", role)
+    }
+}
+
+/// An address involved in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Party {
+    /// The part the address plays.
+    pub role: PartyRole,
+    /// The address.
+    pub address: Address,
+}
+
+/// A token amount, formatted with the token's decimals once known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DecodedAmount {
+    /// Amount in the token's smallest unit.
+    pub raw: U256,
+    /// Decimals of the token, if known.
+    pub decimals: Option<u8>,
+}
+
+impl Display for DecodedAmount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.decimals {
+            Some(decimals) => write!(f, "{}", format_units(self.raw, decimals)),
+            None => write!(f, "{} base units", self.raw),
+        }
+    }
+}
+
+/// An authority granted or revoked by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorityChange {
+    /// Whether the authority was granted or revoked.
+    pub action: AuthorityAction,
+    /// Authority type as reported by the L1.
+    pub authority: String,
+    /// Address gaining or losing the authority.
+    pub address: Address,
+    /// Mint allowance attached to the change, in base units.
+    pub allowance: Option<U256>,
+}
+
+impl Display for AuthorityChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {} for {}", self.action, self.authority, self.address)?;
+        if let Some(allowance) = self.allowance {
+            write!(f, " (allowance {})", allowance)?;
+        }
+        Ok(())
+    }
+}
+
+/// Display-ready description of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionSummary {
+    /// Transaction hash, when decoded from a full transaction.
+    pub hash: Option<B256>,
+    /// The operation performed.
+    pub kind: OperationKind,
+    /// Token the operation applies to; `None` for native transfers.
+    pub token: Option<Address>,
+    /// Addresses involved, starting with the signer.
+    pub parties: Vec<Party>,
+    /// Amount moved, minted or burned.
+    pub amount: Option<DecodedAmount>,
+    /// Authority change, for grant and revoke operations.
+    pub authority_change: Option<AuthorityChange>,
+    /// Further operation-specific fields as label/value pairs.
+    pub details: Vec<(String, String)>,
+}
+
+impl TransactionSummary {
+    /// Decode a full transaction.
+    pub fn from_transaction(transaction: &Transaction) -> Result<Self> {
+        let mut summary = Self::from_payload(transaction.from, &transaction.data)?;
+        summary.hash = Some(transaction.hash);
+        Ok(summary)
+    }
+
+    /// Decode a payload signed by `signer`.
+    ///
+    /// Amounts are left in base units; use
+    /// [`TransactionSummary::with_decimals`] once the token's decimals are
+    /// known.
+    pub fn from_payload(signer: Address, payload: &TxPayload) -> Result<Self> {
+        let mut summary = Self::new(signer, OperationKind::Raw, None);
+
+        match payload {
+            TxPayload::TokenCreate {
+                symbol,
+                decimals,
+                master_authority,
+                is_private,
+                name,
+            } => {
+                summary.kind = OperationKind::TokenCreate;
+                summary.party(PartyRole::MasterAuthority, *master_authority);
+                summary.detail("Symbol", symbol);
+                summary.detail("Name", name);
+                summary.detail("Decimals", decimals);
+                summary.detail("Private", is_private);
+            }
+            TxPayload::TokenTransfer {
+                value,
+                recipient,
+                token,
+            } => {
+                summary.kind = OperationKind::Transfer;
+                summary.token = *token;
+                summary.party(PartyRole::Recipient, *recipient);
+                summary.amount = Some(parse_amount("value", value)?);
+            }
+            TxPayload::TokenGrantAuthority {
+                authority_type,
+                authority_address,
+                value,
+                token,
+            } => {
+                summary.kind = OperationKind::GrantAuthority;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Subject, *authority_address);
+                summary.authority_change = Some(authority_change(
+                    AuthorityAction::Grant,
+                    authority_type,
+                    *authority_address,
+                    value.as_deref(),
+                )?);
+            }
+            TxPayload::TokenRevokeAuthority {
+                authority_type,
+                authority_address,
+                value,
+                token,
+            } => {
+                summary.kind = OperationKind::RevokeAuthority;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Subject, *authority_address);
+                summary.authority_change = Some(authority_change(
+                    AuthorityAction::Revoke,
+                    authority_type,
+                    *authority_address,
+                    value.as_deref(),
+                )?);
+            }
+            TxPayload::TokenBlacklistAccount { address, token } => {
+                summary.kind = OperationKind::Blacklist;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Subject, *address);
+            }
+            TxPayload::TokenWhitelistAccount { address, token } => {
+                summary.kind = OperationKind::Whitelist;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Subject, *address);
+            }
+            TxPayload::TokenMint {
+                value,
+                recipient,
+                token,
+            } => {
+                summary.kind = OperationKind::Mint;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Recipient, *recipient);
+                summary.amount = Some(parse_amount("value", value)?);
+            }
+            TxPayload::TokenBurn {
+                value,
+                recipient,
+                token,
+            } => {
+                summary.kind = OperationKind::Burn;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Source, *recipient);
+                summary.amount = Some(parse_amount("value", value)?);
+            }
+            TxPayload::TokenCloseAccount { token } => {
+                summary.kind = OperationKind::CloseAccount;
+                summary.token = Some(*token);
+            }
+            TxPayload::TokenPause { token } => {
+                summary.kind = OperationKind::Pause;
+                summary.token = Some(*token);
+            }
+            TxPayload::TokenUnpause { token } => {
+                summary.kind = OperationKind::Unpause;
+                summary.token = Some(*token);
+            }
+            TxPayload::TokenUpdateMetadata { metadata, token } => {
+                summary.kind = OperationKind::UpdateMetadata;
+                summary.token = Some(*token);
+                summary.detail("Name", &metadata.name);
+                summary.detail("URI", &metadata.uri);
+                for pair in &metadata.additional_metadata {
+                    summary.detail(&pair.key, &pair.value);
+                }
+            }
+            TxPayload::TokenBridgeAndMint {
+                recipient,
+                value,
+                source_chain_id,
+                source_tx_hash,
+                bridge_metadata,
+                token,
+            } => {
+                summary.kind = OperationKind::BridgeAndMint;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Recipient, *recipient);
+                summary.amount = Some(parse_amount("value", value)?);
+                summary.detail("Source Chain", source_chain_id);
+                summary.detail("Source Transaction", source_tx_hash);
+                if let Some(metadata) = bridge_metadata {
+                    summary.detail("Bridge Metadata", metadata);
+                }
+            }
+            TxPayload::TokenBurnAndBridge {
+                value,
+                sender,
+                destination_chain_id,
+                destination_address,
+                escrow_fee,
+                bridge_metadata,
+                token,
+            } => {
+                summary.kind = OperationKind::BurnAndBridge;
+                summary.token = Some(*token);
+                summary.party(PartyRole::Source, *sender);
+                summary.amount = Some(parse_amount("value", value)?);
+                summary.detail("Destination Chain", destination_chain_id);
+                summary.detail("Destination Address", destination_address);
+                summary.detail("Escrow Fee", escrow_fee);
+                if let Some(metadata) = bridge_metadata {
+                    summary.detail("Bridge Metadata", metadata);
+                }
+            }
+            TxPayload::Raw { input, token } => {
+                summary.token = Some(*token);
+                summary.detail("Input", input);
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Attach the token's decimals so amounts format as decimal strings.
+    pub fn with_decimals(mut self, decimals: u8) -> Self {
+        if let Some(amount) = self.amount.as_mut() {
+            amount.decimals = Some(decimals);
+        }
+        self
+    }
+
+    /// The transaction signer.
+    pub fn signer(&self) -> Address {
+        self.parties
+            .iter()
+            .find(|party| party.role == PartyRole::Signer)
+            .map(|party| party.address)
+            .unwrap_or_default()
+    }
+
+    fn new(signer: Address, kind: OperationKind, token: Option<Address>) -> Self {
+        Self {
+            hash: None,
+            kind,
+            token,
+            parties: vec![Party {
+                role: PartyRole::Signer,
+                address: signer,
+            }],
+            amount: None,
+            authority_change: None,
+            details: Vec::new(),
+        }
+    }
+
+    fn party(&mut self, role: PartyRole, address: Address) {
+        self.parties.push(Party { role, address });
+    }
+
+    fn detail<T: Display + ?Sized>(&mut self, label: &str, value: &T) {
+        self.details.push((label.to_string(), value.to_string()));
+    }
+}
+
+impl Display for TransactionSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.kind)?;
+        if let Some(hash) = &self.hash {
+            write!(f, " {}", hash)?;
+        }
+        writeln!(f)?;
+        match &self.token {
+            Some(token) => writeln!(f, "  Token: {}", token)?,
+            None if self.kind == OperationKind::Transfer => writeln!(f, "  Token: native")?,
+            None => {}
+        }
+        if let Some(amount) = &self.amount {
+            writeln!(f, "  Amount: {}", amount)?;
+        }
+        for party in &self.parties {
+            writeln!(f, "  {}: {}", party.role, party.address)?;
+        }
+        if let Some(change) = &self.authority_change {
+            writeln!(f, "  Authority: {}", change)?;
+        }
+        for (label, value) in &self.details {
+            writeln!(f, "  {}: {}", label, value)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_amount(field: &str, value: &str) -> Result<DecodedAmount> {
+    let raw = value
+        .parse::<U256>()
+        .map_err(|e| Error::validation(field, format!("Invalid amount '{}': {}", value, e)))?;
+    Ok(DecodedAmount {
+        raw,
+        decimals: None,
+    })
+}
+
+fn authority_change(
+    action: AuthorityAction,
+    authority: &str,
+    address: Address,
+    value: Option<&str>,
+) -> Result<AuthorityChange> {
+    let allowance = value
+        .map(|value| parse_amount("value", value).map(|amount| amount.raw))
+        .transpose()?;
+    Ok(AuthorityChange {
+        action,
+        authority: authority.to_string(),
+        address,
+        allowance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::repeat_byte(byte)
+    }
+
+    #[test]
+    fn test_decode_transfer_with_decimals() {
+        let payload = TxPayload::TokenTransfer {
+            value: "1500000".to_string(),
+            recipient: address(2),
+            token: Some(address(3)),
+        };
+        let summary = TransactionSummary::from_payload(address(1), &payload)
+            .expect("Transfer should decode")
+            .with_decimals(6);
+
+        assert_eq!(summary.kind, OperationKind::Transfer);
+        assert_eq!(summary.token, Some(address(3)));
+        assert_eq!(summary.signer(), address(1));
+        assert_eq!(
+            summary.parties[1],
+            Party {
+                role: PartyRole::Recipient,
+                address: address(2)
+            }
+        );
+        let amount = summary.amount.expect("Transfer has an amount");
+        assert_eq!(amount.to_string(), "1.5");
+    }
+
+    #[test]
+    fn test_decode_authority_grant() {
+        let payload = TxPayload::TokenGrantAuthority {
+            authority_type: "MintBurnTokens".to_string(),
+            authority_address: address(4),
+            value: Some("1000".to_string()),
+            token: address(3),
+        };
+        let summary =
+            TransactionSummary::from_payload(address(1), &payload).expect("Grant should decode");
+
+        let change = summary
+            .authority_change
+            .expect("Grant has an authority change");
+        assert_eq!(change.action, AuthorityAction::Grant);
+        assert_eq!(change.allowance, Some(U256::from(1000u64)));
+        assert_eq!(
+            change.to_string(),
+            format!("Grant MintBurnTokens for {} (allowance 1000)", address(4))
+        );
+        assert!(summary.amount.is_none());
+    }
+
+    #[test]
+    fn test_decode_transaction_sets_hash_and_display() {
+        let transaction = Transaction {
+            hash: B256::repeat_byte(0x09),
+            from: address(1),
+            data: TxPayload::TokenBurnAndBridge {
+                value: "25".to_string(),
+                sender: address(1),
+                destination_chain_id: 1,
+                destination_address: "0xabc".to_string(),
+                escrow_fee: "2".to_string(),
+                bridge_metadata: None,
+                token: address(3),
+            },
+            ..Transaction::default()
+        };
+        let summary =
+            TransactionSummary::from_transaction(&transaction).expect("Transaction should decode");
+        assert_eq!(summary.hash, Some(transaction.hash));
+
+        let rendered = summary.to_string();
+        assert!(rendered.starts_with(&format!("Burn and Bridge {}", transaction.hash)));
+        assert!(rendered.contains("  Amount: 25 base units"));
+        assert!(rendered.contains("  Destination Chain: 1"));
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_amount() {
+        let payload = TxPayload::TokenMint {
+            value: "ten".to_string(),
+            recipient: address(2),
+            token: address(3),
+        };
+        assert!(TransactionSummary::from_payload(address(1), &payload).is_err());
+    }
+}
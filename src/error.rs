@@ -1,8 +1,12 @@
 //! Error types for the OneMoney SDK.
 
+use crate::DryRunResult;
+use alloy_primitives::B256;
 use serde::{Deserialize, Serialize};
 use std::array::TryFromSliceError;
+use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::result::Result as StdResult;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Result type alias for OneMoney SDK operations.
@@ -30,10 +34,28 @@ pub enum Error {
         status_code: Option<u16>,
     },
 
-    /// Request timeout error.
+    /// A wait for a result, such as polling for a receipt, ran past its
+    /// deadline.
     #[error("Request timeout after {timeout_ms}ms to {endpoint}")]
     RequestTimeout { endpoint: String, timeout_ms: u64 },
 
+    /// The client gave up on a request after its own timeout of
+    /// `timeout_ms`.
+    ///
+    /// The server may still have processed the request, so a timed-out
+    /// submission can have executed.
+    #[error("Client timeout after {elapsed_ms}ms (limit {timeout_ms}ms) waiting for {endpoint}")]
+    ClientTimeout {
+        endpoint: String,
+        elapsed_ms: u64,
+        timeout_ms: u64,
+    },
+
+    /// The server answered 408, giving up on a request it had not fully
+    /// received, so the request was not processed.
+    #[error("Server timed out the request to {endpoint} after {elapsed_ms}ms")]
+    ServerTimeout { endpoint: String, elapsed_ms: u64 },
+
     /// Connection error.
     #[error("Connection failed: {0}")]
     Connection(String),
@@ -43,11 +65,22 @@ pub enum Error {
     DnsResolution(String),
 
     /// Response deserialization error.
-    #[error("Failed to deserialize {format} response: {error} - Response: {response}")]
+    ///
+    /// Responses the client fails to decode name the rejected value: its
+    /// JSON pointer, what was expected there and the start of what was
+    /// found, so schema drift shows which field changed. The response is
+    /// cut short after its first kilobyte.
+    #[error(
+        "Failed to deserialize {format} response{}: {error}{} - Response: {response}",
+        at(location),
+        found(location)
+    )]
     ResponseDeserialization {
         format: String,
         error: String,
         response: String,
+        /// Where decoding failed, when known.
+        location: Option<Box<DecodeLocation>>,
     },
 
     /// Authentication error.
@@ -105,9 +138,117 @@ pub enum Error {
     #[error("Validation failed: {field} - {message}")]
     Validation { field: String, message: String },
 
+    /// The server already accepted an identical transaction submission.
+    ///
+    /// `original_hash` is the accepted transaction's hash when the server
+    /// named it.
+    #[error(
+        "Duplicate submission: transaction already submitted{}",
+        original_hash.map(|hash| format!(" as {hash}")).unwrap_or_default()
+    )]
+    DuplicateSubmission { original_hash: Option<B256> },
+
+    /// The payload's nonce is not the signing account's next nonce.
+    #[error("Nonce mismatch: {message}")]
+    NonceMismatch { message: String },
+
+    /// The L1 rejected the submission because its view of the chain moved on
+    /// while the submission was in flight.
+    ///
+    /// The L1 API documents no such rejection; the variant is inferred from
+    /// the `business_stale_checkpoint` and `business_checkpoint_expired`
+    /// codes and from "checkpoint too old" messages, so it may never be
+    /// produced by a real node.
+    #[error("Stale checkpoint: {message}")]
+    StaleCheckpoint { message: String },
+
+    /// Persistent storage backend failure.
+    #[error("Storage error: {0}")]
+    Storage(String),
+
+    /// A payload is signed for a different chain than the client's network.
+    #[error("Chain ID mismatch: client expects {expected}, payload is for {actual}")]
+    ChainIdMismatch { expected: u64, actual: u64 },
+
+    /// The client's circuit breaker is open after repeated upstream failures,
+    /// so the request was not sent.
+    ///
+    /// Not [retryable](Error::is_retryable) as such: wait until `until`
+    /// before sending again.
+    #[error(
+        "Circuit breaker open for another {:?}",
+        .until.saturating_duration_since(Instant::now())
+    )]
+    CircuitOpen { until: Instant },
+
+    /// The client is in dry-run mode, so the signed request was returned
+    /// instead of being sent.
+    #[error("{0}")]
+    DryRun(Box<DryRunResult>),
+
+    /// Response body exceeded the client's configured size limit.
+    #[error("Response from {endpoint} exceeds the {limit} byte limit")]
+    ResponseTooLarge { endpoint: String, limit: usize },
+
+    /// A strict-mode client received response fields the SDK does not know.
+    #[error("Response from {endpoint} has unknown fields: {}", .fields.join(", "))]
+    UnknownFields {
+        endpoint: String,
+        fields: Vec<String>,
+    },
+
     /// Generic error with custom message.
     #[error("{0}")]
     Custom(String),
+
+    /// An error annotated with the request that produced it.
+    #[error("{0}")]
+    WithContext(Box<ApiFailure>),
+}
+
+/// Metadata about the request behind an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// HTTP method of the request.
+    pub method: &'static str,
+    /// Request path including the API version prefix and query.
+    pub path: String,
+    /// Status of the last response, if one was received.
+    pub status_code: Option<u16>,
+    /// Request ID the server assigned to the last response, if any.
+    pub request_id: Option<String>,
+    /// Time spent on the request, including retries and backoff.
+    pub elapsed: Duration,
+    /// Number of attempts made, including the first.
+    pub attempts: u32,
+}
+
+impl Display for RequestContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {}", self.method, self.path)?;
+        if let Some(status) = self.status_code {
+            write!(f, ", status {}", status)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id {}", request_id)?;
+        }
+        write!(
+            f,
+            ", {} attempt(s) in {}ms",
+            self.attempts,
+            self.elapsed.as_millis()
+        )
+    }
+}
+
+/// An error together with the request that produced it.
+#[derive(Error, Debug)]
+#[error("{error} [{context}]")]
+pub struct ApiFailure {
+    /// The underlying error.
+    pub error: Error,
+    /// The request that failed.
+    pub context: RequestContext,
 }
 
 /// Cryptographic operation errors.
@@ -132,6 +273,14 @@ pub enum CryptoError {
     /// Key derivation error.
     #[error("Key derivation failed: {0}")]
     KeyDerivation(String),
+
+    /// The requested key is locked or its unlock window has expired.
+    #[error("Key locked: {0}")]
+    KeyLocked(String),
+
+    /// The loaded key does not belong to the expected address.
+    #[error("Key mismatch: expected address {expected}, key derives {actual}")]
+    KeyMismatch { expected: String, actual: String },
 }
 
 /// Client configuration errors.
@@ -152,6 +301,10 @@ pub enum ConfigError {
     /// HTTP client builder failed.
     #[error("Failed to build HTTP client: {0}")]
     ClientBuilder(String),
+
+    /// A configuration file or environment variable holds an invalid setting.
+    #[error("Invalid setting {key}: {message}")]
+    InvalidSetting { key: String, message: String },
 }
 
 /// API error response structure.
@@ -189,29 +342,182 @@ impl Error {
         }
     }
 
+    /// Create a duplicate submission error.
+    pub fn duplicate_submission(original_hash: Option<B256>) -> Self {
+        Self::DuplicateSubmission { original_hash }
+    }
+
+    /// Create a nonce mismatch error.
+    pub fn nonce_mismatch<T: Into<String>>(message: T) -> Self {
+        Self::NonceMismatch {
+            message: message.into(),
+        }
+    }
+
+    /// Create a stale checkpoint error.
+    pub fn stale_checkpoint<T: Into<String>>(message: T) -> Self {
+        Self::StaleCheckpoint {
+            message: message.into(),
+        }
+    }
+
+    /// Create a circuit open error.
+    pub fn circuit_open(until: Instant) -> Self {
+        Self::CircuitOpen { until }
+    }
+
+    /// Create a storage error.
+    pub fn storage<T: Into<String>>(msg: T) -> Self {
+        Self::Storage(msg.into())
+    }
+
+    /// Create a chain ID mismatch error.
+    pub fn chain_id_mismatch(expected: u64, actual: u64) -> Self {
+        Self::ChainIdMismatch { expected, actual }
+    }
+
+    /// Create a dry run error carrying the request that was not sent.
+    pub fn dry_run(result: DryRunResult) -> Self {
+        Self::DryRun(Box::new(result))
+    }
+
+    /// Create a response too large error.
+    pub fn response_too_large<T: Into<String>>(endpoint: T, limit: usize) -> Self {
+        Self::ResponseTooLarge {
+            endpoint: endpoint.into(),
+            limit,
+        }
+    }
+
+    /// Create an unknown fields error listing the dot-separated paths of the
+    /// fields.
+    pub fn unknown_fields<T: Into<String>>(endpoint: T, fields: Vec<String>) -> Self {
+        Self::UnknownFields {
+            endpoint: endpoint.into(),
+            fields,
+        }
+    }
+
     /// Create a custom error.
     pub fn custom<T: Into<String>>(msg: T) -> Self {
         Self::Custom(msg.into())
     }
 
+    /// Annotate the error with the request that produced it.
+    ///
+    /// Replaces any context the error already carries.
+    pub fn with_context(self, context: RequestContext) -> Self {
+        Self::WithContext(Box::new(ApiFailure {
+            error: self.into_root(),
+            context,
+        }))
+    }
+
+    /// Metadata about the failed request, when the client attached it.
+    ///
+    /// Clients attach it when built with
+    /// [`ClientBuilder::error_context`](crate::ClientBuilder::error_context).
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            Self::WithContext(failure) => Some(&failure.context),
+            _ => None,
+        }
+    }
+
+    /// The error without any request context.
+    ///
+    /// Match on this rather than on the error itself when context may be
+    /// attached.
+    pub fn root(&self) -> &Error {
+        match self {
+            Self::WithContext(failure) => &failure.error,
+            error => error,
+        }
+    }
+
+    /// Strip any request context from the error.
+    pub fn into_root(self) -> Error {
+        match self {
+            Self::WithContext(failure) => failure.error,
+            error => error,
+        }
+    }
+
     /// Check if this is an API error.
     pub fn is_api_error(&self) -> bool {
-        matches!(self, Self::Api { .. })
+        matches!(self.root(), Self::Api { .. })
+    }
+
+    /// Check if this is a duplicate submission error.
+    pub fn is_duplicate_submission(&self) -> bool {
+        matches!(self.root(), Self::DuplicateSubmission { .. })
+    }
+
+    /// Check if this is a nonce mismatch error.
+    pub fn is_nonce_mismatch(&self) -> bool {
+        matches!(self.root(), Self::NonceMismatch { .. })
+    }
+
+    /// Check if this is a stale checkpoint error.
+    pub fn is_stale_checkpoint(&self) -> bool {
+        matches!(self.root(), Self::StaleCheckpoint { .. })
+    }
+
+    /// Check if the request was refused by an open circuit breaker.
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self.root(), Self::CircuitOpen { .. })
+    }
+
+    /// Check if the failed request may succeed when sent again unchanged.
+    ///
+    /// Covers timeouts, connection problems, rate limiting and server-side
+    /// errors; everything else reflects the request itself.
+    pub fn is_retryable(&self) -> bool {
+        match self.root() {
+            Self::RequestTimeout { .. }
+            | Self::ClientTimeout { .. }
+            | Self::ServerTimeout { .. }
+            | Self::Connection(_)
+            | Self::DnsResolution(_)
+            | Self::RateLimitExceeded { .. } => true,
+            Self::HttpTransport { status_code, .. } => {
+                status_code.is_none_or(|status| (500..=599).contains(&status))
+            }
+            Self::Api { status_code, .. } => (500..=599).contains(status_code),
+            _ => false,
+        }
+    }
+
+    /// The request a dry-run client did not send, if this is
+    /// [`Error::DryRun`].
+    pub fn dry_run_result(&self) -> Option<&DryRunResult> {
+        match self.root() {
+            Self::DryRun(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Check if a deadline passed: a client, server or wait timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self.root(),
+            Self::RequestTimeout { .. } | Self::ClientTimeout { .. } | Self::ServerTimeout { .. }
+        )
     }
 
     /// Check if this is a configuration error.
     pub fn is_config_error(&self) -> bool {
-        matches!(self, Self::Config(_))
+        matches!(self.root(), Self::Config(_))
     }
 
     /// Check if this is a cryptographic error.
     pub fn is_crypto_error(&self) -> bool {
-        matches!(self, Self::Crypto(_))
+        matches!(self.root(), Self::Crypto(_))
     }
 
     /// Get the status code if this is an API error.
     pub fn status_code(&self) -> Option<u16> {
-        match self {
+        match self.root() {
             Self::Api { status_code, .. } => Some(*status_code),
             _ => None,
         }
@@ -219,7 +525,7 @@ impl Error {
 
     /// Get the error code if this is an API error.
     pub fn error_code(&self) -> Option<&str> {
-        match self {
+        match self.root() {
             Self::Api { error_code, .. } => Some(error_code),
             _ => None,
         }
@@ -241,6 +547,23 @@ impl Error {
         }
     }
 
+    /// Create a client timeout error.
+    pub fn client_timeout<T: Into<String>>(endpoint: T, elapsed_ms: u64, timeout_ms: u64) -> Self {
+        Self::ClientTimeout {
+            endpoint: endpoint.into(),
+            elapsed_ms,
+            timeout_ms,
+        }
+    }
+
+    /// Create a server timeout error.
+    pub fn server_timeout<T: Into<String>>(endpoint: T, elapsed_ms: u64) -> Self {
+        Self::ServerTimeout {
+            endpoint: endpoint.into(),
+            elapsed_ms,
+        }
+    }
+
     /// Create a connection error.
     pub fn connection<T: Into<String>>(message: T) -> Self {
         Self::Connection(message.into())
@@ -261,6 +584,7 @@ impl Error {
             format: format.into(),
             error: error.into(),
             response: response.into(),
+            location: None,
         }
     }
 
@@ -309,6 +633,37 @@ impl Error {
     }
 }
 
+/// Where in a response decoding failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeLocation {
+    /// JSON pointer to the rejected value, empty for the whole response.
+    /// `None` when the response is not valid JSON.
+    pub pointer: Option<String>,
+    /// Type or field serde expected at `pointer`.
+    pub expected: Option<String>,
+    /// Start of the rejected value, or of the text around a syntax error.
+    pub excerpt: Option<String>,
+}
+
+fn at(location: &Option<Box<DecodeLocation>>) -> String {
+    match location
+        .as_ref()
+        .and_then(|location| location.pointer.as_deref())
+    {
+        Some("") => " at the top level".to_string(),
+        Some(pointer) => format!(" at {}", pointer),
+        None => String::new(),
+    }
+}
+
+fn found(location: &Option<Box<DecodeLocation>>) -> String {
+    location
+        .as_ref()
+        .and_then(|location| location.excerpt.as_deref())
+        .map(|excerpt| format!(" (found {})", excerpt))
+        .unwrap_or_default()
+}
+
 impl From<TryFromSliceError> for Error {
     fn from(_err: TryFromSliceError) -> Self {
         Self::ArrayConversion {
@@ -319,14 +674,17 @@ impl From<TryFromSliceError> for Error {
 }
 
 /// Enhanced reqwest error mapping with L1 compatibility.
+#[cfg(feature = "client")]
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
         if err.is_timeout() {
-            Error::request_timeout(
+            // The transport fills in the elapsed time and the limit that applied.
+            Error::client_timeout(
                 err.url()
                     .map(|u| u.to_string())
                     .unwrap_or_else(|| "unknown".to_string()),
-                30000, // Default timeout assumption
+                0,
+                0,
             )
         } else if err.is_connect() {
             Error::connection(format!("Connection failed: {}", err))
@@ -374,6 +732,19 @@ impl CryptoError {
     pub fn key_derivation<T: Into<String>>(msg: T) -> Self {
         Self::KeyDerivation(msg.into())
     }
+
+    /// Create a key locked error.
+    pub fn key_locked<T: Into<String>>(msg: T) -> Self {
+        Self::KeyLocked(msg.into())
+    }
+
+    /// Create a key mismatch error.
+    pub fn key_mismatch<T: Into<String>, U: Into<String>>(expected: T, actual: U) -> Self {
+        Self::KeyMismatch {
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
 }
 
 impl ConfigError {
@@ -396,6 +767,14 @@ impl ConfigError {
     pub fn client_builder<T: Into<String>>(msg: T) -> Self {
         Self::ClientBuilder(msg.into())
     }
+
+    /// Create an invalid setting error for `key`.
+    pub fn invalid_setting<K: Into<String>, M: Into<String>>(key: K, message: M) -> Self {
+        Self::InvalidSetting {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -439,6 +818,34 @@ mod tests {
         let validation_error = Error::validation("email", "Invalid email format");
         assert!(matches!(validation_error, Error::Validation { .. }));
 
+        // Test storage error creation
+        let storage_error = Error::storage("disk full");
+        assert!(matches!(storage_error, Error::Storage(_)));
+        assert_eq!(storage_error.to_string(), "Storage error: disk full");
+
+        // Test chain ID mismatch error creation
+        let mismatch = Error::chain_id_mismatch(21210, 1212101);
+        assert!(matches!(
+            mismatch,
+            Error::ChainIdMismatch {
+                expected: 21210,
+                actual: 1212101
+            }
+        ));
+
+        // Test response too large error creation
+        let too_large = Error::response_too_large("/v1/checkpoints/by_number", 1024);
+        assert!(matches!(
+            too_large,
+            Error::ResponseTooLarge { limit: 1024, .. }
+        ));
+
+        let drift = Error::unknown_fields("/v1/chains/chain_id", vec!["epoch".to_string()]);
+        assert_eq!(
+            drift.to_string(),
+            "Response from /v1/chains/chain_id has unknown fields: epoch"
+        );
+
         // Test custom error creation
         let custom_error = Error::custom("Custom error message");
         assert!(matches!(custom_error, Error::Custom(_)));
@@ -561,6 +968,22 @@ mod tests {
         assert!(crypto_error.is_crypto_error());
     }
 
+    #[test]
+    fn test_error_is_retryable() {
+        assert!(Error::request_timeout("/v1/chains/chain_id", 100).is_retryable());
+        assert!(Error::client_timeout("/v1/chains/chain_id", 101, 100).is_retryable());
+        assert!(Error::server_timeout("/v1/chains/chain_id", 40).is_retryable());
+        assert!(Error::rate_limit_exceeded(None).is_retryable());
+        assert!(Error::http_transport("gateway", Some(502)).is_retryable());
+        assert!(Error::http_transport("reset", None).is_retryable());
+        assert!(Error::api(503, "busy".to_string(), "busy".to_string()).is_retryable());
+
+        assert!(!Error::http_transport("bad request", Some(400)).is_retryable());
+        assert!(!Error::api(422, "business".to_string(), "no".to_string()).is_retryable());
+        assert!(!Error::validation("value", "zero").is_retryable());
+        assert!(!Error::duplicate_submission(None).is_retryable());
+    }
+
     #[test]
     fn test_status_code_and_error_code_extraction() {
         let api_error = Error::api(
@@ -748,4 +1171,34 @@ mod tests {
         // The main error should have the config error as its source
         assert!(main_error.source().is_some());
     }
+
+    #[test]
+    fn test_error_context_wraps_and_unwraps() {
+        let context = RequestContext {
+            method: "GET",
+            path: "/v1/transactions/by_hash?hash=0x01".to_string(),
+            status_code: Some(503),
+            request_id: Some("req-42".to_string()),
+            elapsed: Duration::from_millis(120),
+            attempts: 3,
+        };
+        let error =
+            Error::api(503, "unavailable".into(), "Try again".into()).with_context(context.clone());
+
+        assert_eq!(error.context(), Some(&context));
+        assert_eq!(error.status_code(), Some(503));
+        assert!(error.is_retryable());
+        assert!(matches!(error.root(), Error::Api { .. }));
+        assert!(error.to_string().contains("request id req-42"));
+        assert!(error.to_string().contains("3 attempt(s) in 120ms"));
+
+        // Re-annotating replaces the context instead of nesting it.
+        let rewrapped = error.with_context(RequestContext {
+            attempts: 1,
+            ..context
+        });
+        assert_eq!(rewrapped.context().map(|context| context.attempts), Some(1));
+        assert!(matches!(rewrapped.into_root(), Error::Api { .. }));
+        assert!(Error::custom("local").context().is_none());
+    }
 }
@@ -24,23 +24,69 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Cargo Features
+//!
+//! The default features build the full client. Services that only sign or
+//! encode payloads can use `default-features = false`, which leaves the
+//! types, crypto and encoding layer without reqwest or tokio:
+//!
+//! - `client`: the HTTP client, transport and API operations
+//! - `rustls` / `native-tls`: the TLS backend; either implies `client`
+//! - `tokens`: token management operations and supply queries
+//! - `checkpoints`: checkpoint ranges and streams, watchers and reporting
+//! - `wallet`: [`Wallet`] and [`EvmWallet`] key generation
+//! - `keystore`: password-encrypted keystore files for wallets, with
+//!   password rotation and scrypt cost upgrades; implies `wallet`
 
+#[cfg(feature = "client")]
 pub mod api;
 pub mod client;
 pub mod crypto;
+pub mod decode;
 pub mod error;
+#[cfg(feature = "client")]
+pub mod pipeline;
+pub mod registry;
+#[cfg(feature = "checkpoints")]
+pub mod reporting;
+pub mod storage;
+#[cfg(feature = "client")]
 pub mod transport;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "checkpoints")]
+pub mod watcher;
+
+#[cfg(feature = "testkit")]
+pub mod testkit;
+
+#[cfg(feature = "testvectors")]
+pub mod testvectors;
+
+#[cfg(feature = "perf")]
+pub mod perf;
+
+#[cfg(feature = "schema")]
+pub mod schema;
 
 // Re-export payload types from requests module
-pub use client::{Client, ClientBuilder, Network};
+#[cfg(feature = "client")]
+pub use api::OneMoneyApi;
+pub use client::{ApiVersion, Network};
+#[cfg(feature = "client")]
+pub use client::{Client, ClientBuilder, PageStream, RecoveryEvent, RecoveryPolicy, SubmitOptions};
 pub use crypto::{Signable, sign_transaction_payload, *};
-pub use error::{ConfigError, CryptoError, Error, Result};
+pub use error::{
+    ApiFailure, ConfigError, CryptoError, DecodeLocation, Error, RequestContext, Result,
+};
+pub use registry::*;
 pub use requests::{
-    PaymentPayload, TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload,
-    TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
+    NativePaymentPayload, PaymentPayload, RawTransaction, TokenAuthorityPayload,
+    TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload, TokenMintPayload,
+    TokenPausePayload, TokenWhitelistPayload,
 };
+#[cfg(feature = "client")]
 pub use transport::*;
 pub use types::requests;
 pub use types::*;
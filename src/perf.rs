@@ -0,0 +1,268 @@
+//! Timing budgets for signing and serialization, for CI regression checks.
+//!
+//! Enabled with the `perf` feature. The criterion suite in `benches/` gives
+//! detailed numbers but no pass or fail; [`assert_budget`] times an
+//! operation over several samples and panics when its median is slower than
+//! a fixed budget, so a test can reject an encoding change that makes
+//! signing measurably slower. [`assert_payload_budget`] applies a
+//! [`PayloadBudget`] to every stage a payload goes through before it is
+//! submitted.
+//!
+//! Budgets only mean something for optimized code: run budget tests with
+//! `cargo test --release`.
+
+use crate::crypto::{SecretKeyMaterial, sign_hash};
+use crate::requests::TransactionPayload;
+use crate::requests::submission::short_type_name;
+use serde::de::DeserializeOwned;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Number of timed samples a measurement takes its median over.
+pub const DEFAULT_SAMPLES: usize = 11;
+
+/// Number of times the operation runs in each sample.
+pub const DEFAULT_ITERATIONS: u32 = 100;
+
+/// Timing of one operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    /// What was measured, such as `PaymentPayload::sign`.
+    pub name: String,
+    /// Median time of one run across the samples.
+    pub median: Duration,
+    /// Time of one run in the fastest sample.
+    pub fastest: Duration,
+    /// Number of timed samples.
+    pub samples: usize,
+    /// Runs in each sample.
+    pub iterations: u32,
+}
+
+impl Measurement {
+    /// Whether the median run is no slower than `budget`.
+    pub fn within(&self, budget: Duration) -> bool {
+        self.median <= budget
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{}: {:?} per run (fastest {:?}, {} samples of {})",
+            self.name, self.median, self.fastest, self.samples, self.iterations
+        )
+    }
+}
+
+/// Time `operation` with [`DEFAULT_SAMPLES`] samples of
+/// [`DEFAULT_ITERATIONS`] runs each.
+pub fn measure<T, F>(name: &str, operation: F) -> Measurement
+where
+    F: FnMut() -> T,
+{
+    measure_with(name, DEFAULT_SAMPLES, DEFAULT_ITERATIONS, operation)
+}
+
+/// Time `operation` with a chosen number of samples and runs per sample.
+///
+/// One untimed sample runs first to warm caches and lazy initialization.
+pub fn measure_with<T, F>(
+    name: &str,
+    samples: usize,
+    iterations: u32,
+    mut operation: F,
+) -> Measurement
+where
+    F: FnMut() -> T,
+{
+    let samples = samples.max(1);
+    let iterations = iterations.max(1);
+    for _ in 0..iterations {
+        black_box(operation());
+    }
+
+    let mut timings: Vec<Duration> = (0..samples)
+        .map(|_| {
+            let started = Instant::now();
+            for _ in 0..iterations {
+                black_box(operation());
+            }
+            started.elapsed() / iterations
+        })
+        .collect();
+    timings.sort_unstable();
+
+    Measurement {
+        name: name.to_string(),
+        median: timings[samples / 2],
+        fastest: timings[0],
+        samples,
+        iterations,
+    }
+}
+
+/// Time `operation` and panic if its median run is slower than `budget`.
+///
+/// # Example
+///
+/// ```rust
+/// use onemoney_protocol::PaymentPayload;
+/// use onemoney_protocol::Signable;
+/// use onemoney_protocol::perf::assert_budget;
+/// use alloy_primitives::Address;
+/// use std::time::Duration;
+///
+/// let payload = PaymentPayload::new(1212101, 0, Address::repeat_byte(1), 100u64, Address::repeat_byte(2))?;
+/// let measurement = assert_budget("payment hash", Duration::from_secs(1), || payload.signature_hash());
+/// println!("{}", measurement);
+/// # Ok::<(), onemoney_protocol::Error>(())
+/// ```
+#[track_caller]
+pub fn assert_budget<T, F>(name: &str, budget: Duration, operation: F) -> Measurement
+where
+    F: FnMut() -> T,
+{
+    let measurement = measure(name, operation);
+    assert!(
+        measurement.within(budget),
+        "{} is over its budget of {:?}: {}",
+        name,
+        budget,
+        measurement
+    );
+    measurement
+}
+
+/// Per-run budgets for each stage of preparing a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayloadBudget {
+    /// RLP encoding.
+    pub encode: Duration,
+    /// Keccak-256 of the encoding.
+    pub hash: Duration,
+    /// Hashing and signing with a private key.
+    pub sign: Duration,
+    /// Serializing to JSON.
+    pub serialize: Duration,
+    /// Deserializing from JSON.
+    pub deserialize: Duration,
+}
+
+impl Default for PayloadBudget {
+    /// Budgets with ample headroom over an optimized build on a CI runner.
+    ///
+    /// Signing dominates; at these limits one core still prepares several
+    /// thousand payloads a second.
+    fn default() -> Self {
+        Self {
+            encode: Duration::from_micros(20),
+            hash: Duration::from_micros(20),
+            sign: Duration::from_micros(500),
+            serialize: Duration::from_micros(50),
+            deserialize: Duration::from_micros(50),
+        }
+    }
+}
+
+/// Check every stage of preparing `payload` against `budget`, panicking on
+/// the first that is too slow or fails.
+///
+/// Returns the measurements, named after the payload type and stage, such
+/// as `TokenMintPayload::encode`.
+#[track_caller]
+pub fn assert_payload_budget<P, K>(
+    payload: &P,
+    private_key: K,
+    budget: &PayloadBudget,
+) -> Vec<Measurement>
+where
+    P: TransactionPayload + DeserializeOwned,
+    K: Into<SecretKeyMaterial>,
+{
+    let type_name = short_type_name::<P>();
+    let secret = private_key.into();
+    if let Err(error) = sign_hash(&payload.signature_hash(), &secret) {
+        panic!("{} cannot be signed: {}", type_name, error);
+    }
+    let json = match serde_json::to_string(payload) {
+        Ok(json) => json,
+        Err(error) => panic!("{} does not serialize: {}", type_name, error),
+    };
+    if let Err(error) = serde_json::from_str::<P>(&json) {
+        panic!("{} does not deserialize: {}", type_name, error);
+    }
+
+    vec![
+        assert_budget(&format!("{}::encode", type_name), budget.encode, || {
+            payload.to_rlp()
+        }),
+        assert_budget(&format!("{}::hash", type_name), budget.hash, || {
+            payload.signature_hash()
+        }),
+        assert_budget(&format!("{}::sign", type_name), budget.sign, || {
+            sign_hash(&payload.signature_hash(), &secret)
+        }),
+        assert_budget(
+            &format!("{}::serialize", type_name),
+            budget.serialize,
+            || serde_json::to_string(payload),
+        ),
+        assert_budget(
+            &format!("{}::deserialize", type_name),
+            budget.deserialize,
+            || serde_json::from_str::<P>(&json),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PaymentPayload;
+    use alloy_primitives::{Address, U256};
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_payload_budget_names_every_stage() {
+        let payload = PaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 1,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000u64),
+            token: Address::repeat_byte(0x22),
+        };
+        let generous = Duration::from_secs(1);
+        let budget = PayloadBudget {
+            encode: generous,
+            hash: generous,
+            sign: generous,
+            serialize: generous,
+            deserialize: generous,
+        };
+        let names: Vec<String> = assert_payload_budget(&payload, PRIVATE_KEY, &budget)
+            .into_iter()
+            .map(|measurement| measurement.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "PaymentPayload::encode",
+                "PaymentPayload::hash",
+                "PaymentPayload::sign",
+                "PaymentPayload::serialize",
+                "PaymentPayload::deserialize"
+            ]
+        );
+
+        let over = std::panic::catch_unwind(|| {
+            assert_budget("sleep", Duration::ZERO, || {
+                std::thread::sleep(Duration::from_micros(10))
+            })
+        });
+        assert!(over.is_err(), "A zero budget must fail");
+    }
+}
@@ -0,0 +1,8 @@
+//! Sequencing of dependent transactions and scheduling of delayed ones.
+
+pub mod schedule;
+pub mod sequence;
+
+// Re-export public interfaces
+pub use schedule::*;
+pub use sequence::*;
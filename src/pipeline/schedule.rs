@@ -0,0 +1,303 @@
+//! Transactions held back until a point in time or a checkpoint.
+//!
+//! The L1 executes a transaction as soon as it is accepted; payloads have no
+//! field that delays execution. [`Scheduler`] keeps the payload in the SDK
+//! instead and signs it only when it is due, so the nonce is the account's
+//! next one at that moment rather than when the transfer was planned.
+//! Scheduled transactions live in the process that scheduled them and are
+//! lost if it exits.
+
+use super::sequence::CancelHandle;
+use crate::Result;
+use crate::client::{Client, SubmitOptions};
+use crate::crypto::{SecretKeyMaterial, signing_key_address};
+use crate::error::Error;
+use crate::requests::TransactionPayload;
+use crate::responses::TransactionResponse;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+/// Default interval between checkpoint checks while waiting for one.
+pub const DEFAULT_SCHEDULE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// When a scheduled transaction is signed and submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExecuteAt {
+    /// Once the local clock reaches this time.
+    Time(SystemTime),
+    /// Once the chain's latest checkpoint is at least this number.
+    Checkpoint(u64),
+}
+
+impl From<SystemTime> for ExecuteAt {
+    fn from(time: SystemTime) -> Self {
+        ExecuteAt::Time(time)
+    }
+}
+
+impl Display for ExecuteAt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ExecuteAt::Time(time) => write!(f, "{:?}", time),
+            ExecuteAt::Checkpoint(number) => write!(f, "checkpoint #{}", number),
+        }
+    }
+}
+
+/// Signs and submits payloads from one account when they are due.
+///
+/// Each scheduled payload gets the account's next nonce, reserved through
+/// the client's [`NonceManager`](crate::client::NonceManager) at execution
+/// time, so transactions scheduled from the same account in any order do
+/// not collide. Checkpoint targets are checked against
+/// [`Client::chain_state`], which answers from the client's state tracker.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::pipeline::{ExecuteAt, Scheduler};
+/// use onemoney_protocol::{Client, PaymentPayload};
+/// use alloy_primitives::Address;
+/// use std::str::FromStr;
+/// use std::time::{Duration, SystemTime};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::testnet()?;
+///     let scheduler = Scheduler::new(client, "your_private_key_here");
+///
+///     // The nonce is filled in when the payment is signed.
+///     let payload = PaymentPayload::new(
+///         1212101,
+///         0,
+///         Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?,
+///         "1000000",
+///         Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?,
+///     )?;
+///     let payout = scheduler.schedule(payload, SystemTime::now() + Duration::from_secs(3600));
+///
+///     let response = payout.outcome().await?;
+///     println!("Submitted {}", response.hash);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Scheduler {
+    client: Client,
+    private_key: SecretKeyMaterial,
+    options: SubmitOptions,
+    poll_interval: Duration,
+}
+
+impl Scheduler {
+    /// Schedule transactions signed with `private_key` on `client`.
+    pub fn new<K: Into<SecretKeyMaterial>>(client: Client, private_key: K) -> Self {
+        Self {
+            client,
+            private_key: private_key.into(),
+            options: SubmitOptions::new(),
+            poll_interval: DEFAULT_SCHEDULE_POLL_INTERVAL,
+        }
+    }
+
+    /// Set how rejected submissions are repaired when they are due.
+    pub fn submit_options(mut self, options: SubmitOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the interval between checkpoint checks.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Submit `payload` once `at` is reached.
+    ///
+    /// The payload's nonce is replaced when it is signed; every other field
+    /// is submitted as given. A time in the past or a checkpoint already
+    /// reached executes right away.
+    pub fn schedule<P, T>(&self, payload: P, at: T) -> ScheduledTransaction
+    where
+        P: TransactionPayload + Send + Sync + 'static,
+        T: Into<ExecuteAt>,
+    {
+        let at = at.into();
+        let cancel = CancelHandle::new();
+        let client = self.client.clone();
+        let private_key = self.private_key.clone();
+        let options = self.options;
+        let poll_interval = self.poll_interval;
+        let waiting = cancel.clone();
+        let task = tokio::spawn(async move {
+            tokio::select! {
+                due = wait_until(&client, at, poll_interval) => due?,
+                _ = waiting.cancelled() => return Err(cancelled(at)),
+            }
+            execute(&client, payload, &private_key, options).await
+        });
+        ScheduledTransaction { at, cancel, task }
+    }
+}
+
+/// A transaction waiting for its [`ExecuteAt`].
+#[derive(Debug)]
+pub struct ScheduledTransaction {
+    at: ExecuteAt,
+    cancel: CancelHandle,
+    task: JoinHandle<Result<TransactionResponse>>,
+}
+
+impl ScheduledTransaction {
+    /// When the transaction is due.
+    pub fn execute_at(&self) -> ExecuteAt {
+        self.at
+    }
+
+    /// Drop the transaction if it is still waiting.
+    ///
+    /// A transaction that is already being signed or submitted is not
+    /// stopped.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Whether the transaction was submitted, failed or was cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Wait for the transaction to be submitted.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the accepted transaction, or why it was not submitted.
+    pub async fn outcome(self) -> Result<TransactionResponse> {
+        self.task
+            .await
+            .map_err(|e| Error::custom(format!("Scheduled transaction task failed: {}", e)))?
+    }
+}
+
+async fn wait_until(client: &Client, at: ExecuteAt, poll_interval: Duration) -> Result<()> {
+    match at {
+        ExecuteAt::Time(time) => {
+            if let Ok(remaining) = time.duration_since(SystemTime::now()) {
+                sleep(remaining).await;
+            }
+            Ok(())
+        }
+        ExecuteAt::Checkpoint(number) => loop {
+            match client.chain_state().await {
+                Ok(state) if state.checkpoint >= number => return Ok(()),
+                Ok(_) => {}
+                Err(error) if error.is_retryable() => {}
+                Err(error) => return Err(error),
+            }
+            sleep(poll_interval).await;
+        },
+    }
+}
+
+async fn execute<P>(
+    client: &Client,
+    mut payload: P,
+    private_key: &SecretKeyMaterial,
+    options: SubmitOptions,
+) -> Result<TransactionResponse>
+where
+    P: TransactionPayload,
+{
+    let signer = signing_key_address(&private_key.signing_key()?);
+    payload.set_nonce(client.next_nonce(signer).await?);
+    let result = client
+        .submit_with_options(payload, private_key.expose_secret(), options)
+        .await;
+    if result.is_err() {
+        // The reserved nonce was not used; resynchronize from the network.
+        client.nonce_manager().invalidate(signer);
+    }
+    result
+}
+
+fn cancelled(at: ExecuteAt) -> Error {
+    Error::business_logic("schedule", format!("cancelled before {}", at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::PaymentPayload;
+    use crate::{ClientBuilder, Network};
+    use alloy_primitives::{Address, U256};
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[tokio::test]
+    async fn test_scheduled_payment_signed_with_fresh_nonce() {
+        let mut server = mockito::Server::new_async().await;
+        let _epoch = server
+            .mock("GET", "/v1/governances/epoch")
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"epoch_id": 1, "certificate_hash": "0x{}", "certificate": null}}"#,
+                "cd".repeat(32)
+            ))
+            .create_async()
+            .await;
+        let _checkpoint = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 12}"#)
+            .create_async()
+            .await;
+        let _nonce = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+            )
+            .with_status(200)
+            .with_body(r#"{"nonce": 9}"#)
+            .create_async()
+            .await;
+        let payment = server
+            .mock("POST", "/v1/transactions/payment")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"nonce": 9}"#.to_string(),
+            ))
+            .with_status(200)
+            .with_body(format!(r#"{{"hash": "0x{}"}}"#, "ab".repeat(32)))
+            .create_async()
+            .await;
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let scheduler =
+            Scheduler::new(client, PRIVATE_KEY).poll_interval(Duration::from_millis(10));
+        let payload = PaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 0,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000u64),
+            token: Address::repeat_byte(0x22),
+        };
+
+        let waiting = scheduler.schedule(payload.clone(), ExecuteAt::Checkpoint(1_000));
+        waiting.cancel();
+        let error = waiting.outcome().await.expect_err("Cancelled before due");
+        assert!(
+            error
+                .to_string()
+                .contains("cancelled before checkpoint #1000")
+        );
+
+        let due = scheduler.schedule(payload, ExecuteAt::Checkpoint(12));
+        assert_eq!(due.execute_at(), ExecuteAt::Checkpoint(12));
+        let response = due.outcome().await.expect("Due payment is submitted");
+        assert_eq!(response.hash.to_string(), format!("0x{}", "ab".repeat(32)));
+        payment.assert_async().await;
+    }
+}
@@ -0,0 +1,477 @@
+//! Pipelines that submit transactions one after another, each waiting for the
+//! previous one to be included, with compensation on failure.
+
+use crate::Result;
+use crate::client::Client;
+use crate::error::Error;
+use crate::responses::{TransactionReceipt, TransactionResponse};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::future::Future;
+use std::mem::take;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Default time to wait for each step's receipt.
+pub const DEFAULT_STEP_RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+type StepFuture<'a> = Pin<Box<dyn Future<Output = Result<TransactionResponse>> + Send + 'a>>;
+type SubmitFn<'a> = Box<dyn FnOnce() -> StepFuture<'a> + Send + 'a>;
+type CompensateFn<'a> = Box<dyn FnOnce(TransactionReceipt) -> StepFuture<'a> + Send + 'a>;
+
+/// Handle that cancels a running [`Pipeline`].
+///
+/// Cancellation takes effect between steps. A step whose transaction was
+/// already submitted is waited on until its receipt arrives, so it is
+/// recorded, and compensated, like any other completed step.
+/// [`ScheduledTransaction`](super::ScheduledTransaction) uses the same
+/// handle while it waits to be due.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl CancelHandle {
+    pub(crate) fn new() -> Self {
+        Self {
+            sender: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Request cancellation.
+    pub fn cancel(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// Whether cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    pub(crate) async fn cancelled(&self) {
+        let mut receiver = self.sender.subscribe();
+        // The sender lives as long as `self`, so the wait cannot fail.
+        let _ = receiver.wait_for(|cancelled| *cancelled).await;
+    }
+}
+
+/// A step that was included successfully.
+#[derive(Debug, Clone)]
+pub struct StepRecord {
+    /// Step name.
+    pub name: String,
+    /// Receipt of the step's transaction.
+    pub receipt: TransactionReceipt,
+}
+
+/// Outcome of running a compensation for a completed step.
+#[derive(Debug)]
+pub struct CompensationRecord {
+    /// Name of the step being compensated.
+    pub step: String,
+    /// Receipt of the compensating transaction, or why it failed.
+    pub result: Result<TransactionReceipt>,
+}
+
+/// How a pipeline run ended.
+#[derive(Debug)]
+pub enum PipelineStatus {
+    /// Every step was included successfully.
+    Completed,
+    /// A step failed to submit, failed on-chain or timed out.
+    Failed {
+        /// Name of the failed step.
+        step: String,
+        /// Why the step failed.
+        error: Error,
+    },
+    /// The pipeline was cancelled before a step was submitted.
+    Cancelled {
+        /// Name of the first step that was not submitted.
+        step: String,
+    },
+}
+
+/// Report of a pipeline run.
+#[derive(Debug)]
+pub struct PipelineReport {
+    /// Steps that completed, in order.
+    pub steps: Vec<StepRecord>,
+    /// How the run ended.
+    pub status: PipelineStatus,
+    /// Compensations run after a failure or cancellation, in the order run.
+    pub compensations: Vec<CompensationRecord>,
+}
+
+impl PipelineReport {
+    /// Whether every step completed.
+    pub fn is_completed(&self) -> bool {
+        matches!(self.status, PipelineStatus::Completed)
+    }
+
+    /// Convert the report into the completed steps, or the reason the run stopped.
+    pub fn into_result(self) -> Result<Vec<StepRecord>> {
+        match self.status {
+            PipelineStatus::Completed => Ok(self.steps),
+            PipelineStatus::Failed { error, .. } => Err(error),
+            PipelineStatus::Cancelled { step } => Err(Error::business_logic(
+                "pipeline",
+                format!("cancelled at step '{}'", step),
+            )),
+        }
+    }
+}
+
+struct Step<'a> {
+    name: String,
+    submit: SubmitFn<'a>,
+    compensate: Option<CompensateFn<'a>>,
+}
+
+/// Ordered sequence of dependent transactions.
+///
+/// Each step submits one transaction; the next step starts only once the
+/// previous transaction is included and succeeded. When a step fails or the
+/// pipeline is cancelled, the compensations of the completed steps run in
+/// reverse order.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::pipeline::Pipeline;
+/// use onemoney_protocol::{Authority, AuthorityAction, Client, TokenAuthorityPayload, TokenMintPayload};
+/// use alloy_primitives::{Address, U256};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::testnet()?;
+///     let token = Address::ZERO;
+///     let minter = Address::ZERO;
+///     let master_key = "0x...";
+///     let minter_key = "0x...";
+///
+///     let grant = TokenAuthorityPayload {
+///         chain_id: 1212101,
+///         nonce: 0,
+///         action: AuthorityAction::Grant,
+///         authority_type: Authority::MintBurnTokens,
+///         authority_address: minter,
+///         token,
+///         value: U256::from(1000u64),
+///     };
+///     let revoke = TokenAuthorityPayload {
+///         nonce: 1,
+///         action: AuthorityAction::Revoke,
+///         ..grant.clone()
+///     };
+///     let mint = TokenMintPayload {
+///         chain_id: 1212101,
+///         nonce: 0,
+///         recipient: minter,
+///         value: U256::from(1000u64),
+///         token,
+///     };
+///
+///     let client = &client;
+///     let report = Pipeline::new(client)
+///         .step("grant", move || client.grant_authority(grant, master_key))
+///         .compensate(move |_| client.revoke_authority(revoke, master_key))
+///         .step("mint", move || client.mint_token(mint, minter_key))
+///         .run()
+///         .await;
+///
+///     for step in report.into_result()? {
+///         println!("{}: {}", step.name, step.receipt.transaction_hash);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct Pipeline<'a> {
+    client: &'a Client,
+    steps: Vec<Step<'a>>,
+    receipt_timeout: Duration,
+    cancel: CancelHandle,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Create an empty pipeline.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            steps: Vec::new(),
+            receipt_timeout: DEFAULT_STEP_RECEIPT_TIMEOUT,
+            cancel: CancelHandle::new(),
+        }
+    }
+
+    /// Set how long to wait for each step's receipt.
+    pub fn receipt_timeout(mut self, timeout: Duration) -> Self {
+        self.receipt_timeout = timeout;
+        self
+    }
+
+    /// Handle that cancels this pipeline once it runs.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    /// Append a step that submits one transaction.
+    pub fn step<F, Fut>(mut self, name: &str, submit: F) -> Self
+    where
+        F: FnOnce() -> Fut + Send + 'a,
+        Fut: Future<Output = Result<TransactionResponse>> + Send + 'a,
+    {
+        self.steps.push(Step {
+            name: name.to_string(),
+            submit: Box::new(move || Box::pin(submit())),
+            compensate: None,
+        });
+        self
+    }
+
+    /// Attach a compensation to the most recently added step.
+    ///
+    /// The compensation receives the step's receipt and runs only if the step
+    /// completed and a later step failed or the pipeline was cancelled.
+    /// Without a preceding step this has no effect.
+    pub fn compensate<F, Fut>(mut self, compensate: F) -> Self
+    where
+        F: FnOnce(TransactionReceipt) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<TransactionResponse>> + Send + 'a,
+    {
+        if let Some(step) = self.steps.last_mut() {
+            step.compensate = Some(Box::new(move |receipt| Box::pin(compensate(receipt))));
+        }
+        self
+    }
+
+    /// Number of steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the pipeline has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Run every step in order.
+    pub async fn run(mut self) -> PipelineReport {
+        let pending = take(&mut self.steps);
+        let mut steps = Vec::with_capacity(pending.len());
+        let mut compensations = Vec::new();
+        let mut status = PipelineStatus::Completed;
+
+        for step in pending {
+            if self.cancel.is_cancelled() {
+                status = PipelineStatus::Cancelled { step: step.name };
+                break;
+            }
+
+            match self.execute(step.submit).await {
+                Ok(receipt) => {
+                    compensations.push((step.name.clone(), receipt.clone(), step.compensate));
+                    steps.push(StepRecord {
+                        name: step.name,
+                        receipt,
+                    });
+                }
+                Err(error) => {
+                    status = PipelineStatus::Failed {
+                        step: step.name,
+                        error,
+                    };
+                    break;
+                }
+            }
+        }
+
+        let compensations = if matches!(status, PipelineStatus::Completed) {
+            Vec::new()
+        } else {
+            let mut records = Vec::new();
+            for (name, receipt, compensate) in compensations.into_iter().rev() {
+                if let Some(compensate) = compensate {
+                    records.push(CompensationRecord {
+                        step: name,
+                        result: self.execute(Box::new(move || compensate(receipt))).await,
+                    });
+                }
+            }
+            records
+        };
+
+        PipelineReport {
+            steps,
+            status,
+            compensations,
+        }
+    }
+
+    async fn execute(&self, submit: SubmitFn<'a>) -> Result<TransactionReceipt> {
+        let response = submit().await?;
+        let hash = response.hash.to_string();
+        let receipt = self
+            .client
+            .wait_for_transaction_receipt_with_timeout(&hash, self.receipt_timeout)
+            .await?;
+        if receipt.success {
+            Ok(receipt)
+        } else {
+            Err(Error::business_logic(
+                "pipeline step",
+                format!("transaction {} failed on-chain", hash),
+            ))
+        }
+    }
+}
+
+impl Debug for Pipeline<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let names: Vec<&str> = self.steps.iter().map(|step| step.name.as_str()).collect();
+        f.debug_struct("Pipeline")
+            .field("steps", &names)
+            .field("receipt_timeout", &self.receipt_timeout)
+            .field("cancelled", &self.cancel.is_cancelled())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use alloy_primitives::B256;
+    use std::sync::Mutex;
+
+    const FIRST: &str = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    const SECOND: &str = "0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f";
+    const UNDO: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn client_for(url: String) -> Client {
+        ClientBuilder::new()
+            .network(Network::custom(url))
+            .build()
+            .expect("Client should build")
+    }
+
+    fn submitted(hash: &str) -> Result<TransactionResponse> {
+        Ok(TransactionResponse {
+            hash: hash.parse::<B256>().expect("Test hash should be valid"),
+        })
+    }
+
+    fn receipt_body(hash: &str, success: bool) -> String {
+        format!(
+            r#"{{"success":{success},"transaction_hash":"{hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"0x0000000000000000000000000000000000000001","recipient":null,"token_address":null}}"#
+        )
+    }
+
+    async fn mock_receipt(server: &mut mockito::ServerGuard, hash: &str, success: bool) {
+        server
+            .mock("GET", "/v1/transactions/receipt/by_hash")
+            .match_query(mockito::Matcher::UrlEncoded("hash".into(), hash.into()))
+            .with_status(200)
+            .with_body(receipt_body(hash, success))
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_runs_steps_in_order() {
+        let mut server = mockito::Server::new_async().await;
+        mock_receipt(&mut server, FIRST, true).await;
+        mock_receipt(&mut server, SECOND, true).await;
+        let client = client_for(server.url());
+
+        let order = Mutex::new(Vec::new());
+        let order = &order;
+        let report = Pipeline::new(&client)
+            .step("first", move || async move {
+                order.lock().expect("Lock").push("first");
+                submitted(FIRST)
+            })
+            .step("second", move || async move {
+                order.lock().expect("Lock").push("second");
+                submitted(SECOND)
+            })
+            .run()
+            .await;
+
+        assert!(report.is_completed());
+        assert_eq!(*order.lock().expect("Lock"), ["first", "second"]);
+        let steps = report.into_result().expect("Pipeline should complete");
+        assert_eq!(steps[1].name, "second");
+        assert_eq!(steps[1].receipt.transaction_hash.to_string(), SECOND);
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_compensates_after_on_chain_failure() {
+        let mut server = mockito::Server::new_async().await;
+        mock_receipt(&mut server, FIRST, true).await;
+        mock_receipt(&mut server, SECOND, false).await;
+        mock_receipt(&mut server, UNDO, true).await;
+        let client = client_for(server.url());
+
+        let report = Pipeline::new(&client)
+            .step("grant", || async { submitted(FIRST) })
+            .compensate(|receipt| async move {
+                assert_eq!(receipt.transaction_hash.to_string(), FIRST);
+                submitted(UNDO)
+            })
+            .step("mint", || async { submitted(SECOND) })
+            .compensate(|_| async { panic!("Failed steps are not compensated") })
+            .run()
+            .await;
+
+        assert!(matches!(report.status, PipelineStatus::Failed { ref step, .. } if step == "mint"));
+        assert_eq!(report.steps.len(), 1);
+        assert_eq!(report.compensations.len(), 1);
+        assert_eq!(report.compensations[0].step, "grant");
+        assert!(report.compensations[0].result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_cancelled_before_start() {
+        let client = Client::local().expect("Client should build");
+        let pipeline = Pipeline::new(&client).step("never", || async {
+            panic!("Cancelled pipelines submit nothing")
+        });
+        assert_eq!(pipeline.len(), 1);
+
+        pipeline.cancel_handle().cancel();
+        let report = pipeline.run().await;
+        assert!(matches!(report.status, PipelineStatus::Cancelled { ref step } if step == "never"));
+        assert!(report.into_result().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_cancelled_mid_step_compensates_submitted_step() {
+        let mut server = mockito::Server::new_async().await;
+        mock_receipt(&mut server, FIRST, true).await;
+        mock_receipt(&mut server, UNDO, true).await;
+        let client = client_for(server.url());
+
+        let pipeline = Pipeline::new(&client);
+        let cancel = pipeline.cancel_handle();
+        let report = pipeline
+            .step("grant", move || async move {
+                cancel.cancel();
+                submitted(FIRST)
+            })
+            .compensate(|_| async { submitted(UNDO) })
+            .step("mint", || async {
+                panic!("Cancelled pipelines submit nothing more")
+            })
+            .run()
+            .await;
+
+        assert!(matches!(report.status, PipelineStatus::Cancelled { ref step } if step == "mint"));
+        assert_eq!(report.steps.len(), 1);
+        assert_eq!(report.steps[0].receipt.transaction_hash.to_string(), FIRST);
+        assert_eq!(report.compensations.len(), 1);
+        assert!(report.compensations[0].result.is_ok());
+    }
+}
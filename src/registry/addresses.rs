@@ -0,0 +1,283 @@
+//! Human-readable labels for addresses.
+//!
+//! An [`AddressBook`] maps addresses to names such as `treasury-hot-wallet`.
+//! Types implementing [`LabeledDisplay`] print those names in place of bare
+//! hex when rendered through [`AddressBook::display`] or
+//! [`Client::labeled`]; their plain [`Display`] output is unchanged.
+
+#[cfg(feature = "client")]
+use crate::client::Client;
+use crate::{Error, Result};
+use alloy_primitives::Address;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs;
+use std::path::Path;
+
+/// Labels for addresses, with lookups in both directions.
+///
+/// Each address has at most one label and each label names at most one
+/// address; inserting either again replaces the earlier entry. Labels match
+/// exactly, including case.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddressBook {
+    labels: BTreeMap<Address, String>,
+}
+
+impl AddressBook {
+    /// Create an empty address book.
+    pub const fn new() -> Self {
+        Self {
+            labels: BTreeMap::new(),
+        }
+    }
+
+    /// Parse a JSON object mapping labels to addresses.
+    ///
+    /// ```json
+    /// { "treasury-hot-wallet": "0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0" }
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self> {
+        let entries: BTreeMap<String, Address> = serde_json::from_str(json)
+            .map_err(|e| Error::invalid_parameter("address_book", e.to_string()))?;
+        Ok(entries.into_iter().map(|(l, a)| (a, l)).collect())
+    }
+
+    /// Parse a TOML table mapping labels to addresses.
+    ///
+    /// ```toml
+    /// treasury-hot-wallet = "0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0"
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let entries: BTreeMap<String, Address> = toml::from_str(toml)
+            .map_err(|e| Error::invalid_parameter("address_book", e.to_string()))?;
+        Ok(entries.into_iter().map(|(l, a)| (a, l)).collect())
+    }
+
+    /// Read an address book from a file, parsed by its extension.
+    ///
+    /// `.json` files are always accepted; `.toml` files need the `toml`
+    /// feature.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|e| Error::storage(format!("Failed to read {}: {}", path.display(), e)))?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(Error::invalid_parameter(
+                "path",
+                format!("Unsupported address book format: {}", path.display()),
+            )),
+        }
+    }
+
+    /// Label `address`, replacing its earlier label and moving `label` off
+    /// any other address.
+    pub fn insert<S: Into<String>>(&mut self, address: Address, label: S) {
+        let label = label.into();
+        self.labels.retain(|_, existing| *existing != label);
+        self.labels.insert(address, label);
+    }
+
+    /// Remove the label of `address`, returning it.
+    pub fn remove(&mut self, address: &Address) -> Option<String> {
+        self.labels.remove(address)
+    }
+
+    /// Label of `address`, if it has one.
+    pub fn label(&self, address: &Address) -> Option<&str> {
+        self.labels.get(address).map(String::as_str)
+    }
+
+    /// Address carrying `label`, if any.
+    pub fn address(&self, label: &str) -> Option<Address> {
+        self.labels
+            .iter()
+            .find(|(_, existing)| existing.as_str() == label)
+            .map(|(address, _)| *address)
+    }
+
+    /// Label of `address`, or the address itself in hex when it has none.
+    pub fn name(&self, address: &Address) -> String {
+        match self.label(address) {
+            Some(label) => label.to_string(),
+            None => address.to_string(),
+        }
+    }
+
+    /// Render `value` with this book's labels.
+    pub fn display<'a, T: LabeledDisplay>(&'a self, value: &'a T) -> Labeled<'a, T> {
+        Labeled { value, book: self }
+    }
+
+    /// Iterate over `(address, label)` pairs in address order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Address, &str)> {
+        self.labels
+            .iter()
+            .map(|(address, label)| (address, label.as_str()))
+    }
+
+    /// Number of labeled addresses.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Whether the book is empty.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl FromIterator<(Address, String)> for AddressBook {
+    fn from_iter<I: IntoIterator<Item = (Address, String)>>(iter: I) -> Self {
+        let mut book = Self::new();
+        for (address, label) in iter {
+            book.insert(address, label);
+        }
+        book
+    }
+}
+
+/// Formatting that names addresses through an [`AddressBook`].
+///
+/// The type's [`Display`] output is the same rendering with an empty book.
+pub trait LabeledDisplay {
+    /// Format `self`, writing addresses with [`AddressBook::name`].
+    fn fmt_labeled(&self, f: &mut Formatter<'_>, book: &AddressBook) -> FmtResult;
+}
+
+/// A value rendered with the labels of an address book.
+#[derive(Debug, Clone, Copy)]
+pub struct Labeled<'a, T> {
+    value: &'a T,
+    book: &'a AddressBook,
+}
+
+impl<T: LabeledDisplay> Display for Labeled<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.value.fmt_labeled(f, self.book)
+    }
+}
+
+#[cfg(feature = "client")]
+impl Client {
+    /// The address book set with
+    /// [`ClientBuilder::address_book`](crate::ClientBuilder::address_book);
+    /// empty when none was set.
+    pub fn address_book(&self) -> &AddressBook {
+        &self.inner.address_book
+    }
+
+    /// Render `value` with the labels of this client's address book.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A transaction, receipt, token or other [`LabeledDisplay`] type
+    ///
+    /// # Returns
+    ///
+    /// A wrapper whose `Display` output shows labels in place of addresses.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{AddressBook, ClientBuilder, Network};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ClientBuilder::new()
+    ///         .network(Network::Testnet)
+    ///         .address_book(AddressBook::load("addresses.json")?)
+    ///         .build()?;
+    ///
+    ///     let receipt = client
+    ///         .get_transaction_receipt_by_hash("0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777")
+    ///         .await?;
+    ///     println!("{}", client.labeled(&receipt));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn labeled<'a, T: LabeledDisplay>(&'a self, value: &'a T) -> Labeled<'a, T> {
+        self.inner.address_book.display(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_book_lookups_and_replacement() {
+        let hot = Address::repeat_byte(0x11);
+        let cold = Address::repeat_byte(0x22);
+        let mut book = AddressBook::new();
+        book.insert(hot, "treasury-hot-wallet");
+        book.insert(cold, "treasury-cold-wallet");
+
+        assert_eq!(book.label(&hot), Some("treasury-hot-wallet"));
+        assert_eq!(book.address("treasury-cold-wallet"), Some(cold));
+        assert_eq!(book.name(&Address::ZERO), Address::ZERO.to_string());
+
+        // Moving a label to another address drops it from the first.
+        book.insert(cold, "treasury-hot-wallet");
+        assert_eq!(book.len(), 1);
+        assert_eq!(book.label(&hot), None);
+        assert_eq!(book.address("treasury-hot-wallet"), Some(cold));
+    }
+
+    #[test]
+    fn test_address_book_from_json_and_load() {
+        let json = r#"{"ops": "0x1111111111111111111111111111111111111111"}"#;
+        let book = AddressBook::from_json(json).expect("Book should parse");
+        assert_eq!(book.label(&Address::repeat_byte(0x11)), Some("ops"));
+
+        assert!(AddressBook::from_json(r#"{"ops": "not an address"}"#).is_err());
+
+        let path =
+            std::env::temp_dir().join(format!("onemoney-address-book-{}.json", std::process::id()));
+        fs::write(&path, json).expect("Book should be written");
+        let loaded = AddressBook::load(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded.expect("Book should load"), book);
+        assert!(matches!(
+            AddressBook::load("addresses.yaml"),
+            Err(Error::Storage(_))
+        ));
+    }
+
+    #[test]
+    fn test_labeled_display_names_known_addresses() {
+        use crate::MintInfo;
+
+        let authority = Address::repeat_byte(0x33);
+        let info = MintInfo {
+            symbol: "USD1".to_string(),
+            master_authority: authority,
+            ..MintInfo::default()
+        };
+        let mut book = AddressBook::new();
+        book.insert(authority, "issuer-master");
+
+        let labeled = book.display(&info).to_string();
+        assert!(labeled.contains("Master Authority: issuer-master"));
+        assert_eq!(
+            labeled.replace("issuer-master", &authority.to_string()),
+            info.to_string()
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_address_book_from_toml() {
+        let toml = "treasury-hot-wallet = \"0x2222222222222222222222222222222222222222\"\n";
+        let book = AddressBook::from_toml(toml).expect("Book should parse");
+        assert_eq!(
+            book.address("treasury-hot-wallet"),
+            Some(Address::repeat_byte(0x22))
+        );
+    }
+}
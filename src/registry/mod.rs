@@ -0,0 +1,9 @@
+//! Registries of tokens a client can refer to by symbol and of labels for
+//! addresses.
+
+pub mod addresses;
+pub mod tokens;
+
+// Re-export public interfaces
+pub use addresses::*;
+pub use tokens::*;
@@ -0,0 +1,218 @@
+//! Symbol-to-address lookup for tokens on one network.
+//!
+//! Token addresses differ per network, so each client carries its own
+//! registry. Entries come from [`ClientBuilder::token`] for addresses known
+//! ahead of time, or from [`Client::register_token`], which reads the symbol
+//! and decimals from the chain.
+//!
+//! [`ClientBuilder::token`]: crate::ClientBuilder::token
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "client")]
+use crate::client::Client;
+#[cfg(feature = "client")]
+use crate::{Error, Result};
+#[cfg(feature = "client")]
+use std::sync::MutexGuard;
+
+/// A token known to the registry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KnownToken {
+    /// Token symbol, such as `USD1`.
+    pub symbol: Cow<'static, str>,
+    /// Token mint address.
+    pub address: Address,
+    /// Number of decimals of the token's base unit.
+    pub decimals: u8,
+}
+
+impl KnownToken {
+    /// Create a registry entry.
+    pub fn new<S: Into<Cow<'static, str>>>(symbol: S, address: Address, decimals: u8) -> Self {
+        Self {
+            symbol: symbol.into(),
+            address,
+            decimals,
+        }
+    }
+}
+
+impl Display for KnownToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} ({}, {} decimals)",
+            self.symbol, self.address, self.decimals
+        )
+    }
+}
+
+/// Tokens addressable by symbol.
+///
+/// Symbols match case-insensitively. Registering a symbol or address that is
+/// already present replaces the earlier entry, so a token is never reachable
+/// under two symbols.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenRegistry {
+    tokens: Vec<KnownToken>,
+}
+
+impl TokenRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a token, replacing any entry with the same symbol or address.
+    pub fn insert(&mut self, token: KnownToken) {
+        self.tokens.retain(|known| {
+            known.address != token.address && !known.symbol.eq_ignore_ascii_case(&token.symbol)
+        });
+        self.tokens.push(token);
+    }
+
+    /// Look up a token by symbol.
+    pub fn get(&self, symbol: &str) -> Option<&KnownToken> {
+        self.tokens
+            .iter()
+            .find(|known| known.symbol.eq_ignore_ascii_case(symbol))
+    }
+
+    /// Look up a token by mint address.
+    pub fn by_address(&self, address: &Address) -> Option<&KnownToken> {
+        self.tokens.iter().find(|known| known.address == *address)
+    }
+
+    /// Iterate over the registered tokens in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &KnownToken> {
+        self.tokens.iter()
+    }
+
+    /// Number of registered tokens.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Whether the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
+
+impl FromIterator<KnownToken> for TokenRegistry {
+    fn from_iter<I: IntoIterator<Item = KnownToken>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for token in iter {
+            registry.insert(token);
+        }
+        registry
+    }
+}
+
+#[cfg(feature = "client")]
+impl Client {
+    /// Snapshot of the tokens this client can resolve by symbol.
+    pub fn token_registry(&self) -> TokenRegistry {
+        self.lock_tokens().clone()
+    }
+
+    /// Resolve a token symbol to its registry entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The token symbol, matched case-insensitively
+    ///
+    /// # Returns
+    ///
+    /// The token's address and decimals, or an invalid parameter error if the
+    /// symbol is not registered with this client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::{ClientBuilder, KnownToken, Network};
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let usd = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///     let client = ClientBuilder::new()
+    ///         .network(Network::Testnet)
+    ///         .token(KnownToken::new("USD1", usd, 18))
+    ///         .build()?;
+    ///
+    ///     let token = client.resolve_token("usd1")?;
+    ///     println!("USD1 lives at {}", token.address);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn resolve_token(&self, symbol: &str) -> Result<KnownToken> {
+        self.lock_tokens().get(symbol).cloned().ok_or_else(|| {
+            Error::invalid_parameter(
+                "symbol",
+                format!("Token {} is not registered with this client", symbol),
+            )
+        })
+    }
+
+    /// Add a token to this client's registry using its on-chain metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint_address` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// The registered entry, carrying the symbol and decimals reported by the
+    /// network.
+    pub async fn register_token(&self, mint_address: Address) -> Result<KnownToken> {
+        let metadata = self.get_token_metadata(mint_address).await?;
+        let token = KnownToken::new(metadata.symbol, mint_address, metadata.decimals);
+        self.lock_tokens().insert(token.clone());
+        Ok(token)
+    }
+
+    fn lock_tokens(&self) -> MutexGuard<'_, TokenRegistry> {
+        self.inner
+            .tokens
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lookup_is_case_insensitive() {
+        let usd = Address::repeat_byte(0x11);
+        let registry: TokenRegistry = [KnownToken::new("USD1", usd, 18)].into_iter().collect();
+
+        assert_eq!(registry.get("usd1").map(|token| token.address), Some(usd));
+        assert_eq!(
+            registry.by_address(&usd).map(|token| token.symbol.as_ref()),
+            Some("USD1")
+        );
+        assert!(registry.get("EUR1").is_none());
+    }
+
+    #[test]
+    fn test_registry_insert_replaces_symbol_and_address() {
+        let mut registry = TokenRegistry::new();
+        registry.insert(KnownToken::new("USD1", Address::repeat_byte(0x11), 18));
+        registry.insert(KnownToken::new("usd1", Address::repeat_byte(0x22), 6));
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("USD1").map(|token| token.decimals), Some(6));
+
+        registry.insert(KnownToken::new("EUR1", Address::repeat_byte(0x22), 6));
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get("USD1").is_none());
+        assert!(registry.get("EUR1").is_some());
+    }
+}
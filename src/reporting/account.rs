@@ -0,0 +1,648 @@
+//! History reports for a set of accounts.
+
+use super::fees::{FeeSummary, FeeTotal};
+use super::row::{ReportRow, to_csv};
+use crate::client::Client;
+use crate::decode::{OperationKind, PartyRole, TransactionSummary};
+use crate::responses::Transaction;
+use crate::utils::format_units;
+use crate::watcher::ActivityDirection;
+use crate::watcher::addresses::directions;
+use crate::{CheckpointTransactions, Error, Result};
+use alloy_primitives::{Address, U256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::fs;
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
+
+/// Builds transaction history reports for accounts.
+///
+/// The L1 has no per-account history endpoint, so the reporter walks the
+/// checkpoints in range and keeps the transactions that touch a reported
+/// account. Date ranges are resolved to checkpoints by binary search over
+/// checkpoint timestamps first, so narrow ranges stay cheap. Token symbols and
+/// decimals are fetched once per token, and fees come from the receipts of
+/// transactions the account signed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::Client;
+/// use onemoney_protocol::reporting::Reporter;
+/// use alloy_primitives::Address;
+/// use std::str::FromStr;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::mainnet()?;
+///     let treasury = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+///
+///     // All of January 2024, UTC.
+///     let rows = Reporter::new(&client)
+///         .account(treasury)
+///         .date_range(1704067200..1706745600)
+///         .export_csv("treasury-2024-01.csv")
+///         .await?;
+///     println!("Exported {} rows", rows);
+///     Ok(())
+/// }
+/// ```
+pub struct Reporter<'a> {
+    client: &'a Client,
+    accounts: BTreeSet<Address>,
+    tokens: BTreeSet<Address>,
+    dates: (Bound<u64>, Bound<u64>),
+    checkpoints: (Bound<u64>, Bound<u64>),
+    include_fees: bool,
+}
+
+impl Debug for Reporter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Reporter")
+            .field("accounts", &self.accounts)
+            .field("tokens", &self.tokens)
+            .field("dates", &self.dates)
+            .field("checkpoints", &self.checkpoints)
+            .field("include_fees", &self.include_fees)
+            .finish()
+    }
+}
+
+/// Symbol and decimals of a token, once fetched.
+type TokenInfo = (String, u8);
+
+impl<'a> Reporter<'a> {
+    /// Create a reporter covering all of history and no accounts.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            accounts: BTreeSet::new(),
+            tokens: BTreeSet::new(),
+            dates: (Bound::Unbounded, Bound::Unbounded),
+            checkpoints: (Bound::Unbounded, Bound::Unbounded),
+            include_fees: true,
+        }
+    }
+
+    /// Report transactions involving `account`.
+    pub fn account(mut self, account: Address) -> Self {
+        self.accounts.insert(account);
+        self
+    }
+
+    /// Only report transactions for `token`.
+    ///
+    /// Selecting no token reports every token, including native transfers.
+    pub fn token(mut self, token: Address) -> Self {
+        self.tokens.insert(token);
+        self
+    }
+
+    /// Only report checkpoints whose timestamp, in Unix seconds, is in
+    /// `range`.
+    pub fn date_range(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.dates = (range.start_bound().cloned(), range.end_bound().cloned());
+        self
+    }
+
+    /// Only report checkpoints whose number is in `range`.
+    ///
+    /// Combined with [`Reporter::date_range`], both must hold.
+    pub fn checkpoint_range(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.checkpoints = (range.start_bound().cloned(), range.end_bound().cloned());
+        self
+    }
+
+    /// Whether to fetch receipts for fees; on by default.
+    ///
+    /// Turning fees off saves one request per transaction the reported
+    /// accounts signed.
+    pub fn include_fees(mut self, include: bool) -> Self {
+        self.include_fees = include;
+        self
+    }
+
+    /// Collect the report rows, ordered by checkpoint and position in it.
+    pub async fn rows(&self) -> Result<Vec<ReportRow>> {
+        if self.accounts.is_empty() {
+            return Err(Error::validation("account", "No account to report on"));
+        }
+        let Some((first, last)) = self.resolve_checkpoints().await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut tokens = HashMap::new();
+        let mut rows = Vec::new();
+        for number in first..=last {
+            let Some((timestamp, transactions)) = self.checkpoint_transactions(number).await?
+            else {
+                continue;
+            };
+            for transaction in &transactions {
+                self.collect(number, timestamp, transaction, &mut tokens, &mut rows)
+                    .await?;
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Sum the fees the reported accounts paid, per token and operation.
+    ///
+    /// Every transaction a reported account signed in range is counted,
+    /// failed ones included, and its receipt fetched for `fee_used`. Fees
+    /// are attributed to the token the operation applies to.
+    /// [`Reporter::include_fees`] does not apply.
+    pub async fn fee_summary(&self) -> Result<FeeSummary> {
+        if self.accounts.is_empty() {
+            return Err(Error::validation("account", "No account to report on"));
+        }
+        let mut summary = FeeSummary {
+            accounts: self.accounts.iter().copied().collect(),
+            first_checkpoint: None,
+            last_checkpoint: None,
+            totals: Vec::new(),
+        };
+        let Some((first, last)) = self.resolve_checkpoints().await? else {
+            return Ok(summary);
+        };
+        summary.first_checkpoint = Some(first);
+        summary.last_checkpoint = Some(last);
+
+        let mut sums: BTreeMap<(Option<Address>, OperationKind), (u64, u64, U256)> =
+            BTreeMap::new();
+        for number in first..=last {
+            let Some((_, transactions)) = self.checkpoint_transactions(number).await? else {
+                continue;
+            };
+            for transaction in transactions
+                .iter()
+                .filter(|transaction| self.accounts.contains(&transaction.from))
+            {
+                let operation = TransactionSummary::from_transaction(transaction)?;
+                if !self.tokens.is_empty()
+                    && !operation
+                        .token
+                        .is_some_and(|token| self.tokens.contains(&token))
+                {
+                    continue;
+                }
+                let receipt = self
+                    .client
+                    .get_transaction_receipt_by_hash(&transaction.hash.to_string())
+                    .await?;
+                let (count, failed, raw) = sums
+                    .entry((operation.token, operation.kind))
+                    .or_insert((0, 0, U256::ZERO));
+                *count += 1;
+                *failed += u64::from(!receipt.success);
+                *raw = raw.saturating_add(U256::from(receipt.fee_used));
+            }
+        }
+
+        let mut tokens = HashMap::new();
+        for ((token, kind), (transactions, failed, raw)) in sums {
+            let info = match token {
+                Some(token) => Some(self.token_info(token, &mut tokens).await?),
+                None => None,
+            };
+            summary.totals.push(FeeTotal {
+                token,
+                symbol: info.as_ref().map(|(symbol, _)| symbol.clone()),
+                kind,
+                transactions,
+                failed,
+                raw,
+                amount: match &info {
+                    Some((_, decimals)) => format_units(raw, *decimals),
+                    None => raw.to_string(),
+                },
+            });
+        }
+        Ok(summary)
+    }
+
+    /// Write the report to `path` as CSV; returns the number of rows.
+    ///
+    /// Columns are listed in [`CSV_COLUMNS`](super::CSV_COLUMNS).
+    pub async fn export_csv(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let rows = self.rows().await?;
+        write_report(path.as_ref(), to_csv(&rows))?;
+        Ok(rows.len())
+    }
+
+    /// Write the report to `path` as a JSON array of [`ReportRow`]s; returns
+    /// the number of rows.
+    pub async fn export_json(&self, path: impl AsRef<Path>) -> Result<usize> {
+        let rows = self.rows().await?;
+        write_report(path.as_ref(), serde_json::to_string_pretty(&rows)?)?;
+        Ok(rows.len())
+    }
+
+    /// The inclusive checkpoint range to scan, `None` when it is empty.
+    async fn resolve_checkpoints(&self) -> Result<Option<(u64, u64)>> {
+        let latest = self.client.get_checkpoint_number().await?.number;
+        let mut first = match self.checkpoints.0 {
+            Bound::Included(first) => first,
+            Bound::Excluded(first) => first.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let mut last = match self.checkpoints.1 {
+            Bound::Included(last) => last.min(latest),
+            Bound::Excluded(0) => return Ok(None),
+            Bound::Excluded(last) => (last - 1).min(latest),
+            Bound::Unbounded => latest,
+        };
+        if first > last {
+            return Ok(None);
+        }
+
+        let after_start = match self.dates.0 {
+            Bound::Included(start) => Some(start),
+            Bound::Excluded(start) => Some(start.saturating_add(1)),
+            Bound::Unbounded => None,
+        };
+        if let Some(start) = after_start {
+            first = self.first_at_or_after(start, first, last).await?;
+        }
+        let past_end = match self.dates.1 {
+            Bound::Included(end) => end.checked_add(1),
+            Bound::Excluded(end) => Some(end),
+            Bound::Unbounded => None,
+        };
+        if let Some(end) = past_end {
+            match self.first_at_or_after(end, first, last).await? {
+                0 => return Ok(None),
+                beyond => last = last.min(beyond - 1),
+            }
+        }
+        Ok((first <= last).then_some((first, last)))
+    }
+
+    /// The first checkpoint in `low..=high` with a timestamp of at least
+    /// `timestamp`, or `high + 1` when there is none.
+    async fn first_at_or_after(&self, timestamp: u64, mut low: u64, high: u64) -> Result<u64> {
+        let mut high = high.saturating_add(1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let checkpoint = self.client.get_checkpoint_by_number(middle, false).await?;
+            if checkpoint.timestamp >= timestamp {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        Ok(low)
+    }
+
+    /// Timestamp and transactions of checkpoint `number`, `None` when its
+    /// timestamp is outside the date range.
+    async fn checkpoint_transactions(
+        &self,
+        number: u64,
+    ) -> Result<Option<(u64, Vec<Transaction>)>> {
+        let checkpoint = self.client.get_checkpoint_by_number(number, true).await?;
+        if !self.dates.contains(&checkpoint.timestamp) {
+            return Ok(None);
+        }
+        match checkpoint.transactions {
+            CheckpointTransactions::Full(transactions) => {
+                Ok(Some((checkpoint.timestamp, transactions)))
+            }
+            CheckpointTransactions::Hashes(_) => Err(Error::custom(format!(
+                "Checkpoint {} was returned without full transactions",
+                number
+            ))),
+        }
+    }
+
+    async fn collect(
+        &self,
+        checkpoint: u64,
+        timestamp: u64,
+        transaction: &Transaction,
+        tokens: &mut HashMap<Address, TokenInfo>,
+        rows: &mut Vec<ReportRow>,
+    ) -> Result<()> {
+        let summary = TransactionSummary::from_transaction(transaction)?;
+        if !self.tokens.is_empty()
+            && !summary
+                .token
+                .is_some_and(|token| self.tokens.contains(&token))
+        {
+            return Ok(());
+        }
+
+        for &account in &self.accounts {
+            let directions = directions(&summary, account);
+            if directions.is_empty() {
+                continue;
+            }
+
+            let info = match summary.token {
+                Some(token) => Some(self.token_info(token, tokens).await?),
+                None => None,
+            };
+            let format = |raw: U256| match &info {
+                Some((_, decimals)) => format_units(raw, *decimals),
+                None => raw.to_string(),
+            };
+            let fee = if self.include_fees && transaction.from == account {
+                let receipt = self
+                    .client
+                    .get_transaction_receipt_by_hash(&transaction.hash.to_string())
+                    .await?;
+                Some(format(U256::from(receipt.fee_used)))
+            } else {
+                None
+            };
+
+            for direction in directions {
+                rows.push(ReportRow {
+                    timestamp,
+                    checkpoint,
+                    hash: transaction.hash,
+                    account,
+                    direction,
+                    kind: summary.kind,
+                    counterparty: counterparty(&summary, account, direction),
+                    token: summary.token,
+                    symbol: info.as_ref().map(|(symbol, _)| symbol.clone()),
+                    amount: summary.amount.map(|amount| format(amount.raw)),
+                    fee: match direction {
+                        ActivityDirection::Outgoing => fee.clone(),
+                        ActivityDirection::Incoming => None,
+                    },
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn token_info(
+        &self,
+        token: Address,
+        tokens: &mut HashMap<Address, TokenInfo>,
+    ) -> Result<TokenInfo> {
+        if let Some(info) = tokens.get(&token) {
+            return Ok(info.clone());
+        }
+        let known = self.client.token_info(token).await?;
+        let info = (known.symbol.into_owned(), known.decimals);
+        tokens.insert(token, info.clone());
+        Ok(info)
+    }
+}
+
+/// The party on the other side of `account`'s involvement.
+fn counterparty(
+    summary: &TransactionSummary,
+    account: Address,
+    direction: ActivityDirection,
+) -> Option<Address> {
+    summary
+        .parties
+        .iter()
+        .filter(|party| party.address != account)
+        .find(|party| match direction {
+            ActivityDirection::Outgoing => matches!(
+                party.role,
+                PartyRole::Recipient | PartyRole::Subject | PartyRole::MasterAuthority
+            ),
+            ActivityDirection::Incoming => {
+                matches!(party.role, PartyRole::Signer | PartyRole::Source)
+            }
+        })
+        .map(|party| party.address)
+}
+
+fn write_report(path: &Path, contents: String) -> Result<()> {
+    fs::write(path, contents).map_err(|e| {
+        Error::storage(format!(
+            "Failed to write report to {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::responses::{MintInfo, TxPayload};
+    use alloy_primitives::B256;
+
+    const ACCOUNT: Address = Address::repeat_byte(0xaa);
+    const OTHER: Address = Address::repeat_byte(0xbb);
+    const TOKEN: Address = Address::repeat_byte(0xcc);
+
+    fn transfer(byte: u8, from: Address, recipient: Address, value: &str) -> Transaction {
+        Transaction {
+            hash: B256::repeat_byte(byte),
+            from,
+            data: TxPayload::TokenTransfer {
+                value: value.to_string(),
+                recipient,
+                token: Some(TOKEN),
+            },
+            ..Transaction::default()
+        }
+    }
+
+    async fn checkpoint(
+        server: &mut mockito::ServerGuard,
+        number: u64,
+        timestamp: u64,
+        txs: &[Transaction],
+    ) {
+        server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "number".into(),
+                number.to_string(),
+            ))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"hash":"{hash}","parent_hash":"{hash}","state_root":"{hash}","transactions_root":"{hash}","receipts_root":"{hash}","number":{number},"timestamp":{timestamp},"extra_data":"","transactions":{},"size":null}}"#,
+                serde_json::to_string(txs).expect("Transactions should serialize"),
+                hash = B256::ZERO
+            ))
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_rows_cover_date_range_with_fees_and_decimals() {
+        let mut server = mockito::Server::new_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 3}"#)
+            .create_async()
+            .await;
+        checkpoint(&mut server, 0, 0, &[]).await;
+        checkpoint(&mut server, 1, 100, &[transfer(1, OTHER, ACCOUNT, "1")]).await;
+        checkpoint(
+            &mut server,
+            2,
+            200,
+            &[
+                transfer(2, OTHER, ACCOUNT, "1500"),
+                transfer(3, ACCOUNT, OTHER, "250"),
+                transfer(4, OTHER, OTHER, "9"),
+            ],
+        )
+        .await;
+        checkpoint(&mut server, 3, 300, &[transfer(5, ACCOUNT, OTHER, "1")]).await;
+        let metadata = server
+            .mock("GET", "/v1/tokens/token_metadata")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "token".into(),
+                TOKEN.to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&MintInfo {
+                    symbol: "USDX".to_string(),
+                    decimals: 3,
+                    ..MintInfo::default()
+                })
+                .expect("Metadata should serialize"),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let receipt = server
+            .mock("GET", "/v1/transactions/receipt/by_hash")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "hash".into(),
+                B256::repeat_byte(3).to_string(),
+            ))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"success":true,"transaction_hash":"{}","fee_used":"20","from":"{}","recipient":null,"token_address":null}}"#,
+                B256::repeat_byte(3),
+                ACCOUNT
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let rows = Reporter::new(&client)
+            .account(ACCOUNT)
+            .date_range(150..=250)
+            .rows()
+            .await
+            .expect("Report should build");
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].hash, B256::repeat_byte(2));
+        assert_eq!(rows[0].direction, ActivityDirection::Incoming);
+        assert_eq!(rows[0].counterparty, Some(OTHER));
+        assert_eq!(rows[0].amount.as_deref(), Some("1.5"));
+        assert_eq!(rows[0].fee, None);
+        assert_eq!(rows[1].direction, ActivityDirection::Outgoing);
+        assert_eq!(rows[1].timestamp, 200);
+        assert_eq!(rows[1].symbol.as_deref(), Some("USDX"));
+        assert_eq!(rows[1].amount.as_deref(), Some("0.25"));
+        assert_eq!(rows[1].fee.as_deref(), Some("0.02"));
+        metadata.assert_async().await;
+        receipt.assert_async().await;
+
+        let empty = Reporter::new(&client)
+            .account(ACCOUNT)
+            .date_range(301..)
+            .rows()
+            .await
+            .expect("Report should build");
+        assert!(empty.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fee_summary_sums_signed_receipts() {
+        let mut server = mockito::Server::new_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 9}"#)
+            .create_async()
+            .await;
+        checkpoint(
+            &mut server,
+            1,
+            100,
+            &[
+                transfer(1, ACCOUNT, OTHER, "10"),
+                transfer(2, OTHER, ACCOUNT, "10"),
+            ],
+        )
+        .await;
+        checkpoint(&mut server, 2, 200, &[transfer(3, ACCOUNT, OTHER, "10")]).await;
+        let _metadata = server
+            .mock("GET", "/v1/tokens/token_metadata")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&MintInfo {
+                    symbol: "USDX".to_string(),
+                    decimals: 3,
+                    ..MintInfo::default()
+                })
+                .expect("Metadata should serialize"),
+            )
+            .create_async()
+            .await;
+        for (byte, success, fee) in [(1u8, true, 20u8), (3, false, 5)] {
+            server
+                .mock("GET", "/v1/transactions/receipt/by_hash")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "hash".into(),
+                    B256::repeat_byte(byte).to_string(),
+                ))
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"success":{},"transaction_hash":"{}","fee_used":"{}","from":"{}","recipient":null,"token_address":null}}"#,
+                    success,
+                    B256::repeat_byte(byte),
+                    fee,
+                    ACCOUNT
+                ))
+                .expect(1)
+                .create_async()
+                .await;
+        }
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let summary = client
+            .get_fee_summary(ACCOUNT, 1..=2)
+            .await
+            .expect("Summary should build");
+
+        assert_eq!(summary.first_checkpoint, Some(1));
+        assert_eq!(summary.last_checkpoint, Some(2));
+        assert_eq!(summary.transactions(), 2);
+        let total = summary
+            .total(Some(TOKEN), OperationKind::Transfer)
+            .expect("Transfers should be totalled");
+        assert_eq!(total.failed, 1);
+        assert_eq!(total.raw, U256::from(25));
+        assert_eq!(total.amount, "0.025");
+        assert_eq!(total.symbol.as_deref(), Some("USDX"));
+        assert_eq!(summary.raw_total_for(None), U256::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_rows_require_an_account() {
+        let client = Client::local().expect("Local client should build");
+        assert!(Reporter::new(&client).rows().await.is_err());
+    }
+}
@@ -0,0 +1,276 @@
+//! Token supply and authority changes across a span of checkpoints.
+
+use crate::decode::{AuthorityChange, OperationKind, TransactionSummary};
+use crate::{Checkpoint, CheckpointTransactions, Client, Error, Result};
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Tokens minted and burned for one token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupplyChange {
+    /// The token.
+    pub token: Address,
+    /// Amount minted, bridged in included, in base units.
+    pub minted: U256,
+    /// Amount burned, bridged out included, in base units.
+    pub burned: U256,
+}
+
+/// An authority change and the transaction that made it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorityChangeRecord {
+    /// Checkpoint holding the transaction.
+    pub checkpoint: u64,
+    /// Transaction hash.
+    pub hash: B256,
+    /// Signer of the transaction.
+    pub signer: Address,
+    /// Token whose authority changed.
+    pub token: Address,
+    /// The change.
+    pub change: AuthorityChange,
+}
+
+/// What changed on chain between two checkpoints.
+///
+/// Built from the transactions in the checkpoints, so every transaction the
+/// L1 included is counted; pair it with receipts where failed transactions
+/// matter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointDiff {
+    /// First checkpoint whose transactions are included.
+    pub from: u64,
+    /// Last checkpoint whose transactions are included.
+    pub to: u64,
+    /// Number of transactions seen.
+    pub transactions: usize,
+    /// Supply changes, one per token that was minted or burned, ordered by
+    /// token address.
+    pub supply_changes: Vec<SupplyChange>,
+    /// Authority grants and revocations, in chain order.
+    pub authority_changes: Vec<AuthorityChangeRecord>,
+}
+
+impl CheckpointDiff {
+    /// Changes made after checkpoint `from` up to and including `to`.
+    ///
+    /// Checkpoints are fetched with [`Client::get_checkpoints_range`], so the
+    /// state at `from` is the baseline and its own transactions are not
+    /// counted. Equal bounds give an empty diff.
+    pub async fn between(client: &Client, from: u64, to: u64) -> Result<Self> {
+        if from > to {
+            return Err(Error::invalid_parameter(
+                "to",
+                format!("Range end {} is before its start {}", to, from),
+            ));
+        }
+        if from == to {
+            return Ok(Self::empty(to, to));
+        }
+        let checkpoints = client.get_checkpoints_range(from + 1, to, true).await?;
+        Self::from_checkpoints(&checkpoints)
+    }
+
+    /// Summarize checkpoints fetched with full transactions.
+    ///
+    /// Checkpoints holding only transaction hashes are rejected.
+    pub fn from_checkpoints(checkpoints: &[Checkpoint]) -> Result<Self> {
+        let from = checkpoints
+            .iter()
+            .map(|c| c.number)
+            .min()
+            .unwrap_or_default();
+        let to = checkpoints
+            .iter()
+            .map(|c| c.number)
+            .max()
+            .unwrap_or_default();
+        let mut diff = Self::empty(from, to);
+        let mut supply: BTreeMap<Address, SupplyChange> = BTreeMap::new();
+
+        let mut ordered: Vec<&Checkpoint> = checkpoints.iter().collect();
+        ordered.sort_by_key(|checkpoint| checkpoint.number);
+        for checkpoint in ordered {
+            let CheckpointTransactions::Full(transactions) = &checkpoint.transactions else {
+                return Err(Error::validation(
+                    "transactions",
+                    format!(
+                        "Checkpoint {} was fetched without full transactions",
+                        checkpoint.number
+                    ),
+                ));
+            };
+            for transaction in transactions {
+                diff.transactions += 1;
+                let summary = TransactionSummary::from_transaction(transaction)?;
+                let Some(token) = summary.token else {
+                    continue;
+                };
+                match summary.kind {
+                    OperationKind::Mint | OperationKind::BridgeAndMint => {
+                        let amount = summary.amount.map(|a| a.raw).unwrap_or_default();
+                        let entry = supply.entry(token).or_insert_with(|| zero(token));
+                        entry.minted = entry.minted.saturating_add(amount);
+                    }
+                    OperationKind::Burn | OperationKind::BurnAndBridge => {
+                        let amount = summary.amount.map(|a| a.raw).unwrap_or_default();
+                        let entry = supply.entry(token).or_insert_with(|| zero(token));
+                        entry.burned = entry.burned.saturating_add(amount);
+                    }
+                    OperationKind::GrantAuthority | OperationKind::RevokeAuthority => {
+                        if let Some(change) = summary.authority_change {
+                            diff.authority_changes.push(AuthorityChangeRecord {
+                                checkpoint: checkpoint.number,
+                                hash: transaction.hash,
+                                signer: transaction.from,
+                                token,
+                                change,
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        diff.supply_changes = supply.into_values().collect();
+        Ok(diff)
+    }
+
+    /// Supply change of `token`, if it was minted or burned.
+    pub fn supply_change(&self, token: Address) -> Option<&SupplyChange> {
+        self.supply_changes
+            .iter()
+            .find(|change| change.token == token)
+    }
+
+    /// Whether nothing worth reporting changed.
+    pub fn is_empty(&self) -> bool {
+        self.supply_changes.is_empty() && self.authority_changes.is_empty()
+    }
+
+    fn empty(from: u64, to: u64) -> Self {
+        Self {
+            from,
+            to,
+            transactions: 0,
+            supply_changes: Vec::new(),
+            authority_changes: Vec::new(),
+        }
+    }
+}
+
+fn zero(token: Address) -> SupplyChange {
+    SupplyChange {
+        token,
+        minted: U256::ZERO,
+        burned: U256::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::{Hash, Transaction, TxPayload};
+    use crate::{AuthorityAction, Signature};
+
+    fn transaction(hash: u8, data: TxPayload) -> Transaction {
+        Transaction {
+            hash: B256::repeat_byte(hash),
+            checkpoint_hash: None,
+            checkpoint_number: None,
+            transaction_index: None,
+            chain_id: 1212101,
+            from: Address::repeat_byte(0xaa),
+            nonce: 0,
+            data,
+            signature: Signature::default(),
+        }
+    }
+
+    fn checkpoint(number: u64, transactions: CheckpointTransactions) -> Checkpoint {
+        Checkpoint {
+            hash: Hash::default(),
+            parent_hash: Hash::default(),
+            state_root: Hash::default(),
+            transactions_root: Hash::default(),
+            receipts_root: Hash::default(),
+            number,
+            timestamp: 0,
+            extra_data: String::new(),
+            transactions,
+            size: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_sums_supply_and_collects_authority_changes() {
+        let token = Address::repeat_byte(0x22);
+        let mint = |hash, value: &str| {
+            transaction(
+                hash,
+                TxPayload::TokenMint {
+                    value: value.to_string(),
+                    recipient: Address::repeat_byte(0x11),
+                    token,
+                },
+            )
+        };
+        let burn = transaction(
+            3,
+            TxPayload::TokenBurn {
+                value: "40".to_string(),
+                recipient: Address::repeat_byte(0x11),
+                token,
+            },
+        );
+        let grant = transaction(
+            4,
+            TxPayload::TokenGrantAuthority {
+                authority_type: "Pause".to_string(),
+                authority_address: Address::repeat_byte(0x33),
+                value: None,
+                token,
+            },
+        );
+        let transfer = transaction(5, TxPayload::default());
+
+        let diff = CheckpointDiff::from_checkpoints(&[
+            checkpoint(8, CheckpointTransactions::Full(vec![burn, grant, transfer])),
+            checkpoint(
+                7,
+                CheckpointTransactions::Full(vec![mint(1, "100"), mint(2, "5")]),
+            ),
+        ])
+        .expect("Diff should build");
+
+        assert_eq!((diff.from, diff.to, diff.transactions), (7, 8, 5));
+        let change = diff
+            .supply_change(token)
+            .expect("Token should have changed");
+        assert_eq!(change.minted, U256::from(105u64));
+        assert_eq!(change.burned, U256::from(40u64));
+
+        assert_eq!(diff.authority_changes.len(), 1);
+        let record = &diff.authority_changes[0];
+        assert_eq!(record.checkpoint, 8);
+        assert_eq!(record.change.action, AuthorityAction::Grant);
+        assert_eq!(record.change.address, Address::repeat_byte(0x33));
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_requires_full_transactions() {
+        let hashes = checkpoint(1, CheckpointTransactions::Hashes(Vec::new()));
+        assert!(matches!(
+            CheckpointDiff::from_checkpoints(&[hashes]),
+            Err(Error::Validation { .. })
+        ));
+        assert!(
+            CheckpointDiff::from_checkpoints(&[])
+                .expect("No checkpoints is an empty diff")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Fee totals per token and operation for finance and operations reports.
+
+use super::account::Reporter;
+use crate::decode::OperationKind;
+use crate::{Client, Result};
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::RangeBounds;
+
+/// Fees paid for one kind of operation on one token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeTotal {
+    /// Token the operations applied to; `None` for native transfers.
+    pub token: Option<Address>,
+    /// Symbol of the token.
+    pub symbol: Option<String>,
+    /// The operation.
+    pub kind: OperationKind,
+    /// Number of transactions counted.
+    pub transactions: u64,
+    /// Number of those whose receipt reports failure. Failed transactions
+    /// still pay their fee.
+    pub failed: u64,
+    /// Sum of `fee_used` in base units.
+    pub raw: U256,
+    /// The sum in the token's decimals; base units for native transfers.
+    pub amount: String,
+}
+
+/// Fees paid by a set of accounts over a span of checkpoints.
+///
+/// Built by [`Reporter::fee_summary`] from the receipts of every transaction
+/// a reported account signed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeSummary {
+    /// Accounts whose fees are counted.
+    pub accounts: Vec<Address>,
+    /// First checkpoint scanned; `None` when the range was empty.
+    pub first_checkpoint: Option<u64>,
+    /// Last checkpoint scanned.
+    pub last_checkpoint: Option<u64>,
+    /// One total per token and operation, ordered by token then operation.
+    pub totals: Vec<FeeTotal>,
+}
+
+impl FeeSummary {
+    /// Number of transactions counted across all totals.
+    pub fn transactions(&self) -> u64 {
+        self.totals.iter().map(|total| total.transactions).sum()
+    }
+
+    /// Total fees paid in `token`, in base units, across operations.
+    pub fn raw_total_for(&self, token: Option<Address>) -> U256 {
+        self.totals
+            .iter()
+            .filter(|total| total.token == token)
+            .fold(U256::ZERO, |sum, total| sum.saturating_add(total.raw))
+    }
+
+    /// The total for one operation on one token, if any was counted.
+    pub fn total(&self, token: Option<Address>, kind: OperationKind) -> Option<&FeeTotal> {
+        self.totals
+            .iter()
+            .find(|total| total.token == token && total.kind == kind)
+    }
+}
+
+impl Display for FeeSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match (self.first_checkpoint, self.last_checkpoint) {
+            (Some(first), Some(last)) => {
+                writeln!(f, "Fees for checkpoints {} to {}:", first, last)?
+            }
+            _ => writeln!(f, "Fees: no checkpoints in range")?,
+        }
+        for total in &self.totals {
+            let token = match (&total.symbol, total.token) {
+                (Some(symbol), _) => symbol.clone(),
+                (None, Some(token)) => token.to_string(),
+                (None, None) => "native".to_string(),
+            };
+            writeln!(
+                f,
+                "  {} {}: {} over {} transactions",
+                token, total.kind, total.amount, total.transactions
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Client {
+    /// Sum the fees `address` paid over a range of checkpoints.
+    ///
+    /// A shorthand for [`Reporter::fee_summary`] with one account; use a
+    /// [`Reporter`] directly for several accounts, a token filter or a date
+    /// range.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The account that signed the transactions
+    /// * `checkpoints` - Checkpoint numbers to scan
+    ///
+    /// # Returns
+    ///
+    /// Fee totals per token and operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::mainnet()?;
+    ///     let treasury = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///
+    ///     let summary = client.get_fee_summary(treasury, 1000..2000).await?;
+    ///     print!("{}", summary);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_fee_summary(
+        &self,
+        address: Address,
+        checkpoints: impl RangeBounds<u64>,
+    ) -> Result<FeeSummary> {
+        Reporter::new(self)
+            .account(address)
+            .checkpoint_range(checkpoints)
+            .fee_summary()
+            .await
+    }
+}
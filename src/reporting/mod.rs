@@ -0,0 +1,15 @@
+//! Account history and checkpoint reports for accounting, reconciliation
+//! and audit.
+
+pub mod account;
+pub mod diff;
+pub mod fees;
+pub mod row;
+pub mod time;
+
+// Re-export public interfaces
+pub use account::*;
+pub use diff::*;
+pub use fees::*;
+pub use row::*;
+pub use time::*;
@@ -0,0 +1,138 @@
+//! Normalized report rows and their CSV form.
+
+use crate::decode::OperationKind;
+use crate::watcher::ActivityDirection;
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// Column names of [`to_csv`], in order.
+pub const CSV_COLUMNS: [&str; 11] = [
+    "timestamp",
+    "checkpoint",
+    "hash",
+    "account",
+    "direction",
+    "kind",
+    "counterparty",
+    "token",
+    "symbol",
+    "amount",
+    "fee",
+];
+
+/// One transaction as it affects one account.
+///
+/// A transaction an account both sends and receives, such as a transfer to
+/// itself, yields an outgoing and an incoming row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportRow {
+    /// Timestamp of the checkpoint holding the transaction, in Unix seconds.
+    pub timestamp: u64,
+    /// Checkpoint holding the transaction.
+    pub checkpoint: u64,
+    /// Transaction hash.
+    pub hash: B256,
+    /// The account the row is about.
+    pub account: Address,
+    /// Whether the account sent or received.
+    pub direction: ActivityDirection,
+    /// The operation performed.
+    pub kind: OperationKind,
+    /// The other side: the recipient or subject for outgoing rows, the signer
+    /// or source for incoming ones. `None` when the account is on both sides.
+    pub counterparty: Option<Address>,
+    /// Token the operation applies to; `None` for native transfers.
+    pub token: Option<Address>,
+    /// Symbol of the token.
+    pub symbol: Option<String>,
+    /// Amount in the token's decimals; base units for native transfers.
+    pub amount: Option<String>,
+    /// Fee paid by the account, in the same units as `amount`. Only set on
+    /// outgoing rows of transactions the account signed.
+    pub fee: Option<String>,
+}
+
+impl ReportRow {
+    /// The row's values in [`CSV_COLUMNS`] order, absent values as empty
+    /// strings.
+    pub fn csv_values(&self) -> [String; 11] {
+        let or_empty = |value: Option<String>| value.unwrap_or_default();
+        [
+            self.timestamp.to_string(),
+            self.checkpoint.to_string(),
+            self.hash.to_string(),
+            self.account.to_string(),
+            self.direction.to_string(),
+            self.kind.to_string(),
+            or_empty(self.counterparty.map(|address| address.to_string())),
+            or_empty(self.token.map(|address| address.to_string())),
+            or_empty(self.symbol.clone()),
+            or_empty(self.amount.clone()),
+            or_empty(self.fee.clone()),
+        ]
+    }
+}
+
+/// Render rows as CSV with a header line.
+///
+/// Values holding a comma, quote or line break are quoted as in RFC 4180.
+pub fn to_csv(rows: &[ReportRow]) -> String {
+    let mut csv = String::new();
+    push_record(&mut csv, CSV_COLUMNS.iter().copied());
+    for row in rows {
+        push_record(&mut csv, row.csv_values().iter().map(String::as_str));
+    }
+    csv
+}
+
+fn push_record<'a>(csv: &mut String, values: impl Iterator<Item = &'a str>) {
+    for (index, value) in values.enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        if value.contains([',', '"', '\n', '\r']) {
+            csv.push('"');
+            csv.push_str(&value.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(value);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_csv_quotes_special_values() {
+        let row = ReportRow {
+            timestamp: 1703097600,
+            checkpoint: 7,
+            hash: B256::repeat_byte(0x01),
+            account: Address::repeat_byte(0xaa),
+            direction: ActivityDirection::Outgoing,
+            kind: OperationKind::Transfer,
+            counterparty: Some(Address::repeat_byte(0xbb)),
+            token: None,
+            symbol: Some("US\"D, X".to_string()),
+            amount: Some("1.5".to_string()),
+            fee: None,
+        };
+
+        let csv = to_csv(std::slice::from_ref(&row));
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(lines[0], CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            format!(
+                "1703097600,7,{},{},Outgoing,Transfer,{},,\"US\"\"D, X\",1.5,",
+                row.hash,
+                row.account,
+                Address::repeat_byte(0xbb)
+            )
+        );
+        assert_eq!(lines[2], "");
+    }
+}
@@ -0,0 +1,329 @@
+//! Mapping between checkpoint numbers and wall-clock time.
+
+use crate::{Checkpoint, Client, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Checkpoints between the two samples [`Client::checkpoint_clock`]
+/// measures the checkpoint interval over.
+pub const DEFAULT_CLOCK_SAMPLE_SPAN: u64 = 100;
+
+/// Where a checkpoint timestamp came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeSource {
+    /// Read from the checkpoint itself.
+    Observed,
+    /// Extrapolated from the observed checkpoint interval.
+    Estimated,
+}
+
+/// The wall-clock time of a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckpointTime {
+    /// Checkpoint number.
+    pub checkpoint: u64,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    /// Whether the timestamp is observed or estimated.
+    pub source: TimeSource,
+}
+
+impl Display for CheckpointTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let qualifier = match self.source {
+            TimeSource::Observed => "",
+            TimeSource::Estimated => "~",
+        };
+        write!(
+            f,
+            "Checkpoint #{} at {}{}",
+            self.checkpoint, qualifier, self.timestamp
+        )
+    }
+}
+
+/// A linear clock anchored on one checkpoint with a known time.
+///
+/// Checkpoints are assumed to follow each other every `interval_ms`
+/// milliseconds, before the anchor as well as after it. The further a
+/// checkpoint is from the anchor, the less accurate its estimate; re-anchor
+/// on a recent checkpoint for long-running reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CheckpointClock {
+    /// Checkpoint the clock is anchored on.
+    pub anchor: u64,
+    /// Unix timestamp of the anchor, in seconds.
+    pub anchor_timestamp: u64,
+    /// Time between consecutive checkpoints, in milliseconds.
+    pub interval_ms: u64,
+}
+
+impl CheckpointClock {
+    /// A clock anchored on `anchor` at `anchor_timestamp`.
+    pub fn new(anchor: u64, anchor_timestamp: u64, interval_ms: u64) -> Self {
+        Self {
+            anchor,
+            anchor_timestamp,
+            interval_ms,
+        }
+    }
+
+    /// Measure the interval between two checkpoints and anchor on the later.
+    ///
+    /// Fails when the checkpoints are the same or either carries no
+    /// timestamp.
+    pub fn from_checkpoints(a: &Checkpoint, b: &Checkpoint) -> Result<Self> {
+        let (earlier, later) = if a.number <= b.number { (a, b) } else { (b, a) };
+        if earlier.number == later.number {
+            return Err(Error::invalid_parameter(
+                "checkpoints",
+                format!(
+                    "Sampling needs two distinct checkpoints, got #{} twice",
+                    later.number
+                ),
+            ));
+        }
+        if earlier.timestamp == 0 || later.timestamp == 0 {
+            return Err(no_timestamps());
+        }
+        let elapsed_ms = u128::from(later.timestamp.saturating_sub(earlier.timestamp)) * 1000;
+        let interval_ms = elapsed_ms / u128::from(later.number - earlier.number);
+        Ok(Self::new(
+            later.number,
+            later.timestamp,
+            u64::try_from(interval_ms).unwrap_or(u64::MAX),
+        ))
+    }
+
+    /// Estimated Unix timestamp of `checkpoint`, in seconds.
+    pub fn timestamp_of(&self, checkpoint: u64) -> u64 {
+        let offset_ms = |distance: u64| {
+            let ms = u128::from(distance) * u128::from(self.interval_ms);
+            u64::try_from(ms / 1000).unwrap_or(u64::MAX)
+        };
+        if checkpoint >= self.anchor {
+            self.anchor_timestamp
+                .saturating_add(offset_ms(checkpoint - self.anchor))
+        } else {
+            self.anchor_timestamp
+                .saturating_sub(offset_ms(self.anchor - checkpoint))
+        }
+    }
+
+    /// Estimated number of the last checkpoint made by `timestamp`.
+    pub fn checkpoint_at(&self, timestamp: u64) -> u64 {
+        if self.interval_ms == 0 {
+            return self.anchor;
+        }
+        let distance = |seconds: u64| {
+            let checkpoints = u128::from(seconds) * 1000 / u128::from(self.interval_ms);
+            u64::try_from(checkpoints).unwrap_or(u64::MAX)
+        };
+        if timestamp >= self.anchor_timestamp {
+            self.anchor
+                .saturating_add(distance(timestamp - self.anchor_timestamp))
+        } else {
+            self.anchor
+                .saturating_sub(distance(self.anchor_timestamp - timestamp))
+        }
+    }
+
+    /// The time of `checkpoint` as estimated by this clock.
+    pub fn estimate(&self, checkpoint: u64) -> CheckpointTime {
+        CheckpointTime {
+            checkpoint,
+            timestamp: self.timestamp_of(checkpoint),
+            source: TimeSource::Estimated,
+        }
+    }
+}
+
+impl Client {
+    /// Build a clock from the latest checkpoint and the one
+    /// [`DEFAULT_CLOCK_SAMPLE_SPAN`] checkpoints before it.
+    ///
+    /// When the chain is younger than that span the first checkpoint is
+    /// sampled instead.
+    ///
+    /// # Returns
+    ///
+    /// A clock anchored on the latest checkpoint.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let clock = client.checkpoint_clock().await?;
+    ///     println!("One checkpoint every {} ms", clock.interval_ms);
+    ///     println!("Checkpoint 1000 at ~{}", clock.timestamp_of(1000));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn checkpoint_clock(&self) -> Result<CheckpointClock> {
+        let latest = self.get_checkpoint_number().await?.number;
+        let anchor = self.get_checkpoint_by_number(latest, false).await?;
+        if anchor.timestamp == 0 {
+            return Err(no_timestamps());
+        }
+        let sample = latest.saturating_sub(DEFAULT_CLOCK_SAMPLE_SPAN);
+        if sample < latest {
+            let earlier = self.get_checkpoint_by_number(sample, false).await?;
+            if let Ok(clock) = CheckpointClock::from_checkpoints(&earlier, &anchor) {
+                return Ok(clock);
+            }
+        }
+        Err(Error::validation(
+            "checkpoints",
+            "No second checkpoint to measure the interval against",
+        ))
+    }
+
+    /// Estimate when checkpoint `number` was or will be made.
+    ///
+    /// Checkpoints that exist and carry a timestamp report it as observed;
+    /// future checkpoints are extrapolated with [`Client::checkpoint_clock`].
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The checkpoint number
+    ///
+    /// # Returns
+    ///
+    /// The checkpoint's Unix timestamp and whether it was observed or
+    /// estimated.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///
+    ///     let latest = client.get_checkpoint_number().await?.number;
+    ///     let time = client.estimate_time_of_checkpoint(latest + 600).await?;
+    ///     println!("{}", time);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn estimate_time_of_checkpoint(&self, number: u64) -> Result<CheckpointTime> {
+        let latest = self.get_checkpoint_number().await?.number;
+        if number <= latest {
+            let checkpoint = self.get_checkpoint_by_number(number, false).await?;
+            if checkpoint.timestamp != 0 {
+                return Ok(CheckpointTime {
+                    checkpoint: number,
+                    timestamp: checkpoint.timestamp,
+                    source: TimeSource::Observed,
+                });
+            }
+        }
+        Ok(self.checkpoint_clock().await?.estimate(number))
+    }
+}
+
+fn no_timestamps() -> Error {
+    Error::validation("timestamp", "Checkpoint responses carry no timestamps")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::{CheckpointTransactions, Hash};
+
+    fn checkpoint(number: u64, timestamp: u64) -> Checkpoint {
+        Checkpoint {
+            hash: Hash::default(),
+            parent_hash: Hash::default(),
+            state_root: Hash::default(),
+            transactions_root: Hash::default(),
+            receipts_root: Hash::default(),
+            number,
+            timestamp,
+            extra_data: String::new(),
+            transactions: CheckpointTransactions::Hashes(Vec::new()),
+            size: None,
+        }
+    }
+
+    #[test]
+    fn test_clock_from_checkpoints_extrapolates_both_ways() {
+        let clock = CheckpointClock::from_checkpoints(
+            &checkpoint(1100, 1_700_000_050),
+            &checkpoint(1000, 1_700_000_000),
+        )
+        .expect("Clock should build");
+        assert_eq!(clock, CheckpointClock::new(1100, 1_700_000_050, 500));
+
+        assert_eq!(clock.timestamp_of(1100), 1_700_000_050);
+        assert_eq!(clock.timestamp_of(1120), 1_700_000_060);
+        assert_eq!(clock.timestamp_of(1000), 1_700_000_000);
+        assert_eq!(clock.timestamp_of(0), 1_699_999_500);
+        assert_eq!(clock.checkpoint_at(1_700_000_060), 1120);
+        assert_eq!(clock.checkpoint_at(1_700_000_000), 1000);
+        assert_eq!(
+            clock.estimate(1120).to_string(),
+            "Checkpoint #1120 at ~1700000060"
+        );
+    }
+
+    #[test]
+    fn test_clock_requires_distinct_timestamped_checkpoints() {
+        let sample = checkpoint(5, 1_700_000_000);
+        assert!(CheckpointClock::from_checkpoints(&sample, &sample).is_err());
+        assert!(CheckpointClock::from_checkpoints(&sample, &checkpoint(6, 0)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_estimate_time_of_checkpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 200}"#)
+            .create_async()
+            .await;
+        for (number, timestamp) in [(100u64, 1_700_000_000u64), (200, 1_700_000_100)] {
+            server
+                .mock("GET", "/v1/checkpoints/by_number")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "number".into(),
+                    number.to_string(),
+                ))
+                .with_status(200)
+                .with_body(
+                    serde_json::to_string(&checkpoint(number, timestamp))
+                        .expect("Checkpoint should serialize"),
+                )
+                .create_async()
+                .await;
+        }
+
+        let client = crate::ClientBuilder::new()
+            .network(crate::Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let observed = client
+            .estimate_time_of_checkpoint(100)
+            .await
+            .expect("Past checkpoint should be read");
+        assert_eq!(observed.source, TimeSource::Observed);
+        assert_eq!(observed.timestamp, 1_700_000_000);
+
+        let estimated = client
+            .estimate_time_of_checkpoint(260)
+            .await
+            .expect("Future checkpoint should be estimated");
+        assert_eq!(estimated.source, TimeSource::Estimated);
+        assert_eq!(estimated.timestamp, 1_700_000_160);
+    }
+}
@@ -0,0 +1,1055 @@
+//! JSON Schema export for the API request and response types.
+//!
+//! Enabled with the `schema` feature. Every type the SDK sends to or reads
+//! from the L1 implements [`JsonSchema`], describing its JSON wire form:
+//! field names as serialized, addresses and hashes as `0x`-prefixed hex,
+//! and `U256` amounts as either hex quantities or decimal strings depending
+//! on the field. [`api_schema`] collects all of them into one document with
+//! a definition per type, which non-Rust integrations can feed to their own
+//! validator or code generator.
+//!
+//! The schemas describe what the SDK serializes. Fields the SDK also
+//! accepts under an alias when deserializing appear under their canonical
+//! name only.
+
+use crate::requests::{
+    BlacklistAction, BlacklistTokenRequest, BurnTokenRequest, FeeEstimateRequest, MintTokenRequest,
+    PauseAction, PauseTokenRequest, PaymentRequest, TokenAuthorityRequest, UpdateMetadataRequest,
+    WhitelistAction, WhitelistTokenRequest,
+};
+use crate::responses::{
+    AccountBBNonce, AccountNonce, AssociatedTokenAccount, BridgeInfo, CertificateData,
+    ChainIdResponse, Checkpoint, CheckpointHeader, CheckpointNumber, CheckpointTransactions,
+    EpochResponse, FeeEstimate, FinalizedTransaction, Hash, HashWithToken, ListMembership,
+    MetadataKVPair, MintInfo, MinterAllowance, Page, SuccessInfo, SupplyHistory, TokenBalance,
+    TokenMetadata, TokenSupply, Transaction, TransactionReceipt, TransactionResponse, TxPayload,
+};
+use crate::{
+    ActionType, Authority, AuthorityAction, NativePaymentPayload, PaymentPayload, Result,
+    Signature, TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload,
+    TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
+};
+use schemars::generate::{SchemaGenerator, SchemaSettings};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+#[cfg(feature = "bridge")]
+use crate::requests::{
+    TokenBridgeAndMintPayload, TokenBridgeAndMintRequest, TokenBurnAndBridgePayload,
+    TokenBurnAndBridgeRequest,
+};
+
+pub use schemars::{JsonSchema, Schema, json_schema};
+
+/// Title of the document built by [`api_schema`].
+pub const API_SCHEMA_TITLE: &str = "OneMoney API";
+
+/// Build one schema document defining every request and response type.
+///
+/// The document validates nothing by itself; each type is a definition
+/// under `$defs`, keyed by its Rust type name, for consumers to reference.
+pub fn api_schema() -> Schema {
+    let mut generator = SchemaSettings::draft2020_12().into_generator();
+    register_all(&mut generator);
+    let definitions = generator.take_definitions(true);
+    json_schema!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": API_SCHEMA_TITLE,
+        "$defs": definitions,
+    })
+}
+
+/// [`api_schema`] as pretty-printed JSON.
+pub fn api_schema_json() -> Result<String> {
+    Ok(serde_json::to_string_pretty(&api_schema())?)
+}
+
+fn register_all(generator: &mut SchemaGenerator) {
+    macro_rules! register {
+        ($($ty:ty),* $(,)?) => {
+            $(generator.subschema_for::<$ty>();)*
+        };
+    }
+
+    register!(
+        // Requests
+        PaymentPayload,
+        PaymentRequest,
+        NativePaymentPayload,
+        FeeEstimateRequest,
+        TokenMintPayload,
+        MintTokenRequest,
+        TokenBurnPayload,
+        BurnTokenRequest,
+        TokenAuthorityPayload,
+        TokenAuthorityRequest,
+        TokenPausePayload,
+        PauseTokenRequest,
+        TokenBlacklistPayload,
+        BlacklistTokenRequest,
+        TokenWhitelistPayload,
+        WhitelistTokenRequest,
+        TokenMetadataUpdatePayload,
+        UpdateMetadataRequest,
+        // Responses
+        TransactionResponse,
+        AccountNonce,
+        AccountBBNonce,
+        AssociatedTokenAccount,
+        TokenBalance,
+        ChainIdResponse,
+        Checkpoint,
+        CheckpointHeader,
+        CheckpointNumber,
+        EpochResponse,
+        MintInfo,
+        ListMembership,
+        TokenSupply,
+        SupplyHistory,
+        FeeEstimate,
+        HashWithToken,
+        Transaction,
+        TransactionReceipt,
+        FinalizedTransaction,
+        ActionType,
+    );
+
+    #[cfg(feature = "bridge")]
+    register!(
+        TokenBridgeAndMintPayload,
+        TokenBridgeAndMintRequest,
+        TokenBurnAndBridgePayload,
+        TokenBurnAndBridgeRequest,
+    );
+}
+
+/// Strings that stand in for a non-JSON value on the wire.
+macro_rules! string_schema {
+    ($($marker:ident => $name:literal, $pattern:literal, $description:literal;)*) => {
+        $(
+            enum $marker {}
+
+            impl JsonSchema for $marker {
+                fn schema_name() -> Cow<'static, str> {
+                    $name.into()
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "pattern": $pattern,
+                        "description": $description,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+string_schema! {
+    HexAddress => "Address", "^0x[0-9a-fA-F]{40}$", "20-byte address as 0x-prefixed hex.";
+    HexHash => "B256", "^0x[0-9a-fA-F]{64}$", "32-byte hash as 0x-prefixed hex.";
+    HexBytes => "Bytes", "^0x([0-9a-fA-F]{2})*$", "Arbitrary bytes as 0x-prefixed hex.";
+    HexQuantity => "HexQuantity", "^0x[0-9a-fA-F]{1,64}$",
+        "Unsigned 256-bit integer as 0x-prefixed hex without leading zeros.";
+    DecimalAmount => "DecimalAmount", "^[0-9]+$",
+        "Unsigned integer as a base-10 string, in the token's smallest unit.";
+}
+
+/// Properties of one JSON object schema, collected field by field.
+#[derive(Default)]
+struct ObjectSchema {
+    properties: Map<String, Value>,
+    required: Vec<Value>,
+    all_of: Vec<Value>,
+}
+
+impl ObjectSchema {
+    fn field<T: JsonSchema>(&mut self, generator: &mut SchemaGenerator, name: &str) {
+        self.optional::<T>(generator, name);
+        self.required.push(name.into());
+    }
+
+    fn optional<T: JsonSchema>(&mut self, generator: &mut SchemaGenerator, name: &str) {
+        self.properties
+            .insert(name.into(), generator.subschema_for::<T>().to_value());
+    }
+
+    fn flatten<T: JsonSchema>(&mut self, generator: &mut SchemaGenerator) {
+        self.all_of.push(generator.subschema_for::<T>().to_value());
+    }
+
+    fn finish(self) -> Schema {
+        let mut schema = json_schema!({
+            "type": "object",
+            "properties": self.properties,
+            "required": self.required,
+        });
+        if !self.all_of.is_empty() {
+            schema.insert("allOf".into(), self.all_of.into());
+        }
+        schema
+    }
+}
+
+/// The object schema for a list of fields, each given as its wire type.
+///
+/// `#[optional]` marks a field that may be absent, `#[flatten]` one whose
+/// properties are merged into the enclosing object.
+macro_rules! object_body {
+    ($generator:ident, { $($(#[$attr:ident])? $field:ident: $schema:ty),* $(,)? }) => {{
+        #[allow(unused_mut)]
+        let mut object = ObjectSchema::default();
+        $(object_body!(@field object, $generator, [$($attr)?], $field, $schema);)*
+        object.finish()
+    }};
+    (@field $object:ident, $generator:ident, [], $field:ident, $schema:ty) => {
+        $object.field::<$schema>($generator, stringify!($field))
+    };
+    (@field $object:ident, $generator:ident, [optional], $field:ident, $schema:ty) => {
+        $object.optional::<$schema>($generator, stringify!($field))
+    };
+    (@field $object:ident, $generator:ident, [flatten], $field:ident, $schema:ty) => {
+        $object.flatten::<$schema>($generator)
+    };
+}
+
+macro_rules! object_schema {
+    ($($ty:ty as $name:literal { $($body:tt)* })*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn schema_name() -> Cow<'static, str> {
+                    $name.into()
+                }
+
+                fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+                    object_body!(generator, { $($body)* })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! string_enum_schema {
+    ($($ty:ty as $name:literal [$($variant:literal),+ $(,)?])*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn schema_name() -> Cow<'static, str> {
+                    $name.into()
+                }
+
+                fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                    json_schema!({
+                        "type": "string",
+                        "enum": [$($variant),+],
+                    })
+                }
+            }
+        )*
+    };
+}
+
+string_enum_schema! {
+    AuthorityAction as "AuthorityAction" ["Grant", "Revoke"]
+    Authority as "Authority" [
+        "MasterMintBurn",
+        "MintBurnTokens",
+        "Pause",
+        "ManageList",
+        "UpdateMetadata",
+        "Bridge",
+    ]
+    PauseAction as "PauseAction" ["Pause", "Unpause"]
+    BlacklistAction as "BlacklistAction" ["Add", "Remove"]
+    WhitelistAction as "WhitelistAction" ["Add", "Remove"]
+    ActionType as "ActionType" [
+        "payment",
+        "token_issue",
+        "token_mint",
+        "token_burn",
+        "authority_grant",
+        "authority_revoke",
+    ]
+}
+
+object_schema! {
+    Signature as "Signature" {
+        r: HexQuantity,
+        s: HexQuantity,
+        v: u64,
+    }
+
+    PaymentPayload as "PaymentPayload" {
+        chain_id: u64,
+        nonce: u64,
+        recipient: HexAddress,
+        value: DecimalAmount,
+        token: HexAddress,
+    }
+    PaymentRequest as "PaymentRequest" {
+        #[flatten] payload: PaymentPayload,
+        signature: Signature,
+    }
+    NativePaymentPayload as "NativePaymentPayload" {
+        chain_id: u64,
+        nonce: u64,
+        recipient: HexAddress,
+        value: DecimalAmount,
+    }
+    FeeEstimateRequest as "FeeEstimateRequest" {
+        from: String,
+        value: String,
+        #[optional] token: Option<String>,
+    }
+
+    TokenMintPayload as "TokenMintPayload" {
+        chain_id: u64,
+        nonce: u64,
+        recipient: HexAddress,
+        value: DecimalAmount,
+        token: HexAddress,
+    }
+    MintTokenRequest as "MintTokenRequest" {
+        #[flatten] payload: TokenMintPayload,
+        signature: Signature,
+    }
+    TokenBurnPayload as "TokenBurnPayload" {
+        chain_id: u64,
+        nonce: u64,
+        recipient: HexAddress,
+        value: DecimalAmount,
+        token: HexAddress,
+    }
+    BurnTokenRequest as "BurnTokenRequest" {
+        #[flatten] payload: TokenBurnPayload,
+        signature: Signature,
+    }
+    TokenAuthorityPayload as "TokenAuthorityPayload" {
+        chain_id: u64,
+        nonce: u64,
+        action: AuthorityAction,
+        authority_type: Authority,
+        authority_address: HexAddress,
+        token: HexAddress,
+        value: DecimalAmount,
+    }
+    TokenAuthorityRequest as "TokenAuthorityRequest" {
+        #[flatten] payload: TokenAuthorityPayload,
+        signature: Signature,
+    }
+    TokenPausePayload as "TokenPausePayload" {
+        chain_id: u64,
+        nonce: u64,
+        action: PauseAction,
+        token: HexAddress,
+    }
+    PauseTokenRequest as "PauseTokenRequest" {
+        #[flatten] payload: TokenPausePayload,
+        signature: Signature,
+    }
+    TokenBlacklistPayload as "TokenBlacklistPayload" {
+        chain_id: u64,
+        nonce: u64,
+        action: BlacklistAction,
+        address: HexAddress,
+        token: HexAddress,
+    }
+    BlacklistTokenRequest as "BlacklistTokenRequest" {
+        #[flatten] payload: TokenBlacklistPayload,
+        signature: Signature,
+    }
+    TokenWhitelistPayload as "TokenWhitelistPayload" {
+        chain_id: u64,
+        nonce: u64,
+        action: WhitelistAction,
+        address: HexAddress,
+        token: HexAddress,
+    }
+    WhitelistTokenRequest as "WhitelistTokenRequest" {
+        #[flatten] payload: TokenWhitelistPayload,
+        signature: Signature,
+    }
+    TokenMetadataUpdatePayload as "TokenMetadataUpdatePayload" {
+        chain_id: u64,
+        nonce: u64,
+        name: String,
+        uri: String,
+        token: HexAddress,
+        additional_metadata: Vec<MetadataKVPair>,
+    }
+    UpdateMetadataRequest as "UpdateMetadataRequest" {
+        #[flatten] payload: TokenMetadataUpdatePayload,
+        signature: Signature,
+    }
+
+    TransactionResponse as "TransactionResponse" {
+        hash: HexHash,
+    }
+    AccountNonce as "AccountNonce" {
+        nonce: u64,
+    }
+    AccountBBNonce as "AccountBBNonce" {
+        bbnonce: u64,
+    }
+    AssociatedTokenAccount as "AssociatedTokenAccount" {
+        balance: String,
+        nonce: u64,
+    }
+    TokenBalance as "TokenBalance" {
+        token: HexAddress,
+        amount: HexQuantity,
+        decimals: u8,
+    }
+    ChainIdResponse as "ChainIdResponse" {
+        chain_id: u64,
+    }
+    Checkpoint as "Checkpoint" {
+        hash: Hash,
+        parent_hash: Hash,
+        state_root: Hash,
+        transactions_root: Hash,
+        receipts_root: Hash,
+        number: u64,
+        timestamp: u64,
+        extra_data: String,
+        transactions: CheckpointTransactions,
+        #[optional] size: Option<u64>,
+    }
+    CheckpointHeader as "CheckpointHeader" {
+        hash: Hash,
+        parent_hash: Hash,
+        state_root: Hash,
+        transactions_root: Hash,
+        receipts_root: Hash,
+        number: u64,
+        timestamp: u64,
+        extra_data: String,
+    }
+    CheckpointNumber as "CheckpointNumber" {
+        number: u64,
+    }
+    EpochResponse as "EpochResponse" {
+        epoch_id: u64,
+        certificate_hash: HexHash,
+        #[flatten] certificate_data: CertificateData,
+    }
+    MintInfo as "MintInfo" {
+        symbol: String,
+        master_authority: HexAddress,
+        master_mint_burn_authority: HexAddress,
+        mint_burn_authorities: Vec<MinterAllowance>,
+        pause_authorities: Vec<HexAddress>,
+        list_authorities: Vec<HexAddress>,
+        black_list: Vec<HexAddress>,
+        white_list: Vec<HexAddress>,
+        metadata_update_authorities: Vec<HexAddress>,
+        bridge_mint_authorities: Vec<HexAddress>,
+        supply: String,
+        decimals: u8,
+        is_paused: bool,
+        is_private: bool,
+        #[optional] meta: Option<TokenMetadata>,
+    }
+    ListMembership as "ListMembership" {
+        address: HexAddress,
+        blacklisted: bool,
+        whitelisted: bool,
+        is_private: bool,
+    }
+    TokenSupply as "TokenSupply" {
+        token: HexAddress,
+        circulating: HexQuantity,
+        decimals: u8,
+        checkpoint: u64,
+    }
+    SupplyHistory as "SupplyHistory" {
+        at: TokenSupply,
+        current: TokenSupply,
+        minted: HexQuantity,
+        burned: HexQuantity,
+    }
+    MinterAllowance as "MinterAllowance" {
+        minter: HexAddress,
+        allowance: String,
+    }
+    TokenMetadata as "TokenMetadata" {
+        name: String,
+        uri: String,
+        additional_metadata: Vec<MetadataKVPair>,
+    }
+    MetadataKVPair as "MetadataKVPair" {
+        key: String,
+        value: String,
+    }
+    BridgeInfo as "BridgeInfo" {
+        bbnonce: u64,
+        destination_chain_id: u64,
+        destination_address: String,
+        #[optional] bridge_param: Option<HexBytes>,
+    }
+    SuccessInfo as "SuccessInfo" {
+        sender: HexAddress,
+        receiver: HexAddress,
+        is_private: bool,
+        message: String,
+        #[optional] bridge_info: Option<BridgeInfo>,
+    }
+    FeeEstimate as "FeeEstimate" {
+        fee: String,
+    }
+    HashWithToken as "HashWithToken" {
+        hash: HexHash,
+        token: HexAddress,
+    }
+    Transaction as "Transaction" {
+        hash: HexHash,
+        #[optional] checkpoint_hash: Option<HexHash>,
+        #[optional] checkpoint_number: Option<u64>,
+        #[optional] transaction_index: Option<u64>,
+        chain_id: u64,
+        from: HexAddress,
+        nonce: u64,
+        #[flatten] data: TxPayload,
+        signature: Signature,
+    }
+    TransactionReceipt as "TransactionReceipt" {
+        success: bool,
+        transaction_hash: HexHash,
+        #[optional] transaction_index: Option<u64>,
+        #[optional] checkpoint_hash: Option<HexHash>,
+        #[optional] checkpoint_number: Option<u64>,
+        fee_used: DecimalAmount,
+        from: HexAddress,
+        #[optional] recipient: Option<HexAddress>,
+        #[optional] token_address: Option<HexAddress>,
+        #[optional] success_info: Option<SuccessInfo>,
+    }
+    FinalizedTransaction as "FinalizedTransaction" {
+        epoch: u64,
+        #[flatten] receipt: TransactionReceipt,
+        counter_signatures: Vec<Signature>,
+    }
+}
+
+#[cfg(feature = "bridge")]
+object_schema! {
+    TokenBridgeAndMintPayload as "TokenBridgeAndMintPayload" {
+        chain_id: u64,
+        nonce: u64,
+        recipient: HexAddress,
+        value: DecimalAmount,
+        token: HexAddress,
+        source_chain_id: u64,
+        source_tx_hash: String,
+        #[optional] bridge_metadata: Option<String>,
+    }
+    TokenBridgeAndMintRequest as "TokenBridgeAndMintRequest" {
+        #[flatten] data: TokenBridgeAndMintPayload,
+        signature: Signature,
+    }
+    TokenBurnAndBridgePayload as "TokenBurnAndBridgePayload" {
+        chain_id: u64,
+        nonce: u64,
+        sender: HexAddress,
+        value: DecimalAmount,
+        token: HexAddress,
+        destination_chain_id: u64,
+        destination_address: String,
+        escrow_fee: DecimalAmount,
+        #[optional] bridge_metadata: Option<String>,
+        #[optional] bridge_param: Option<HexBytes>,
+    }
+    TokenBurnAndBridgeRequest as "TokenBurnAndBridgeRequest" {
+        #[flatten] data: TokenBurnAndBridgePayload,
+        signature: Signature,
+    }
+}
+
+impl JsonSchema for Hash {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Hash".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<HexHash>()
+    }
+}
+
+impl JsonSchema for CheckpointTransactions {
+    fn schema_name() -> Cow<'static, str> {
+        "CheckpointTransactions".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                generator.subschema_for::<Vec<Transaction>>(),
+                generator.subschema_for::<Vec<Hash>>(),
+            ],
+        })
+    }
+}
+
+impl JsonSchema for CertificateData {
+    fn schema_name() -> Cow<'static, str> {
+        "CertificateData".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                object_body!(generator, { certificate: String }),
+                object_body!(generator, { certificate: Value }),
+            ],
+        })
+    }
+}
+
+impl JsonSchema for TxPayload {
+    fn schema_name() -> Cow<'static, str> {
+        "TxPayload".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        macro_rules! variants {
+            ($($variant:ident { $($body:tt)* })*) => {
+                vec![$(
+                    json_schema!({
+                        "type": "object",
+                        "properties": {
+                            "transaction_type": { "const": stringify!($variant) },
+                            "data": object_body!(generator, { $($body)* }),
+                        },
+                        "required": ["transaction_type", "data"],
+                    }),
+                )*]
+            };
+        }
+
+        let variants = variants! {
+            TokenCreate {
+                symbol: String,
+                decimals: u8,
+                master_authority: HexAddress,
+                is_private: bool,
+                name: String,
+            }
+            TokenTransfer {
+                value: String,
+                recipient: HexAddress,
+                #[optional] token: Option<HexAddress>,
+            }
+            TokenGrantAuthority {
+                authority_type: String,
+                authority_address: HexAddress,
+                #[optional] value: Option<String>,
+                token: HexAddress,
+            }
+            TokenRevokeAuthority {
+                authority_type: String,
+                authority_address: HexAddress,
+                #[optional] value: Option<String>,
+                token: HexAddress,
+            }
+            TokenBlacklistAccount {
+                address: HexAddress,
+                token: HexAddress,
+            }
+            TokenWhitelistAccount {
+                address: HexAddress,
+                token: HexAddress,
+            }
+            TokenMint {
+                value: String,
+                recipient: HexAddress,
+                token: HexAddress,
+            }
+            TokenBurn {
+                value: String,
+                recipient: HexAddress,
+                token: HexAddress,
+            }
+            TokenCloseAccount {
+                token: HexAddress,
+            }
+            TokenPause {
+                token: HexAddress,
+            }
+            TokenUnpause {
+                token: HexAddress,
+            }
+            TokenUpdateMetadata {
+                metadata: TokenMetadata,
+                token: HexAddress,
+            }
+            TokenBridgeAndMint {
+                recipient: HexAddress,
+                value: String,
+                source_chain_id: u64,
+                source_tx_hash: String,
+                #[optional] bridge_metadata: Option<String>,
+                token: HexAddress,
+            }
+            TokenBurnAndBridge {
+                value: String,
+                sender: HexAddress,
+                destination_chain_id: u64,
+                destination_address: String,
+                escrow_fee: String,
+                #[optional] bridge_metadata: Option<String>,
+                token: HexAddress,
+            }
+            Raw {
+                input: HexBytes,
+                token: HexAddress,
+            }
+        };
+        json_schema!({ "oneOf": variants })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Page<T> {
+    fn schema_name() -> Cow<'static, str> {
+        format!("Page_for_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("Page<{}>", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                object_body!(generator, {
+                    items: Vec<T>,
+                    #[optional] next_cursor: Option<String>,
+                    #[optional] prev_cursor: Option<String>,
+                    #[optional] total: Option<u64>,
+                }),
+                generator.subschema_for::<Vec<T>>(),
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::TxPayload;
+    use alloy_primitives::{Address, B256, U256};
+    use serde::Serialize;
+    use serde_json::json;
+
+    /// Check `value` against `schema` for the keywords this module emits.
+    ///
+    /// Objects are treated as closed unless they flatten another schema or
+    /// are flattened into one, so a field added to a struct but not to its
+    /// schema is reported.
+    fn check(
+        value: &Value,
+        schema: &Value,
+        defs: &Map<String, Value>,
+    ) -> std::result::Result<(), String> {
+        check_object(value, schema, defs, true)
+    }
+
+    fn check_object(
+        value: &Value,
+        schema: &Value,
+        defs: &Map<String, Value>,
+        closed: bool,
+    ) -> std::result::Result<(), String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/$defs/");
+            let target = defs
+                .get(name)
+                .ok_or(format!("missing definition {}", name))?;
+            return check_object(value, target, defs, closed);
+        }
+        if let Some(expected) = schema.get("const")
+            && value != expected
+        {
+            return Err(format!("{} is not {}", value, expected));
+        }
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+            && !allowed.contains(value)
+        {
+            return Err(format!("{} is not one of {:?}", value, allowed));
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            let matches = types.iter().any(|kind| match *kind {
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "integer" => value.is_u64() || value.is_i64(),
+                "boolean" => value.is_boolean(),
+                "null" => value.is_null(),
+                _ => false,
+            });
+            if !matches {
+                return Err(format!("{} is not of type {:?}", value, types));
+            }
+        }
+        if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+            for item in values {
+                check(item, items, defs)?;
+            }
+        }
+        if let (Some(properties), Some(object)) = (
+            schema.get("properties").and_then(Value::as_object),
+            value.as_object(),
+        ) {
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let name = required.as_str().unwrap_or_default();
+                if !object.contains_key(name) {
+                    return Err(format!("missing required field {}", name));
+                }
+            }
+            for (name, field) in object {
+                match properties.get(name) {
+                    Some(property) => check(field, property, defs)
+                        .map_err(|error| format!("{}: {}", name, error))?,
+                    None if closed && schema.get("allOf").is_none() => {
+                        return Err(format!("field {} is not in the schema", name));
+                    }
+                    None => {}
+                }
+            }
+        }
+        for member in schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            check_object(value, member, defs, false)?;
+        }
+        if let Some(branches) = schema.get("anyOf").and_then(Value::as_array)
+            && !branches
+                .iter()
+                .any(|branch| check_object(value, branch, defs, closed).is_ok())
+        {
+            return Err(format!("{} matches no anyOf branch", value));
+        }
+        if let Some(branches) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = branches
+                .iter()
+                .filter(|branch| check_object(value, branch, defs, closed).is_ok())
+                .count();
+            if matching != 1 {
+                return Err(format!("{} matches {} oneOf branches", value, matching));
+            }
+        }
+        Ok(())
+    }
+
+    fn assert_conforms<T: Serialize + JsonSchema>(value: &T) {
+        let schema = api_schema();
+        let defs = schema
+            .get("$defs")
+            .and_then(Value::as_object)
+            .expect("Schema should have definitions");
+        let name = T::schema_name();
+        let definition = defs
+            .get(name.as_ref())
+            .unwrap_or_else(|| panic!("{} should be registered", name));
+        let json = serde_json::to_value(value).expect("Value should serialize");
+        if let Err(error) = check(&json, definition, defs) {
+            panic!("{} does not match its schema: {}\n{}", name, error, json);
+        }
+    }
+
+    #[test]
+    fn test_api_schema_defines_every_type() {
+        let schema = api_schema();
+        assert_eq!(schema.get("title"), Some(&json!(API_SCHEMA_TITLE)));
+        let defs = schema
+            .get("$defs")
+            .and_then(Value::as_object)
+            .expect("Schema should have definitions");
+        for name in [
+            "PaymentRequest",
+            "TxPayload",
+            "FinalizedTransaction",
+            "Address",
+            "DecimalAmount",
+        ] {
+            assert!(defs.contains_key(name), "{} should be defined", name);
+        }
+        assert!(!defs.contains_key("Hash"), "Hash is inlined as B256");
+
+        let json = api_schema_json().expect("Schema should serialize");
+        assert!(json.contains("\"$defs\""));
+    }
+
+    #[test]
+    fn test_request_schemas_match_serialization() {
+        let payload = PaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 1,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000u64),
+            token: Address::repeat_byte(0x22),
+        };
+        let signature = Signature {
+            r: U256::from(7u64),
+            s: U256::MAX,
+            v: 1,
+        };
+        assert_conforms(&PaymentRequest {
+            payload,
+            signature: signature.clone(),
+        });
+        assert_conforms(&FeeEstimateRequest {
+            from: Address::ZERO.to_string(),
+            value: "1".to_string(),
+            token: None,
+        });
+        assert_conforms(&TokenAuthorityRequest {
+            payload: TokenAuthorityPayload {
+                chain_id: 1_212_101,
+                nonce: 2,
+                action: AuthorityAction::Grant,
+                authority_type: Authority::MintBurnTokens,
+                authority_address: Address::repeat_byte(0x11),
+                token: Address::repeat_byte(0x22),
+                value: U256::from(5u64),
+            },
+            signature: signature.clone(),
+        });
+        assert_conforms(&PauseTokenRequest {
+            payload: TokenPausePayload {
+                chain_id: 1_212_101,
+                nonce: 3,
+                action: PauseAction::Unpause,
+                token: Address::repeat_byte(0x22),
+            },
+            signature: signature.clone(),
+        });
+        assert_conforms(&UpdateMetadataRequest {
+            payload: TokenMetadataUpdatePayload {
+                chain_id: 1_212_101,
+                nonce: 4,
+                name: "Token".to_string(),
+                uri: String::new(),
+                token: Address::repeat_byte(0x22),
+                additional_metadata: vec![MetadataKVPair::default()],
+            },
+            signature,
+        });
+    }
+
+    #[test]
+    fn test_response_schemas_match_serialization() {
+        assert_conforms(&MintInfo {
+            meta: Some(TokenMetadata::default()),
+            mint_burn_authorities: vec![MinterAllowance::default()],
+            pause_authorities: vec![Address::repeat_byte(0x33)],
+            ..MintInfo::default()
+        });
+        assert_conforms(&MintInfo::default());
+        assert_conforms(&TokenBalance {
+            token: Address::repeat_byte(0x22),
+            amount: U256::from(10u64),
+            decimals: 6,
+        });
+
+        let transaction = Transaction {
+            checkpoint_number: Some(9),
+            data: TxPayload::TokenTransfer {
+                value: "5".to_string(),
+                recipient: Address::repeat_byte(0x11),
+                token: None,
+            },
+            ..Transaction::default()
+        };
+        assert_conforms(&transaction);
+        assert_conforms(&Transaction {
+            data: TxPayload::TokenPause {
+                token: Address::repeat_byte(0x22),
+            },
+            ..transaction.clone()
+        });
+
+        let receipt = TransactionReceipt {
+            success: true,
+            transaction_hash: B256::repeat_byte(0xaa),
+            transaction_index: Some(0),
+            checkpoint_hash: None,
+            checkpoint_number: Some(9),
+            fee_used: 21_000,
+            from: Address::repeat_byte(0x11),
+            recipient: None,
+            token_address: Some(Address::repeat_byte(0x22)),
+            success_info: Some(SuccessInfo {
+                sender: Address::repeat_byte(0x11),
+                receiver: Address::repeat_byte(0x33),
+                is_private: false,
+                message: String::new(),
+                bridge_info: None,
+            }),
+        };
+        assert_conforms(&receipt);
+        assert_conforms(&FinalizedTransaction {
+            epoch: 3,
+            receipt,
+            counter_signatures: vec![Signature::default()],
+        });
+
+        let header = CheckpointHeader {
+            hash: Hash::default(),
+            parent_hash: Hash::default(),
+            state_root: Hash::default(),
+            transactions_root: Hash::default(),
+            receipts_root: Hash::default(),
+            number: 9,
+            timestamp: 1_700_000_000,
+            extra_data: String::new(),
+        };
+        assert_conforms(&header);
+        for transactions in [
+            CheckpointTransactions::Full(vec![transaction]),
+            CheckpointTransactions::Hashes(vec![Hash::default()]),
+        ] {
+            assert_conforms(&Checkpoint {
+                hash: header.hash.clone(),
+                parent_hash: header.parent_hash.clone(),
+                state_root: header.state_root.clone(),
+                transactions_root: header.transactions_root.clone(),
+                receipts_root: header.receipts_root.clone(),
+                number: header.number,
+                timestamp: header.timestamp,
+                extra_data: header.extra_data.clone(),
+                transactions,
+                size: None,
+            });
+        }
+        assert_conforms(&EpochResponse {
+            epoch_id: 1,
+            certificate_hash: B256::ZERO,
+            certificate_data: CertificateData::Json {
+                certificate: json!({"signers": []}),
+            },
+        });
+    }
+
+    #[test]
+    fn test_schema_rejects_drifted_fields() {
+        let schema = api_schema();
+        let defs = schema
+            .get("$defs")
+            .and_then(Value::as_object)
+            .expect("Schema should have definitions");
+        let mut json = serde_json::to_value(AccountNonce { nonce: 1 }).expect("Should serialize");
+        json["frozen"] = json!(true);
+        assert!(check(&json, &defs["AccountNonce"], defs).is_err());
+    }
+}
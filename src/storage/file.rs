@@ -0,0 +1,206 @@
+//! File-system storage backend.
+
+use super::{Storage, validate_namespace};
+use crate::{Error, Result};
+use hex::{decode as hex_decode, encode as hex_encode};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const TEMP_SUFFIX: &str = ".tmp";
+
+/// Storage keeping one file per value under a root directory.
+///
+/// Each namespace is a subdirectory and each key a file named by the hex
+/// encoding of the key, so arbitrary keys map onto valid file names. Writes
+/// go to a temporary file that is renamed into place, so a crash mid-write
+/// leaves the previous value intact.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    root: PathBuf,
+}
+
+impl FileStorage {
+    /// Open a store rooted at `root`, creating the directory if needed.
+    pub fn new<P: Into<PathBuf>>(root: P) -> Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(|e| io_error("create", &root, e))?;
+        Ok(Self { root })
+    }
+
+    /// The directory holding the stored values.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn namespace_dir(&self, namespace: &str) -> Result<PathBuf> {
+        validate_namespace(namespace)?;
+        Ok(self.root.join(namespace))
+    }
+
+    fn value_path(&self, namespace: &str, key: &str) -> Result<PathBuf> {
+        Ok(self.namespace_dir(namespace)?.join(hex_encode(key)))
+    }
+}
+
+impl Storage for FileStorage {
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.value_path(namespace, key)?;
+        match fs::read(&path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error("read", &path, e)),
+        }
+    }
+
+    fn put(&self, namespace: &str, key: &str, value: &[u8]) -> Result<()> {
+        let directory = self.namespace_dir(namespace)?;
+        fs::create_dir_all(&directory).map_err(|e| io_error("create", &directory, e))?;
+
+        let file_name = hex_encode(key);
+        let path = directory.join(&file_name);
+        let temp_path = directory.join(format!("{}{}", file_name, TEMP_SUFFIX));
+        fs::write(&temp_path, value).map_err(|e| io_error("write", &temp_path, e))?;
+        fs::rename(&temp_path, &path).map_err(|e| io_error("rename", &path, e))
+    }
+
+    fn list(&self, namespace: &str) -> Result<Vec<String>> {
+        let directory = self.namespace_dir(namespace)?;
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error("list", &directory, e)),
+        };
+
+        let mut keys = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| io_error("list", &directory, e))?;
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            if name.ends_with(TEMP_SUFFIX) {
+                continue;
+            }
+            // Files not written by this backend are not keys.
+            if let Some(key) = hex_decode(name)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+            {
+                keys.push(key);
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn delete(&self, namespace: &str, key: &str) -> Result<bool> {
+        let path = self.value_path(namespace, key)?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(io_error("delete", &path, e)),
+        }
+    }
+}
+
+fn io_error(operation: &str, path: &Path, error: std::io::Error) -> Error {
+    Error::storage(format!(
+        "Failed to {} {}: {}",
+        operation,
+        path.display(),
+        error
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_root(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default();
+        env::temp_dir().join(format!(
+            "onemoney-file-storage-{}-{}-{}",
+            name,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    #[test]
+    fn test_file_storage_round_trip_survives_reopen() {
+        let root = temp_root("round-trip");
+        let storage = FileStorage::new(&root).expect("Storage should open");
+        storage
+            .put("journal", "0xabc/1", b"entry")
+            .expect("Put should succeed");
+        storage
+            .put("journal", "0xabc/0", b"first")
+            .expect("Put should succeed");
+
+        let reopened = FileStorage::new(&root).expect("Storage should reopen");
+        assert_eq!(
+            reopened
+                .get("journal", "0xabc/1")
+                .expect("Get should succeed"),
+            Some(b"entry".to_vec())
+        );
+        assert_eq!(
+            reopened.list("journal").expect("List should succeed"),
+            ["0xabc/0", "0xabc/1"]
+        );
+
+        assert!(
+            reopened
+                .delete("journal", "0xabc/1")
+                .expect("Delete should succeed")
+        );
+        assert!(
+            !reopened
+                .delete("journal", "0xabc/1")
+                .expect("Delete should succeed")
+        );
+        assert_eq!(
+            reopened
+                .get("journal", "0xabc/1")
+                .expect("Get should succeed"),
+            None
+        );
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn test_file_storage_missing_namespace_and_foreign_files() {
+        let root = temp_root("foreign");
+        let storage = FileStorage::new(&root).expect("Storage should open");
+        assert!(
+            storage
+                .list("cursors")
+                .expect("List should succeed")
+                .is_empty()
+        );
+        assert_eq!(
+            storage
+                .get("cursors", "watcher")
+                .expect("Get should succeed"),
+            None
+        );
+
+        storage
+            .put("cursors", "watcher", b"1")
+            .expect("Put should succeed");
+        fs::write(root.join("cursors").join("README"), b"not a key").expect("Write should succeed");
+        assert_eq!(
+            storage.list("cursors").expect("List should succeed"),
+            ["watcher"]
+        );
+
+        assert!(storage.put("../outside", "key", b"").is_err());
+        let _ = fs::remove_dir_all(root);
+    }
+}
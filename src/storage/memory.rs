@@ -0,0 +1,116 @@
+//! In-memory storage backend.
+
+use super::{Storage, validate_namespace};
+use crate::Result;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+
+type Namespaces = BTreeMap<String, BTreeMap<String, Vec<u8>>>;
+
+/// Storage kept in process memory; contents are lost on drop.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    namespaces: Mutex<Namespaces>,
+}
+
+impl MemoryStorage {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Namespaces> {
+        self.namespaces
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        validate_namespace(namespace)?;
+        Ok(self
+            .lock()
+            .get(namespace)
+            .and_then(|entries| entries.get(key))
+            .cloned())
+    }
+
+    fn put(&self, namespace: &str, key: &str, value: &[u8]) -> Result<()> {
+        validate_namespace(namespace)?;
+        self.lock()
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    fn list(&self, namespace: &str) -> Result<Vec<String>> {
+        validate_namespace(namespace)?;
+        Ok(self
+            .lock()
+            .get(namespace)
+            .map(|entries| entries.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    fn delete(&self, namespace: &str, key: &str) -> Result<bool> {
+        validate_namespace(namespace)?;
+        let mut namespaces = self.lock();
+        let removed = namespaces
+            .get_mut(namespace)
+            .is_some_and(|entries| entries.remove(key).is_some());
+        if namespaces.get(namespace).is_some_and(BTreeMap::is_empty) {
+            namespaces.remove(namespace);
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_storage_namespaces_are_isolated() {
+        let storage = MemoryStorage::new();
+        storage.put("a", "key", b"one").expect("Put should succeed");
+        storage.put("b", "key", b"two").expect("Put should succeed");
+
+        assert_eq!(
+            storage.get("a", "key").expect("Get should succeed"),
+            Some(b"one".to_vec())
+        );
+        assert_eq!(
+            storage.get("b", "key").expect("Get should succeed"),
+            Some(b"two".to_vec())
+        );
+        assert!(storage.delete("a", "key").expect("Delete should succeed"));
+        assert!(!storage.delete("a", "key").expect("Delete should succeed"));
+        assert!(storage.list("a").expect("List should succeed").is_empty());
+        assert_eq!(storage.list("b").expect("List should succeed"), ["key"]);
+    }
+
+    #[test]
+    fn test_memory_storage_lists_sorted_keys() {
+        let storage = MemoryStorage::new();
+        for key in ["c", "a", "b"] {
+            storage
+                .put("ns", key, key.as_bytes())
+                .expect("Put should succeed");
+        }
+        storage
+            .put("ns", "a", b"replaced")
+            .expect("Put should succeed");
+
+        assert_eq!(
+            storage.list("ns").expect("List should succeed"),
+            ["a", "b", "c"]
+        );
+        assert_eq!(
+            storage.get("ns", "a").expect("Get should succeed"),
+            Some(b"replaced".to_vec())
+        );
+        assert!(storage.put("bad/ns", "a", b"").is_err());
+    }
+}
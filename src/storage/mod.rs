@@ -0,0 +1,115 @@
+//! Pluggable persistence shared by the SDK's stateful features.
+//!
+//! Anything the SDK persists across restarts, such as watcher snapshots,
+//! goes through a [`Storage`] backend. Each feature keeps its records in its
+//! own namespace so a single backend can serve all of them.
+
+pub mod file;
+pub mod memory;
+
+// Re-export public interfaces
+pub use file::*;
+pub use memory::*;
+
+use crate::{Error, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Namespaces reserved for the SDK's own records.
+pub mod namespaces {
+    /// Transaction journal entries.
+    pub const JOURNAL: &str = "journal";
+    /// Transactions queued while offline.
+    pub const OFFLINE_QUEUE: &str = "offline_queue";
+    /// Address book entries.
+    pub const ADDRESS_BOOK: &str = "address_book";
+    /// Resume points for watchers and subscriptions.
+    pub const CURSORS: &str = "cursors";
+    /// External references recorded for payments.
+    pub const PAYMENT_REFERENCES: &str = "payment_references";
+}
+
+/// Namespaced key-value storage backend.
+///
+/// Namespaces are restricted to ASCII letters, digits, `_` and `-` so that
+/// backends can map them onto file names or table prefixes; keys may be any
+/// string.
+pub trait Storage: Send + Sync {
+    /// Read a value.
+    fn get(&self, namespace: &str, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Write a value, replacing any previous one.
+    fn put(&self, namespace: &str, key: &str, value: &[u8]) -> Result<()>;
+
+    /// List the keys in a namespace in ascending order.
+    fn list(&self, namespace: &str) -> Result<Vec<String>>;
+
+    /// Delete a value, returning whether it existed.
+    fn delete(&self, namespace: &str, key: &str) -> Result<bool>;
+}
+
+/// JSON helpers available on every [`Storage`].
+pub trait StorageExt: Storage {
+    /// Read and deserialize a JSON value.
+    fn get_json<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Result<Option<T>> {
+        match self.get(namespace, key)? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Serialize and write a JSON value.
+    fn put_json<T: Serialize>(&self, namespace: &str, key: &str, value: &T) -> Result<()> {
+        self.put(namespace, key, &serde_json::to_vec(value)?)
+    }
+}
+
+impl<S: Storage + ?Sized> StorageExt for S {}
+
+/// Check that a namespace is non-empty and uses only portable characters.
+pub(crate) fn validate_namespace(namespace: &str) -> Result<()> {
+    let valid = !namespace.is_empty()
+        && namespace
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::storage(format!("Invalid namespace '{}'", namespace)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_validate_namespace() {
+        assert!(validate_namespace(namespaces::JOURNAL).is_ok());
+        assert!(validate_namespace("custom-ns_2").is_ok());
+        assert!(validate_namespace("").is_err());
+        assert!(validate_namespace("../escape").is_err());
+        assert!(validate_namespace("with space").is_err());
+    }
+
+    #[test]
+    fn test_json_helpers_on_trait_object() {
+        let storage: Box<dyn Storage> = Box::new(MemoryStorage::new());
+        let mut value = BTreeMap::new();
+        value.insert("alice".to_string(), 1u64);
+
+        storage
+            .put_json(namespaces::ADDRESS_BOOK, "book", &value)
+            .expect("Value should be stored");
+        let loaded: Option<BTreeMap<String, u64>> = storage
+            .get_json(namespaces::ADDRESS_BOOK, "book")
+            .expect("Value should load");
+        assert_eq!(loaded, Some(value));
+
+        let missing: Option<u64> = storage
+            .get_json(namespaces::ADDRESS_BOOK, "missing")
+            .expect("Missing value is not an error");
+        assert_eq!(missing, None);
+    }
+}
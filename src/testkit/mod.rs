@@ -0,0 +1,10 @@
+//! Test support utilities for applications built on the SDK.
+//!
+//! Enabled with the `testkit` feature; not intended for production builds.
+
+pub mod recorder;
+pub mod server;
+
+// Re-export public interfaces
+pub use recorder::*;
+pub use server::*;
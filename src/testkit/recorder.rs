@@ -0,0 +1,238 @@
+//! Recording hook with typed assertions over submitted transactions.
+
+use crate::Signature;
+use crate::client::config::api_path;
+use crate::client::hooks::Hook;
+use crate::requests::TransactionPayload;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::any::type_name;
+use std::sync::{Arc, Mutex, MutexGuard};
+use url::Url;
+
+/// A payload type the recorder can pick out of recorded submissions.
+pub trait SubmittedPayload: TransactionPayload + DeserializeOwned {}
+
+impl<T: TransactionPayload + DeserializeOwned> SubmittedPayload for T {}
+
+/// A request observed by the recorder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP method.
+    pub method: String,
+    /// Full request URL.
+    pub url: String,
+    /// Request body, if one was sent.
+    pub body: Option<String>,
+    /// Response status, once the response arrived.
+    pub status: Option<u16>,
+}
+
+impl RecordedRequest {
+    fn path(&self) -> String {
+        Url::parse(&self.url)
+            .map(|url| url.path().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// A decoded transaction submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedSubmission<T> {
+    /// The submitted payload.
+    pub payload: T,
+    /// The signature sent with it.
+    pub signature: Signature,
+}
+
+/// Hook that records every request a client sends.
+///
+/// The recorder is a cheap handle: register a clone with
+/// [`ClientBuilder::hook`](crate::ClientBuilder::hook) and keep the original
+/// for assertions.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::testkit::NetworkRecorder;
+/// use onemoney_protocol::{ClientBuilder, Network, TokenMintPayload};
+///
+/// let recorder = NetworkRecorder::new();
+/// let client = ClientBuilder::new()
+///     .network(Network::Local)
+///     .hook(recorder.clone())
+///     .build()
+///     .unwrap();
+///
+/// // ... run the code under test with `client` ...
+///
+/// recorder.assert_submitted::<TokenMintPayload>(1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NetworkRecorder {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl NetworkRecorder {
+    /// Create an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every request recorded so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().clone()
+    }
+
+    /// Forget every recorded request.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Decode every recorded submission of payload type `T`, in order.
+    ///
+    /// Bodies that do not decode as `T` are skipped.
+    pub fn submitted<T: SubmittedPayload>(&self) -> Vec<SignedSubmission<T>> {
+        let endpoint = api_path(T::ENDPOINT);
+        self.lock()
+            .iter()
+            .filter(|request| request.method == "POST" && request.path() == endpoint)
+            .filter_map(|request| request.body.as_deref())
+            .filter_map(|body| serde_json::from_str::<Value>(body).ok())
+            .filter_map(|body| {
+                let signature = serde_json::from_value(body.get("signature")?.clone()).ok()?;
+                let payload = serde_json::from_value(body).ok()?;
+                Some(SignedSubmission { payload, signature })
+            })
+            .collect()
+    }
+
+    /// Panic unless exactly `times` payloads of type `T` were submitted.
+    #[track_caller]
+    pub fn assert_submitted<T: SubmittedPayload>(&self, times: usize) {
+        let actual = self.submitted::<T>().len();
+        assert_eq!(
+            actual,
+            times,
+            "expected {} submission(s) of {}, recorded {}",
+            times,
+            type_name::<T>(),
+            actual
+        );
+    }
+
+    /// Panic unless no transaction of any kind was submitted.
+    #[track_caller]
+    pub fn assert_nothing_submitted(&self) {
+        let posts: Vec<String> = self
+            .lock()
+            .iter()
+            .filter(|request| request.method == "POST")
+            .map(|request| request.url.clone())
+            .collect();
+        assert!(
+            posts.is_empty(),
+            "expected no submissions, recorded {posts:?}"
+        );
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<RecordedRequest>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Hook for NetworkRecorder {
+    fn before_request(&self, method: &str, url: &str, body: Option<&str>) {
+        self.lock().push(RecordedRequest {
+            method: method.to_string(),
+            url: url.to_string(),
+            body: body.map(str::to_string),
+            status: None,
+        });
+    }
+
+    fn after_response(&self, method: &str, url: &str, status: u16, _body: Option<&str>) {
+        if let Some(request) = self.lock().iter_mut().rev().find(|request| {
+            request.method == method && request.url == url && request.status.is_none()
+        }) {
+            request.status = Some(status);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::config::endpoints::tokens;
+    use crate::crypto::sign_transaction_payload;
+    use crate::requests::{MintTokenRequest, TokenBurnPayload, TokenMintPayload};
+    use alloy_primitives::{Address, U256};
+
+    const TEST_KEY: &str = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    const BASE: &str = "http://127.0.0.1:18555";
+
+    fn mint_payload(nonce: u64) -> TokenMintPayload {
+        TokenMintPayload {
+            chain_id: 1212101,
+            nonce,
+            recipient: Address::from([0x11; 20]),
+            value: U256::from(1000u64),
+            token: Address::from([0x22; 20]),
+        }
+    }
+
+    fn record_mint(recorder: &NetworkRecorder, payload: TokenMintPayload) -> Signature {
+        let signature =
+            sign_transaction_payload(&payload, TEST_KEY).expect("Signing should succeed");
+        let body = serde_json::to_string(&MintTokenRequest {
+            payload,
+            signature: signature.clone(),
+        })
+        .expect("Request should serialize");
+        let url = format!("{BASE}{}", api_path(tokens::MINT));
+        recorder.before_request("POST", &url, Some(&body));
+        recorder.after_response("POST", &url, 200, Some("{}"));
+        signature
+    }
+
+    #[test]
+    fn test_recorder_decodes_submissions() {
+        let recorder = NetworkRecorder::new();
+        let signature = record_mint(&recorder, mint_payload(1));
+        record_mint(&recorder, mint_payload(2));
+        recorder.before_request("GET", &format!("{BASE}/v1/chains/chain_id"), None);
+
+        recorder.assert_submitted::<TokenMintPayload>(2);
+        recorder.assert_submitted::<TokenBurnPayload>(0);
+
+        let submissions = recorder.submitted::<TokenMintPayload>();
+        assert_eq!(submissions[0].payload, mint_payload(1));
+        assert_eq!(submissions[0].signature, signature);
+        assert_eq!(submissions[1].payload.nonce, 2);
+
+        let requests = recorder.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].status, Some(200));
+        assert_eq!(requests[2].status, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 1 submission(s)")]
+    fn test_assert_submitted_reports_mismatch() {
+        let recorder = NetworkRecorder::new();
+        recorder.assert_submitted::<TokenMintPayload>(1);
+    }
+
+    #[test]
+    fn test_recorder_clear_and_nothing_submitted() {
+        let recorder = NetworkRecorder::new();
+        let clone = recorder.clone();
+        record_mint(&clone, mint_payload(1));
+        assert_eq!(recorder.requests().len(), 1);
+
+        recorder.clear();
+        recorder.assert_nothing_submitted();
+    }
+}
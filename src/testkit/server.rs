@@ -0,0 +1,891 @@
+//! In-process mock L1 for end-to-end tests.
+
+use crate::client::config::API_VERSION;
+use crate::client::config::endpoints::{accounts, chains, checkpoints, tokens, transactions};
+use crate::crypto::recover_signer;
+use crate::requests::{
+    BlacklistAction, NativePaymentPayload, PauseAction, PaymentPayload, RawTransaction,
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataUpdatePayload,
+    TokenMintPayload, TokenPausePayload, TokenWhitelistPayload, WhitelistAction,
+};
+use crate::responses::{
+    AccountNonce, AssociatedTokenAccount, ChainIdResponse, CheckpointNumber, FeeEstimate, MintInfo,
+    TokenMetadata, Transaction, TransactionReceipt, TransactionResponse, TxPayload,
+};
+use crate::testkit::SubmittedPayload;
+use crate::{AuthorityAction, Client, ClientBuilder, Error, Network, Result, Signature};
+use alloy_primitives::{Address, B256, U256, keccak256};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use url::Url;
+
+/// Chain ID a [`MockL1`] reports unless told otherwise.
+pub const MOCK_CHAIN_ID: u64 = 1_212_101;
+
+/// Largest request a [`MockL1`] reads, headers included.
+const MAX_REQUEST_BYTES: usize = 1024 * 1024;
+
+/// A mock L1 REST server running inside the test process.
+///
+/// The server answers the read endpoints the client uses and accepts every
+/// token and payment submission. Submissions are checked the way the L1
+/// checks them in the common case: the chain ID must match, the signature
+/// must recover, and the nonce must be the signer's next one. Accepted
+/// transactions update nonces, balances and token state, are sealed into a
+/// checkpoint of their own, and can be read back with their receipts.
+///
+/// Transaction hashes are the keccak hash of the
+/// [`RawTransaction`] blob, so the same signed payload always gets the same
+/// hash and resubmitting it is reported as a duplicate. Fees are always
+/// zero, authorities are not enforced, and native balances are not tracked.
+/// Bridge endpoints are not served.
+///
+/// The server stops when the `MockL1` is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::testkit::MockL1;
+/// use onemoney_protocol::{MintInfo, TokenMintPayload};
+/// use alloy_primitives::{Address, U256};
+///
+/// # async fn example() -> onemoney_protocol::Result<()> {
+/// let l1 = MockL1::start().await?;
+/// let token = Address::repeat_byte(0x22);
+/// l1.add_token(token, MintInfo { symbol: "USD1".to_string(), decimals: 6, ..MintInfo::default() });
+///
+/// let client = l1.client()?;
+/// let recipient = Address::repeat_byte(0x11);
+/// let payload = TokenMintPayload::new(1_212_101, 0, recipient, U256::from(500u64), token)?;
+/// let response = client.mint_token(payload, "0x...private_key...").await?;
+///
+/// assert_eq!(l1.balance(recipient, token), U256::from(500u64));
+/// let receipt = client.get_transaction_receipt_by_hash(&response.hash.to_string()).await?;
+/// assert!(receipt.success);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockL1 {
+    url: String,
+    ledger: Arc<Mutex<Ledger>>,
+    task: JoinHandle<()>,
+}
+
+impl MockL1 {
+    /// Start a server on a free local port for chain [`MOCK_CHAIN_ID`].
+    pub async fn start() -> Result<Self> {
+        Self::with_chain_id(MOCK_CHAIN_ID).await
+    }
+
+    /// Start a server on a free local port for `chain_id`.
+    pub async fn with_chain_id(chain_id: u64) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| Error::connection(format!("Failed to bind mock L1: {}", e)))?;
+        let address = listener
+            .local_addr()
+            .map_err(|e| Error::connection(format!("Failed to bind mock L1: {}", e)))?;
+        let ledger = Arc::new(Mutex::new(Ledger::new(chain_id)));
+        let task = tokio::spawn(serve(listener, Arc::clone(&ledger)));
+        Ok(Self {
+            url: format!("http://{}", address),
+            ledger,
+            task,
+        })
+    }
+
+    /// Base URL of the server, e.g. `http://127.0.0.1:41234`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A client pointed at this server.
+    pub fn client(&self) -> Result<Client> {
+        ClientBuilder::new()
+            .network(Network::custom(self.url.clone()))
+            .build()
+    }
+
+    /// Register a token, replacing any token at the same address.
+    pub fn add_token(&self, token: Address, info: MintInfo) {
+        self.lock().tokens.insert(token, info);
+    }
+
+    /// Set the balance `owner` holds of `token`, in base units.
+    pub fn set_balance(&self, owner: Address, token: Address, amount: U256) {
+        self.lock().balances.insert((owner, token), amount);
+    }
+
+    /// Advance the checkpoint number without adding transactions.
+    pub fn advance_checkpoints(&self, count: u64) {
+        let mut ledger = self.lock();
+        ledger.checkpoint = ledger.checkpoint.saturating_add(count);
+    }
+
+    /// Next nonce the server expects from `address`.
+    pub fn nonce(&self, address: Address) -> u64 {
+        self.lock().nonce(address)
+    }
+
+    /// Balance `owner` holds of `token`, in base units.
+    pub fn balance(&self, owner: Address, token: Address) -> U256 {
+        self.lock().balance(owner, token)
+    }
+
+    /// Current state of a registered token.
+    pub fn token(&self, token: Address) -> Option<MintInfo> {
+        self.lock().tokens.get(&token).cloned()
+    }
+
+    /// Number of the latest checkpoint.
+    pub fn checkpoint_number(&self) -> u64 {
+        self.lock().checkpoint
+    }
+
+    /// Every accepted transaction, oldest first.
+    pub fn transactions(&self) -> Vec<Transaction> {
+        self.lock()
+            .accepted
+            .iter()
+            .map(|(transaction, _)| transaction.clone())
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Ledger> {
+        lock(&self.ledger)
+    }
+}
+
+impl Drop for MockL1 {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+fn lock(ledger: &Mutex<Ledger>) -> MutexGuard<'_, Ledger> {
+    ledger
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Chain state behind a [`MockL1`].
+#[derive(Debug)]
+struct Ledger {
+    chain_id: u64,
+    checkpoint: u64,
+    nonces: HashMap<Address, u64>,
+    balances: HashMap<(Address, Address), U256>,
+    tokens: HashMap<Address, MintInfo>,
+    accepted: Vec<(Transaction, TransactionReceipt)>,
+}
+
+impl Ledger {
+    fn new(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            checkpoint: 0,
+            nonces: HashMap::new(),
+            balances: HashMap::new(),
+            tokens: HashMap::new(),
+            accepted: Vec::new(),
+        }
+    }
+
+    fn nonce(&self, address: Address) -> u64 {
+        self.nonces.get(&address).copied().unwrap_or_default()
+    }
+
+    fn balance(&self, owner: Address, token: Address) -> U256 {
+        self.balances
+            .get(&(owner, token))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn token_mut(&mut self, token: Address) -> Handled<&mut MintInfo> {
+        self.tokens.get_mut(&token).ok_or_else(|| {
+            Reply::error(404, "resource_token", format!("Token {} not found", token))
+        })
+    }
+
+    fn credit(&mut self, owner: Address, token: Address, amount: U256) -> Handled<()> {
+        let balance = self.balance(owner, token);
+        let updated = balance
+            .checked_add(amount)
+            .ok_or_else(|| Reply::error(422, "business_overflow", "Balance overflows"))?;
+        self.balances.insert((owner, token), updated);
+        Ok(())
+    }
+
+    fn debit(&mut self, owner: Address, token: Address, amount: U256) -> Handled<()> {
+        let balance = self.balance(owner, token);
+        let updated = balance.checked_sub(amount).ok_or_else(|| {
+            Reply::error(
+                422,
+                "business_insufficient_balance",
+                format!("{} holds {} of {}, needs {}", owner, balance, token, amount),
+            )
+        })?;
+        self.balances.insert((owner, token), updated);
+        Ok(())
+    }
+
+    fn adjust_supply(&mut self, token: Address, amount: U256, increase: bool) -> Handled<()> {
+        let info = self.token_mut(token)?;
+        let supply = U256::from_str_radix(&info.supply, 10).unwrap_or_default();
+        let updated = if increase {
+            supply.checked_add(amount)
+        } else {
+            supply.checked_sub(amount)
+        };
+        let updated =
+            updated.ok_or_else(|| Reply::error(422, "business_supply", "Supply out of range"))?;
+        info.supply = updated.to_string();
+        Ok(())
+    }
+
+    fn receipt(&self, hash: B256) -> Option<&TransactionReceipt> {
+        self.accepted
+            .iter()
+            .find(|(transaction, _)| transaction.hash == hash)
+            .map(|(_, receipt)| receipt)
+    }
+}
+
+/// Result of a handler; the error side is an L1 error reply.
+type Handled<T> = std::result::Result<T, Reply>;
+
+/// An HTTP response from the mock.
+#[derive(Debug)]
+struct Reply {
+    status: u16,
+    body: String,
+}
+
+impl Reply {
+    fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status: 200, body },
+            Err(e) => Self::error(500, "system_serialization", e.to_string()),
+        }
+    }
+
+    /// An error in the L1's `{"error_code", "message"}` shape.
+    fn error(status: u16, error_code: &str, message: impl Into<String>) -> Self {
+        let body = serde_json::json!({
+            "error_code": error_code,
+            "message": message.into(),
+        });
+        Self {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            409 => "Conflict",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+async fn serve(listener: TcpListener, ledger: Arc<Mutex<Ledger>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_connection(stream, Arc::clone(&ledger)));
+    }
+}
+
+/// Answer one request. Connections are closed after each response, which
+/// keeps the parser to the subset of HTTP/1.1 clients send unprompted.
+async fn handle_connection(mut stream: TcpStream, ledger: Arc<Mutex<Ledger>>) {
+    let reply = match read_request(&mut stream).await {
+        Ok(Some((method, target, body))) => route(&mut lock(&ledger), &method, &target, &body),
+        Ok(None) => return,
+        Err(reply) => reply,
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        reply.status,
+        reply.reason(),
+        reply.body.len(),
+        reply.body
+    );
+    // The client may already have given up; there is nobody to tell.
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Read the method, target and body of a request, or `None` if the peer
+/// closed the connection before sending one.
+async fn read_request(stream: &mut TcpStream) -> Handled<Option<(String, String, Vec<u8>)>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 8192];
+    let header_end = loop {
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return Ok(None),
+            Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+        }
+        if buffer.len() > MAX_REQUEST_BYTES {
+            return Err(Reply::error(413, "validation_request", "Request too large"));
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_string();
+    let target = request_line.next().unwrap_or_default().to_string();
+    let content_length = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+        .unwrap_or_default();
+    if header_end + content_length > MAX_REQUEST_BYTES {
+        return Err(Reply::error(413, "validation_request", "Request too large"));
+    }
+
+    while buffer.len() < header_end + content_length {
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return Ok(None),
+            Ok(read) => buffer.extend_from_slice(&chunk[..read]),
+        }
+    }
+    let body = buffer[header_end..header_end + content_length].to_vec();
+    Ok(Some((method, target, body)))
+}
+
+fn route(ledger: &mut Ledger, method: &str, target: &str, body: &[u8]) -> Reply {
+    let Ok(url) = Url::parse(&format!("http://mock{}", target)) else {
+        return Reply::error(
+            400,
+            "validation_path",
+            format!("Bad request target {}", target),
+        );
+    };
+    let Some(endpoint) = url.path().strip_prefix(API_VERSION) else {
+        return not_found(url.path());
+    };
+    let query = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    let reply = match (method, endpoint) {
+        ("GET", chains::CHAIN_ID) => Ok(Reply::json(&ChainIdResponse {
+            chain_id: ledger.chain_id,
+        })),
+        ("GET", checkpoints::NUMBER) => Ok(Reply::json(&CheckpointNumber {
+            number: ledger.checkpoint,
+        })),
+        ("GET", accounts::NONCE) => address_param(query("address"), "address").map(|address| {
+            Reply::json(&AccountNonce {
+                nonce: ledger.nonce(address),
+            })
+        }),
+        ("GET", accounts::TOKEN_ACCOUNT) => {
+            address_param(query("address"), "address").and_then(|owner| {
+                let token = address_param(query("token"), "token")?;
+                Ok(Reply::json(&AssociatedTokenAccount {
+                    balance: ledger.balance(owner, token).to_string(),
+                    nonce: ledger.nonce(owner),
+                }))
+            })
+        }
+        ("GET", tokens::TOKEN_METADATA) => address_param(query("token"), "token")
+            .and_then(|token| ledger.token_mut(token).map(|info| Reply::json(info))),
+        ("GET", transactions::ESTIMATE_FEE) => Ok(Reply::json(&FeeEstimate {
+            fee: "0".to_string(),
+        })),
+        ("GET", transactions::BY_HASH) => hash_param(query("hash")).and_then(|hash| {
+            ledger
+                .accepted
+                .iter()
+                .find(|(transaction, _)| transaction.hash == hash)
+                .map(|(transaction, _)| Reply::json(transaction))
+                .ok_or_else(|| unknown_transaction(hash))
+        }),
+        ("GET", transactions::RECEIPT_BY_HASH) => hash_param(query("hash")).and_then(|hash| {
+            ledger
+                .receipt(hash)
+                .map(Reply::json)
+                .ok_or_else(|| unknown_transaction(hash))
+        }),
+        ("POST", transactions::PAYMENT) => {
+            // Token payments carry a token field; native ones do not.
+            if serde_json::from_slice::<Value>(body).is_ok_and(|value| value.get("token").is_some())
+            {
+                submit(ledger, body, apply_payment)
+            } else {
+                submit(ledger, body, apply_native_payment)
+            }
+        }
+        ("POST", tokens::MINT) => submit(ledger, body, apply_mint),
+        ("POST", tokens::BURN) => submit(ledger, body, apply_burn),
+        ("POST", tokens::GRANT_AUTHORITY) => submit(ledger, body, apply_authority),
+        ("POST", tokens::PAUSE) => submit(ledger, body, apply_pause),
+        ("POST", tokens::MANAGE_BLACKLIST) => submit(ledger, body, apply_blacklist),
+        ("POST", tokens::MANAGE_WHITELIST) => submit(ledger, body, apply_whitelist),
+        ("POST", tokens::UPDATE_METADATA) => submit(ledger, body, apply_metadata),
+        _ => Err(not_found(url.path())),
+    };
+    reply.unwrap_or_else(|error| error)
+}
+
+fn not_found(path: &str) -> Reply {
+    Reply::error(404, "resource_endpoint", format!("No endpoint {}", path))
+}
+
+fn unknown_transaction(hash: B256) -> Reply {
+    Reply::error(
+        404,
+        "resource_transaction",
+        format!("Transaction {} not found", hash),
+    )
+}
+
+fn address_param(value: Option<String>, name: &str) -> Handled<Address> {
+    value
+        .and_then(|value| Address::from_str(&value).ok())
+        .ok_or_else(|| {
+            Reply::error(
+                400,
+                &format!("validation_{}", name),
+                format!("Missing or invalid {}", name),
+            )
+        })
+}
+
+fn hash_param(value: Option<String>) -> Handled<B256> {
+    value
+        .and_then(|value| B256::from_str(&value).ok())
+        .ok_or_else(|| Reply::error(400, "validation_hash", "Missing or invalid hash"))
+}
+
+/// What applying a payload produced: the transaction data and the receipt's
+/// recipient and token.
+struct Effect {
+    data: TxPayload,
+    recipient: Option<Address>,
+    token: Option<Address>,
+}
+
+/// Check a submission, apply it with `apply` and seal it into a checkpoint.
+fn submit<P: SubmittedPayload>(
+    ledger: &mut Ledger,
+    body: &[u8],
+    apply: fn(&mut Ledger, &P, Address) -> Handled<Effect>,
+) -> Handled<Reply> {
+    let value: Value = serde_json::from_slice(body)
+        .map_err(|e| Reply::error(400, "validation_body", e.to_string()))?;
+    let signature: Signature = value
+        .get("signature")
+        .cloned()
+        .and_then(|signature| serde_json::from_value(signature).ok())
+        .ok_or_else(|| Reply::error(400, "validation_signature", "Missing signature"))?;
+    let payload: P = serde_json::from_value(value)
+        .map_err(|e| Reply::error(400, "validation_body", e.to_string()))?;
+
+    if payload.chain_id() != ledger.chain_id {
+        return Err(Reply::error(
+            400,
+            "validation_chain_id",
+            format!(
+                "Payload is for chain {}, this is chain {}",
+                payload.chain_id(),
+                ledger.chain_id
+            ),
+        ));
+    }
+    let from = recover_signer(&payload.signature_hash(), &signature)
+        .map_err(|e| Reply::error(400, "validation_signature", e.to_string()))?;
+
+    let raw = RawTransaction::new(&payload, signature.clone());
+    let hash = keccak256(raw.to_rlp());
+    if ledger.receipt(hash).is_some() {
+        return Err(Reply::error(
+            409,
+            "business_duplicate_transaction",
+            format!("Transaction {} was already submitted", hash),
+        ));
+    }
+    let expected = ledger.nonce(from);
+    if payload.nonce() != expected {
+        return Err(Reply::error(
+            422,
+            "business_invalid_nonce",
+            format!(
+                "Nonce {} is not the next nonce {} of {}",
+                payload.nonce(),
+                expected,
+                from
+            ),
+        ));
+    }
+
+    let effect = apply(ledger, &payload, from)?;
+    ledger.nonces.insert(from, expected + 1);
+    ledger.checkpoint += 1;
+    let checkpoint_hash = keccak256(ledger.checkpoint.to_be_bytes());
+    let transaction = Transaction {
+        hash,
+        checkpoint_hash: Some(checkpoint_hash),
+        checkpoint_number: Some(ledger.checkpoint),
+        transaction_index: Some(0),
+        chain_id: payload.chain_id(),
+        from,
+        nonce: payload.nonce(),
+        data: effect.data,
+        signature,
+    };
+    let receipt = TransactionReceipt {
+        success: true,
+        transaction_hash: hash,
+        transaction_index: Some(0),
+        checkpoint_hash: Some(checkpoint_hash),
+        checkpoint_number: Some(ledger.checkpoint),
+        fee_used: 0,
+        from,
+        recipient: effect.recipient,
+        token_address: effect.token,
+        success_info: None,
+    };
+    ledger.accepted.push((transaction, receipt));
+    Ok(Reply::json(&TransactionResponse { hash }))
+}
+
+fn apply_payment(ledger: &mut Ledger, payload: &PaymentPayload, from: Address) -> Handled<Effect> {
+    if ledger.token_mut(payload.token)?.is_paused {
+        return Err(Reply::error(
+            422,
+            "business_token_paused",
+            format!("Token {} is paused", payload.token),
+        ));
+    }
+    ledger.debit(from, payload.token, payload.value)?;
+    ledger.credit(payload.recipient, payload.token, payload.value)?;
+    Ok(Effect {
+        data: TxPayload::TokenTransfer {
+            value: payload.value.to_string(),
+            recipient: payload.recipient,
+            token: Some(payload.token),
+        },
+        recipient: Some(payload.recipient),
+        token: Some(payload.token),
+    })
+}
+
+fn apply_native_payment(
+    _ledger: &mut Ledger,
+    payload: &NativePaymentPayload,
+    _from: Address,
+) -> Handled<Effect> {
+    Ok(Effect {
+        data: TxPayload::TokenTransfer {
+            value: payload.value.to_string(),
+            recipient: payload.recipient,
+            token: None,
+        },
+        recipient: Some(payload.recipient),
+        token: None,
+    })
+}
+
+fn apply_mint(ledger: &mut Ledger, payload: &TokenMintPayload, _from: Address) -> Handled<Effect> {
+    ledger.adjust_supply(payload.token, payload.value, true)?;
+    ledger.credit(payload.recipient, payload.token, payload.value)?;
+    Ok(Effect {
+        data: TxPayload::TokenMint {
+            value: payload.value.to_string(),
+            recipient: payload.recipient,
+            token: payload.token,
+        },
+        recipient: Some(payload.recipient),
+        token: Some(payload.token),
+    })
+}
+
+fn apply_burn(ledger: &mut Ledger, payload: &TokenBurnPayload, _from: Address) -> Handled<Effect> {
+    ledger.token_mut(payload.token)?;
+    ledger.debit(payload.recipient, payload.token, payload.value)?;
+    ledger.adjust_supply(payload.token, payload.value, false)?;
+    Ok(Effect {
+        data: TxPayload::TokenBurn {
+            value: payload.value.to_string(),
+            recipient: payload.recipient,
+            token: payload.token,
+        },
+        recipient: Some(payload.recipient),
+        token: Some(payload.token),
+    })
+}
+
+fn apply_authority(
+    ledger: &mut Ledger,
+    payload: &TokenAuthorityPayload,
+    _from: Address,
+) -> Handled<Effect> {
+    ledger.token_mut(payload.token)?;
+    let authority_type = payload.authority_type.as_str().to_string();
+    let value = Some(payload.value.to_string());
+    let data = match payload.action {
+        AuthorityAction::Grant => TxPayload::TokenGrantAuthority {
+            authority_type,
+            authority_address: payload.authority_address,
+            value,
+            token: payload.token,
+        },
+        AuthorityAction::Revoke => TxPayload::TokenRevokeAuthority {
+            authority_type,
+            authority_address: payload.authority_address,
+            value,
+            token: payload.token,
+        },
+    };
+    Ok(Effect {
+        data,
+        recipient: Some(payload.authority_address),
+        token: Some(payload.token),
+    })
+}
+
+fn apply_pause(
+    ledger: &mut Ledger,
+    payload: &TokenPausePayload,
+    _from: Address,
+) -> Handled<Effect> {
+    let token = payload.token;
+    let paused = matches!(payload.action, PauseAction::Pause);
+    ledger.token_mut(token)?.is_paused = paused;
+    Ok(Effect {
+        data: if paused {
+            TxPayload::TokenPause { token }
+        } else {
+            TxPayload::TokenUnpause { token }
+        },
+        recipient: None,
+        token: Some(token),
+    })
+}
+
+fn apply_blacklist(
+    ledger: &mut Ledger,
+    payload: &TokenBlacklistPayload,
+    _from: Address,
+) -> Handled<Effect> {
+    let (address, token) = (payload.address, payload.token);
+    let list = &mut ledger.token_mut(token)?.black_list;
+    let data = match payload.action {
+        BlacklistAction::Add => {
+            if !list.contains(&address) {
+                list.push(address);
+            }
+            TxPayload::TokenBlacklistAccount { address, token }
+        }
+        BlacklistAction::Remove => {
+            list.retain(|listed| *listed != address);
+            raw_data(payload, token)
+        }
+    };
+    Ok(Effect {
+        data,
+        recipient: Some(address),
+        token: Some(token),
+    })
+}
+
+fn apply_whitelist(
+    ledger: &mut Ledger,
+    payload: &TokenWhitelistPayload,
+    _from: Address,
+) -> Handled<Effect> {
+    let (address, token) = (payload.address, payload.token);
+    let list = &mut ledger.token_mut(token)?.white_list;
+    let data = match payload.action {
+        WhitelistAction::Add => {
+            if !list.contains(&address) {
+                list.push(address);
+            }
+            TxPayload::TokenWhitelistAccount { address, token }
+        }
+        WhitelistAction::Remove => {
+            list.retain(|listed| *listed != address);
+            raw_data(payload, token)
+        }
+    };
+    Ok(Effect {
+        data,
+        recipient: Some(address),
+        token: Some(token),
+    })
+}
+
+fn apply_metadata(
+    ledger: &mut Ledger,
+    payload: &TokenMetadataUpdatePayload,
+    _from: Address,
+) -> Handled<Effect> {
+    let metadata = TokenMetadata {
+        name: payload.name.clone(),
+        uri: payload.uri.clone(),
+        additional_metadata: payload.additional_metadata.clone(),
+    };
+    ledger.token_mut(payload.token)?.meta = Some(metadata.clone());
+    Ok(Effect {
+        data: TxPayload::TokenUpdateMetadata {
+            metadata,
+            token: payload.token,
+        },
+        recipient: None,
+        token: Some(payload.token),
+    })
+}
+
+/// List removals have no dedicated transaction type; they read back as raw
+/// payload bytes.
+fn raw_data<P: SubmittedPayload>(payload: &P, token: Address) -> TxPayload {
+    TxPayload::Raw {
+        input: alloy_rlp::encode(payload).into(),
+        token,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{private_key_to_address, sign_transaction_payload};
+    use crate::requests::MintTokenRequest;
+
+    const TEST_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn signer() -> Address {
+        private_key_to_address(TEST_KEY)
+            .expect("Key should be valid")
+            .parse()
+            .expect("Address should parse")
+    }
+
+    fn mint_body(nonce: u64, chain_id: u64) -> Vec<u8> {
+        let payload = TokenMintPayload {
+            chain_id,
+            nonce,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(10u64),
+            token: Address::repeat_byte(0x22),
+        };
+        let signature = sign_transaction_payload(&payload, TEST_KEY).expect("Signing should work");
+        serde_json::to_vec(&MintTokenRequest { payload, signature }).expect("Body should serialize")
+    }
+
+    fn ledger_with_token() -> Ledger {
+        let mut ledger = Ledger::new(MOCK_CHAIN_ID);
+        ledger
+            .tokens
+            .insert(Address::repeat_byte(0x22), MintInfo::default());
+        ledger
+    }
+
+    fn error_code(reply: &Reply) -> String {
+        let body: Value = serde_json::from_str(&reply.body).expect("Reply should be JSON");
+        body["error_code"].as_str().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn test_submit_tracks_nonces_and_supply() {
+        let mut ledger = ledger_with_token();
+        let first = route(
+            &mut ledger,
+            "POST",
+            "/v1/tokens/mint",
+            &mint_body(0, MOCK_CHAIN_ID),
+        );
+        assert_eq!(first.status, 200, "{}", first.body);
+        assert_eq!(ledger.nonce(signer()), 1);
+        assert_eq!(ledger.checkpoint, 1);
+        assert_eq!(
+            ledger.balance(Address::repeat_byte(0x11), Address::repeat_byte(0x22)),
+            U256::from(10u64)
+        );
+        assert_eq!(ledger.tokens[&Address::repeat_byte(0x22)].supply, "10");
+
+        let replay = route(
+            &mut ledger,
+            "POST",
+            "/v1/tokens/mint",
+            &mint_body(0, MOCK_CHAIN_ID),
+        );
+        assert_eq!(replay.status, 409);
+        let stale = route(
+            &mut ledger,
+            "POST",
+            "/v1/tokens/mint",
+            &mint_body(5, MOCK_CHAIN_ID),
+        );
+        assert_eq!(error_code(&stale), "business_invalid_nonce");
+        let wrong_chain = route(&mut ledger, "POST", "/v1/tokens/mint", &mint_body(1, 1));
+        assert_eq!(error_code(&wrong_chain), "validation_chain_id");
+        assert_eq!(ledger.accepted.len(), 1);
+    }
+
+    #[test]
+    fn test_transaction_hash_is_deterministic() {
+        let hash_of = |reply: Reply| {
+            serde_json::from_str::<TransactionResponse>(&reply.body)
+                .expect("Reply should be a hash")
+                .hash
+        };
+        let mut first = ledger_with_token();
+        let mut second = ledger_with_token();
+        let body = mint_body(0, MOCK_CHAIN_ID);
+        assert_eq!(
+            hash_of(route(&mut first, "POST", "/v1/tokens/mint", &body)),
+            hash_of(route(&mut second, "POST", "/v1/tokens/mint", &body))
+        );
+    }
+
+    #[test]
+    fn test_unknown_routes_and_parameters() {
+        let mut ledger = Ledger::new(MOCK_CHAIN_ID);
+        assert_eq!(
+            error_code(&route(&mut ledger, "GET", "/v1/nope", &[])),
+            "resource_endpoint"
+        );
+        assert_eq!(
+            error_code(&route(
+                &mut ledger,
+                "GET",
+                "/v1/accounts/nonce?address=zz",
+                &[]
+            )),
+            "validation_address"
+        );
+        assert_eq!(
+            error_code(&route(
+                &mut ledger,
+                "GET",
+                &format!(
+                    "/v1/tokens/token_metadata?token={}",
+                    Address::repeat_byte(1)
+                ),
+                &[]
+            )),
+            "resource_token"
+        );
+    }
+}
@@ -0,0 +1,483 @@
+//! Canonical payload encodings for cross-SDK parity checks.
+//!
+//! Enabled with the `testvectors` feature. Each [`TestVector`] pairs a fixed
+//! payload with the RLP bytes, signature hash and signature this crate
+//! produces for it under [`TEST_VECTOR_PRIVATE_KEY`]. The expected values are
+//! pinned in source rather than computed, so a change to the encoder shows up
+//! as a mismatch instead of silently updating the vectors. Other SDKs
+//! consume the JSON from [`vectors_json`] and must reproduce every vector
+//! byte for byte; [`TestVector::verify`] performs that comparison in Rust.
+
+use crate::crypto::{Signable, sign_hash};
+use crate::requests::authorities::{Authority, AuthorityAction};
+use crate::requests::{
+    BlacklistAction, PauseAction, TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload,
+    TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
+    TransactionPayload, WhitelistAction,
+};
+use crate::responses::MetadataKVPair;
+use crate::{CryptoError, Error, PaymentPayload, Result, Signature};
+use alloy_primitives::{Address, B256, U256};
+use alloy_rlp::Encodable;
+use hex::{decode as hex_decode, encode as hex_encode};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Private key every vector is signed with.
+///
+/// This is a widely published test key; never fund its address.
+pub const TEST_VECTOR_PRIVATE_KEY: &str =
+    "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+/// Chain ID used by every vector.
+pub const TEST_VECTOR_CHAIN_ID: u64 = 1_212_101;
+
+/// A payload with its expected encoding, hash and signature.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestVector {
+    /// Stable identifier of the vector.
+    pub name: &'static str,
+    /// Payload type, e.g. `PaymentPayload`.
+    pub payload_type: &'static str,
+    /// The payload as the API's JSON representation.
+    pub payload: Value,
+    /// Expected RLP encoding, `0x`-prefixed hex.
+    pub rlp: &'static str,
+    /// Expected keccak-256 of the RLP encoding.
+    pub signature_hash: &'static str,
+    /// Expected signature by [`TEST_VECTOR_PRIVATE_KEY`].
+    pub signature: Signature,
+}
+
+impl TestVector {
+    /// Compare values produced by another implementation with this vector.
+    pub fn verify(&self, rlp: &[u8], signature_hash: &B256, signature: &Signature) -> Result<()> {
+        let expected_rlp = decode_hex(self.rlp)?;
+        if expected_rlp != rlp {
+            return Err(self.mismatch("rlp", self.rlp, &format!("0x{}", hex_encode(rlp))));
+        }
+        let actual_hash = signature_hash.to_string();
+        if !self.signature_hash.eq_ignore_ascii_case(&actual_hash) {
+            return Err(self.mismatch("signature_hash", self.signature_hash, &actual_hash));
+        }
+        if &self.signature != signature {
+            return Err(self.mismatch(
+                "signature",
+                &format!("{:?}", self.signature),
+                &format!("{:?}", signature),
+            ));
+        }
+        Ok(())
+    }
+
+    fn mismatch(&self, field: &str, expected: &str, actual: &str) -> Error {
+        CryptoError::verification_failed(format!(
+            "Test vector '{}' {} mismatch: expected {}, got {}",
+            self.name, field, expected, actual
+        ))
+        .into()
+    }
+}
+
+struct Pinned {
+    name: &'static str,
+    rlp: &'static str,
+    signature_hash: &'static str,
+    r: &'static str,
+    s: &'static str,
+    v: u64,
+}
+
+const PINNED: &[Pinned] = &[
+    Pinned {
+        name: "payment",
+        rlp: "0xf383127ec580941111111111111111111111111111111111111111830f4240942222222222222222222222222222222222222222",
+        signature_hash: "0x8c8dd3606a1cc9d22a78bd7bc3bce53e6c116554d77d618c55f6f159f228b496",
+        r: "0x6314341c0d20767d6dd00116ac1d859a5265fbd551f1c40f63b702c5edc87efa",
+        s: "0x18a34355f521b6718020a1fd13e9394e89d3f78e7d31b8eb73414fa7cc1a9b41",
+        v: 0,
+    },
+    Pinned {
+        name: "payment_large_value",
+        rlp: "0xf85583127ec5850100000000941111111111111111111111111111111111111111a0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff942222222222222222222222222222222222222222",
+        signature_hash: "0x36e5c4a419325e16d63439adb23111280097e3ccb6462f2017ab30a39b042f5d",
+        r: "0xb33f31375e657379230d43e63effbc1cb4603e8938de3121b90e3cc2b0b30f18",
+        s: "0x62823111a14056a2a54323ed982ca342a811d8282938c6c02ac7e3a6e3db84dc",
+        v: 1,
+    },
+    Pinned {
+        name: "token_mint",
+        rlp: "0xf383127ec501941111111111111111111111111111111111111111834c4b40942222222222222222222222222222222222222222",
+        signature_hash: "0xb45d043e74b3b29e0b6b9fbb71e6f1d66f265f6648de1fcac1f7668b43739985",
+        r: "0xadc3300ddd5853299cfe3ffb32148b59cbbe5490058acc5b2804578ce3afcdab",
+        s: "0x324be2dbb3c3e93600e46cde78d7216095f1b5ec666b98b4b85a7358c732140a",
+        v: 0,
+    },
+    Pinned {
+        name: "token_burn",
+        rlp: "0xf183127ec50294111111111111111111111111111111111111111181fa942222222222222222222222222222222222222222",
+        signature_hash: "0x32e8d2de2350cb1b3a6f0dcb48a4ecadcf3dfb81c8b49cb1e9ec716a337d4208",
+        r: "0xe282005ba3a1a8d64d007d254b5246ab73356ef0d6525f7e7509c1410d3a4df7",
+        s: "0x10604b729c6c7e429dc92b89f90943ce7dba202a5b062dfb2285b192d756b5cf",
+        v: 1,
+    },
+    Pinned {
+        name: "token_grant_mint_burn",
+        rlp: "0xf84983127ec503854772616e748e4d696e744275726e546f6b656e73941111111111111111111111111111111111111111942222222222222222222222222222222222222222843b9aca00",
+        signature_hash: "0xf31f7d544266d35247ae37a0342578c69843f9fce0b79bf42700c1c89c7f9b4d",
+        r: "0x9120997c9c36dd4c949de3d30384eeb61289cf1fb33d2eaf017282ee99eb5ca",
+        s: "0x3ab252ffc38cad0fd0d59655766061c3d8a7175202ec4b497ffd2ad9783693d8",
+        v: 1,
+    },
+    Pinned {
+        name: "token_revoke_pause",
+        rlp: "0xf83d83127ec504865265766f6b6585506175736594111111111111111111111111111111111111111194222222222222222222222222222222222222222280",
+        signature_hash: "0x26ffd58d9921c22a0934698b3f16c85261c67e5473a05fd33d22329d3e4002a4",
+        r: "0x7d7f9e5c6400c588eb5ed03089b3aa32112c246ae94bcc6144fde4773bd3cbb3",
+        s: "0x151ab3566dad0454043d53114fa256c405736f08a61229680848125f0d016191",
+        v: 1,
+    },
+    Pinned {
+        name: "token_pause",
+        rlp: "0xe083127ec505855061757365942222222222222222222222222222222222222222",
+        signature_hash: "0x52dd0fbf976cbb3a52d2210915afa8161c04c4708bcbba147de4fc6d07980012",
+        r: "0x1d770b0c75cd9e8907f78bcdb488b1301897c2bda278c087b0cdba1285ecb6a5",
+        s: "0x7e0704cc98eca0e4b4456888758d359992eece69ba451d43b20ab93b32ea9fe4",
+        v: 0,
+    },
+    Pinned {
+        name: "token_blacklist_add",
+        rlp: "0xf383127ec50683416464941111111111111111111111111111111111111111942222222222222222222222222222222222222222",
+        signature_hash: "0xb40c2efad09dbb22bd5e2f6251bef1f66e12a9654b881b3f3c40178c8d679fdf",
+        r: "0x42f3d5e052c20b9fde3f946483c11b4cc34aa424dddbcb21e99490d8393c4440",
+        s: "0x3c35fdd13386fb26876716823042aab0c4d41db06f25b54e2f029a4732f72289",
+        v: 0,
+    },
+    Pinned {
+        name: "token_whitelist_remove",
+        rlp: "0xf683127ec5078652656d6f7665941111111111111111111111111111111111111111942222222222222222222222222222222222222222",
+        signature_hash: "0x2f331cda2894c65b7690298167089712696b56cfcdf6024fc66db23cb819cb34",
+        r: "0x6ddc49f8551f16645718c3e7a7a187bdd88de4f755b360ca5cbefd88916ab9dc",
+        s: "0x4b99f74541a04a742db116daf5da877ed9d5070a4d1aa9e8d11a78eb1e93d3e5",
+        v: 1,
+    },
+    Pinned {
+        name: "token_update_metadata",
+        rlp: "0xf85b83127ec5088b5465737420446f6c6c61729e68747470733a2f2f6578616d706c652e636f6d2f746f6b656e2e6a736f6e942222222222222222222222222222222222222222d5d4866973737565728c4578616d706c6520496e632e",
+        signature_hash: "0x43ef211b4faedd95e30aada128f67b3758eff4b2856dfb310a8995fd0627d101",
+        r: "0x944d5586c3e6f9c34a567e3bfd605d17f3d7b5072e0a1c210427091c6b597ea9",
+        s: "0x4b2093429c02f5f102fd823b124424219eff316a064b96dbca6f3020bf418075",
+        v: 1,
+    },
+];
+
+/// Every test vector.
+pub fn vectors() -> Result<Vec<TestVector>> {
+    canonical_payloads()
+        .into_iter()
+        .map(|(name, payload)| {
+            let pinned = PINNED
+                .iter()
+                .find(|pinned| pinned.name == name)
+                .ok_or_else(|| Error::custom(format!("Test vector '{}' is not pinned", name)))?;
+            Ok(TestVector {
+                name,
+                payload_type: payload.type_name(),
+                payload: payload.to_json()?,
+                rlp: pinned.rlp,
+                signature_hash: pinned.signature_hash,
+                signature: Signature {
+                    r: parse_scalar(pinned.r)?,
+                    s: parse_scalar(pinned.s)?,
+                    v: pinned.v,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Every test vector as pretty-printed JSON, for consumption by other SDKs.
+pub fn vectors_json() -> Result<String> {
+    Ok(serde_json::to_string_pretty(&vectors()?)?)
+}
+
+/// Re-encode and re-sign every canonical payload and check the result
+/// against the pinned vectors, then decode each pinned encoding back into
+/// its canonical payload.
+pub fn verify_all() -> Result<()> {
+    let vectors = vectors()?;
+    for ((_, payload), vector) in canonical_payloads().into_iter().zip(&vectors) {
+        let rlp = payload.rlp();
+        let hash = payload.signature_hash();
+        let signature = sign_hash(&hash, TEST_VECTOR_PRIVATE_KEY)?;
+        vector.verify(&rlp, &hash, &signature)?;
+        if !payload.decodes_from(&decode_hex(vector.rlp)?)? {
+            return Err(vector.mismatch("decoded payload", &vector.payload.to_string(), "other"));
+        }
+    }
+    Ok(())
+}
+
+enum CanonicalPayload {
+    Payment(PaymentPayload),
+    Mint(TokenMintPayload),
+    Burn(TokenBurnPayload),
+    Authority(TokenAuthorityPayload),
+    Pause(TokenPausePayload),
+    Blacklist(TokenBlacklistPayload),
+    Whitelist(TokenWhitelistPayload),
+    Metadata(TokenMetadataUpdatePayload),
+}
+
+impl CanonicalPayload {
+    fn type_name(&self) -> &'static str {
+        match self {
+            CanonicalPayload::Payment(_) => "PaymentPayload",
+            CanonicalPayload::Mint(_) => "TokenMintPayload",
+            CanonicalPayload::Burn(_) => "TokenBurnPayload",
+            CanonicalPayload::Authority(_) => "TokenAuthorityPayload",
+            CanonicalPayload::Pause(_) => "TokenPausePayload",
+            CanonicalPayload::Blacklist(_) => "TokenBlacklistPayload",
+            CanonicalPayload::Whitelist(_) => "TokenWhitelistPayload",
+            CanonicalPayload::Metadata(_) => "TokenMetadataUpdatePayload",
+        }
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        Ok(match self {
+            CanonicalPayload::Payment(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Mint(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Burn(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Authority(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Pause(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Blacklist(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Whitelist(payload) => serde_json::to_value(payload)?,
+            CanonicalPayload::Metadata(payload) => serde_json::to_value(payload)?,
+        })
+    }
+
+    fn rlp(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        match self {
+            CanonicalPayload::Payment(payload) => payload.encode(&mut out),
+            CanonicalPayload::Mint(payload) => payload.encode(&mut out),
+            CanonicalPayload::Burn(payload) => payload.encode(&mut out),
+            CanonicalPayload::Authority(payload) => payload.encode(&mut out),
+            CanonicalPayload::Pause(payload) => payload.encode(&mut out),
+            CanonicalPayload::Blacklist(payload) => payload.encode(&mut out),
+            CanonicalPayload::Whitelist(payload) => payload.encode(&mut out),
+            CanonicalPayload::Metadata(payload) => payload.encode(&mut out),
+        }
+        out
+    }
+
+    fn decodes_from(&self, rlp: &[u8]) -> Result<bool> {
+        Ok(match self {
+            CanonicalPayload::Payment(payload) => PaymentPayload::from_rlp(rlp)? == *payload,
+            CanonicalPayload::Mint(payload) => TokenMintPayload::from_rlp(rlp)? == *payload,
+            CanonicalPayload::Burn(payload) => TokenBurnPayload::from_rlp(rlp)? == *payload,
+            CanonicalPayload::Authority(payload) => {
+                TokenAuthorityPayload::from_rlp(rlp)? == *payload
+            }
+            CanonicalPayload::Pause(payload) => TokenPausePayload::from_rlp(rlp)? == *payload,
+            CanonicalPayload::Blacklist(payload) => {
+                TokenBlacklistPayload::from_rlp(rlp)? == *payload
+            }
+            CanonicalPayload::Whitelist(payload) => {
+                TokenWhitelistPayload::from_rlp(rlp)? == *payload
+            }
+            CanonicalPayload::Metadata(payload) => {
+                TokenMetadataUpdatePayload::from_rlp(rlp)? == *payload
+            }
+        })
+    }
+
+    fn signature_hash(&self) -> B256 {
+        match self {
+            CanonicalPayload::Payment(payload) => Signable::signature_hash(payload),
+            CanonicalPayload::Mint(payload) => payload.signature_hash(),
+            CanonicalPayload::Burn(payload) => payload.signature_hash(),
+            CanonicalPayload::Authority(payload) => payload.signature_hash(),
+            CanonicalPayload::Pause(payload) => payload.signature_hash(),
+            CanonicalPayload::Blacklist(payload) => payload.signature_hash(),
+            CanonicalPayload::Whitelist(payload) => payload.signature_hash(),
+            CanonicalPayload::Metadata(payload) => payload.signature_hash(),
+        }
+    }
+}
+
+fn canonical_payloads() -> Vec<(&'static str, CanonicalPayload)> {
+    let chain_id = TEST_VECTOR_CHAIN_ID;
+    let token = Address::repeat_byte(0x22);
+    let account = Address::repeat_byte(0x11);
+    vec![
+        (
+            "payment",
+            CanonicalPayload::Payment(PaymentPayload {
+                chain_id,
+                nonce: 0,
+                recipient: account,
+                value: U256::from(1_000_000u64),
+                token,
+            }),
+        ),
+        (
+            "payment_large_value",
+            CanonicalPayload::Payment(PaymentPayload {
+                chain_id,
+                nonce: 4_294_967_296,
+                recipient: account,
+                value: U256::MAX,
+                token,
+            }),
+        ),
+        (
+            "token_mint",
+            CanonicalPayload::Mint(TokenMintPayload {
+                chain_id,
+                nonce: 1,
+                recipient: account,
+                value: U256::from(5_000_000u64),
+                token,
+            }),
+        ),
+        (
+            "token_burn",
+            CanonicalPayload::Burn(TokenBurnPayload {
+                chain_id,
+                nonce: 2,
+                recipient: account,
+                value: U256::from(250u64),
+                token,
+            }),
+        ),
+        (
+            "token_grant_mint_burn",
+            CanonicalPayload::Authority(TokenAuthorityPayload {
+                chain_id,
+                nonce: 3,
+                action: AuthorityAction::Grant,
+                authority_type: Authority::MintBurnTokens,
+                authority_address: account,
+                token,
+                value: U256::from(1_000_000_000u64),
+            }),
+        ),
+        (
+            "token_revoke_pause",
+            CanonicalPayload::Authority(TokenAuthorityPayload {
+                chain_id,
+                nonce: 4,
+                action: AuthorityAction::Revoke,
+                authority_type: Authority::Pause,
+                authority_address: account,
+                token,
+                value: U256::ZERO,
+            }),
+        ),
+        (
+            "token_pause",
+            CanonicalPayload::Pause(TokenPausePayload {
+                chain_id,
+                nonce: 5,
+                action: PauseAction::Pause,
+                token,
+            }),
+        ),
+        (
+            "token_blacklist_add",
+            CanonicalPayload::Blacklist(TokenBlacklistPayload {
+                chain_id,
+                nonce: 6,
+                action: BlacklistAction::Add,
+                address: account,
+                token,
+            }),
+        ),
+        (
+            "token_whitelist_remove",
+            CanonicalPayload::Whitelist(TokenWhitelistPayload {
+                chain_id,
+                nonce: 7,
+                action: WhitelistAction::Remove,
+                address: account,
+                token,
+            }),
+        ),
+        (
+            "token_update_metadata",
+            CanonicalPayload::Metadata(TokenMetadataUpdatePayload {
+                chain_id,
+                nonce: 8,
+                name: "Test Dollar".to_string(),
+                uri: "https://example.com/token.json".to_string(),
+                token,
+                additional_metadata: vec![MetadataKVPair {
+                    key: "issuer".to_string(),
+                    value: "Example Inc.".to_string(),
+                }],
+            }),
+        ),
+    ]
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>> {
+    hex_decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| Error::custom(format!("Invalid pinned hex '{}': {}", value, e)))
+}
+
+fn parse_scalar(value: &str) -> Result<U256> {
+    value
+        .parse()
+        .map_err(|e| Error::custom(format!("Invalid pinned scalar '{}': {}", value, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vectors_match_pinned_values() {
+        verify_all().expect("Encoder and signer should reproduce every vector");
+        assert_eq!(
+            vectors().expect("Vectors should build").len(),
+            canonical_payloads().len()
+        );
+    }
+
+    #[test]
+    fn test_verify_reports_mismatched_field() {
+        let vector = vectors()
+            .expect("Vectors should build")
+            .into_iter()
+            .find(|vector| vector.name == "payment")
+            .expect("Payment vector should exist");
+        let rlp = decode_hex(vector.rlp).expect("Pinned RLP should decode");
+        let hash: B256 = vector
+            .signature_hash
+            .parse()
+            .expect("Pinned hash should parse");
+        assert!(vector.verify(&rlp, &hash, &vector.signature).is_ok());
+
+        let mut tampered = rlp.clone();
+        tampered[4] ^= 1;
+        let error = vector
+            .verify(&tampered, &hash, &vector.signature)
+            .expect_err("Tampered RLP should not verify");
+        assert!(error.to_string().contains("rlp mismatch"));
+
+        let mut signature = vector.signature.clone();
+        signature.v ^= 1;
+        assert!(vector.verify(&rlp, &hash, &signature).is_err());
+    }
+
+    #[test]
+    fn test_vectors_json_export() {
+        let json = vectors_json().expect("Vectors should serialize");
+        let parsed: Value = serde_json::from_str(&json).expect("Export should be valid JSON");
+        let first = &parsed[0];
+        assert_eq!(first["name"], "payment");
+        assert_eq!(first["payload_type"], "PaymentPayload");
+        assert_eq!(first["payload"]["value"], "1000000");
+    }
+}
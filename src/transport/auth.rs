@@ -0,0 +1,104 @@
+//! Authentication of requests to gateways that require credentials.
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use zeroize::Zeroizing;
+
+/// Header carrying the API key unless another one is chosen.
+pub const DEFAULT_API_KEY_HEADER: &str = "X-API-Key";
+
+/// Credentials attached to every request sent by a client.
+#[derive(Clone)]
+pub enum AuthScheme {
+    /// `Authorization: Bearer <token>`.
+    Bearer {
+        /// The bearer token.
+        token: Zeroizing<String>,
+    },
+    /// A static API key sent in a header.
+    ApiKey {
+        /// Header name.
+        header: Cow<'static, str>,
+        /// The API key.
+        key: Zeroizing<String>,
+    },
+}
+
+impl AuthScheme {
+    /// Authenticate with a bearer token.
+    pub fn bearer<T: Into<String>>(token: T) -> Self {
+        Self::Bearer {
+            token: Zeroizing::new(token.into()),
+        }
+    }
+
+    /// Authenticate with an API key in the [`DEFAULT_API_KEY_HEADER`] header.
+    pub fn api_key<T: Into<String>>(key: T) -> Self {
+        Self::api_key_header(DEFAULT_API_KEY_HEADER, key)
+    }
+
+    /// Authenticate with an API key in a custom header.
+    pub fn api_key_header<H, T>(header: H, key: T) -> Self
+    where
+        H: Into<Cow<'static, str>>,
+        T: Into<String>,
+    {
+        Self::ApiKey {
+            header: header.into(),
+            key: Zeroizing::new(key.into()),
+        }
+    }
+
+    /// Headers to attach to a request.
+    pub fn headers(&self) -> Vec<(String, String)> {
+        match self {
+            Self::Bearer { token } => vec![(
+                "Authorization".to_string(),
+                format!("Bearer {}", token.as_str()),
+            )],
+            Self::ApiKey { header, key } => vec![(header.to_string(), key.to_string())],
+        }
+    }
+}
+
+impl Debug for AuthScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Bearer { .. } => f.write_str("AuthScheme::Bearer(<redacted>)"),
+            Self::ApiKey { header, .. } => f
+                .debug_struct("AuthScheme::ApiKey")
+                .field("header", header)
+                .field("key", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_and_api_key_headers() {
+        let headers = AuthScheme::bearer("tok").headers();
+        assert_eq!(
+            headers,
+            vec![("Authorization".to_string(), "Bearer tok".to_string())]
+        );
+
+        let headers = AuthScheme::api_key("k-1").headers();
+        assert_eq!(headers, vec![("X-API-Key".to_string(), "k-1".to_string())]);
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let rendered = format!(
+            "{:?} {:?}",
+            AuthScheme::bearer("tok-secret"),
+            AuthScheme::api_key("key-secret")
+        );
+        assert!(!rendered.contains("tok-secret"));
+        assert!(!rendered.contains("key-secret"));
+        assert!(rendered.contains("X-API-Key"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Delay schedules between retries.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Schedule of delays between retry attempts.
+///
+/// Closures taking the retry number and returning a delay implement this
+/// trait, so one-off schedules need no dedicated type.
+pub trait BackoffStrategy: Send + Sync {
+    /// Delay before retry number `attempt`, starting at 1 for the first retry.
+    fn delay(&self, attempt: u32) -> Duration;
+}
+
+impl<F> BackoffStrategy for F
+where
+    F: Fn(u32) -> Duration + Send + Sync,
+{
+    fn delay(&self, attempt: u32) -> Duration {
+        self(attempt)
+    }
+}
+
+/// Delay multiplied by a constant factor after every retry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialBackoff {
+    /// Delay before the first retry.
+    pub initial: Duration,
+    /// Factor applied to the delay after every retry.
+    pub multiplier: f64,
+    /// Upper bound on any single delay.
+    pub max: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Start at `initial` and double after every retry, capped at one minute.
+    pub fn new(initial: Duration) -> Self {
+        Self {
+            initial,
+            multiplier: 2.0,
+            max: Duration::from_secs(60),
+        }
+    }
+
+    /// Set the growth factor.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the upper bound on a single delay.
+    pub fn max_delay(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100))
+    }
+}
+
+impl BackoffStrategy for ExponentialBackoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+        let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+        let seconds = self.initial.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or(self.max)
+            .min(self.max)
+    }
+}
+
+/// Delays following the Fibonacci sequence in multiples of a base unit.
+///
+/// Grows more gently than doubling: 1, 1, 2, 3, 5, 8, ... units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FibonacciBackoff {
+    /// Length of one unit.
+    pub unit: Duration,
+    /// Upper bound on any single delay.
+    pub max: Duration,
+}
+
+impl FibonacciBackoff {
+    /// Use `unit` as the first delay, capped at one minute.
+    pub fn new(unit: Duration) -> Self {
+        Self {
+            unit,
+            max: Duration::from_secs(60),
+        }
+    }
+
+    /// Set the upper bound on a single delay.
+    pub fn max_delay(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+}
+
+impl BackoffStrategy for FibonacciBackoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+        let (mut previous, mut current) = (0u32, 1u32);
+        for _ in 1..attempt {
+            let next = previous.saturating_add(current);
+            previous = current;
+            current = next;
+            if self.unit.saturating_mul(current) >= self.max {
+                break;
+            }
+        }
+        self.unit.saturating_mul(current).min(self.max)
+    }
+}
+
+/// The same delay before every retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantBackoff {
+    /// Delay before each retry.
+    pub delay: Duration,
+}
+
+impl ConstantBackoff {
+    /// Wait `delay` before every retry.
+    pub fn new(delay: Duration) -> Self {
+        Self { delay }
+    }
+}
+
+impl BackoffStrategy for ConstantBackoff {
+    fn delay(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            Duration::ZERO
+        } else {
+            self.delay
+        }
+    }
+}
+
+/// Another schedule with a random share of each delay taken off.
+///
+/// Clients that start retrying or polling at the same moment, such as
+/// services that submit in bursts, spread out instead of hitting the node
+/// in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jittered<B> {
+    /// Schedule the delays are taken from.
+    pub backoff: B,
+    /// Largest fraction, from 0 to 1, randomly taken off each delay.
+    pub fraction: f64,
+}
+
+impl<B: BackoffStrategy> Jittered<B> {
+    /// Take a random share of up to `fraction` off each delay of `backoff`,
+    /// with `fraction` clamped to between 0 and 1.
+    pub fn new(backoff: B, fraction: f64) -> Self {
+        Self {
+            backoff,
+            fraction: clamp_fraction(fraction),
+        }
+    }
+}
+
+impl<B: BackoffStrategy> BackoffStrategy for Jittered<B> {
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self.backoff.delay(attempt);
+        let fraction = clamp_fraction(self.fraction);
+        if fraction == 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - fraction * unit_random())
+    }
+}
+
+/// `fraction` limited to between 0 and 1, with NaN read as 0.
+pub(crate) fn clamp_fraction(fraction: f64) -> f64 {
+    if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// A number in `[0, 1)` that varies between calls.
+///
+/// Every `RandomState` is seeded differently, which is unpredictable enough
+/// to spread delays apart without a random number generator dependency.
+fn unit_random() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_backoff() {
+        let backoff = ExponentialBackoff::new(Duration::from_millis(100))
+            .multiplier(3.0)
+            .max_delay(Duration::from_secs(1));
+        assert_eq!(backoff.delay(0), Duration::ZERO);
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(300));
+        assert_eq!(backoff.delay(3), Duration::from_millis(900));
+        assert_eq!(backoff.delay(4), Duration::from_secs(1));
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_fibonacci_backoff() {
+        let backoff =
+            FibonacciBackoff::new(Duration::from_millis(10)).max_delay(Duration::from_millis(100));
+        let delays: Vec<u128> = (1..=7)
+            .map(|attempt| backoff.delay(attempt).as_millis())
+            .collect();
+        assert_eq!(delays, vec![10, 10, 20, 30, 50, 80, 100]);
+        assert_eq!(backoff.delay(u32::MAX), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_constant_and_closure_backoff() {
+        let constant = ConstantBackoff::new(Duration::from_millis(250));
+        assert_eq!(constant.delay(1), constant.delay(9));
+
+        let linear = |attempt: u32| Duration::from_millis(u64::from(attempt) * 5);
+        assert_eq!(
+            BackoffStrategy::delay(&linear, 4),
+            Duration::from_millis(20)
+        );
+    }
+
+    #[test]
+    fn test_jittered_backoff() {
+        let backoff = ExponentialBackoff::new(Duration::from_millis(100));
+        let jittered = Jittered::new(backoff, 3.0);
+        assert_eq!(jittered.fraction, 1.0);
+        for attempt in 1..=20 {
+            assert!(jittered.delay(attempt) <= backoff.delay(attempt));
+        }
+
+        let unclamped = Jittered {
+            backoff,
+            fraction: f64::NAN,
+        };
+        assert_eq!(unclamped.delay(2), backoff.delay(2));
+    }
+}
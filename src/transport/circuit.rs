@@ -0,0 +1,364 @@
+//! Circuit breaking and retry budgets, which keep retries from amplifying
+//! load on an upstream that is already failing.
+
+use super::retry::ErrorClass;
+use crate::{Error, Result};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// When a [`CircuitBreaker`] opens and how it recovers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive upstream failures that open the circuit.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before probing the upstream.
+    pub open_for: Duration,
+    /// Requests let through at once while half-open.
+    pub half_open_probes: u32,
+}
+
+impl CircuitBreakerConfig {
+    /// Open after 5 consecutive failures for 30 seconds, then probe with
+    /// one request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the consecutive failures that open the circuit.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// Set how long the circuit stays open.
+    pub fn open_for(mut self, duration: Duration) -> Self {
+        self.open_for = duration;
+        self
+    }
+
+    /// Set how many probes may be in flight while half-open.
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes.max(1);
+        self
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+/// State of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests are refused with [`Error::CircuitOpen`] until `until`.
+    Open {
+        /// When the circuit turns half-open.
+        until: Instant,
+    },
+    /// A limited number of probe requests decide whether the circuit closes
+    /// again or reopens.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct CircuitInner {
+    state: CircuitState,
+    failures: u32,
+    probes: u32,
+    opened_until: Instant,
+}
+
+/// Stops sending requests to an upstream after repeated failures.
+///
+/// Timeouts, connection failures, rate limiting and 5xx responses count as
+/// failures; any other outcome, including a 4xx rejection, shows the
+/// upstream is answering and resets the count. Once
+/// [`CircuitBreakerConfig::failure_threshold`] failures happen in a row the
+/// circuit opens and requests fail fast. After
+/// [`CircuitBreakerConfig::open_for`] it turns half-open and lets probes
+/// through: a success closes it, a failure opens it again.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<CircuitInner>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed circuit breaker.
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(CircuitInner {
+                state: CircuitState::Closed,
+                failures: 0,
+                probes: 0,
+                opened_until: Instant::now(),
+            }),
+        }
+    }
+
+    /// Current state, turning an open circuit half-open once its time is up.
+    pub fn state(&self) -> CircuitState {
+        let mut inner = self.lock();
+        Self::expire(&mut inner);
+        inner.state
+    }
+
+    /// Ask to send a request.
+    ///
+    /// Refuses with [`Error::CircuitOpen`] while the circuit is open, and
+    /// while half-open once every probe slot is taken; `until` is then the
+    /// end of the open period that just elapsed, as the wait is over and an
+    /// outstanding probe decides the outcome.
+    ///
+    /// The returned permit reports the request's outcome through
+    /// [`CircuitPermit::record`]. A permit dropped without recording, as
+    /// when the request future is cancelled, gives its probe slot back.
+    pub fn acquire(&self) -> Result<CircuitPermit<'_>> {
+        let mut inner = self.lock();
+        Self::expire(&mut inner);
+        match inner.state {
+            CircuitState::Closed => Ok(CircuitPermit {
+                breaker: self,
+                probe_of: None,
+            }),
+            CircuitState::Open { until } => Err(Error::circuit_open(until)),
+            CircuitState::HalfOpen if inner.probes < self.config.half_open_probes => {
+                inner.probes += 1;
+                Ok(CircuitPermit {
+                    breaker: self,
+                    probe_of: Some(inner.opened_until),
+                })
+            }
+            CircuitState::HalfOpen => Err(Error::circuit_open(inner.opened_until)),
+        }
+    }
+
+    fn record(&self, error: Option<&Error>) {
+        let failed = error.is_some_and(|error| ErrorClass::of(error).is_transient());
+        let mut inner = self.lock();
+        match inner.state {
+            // Requests sent before the circuit opened say nothing new.
+            CircuitState::Open { .. } => {}
+            CircuitState::Closed if !failed => inner.failures = 0,
+            CircuitState::Closed => {
+                inner.failures += 1;
+                if inner.failures >= self.config.failure_threshold {
+                    self.open(&mut inner);
+                }
+            }
+            CircuitState::HalfOpen if !failed => {
+                inner.state = CircuitState::Closed;
+                inner.failures = 0;
+                inner.probes = 0;
+            }
+            CircuitState::HalfOpen => self.open(&mut inner),
+        }
+    }
+
+    /// Free the probe slot taken while the circuit was half-open after the
+    /// open period ending at `opened_until`, unless the circuit has moved
+    /// on since.
+    fn release(&self, opened_until: Instant) {
+        let mut inner = self.lock();
+        if inner.state == CircuitState::HalfOpen && inner.opened_until == opened_until {
+            inner.probes = inner.probes.saturating_sub(1);
+        }
+    }
+
+    fn open(&self, inner: &mut CircuitInner) {
+        let until = Instant::now() + self.config.open_for;
+        inner.state = CircuitState::Open { until };
+        inner.opened_until = until;
+        inner.probes = 0;
+    }
+
+    fn expire(inner: &mut CircuitInner) {
+        if let CircuitState::Open { until } = inner.state
+            && Instant::now() >= until
+        {
+            inner.state = CircuitState::HalfOpen;
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CircuitInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Permission to send one request, handed out by
+/// [`CircuitBreaker::acquire`].
+#[derive(Debug)]
+#[must_use = "dropping the permit discards the request's outcome"]
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    /// End of the open period whose half-open probe slot this permit holds.
+    probe_of: Option<Instant>,
+}
+
+impl CircuitPermit<'_> {
+    /// Record the outcome of the request: the error it failed with, or
+    /// `None` if it succeeded.
+    pub fn record(mut self, error: Option<&Error>) {
+        self.probe_of = None;
+        self.breaker.record(error);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(opened_until) = self.probe_of.take() {
+            self.breaker.release(opened_until);
+        }
+    }
+}
+
+/// Most retries a client makes across all requests in a time window.
+///
+/// A [`RetryPolicy`](crate::RetryPolicy) bounds the retries of one request;
+/// the budget bounds their sum, so an outage affecting many concurrent
+/// requests does not multiply the load they put on the upstream. Once the
+/// budget is spent, failures are returned without retrying until the window
+/// ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryBudget {
+    /// Retries allowed per window.
+    pub max_retries: u32,
+    /// Length of a window.
+    pub window: Duration,
+}
+
+impl RetryBudget {
+    /// Allow `max_retries` retries per `window`.
+    pub fn new(max_retries: u32, window: Duration) -> Self {
+        Self {
+            max_retries,
+            window,
+        }
+    }
+}
+
+/// Retries spent from a [`RetryBudget`] in the current window.
+#[derive(Debug)]
+pub(crate) struct RetryBudgetTracker {
+    budget: RetryBudget,
+    spent: Mutex<(Instant, u32)>,
+}
+
+impl RetryBudgetTracker {
+    pub(crate) fn new(budget: RetryBudget) -> Self {
+        Self {
+            budget,
+            spent: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Take one retry from the budget, returning whether one was left.
+    pub(crate) fn try_spend(&self) -> bool {
+        let mut spent = self
+            .spent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (window_start, used) = &mut *spent;
+        if window_start.elapsed() >= self.budget.window {
+            *window_start = Instant::now();
+            *used = 0;
+        }
+        if *used >= self.budget.max_retries {
+            return false;
+        }
+        *used += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timeout() -> Error {
+        Error::request_timeout("/v1/chains/chain_id", 1000)
+    }
+
+    #[test]
+    fn test_circuit_opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .failure_threshold(2)
+                .open_for(Duration::from_secs(60)),
+        );
+
+        let fail = |error: &Error| {
+            breaker
+                .acquire()
+                .expect("Closed circuit should let requests through")
+                .record(Some(error))
+        };
+        fail(&timeout());
+        // A rejected request proves the upstream answers and resets the count.
+        fail(&Error::invalid_parameter("address", "bad"));
+        fail(&timeout());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        fail(&timeout());
+        assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+        let refused = breaker.acquire().expect_err("Open circuit should refuse");
+        assert!(refused.is_circuit_open());
+        assert!(refused.to_string().starts_with("Circuit breaker open"));
+    }
+
+    #[test]
+    fn test_half_open_probe_closes_or_reopens() {
+        let breaker = CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .failure_threshold(1)
+                .open_for(Duration::ZERO),
+        );
+
+        breaker
+            .acquire()
+            .expect("Closed circuit should let requests through")
+            .record(Some(&timeout()));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let probe = breaker
+            .acquire()
+            .expect("First probe should be let through");
+        assert!(breaker.acquire().is_err());
+        probe.record(Some(&timeout()));
+        assert!(matches!(breaker.lock().state, CircuitState::Open { .. }));
+
+        // A cancelled probe frees its slot.
+        let cancelled = breaker
+            .acquire()
+            .expect("Probe should be let through again");
+        drop(cancelled);
+        let probe = breaker
+            .acquire()
+            .expect("Slot of the cancelled probe is free");
+        probe.record(None);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.acquire().is_ok());
+    }
+
+    #[test]
+    fn test_retry_budget_refills_per_window() {
+        let tracker = RetryBudgetTracker::new(RetryBudget::new(2, Duration::from_secs(60)));
+        assert!(tracker.try_spend());
+        assert!(tracker.try_spend());
+        assert!(!tracker.try_spend());
+
+        let tracker = RetryBudgetTracker::new(RetryBudget::new(1, Duration::ZERO));
+        assert!(tracker.try_spend());
+        assert!(tracker.try_spend());
+    }
+}
@@ -0,0 +1,231 @@
+//! Latency- and error-aware selection between equivalent base URLs.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use url::Url;
+
+/// Default interval after which an endpoint that is not being used is probed again.
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Weight of the newest sample in the latency and error moving averages.
+const SMOOTHING: f64 = 0.2;
+
+/// Factor by which a fully failing endpoint's latency score is inflated.
+const ERROR_PENALTY: f64 = 10.0;
+
+/// Observed quality of one endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStats {
+    /// Base URL of the endpoint.
+    pub url: Url,
+    /// Smoothed response latency, `None` until the endpoint has been used.
+    pub latency: Option<Duration>,
+    /// Smoothed fraction of failed requests, between 0 and 1.
+    pub error_rate: f64,
+    /// Number of requests recorded against the endpoint.
+    pub samples: u64,
+}
+
+impl EndpointStats {
+    /// Lower is better; unmeasured endpoints score zero so they are tried first.
+    fn score(&self) -> f64 {
+        let latency = self.latency.map_or(0.0, |latency| latency.as_secs_f64());
+        latency * (1.0 + ERROR_PENALTY * self.error_rate)
+    }
+}
+
+#[derive(Debug)]
+struct EndpointState {
+    stats: EndpointStats,
+    last_used: Option<Instant>,
+}
+
+/// Routes requests to the base URL with the best recent latency and error score.
+///
+/// Endpoints that are not selected would never refresh their score, so any
+/// endpoint idle for longer than the probe interval is handed out for one
+/// request before the best endpoint is used again.
+#[derive(Debug)]
+pub struct EndpointSelector {
+    probe_interval: Duration,
+    endpoints: Mutex<Vec<EndpointState>>,
+}
+
+impl EndpointSelector {
+    /// Create a selector over `urls`; the first URL wins ties.
+    pub fn new(urls: Vec<Url>, probe_interval: Duration) -> Self {
+        let endpoints = urls
+            .into_iter()
+            .map(|url| EndpointState {
+                stats: EndpointStats {
+                    url,
+                    latency: None,
+                    error_rate: 0.0,
+                    samples: 0,
+                },
+                last_used: None,
+            })
+            .collect();
+        Self {
+            probe_interval,
+            endpoints: Mutex::new(endpoints),
+        }
+    }
+
+    /// Number of endpoints.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the selector holds no endpoints.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Pick the endpoint for the next request, returning its index and base URL.
+    pub fn select(&self) -> Option<(usize, Url)> {
+        let mut endpoints = self.lock();
+        let now = Instant::now();
+
+        let best = endpoints
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.stats.score().total_cmp(&b.stats.score()))
+            .map(|(index, _)| index)?;
+
+        let stale = endpoints
+            .iter()
+            .enumerate()
+            .filter(|(index, endpoint)| {
+                *index != best
+                    && endpoint
+                        .last_used
+                        .is_some_and(|used| now.duration_since(used) >= self.probe_interval)
+            })
+            .min_by_key(|(_, endpoint)| endpoint.last_used)
+            .map(|(index, _)| index);
+        let index = stale.unwrap_or(best);
+
+        let endpoint = &mut endpoints[index];
+        endpoint.last_used = Some(now);
+        Some((index, endpoint.stats.url.clone()))
+    }
+
+    /// Record the outcome of a request sent to the endpoint at `index`.
+    pub fn record(&self, index: usize, latency: Duration, success: bool) {
+        let mut endpoints = self.lock();
+        let Some(endpoint) = endpoints.get_mut(index) else {
+            return;
+        };
+        let stats = &mut endpoint.stats;
+        let failure = if success { 0.0 } else { 1.0 };
+        if stats.samples == 0 {
+            stats.latency = Some(latency);
+            stats.error_rate = failure;
+        } else {
+            let previous = stats.latency.unwrap_or(latency).as_secs_f64();
+            stats.latency = Some(Duration::from_secs_f64(
+                previous + SMOOTHING * (latency.as_secs_f64() - previous),
+            ));
+            stats.error_rate += SMOOTHING * (failure - stats.error_rate);
+        }
+        stats.samples += 1;
+    }
+
+    /// Current statistics of every endpoint, in configuration order.
+    pub fn stats(&self) -> Vec<EndpointStats> {
+        self.lock()
+            .iter()
+            .map(|endpoint| endpoint.stats.clone())
+            .collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<EndpointState>> {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(probe_interval: Duration) -> EndpointSelector {
+        let urls = ["https://a.example.com", "https://b.example.com"]
+            .iter()
+            .map(|url| Url::parse(url).expect("Valid URL"))
+            .collect();
+        EndpointSelector::new(urls, probe_interval)
+    }
+
+    fn selector_with_samples(probe_interval: Duration) -> EndpointSelector {
+        let selector = selector(probe_interval);
+        for latency in [10, 500] {
+            let (index, _) = selector.select().expect("Endpoints configured");
+            selector.record(index, Duration::from_millis(latency), true);
+        }
+        selector
+    }
+
+    #[test]
+    fn test_prefers_lowest_latency() {
+        let selector = selector(Duration::from_secs(3600));
+        selector.record(0, Duration::from_millis(200), true);
+        selector.record(1, Duration::from_millis(50), true);
+
+        let (index, url) = selector.select().expect("Endpoints configured");
+        assert_eq!(index, 1);
+        assert_eq!(url.as_str(), "https://b.example.com/");
+    }
+
+    #[test]
+    fn test_errors_outweigh_latency() {
+        let selector = selector(Duration::from_secs(3600));
+        selector.record(0, Duration::from_millis(100), true);
+        selector.record(1, Duration::from_millis(40), false);
+
+        assert_eq!(selector.select().map(|(index, _)| index), Some(0));
+
+        let stats = selector.stats();
+        assert_eq!(stats[1].error_rate, 1.0);
+        assert_eq!(stats[1].samples, 1);
+    }
+
+    #[test]
+    fn test_unmeasured_endpoints_tried_first() {
+        let selector = selector(Duration::from_secs(3600));
+        assert_eq!(selector.select().map(|(index, _)| index), Some(0));
+        selector.record(0, Duration::from_millis(10), true);
+        assert_eq!(selector.select().map(|(index, _)| index), Some(1));
+    }
+
+    #[test]
+    fn test_idle_endpoint_is_probed() {
+        let selector = selector_with_samples(Duration::from_millis(20));
+        std::thread::sleep(Duration::from_millis(30));
+
+        // Endpoint 1 is slower but has been idle for the whole probe interval.
+        assert_eq!(selector.select().map(|(index, _)| index), Some(1));
+
+        let settled = selector_with_samples(Duration::from_secs(3600));
+        assert_eq!(settled.select().map(|(index, _)| index), Some(0));
+        assert_eq!(settled.select().map(|(index, _)| index), Some(0));
+        assert!(
+            EndpointSelector::new(Vec::new(), Duration::ZERO)
+                .select()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let selector = selector(Duration::from_secs(3600));
+        selector.record(0, Duration::from_millis(100), true);
+        selector.record(0, Duration::from_millis(200), false);
+
+        let stats = &selector.stats()[0];
+        assert_eq!(stats.latency, Some(Duration::from_millis(120)));
+        assert!((stats.error_rate - 0.2).abs() < 1e-9);
+    }
+}
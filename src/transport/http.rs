@@ -0,0 +1,68 @@
+//! Pluggable execution of HTTP requests.
+//!
+//! The client prepares every request itself, with URL, headers, body and
+//! timeout, and hands it to an [`HttpTransport`] to send. By default that is
+//! the `reqwest` client built from the
+//! [`ClientBuilder`](crate::ClientBuilder) settings. Embedders that need
+//! another resolver, an instrumented connection pool or canned responses in
+//! tests set their own with
+//! [`ClientBuilder::transport`](crate::ClientBuilder::transport).
+
+use crate::Result;
+use reqwest::{Client as HttpClient, Request, Response};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Future returned by [`HttpTransport::execute`].
+pub type HttpTransportFuture<'a> = Pin<Box<dyn Future<Output = Result<Response>> + Send + 'a>>;
+
+/// Sends the requests of a [`Client`](crate::Client).
+///
+/// Retries, rate limiting, endpoint selection and error classification
+/// happen around the transport, so an implementation only sends one request
+/// and returns whatever response arrives, successful or not. A response
+/// built from an `http::Response` with `reqwest::Response::from` is
+/// accepted like one from the network.
+///
+/// # Example
+///
+/// ```rust
+/// use onemoney_protocol::{ClientBuilder, HttpTransport, HttpTransportFuture, Network};
+/// use reqwest::{Request, Response};
+///
+/// /// Forwards requests to a pool shared with the rest of the application.
+/// struct SharedPool(reqwest::Client);
+///
+/// impl HttpTransport for SharedPool {
+///     fn execute(&self, request: Request) -> HttpTransportFuture<'_> {
+///         Box::pin(async move {
+///             let response: Response = self.0.execute(request).await?;
+///             Ok(response)
+///         })
+///     }
+/// }
+///
+/// let client = ClientBuilder::new()
+///     .network(Network::Testnet)
+///     .transport(SharedPool(reqwest::Client::new()))
+///     .build()?;
+/// # Ok::<(), onemoney_protocol::Error>(())
+/// ```
+pub trait HttpTransport: Send + Sync {
+    /// Send `request` and return the response.
+    fn execute(&self, request: Request) -> HttpTransportFuture<'_>;
+}
+
+impl HttpTransport for HttpClient {
+    fn execute(&self, request: Request) -> HttpTransportFuture<'_> {
+        let pending = HttpClient::execute(self, request);
+        Box::pin(async move { Ok(pending.await?) })
+    }
+}
+
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    fn execute(&self, request: Request) -> HttpTransportFuture<'_> {
+        (**self).execute(request)
+    }
+}
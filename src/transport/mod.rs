@@ -1,9 +1,34 @@
 //! HTTP transport layer for API communication.
+//!
+//! The SDK speaks the OneMoney REST/JSON API only. A gRPC backend is not
+//! provided: the gateway's protobuf service definitions are not published
+//! alongside this crate, so there is no contract a `grpc` feature could be
+//! generated or tested against.
 
+pub mod auth;
+pub mod backoff;
+pub mod circuit;
+pub mod endpoints;
+pub mod http;
+pub mod rate_limit;
 pub mod retry;
+pub mod timeout;
+
+#[cfg(feature = "gzip")]
+pub mod compression;
 
 // Re-export public interfaces
+pub use auth::*;
+pub use backoff::*;
+pub use circuit::*;
+pub use endpoints::*;
+pub use http::*;
+pub use rate_limit::*;
 pub use retry::*;
+pub use timeout::*;
+
+#[cfg(feature = "gzip")]
+pub use compression::*;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,306 @@
+//! Client-side token-bucket rate limiting.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::time::{Instant, sleep};
+
+/// Longest single wait for a token, so very slow refill rates recheck the
+/// bucket instead of sleeping for an unrepresentable duration.
+const MAX_TOKEN_WAIT: Duration = Duration::from_secs(60);
+
+/// Class of endpoint a request belongs to, each with its own bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointClass {
+    /// Queries issued with GET.
+    Read,
+    /// Submissions issued with POST.
+    Write,
+}
+
+/// Token-bucket parameters for one endpoint class.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Maximum number of requests that can be issued in a burst.
+    pub burst: u32,
+    /// Number of requests replenished per second.
+    pub per_second: f64,
+}
+
+impl RateLimit {
+    /// Allow `requests` per second with an equal burst size.
+    pub fn per_second(requests: u32) -> Self {
+        Self {
+            burst: requests.max(1),
+            per_second: f64::from(requests),
+        }
+    }
+
+    /// Set the burst size.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Rate limits for reads and writes; a class without a limit is unthrottled.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RateLimitConfig {
+    /// Limit applied to read requests.
+    pub reads: Option<RateLimit>,
+    /// Limit applied to write requests.
+    pub writes: Option<RateLimit>,
+}
+
+impl RateLimitConfig {
+    /// Create a configuration without any limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit read requests.
+    pub fn reads(mut self, limit: RateLimit) -> Self {
+        self.reads = Some(limit);
+        self
+    }
+
+    /// Limit write requests.
+    pub fn writes(mut self, limit: RateLimit) -> Self {
+        self.writes = Some(limit);
+        self
+    }
+}
+
+/// Point-in-time view of a bucket, for observability.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BucketState {
+    /// Requests that can be issued immediately.
+    pub available: f64,
+    /// Bucket capacity.
+    pub burst: u32,
+    /// Refill rate in requests per second.
+    pub per_second: f64,
+    /// Remaining server-imposed pause after a 429 response.
+    pub paused_for: Duration,
+}
+
+#[derive(Debug)]
+struct BucketInner {
+    tokens: f64,
+    last_refill: Instant,
+    paused_until: Instant,
+}
+
+/// A single token bucket.
+#[derive(Debug)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    inner: Mutex<BucketInner>,
+}
+
+impl TokenBucket {
+    /// Create a full bucket.
+    pub fn new(limit: RateLimit) -> Self {
+        let now = Instant::now();
+        Self {
+            limit,
+            inner: Mutex::new(BucketInner {
+                tokens: f64::from(limit.burst),
+                last_refill: now,
+                paused_until: now,
+            }),
+        }
+    }
+
+    /// Wait until a request may be issued and consume one token.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut inner = self.lock();
+                let now = Instant::now();
+                self.refill(&mut inner, now);
+
+                if inner.paused_until > now {
+                    inner.paused_until - now
+                } else if inner.tokens >= 1.0 {
+                    inner.tokens -= 1.0;
+                    return;
+                } else if self.limit.per_second > 0.0 {
+                    Duration::try_from_secs_f64((1.0 - inner.tokens) / self.limit.per_second)
+                        .unwrap_or(MAX_TOKEN_WAIT)
+                        .min(MAX_TOKEN_WAIT)
+                } else {
+                    // A zero refill rate never replenishes; poll slowly instead of spinning.
+                    Duration::from_secs(1)
+                }
+            };
+            sleep(wait).await;
+        }
+    }
+
+    /// Consume one token if one is available right now.
+    pub fn try_acquire(&self) -> bool {
+        let mut inner = self.lock();
+        let now = Instant::now();
+        self.refill(&mut inner, now);
+        if inner.paused_until <= now && inner.tokens >= 1.0 {
+            inner.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Empty the bucket and stop issuing requests for `pause`.
+    ///
+    /// Called when the server answers 429 so the client backs off instead of
+    /// spending the remaining burst on requests that will be rejected.
+    pub fn pause(&self, pause: Duration) {
+        let mut inner = self.lock();
+        let now = Instant::now();
+        inner.tokens = 0.0;
+        inner.last_refill = now + pause;
+        inner.paused_until = inner.paused_until.max(now + pause);
+    }
+
+    /// Current bucket state.
+    pub fn state(&self) -> BucketState {
+        let mut inner = self.lock();
+        let now = Instant::now();
+        self.refill(&mut inner, now);
+        BucketState {
+            available: inner.tokens,
+            burst: self.limit.burst,
+            per_second: self.limit.per_second,
+            paused_for: inner.paused_until.saturating_duration_since(now),
+        }
+    }
+
+    fn refill(&self, inner: &mut BucketInner, now: Instant) {
+        if now <= inner.last_refill {
+            return;
+        }
+        let elapsed = now.duration_since(inner.last_refill).as_secs_f64();
+        inner.tokens =
+            (inner.tokens + elapsed * self.limit.per_second).min(f64::from(self.limit.burst));
+        inner.last_refill = now;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BucketInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Rate limiter holding one bucket per endpoint class.
+#[derive(Debug)]
+pub struct RateLimiter {
+    reads: Option<TokenBucket>,
+    writes: Option<TokenBucket>,
+}
+
+impl RateLimiter {
+    /// Create a limiter from a configuration.
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            reads: config.reads.map(TokenBucket::new),
+            writes: config.writes.map(TokenBucket::new),
+        }
+    }
+
+    /// Wait for permission to issue a request of the given class.
+    pub async fn acquire(&self, class: EndpointClass) {
+        if let Some(bucket) = self.bucket(class) {
+            bucket.acquire().await;
+        }
+    }
+
+    /// Pause a class after the server rejected a request with 429.
+    pub fn pause(&self, class: EndpointClass, pause: Duration) {
+        if let Some(bucket) = self.bucket(class) {
+            bucket.pause(pause);
+        }
+    }
+
+    /// State of the bucket for a class, or `None` if the class is unthrottled.
+    pub fn state(&self, class: EndpointClass) -> Option<BucketState> {
+        self.bucket(class).map(TokenBucket::state)
+    }
+
+    fn bucket(&self, class: EndpointClass) -> Option<&TokenBucket> {
+        match class {
+            EndpointClass::Read => self.reads.as_ref(),
+            EndpointClass::Write => self.writes.as_ref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::timeout;
+
+    #[test]
+    fn test_rate_limit_constructors() {
+        let limit = RateLimit::per_second(10).burst(3);
+        assert_eq!(limit.burst, 3);
+        assert_eq!(limit.per_second, 10.0);
+        assert_eq!(RateLimit::per_second(0).burst, 1);
+
+        let config = RateLimitConfig::new().writes(limit);
+        assert!(config.reads.is_none());
+        assert_eq!(config.writes, Some(limit));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_burst_then_refill() {
+        let bucket = TokenBucket::new(RateLimit::per_second(2).burst(2));
+        assert!(bucket.try_acquire());
+        assert!(bucket.try_acquire());
+        assert!(!bucket.try_acquire());
+
+        let start = Instant::now();
+        bucket.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert!(bucket.state().available < 1.0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_pause() {
+        let bucket = TokenBucket::new(RateLimit::per_second(100));
+        bucket.pause(Duration::from_secs(2));
+        assert!(!bucket.try_acquire());
+        assert_eq!(bucket.state().paused_for, Duration::from_secs(2));
+
+        let start = Instant::now();
+        bucket.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_tiny_rate_waits_capped() {
+        let bucket = TokenBucket::new(RateLimit {
+            burst: 1,
+            per_second: 1e-300,
+        });
+        assert!(bucket.try_acquire());
+
+        let waited = timeout(Duration::from_secs(150), bucket.acquire()).await;
+        assert!(waited.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_unthrottled_class() {
+        let limiter = RateLimiter::new(RateLimitConfig::new().reads(RateLimit::per_second(1)));
+        assert!(limiter.state(EndpointClass::Write).is_none());
+        let state = limiter
+            .state(EndpointClass::Read)
+            .expect("Read bucket should exist");
+        assert_eq!(state.burst, 1);
+
+        // Writes are never throttled without a configured limit.
+        for _ in 0..10 {
+            limiter.acquire(EndpointClass::Write).await;
+        }
+    }
+}
@@ -1,5 +1,9 @@
 //! Retry logic and error handling utilities.
 
+use super::backoff::{BackoffStrategy, ExponentialBackoff};
+use crate::Error;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Retry configuration for HTTP requests.
@@ -74,12 +78,262 @@ impl RetryConfig {
         }
     }
 
+    /// Calculate the delay before retrying after `error`.
+    ///
+    /// Honors the server's `Retry-After` hint on rate limit errors when it is
+    /// longer than the backoff delay.
+    pub fn delay_for_error(&self, attempt: u32, error: &Error) -> Duration {
+        let backoff = self.delay_for_attempt(attempt);
+        match error {
+            Error::RateLimitExceeded {
+                retry_after_seconds: Some(seconds),
+            } => backoff.max(Duration::from_secs(*seconds)),
+            _ => backoff,
+        }
+    }
+
     /// Check if the given attempt number should be retried.
     pub fn should_retry(&self, attempt: u32) -> bool {
         attempt < self.max_attempts
     }
 }
 
+impl BackoffStrategy for RetryConfig {
+    fn delay(&self, attempt: u32) -> Duration {
+        self.delay_for_attempt(attempt)
+    }
+}
+
+/// Broad category of an error, used to decide whether to retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The request ran past the client's timeout or a wait deadline; a
+    /// submission may still have executed.
+    Timeout,
+    /// The server answered 408 without processing the request.
+    ServerTimeout,
+    /// The connection failed or the server could not be resolved or reached.
+    Connection,
+    /// The server asked the client to slow down.
+    RateLimited,
+    /// The server failed with a 5xx status.
+    Server,
+    /// The server rejected the request itself, such as with a 4xx status.
+    Request,
+    /// The request was valid but violated a business rule.
+    BusinessLogic,
+    /// A local failure such as encoding, signing or configuration.
+    Local,
+}
+
+impl ErrorClass {
+    /// Classify an error.
+    pub fn of(error: &Error) -> Self {
+        match error.root() {
+            Error::RequestTimeout { .. } | Error::ClientTimeout { .. } => Self::Timeout,
+            Error::ServerTimeout { .. } => Self::ServerTimeout,
+            Error::Connection(_) | Error::DnsResolution(_) => Self::Connection,
+            Error::HttpTransport {
+                status_code: None, ..
+            } => Self::Connection,
+            Error::RateLimitExceeded { .. } => Self::RateLimited,
+            Error::HttpTransport {
+                status_code: Some(status),
+                ..
+            }
+            | Error::Api {
+                status_code: status,
+                ..
+            } if (500..=599).contains(status) => Self::Server,
+            Error::BusinessLogic { .. } => Self::BusinessLogic,
+            Error::HttpTransport { .. }
+            | Error::Api { .. }
+            | Error::Authentication(_)
+            | Error::Authorization(_)
+            | Error::InvalidParameter { .. }
+            | Error::ResourceNotFound { .. }
+            | Error::DuplicateSubmission { .. }
+            | Error::ResponseDeserialization { .. }
+            | Error::ResponseTooLarge { .. }
+            | Error::UnknownFields { .. } => Self::Request,
+            _ => Self::Local,
+        }
+    }
+
+    /// Whether errors of this class are retried unless a policy says otherwise.
+    pub fn is_transient(self) -> bool {
+        matches!(
+            self,
+            Self::Timeout
+                | Self::ServerTimeout
+                | Self::Connection
+                | Self::RateLimited
+                | Self::Server
+        )
+    }
+
+    /// Whether a write failing with this class is known not to have
+    /// executed, so it may be retried even without
+    /// [`RetryPolicy::retry_writes`].
+    pub fn is_unprocessed(self) -> bool {
+        matches!(self, Self::ServerTimeout)
+    }
+}
+
+type RetryPredicate = dyn Fn(&Error) -> bool + Send + Sync;
+
+/// Retry behaviour applied by the client to failed requests.
+///
+/// By default transient errors (see [`ErrorClass::is_transient`]) are retried
+/// up to three times with exponential backoff. Individual classes can be
+/// forced on or off with [`RetryPolicy::always_retry`] and
+/// [`RetryPolicy::never_retry`], or the decision replaced entirely with
+/// [`RetryPolicy::retry_if`].
+///
+/// Only reads are retried unless [`RetryPolicy::retry_writes`] is enabled.
+/// Resending a signed submission cannot execute it twice, since the chain
+/// rejects a reused nonce, but a retry after a lost response surfaces as a
+/// duplicate submission rather than the original success. Writes the server
+/// timed out with a 408 are the exception: they were never processed, so
+/// they are retried like reads.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    backoff: Arc<dyn BackoffStrategy>,
+    overrides: Vec<(ErrorClass, bool)>,
+    predicate: Option<Arc<RetryPredicate>>,
+    retry_writes: bool,
+}
+
+impl RetryPolicy {
+    /// Create the default policy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self::new().max_retries(0)
+    }
+
+    /// Set the maximum number of retries after the first attempt.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Set the delay schedule between retries.
+    pub fn backoff<B: BackoffStrategy + 'static>(mut self, backoff: B) -> Self {
+        self.backoff = Arc::new(backoff);
+        self
+    }
+
+    /// Retry errors of `class` even if they are not transient.
+    pub fn always_retry(mut self, class: ErrorClass) -> Self {
+        self.overrides.retain(|(existing, _)| *existing != class);
+        self.overrides.push((class, true));
+        self
+    }
+
+    /// Never retry errors of `class`.
+    pub fn never_retry(mut self, class: ErrorClass) -> Self {
+        self.overrides.retain(|(existing, _)| *existing != class);
+        self.overrides.push((class, false));
+        self
+    }
+
+    /// Decide retries with a custom predicate instead of error classes.
+    pub fn retry_if<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&Error) -> bool + Send + Sync + 'static,
+    {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Also retry POST requests.
+    pub fn retry_writes(mut self, enabled: bool) -> Self {
+        self.retry_writes = enabled;
+        self
+    }
+
+    /// Maximum number of retries after the first attempt.
+    pub fn retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Whether POST requests are retried.
+    pub fn retries_writes(&self) -> bool {
+        self.retry_writes
+    }
+
+    /// Whether `error` should be retried.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        if let Some(predicate) = &self.predicate {
+            return predicate(error);
+        }
+        let class = ErrorClass::of(error);
+        self.overrides
+            .iter()
+            .find(|(overridden, _)| *overridden == class)
+            .map_or(class.is_transient(), |(_, retry)| *retry)
+    }
+
+    /// Whether to retry after `error` once `retried` retries have been made.
+    pub fn should_retry(&self, error: &Error, retried: u32) -> bool {
+        retried < self.max_retries && self.is_retryable(error)
+    }
+
+    /// Whether to retry a failed write, which is only done when writes are
+    /// retried or the server did not process the request.
+    pub fn should_retry_write(&self, error: &Error, retried: u32) -> bool {
+        (self.retry_writes || ErrorClass::of(error).is_unprocessed())
+            && self.should_retry(error, retried)
+    }
+
+    /// Delay before retry number `attempt` after `error`.
+    ///
+    /// A server `Retry-After` hint longer than the backoff delay wins.
+    pub fn delay(&self, attempt: u32, error: &Error) -> Duration {
+        let backoff = self.backoff.delay(attempt);
+        match error.root() {
+            Error::RateLimitExceeded {
+                retry_after_seconds: Some(seconds),
+            } => backoff.max(Duration::from_secs(*seconds)),
+            _ => backoff,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: Arc::new(ExponentialBackoff::default()),
+            overrides: Vec::new(),
+            predicate: None,
+            retry_writes: false,
+        }
+    }
+}
+
+impl From<RetryConfig> for RetryPolicy {
+    fn from(config: RetryConfig) -> Self {
+        Self::new().max_retries(config.max_attempts).backoff(config)
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("overrides", &self.overrides)
+            .field("custom_predicate", &self.predicate.is_some())
+            .field("retry_writes", &self.retry_writes)
+            .finish()
+    }
+}
+
 /// Check if a HTTP status code indicates a retryable error.
 pub fn is_retryable_status(status: u16) -> bool {
     matches!(
@@ -160,4 +414,116 @@ mod tests {
         assert!(is_retryable_status(502)); // Bad Gateway
         assert!(is_retryable_status(503)); // Service Unavailable
     }
+
+    #[test]
+    fn test_delay_for_error_honors_retry_after() {
+        let config = RetryConfig::new().initial_delay(Duration::from_millis(100));
+
+        let throttled = Error::rate_limit_exceeded(Some(5));
+        assert_eq!(
+            config.delay_for_error(1, &throttled),
+            Duration::from_secs(5)
+        );
+
+        let unknown = Error::rate_limit_exceeded(None);
+        assert_eq!(
+            config.delay_for_error(1, &unknown),
+            Duration::from_millis(100)
+        );
+
+        let other = Error::custom("boom");
+        assert_eq!(
+            config.delay_for_error(2, &other),
+            Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn test_error_classes() {
+        assert_eq!(
+            ErrorClass::of(&Error::request_timeout("/v1", 10)),
+            ErrorClass::Timeout
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::client_timeout("/v1", 12, 10)),
+            ErrorClass::Timeout
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::server_timeout("/v1", 3)),
+            ErrorClass::ServerTimeout
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::http_transport("reset", None)),
+            ErrorClass::Connection
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::http_transport("bad gateway", Some(502))),
+            ErrorClass::Server
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::api(400, "bad".into(), "bad".into())),
+            ErrorClass::Request
+        );
+        assert_eq!(
+            ErrorClass::of(&Error::business_logic("payment", "insufficient funds")),
+            ErrorClass::BusinessLogic
+        );
+        assert_eq!(ErrorClass::of(&Error::custom("local")), ErrorClass::Local);
+    }
+
+    #[test]
+    fn test_retry_policy_class_overrides() {
+        let timeout = Error::request_timeout("/v1", 10);
+        let not_found = Error::resource_not_found("transaction", "0x01");
+
+        let policy = RetryPolicy::new();
+        assert!(policy.should_retry(&timeout, 0));
+        assert!(!policy.should_retry(&timeout, 3));
+        assert!(!policy.should_retry(&not_found, 0));
+
+        let policy = RetryPolicy::new()
+            .never_retry(ErrorClass::Timeout)
+            .always_retry(ErrorClass::Request);
+        assert!(!policy.should_retry(&timeout, 0));
+        assert!(policy.should_retry(&not_found, 0));
+
+        let policy = policy.retry_if(|error| matches!(error, Error::Custom(_)));
+        assert!(policy.should_retry(&Error::custom("flaky"), 0));
+        assert!(!policy.should_retry(&not_found, 0));
+
+        assert!(!RetryPolicy::none().should_retry(&timeout, 0));
+    }
+
+    #[test]
+    fn test_retry_policy_writes_retry_only_unprocessed_timeouts() {
+        let client_timeout = Error::client_timeout("/v1/transactions/payment", 12, 10);
+        let server_timeout = Error::server_timeout("/v1/transactions/payment", 3);
+
+        let policy = RetryPolicy::new();
+        assert!(!policy.should_retry_write(&client_timeout, 0));
+        assert!(policy.should_retry_write(&server_timeout, 0));
+        assert!(!policy.should_retry_write(&server_timeout, 3));
+
+        let policy = policy.retry_writes(true);
+        assert!(policy.should_retry_write(&client_timeout, 0));
+
+        let policy = RetryPolicy::new().never_retry(ErrorClass::ServerTimeout);
+        assert!(!policy.should_retry_write(&server_timeout, 0));
+    }
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy::from(
+            RetryConfig::new()
+                .max_attempts(2)
+                .initial_delay(Duration::from_millis(50)),
+        );
+        assert_eq!(policy.retries(), 2);
+        let timeout = Error::request_timeout("/v1", 10);
+        assert_eq!(policy.delay(2, &timeout), Duration::from_millis(100));
+        assert_eq!(
+            policy.delay(1, &Error::rate_limit_exceeded(Some(3))),
+            Duration::from_secs(3)
+        );
+    }
 }
@@ -0,0 +1,113 @@
+//! Request timeouts per endpoint class.
+
+use crate::client::config::{ApiVersion, DEFAULT_TIMEOUT, endpoints::checkpoints};
+use std::time::Duration;
+
+/// Default time allowed to establish a connection.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time limits applied to requests, chosen by what the request does.
+///
+/// Each limit covers the whole request, from sending it to reading the last
+/// byte of the response. Unset fields keep their defaults:
+///
+/// ```rust
+/// use onemoney_protocol::TimeoutProfile;
+/// use std::time::Duration;
+///
+/// let timeouts = TimeoutProfile {
+///     read: Duration::from_secs(2),
+///     checkpoint: Duration::from_secs(60),
+///     ..TimeoutProfile::default()
+/// };
+/// assert_eq!(timeouts.submit, Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeoutProfile {
+    /// GET requests other than full checkpoint queries, e.g. nonce and
+    /// balance lookups.
+    pub read: Duration,
+    /// Checkpoint queries by number or hash, which may carry every
+    /// transaction of the checkpoint.
+    pub checkpoint: Duration,
+    /// Transaction submissions and other POST requests.
+    pub submit: Duration,
+    /// Establishing a connection. Only applies to HTTP clients created by
+    /// the builder, not to one passed to
+    /// [`ClientBuilder::http_client`](crate::ClientBuilder::http_client).
+    pub connect: Duration,
+}
+
+impl TimeoutProfile {
+    /// Use `timeout` for every kind of request and for connecting.
+    pub const fn uniform(timeout: Duration) -> Self {
+        Self {
+            read: timeout,
+            checkpoint: timeout,
+            submit: timeout,
+            connect: timeout,
+        }
+    }
+
+    /// Limit for a request with `method` to `path`.
+    ///
+    /// The path may carry any known version prefix and a query string.
+    pub fn for_request(&self, method: &str, path: &str) -> Duration {
+        if method != "GET" {
+            return self.submit;
+        }
+        let path = ApiVersion::strip(path);
+        let path = path.split('?').next().unwrap_or(path);
+        if path == checkpoints::BY_NUMBER || path == checkpoints::BY_HASH {
+            self.checkpoint
+        } else {
+            self.read
+        }
+    }
+}
+
+impl Default for TimeoutProfile {
+    fn default() -> Self {
+        Self {
+            connect: DEFAULT_CONNECT_TIMEOUT,
+            ..Self::uniform(DEFAULT_TIMEOUT)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout_profile_for_request() {
+        let profile = TimeoutProfile {
+            read: Duration::from_secs(1),
+            checkpoint: Duration::from_secs(2),
+            submit: Duration::from_secs(3),
+            ..TimeoutProfile::default()
+        };
+
+        assert_eq!(
+            profile.for_request("GET", "/v1/accounts/nonce?address=0x1"),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            profile.for_request("GET", "/v1/checkpoints/number"),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            profile.for_request("GET", "/v2/checkpoints/by_number?number=5&full=true"),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            profile.for_request("GET", "/v1/checkpoints/by_hash"),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            profile.for_request("POST", "/v1/transactions/payment"),
+            Duration::from_secs(3)
+        );
+        assert_eq!(profile.connect, DEFAULT_CONNECT_TIMEOUT);
+    }
+}
@@ -0,0 +1,162 @@
+//! Reports for operations applied to many items at once.
+
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// An item the batch operation completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSuccess<I, O> {
+    /// Position of the item in the batch.
+    pub index: usize,
+    /// The input item.
+    pub item: I,
+    /// What the operation returned for it.
+    pub output: O,
+}
+
+/// An item the batch operation could not complete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchFailure<I> {
+    /// Position of the item in the batch.
+    pub index: usize,
+    /// The input item, kept so the failure can be re-driven.
+    pub item: I,
+    /// Why the item failed.
+    pub reason: String,
+}
+
+/// Outcome of a batch operation, split by how each item ended.
+///
+/// Retryable failures are those where [`Error::is_retryable`](crate::Error::is_retryable)
+/// holds: sending the same item again may succeed. Permanent failures were
+/// rejected for the item itself and need to be changed before another
+/// attempt. The report serializes with its inputs so it can be stored and
+/// the retryable part re-driven later.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchReport<I, O> {
+    /// Items that completed.
+    pub successes: Vec<BatchSuccess<I, O>>,
+    /// Items that failed transiently.
+    pub retryable: Vec<BatchFailure<I>>,
+    /// Items that were rejected.
+    pub permanent: Vec<BatchFailure<I>>,
+}
+
+impl<I, O> BatchReport<I, O> {
+    /// Create an empty report.
+    pub fn new() -> Self {
+        Self {
+            successes: Vec::new(),
+            retryable: Vec::new(),
+            permanent: Vec::new(),
+        }
+    }
+
+    /// Record the result for the item at `index`.
+    pub fn record(&mut self, index: usize, item: I, result: Result<O>) {
+        match result {
+            Ok(output) => self.successes.push(BatchSuccess {
+                index,
+                item,
+                output,
+            }),
+            Err(error) => {
+                let failure = BatchFailure {
+                    index,
+                    item,
+                    reason: error.to_string(),
+                };
+                if error.is_retryable() {
+                    self.retryable.push(failure);
+                } else {
+                    self.permanent.push(failure);
+                }
+            }
+        }
+    }
+
+    /// Number of items recorded.
+    pub fn len(&self) -> usize {
+        self.successes.len() + self.retryable.len() + self.permanent.len()
+    }
+
+    /// Whether no items were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether every recorded item succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.retryable.is_empty() && self.permanent.is_empty()
+    }
+
+    /// Take the items worth sending again, in batch order.
+    pub fn take_retryable(&mut self) -> Vec<I> {
+        let mut failures = std::mem::take(&mut self.retryable);
+        failures.sort_by_key(|failure| failure.index);
+        failures.into_iter().map(|failure| failure.item).collect()
+    }
+}
+
+impl<I, O> Default for BatchReport<I, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, O> Display for BatchReport<I, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Batch of {}: {} succeeded, {} retryable, {} permanent",
+            self.len(),
+            self.successes.len(),
+            self.retryable.len(),
+            self.permanent.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    fn report() -> BatchReport<u32, String> {
+        let mut report = BatchReport::new();
+        report.record(0, 10, Ok("done".to_string()));
+        report.record(1, 11, Err(Error::rate_limit_exceeded(None)));
+        report.record(2, 12, Err(Error::validation("value", "zero")));
+        report.record(3, 13, Err(Error::http_transport("gateway", Some(502))));
+        report
+    }
+
+    #[test]
+    fn test_batch_report_classifies_results() {
+        let report = report();
+        assert_eq!(report.len(), 4);
+        assert!(!report.is_complete());
+        assert_eq!(report.successes[0].output, "done");
+        assert_eq!(report.retryable.len(), 2);
+        assert_eq!(report.permanent[0].index, 2);
+        assert!(report.permanent[0].reason.contains("zero"));
+        assert_eq!(
+            report.to_string(),
+            "Batch of 4: 1 succeeded, 2 retryable, 1 permanent"
+        );
+    }
+
+    #[test]
+    fn test_batch_report_round_trip_and_retry() {
+        let report = report();
+        let stored = serde_json::to_string(&report).expect("Report should serialize");
+        let mut restored: BatchReport<u32, String> =
+            serde_json::from_str(&stored).expect("Report should deserialize");
+        assert_eq!(restored, report);
+
+        assert_eq!(restored.take_retryable(), vec![11, 13]);
+        assert!(restored.retryable.is_empty());
+        assert!(BatchReport::<u32, String>::default().is_complete());
+    }
+}
@@ -1,9 +1,28 @@
 //! Common types used throughout the OneMoney SDK.
 
+use crate::{Error, Result};
 use alloy_primitives::U256;
+use hex::{decode as hex_decode, encode as hex_encode};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+/// Order of the secp256k1 group; valid `r` and `s` lie in `1..SECP256K1_ORDER`.
+pub const SECP256K1_ORDER: U256 = U256::from_limbs([
+    0xBFD2_5E8C_D036_4141,
+    0xBAAE_DCE6_AF48_A03B,
+    0xFFFF_FFFF_FFFF_FFFE,
+    0xFFFF_FFFF_FFFF_FFFF,
+]);
+
+/// Half the secp256k1 group order, rounded down; canonical signatures have
+/// `s` no greater than this.
+pub const SECP256K1_HALF_ORDER: U256 = U256::from_limbs([
+    0xDFE9_2F46_681B_20A0,
+    0x5D57_6E73_57A4_501D,
+    0xFFFF_FFFF_FFFF_FFFF,
+    0x7FFF_FFFF_FFFF_FFFF,
+]);
+
 /// ECDSA signature components.
 ///
 /// Compatible with REST API and L1 implementation signature format.
@@ -25,14 +44,233 @@ impl Signature {
     pub fn new(r: U256, s: U256, v: u64) -> Self {
         Self { r, s, v }
     }
+
+    /// The y-parity of the signature, 0 or 1, whichever way `v` encodes it.
+    ///
+    /// Accepts the L1 parity encoding (0 or 1), the legacy Ethereum encoding
+    /// (27 or 28) and EIP-155 values (`35 + 2 * chain_id + parity`).
+    pub fn recovery_id(&self) -> Result<u8> {
+        match self.v {
+            0 | 27 => Ok(0),
+            1 | 28 => Ok(1),
+            v if v >= 35 => Ok(((v - 35) % 2) as u8),
+            v => Err(Error::validation(
+                "v",
+                format!("{} is not a recovery id, legacy or EIP-155 value", v),
+            )),
+        }
+    }
+
+    /// This signature with `v` in the L1 parity encoding (0 or 1).
+    pub fn normalized(&self) -> Result<Self> {
+        Ok(Self::new(self.r, self.s, u64::from(self.recovery_id()?)))
+    }
+
+    /// This signature with `v` in the legacy Ethereum encoding (27 or 28).
+    pub fn to_legacy(&self) -> Result<Self> {
+        Ok(Self::new(
+            self.r,
+            self.s,
+            27 + u64::from(self.recovery_id()?),
+        ))
+    }
+
+    /// This signature with `v` replay-protected for `chain_id` as in EIP-155.
+    pub fn to_eip155(&self, chain_id: u64) -> Result<Self> {
+        let parity = u64::from(self.recovery_id()?);
+        let v = chain_id
+            .checked_mul(2)
+            .and_then(|v| v.checked_add(35 + parity))
+            .ok_or_else(|| {
+                Error::validation("chain_id", format!("{} is too large for EIP-155", chain_id))
+            })?;
+        Ok(Self::new(self.r, self.s, v))
+    }
+
+    /// Check that `r` and `s` are valid secp256k1 scalars and `v` a known
+    /// encoding. Does not require `s` to be in the lower half of the order;
+    /// see [`validate_canonical`](Self::validate_canonical).
+    pub fn validate(&self) -> Result<()> {
+        for (field, value) in [("r", self.r), ("s", self.s)] {
+            if value.is_zero() || value >= SECP256K1_ORDER {
+                return Err(Error::validation(
+                    field,
+                    "Must be between 1 and the secp256k1 group order",
+                ));
+            }
+        }
+        self.recovery_id().map(|_| ())
+    }
+
+    /// Whether `s` is in the lower half of the group order.
+    ///
+    /// For every signature `(r, s)` the pair `(r, n - s)` with the opposite
+    /// parity verifies too. The L1 validator follows Ethereum's EIP-2 and
+    /// only accepts the low-`s` form, so two implementations cannot disagree
+    /// about which of the two is the transaction's signature.
+    pub fn is_low_s(&self) -> bool {
+        self.s <= SECP256K1_HALF_ORDER
+    }
+
+    /// [`validate`](Self::validate), and also require a low `s` as the L1
+    /// validator does.
+    pub fn validate_canonical(&self) -> Result<()> {
+        self.validate()?;
+        if !self.is_low_s() {
+            return Err(Error::validation(
+                "s",
+                "High s values are rejected by the L1; normalize the signature first",
+            ));
+        }
+        Ok(())
+    }
+
+    /// This signature in canonical form: `s` in the lower half of the order
+    /// and `v` in the L1 parity encoding.
+    ///
+    /// A high `s` is replaced by `n - s` and the parity flipped, which
+    /// verifies for the same hash and key.
+    pub fn normalize(&self) -> Result<Self> {
+        self.validate()?;
+        let parity = self.recovery_id()?;
+        if self.is_low_s() {
+            Ok(Self::new(self.r, self.s, u64::from(parity)))
+        } else {
+            Ok(Self::new(
+                self.r,
+                SECP256K1_ORDER - self.s,
+                u64::from(parity ^ 1),
+            ))
+        }
+    }
+
+    /// The 64-byte EIP-2098 encoding: `r` followed by `s` with the y-parity
+    /// in its top bit.
+    ///
+    /// Fails when `s` is in the upper half of the order, where its top bit
+    /// is taken.
+    pub fn to_compact(&self) -> Result<[u8; 64]> {
+        let parity = self.recovery_id()?;
+        if self.s.bit(255) {
+            return Err(Error::validation(
+                "s",
+                "High s values have no compact encoding",
+            ));
+        }
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
+        bytes[32..].copy_from_slice(&self.s.to_be_bytes::<32>());
+        if parity == 1 {
+            bytes[32] |= 0x80;
+        }
+        Ok(bytes)
+    }
+
+    /// Parse a 64-byte EIP-2098 compact signature or a 65-byte `r || s || v`
+    /// signature. The result has `v` in the L1 parity encoding.
+    pub fn from_compact(bytes: &[u8]) -> Result<Self> {
+        let signature = match bytes.len() {
+            64 => {
+                let mut s = [0u8; 32];
+                s.copy_from_slice(&bytes[32..]);
+                let parity = s[0] >> 7;
+                s[0] &= 0x7f;
+                Self::new(
+                    U256::from_be_slice(&bytes[..32]),
+                    U256::from_be_bytes(s),
+                    u64::from(parity),
+                )
+            }
+            65 => Self::new(
+                U256::from_be_slice(&bytes[..32]),
+                U256::from_be_slice(&bytes[32..64]),
+                u64::from(bytes[64]),
+            )
+            .normalized()?,
+            other => {
+                return Err(Error::validation(
+                    "signature",
+                    format!("Expected 64 or 65 bytes, got {}", other),
+                ));
+            }
+        };
+        signature.validate()?;
+        Ok(signature)
+    }
+
+    /// The 65-byte `r || s || v` encoding as `0x`-prefixed hex, with `v` as
+    /// 27 or 28 as Ethereum tooling expects.
+    pub fn to_hex(&self) -> Result<String> {
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&self.r.to_be_bytes::<32>());
+        bytes[32..64].copy_from_slice(&self.s.to_be_bytes::<32>());
+        bytes[64] = 27 + self.recovery_id()?;
+        Ok(format!("0x{}", hex_encode(bytes)))
+    }
+
+    /// Parse a hex signature in either form accepted by
+    /// [`from_compact`](Self::from_compact), with or without `0x`.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+        Self::from_compact(&hex_decode(digits)?)
+    }
 }
 
+/// Alternate formatting (`{:#}`) prints the hex encoding of
+/// [`Signature::to_hex`], falling back to the components when `v` is not a
+/// known encoding.
 impl Display for Signature {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate()
+            && let Ok(hex) = self.to_hex()
+        {
+            return f.write_str(&hex);
+        }
         write!(f, "Signature(r: {}, s: {}, v: {})", self.r, self.s, self.v)
     }
 }
 
+/// Serde support for signatures as a single hex string.
+///
+/// Serializes with [`Signature::to_hex`] and accepts anything
+/// [`Signature::from_hex`] parses. The L1 API itself expects the structured
+/// `{r, s, v}` form; use this for fields exchanged with other tooling:
+///
+/// ```rust
+/// use onemoney_protocol::Signature;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Exported {
+///     #[serde(with = "onemoney_protocol::signature_hex")]
+///     signature: Signature,
+/// }
+/// ```
+pub mod signature_hex {
+    use super::Signature;
+    use serde::de::Error as DeError;
+    use serde::ser::Error as SerError;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize `signature` as 65-byte hex.
+    pub fn serialize<S>(signature: &Signature, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let hex = signature.to_hex().map_err(SerError::custom)?;
+        serializer.serialize_str(&hex)
+    }
+
+    /// Deserialize a signature from 64- or 65-byte hex.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Signature, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        Signature::from_hex(&hex).map_err(DeError::custom)
+    }
+}
+
 /// Transaction action types.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -414,4 +652,106 @@ mod tests {
         assert_eq!(mutable_signature.v, 30);
         assert_ne!(signature, mutable_signature);
     }
+
+    #[test]
+    fn test_signature_recovery_id_encodings() {
+        let r = U256::from(1u64);
+        let s = U256::from(2u64);
+        for (v, parity) in [(0, 0), (1, 1), (27, 0), (28, 1), (37, 0), (38, 1)] {
+            let signature = Signature::new(r, s, v);
+            assert_eq!(signature.recovery_id().ok(), Some(parity), "v = {}", v);
+        }
+        for v in [2, 26, 29, 34] {
+            assert!(Signature::new(r, s, v).recovery_id().is_err(), "v = {}", v);
+        }
+
+        let signature = Signature::new(r, s, 1);
+        let eip155 = signature.to_eip155(1212101).expect("Chain ID should fit");
+        assert_eq!(eip155.v, 1212101 * 2 + 36);
+        assert_eq!(eip155.normalized().ok(), Some(signature.clone()));
+        assert_eq!(eip155.to_legacy().map(|sig| sig.v).ok(), Some(28));
+        assert!(signature.to_eip155(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_signature_validate() {
+        let one = U256::from(1u64);
+        assert!(Signature::new(one, one, 27).validate().is_ok());
+        assert!(Signature::new(U256::ZERO, one, 0).validate().is_err());
+        assert!(Signature::new(one, SECP256K1_ORDER, 0).validate().is_err());
+        assert!(
+            Signature::new(one, SECP256K1_ORDER - one, 0)
+                .validate()
+                .is_ok()
+        );
+        assert!(Signature::new(one, one, 5).validate().is_err());
+    }
+
+    #[test]
+    fn test_signature_normalize_to_low_s() {
+        let one = U256::from(1u64);
+        assert_eq!(
+            SECP256K1_HALF_ORDER * U256::from(2u64) + one,
+            SECP256K1_ORDER
+        );
+
+        let low = Signature::new(one, SECP256K1_HALF_ORDER, 28);
+        assert!(low.is_low_s());
+        assert!(low.validate_canonical().is_ok());
+        assert_eq!(
+            low.normalize().ok(),
+            Some(Signature::new(one, SECP256K1_HALF_ORDER, 1))
+        );
+
+        let high = Signature::new(one, SECP256K1_HALF_ORDER + one, 0);
+        assert!(!high.is_low_s());
+        assert!(high.validate().is_ok());
+        assert!(matches!(
+            high.validate_canonical(),
+            Err(Error::Validation { ref field, .. }) if field == "s"
+        ));
+        let normalized = high.normalize().expect("High s should normalize");
+        assert_eq!(normalized, Signature::new(one, SECP256K1_HALF_ORDER, 1));
+        assert!(Signature::new(one, U256::ZERO, 0).normalize().is_err());
+    }
+
+    #[test]
+    fn test_signature_compact_and_hex_round_trip() {
+        let signature = Signature::new(
+            U256::from(0x1234u64) << 200,
+            U256::from(0x5678u64) << 100,
+            28,
+        );
+        let normalized = signature.normalized().expect("v should be valid");
+
+        let compact = signature.to_compact().expect("Low s should encode");
+        assert_eq!(compact[32] & 0x80, 0x80);
+        assert_eq!(
+            Signature::from_compact(&compact).ok(),
+            Some(normalized.clone())
+        );
+
+        let hex = signature.to_hex().expect("v should be valid");
+        assert_eq!(hex.len(), 2 + 130);
+        assert!(hex.ends_with("1c"));
+        assert_eq!(Signature::from_hex(&hex).ok(), Some(normalized.clone()));
+        assert_eq!(format!("{:#}", signature), hex);
+
+        assert!(Signature::from_compact(&compact[..63]).is_err());
+        let high_s = Signature::new(U256::from(1u64), SECP256K1_ORDER - U256::from(1u64), 0);
+        assert!(high_s.to_compact().is_err());
+
+        #[derive(Serialize, Deserialize)]
+        struct Exported {
+            #[serde(with = "signature_hex")]
+            signature: Signature,
+        }
+        let json = serde_json::to_string(&Exported {
+            signature: signature.clone(),
+        })
+        .expect("Should serialize");
+        assert_eq!(json, format!("{{\"signature\":\"{}\"}}", hex));
+        let parsed: Exported = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed.signature, normalized);
+    }
 }
@@ -0,0 +1,35 @@
+//! Submissions prepared but not sent by a dry-run client.
+
+use alloy_primitives::{B256, Bytes};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The request a client built with
+/// [`ClientBuilder::dry_run`](crate::ClientBuilder::dry_run) would have sent.
+///
+/// Returned inside [`Error::DryRun`](crate::Error::DryRun) by every method
+/// that submits a transaction, after the payload was validated, signed and
+/// encoded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DryRunResult {
+    /// Endpoint the request would have been posted to, including the API
+    /// version prefix.
+    pub endpoint: String,
+    /// The JSON request body, signatures included.
+    pub body: Value,
+    /// Digest covered by the signatures.
+    pub signature_hash: B256,
+    /// RLP encoding of the payload; its keccak-256 is `signature_hash`.
+    pub signed_bytes: Bytes,
+}
+
+impl Display for DryRunResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Dry run: POST {} with signature hash {}",
+            self.endpoint, self.signature_hash
+        )
+    }
+}
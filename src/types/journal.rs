@@ -0,0 +1,307 @@
+//! Durable record of transaction submissions, for at-least-once delivery.
+//!
+//! A payment service that crashes between signing a transaction and learning
+//! its outcome cannot tell from memory whether the transaction was sent. The
+//! journal writes each step to a [`Storage`] backend before taking the next
+//! one, keyed by a caller-chosen idempotency key such as an order ID:
+//!
+//! ```text
+//! Intent -> Signed -> Submitted -> Confirmed
+//!                  \-> Failed    \-> Failed
+//! ```
+//!
+//! [`Client::submit_journaled`](crate::Client::submit_journaled) drives an
+//! entry through these states and
+//! [`Client::recover_journal`](crate::Client::recover_journal) resumes every
+//! entry a previous process left unfinished. Any [`Storage`] backend works;
+//! a database such as SQLite or sled plugs in by implementing the trait.
+
+use crate::requests::{RawTransaction, TransactionPayload};
+use crate::storage::{Storage, StorageExt, namespaces};
+use crate::{Error, Result, Signature};
+use alloy_primitives::{B256, Bytes, keccak256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How far a journaled transaction got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalState {
+    /// The payload is recorded but not signed, so nothing was sent.
+    Intent,
+    /// The payload is signed and may or may not have reached the network.
+    Signed,
+    /// The network accepted the transaction; its receipt is outstanding.
+    Submitted,
+    /// The transaction succeeded.
+    Confirmed,
+    /// The transaction was rejected or failed on chain.
+    Failed,
+}
+
+impl JournalState {
+    /// Whether the entry needs no further work.
+    pub fn is_final(&self) -> bool {
+        matches!(self, JournalState::Confirmed | JournalState::Failed)
+    }
+}
+
+impl Display for JournalState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            JournalState::Intent => "intent",
+            JournalState::Signed => "signed",
+            JournalState::Submitted => "submitted",
+            JournalState::Confirmed => "confirmed",
+            JournalState::Failed => "failed",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// One journaled transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// The caller's idempotency key.
+    pub key: String,
+    /// Current state.
+    pub state: JournalState,
+    /// Endpoint the payload is submitted to, without a version prefix.
+    pub endpoint: String,
+    /// RLP encoding of the payload.
+    pub payload: Bytes,
+    /// Signature over the payload, from [`JournalState::Signed`] on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+    /// Transaction hash, from [`JournalState::Submitted`] on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<B256>,
+    /// Most recent error, kept while the entry can still be resumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Unix time of the last state change, in seconds.
+    pub updated_at: u64,
+}
+
+impl JournalEntry {
+    /// Record the intent to submit `payload` under `key`.
+    pub fn intent<P: TransactionPayload>(key: &str, payload: &P) -> Result<Self> {
+        if key.trim().is_empty() {
+            return Err(Error::validation(
+                "key",
+                "Idempotency key must not be empty",
+            ));
+        }
+        Ok(Self {
+            key: key.to_string(),
+            state: JournalState::Intent,
+            endpoint: P::ENDPOINT.to_string(),
+            payload: alloy_rlp::encode(payload).into(),
+            signature: None,
+            transaction_hash: None,
+            error: None,
+            updated_at: unix_now(),
+        })
+    }
+
+    /// Hash of the payload that is signed.
+    pub fn signature_hash(&self) -> B256 {
+        keccak256(&self.payload)
+    }
+
+    /// The signed transaction, once the entry has a signature.
+    pub fn raw_transaction(&self) -> Option<RawTransaction> {
+        self.signature.clone().map(|signature| RawTransaction {
+            endpoint: self.endpoint.clone(),
+            payload: self.payload.clone(),
+            signature,
+        })
+    }
+
+    /// Move to [`JournalState::Signed`].
+    pub fn mark_signed(&mut self, signature: Signature) {
+        self.signature = Some(signature);
+        self.transition(JournalState::Signed);
+    }
+
+    /// Move to [`JournalState::Submitted`].
+    pub fn mark_submitted(&mut self, transaction_hash: B256) {
+        self.transaction_hash = Some(transaction_hash);
+        self.transition(JournalState::Submitted);
+    }
+
+    /// Move to [`JournalState::Confirmed`].
+    pub fn mark_confirmed(&mut self) {
+        self.transition(JournalState::Confirmed);
+    }
+
+    /// Move to [`JournalState::Failed`], keeping `reason`.
+    pub fn mark_failed<S: Into<String>>(&mut self, reason: S) {
+        self.transition(JournalState::Failed);
+        self.error = Some(reason.into());
+    }
+
+    /// Persist the entry, replacing the previous record for its key.
+    pub fn save(&self, storage: &dyn Storage) -> Result<()> {
+        storage.put_json(namespaces::JOURNAL, &self.key, self)
+    }
+
+    /// Load the entry recorded under `key`.
+    pub fn load(storage: &dyn Storage, key: &str) -> Result<Option<Self>> {
+        storage.get_json(namespaces::JOURNAL, key)
+    }
+
+    /// Forget the entry recorded under `key`, returning whether there was one.
+    pub fn remove(storage: &dyn Storage, key: &str) -> Result<bool> {
+        storage.delete(namespaces::JOURNAL, key)
+    }
+
+    /// Every entry not yet confirmed or failed, in key order.
+    pub fn incomplete(storage: &dyn Storage) -> Result<Vec<Self>> {
+        let mut entries = Vec::new();
+        for key in storage.list(namespaces::JOURNAL)? {
+            if let Some(entry) = Self::load(storage, &key)?
+                && !entry.state.is_final()
+            {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    fn transition(&mut self, state: JournalState) {
+        self.state = state;
+        self.error = None;
+        self.updated_at = unix_now();
+    }
+}
+
+impl Display for JournalEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} {} ({})", self.key, self.state, self.endpoint)?;
+        if let Some(hash) = self.transaction_hash {
+            write!(f, " as {}", hash)?;
+        }
+        if let Some(error) = &self.error {
+            write!(f, ": {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+/// What [`Client::recover_journal`](crate::Client::recover_journal) did with
+/// the unfinished entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalRecovery {
+    /// Entries whose transaction succeeded.
+    pub confirmed: Vec<JournalEntry>,
+    /// Entries whose transaction was rejected or failed on chain.
+    pub failed: Vec<JournalEntry>,
+    /// Entries that could not be finished yet, with the error that stopped
+    /// them; recovering again resumes them.
+    pub pending: Vec<JournalEntry>,
+    /// Signed entries the server rejected as duplicates without naming the
+    /// transaction, so their receipt cannot be looked up. The transaction
+    /// most likely landed: check the account's history, then mark the entry
+    /// confirmed or failed and save it, or remove it.
+    pub unresolved: Vec<JournalEntry>,
+    /// Entries that were never signed. Nothing was sent for them, so they
+    /// are left for the caller to submit again or remove.
+    pub unsigned: Vec<JournalEntry>,
+}
+
+impl JournalRecovery {
+    /// Whether every resumable entry reached a final state.
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+impl Display for JournalRecovery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "Journal recovery: {} confirmed, {} failed, {} pending, {} unresolved, {} unsigned",
+            self.confirmed.len(),
+            self.failed.len(),
+            self.pending.len(),
+            self.unresolved.len(),
+            self.unsigned.len()
+        )?;
+        for entry in self
+            .failed
+            .iter()
+            .chain(&self.pending)
+            .chain(&self.unresolved)
+        {
+            writeln!(f, "  {}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::PaymentPayload;
+    use crate::storage::MemoryStorage;
+    use alloy_primitives::{Address, U256};
+
+    #[test]
+    fn test_journal_entry_lifecycle() {
+        let storage = MemoryStorage::new();
+        let payload = PaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 3,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000u64),
+            token: Address::repeat_byte(0x22),
+        };
+        assert!(JournalEntry::intent(" ", &payload).is_err());
+
+        let mut entry = JournalEntry::intent("order-1", &payload).expect("Key is valid");
+        assert_eq!(entry.signature_hash(), payload.signature_hash());
+        assert!(entry.raw_transaction().is_none());
+        entry.save(&storage).expect("Entry should be stored");
+
+        entry.mark_signed(Signature::default());
+        let raw = entry
+            .raw_transaction()
+            .expect("Signed entry has a transaction");
+        assert_eq!(
+            raw.payload_as::<PaymentPayload>().expect("Payload decodes"),
+            payload
+        );
+        entry.mark_submitted(B256::repeat_byte(0xaa));
+        entry.save(&storage).expect("Entry should be stored");
+
+        let incomplete = JournalEntry::incomplete(&storage).expect("Journal should list");
+        assert_eq!(incomplete, vec![entry.clone()]);
+
+        entry.mark_failed("Transaction failed");
+        entry.save(&storage).expect("Entry should be stored");
+        assert!(
+            JournalEntry::incomplete(&storage)
+                .expect("Journal should list")
+                .is_empty()
+        );
+        let loaded = JournalEntry::load(&storage, "order-1")
+            .expect("Entry should load")
+            .expect("Entry exists");
+        assert_eq!(loaded.state, JournalState::Failed);
+        assert_eq!(
+            loaded.to_string(),
+            format!(
+                "order-1 failed (/transactions/payment) as {}: Transaction failed",
+                B256::repeat_byte(0xaa)
+            )
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Off-chain token metadata documents.
+//!
+//! A token's on-chain metadata holds a URI pointing to a richer JSON
+//! document. [`TokenMetadataDocument`] is that document in the common
+//! wallet-compatible shape: name, symbol, description, image and external
+//! URL, plus any further properties.
+
+use crate::{Error, Result};
+use alloy_primitives::{B256, keccak256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use url::Url;
+
+/// Most bytes a serialized [`TokenMetadataDocument`] may take.
+pub const MAX_METADATA_DOCUMENT_BYTES: usize = 64 * 1024;
+
+/// JSON document a token's metadata URI points to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadataDocument {
+    /// Token name.
+    pub name: String,
+    /// Token symbol.
+    pub symbol: String,
+    /// Free-form description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// URL of the token's logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// URL of the token's project page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    /// Further top-level properties, serialized as given.
+    #[serde(flatten)]
+    pub properties: BTreeMap<String, Value>,
+}
+
+impl TokenMetadataDocument {
+    /// A document with only a name and symbol.
+    pub fn new<N: Into<String>, S: Into<String>>(name: N, symbol: S) -> Self {
+        Self {
+            name: name.into(),
+            symbol: symbol.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the description.
+    pub fn description<T: Into<String>>(mut self, description: T) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the logo URL.
+    pub fn image<T: Into<String>>(mut self, url: T) -> Self {
+        self.image = Some(url.into());
+        self
+    }
+
+    /// Set the project page URL.
+    pub fn external_url<T: Into<String>>(mut self, url: T) -> Self {
+        self.external_url = Some(url.into());
+        self
+    }
+
+    /// Add a top-level property, replacing any earlier one with that key.
+    pub fn property<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Check that the name and symbol are set, that the URLs parse and that
+    /// no property shadows a named field.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(Error::validation("name", "Name must not be empty"));
+        }
+        if self.symbol.trim().is_empty() {
+            return Err(Error::validation("symbol", "Symbol must not be empty"));
+        }
+        for (field, url) in [("image", &self.image), ("external_url", &self.external_url)] {
+            if let Some(url) = url
+                && let Err(e) = Url::parse(url)
+            {
+                return Err(Error::validation(
+                    field,
+                    format!("{:?} is not a URL: {}", url, e),
+                ));
+            }
+        }
+        const NAMED: [&str; 5] = ["name", "symbol", "description", "image", "external_url"];
+        if let Some(key) = self
+            .properties
+            .keys()
+            .find(|key| NAMED.contains(&key.as_str()))
+        {
+            return Err(Error::validation(
+                "properties",
+                format!("Property {:?} shadows the field of the same name", key),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validate the document and serialize it to JSON.
+    ///
+    /// Fails when the JSON exceeds [`MAX_METADATA_DOCUMENT_BYTES`].
+    pub fn to_json_bytes(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        let bytes = serde_json::to_vec(self)?;
+        if bytes.len() > MAX_METADATA_DOCUMENT_BYTES {
+            return Err(Error::validation(
+                "document",
+                format!(
+                    "{} bytes exceed the limit of {}",
+                    bytes.len(),
+                    MAX_METADATA_DOCUMENT_BYTES
+                ),
+            ));
+        }
+        Ok(bytes)
+    }
+
+    /// Keccak-256 digest of the serialized document, used as its storage
+    /// key so that uploading the same document again is idempotent.
+    pub fn content_hash(&self) -> Result<B256> {
+        Ok(keccak256(self.to_json_bytes()?))
+    }
+}
+
+/// Result of [`Client::upload_token_metadata`](crate::Client::upload_token_metadata).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataUpload {
+    /// URI the document was stored under and the token now points to.
+    pub uri: String,
+    /// [`TokenMetadataDocument::content_hash`] of the stored document.
+    pub document_hash: B256,
+    /// Hash of the metadata update transaction.
+    pub hash: B256,
+}
+
+impl Display for MetadataUpload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Metadata {} set in transaction {}", self.uri, self.hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_serializes_flat() {
+        let document = TokenMetadataDocument::new("Dollar", "USD1")
+            .image("https://example.com/usd1.png")
+            .property("decimals", 6);
+        let json: Value =
+            serde_json::from_slice(&document.to_json_bytes().expect("Document is valid"))
+                .expect("Document is JSON");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Dollar",
+                "symbol": "USD1",
+                "image": "https://example.com/usd1.png",
+                "decimals": 6,
+            })
+        );
+        let restored: TokenMetadataDocument =
+            serde_json::from_value(json).expect("Document should round-trip");
+        assert_eq!(restored, document);
+    }
+
+    #[test]
+    fn test_document_validation() {
+        let valid = TokenMetadataDocument::new("Dollar", "USD1");
+        assert!(valid.validate().is_ok());
+
+        let field = |document: TokenMetadataDocument| match document.to_json_bytes() {
+            Err(Error::Validation { field, .. }) => field,
+            other => panic!("Expected a validation error, got {:?}", other),
+        };
+        assert_eq!(field(TokenMetadataDocument::new(" ", "USD1")), "name");
+        assert_eq!(field(valid.clone().image("not a url")), "image");
+        assert_eq!(field(valid.clone().property("symbol", "X")), "properties");
+        assert_eq!(
+            field(valid.property("blob", "x".repeat(MAX_METADATA_DOCUMENT_BYTES))),
+            "document"
+        );
+    }
+}
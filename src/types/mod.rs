@@ -4,8 +4,19 @@
 pub mod common;
 
 // New organized API types
+pub mod batch;
+pub mod dry_run;
+pub mod journal;
+pub mod metadata;
+pub mod payments;
+#[cfg(feature = "client")]
+pub mod polling;
+pub mod references;
 pub mod requests;
 pub mod responses;
+pub mod resubmit;
+pub mod rotation;
+pub mod status;
 
 // Re-export commonly used types from original SDK
 pub use common::*;
@@ -26,9 +37,23 @@ pub use responses::accounts::*;
 pub use responses::chains::*;
 pub use responses::checkpoints::*;
 pub use responses::governance::*;
+pub use responses::pagination::*;
 pub use responses::tokens::*;
 pub use responses::transactions::*;
 
+// Re-export workflow types
+pub use batch::*;
+pub use dry_run::*;
+pub use journal::*;
+pub use metadata::*;
+pub use payments::*;
+#[cfg(feature = "client")]
+pub use polling::*;
+pub use references::*;
+pub use resubmit::*;
+pub use rotation::*;
+pub use status::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
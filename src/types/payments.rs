@@ -0,0 +1,194 @@
+//! Types for the end-to-end payment workflow.
+
+use crate::requests::PaymentPayload;
+use crate::responses::TransactionReceipt;
+use alloy_primitives::B256;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::Duration;
+
+/// Default time to wait for a payment receipt.
+pub const DEFAULT_PAYMENT_RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default interval between payment receipt polls.
+pub const DEFAULT_PAYMENT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Options for [`Client::send_payment_and_wait`](crate::Client::send_payment_and_wait).
+///
+/// Fields left unset are filled from the network before signing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentOptions {
+    /// Chain ID to sign for; fetched from the network when unset.
+    pub chain_id: Option<u64>,
+    /// Nonce to sign with; fetched for the signer when unset.
+    pub nonce: Option<u64>,
+    /// Whether to check the signer's balance before submitting.
+    pub check_balance: bool,
+    /// Maximum time to wait for the receipt.
+    pub receipt_timeout: Duration,
+    /// Interval between receipt polls.
+    pub poll_interval: Duration,
+}
+
+impl PaymentOptions {
+    /// Create options with defaults: network-filled chain ID and nonce, a
+    /// balance check, and the default receipt timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sign for a specific chain ID instead of fetching it.
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sign with a specific nonce instead of fetching it.
+    pub fn nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Enable or disable the balance preflight check.
+    pub fn check_balance(mut self, check_balance: bool) -> Self {
+        self.check_balance = check_balance;
+        self
+    }
+
+    /// Set the maximum time to wait for the receipt.
+    pub fn receipt_timeout(mut self, timeout: Duration) -> Self {
+        self.receipt_timeout = timeout;
+        self
+    }
+
+    /// Set the interval between receipt polls.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+impl Default for PaymentOptions {
+    fn default() -> Self {
+        Self {
+            chain_id: None,
+            nonce: None,
+            check_balance: true,
+            receipt_timeout: DEFAULT_PAYMENT_RECEIPT_TIMEOUT,
+            poll_interval: DEFAULT_PAYMENT_POLL_INTERVAL,
+        }
+    }
+}
+
+/// Result of a payment that was accepted by the network.
+///
+/// Failures before submission, such as an insufficient balance or a
+/// rejected request, are returned as errors instead.
+#[derive(Debug, Clone)]
+pub enum PaymentOutcome {
+    /// The payment was included and executed successfully.
+    Confirmed {
+        /// The signed payload, with the chain ID and nonce that were used.
+        payload: PaymentPayload,
+        /// The transaction receipt.
+        receipt: TransactionReceipt,
+    },
+    /// The payment was included but its execution failed.
+    Failed {
+        /// The signed payload, with the chain ID and nonce that were used.
+        payload: PaymentPayload,
+        /// The transaction receipt.
+        receipt: TransactionReceipt,
+    },
+    /// The payment was submitted but no receipt arrived before the timeout
+    /// or polling for it failed.
+    Pending {
+        /// The signed payload, with the chain ID and nonce that were used.
+        payload: PaymentPayload,
+        /// Hash of the submitted transaction.
+        hash: B256,
+    },
+}
+
+impl PaymentOutcome {
+    /// Hash of the submitted transaction.
+    pub fn hash(&self) -> B256 {
+        match self {
+            Self::Confirmed { receipt, .. } | Self::Failed { receipt, .. } => {
+                receipt.transaction_hash
+            }
+            Self::Pending { hash, .. } => *hash,
+        }
+    }
+
+    /// The payload that was signed and submitted.
+    pub fn payload(&self) -> &PaymentPayload {
+        match self {
+            Self::Confirmed { payload, .. }
+            | Self::Failed { payload, .. }
+            | Self::Pending { payload, .. } => payload,
+        }
+    }
+
+    /// The receipt, if one was received.
+    pub fn receipt(&self) -> Option<&TransactionReceipt> {
+        match self {
+            Self::Confirmed { receipt, .. } | Self::Failed { receipt, .. } => Some(receipt),
+            Self::Pending { .. } => None,
+        }
+    }
+
+    /// Whether the payment executed successfully.
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed { .. })
+    }
+}
+
+impl Display for PaymentOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let state = match self {
+            Self::Confirmed { .. } => "confirmed",
+            Self::Failed { .. } => "failed",
+            Self::Pending { .. } => "pending",
+        };
+        write!(f, "Payment {} {}", self.hash(), state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_options_builder() {
+        let options = PaymentOptions::new()
+            .chain_id(1212101)
+            .nonce(4)
+            .check_balance(false)
+            .receipt_timeout(Duration::from_secs(5))
+            .poll_interval(Duration::from_millis(10));
+
+        assert_eq!(options.chain_id, Some(1212101));
+        assert_eq!(options.nonce, Some(4));
+        assert!(!options.check_balance);
+        assert_eq!(options.receipt_timeout, Duration::from_secs(5));
+        assert_eq!(options.poll_interval, Duration::from_millis(10));
+
+        let defaults = PaymentOptions::default();
+        assert!(defaults.check_balance);
+        assert_eq!(defaults.chain_id, None);
+    }
+
+    #[test]
+    fn test_pending_outcome_accessors() {
+        let hash = B256::repeat_byte(0x07);
+        let outcome = PaymentOutcome::Pending {
+            payload: PaymentPayload::default(),
+            hash,
+        };
+
+        assert_eq!(outcome.hash(), hash);
+        assert!(outcome.receipt().is_none());
+        assert!(!outcome.is_confirmed());
+        assert_eq!(outcome.to_string(), format!("Payment {} pending", hash));
+    }
+}
@@ -0,0 +1,151 @@
+//! How often to ask for a receipt while waiting for a transaction.
+
+use crate::transport::backoff::{BackoffStrategy, ExponentialBackoff, Jittered, clamp_fraction};
+use std::time::Duration;
+
+/// Default time to wait for a receipt.
+pub const DEFAULT_RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default interval before the first receipt poll is repeated.
+pub const DEFAULT_RECEIPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Schedule of receipt polls for
+/// [`Client::wait_for_transaction_receipt_with_polling`](crate::Client::wait_for_transaction_receipt_with_polling).
+///
+/// The waits follow an [`ExponentialBackoff`], with poll number `n` taking
+/// the delay of retry `n`. With `jitter` above zero each wait is shortened
+/// as by [`Jittered`]. Waiting ends at `timeout` or after `max_polls` polls,
+/// whichever comes first.
+///
+/// The default polls every 50ms for up to 30 seconds, like
+/// [`Client::wait_for_transaction_receipt`](crate::Client::wait_for_transaction_receipt).
+///
+/// # Example
+///
+/// ```rust
+/// use onemoney_protocol::ReceiptPolling;
+/// use std::time::Duration;
+///
+/// // Background settlement: start at 200ms, back off to 5s, at most 40 polls.
+/// let polling = ReceiptPolling::exponential(Duration::from_millis(200), 2.0)
+///     .max_interval(Duration::from_secs(5))
+///     .jitter(0.2)
+///     .timeout(Duration::from_secs(120))
+///     .max_polls(40);
+/// assert_eq!(polling.interval(3), Duration::from_millis(800));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReceiptPolling {
+    /// Waits between polls before any jitter; a multiplier of 1 keeps them
+    /// fixed.
+    pub backoff: ExponentialBackoff,
+    /// Largest fraction, from 0 to 1, randomly taken off each wait.
+    pub jitter: f64,
+    /// Longest time to wait for the receipt in total.
+    pub timeout: Duration,
+    /// Most polls to make, or `None` for as many as the timeout allows.
+    pub max_polls: Option<u32>,
+}
+
+impl ReceiptPolling {
+    /// The default schedule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Poll every `interval`.
+    pub fn fixed(interval: Duration) -> Self {
+        Self {
+            backoff: ExponentialBackoff::new(interval)
+                .multiplier(1.0)
+                .max_delay(interval),
+            ..Self::default()
+        }
+    }
+
+    /// Start at `initial` and multiply the interval by `multiplier` after
+    /// every poll, capped at ten seconds.
+    pub fn exponential(initial: Duration, multiplier: f64) -> Self {
+        Self {
+            backoff: ExponentialBackoff::new(initial)
+                .multiplier(multiplier)
+                .max_delay(Duration::from_secs(10)),
+            ..Self::default()
+        }
+    }
+
+    /// Set the upper bound on a single wait.
+    pub fn max_interval(mut self, max: Duration) -> Self {
+        self.backoff = self.backoff.max_delay(max);
+        self
+    }
+
+    /// Take a random share of up to `fraction` off each wait, clamped to
+    /// between 0 and 1.
+    pub fn jitter(mut self, fraction: f64) -> Self {
+        self.jitter = clamp_fraction(fraction);
+        self
+    }
+
+    /// Set the longest time to wait in total.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Stop after `polls` polls even if the timeout has not passed.
+    pub fn max_polls(mut self, polls: u32) -> Self {
+        self.max_polls = Some(polls.max(1));
+        self
+    }
+
+    /// Wait after poll number `poll`, starting at 1, before any jitter.
+    pub fn interval(&self, poll: u32) -> Duration {
+        self.backoff.delay(poll)
+    }
+}
+
+/// Waits after each poll with jitter applied, different on every call.
+impl BackoffStrategy for ReceiptPolling {
+    fn delay(&self, poll: u32) -> Duration {
+        Jittered::new(self.backoff, self.jitter).delay(poll)
+    }
+}
+
+impl Default for ReceiptPolling {
+    fn default() -> Self {
+        Self {
+            backoff: ExponentialBackoff::new(DEFAULT_RECEIPT_POLL_INTERVAL)
+                .multiplier(1.0)
+                .max_delay(DEFAULT_RECEIPT_POLL_INTERVAL),
+            jitter: 0.0,
+            timeout: DEFAULT_RECEIPT_TIMEOUT,
+            max_polls: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receipt_polling_schedule() {
+        let polling = ReceiptPolling::exponential(Duration::from_millis(100), 2.0)
+            .max_interval(Duration::from_millis(500));
+        let waits: Vec<u128> = (1..=5)
+            .map(|poll| polling.interval(poll).as_millis())
+            .collect();
+        assert_eq!(waits, vec![100, 200, 400, 500, 500]);
+        assert_eq!(polling.interval(u32::MAX), Duration::from_millis(500));
+
+        let fixed = ReceiptPolling::fixed(Duration::from_millis(250));
+        assert_eq!(fixed.interval(1), fixed.interval(9));
+
+        let jittered = polling.jitter(3.0);
+        assert_eq!(jittered.jitter, 1.0);
+        for poll in 1..=20 {
+            assert!(jittered.delay(poll) <= jittered.interval(poll));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! External references attached to payments.
+//!
+//! Payments on the L1 carry no memo, so the SDK keeps references itself. A
+//! reference is stored under the signature hash of the payment it belongs
+//! to: the hash is fixed once the payload is final and can be recomputed
+//! from the transaction the network returns, so the reference can be found
+//! again from either side.
+
+use crate::requests::{NativePaymentPayload, PaymentPayload};
+use crate::responses::{Transaction, TxPayload};
+use crate::storage::{Storage, StorageExt, namespaces};
+use crate::{Error, Result};
+use alloy_primitives::{B256, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Longest reference accepted, in bytes.
+pub const MAX_PAYMENT_REFERENCE_BYTES: usize = 256;
+
+/// An external reference, such as an invoice or ERP document number,
+/// recorded for a payment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentReference {
+    /// The caller's reference.
+    pub reference: String,
+    /// Signature hash of the referenced payment.
+    pub signature_hash: B256,
+    /// Hash of the submitted transaction, once the network has accepted it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<B256>,
+}
+
+impl PaymentReference {
+    /// Reference the payment whose payload hashes to `signature_hash`.
+    pub fn new<S: Into<String>>(reference: S, signature_hash: B256) -> Result<Self> {
+        let reference = reference.into();
+        Self::validate(&reference)?;
+        Ok(Self {
+            reference,
+            signature_hash,
+            transaction_hash: None,
+        })
+    }
+
+    /// Check that a reference is non-blank, has no control characters and
+    /// fits in [`MAX_PAYMENT_REFERENCE_BYTES`].
+    pub fn validate(reference: &str) -> Result<()> {
+        if reference.trim().is_empty() {
+            return Err(Error::validation(
+                "reference",
+                "Reference must not be empty",
+            ));
+        }
+        if reference.len() > MAX_PAYMENT_REFERENCE_BYTES {
+            return Err(Error::validation(
+                "reference",
+                format!(
+                    "{} bytes exceed the limit of {}",
+                    reference.len(),
+                    MAX_PAYMENT_REFERENCE_BYTES
+                ),
+            ));
+        }
+        if reference.chars().any(char::is_control) {
+            return Err(Error::validation(
+                "reference",
+                "Reference must not contain control characters",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Persist the reference, replacing any earlier one for the same payment.
+    pub fn save(&self, storage: &dyn Storage) -> Result<()> {
+        storage.put_json(
+            namespaces::PAYMENT_REFERENCES,
+            &self.signature_hash.to_string(),
+            self,
+        )
+    }
+
+    /// Load the reference recorded for the payment hashing to `signature_hash`.
+    pub fn load(storage: &dyn Storage, signature_hash: B256) -> Result<Option<Self>> {
+        storage.get_json(namespaces::PAYMENT_REFERENCES, &signature_hash.to_string())
+    }
+
+    /// Forget the reference recorded for a payment, returning whether there
+    /// was one.
+    pub fn remove(storage: &dyn Storage, signature_hash: B256) -> Result<bool> {
+        storage.delete(namespaces::PAYMENT_REFERENCES, &signature_hash.to_string())
+    }
+
+    /// Every recorded payment carrying `reference`.
+    ///
+    /// Scans the whole namespace; keep a separate index for large volumes.
+    pub fn find(storage: &dyn Storage, reference: &str) -> Result<Vec<Self>> {
+        let mut found = Vec::new();
+        for key in storage.list(namespaces::PAYMENT_REFERENCES)? {
+            if let Some(record) = storage.get_json::<Self>(namespaces::PAYMENT_REFERENCES, &key)?
+                && record.reference == reference
+            {
+                found.push(record);
+            }
+        }
+        Ok(found)
+    }
+
+    /// The reference recorded for a transfer fetched from the network.
+    ///
+    /// Returns `None` for transactions other than transfers and for
+    /// transfers without a recorded reference.
+    pub fn for_transaction(
+        storage: &dyn Storage,
+        transaction: &Transaction,
+    ) -> Result<Option<Self>> {
+        match transfer_signature_hash(transaction) {
+            Some(hash) => Self::load(storage, hash),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Display for PaymentReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.transaction_hash {
+            Some(hash) => write!(f, "Reference {:?} for transaction {}", self.reference, hash),
+            None => write!(
+                f,
+                "Reference {:?} for unsubmitted payment {}",
+                self.reference, self.signature_hash
+            ),
+        }
+    }
+}
+
+/// Rebuild the signed payload of a transfer and hash it.
+fn transfer_signature_hash(transaction: &Transaction) -> Option<B256> {
+    let TxPayload::TokenTransfer {
+        value,
+        recipient,
+        token,
+    } = &transaction.data
+    else {
+        return None;
+    };
+    let value = value.parse::<U256>().ok()?;
+    Some(match token {
+        Some(token) => PaymentPayload {
+            chain_id: transaction.chain_id,
+            nonce: transaction.nonce,
+            recipient: *recipient,
+            value,
+            token: *token,
+        }
+        .signature_hash(),
+        None => NativePaymentPayload {
+            chain_id: transaction.chain_id,
+            nonce: transaction.nonce,
+            recipient: *recipient,
+            value,
+        }
+        .signature_hash(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+
+    #[test]
+    fn test_reference_validation() {
+        assert!(PaymentReference::new("INV-2024-0042", B256::ZERO).is_ok());
+        for invalid in [
+            " ".to_string(),
+            "line\nbreak".to_string(),
+            "x".repeat(MAX_PAYMENT_REFERENCE_BYTES + 1),
+        ] {
+            assert!(matches!(
+                PaymentReference::new(invalid, B256::ZERO),
+                Err(Error::Validation { ref field, .. }) if field == "reference"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reference_storage_round_trip() {
+        let storage = MemoryStorage::new();
+        let mut record =
+            PaymentReference::new("INV-7", B256::repeat_byte(0x01)).expect("Valid reference");
+        record.save(&storage).expect("Save should succeed");
+        record.transaction_hash = Some(B256::repeat_byte(0x02));
+        record.save(&storage).expect("Save should replace");
+        PaymentReference::new("INV-8", B256::repeat_byte(0x03))
+            .expect("Valid reference")
+            .save(&storage)
+            .expect("Save should succeed");
+
+        let loaded =
+            PaymentReference::load(&storage, B256::repeat_byte(0x01)).expect("Load should succeed");
+        assert_eq!(loaded, Some(record.clone()));
+        assert_eq!(
+            PaymentReference::find(&storage, "INV-7").expect("Find should succeed"),
+            vec![record]
+        );
+        assert!(
+            PaymentReference::remove(&storage, B256::repeat_byte(0x01))
+                .expect("Remove should succeed")
+        );
+        assert!(
+            PaymentReference::find(&storage, "INV-7")
+                .expect("Find should succeed")
+                .is_empty()
+        );
+    }
+}
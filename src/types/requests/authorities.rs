@@ -1,6 +1,6 @@
 //! Token-related type definitions.
 
-use alloy_rlp::{BufMut, Encodable};
+use super::rlp::{impl_decodable_by_name, impl_encodable_by_name};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -66,17 +66,26 @@ impl AuthorityAction {
     }
 }
 
-impl Encodable for AuthorityAction {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.as_str().encode(out);
-    }
-}
-
-impl Encodable for Authority {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.as_str().encode(out);
-    }
-}
+impl_encodable_by_name!(AuthorityAction);
+
+impl_encodable_by_name!(Authority);
+
+impl_decodable_by_name!(
+    AuthorityAction,
+    [AuthorityAction::Grant, AuthorityAction::Revoke]
+);
+
+impl_decodable_by_name!(
+    Authority,
+    [
+        Authority::MasterMintBurn,
+        Authority::MintBurnTokens,
+        Authority::Pause,
+        Authority::ManageList,
+        Authority::UpdateMetadata,
+        Authority::Bridge,
+    ]
+);
 
 #[cfg(test)]
 mod tests {
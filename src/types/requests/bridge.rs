@@ -1,9 +1,12 @@
 //! Bridge-related API request types and payloads.
 
+use super::rlp::{decode_flagged, flagged_length, signing_hash};
+use super::setters::payload_setters;
 use crate::Signature;
 use crate::crypto::Signable;
-use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
-use alloy_rlp::{BufMut, Encodable as AlloyEncodable};
+use crate::utils::{IntoAddress, IntoAmount};
+use alloy_primitives::{Address, B256, Bytes, U256};
+use alloy_rlp::{BufMut, Decodable as AlloyDecodable, Encodable as AlloyEncodable};
 use serde::{Deserialize, Serialize};
 
 // Serialize U256 as decimal string instead of hex (L1 compatibility)
@@ -68,13 +71,71 @@ impl AlloyEncodable for TokenBridgeAndMintPayload {
             meta.encode(out);
         }
     }
+
+    fn length(&self) -> usize {
+        self.chain_id.length()
+            + self.nonce.length()
+            + self.recipient.length()
+            + self.value.length()
+            + self.token.length()
+            + self.source_chain_id.length()
+            + self.source_tx_hash.length()
+            + flagged_length(&self.bridge_metadata)
+    }
+}
+
+impl AlloyDecodable for TokenBridgeAndMintPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self {
+            chain_id: AlloyDecodable::decode(buf)?,
+            nonce: AlloyDecodable::decode(buf)?,
+            recipient: AlloyDecodable::decode(buf)?,
+            value: AlloyDecodable::decode(buf)?,
+            token: AlloyDecodable::decode(buf)?,
+            source_chain_id: AlloyDecodable::decode(buf)?,
+            source_tx_hash: AlloyDecodable::decode(buf)?,
+            bridge_metadata: decode_flagged(buf)?,
+        })
+    }
+}
+
+impl TokenBridgeAndMintPayload {
+    /// Create a payload minting tokens bridged in from `source_chain_id`,
+    /// without bridge metadata.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        recipient: impl IntoAddress,
+        value: impl IntoAmount,
+        token: impl IntoAddress,
+        source_chain_id: u64,
+        source_tx_hash: impl Into<String>,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            recipient: recipient.into_address("recipient")?,
+            value: value.into_amount("value")?,
+            token: token.into_address("token")?,
+            source_chain_id,
+            source_tx_hash: source_tx_hash.into(),
+            bridge_metadata: None,
+        })
+    }
+}
+
+payload_setters! {
+    TokenBridgeAndMintPayload {
+        address recipient => with_recipient,
+        address token => with_token,
+        amount value => with_value,
+        plain bridge_metadata: Option<String> => with_bridge_metadata,
+    }
 }
 
 impl Signable for TokenBridgeAndMintPayload {
     fn signature_hash(&self) -> B256 {
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -133,13 +194,81 @@ impl AlloyEncodable for TokenBurnAndBridgePayload {
             param.encode(out);
         }
     }
+
+    fn length(&self) -> usize {
+        self.chain_id.length()
+            + self.nonce.length()
+            + self.sender.length()
+            + self.value.length()
+            + self.token.length()
+            + self.destination_chain_id.length()
+            + self.destination_address.length()
+            + self.escrow_fee.length()
+            + flagged_length(&self.bridge_metadata)
+            + flagged_length(&self.bridge_param)
+    }
+}
+
+impl AlloyDecodable for TokenBurnAndBridgePayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        Ok(Self {
+            chain_id: AlloyDecodable::decode(buf)?,
+            nonce: AlloyDecodable::decode(buf)?,
+            sender: AlloyDecodable::decode(buf)?,
+            value: AlloyDecodable::decode(buf)?,
+            token: AlloyDecodable::decode(buf)?,
+            destination_chain_id: AlloyDecodable::decode(buf)?,
+            destination_address: AlloyDecodable::decode(buf)?,
+            escrow_fee: AlloyDecodable::decode(buf)?,
+            bridge_metadata: decode_flagged(buf)?,
+            bridge_param: decode_flagged(buf)?,
+        })
+    }
+}
+
+impl TokenBurnAndBridgePayload {
+    /// Create a payload burning tokens to bridge them to
+    /// `destination_chain_id`, without bridge metadata or parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        sender: impl IntoAddress,
+        value: impl IntoAmount,
+        token: impl IntoAddress,
+        destination_chain_id: u64,
+        destination_address: impl Into<String>,
+        escrow_fee: impl IntoAmount,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            sender: sender.into_address("sender")?,
+            value: value.into_amount("value")?,
+            token: token.into_address("token")?,
+            destination_chain_id,
+            destination_address: destination_address.into(),
+            escrow_fee: escrow_fee.into_amount("escrow_fee")?,
+            bridge_metadata: None,
+            bridge_param: None,
+        })
+    }
+}
+
+payload_setters! {
+    TokenBurnAndBridgePayload {
+        address sender => with_sender,
+        address token => with_token,
+        amount value => with_value,
+        amount escrow_fee => with_escrow_fee,
+        plain bridge_metadata: Option<String> => with_bridge_metadata,
+        plain bridge_param: Option<Bytes> => with_bridge_param,
+    }
 }
 
 impl Signable for TokenBurnAndBridgePayload {
     fn signature_hash(&self) -> B256 {
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
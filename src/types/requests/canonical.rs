@@ -0,0 +1,171 @@
+//! Deterministic JSON for payloads, and checks that it matches their RLP.
+//!
+//! The signature covers a payload's RLP encoding, while people review the
+//! JSON a wallet or approval tool displays. Canonical JSON sorts object keys
+//! and drops whitespace, so the same payload always renders to the same
+//! bytes and two renderings can be compared or hashed directly.
+//! [`verify_encodings`] decodes both forms and reports the fields on which
+//! they disagree.
+
+use super::submission::TransactionPayload;
+use crate::{Error, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// Render `value` as JSON with object keys sorted and no whitespace.
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string(&sorted(serde_json::to_value(
+        value,
+    )?))?)
+}
+
+/// Sort every object's keys, independent of the map order serde_json was
+/// built with.
+fn sorted(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => {
+            let mut fields: Vec<(String, Value)> = fields.into_iter().collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key, sorted(value)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sorted).collect()),
+        other => other,
+    }
+}
+
+/// Check that a JSON rendering and an RLP encoding describe the same payload.
+///
+/// Both are decoded as `P` before they are compared, so formatting that
+/// does not change a value, such as key order or the case of a hex address,
+/// is accepted. Fields outside `P` in the JSON are ignored, as they are when
+/// the JSON is parsed on its own.
+///
+/// # Arguments
+///
+/// * `json` - The payload as displayed or exported
+/// * `rlp` - The bytes whose keccak hash was signed
+///
+/// # Returns
+///
+/// The payload, or [`Error::InvalidParameter`] naming every field whose
+/// values differ.
+///
+/// ```rust
+/// use onemoney_protocol::PaymentPayload;
+/// use onemoney_protocol::requests::{TransactionPayload, verify_encodings};
+/// use alloy_primitives::Address;
+///
+/// let payload = PaymentPayload::new(1212101, 0, Address::repeat_byte(1), 100u64, Address::repeat_byte(2))?;
+/// let json = payload.canonical_json()?;
+///
+/// let verified: PaymentPayload = verify_encodings(&json, &payload.to_rlp())?;
+/// assert_eq!(verified, payload);
+///
+/// let altered = json.replace("\"nonce\":0", "\"nonce\":1");
+/// assert!(verify_encodings::<PaymentPayload>(&altered, &payload.to_rlp()).is_err());
+/// # Ok::<(), onemoney_protocol::Error>(())
+/// ```
+pub fn verify_encodings<P>(json: &str, rlp: &[u8]) -> Result<P>
+where
+    P: TransactionPayload + DeserializeOwned,
+{
+    let signed = P::from_rlp(rlp)?;
+    let displayed: P = serde_json::from_str(json)?;
+
+    let (Value::Object(signed_fields), Value::Object(displayed_fields)) = (
+        serde_json::to_value(&signed)?,
+        serde_json::to_value(&displayed)?,
+    ) else {
+        return Err(Error::invalid_parameter(
+            "json",
+            "Payload does not serialize to a JSON object",
+        ));
+    };
+    let mut differing: Vec<&str> = signed_fields
+        .iter()
+        .filter(|(key, value)| displayed_fields.get(key.as_str()) != Some(value))
+        .map(|(key, _)| key.as_str())
+        .collect();
+    differing.sort_unstable();
+
+    if differing.is_empty() {
+        Ok(signed)
+    } else {
+        Err(Error::invalid_parameter(
+            "json",
+            format!(
+                "Fields differ from the signed RLP encoding: {}",
+                differing.join(", ")
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::TokenMetadataUpdatePayload;
+    use crate::responses::MetadataKVPair;
+    use alloy_primitives::Address;
+
+    fn metadata_update() -> TokenMetadataUpdatePayload {
+        TokenMetadataUpdatePayload {
+            chain_id: 1_212_101,
+            nonce: 3,
+            name: "Test Token".to_string(),
+            uri: "https://example.com".to_string(),
+            token: Address::repeat_byte(0xab),
+            additional_metadata: vec![MetadataKVPair {
+                key: "issuer".to_string(),
+                value: "Example".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_canonical_json_is_sorted_and_compact() {
+        let json = metadata_update()
+            .canonical_json()
+            .expect("Payload should serialize");
+        assert!(json.starts_with(r#"{"additional_metadata":[{"key":"issuer","value":"Example"}],"chain_id":1212101,"name":"Test Token","nonce":3,"#));
+        assert_eq!(
+            canonical_json(&serde_json::json!({"b": {"z": 1, "a": 2}, "a": [3]}))
+                .expect("Value should serialize"),
+            r#"{"a":[3],"b":{"a":2,"z":1}}"#
+        );
+    }
+
+    #[test]
+    fn test_verify_encodings_names_differing_fields() {
+        let payload = metadata_update();
+        let rlp = payload.to_rlp();
+
+        let mut restyled: Value =
+            serde_json::from_str(&payload.canonical_json().expect("Payload should serialize"))
+                .expect("Canonical JSON should parse");
+        restyled["token"] = Value::String(format!("0x{}", "AB".repeat(20)));
+        let verified: TokenMetadataUpdatePayload =
+            verify_encodings(&restyled.to_string(), &rlp).expect("Same values should verify");
+        assert_eq!(verified, payload);
+
+        let mut altered = payload.clone();
+        altered.name = "Other".to_string();
+        altered.nonce = 4;
+        let error = verify_encodings::<TokenMetadataUpdatePayload>(
+            &altered.canonical_json().expect("Payload should serialize"),
+            &rlp,
+        )
+        .expect_err("Changed fields must be reported");
+        assert!(
+            error.to_string().contains("name, nonce"),
+            "Error should list the fields: {}",
+            error
+        );
+    }
+}
@@ -1,15 +1,28 @@
 //! API request type definitions.
 
 pub mod authorities;
+pub mod canonical;
+pub mod preview;
+pub mod raw;
+pub mod submission;
 pub mod tokens;
 pub mod transactions;
+pub mod validate;
+
+pub(crate) mod rlp;
+pub(crate) mod setters;
 
 #[cfg(feature = "bridge")]
 pub mod bridge;
 
 // Re-export commonly used request types
+pub use canonical::*;
+pub use preview::*;
+pub use raw::*;
+pub use submission::*;
 pub use tokens::*;
 pub use transactions::*;
+pub use validate::*;
 
 #[cfg(feature = "bridge")]
 pub use bridge::*;
@@ -0,0 +1,174 @@
+//! What a signature over a payload commits to, laid out for approval screens.
+
+use super::submission::TransactionPayload;
+use crate::Result;
+use alloy_primitives::{B256, Bytes};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// One field of a [`SigningPreview`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviewField {
+    /// Field name as it appears in the request body.
+    pub name: String,
+    /// The value as text: strings without quotes, amounts in base units,
+    /// lists and objects as compact JSON.
+    pub value: String,
+}
+
+/// A payload broken down for display before it is signed.
+///
+/// The fields are listed in the order they are RLP-encoded, and the digest
+/// is the keccak-256 of that encoding: the exact value a signer signs. A
+/// hardware wallet or approval tool can show the fields next to the digest
+/// so the approver can check one against the other.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SigningPreview {
+    /// Endpoint the payload is submitted to, without a version prefix.
+    pub endpoint: String,
+    /// Chain the signature is valid on.
+    pub chain_id: u64,
+    /// Nonce the payload consumes.
+    pub nonce: u64,
+    /// Every signed field, in encoding order.
+    pub fields: Vec<PreviewField>,
+    /// Digest the signature commits to.
+    pub digest: B256,
+    /// The encoding the digest is taken over.
+    pub rlp: Bytes,
+}
+
+impl SigningPreview {
+    /// Break `payload` down for display.
+    pub fn new<P: TransactionPayload>(payload: &P) -> Result<Self> {
+        let OrderedFields(fields) = serde_json::from_str(&serde_json::to_string(payload)?)?;
+        Ok(Self {
+            endpoint: P::ENDPOINT.to_string(),
+            chain_id: payload.chain_id(),
+            nonce: payload.nonce(),
+            fields: fields
+                .into_iter()
+                .map(|(name, value)| PreviewField {
+                    name,
+                    value: display_value(&value),
+                })
+                .collect(),
+            digest: payload.signature_hash(),
+            rlp: payload.to_rlp(),
+        })
+    }
+
+    /// The value of the field called `name`, if the payload has one.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| field.value.as_str())
+    }
+}
+
+impl Display for SigningPreview {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Sign {} on chain {}", self.endpoint, self.chain_id)?;
+        let width = self
+            .fields
+            .iter()
+            .map(|field| field.name.len())
+            .max()
+            .unwrap_or(0);
+        for field in &self.fields {
+            writeln!(f, "  {:<width$}  {}", field.name, field.value)?;
+        }
+        write!(f, "Digest {}", self.digest)
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => "none".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// The top-level fields of a JSON object in the order they were written.
+///
+/// Payload structs serialize their fields in declaration order, which is
+/// also their encoding order; collecting into a `serde_json::Map` would sort
+/// them instead.
+struct OrderedFields(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for OrderedFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = OrderedFields;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("a payload object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<Self::Value, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(OrderedFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::requests::{TokenAuthorityPayload, TransactionPayload};
+    use crate::{Authority, AuthorityAction, Signable};
+    use alloy_primitives::{Address, U256, keccak256};
+
+    #[test]
+    fn test_signing_preview_lists_fields_in_encoding_order() {
+        let payload = TokenAuthorityPayload {
+            chain_id: 1_212_101,
+            nonce: 2,
+            action: AuthorityAction::Grant,
+            authority_type: Authority::MintBurnTokens,
+            authority_address: Address::repeat_byte(0x11),
+            token: Address::repeat_byte(0x22),
+            value: U256::from(750_000u64),
+        };
+        let preview = payload.signing_preview().expect("Payload should serialize");
+
+        let names: Vec<&str> = preview
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "chain_id",
+                "nonce",
+                "action",
+                "authority_type",
+                "authority_address",
+                "token",
+                "value"
+            ]
+        );
+        assert_eq!(preview.field("value"), Some("750000"));
+        assert_eq!(preview.digest, payload.signature_hash());
+        assert_eq!(keccak256(&preview.rlp), preview.digest);
+
+        let shown = preview.to_string();
+        assert!(shown.starts_with("Sign /tokens/grant_authority on chain 1212101\n"));
+        assert!(shown.ends_with(&format!("Digest {}", preview.digest)));
+    }
+}
@@ -0,0 +1,207 @@
+//! Pre-signed transactions in a portable binary form.
+
+use super::rlp::decode_list;
+use crate::crypto::recover_signer;
+use crate::requests::TransactionPayload;
+use crate::{Error, Result, Signature};
+use alloy_primitives::{Address, Bytes, U256, keccak256};
+use alloy_rlp::{BufMut, Decodable, Encodable, Header};
+
+/// A signed transaction as one RLP blob, for handing transactions between
+/// signers and the client that broadcasts them.
+///
+/// The blob is the list `[endpoint, payload, v, r, s]`:
+///
+/// * `endpoint` - the payload's [`TransactionPayload::ENDPOINT`] without a
+///   version prefix, e.g. `/tokens/mint`, which tells apart payloads that
+///   share a field layout
+/// * `payload` - the payload's own RLP list, the bytes whose keccak hash is
+///   signed
+/// * `v`, `r`, `s` - the signature, `v` in any encoding
+///   [`Signature::recovery_id`] accepts
+///
+/// ```rust
+/// use onemoney_protocol::{PaymentPayload, RawTransaction, sign_transaction_payload};
+/// use alloy_primitives::Address;
+///
+/// let payload = PaymentPayload::new(1212101, 0, Address::repeat_byte(1), 100u64, Address::repeat_byte(2))?;
+/// let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+/// let signature = sign_transaction_payload(&payload, key)?;
+///
+/// let blob = RawTransaction::new(&payload, signature).to_rlp();
+/// let decoded = RawTransaction::from_rlp(&blob)?;
+/// assert_eq!(decoded.payload_as::<PaymentPayload>()?, payload);
+/// # Ok::<(), onemoney_protocol::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTransaction {
+    /// Endpoint the payload is submitted to, without a version prefix.
+    pub endpoint: String,
+    /// RLP encoding of the payload.
+    pub payload: Bytes,
+    /// Signature over the keccak hash of `payload`.
+    pub signature: Signature,
+}
+
+impl RawTransaction {
+    /// Wrap a signed payload.
+    pub fn new<P: TransactionPayload>(payload: &P, signature: Signature) -> Self {
+        Self {
+            endpoint: P::ENDPOINT.to_string(),
+            payload: alloy_rlp::encode(payload).into(),
+            signature,
+        }
+    }
+
+    /// Parse a blob; the whole input must be one transaction.
+    ///
+    /// Only the envelope is checked here. The payload is decoded by
+    /// [`payload_as`](Self::payload_as).
+    pub fn from_rlp(bytes: &[u8]) -> Result<Self> {
+        alloy_rlp::decode_exact(bytes).map_err(|error| {
+            Error::invalid_parameter("rlp", format!("Not a valid RawTransaction: {}", error))
+        })
+    }
+
+    /// Encode as a blob.
+    pub fn to_rlp(&self) -> Bytes {
+        alloy_rlp::encode(self).into()
+    }
+
+    /// Decode the payload as `P`, which must be submitted to this
+    /// transaction's endpoint.
+    pub fn payload_as<P: TransactionPayload>(&self) -> Result<P> {
+        if self.endpoint != P::ENDPOINT {
+            return Err(Error::invalid_parameter(
+                "endpoint",
+                format!("Transaction is for {}, not {}", self.endpoint, P::ENDPOINT),
+            ));
+        }
+        P::from_rlp(&self.payload)
+    }
+
+    /// Recover the address that signed the payload.
+    pub fn signer(&self) -> Result<Address> {
+        recover_signer(&keccak256(&self.payload), &self.signature)
+    }
+
+    fn fields_length(&self) -> usize {
+        self.endpoint.as_str().length()
+            + self.payload.len()
+            + self.signature.v.length()
+            + self.signature.r.length()
+            + self.signature.s.length()
+    }
+}
+
+impl Encodable for RawTransaction {
+    fn encode(&self, out: &mut dyn BufMut) {
+        Header {
+            list: true,
+            payload_length: self.fields_length(),
+        }
+        .encode(out);
+        self.endpoint.as_str().encode(out);
+        // Already an RLP list, so it is nested as-is.
+        out.put_slice(&self.payload);
+        self.signature.v.encode(out);
+        self.signature.r.encode(out);
+        self.signature.s.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.fields_length();
+        Header {
+            list: true,
+            payload_length,
+        }
+        .length()
+            + payload_length
+    }
+}
+
+impl Decodable for RawTransaction {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |fields| {
+            let endpoint = String::decode(fields)?;
+            let start = *fields;
+            let header = Header::decode(fields)?;
+            if !header.list {
+                return Err(alloy_rlp::Error::UnexpectedString);
+            }
+            if fields.len() < header.payload_length {
+                return Err(alloy_rlp::Error::InputTooShort);
+            }
+            *fields = &fields[header.payload_length..];
+            let payload = Bytes::copy_from_slice(&start[..start.len() - fields.len()]);
+
+            let v = u64::decode(fields)?;
+            let r = U256::decode(fields)?;
+            let s = U256::decode(fields)?;
+            Ok(Self {
+                endpoint,
+                payload,
+                signature: Signature::new(r, s, v),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{private_key_to_address, sign_transaction_payload};
+    use crate::requests::{NativePaymentPayload, PaymentPayload, TokenMintPayload};
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_raw_transaction_round_trip() {
+        let payload = NativePaymentPayload::new(1212101, 3, Address::repeat_byte(0x11), 42u64)
+            .expect("Payload should build");
+        let signature =
+            sign_transaction_payload(&payload, PRIVATE_KEY).expect("Signing should succeed");
+        let raw = RawTransaction::new(&payload, signature.clone());
+
+        let blob = raw.to_rlp();
+        assert_eq!(blob.len(), raw.length());
+        let decoded = RawTransaction::from_rlp(&blob).expect("Blob should decode");
+        assert_eq!(decoded, raw);
+        assert_eq!(decoded.signature, signature);
+        assert_eq!(
+            decoded
+                .payload_as::<NativePaymentPayload>()
+                .expect("Payload should decode"),
+            payload
+        );
+        let expected: Address = private_key_to_address(PRIVATE_KEY)
+            .expect("Key should be valid")
+            .parse()
+            .expect("Address should parse");
+        assert_eq!(decoded.signer().expect("Signer should recover"), expected);
+
+        let mut trailing = blob.to_vec();
+        trailing.push(0x80);
+        assert!(RawTransaction::from_rlp(&trailing).is_err());
+        assert!(RawTransaction::from_rlp(&blob[..blob.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_raw_transaction_payload_as_checks_endpoint() {
+        let payload = TokenMintPayload {
+            chain_id: 1212101,
+            nonce: 0,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(5u64),
+            token: Address::repeat_byte(0x22),
+        };
+        let raw = RawTransaction::new(&payload, Signature::default());
+
+        // Same field layout, different operation.
+        let error = raw
+            .payload_as::<PaymentPayload>()
+            .expect_err("Mint must not decode as a payment");
+        assert!(error.to_string().contains("/tokens/mint"), "{}", error);
+        assert_eq!(raw.payload_as::<TokenMintPayload>().ok(), Some(payload));
+    }
+}
@@ -0,0 +1,249 @@
+//! Helpers shared by the payload RLP encoders and decoders.
+
+use alloy_primitives::{B256, keccak256};
+use alloy_rlp::{BufMut, Encodable, Error as RlpError, Header, Result as RlpResult};
+
+/// A value encoded as an RLP list of its fields.
+///
+/// The list header needs the payload length up front. Summing the fields'
+/// [`Encodable::length`] provides it without encoding into a scratch buffer
+/// first, so every encoding writes each field once.
+pub(crate) trait RlpFields {
+    /// Encode the fields in order, without the list header.
+    fn encode_fields(&self, out: &mut dyn BufMut);
+
+    /// Encoded length of the fields, without the list header.
+    fn fields_length(&self) -> usize;
+}
+
+fn list_header<T: RlpFields + ?Sized>(value: &T) -> Header {
+    Header {
+        list: true,
+        payload_length: value.fields_length(),
+    }
+}
+
+/// Encode `value` as a list: the header, then its fields.
+pub(crate) fn encode_list<T: RlpFields + ?Sized>(value: &T, out: &mut dyn BufMut) {
+    list_header(value).encode(out);
+    value.encode_fields(out);
+}
+
+/// Encoded length of `value` as a list, header included.
+pub(crate) fn list_length<T: RlpFields + ?Sized>(value: &T) -> usize {
+    let header = list_header(value);
+    header.length() + header.payload_length
+}
+
+/// Keccak-256 of the RLP encoding of `value`, encoded into a buffer sized
+/// exactly once.
+pub(crate) fn signing_hash<T: Encodable + ?Sized>(value: &T) -> B256 {
+    let mut encoded = Vec::with_capacity(value.length());
+    value.encode(&mut encoded);
+    keccak256(&encoded)
+}
+
+/// Implement [`RlpFields`] and `Encodable` for a struct encoded as the list
+/// of the named fields, in order.
+macro_rules! rlp_list {
+    ($ty:ty { $($field:ident),+ $(,)? }) => {
+        impl $crate::types::requests::rlp::RlpFields for $ty {
+            fn encode_fields(&self, out: &mut dyn alloy_rlp::BufMut) {
+                $(alloy_rlp::Encodable::encode(&self.$field, out);)+
+            }
+
+            fn fields_length(&self) -> usize {
+                0 $(+ alloy_rlp::Encodable::length(&self.$field))+
+            }
+        }
+
+        $crate::types::requests::rlp::rlp_list!(@encodable $ty);
+    };
+    (@encodable $ty:ty) => {
+        impl alloy_rlp::Encodable for $ty {
+            fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+                $crate::types::requests::rlp::encode_list(self, out)
+            }
+
+            fn length(&self) -> usize {
+                $crate::types::requests::rlp::list_length(self)
+            }
+        }
+    };
+}
+
+pub(crate) use rlp_list;
+
+/// Decode an RLP list and run `fields` over its contents.
+///
+/// Bytes left inside the list after `fields` returns are rejected, so a
+/// decoder that reads fewer fields than were encoded fails loudly.
+pub(crate) fn decode_list<T>(
+    buf: &mut &[u8],
+    fields: impl FnOnce(&mut &[u8]) -> RlpResult<T>,
+) -> RlpResult<T> {
+    let mut payload = Header::decode_bytes(buf, true)?;
+    let expected = payload.len();
+    let value = fields(&mut payload)?;
+    if payload.is_empty() {
+        Ok(value)
+    } else {
+        Err(RlpError::ListLengthMismatch {
+            expected,
+            got: expected - payload.len(),
+        })
+    }
+}
+
+/// Encoded length of an optional value written as a presence flag followed
+/// by the value when present.
+#[cfg(feature = "bridge")]
+pub(crate) fn flagged_length<T: Encodable>(value: &Option<T>) -> usize {
+    value.is_some().length() + value.as_ref().map_or(0, Encodable::length)
+}
+
+/// Decode an optional value encoded as a presence flag followed by the
+/// value when present.
+#[cfg(feature = "bridge")]
+pub(crate) fn decode_flagged<T: alloy_rlp::Decodable>(buf: &mut &[u8]) -> RlpResult<Option<T>> {
+    if <bool as alloy_rlp::Decodable>::decode(buf)? {
+        T::decode(buf).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Implement `Decodable` for an enum encoded as its `as_str` name.
+macro_rules! impl_decodable_by_name {
+    ($ty:ty, [$($variant:expr),+ $(,)?]) => {
+        impl alloy_rlp::Decodable for $ty {
+            fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+                let name = alloy_rlp::Header::decode_bytes(buf, false)?;
+                [$($variant),+]
+                    .into_iter()
+                    .find(|variant| variant.as_str().as_bytes() == name)
+                    .ok_or(alloy_rlp::Error::Custom(concat!("unknown ", stringify!($ty))))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_decodable_by_name;
+
+/// Implement `Encodable` for an enum encoded as its `as_str` name.
+macro_rules! impl_encodable_by_name {
+    ($ty:ty) => {
+        impl alloy_rlp::Encodable for $ty {
+            fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+                alloy_rlp::Encodable::encode(self.as_str(), out);
+            }
+
+            fn length(&self) -> usize {
+                alloy_rlp::Encodable::length(self.as_str())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_encodable_by_name;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rlp::{Decodable, Encodable};
+
+    #[test]
+    fn test_decode_list_rejects_unread_fields() {
+        let mut encoded = Vec::new();
+        Header {
+            list: true,
+            payload_length: 2,
+        }
+        .encode(&mut encoded);
+        1u8.encode(&mut encoded);
+        2u8.encode(&mut encoded);
+
+        let one = decode_list(&mut encoded.as_slice(), u8::decode);
+        assert!(matches!(one, Err(RlpError::ListLengthMismatch { .. })));
+
+        let both = decode_list(&mut encoded.as_slice(), |payload| {
+            Ok((u8::decode(payload)?, u8::decode(payload)?))
+        });
+        assert_eq!(both, Ok((1, 2)));
+    }
+
+    #[test]
+    fn test_list_length_matches_encoding() {
+        use crate::requests::{PaymentPayload, TokenMetadataUpdatePayload};
+        use crate::responses::MetadataKVPair;
+        use alloy_primitives::{Address, U256};
+
+        let payment = PaymentPayload {
+            chain_id: 1212101,
+            nonce: 42,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1_000_000u64),
+            token: Address::repeat_byte(0x22),
+        };
+        // The list encoding the fields were written through before.
+        let mut fields = Vec::new();
+        payment.chain_id.encode(&mut fields);
+        payment.nonce.encode(&mut fields);
+        payment.recipient.encode(&mut fields);
+        payment.value.encode(&mut fields);
+        payment.token.encode(&mut fields);
+        let mut expected = Vec::new();
+        Header {
+            list: true,
+            payload_length: fields.len(),
+        }
+        .encode(&mut expected);
+        expected.extend_from_slice(&fields);
+
+        let mut encoded = Vec::new();
+        payment.encode(&mut encoded);
+        assert_eq!(encoded, expected);
+        assert_eq!(payment.length(), encoded.len());
+        assert_eq!(signing_hash(&payment), keccak256(&expected));
+
+        // Long enough to need a multi-byte list header.
+        let update = TokenMetadataUpdatePayload {
+            chain_id: 1212101,
+            nonce: 0,
+            name: "Token".repeat(20),
+            uri: "https://example.com/token.json".to_string(),
+            token: Address::repeat_byte(0x22),
+            additional_metadata: vec![
+                MetadataKVPair {
+                    key: "issuer".to_string(),
+                    value: "x".repeat(60),
+                };
+                3
+            ],
+        };
+        let mut encoded = Vec::new();
+        update.encode(&mut encoded);
+        assert_eq!(update.length(), encoded.len());
+        assert_eq!(
+            TokenMetadataUpdatePayload::decode(&mut encoded.as_slice()),
+            Ok(update)
+        );
+    }
+
+    #[cfg(feature = "bridge")]
+    #[test]
+    fn test_decode_flagged() {
+        let mut encoded = Vec::new();
+        true.encode(&mut encoded);
+        "meta".encode(&mut encoded);
+        false.encode(&mut encoded);
+
+        let mut buf = encoded.as_slice();
+        assert_eq!(
+            decode_flagged::<String>(&mut buf),
+            Ok(Some("meta".to_string()))
+        );
+        assert_eq!(decode_flagged::<String>(&mut buf), Ok(None));
+        assert!(buf.is_empty());
+    }
+}
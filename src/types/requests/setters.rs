@@ -0,0 +1,65 @@
+//! Generated `with_*` setters shared by the payload types.
+
+/// Implement `with_chain_id`, `with_nonce` and one setter per listed field.
+///
+/// Address and amount setters go through [`IntoAddress`] and
+/// [`IntoAmount`] and fail on unparsable input; `plain` setters take the
+/// field type as-is.
+///
+/// [`IntoAddress`]: crate::utils::IntoAddress
+/// [`IntoAmount`]: crate::utils::IntoAmount
+macro_rules! payload_setters {
+    (
+        $payload:ty {
+            $(address $address:ident => $address_setter:ident,)*
+            $(amount $amount:ident => $amount_setter:ident,)*
+            $(plain $plain:ident: $plain_ty:ty => $plain_setter:ident,)*
+        }
+    ) => {
+        impl $payload {
+            /// Replace the chain ID.
+            pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+                self.chain_id = chain_id;
+                self
+            }
+
+            /// Replace the nonce.
+            pub fn with_nonce(mut self, nonce: u64) -> Self {
+                self.nonce = nonce;
+                self
+            }
+
+            $(
+                #[doc = concat!("Replace `", stringify!($address), "`, parsing strings as addresses.")]
+                pub fn $address_setter(
+                    mut self,
+                    $address: impl $crate::utils::IntoAddress,
+                ) -> $crate::Result<Self> {
+                    self.$address = $address.into_address(stringify!($address))?;
+                    Ok(self)
+                }
+            )*
+
+            $(
+                #[doc = concat!("Replace `", stringify!($amount), "`, parsing strings as base-unit amounts.")]
+                pub fn $amount_setter(
+                    mut self,
+                    $amount: impl $crate::utils::IntoAmount,
+                ) -> $crate::Result<Self> {
+                    self.$amount = $amount.into_amount(stringify!($amount))?;
+                    Ok(self)
+                }
+            )*
+
+            $(
+                #[doc = concat!("Replace `", stringify!($plain), "`.")]
+                pub fn $plain_setter(mut self, $plain: $plain_ty) -> Self {
+                    self.$plain = $plain;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+pub(crate) use payload_setters;
@@ -0,0 +1,292 @@
+//! Shared traits for signed transaction submissions.
+
+use crate::client::config::endpoints::{tokens, transactions};
+use crate::crypto::Signable;
+use crate::requests::{
+    NativePaymentPayload, PaymentPayload, TokenAuthorityPayload, TokenBlacklistPayload,
+    TokenBurnPayload, TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload,
+    TokenWhitelistPayload, Validate,
+};
+use crate::requests::{SigningPreview, canonical_json};
+use crate::{Error, Result};
+use alloy_primitives::Bytes;
+use alloy_rlp::{Decodable, Encodable};
+use serde::Serialize;
+use std::any::type_name;
+
+#[cfg(feature = "client")]
+use crate::Signature;
+#[cfg(feature = "bridge")]
+use crate::client::config::endpoints::bridge;
+#[cfg(feature = "bridge")]
+use crate::requests::{TokenBridgeAndMintPayload, TokenBurnAndBridgePayload};
+
+/// A payload submitted with its fields flattened next to the signature.
+///
+/// The client [validates](Validate) every payload before signing it.
+pub trait TransactionPayload: Signable + Validate + Serialize + Encodable + Decodable {
+    /// Endpoint the payload is posted to, without the API version prefix.
+    const ENDPOINT: &'static str;
+
+    /// Chain the payload is signed for.
+    ///
+    /// The chain ID is part of the signed encoding, so a signature is only
+    /// valid on this chain.
+    fn chain_id(&self) -> u64;
+
+    /// Nonce of the signing account the payload consumes.
+    fn nonce(&self) -> u64;
+
+    /// Replace the nonce. Signatures over the old nonce no longer match.
+    fn set_nonce(&mut self, nonce: u64);
+
+    /// Parse a payload from the RLP bytes its signature covers.
+    ///
+    /// This is the inverse of the encoding hashed by
+    /// [`Signable::signature_hash`], so blobs signed by other tools can be
+    /// inspected before they are trusted or resubmitted. The whole input must
+    /// be one payload; trailing bytes are rejected.
+    fn from_rlp(bytes: &[u8]) -> Result<Self> {
+        alloy_rlp::decode_exact(bytes).map_err(|error| {
+            Error::invalid_parameter(
+                "rlp",
+                format!("Not a valid {}: {}", short_type_name::<Self>(), error),
+            )
+        })
+    }
+
+    /// The RLP bytes the signature covers; [`Signable::signature_hash`] is
+    /// their keccak hash.
+    fn to_rlp(&self) -> Bytes {
+        alloy_rlp::encode(self).into()
+    }
+
+    /// The payload as JSON with sorted keys and no whitespace, for display
+    /// next to [`to_rlp`](Self::to_rlp) and for comparing with
+    /// [`verify_encodings`](super::verify_encodings).
+    fn canonical_json(&self) -> Result<String> {
+        canonical_json(self)
+    }
+
+    /// The signed fields, the digest and its encoding, for showing an
+    /// approver before the payload is signed.
+    fn signing_preview(&self) -> Result<SigningPreview> {
+        SigningPreview::new(self)
+    }
+}
+
+/// The type name without its module path.
+pub(crate) fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// A payload with its signature, in the shape every submission endpoint accepts.
+#[cfg(feature = "client")]
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct SignedSubmission<'a, P> {
+    #[serde(flatten)]
+    pub(crate) payload: &'a P,
+    pub(crate) signature: Signature,
+}
+
+macro_rules! transaction_payload {
+    ($($payload:ty => $endpoint:expr),* $(,)?) => {
+        $(
+            impl TransactionPayload for $payload {
+                const ENDPOINT: &'static str = $endpoint;
+
+                fn chain_id(&self) -> u64 {
+                    self.chain_id
+                }
+
+                fn nonce(&self) -> u64 {
+                    self.nonce
+                }
+
+                fn set_nonce(&mut self, nonce: u64) {
+                    self.nonce = nonce;
+                }
+            }
+        )*
+    };
+}
+
+transaction_payload! {
+    PaymentPayload => transactions::PAYMENT,
+    NativePaymentPayload => transactions::PAYMENT,
+    TokenMintPayload => tokens::MINT,
+    TokenBurnPayload => tokens::BURN,
+    TokenAuthorityPayload => tokens::GRANT_AUTHORITY,
+    TokenPausePayload => tokens::PAUSE,
+    TokenBlacklistPayload => tokens::MANAGE_BLACKLIST,
+    TokenWhitelistPayload => tokens::MANAGE_WHITELIST,
+    TokenMetadataUpdatePayload => tokens::UPDATE_METADATA,
+}
+
+#[cfg(feature = "bridge")]
+transaction_payload! {
+    TokenBridgeAndMintPayload => bridge::BRIDGE_AND_MINT,
+    TokenBurnAndBridgePayload => bridge::BURN_AND_BRIDGE,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::{BlacklistAction, PauseAction, WhitelistAction};
+    use crate::responses::MetadataKVPair;
+    use crate::{Authority, AuthorityAction};
+    use alloy_primitives::{Address, U256};
+    use alloy_rlp::Encodable;
+    use std::fmt::Debug;
+
+    fn assert_round_trip<P: TransactionPayload + Encodable + PartialEq + Debug>(payload: P) {
+        let mut encoded = Vec::new();
+        payload.encode(&mut encoded);
+        let decoded = P::from_rlp(&encoded).expect("Encoded payload should decode");
+        assert_eq!(decoded, payload);
+
+        encoded.push(0x80);
+        assert!(
+            P::from_rlp(&encoded).is_err(),
+            "Trailing bytes must be rejected"
+        );
+        encoded.truncate(encoded.len() - 2);
+        assert!(
+            P::from_rlp(&encoded).is_err(),
+            "Truncated input must be rejected"
+        );
+    }
+
+    #[test]
+    fn test_from_rlp_round_trips_every_payload() {
+        let account = Address::repeat_byte(0x11);
+        let token = Address::repeat_byte(0x22);
+
+        assert_round_trip(PaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 3,
+            recipient: account,
+            value: U256::MAX,
+            token,
+        });
+        assert_round_trip(NativePaymentPayload {
+            chain_id: 1_212_101,
+            nonce: 4,
+            recipient: account,
+            value: U256::from(5u64),
+        });
+        assert_round_trip(TokenMintPayload {
+            chain_id: 1_212_101,
+            nonce: 0,
+            recipient: account,
+            value: U256::ZERO,
+            token,
+        });
+        assert_round_trip(TokenBurnPayload {
+            chain_id: 1_212_101,
+            nonce: 1,
+            recipient: account,
+            value: U256::from(250u64),
+            token,
+        });
+        assert_round_trip(TokenAuthorityPayload {
+            chain_id: 1_212_101,
+            nonce: 2,
+            action: AuthorityAction::Revoke,
+            authority_type: Authority::UpdateMetadata,
+            authority_address: account,
+            token,
+            value: U256::from(7u64),
+        });
+        assert_round_trip(TokenPausePayload {
+            chain_id: 1_212_101,
+            nonce: 4,
+            action: PauseAction::Unpause,
+            token,
+        });
+        assert_round_trip(TokenBlacklistPayload {
+            chain_id: 1_212_101,
+            nonce: 5,
+            action: BlacklistAction::Add,
+            address: account,
+            token,
+        });
+        assert_round_trip(TokenWhitelistPayload {
+            chain_id: 1_212_101,
+            nonce: 6,
+            action: WhitelistAction::Remove,
+            address: account,
+            token,
+        });
+        assert_round_trip(TokenMetadataUpdatePayload {
+            chain_id: 1_212_101,
+            nonce: 7,
+            name: "Test Token".to_string(),
+            uri: String::new(),
+            token,
+            additional_metadata: vec![
+                MetadataKVPair {
+                    key: "issuer".to_string(),
+                    value: "Example".to_string(),
+                },
+                MetadataKVPair::default(),
+            ],
+        });
+    }
+
+    #[cfg(feature = "bridge")]
+    #[test]
+    fn test_from_rlp_round_trips_bridge_payloads() {
+        use alloy_primitives::Bytes;
+
+        for bridge_metadata in [None, Some(String::new()), Some("memo".to_string())] {
+            assert_round_trip(TokenBridgeAndMintPayload {
+                chain_id: 1_212_101,
+                nonce: 8,
+                recipient: Address::repeat_byte(0x11),
+                value: U256::from(100u64),
+                token: Address::repeat_byte(0x22),
+                source_chain_id: 1,
+                source_tx_hash: "0xabc".to_string(),
+                bridge_metadata: bridge_metadata.clone(),
+            });
+            assert_round_trip(TokenBurnAndBridgePayload {
+                chain_id: 1_212_101,
+                nonce: 9,
+                sender: Address::repeat_byte(0x11),
+                value: U256::from(100u64),
+                token: Address::repeat_byte(0x22),
+                destination_chain_id: 1,
+                destination_address: "0xdef".to_string(),
+                escrow_fee: U256::from(1u64),
+                bridge_metadata,
+                bridge_param: Some(Bytes::from(vec![1, 2, 3])),
+            });
+        }
+    }
+
+    #[test]
+    fn test_from_rlp_rejects_unknown_action() {
+        let mut encoded = Vec::new();
+        TokenPausePayload {
+            chain_id: 1_212_101,
+            nonce: 0,
+            action: PauseAction::Pause,
+            token: Address::ZERO,
+        }
+        .encode(&mut encoded);
+        let position = encoded
+            .windows(5)
+            .position(|window| window == b"Pause")
+            .expect("Action name should be in the encoding");
+        encoded[position..position + 5].copy_from_slice(b"Halts");
+
+        let error = TokenPausePayload::from_rlp(&encoded).expect_err("Unknown action must fail");
+        assert!(
+            error.to_string().contains("TokenPausePayload"),
+            "Error should name the payload type: {}",
+            error
+        );
+    }
+}
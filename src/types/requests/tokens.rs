@@ -1,10 +1,15 @@
 //! Token-related API request types and payloads.
 
+use super::rlp::{
+    decode_list, impl_decodable_by_name, impl_encodable_by_name, rlp_list, signing_hash,
+};
+use super::setters::payload_setters;
 use crate::crypto::Signable;
 use crate::responses::MetadataKVPair;
+use crate::utils::{IntoAddress, IntoAmount};
 use crate::{Authority, AuthorityAction, Signature};
-use alloy_primitives::{Address, B256, U256, keccak256};
-use alloy_rlp::{BufMut, Encodable as AlloyEncodable};
+use alloy_primitives::{Address, B256, U256};
+use alloy_rlp::Decodable as AlloyDecodable;
 use serde::{Deserialize, Serialize};
 
 // Serialize U256 as decimal string instead of hex (L1 compatibility)
@@ -48,35 +53,58 @@ pub struct TokenMintPayload {
     pub token: Address,
 }
 
-impl AlloyEncodable for TokenMintPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.recipient.encode(&mut temp_buf);
-        self.value.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenMintPayload {
+    chain_id,
+    nonce,
+    recipient,
+    value,
+    token
+});
+
+impl AlloyDecodable for TokenMintPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                recipient: AlloyDecodable::decode(payload)?,
+                value: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+impl TokenMintPayload {
+    /// Create a mint payload issuing `value` to `recipient`.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        recipient: impl IntoAddress,
+        value: impl IntoAmount,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            recipient: recipient.into_address("recipient")?,
+            value: value.into_amount("value")?,
+            token: token.into_address("token")?,
+        })
+    }
+}
+
+payload_setters! {
+    TokenMintPayload {
+        address recipient => with_recipient,
+        address token => with_token,
+        amount value => with_value,
     }
 }
 
 impl Signable for TokenMintPayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -99,35 +127,58 @@ pub struct TokenBurnPayload {
     pub token: Address,
 }
 
-impl AlloyEncodable for TokenBurnPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.recipient.encode(&mut temp_buf);
-        self.value.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenBurnPayload {
+    chain_id,
+    nonce,
+    recipient,
+    value,
+    token
+});
+
+impl AlloyDecodable for TokenBurnPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                recipient: AlloyDecodable::decode(payload)?,
+                value: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
+
+impl TokenBurnPayload {
+    /// Create a burn payload destroying `value` held by `recipient`.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        recipient: impl IntoAddress,
+        value: impl IntoAmount,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            recipient: recipient.into_address("recipient")?,
+            value: value.into_amount("value")?,
+            token: token.into_address("token")?,
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+payload_setters! {
+    TokenBurnPayload {
+        address recipient => with_recipient,
+        address token => with_token,
+        amount value => with_value,
     }
 }
 
 impl Signable for TokenBurnPayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -154,38 +205,71 @@ pub struct TokenAuthorityPayload {
     pub value: U256,
 }
 
-impl AlloyEncodable for TokenAuthorityPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Use alloy_rlp encoding to match L1 implementation exactly
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.action.encode(&mut temp_buf);
-        self.authority_type.encode(&mut temp_buf);
-        self.authority_address.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-        self.value.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenAuthorityPayload {
+    chain_id,
+    nonce,
+    action,
+    authority_type,
+    authority_address,
+    token,
+    value
+});
+
+impl AlloyDecodable for TokenAuthorityPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                action: AlloyDecodable::decode(payload)?,
+                authority_type: AlloyDecodable::decode(payload)?,
+                authority_address: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+                value: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+impl TokenAuthorityPayload {
+    /// Create a payload granting or revoking `authority_type`.
+    ///
+    /// `value` is the mint allowance for `MintBurnTokens` and ignored by
+    /// the L1 for other authorities.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        action: AuthorityAction,
+        authority_type: Authority,
+        authority_address: impl IntoAddress,
+        token: impl IntoAddress,
+        value: impl IntoAmount,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            action,
+            authority_type,
+            authority_address: authority_address.into_address("authority_address")?,
+            token: token.into_address("token")?,
+            value: value.into_amount("value")?,
+        })
+    }
+}
+
+payload_setters! {
+    TokenAuthorityPayload {
+        address authority_address => with_authority_address,
+        address token => with_token,
+        amount value => with_value,
+        plain action: AuthorityAction => with_action,
+        plain authority_type: Authority => with_authority_type,
     }
 }
 
 impl Signable for TokenAuthorityPayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -209,11 +293,9 @@ impl PauseAction {
     }
 }
 
-impl AlloyEncodable for PauseAction {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.as_str().encode(out);
-    }
-}
+impl_encodable_by_name!(PauseAction);
+
+impl_decodable_by_name!(PauseAction, [PauseAction::Pause, PauseAction::Unpause]);
 
 /// Token pause payload.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -228,34 +310,53 @@ pub struct TokenPausePayload {
     pub token: Address,
 }
 
-impl AlloyEncodable for TokenPausePayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.action.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
+rlp_list!(TokenPausePayload {
+    chain_id,
+    nonce,
+    action,
+    token
+});
+
+impl AlloyDecodable for TokenPausePayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                action: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
 
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+impl TokenPausePayload {
+    /// Create a payload pausing or unpausing a token.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        action: PauseAction,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            action,
+            token: token.into_address("token")?,
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+payload_setters! {
+    TokenPausePayload {
+        address token => with_token,
+        plain action: PauseAction => with_action,
     }
 }
 
 impl Signable for TokenPausePayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -279,11 +380,12 @@ impl BlacklistAction {
     }
 }
 
-impl AlloyEncodable for BlacklistAction {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.as_str().encode(out);
-    }
-}
+impl_encodable_by_name!(BlacklistAction);
+
+impl_decodable_by_name!(
+    BlacklistAction,
+    [BlacklistAction::Add, BlacklistAction::Remove]
+);
 
 /// Token blacklist management payload.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -300,35 +402,58 @@ pub struct TokenBlacklistPayload {
     pub token: Address,
 }
 
-impl AlloyEncodable for TokenBlacklistPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.action.encode(&mut temp_buf);
-        self.address.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenBlacklistPayload {
+    chain_id,
+    nonce,
+    action,
+    address,
+    token
+});
+
+impl AlloyDecodable for TokenBlacklistPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                action: AlloyDecodable::decode(payload)?,
+                address: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+impl TokenBlacklistPayload {
+    /// Create a payload adding `address` to or removing it from the blacklist.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        action: BlacklistAction,
+        address: impl IntoAddress,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            action,
+            address: address.into_address("address")?,
+            token: token.into_address("token")?,
+        })
+    }
+}
+
+payload_setters! {
+    TokenBlacklistPayload {
+        address address => with_address,
+        address token => with_token,
+        plain action: BlacklistAction => with_action,
     }
 }
 
 impl Signable for TokenBlacklistPayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -352,11 +477,12 @@ impl WhitelistAction {
     }
 }
 
-impl AlloyEncodable for WhitelistAction {
-    fn encode(&self, out: &mut dyn BufMut) {
-        self.as_str().encode(out);
-    }
-}
+impl_encodable_by_name!(WhitelistAction);
+
+impl_decodable_by_name!(
+    WhitelistAction,
+    [WhitelistAction::Add, WhitelistAction::Remove]
+);
 
 /// Token whitelist management payload.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -373,35 +499,58 @@ pub struct TokenWhitelistPayload {
     pub token: Address,
 }
 
-impl AlloyEncodable for TokenWhitelistPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.action.encode(&mut temp_buf);
-        self.address.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenWhitelistPayload {
+    chain_id,
+    nonce,
+    action,
+    address,
+    token
+});
+
+impl AlloyDecodable for TokenWhitelistPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                action: AlloyDecodable::decode(payload)?,
+                address: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+impl TokenWhitelistPayload {
+    /// Create a payload adding `address` to or removing it from the whitelist.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        action: WhitelistAction,
+        address: impl IntoAddress,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            action,
+            address: address.into_address("address")?,
+            token: token.into_address("token")?,
+        })
+    }
+}
+
+payload_setters! {
+    TokenWhitelistPayload {
+        address address => with_address,
+        address token => with_token,
+        plain action: WhitelistAction => with_action,
     }
 }
 
 impl Signable for TokenWhitelistPayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -422,36 +571,62 @@ pub struct TokenMetadataUpdatePayload {
     pub additional_metadata: Vec<MetadataKVPair>,
 }
 
-impl AlloyEncodable for TokenMetadataUpdatePayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.name.encode(&mut temp_buf);
-        self.uri.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-        self.additional_metadata.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
+rlp_list!(TokenMetadataUpdatePayload {
+    chain_id,
+    nonce,
+    name,
+    uri,
+    token,
+    additional_metadata
+});
+
+impl AlloyDecodable for TokenMetadataUpdatePayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                name: AlloyDecodable::decode(payload)?,
+                uri: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+                additional_metadata: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
+
+impl TokenMetadataUpdatePayload {
+    /// Create a metadata update payload without additional metadata.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        name: impl Into<String>,
+        uri: impl Into<String>,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            name: name.into(),
+            uri: uri.into(),
+            token: token.into_address("token")?,
+            additional_metadata: Vec::new(),
+        })
+    }
+}
 
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+payload_setters! {
+    TokenMetadataUpdatePayload {
+        address token => with_token,
+        plain name: String => with_name,
+        plain uri: String => with_uri,
+        plain additional_metadata: Vec<MetadataKVPair> => with_additional_metadata,
     }
 }
 
 impl Signable for TokenMetadataUpdatePayload {
     fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -524,8 +699,71 @@ pub struct UpdateMetadataRequest {
 mod tests {
     use super::*;
     use alloy_primitives::{Address, U256};
+    use alloy_rlp::Encodable as AlloyEncodable;
     use std::str::FromStr;
 
+    #[test]
+    fn test_payload_constructors_match_literals() {
+        let recipient = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf";
+        let token = Address::repeat_byte(0x22);
+
+        let mint = TokenMintPayload::new(1212101, 5, recipient, "1000000", token)
+            .expect("Constructor input should be valid");
+        assert_eq!(
+            mint,
+            TokenMintPayload {
+                chain_id: 1212101,
+                nonce: 5,
+                recipient: Address::from_str(recipient).expect("Test data should be valid"),
+                value: U256::from(1_000_000u64),
+                token,
+            }
+        );
+
+        let updated = mint
+            .clone()
+            .with_nonce(6)
+            .with_value(2_000_000u128)
+            .expect("Amount should be valid");
+        assert_eq!(updated.nonce, 6);
+        assert_eq!(updated.value, U256::from(2_000_000u64));
+        assert_eq!(updated.recipient, mint.recipient);
+
+        let authority = TokenAuthorityPayload::new(
+            1212101,
+            7,
+            AuthorityAction::Grant,
+            Authority::MintBurnTokens,
+            recipient,
+            token,
+            U256::from(10u64),
+        )
+        .expect("Constructor input should be valid")
+        .with_action(AuthorityAction::Revoke);
+        assert_eq!(authority.action, AuthorityAction::Revoke);
+
+        let metadata = TokenMetadataUpdatePayload::new(1212101, 8, "Name", "https://x", token)
+            .expect("Constructor input should be valid")
+            .with_additional_metadata(vec![MetadataKVPair::default()]);
+        assert_eq!(metadata.name, "Name");
+        assert_eq!(metadata.additional_metadata.len(), 1);
+    }
+
+    #[test]
+    fn test_payload_constructors_report_invalid_fields() {
+        let error = TokenBurnPayload::new(1, 0, "0x1234", 1u64, Address::ZERO)
+            .expect_err("Short address should be rejected");
+        assert!(error.to_string().contains("recipient"), "{}", error);
+
+        let error = TokenBlacklistPayload::new(1, 0, BlacklistAction::Add, Address::ZERO, "zz")
+            .expect_err("Invalid token should be rejected");
+        assert!(error.to_string().contains("token"), "{}", error);
+
+        let error = TokenMintPayload::new(1, 0, Address::ZERO, "1.5", Address::ZERO)
+            .expect_err("Fractional amount should be rejected");
+        assert!(error.to_string().contains("value"), "{}", error);
+    }
+
     #[test]
     fn test_token_mint_payload_decimal_serialization() {
         let payload = TokenMintPayload {
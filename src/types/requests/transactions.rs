@@ -1,9 +1,12 @@
 //! Transaction-related API request types.
 
+use super::rlp::{RlpFields, decode_list, rlp_list, signing_hash};
+use super::setters::payload_setters;
 use crate::Signature;
 use crate::crypto::Signable;
-use alloy_primitives::{Address, B256, U256, keccak256};
-use alloy_rlp::{BufMut, Encodable as AlloyEncodable};
+use crate::utils::{IntoAddress, IntoAmount};
+use alloy_primitives::{Address, B256, U256};
+use alloy_rlp::{BufMut, Decodable as AlloyDecodable, Encodable as AlloyEncodable};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::result::Result;
@@ -56,26 +59,55 @@ impl Display for PaymentPayload {
     }
 }
 
-impl AlloyEncodable for PaymentPayload {
-    fn encode(&self, out: &mut dyn BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.chain_id.encode(&mut temp_buf);
-        self.nonce.encode(&mut temp_buf);
-        self.recipient.encode(&mut temp_buf);
-        self.value.encode(&mut temp_buf);
-        self.token.encode(&mut temp_buf);
-
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
-
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+rlp_list!(PaymentPayload {
+    chain_id,
+    nonce,
+    recipient,
+    value,
+    token
+});
+
+impl AlloyDecodable for PaymentPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            Ok(Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                recipient: AlloyDecodable::decode(payload)?,
+                value: AlloyDecodable::decode(payload)?,
+                token: AlloyDecodable::decode(payload)?,
+            })
+        })
+    }
+}
+
+impl PaymentPayload {
+    /// Create a payment payload.
+    ///
+    /// Addresses accept [`Address`] values or address strings, amounts
+    /// integers or base-10 strings in the token's smallest unit.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        recipient: impl IntoAddress,
+        value: impl IntoAmount,
+        token: impl IntoAddress,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            recipient: recipient.into_address("recipient")?,
+            value: value.into_amount("value")?,
+            token: token.into_address("token")?,
+        })
+    }
+}
+
+payload_setters! {
+    PaymentPayload {
+        address recipient => with_recipient,
+        address token => with_token,
+        amount value => with_value,
     }
 }
 
@@ -83,10 +115,7 @@ impl PaymentPayload {
     /// Calculate the signature hash for this payload.
     /// This matches the L1 implementation's signature_hash method.
     pub fn signature_hash(&self) -> B256 {
-        // Use alloy_rlp encoding to match L1 exactly
-        let mut encoded = Vec::new();
-        self.encode(&mut encoded);
-        keccak256(&encoded)
+        signing_hash(self)
     }
 }
 
@@ -96,6 +125,120 @@ impl Signable for PaymentPayload {
     }
 }
 
+/// Payment of the chain's native asset.
+///
+/// Native transfers name no token. The payload is signed and posted like a
+/// [`PaymentPayload`] whose token field is absent: the RLP encoding carries
+/// an empty string in the token position, as RLP does for any absent
+/// address, and the JSON body omits `token`. The L1 reports these
+/// transfers as [`TxPayload::TokenTransfer`] with `token: None`.
+///
+/// [`TxPayload::TokenTransfer`]: crate::responses::TxPayload::TokenTransfer
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NativePaymentPayload {
+    /// Chain ID.
+    pub chain_id: u64,
+    /// Account nonce.
+    pub nonce: u64,
+    /// Recipient address.
+    pub recipient: Address,
+    /// Amount to transfer, in base units of the native asset.
+    #[serde(
+        serialize_with = "serialize_token_amount_decimal",
+        deserialize_with = "deserialize_token_amount_decimal"
+    )]
+    pub value: U256,
+}
+
+impl Display for NativePaymentPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Native payment to {}: value {}, nonce {}, chain {}",
+            self.recipient, self.value, self.nonce, self.chain_id
+        )
+    }
+}
+
+impl RlpFields for NativePaymentPayload {
+    fn encode_fields(&self, out: &mut dyn BufMut) {
+        self.chain_id.encode(out);
+        self.nonce.encode(out);
+        self.recipient.encode(out);
+        self.value.encode(out);
+        // No token: an empty string stands in for the absent address.
+        out.put_u8(alloy_rlp::EMPTY_STRING_CODE);
+    }
+
+    fn fields_length(&self) -> usize {
+        self.chain_id.length()
+            + self.nonce.length()
+            + self.recipient.length()
+            + self.value.length()
+            + 1
+    }
+}
+
+rlp_list!(@encodable NativePaymentPayload);
+
+impl AlloyDecodable for NativePaymentPayload {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        decode_list(buf, |payload| {
+            let decoded = Self {
+                chain_id: AlloyDecodable::decode(payload)?,
+                nonce: AlloyDecodable::decode(payload)?,
+                recipient: AlloyDecodable::decode(payload)?,
+                value: AlloyDecodable::decode(payload)?,
+            };
+            let token = alloy_rlp::Header::decode(payload)?;
+            if token.list || token.payload_length != 0 {
+                return Err(alloy_rlp::Error::Custom(
+                    "native payment must not name a token",
+                ));
+            }
+            Ok(decoded)
+        })
+    }
+}
+
+impl NativePaymentPayload {
+    /// Create a native payment payload.
+    ///
+    /// The recipient accepts an [`Address`] or an address string, the value
+    /// an integer or a base-10 string in base units.
+    pub fn new(
+        chain_id: u64,
+        nonce: u64,
+        recipient: impl IntoAddress,
+        value: impl IntoAmount,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            chain_id,
+            nonce,
+            recipient: recipient.into_address("recipient")?,
+            value: value.into_amount("value")?,
+        })
+    }
+
+    /// Calculate the signature hash for this payload.
+    pub fn signature_hash(&self) -> B256 {
+        signing_hash(self)
+    }
+}
+
+payload_setters! {
+    NativePaymentPayload {
+        address recipient => with_recipient,
+        amount value => with_value,
+    }
+}
+
+impl Signable for NativePaymentPayload {
+    fn signature_hash(&self) -> B256 {
+        NativePaymentPayload::signature_hash(self)
+    }
+}
+
 /// Payment transaction request.
 #[derive(Debug, Clone, Serialize)]
 pub struct PaymentRequest {
@@ -463,4 +606,32 @@ mod tests {
             "Signature hash should be consistent for edge case values"
         );
     }
+
+    #[test]
+    fn test_native_payment_payload_encoding() {
+        let recipient = Address::repeat_byte(0xaa);
+        let native = NativePaymentPayload::new(1212101, 9, recipient, "2500").unwrap();
+        let token_payment = PaymentPayload {
+            chain_id: 1212101,
+            nonce: 9,
+            recipient,
+            value: U256::from(2500u64),
+            token: Address::repeat_byte(0x55),
+        };
+
+        let mut encoded = Vec::new();
+        native.encode(&mut encoded);
+        assert_eq!(encoded.last(), Some(&alloy_rlp::EMPTY_STRING_CODE));
+        assert_ne!(native.signature_hash(), token_payment.signature_hash());
+
+        let mut token_encoded = Vec::new();
+        token_payment.encode(&mut token_encoded);
+        assert!(NativePaymentPayload::decode(&mut token_encoded.as_slice()).is_err());
+        assert!(PaymentPayload::decode(&mut encoded.as_slice()).is_err());
+
+        let json = serde_json::to_value(&native).unwrap();
+        assert_eq!(json["value"], "2500");
+        assert!(json.get("token").is_none());
+        assert!(native.to_string().starts_with("Native payment to"));
+    }
 }
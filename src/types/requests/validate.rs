@@ -0,0 +1,253 @@
+//! Client-side checks run on payloads before they are signed.
+
+use crate::requests::{
+    NativePaymentPayload, PaymentPayload, TokenAuthorityPayload, TokenBlacklistPayload,
+    TokenBurnPayload, TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload,
+    TokenWhitelistPayload,
+};
+use crate::{Error, Result};
+use alloy_primitives::Address;
+
+#[cfg(feature = "bridge")]
+use crate::requests::{TokenBridgeAndMintPayload, TokenBurnAndBridgePayload};
+
+/// Most `additional_metadata` entries a metadata update may carry.
+pub const MAX_ADDITIONAL_METADATA_ENTRIES: usize = 32;
+
+/// Checks that catch payloads the L1 would reject for their content alone.
+///
+/// The client runs [`Validate::validate`] before signing, so a malformed
+/// payload costs neither a signature nor a round trip. State-dependent
+/// conditions such as balances, authorities and nonces are left to the L1.
+pub trait Validate {
+    /// Return [`Error::Validation`] naming the first offending field.
+    fn validate(&self) -> Result<()>;
+}
+
+fn non_zero(field: &str, address: Address) -> Result<()> {
+    if address.is_zero() {
+        return Err(Error::validation(field, "Must not be the zero address"));
+    }
+    Ok(())
+}
+
+fn non_blank(field: &str, value: &str) -> Result<()> {
+    if value.trim().is_empty() {
+        return Err(Error::validation(field, "Must not be empty"));
+    }
+    Ok(())
+}
+
+impl Validate for PaymentPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("recipient", self.recipient)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for NativePaymentPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("recipient", self.recipient)
+    }
+}
+
+impl Validate for TokenMintPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("recipient", self.recipient)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenBurnPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("recipient", self.recipient)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenAuthorityPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("authority_address", self.authority_address)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenPausePayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenBlacklistPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("address", self.address)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenWhitelistPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("address", self.address)?;
+        non_zero("token", self.token)
+    }
+}
+
+impl Validate for TokenMetadataUpdatePayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("token", self.token)?;
+        non_blank("name", &self.name)?;
+        non_blank("uri", &self.uri)?;
+        if self.additional_metadata.len() > MAX_ADDITIONAL_METADATA_ENTRIES {
+            return Err(Error::validation(
+                "additional_metadata",
+                format!(
+                    "{} entries exceed the limit of {}",
+                    self.additional_metadata.len(),
+                    MAX_ADDITIONAL_METADATA_ENTRIES
+                ),
+            ));
+        }
+        if let Some(index) = self
+            .additional_metadata
+            .iter()
+            .position(|pair| pair.key.trim().is_empty())
+        {
+            return Err(Error::validation(
+                "additional_metadata",
+                format!("Entry {} has an empty key", index),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bridge")]
+impl Validate for TokenBridgeAndMintPayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("recipient", self.recipient)?;
+        non_zero("token", self.token)
+    }
+}
+
+#[cfg(feature = "bridge")]
+impl Validate for TokenBurnAndBridgePayload {
+    fn validate(&self) -> Result<()> {
+        non_zero("sender", self.sender)?;
+        non_zero("token", self.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::PauseAction;
+    use crate::responses::MetadataKVPair;
+    use alloy_primitives::U256;
+
+    fn field_of(result: Result<()>) -> String {
+        match result {
+            Err(Error::Validation { field, .. }) => field,
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_addresses() {
+        let payment = PaymentPayload {
+            chain_id: 1212101,
+            nonce: 0,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1u64),
+            token: Address::repeat_byte(0x22),
+        };
+        assert!(payment.validate().is_ok());
+        assert_eq!(
+            field_of(
+                PaymentPayload {
+                    recipient: Address::ZERO,
+                    ..payment.clone()
+                }
+                .validate()
+            ),
+            "recipient"
+        );
+        assert_eq!(
+            field_of(
+                PaymentPayload {
+                    token: Address::ZERO,
+                    ..payment
+                }
+                .validate()
+            ),
+            "token"
+        );
+
+        let pause = TokenPausePayload {
+            chain_id: 1212101,
+            nonce: 0,
+            action: PauseAction::Pause,
+            token: Address::ZERO,
+        };
+        assert_eq!(field_of(pause.validate()), "token");
+    }
+
+    #[test]
+    fn test_validate_metadata_update() {
+        let pair = |key: &str| MetadataKVPair {
+            key: key.to_string(),
+            value: "value".to_string(),
+        };
+        let update = TokenMetadataUpdatePayload {
+            chain_id: 1212101,
+            nonce: 0,
+            name: "Test Token".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            token: Address::repeat_byte(0x22),
+            additional_metadata: vec![pair("issuer")],
+        };
+        assert!(update.validate().is_ok());
+
+        let blank_name = TokenMetadataUpdatePayload {
+            name: "  ".to_string(),
+            ..update.clone()
+        };
+        assert_eq!(field_of(blank_name.validate()), "name");
+        let empty_uri = TokenMetadataUpdatePayload {
+            uri: String::new(),
+            ..update.clone()
+        };
+        assert_eq!(field_of(empty_uri.validate()), "uri");
+        let empty_key = TokenMetadataUpdatePayload {
+            additional_metadata: vec![pair("issuer"), pair("")],
+            ..update.clone()
+        };
+        assert_eq!(field_of(empty_key.validate()), "additional_metadata");
+
+        let at_limit = TokenMetadataUpdatePayload {
+            additional_metadata: vec![pair("key"); MAX_ADDITIONAL_METADATA_ENTRIES],
+            ..update.clone()
+        };
+        assert!(at_limit.validate().is_ok());
+        let over_limit = TokenMetadataUpdatePayload {
+            additional_metadata: vec![pair("key"); MAX_ADDITIONAL_METADATA_ENTRIES + 1],
+            ..update.clone()
+        };
+        assert_eq!(field_of(over_limit.validate()), "additional_metadata");
+    }
+
+    #[cfg(feature = "client")]
+    #[tokio::test]
+    async fn test_client_validates_before_signing() {
+        let client = crate::Client::local().expect("Local client should build");
+        let payload = NativePaymentPayload {
+            chain_id: 1212101,
+            nonce: 0,
+            recipient: Address::ZERO,
+            value: U256::from(1u64),
+        };
+
+        // An unparsable key proves no signature was attempted.
+        let result = client.send_native(payload, "not a key").await.map(|_| ());
+        assert_eq!(field_of(result), "recipient");
+    }
+}
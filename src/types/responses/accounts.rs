@@ -1,5 +1,7 @@
 //! Account-related API response types.
 
+use crate::utils::format_units;
+use alloy_primitives::{Address, U256};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -53,6 +55,41 @@ impl Display for AssociatedTokenAccount {
     }
 }
 
+/// A token balance in base units together with the token's decimals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenBalance {
+    /// The token mint address.
+    pub token: Address,
+    /// Balance in the token's smallest unit.
+    pub amount: U256,
+    /// Number of decimals the token uses.
+    pub decimals: u8,
+}
+
+impl TokenBalance {
+    /// Create a new token balance.
+    pub fn new(token: Address, amount: U256, decimals: u8) -> Self {
+        Self {
+            token,
+            amount,
+            decimals,
+        }
+    }
+
+    /// Format the balance as a decimal string, e.g. `1.5` for `1500000` with 6 decimals.
+    ///
+    /// Trailing fractional zeros are trimmed.
+    pub fn to_decimal_string(&self) -> String {
+        format_units(self.amount, self.decimals)
+    }
+}
+
+impl Display for TokenBalance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} (token: {})", self.to_decimal_string(), self.token)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,4 +349,28 @@ mod tests {
         assert!(json.contains("balance"));
         assert!(json.contains("nonce"));
     }
+
+    #[test]
+    fn test_token_balance_decimal_formatting() {
+        let token = Address::ZERO;
+        let cases = [
+            (1_500_000u64, 6u8, "1.5"),
+            (1_000_000, 6, "1"),
+            (1, 6, "0.000001"),
+            (0, 6, "0"),
+            (123, 0, "123"),
+            (10_000_000_000_000_000_000, 18, "10"),
+        ];
+
+        for (amount, decimals, expected) in cases {
+            let balance = TokenBalance::new(token, U256::from(amount), decimals);
+            assert_eq!(balance.to_decimal_string(), expected);
+        }
+
+        let balance = TokenBalance::new(token, U256::from(25u64), 1);
+        assert_eq!(
+            balance.to_string(),
+            "2.5 (token: 0x0000000000000000000000000000000000000000)"
+        );
+    }
 }
@@ -40,18 +40,24 @@ pub struct Checkpoint {
     /// Hash of the checkpoint.
     pub hash: Hash,
     /// Hash of the parent.
+    #[serde(alias = "parentHash")]
     pub parent_hash: Hash,
     /// State root hash.
+    #[serde(alias = "stateRoot")]
     pub state_root: Hash,
     /// Transactions root hash.
+    #[serde(alias = "transactionsRoot")]
     pub transactions_root: Hash,
     /// Transactions receipts root hash.
+    #[serde(alias = "receiptsRoot")]
     pub receipts_root: Hash,
     /// Checkpoint number.
+    #[serde(alias = "checkpoint_number", alias = "checkpointNumber")]
     pub number: u64,
     /// Timestamp.
     pub timestamp: u64,
     /// Extra data.
+    #[serde(alias = "extraData")]
     pub extra_data: String,
     /// Checkpoint transactions (either hashes or full objects).
     pub transactions: CheckpointTransactions,
@@ -130,18 +136,24 @@ pub struct CheckpointHeader {
     /// Hash of the checkpoint.
     pub hash: Hash,
     /// Hash of the parent.
+    #[serde(alias = "parentHash")]
     pub parent_hash: Hash,
     /// State root hash.
+    #[serde(alias = "stateRoot")]
     pub state_root: Hash,
     /// Transactions root hash.
+    #[serde(alias = "transactionsRoot")]
     pub transactions_root: Hash,
     /// Transactions receipts root hash.
+    #[serde(alias = "receiptsRoot")]
     pub receipts_root: Hash,
     /// Checkpoint number.
+    #[serde(alias = "checkpoint_number", alias = "checkpointNumber")]
     pub number: u64,
     /// Timestamp.
     pub timestamp: u64,
     /// Extra data.
+    #[serde(alias = "extraData")]
     pub extra_data: String,
 }
 
@@ -159,6 +171,11 @@ impl Display for CheckpointHeader {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckpointNumber {
     /// Current checkpoint number.
+    #[serde(
+        alias = "checkpoint",
+        alias = "checkpoint_number",
+        alias = "checkpointNumber"
+    )]
     pub number: u64,
 }
 
@@ -413,4 +430,42 @@ mod tests {
         // with all required fields. For now, we've tested the Hashes variant which works correctly.
         // The untagged enum will automatically choose the correct variant based on the JSON structure.
     }
+
+    #[test]
+    fn test_checkpoint_number_aliases() {
+        for key in [
+            "number",
+            "checkpoint",
+            "checkpoint_number",
+            "checkpointNumber",
+        ] {
+            let json = format!(r#"{{"{key}": 1500}}"#);
+            let parsed: CheckpointNumber =
+                serde_json::from_str(&json).expect("Aliased checkpoint number should parse");
+            assert_eq!(parsed.number, 1500, "alias {key} should map to number");
+        }
+
+        let serialized = serde_json::to_string(&CheckpointNumber { number: 1 })
+            .expect("Checkpoint number should serialize");
+        assert_eq!(serialized, r#"{"number":1}"#);
+    }
+
+    #[test]
+    fn test_checkpoint_header_camel_case_aliases() {
+        let json = r#"{
+            "hash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+            "parentHash": "0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f",
+            "stateRoot": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+            "transactionsRoot": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+            "receiptsRoot": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+            "checkpointNumber": 9,
+            "timestamp": 1700000000,
+            "extraData": ""
+        }"#;
+
+        let header: CheckpointHeader =
+            serde_json::from_str(json).expect("camelCase header should parse");
+        assert_eq!(header.number, 9);
+        assert_eq!(header.timestamp, 1700000000);
+    }
 }
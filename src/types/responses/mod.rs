@@ -8,6 +8,7 @@ pub mod accounts;
 pub mod chains;
 pub mod checkpoints;
 pub mod governance;
+pub mod pagination;
 pub mod tokens;
 pub mod transactions;
 
@@ -33,6 +34,7 @@ pub use accounts::*;
 pub use chains::*;
 pub use checkpoints::*;
 pub use governance::*;
+pub use pagination::*;
 pub use tokens::*;
 pub use transactions::*;
 
@@ -0,0 +1,166 @@
+//! Paginated list responses.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+/// One page of a list endpoint.
+///
+/// Endpoints that do not paginate return a bare JSON array; it decodes as a
+/// single page without cursors or total.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(
+    from = "PageRepr<T>",
+    bound(deserialize = "T: Deserialize<'de>", serialize = "T: Serialize")
+)]
+pub struct Page<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+    /// Cursor of the following page, if there is one.
+    pub next_cursor: Option<String>,
+    /// Cursor of the preceding page, if there is one.
+    pub prev_cursor: Option<String>,
+    /// Number of items across every page, when the server reports it.
+    pub total: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PageRepr<T> {
+    Paged {
+        items: Vec<T>,
+        #[serde(default)]
+        next_cursor: Option<String>,
+        #[serde(default)]
+        prev_cursor: Option<String>,
+        #[serde(default)]
+        total: Option<u64>,
+    },
+    List(Vec<T>),
+}
+
+impl<T> From<PageRepr<T>> for Page<T> {
+    fn from(repr: PageRepr<T>) -> Self {
+        match repr {
+            PageRepr::Paged {
+                items,
+                next_cursor,
+                prev_cursor,
+                total,
+            } => Self {
+                items,
+                next_cursor: next_cursor.filter(|cursor| !cursor.is_empty()),
+                prev_cursor: prev_cursor.filter(|cursor| !cursor.is_empty()),
+                total,
+            },
+            PageRepr::List(items) => Self::new(items),
+        }
+    }
+}
+
+impl<T> Page<T> {
+    /// A single page holding every item.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            next_cursor: None,
+            prev_cursor: None,
+            total: None,
+        }
+    }
+
+    /// Number of items on this page.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether this page holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Whether a following page exists.
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// Iterate over the items on this page.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Take the items, dropping the cursors.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T> Display for Page<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Page of {} items", self.items.len())?;
+        if let Some(total) = self.total {
+            write!(f, " (total: {})", total)?;
+        }
+        if self.has_next() {
+            write!(f, ", more available")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_from_bare_array() {
+        let page: Page<u64> = serde_json::from_str("[1, 2, 3]").expect("Test data should be valid");
+        assert_eq!(page, Page::new(vec![1, 2, 3]));
+        assert!(!page.has_next());
+        assert_eq!(page.into_iter().sum::<u64>(), 6);
+    }
+
+    #[test]
+    fn test_page_from_object() {
+        let json = r#"{"items": [4, 5], "next_cursor": "abc", "prev_cursor": "", "total": 9}"#;
+        let page: Page<u64> = serde_json::from_str(json).expect("Test data should be valid");
+        assert_eq!(page.items, vec![4, 5]);
+        assert_eq!(page.next_cursor.as_deref(), Some("abc"));
+        assert_eq!(page.prev_cursor, None);
+        assert_eq!(page.total, Some(9));
+        assert_eq!(
+            page.to_string(),
+            "Page of 2 items (total: 9), more available"
+        );
+
+        let round_trip: Page<u64> =
+            serde_json::from_str(&serde_json::to_string(&page).expect("Should serialize"))
+                .expect("Should deserialize");
+        assert_eq!(round_trip, page);
+    }
+}
@@ -1,6 +1,10 @@
 //! Token-related API response types.
 
-use alloy_primitives::Address;
+use crate::registry::{AddressBook, LabeledDisplay};
+use crate::requests::authorities::Authority;
+use crate::utils::format_units;
+use crate::{Error, Result};
+use alloy_primitives::{Address, U256};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
@@ -31,25 +35,32 @@ pub struct MintInfo {
     /// mint any more tokens until the allowance is updated.
     ///
     /// The allowance to burn is unlimited. Maximum of 20 authorities.
+    #[serde(default)]
     pub mint_burn_authorities: Vec<MinterAllowance>,
 
     /// The authorities to pause/unpause token transactions. Maximum of 5
     /// authorities.
+    #[serde(default)]
     pub pause_authorities: Vec<Address>,
 
     /// The authorities to blacklist/whitelist malicious accounts
+    #[serde(default)]
     pub list_authorities: Vec<Address>,
 
     /// A blacklist of token accounts
+    #[serde(default)]
     pub black_list: Vec<Address>,
 
     /// A whitelist of token accounts. Only used if the token is private
+    #[serde(default)]
     pub white_list: Vec<Address>,
 
     /// The authorities for updating the metadata. Maximum of 5 authorities.
+    #[serde(default)]
     pub metadata_update_authorities: Vec<Address>,
 
     /// The authorities for bridge operations (minting from bridge).
+    #[serde(default)]
     pub bridge_mint_authorities: Vec<Address>,
 
     /// Total supply of tokens.
@@ -59,23 +70,457 @@ pub struct MintInfo {
     pub decimals: u8,
 
     /// `true` if all transactions for this token are paused
+    #[serde(default)]
     pub is_paused: bool,
 
     /// `true` if this token is private and only whitelisted addresses can
     /// operate with the tokens
+    #[serde(default)]
     pub is_private: bool,
 
     /// Metadata of the token
     pub meta: Option<TokenMetadata>,
 }
 
+impl MintInfo {
+    /// Whether `address` currently holds `authority` on this token.
+    pub fn has_authority(&self, authority: Authority, address: Address) -> bool {
+        match authority {
+            Authority::MasterMintBurn => self.master_mint_burn_authority == address,
+            Authority::MintBurnTokens => self
+                .mint_burn_authorities
+                .iter()
+                .any(|allowance| allowance.minter == address),
+            Authority::Pause => self.pause_authorities.contains(&address),
+            Authority::ManageList => self.list_authorities.contains(&address),
+            Authority::UpdateMetadata => self.metadata_update_authorities.contains(&address),
+            Authority::Bridge => self.bridge_mint_authorities.contains(&address),
+        }
+    }
+
+    /// Remaining mint allowance of `minter`, or zero if it is not a minter.
+    pub fn mint_allowance(&self, minter: Address) -> Result<U256> {
+        self.mint_burn_authorities
+            .iter()
+            .find(|allowance| allowance.minter == minter)
+            .map_or(Ok(U256::ZERO), MinterAllowance::amount)
+    }
+
+    /// Blacklist and whitelist standing of `address` on this token.
+    pub fn list_membership(&self, address: Address) -> ListMembership {
+        ListMembership {
+            address,
+            blacklisted: self.black_list.contains(&address),
+            whitelisted: self.white_list.contains(&address),
+            is_private: self.is_private,
+        }
+    }
+}
+
+/// Whether an address is on a token's blacklist or whitelist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListMembership {
+    /// The address checked.
+    pub address: Address,
+    /// The address is on the token's blacklist.
+    pub blacklisted: bool,
+    /// The address is on the token's whitelist.
+    pub whitelisted: bool,
+    /// The token is private, so only whitelisted addresses may use it.
+    pub is_private: bool,
+}
+
+impl ListMembership {
+    /// Whether the lists allow the address to operate with the token.
+    ///
+    /// This does not account for the token being paused.
+    pub fn is_allowed(&self) -> bool {
+        !self.blacklisted && (!self.is_private || self.whitelisted)
+    }
+}
+
+impl Display for ListMembership {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "List Membership:\n  Address: {}\n  Blacklisted: {}\n  Whitelisted: {}\n  Private Token: {}",
+            self.address, self.blacklisted, self.whitelisted, self.is_private
+        )
+    }
+}
+
+/// A mint/burn authority and the amount it may still mint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MintBurnAuthority {
+    /// Address holding the `MintBurnTokens` authority.
+    pub minter: Address,
+    /// Remaining mint allowance, in base units. Burning is not limited.
+    pub allowance: U256,
+}
+
+/// Who holds which authority on a token.
+///
+/// Built from [`MintInfo`] with allowances parsed, so authority checks do
+/// not need to walk the metadata's lists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenAuthorities {
+    /// The token mint address.
+    pub token: Address,
+    /// Grants every other authority.
+    pub master: Address,
+    /// Grants and revokes mint/burn authorities.
+    pub master_mint_burn: Address,
+    /// Accounts that may mint up to their allowance and burn.
+    pub mint_burn: Vec<MintBurnAuthority>,
+    /// Accounts that may pause and unpause the token.
+    pub pause: Vec<Address>,
+    /// Accounts that may manage the blacklist and whitelist.
+    pub list: Vec<Address>,
+    /// Accounts that may update the token metadata.
+    pub metadata: Vec<Address>,
+    /// Accounts that may mint tokens bridged in from other chains.
+    pub bridge_mint: Vec<Address>,
+}
+
+impl TokenAuthorities {
+    /// Collect the authorities of `token` from its metadata.
+    pub fn from_mint_info(token: Address, mint: &MintInfo) -> Result<Self> {
+        let mint_burn = mint
+            .mint_burn_authorities
+            .iter()
+            .map(|allowance| {
+                Ok(MintBurnAuthority {
+                    minter: allowance.minter,
+                    allowance: allowance.amount()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            token,
+            master: mint.master_authority,
+            master_mint_burn: mint.master_mint_burn_authority,
+            mint_burn,
+            pause: mint.pause_authorities.clone(),
+            list: mint.list_authorities.clone(),
+            metadata: mint.metadata_update_authorities.clone(),
+            bridge_mint: mint.bridge_mint_authorities.clone(),
+        })
+    }
+
+    /// Whether `address` is the token's master authority.
+    pub fn is_master(&self, address: Address) -> bool {
+        self.master == address
+    }
+
+    /// Whether `address` holds `authority`.
+    pub fn has(&self, authority: Authority, address: Address) -> bool {
+        match authority {
+            Authority::MasterMintBurn => self.master_mint_burn == address,
+            Authority::MintBurnTokens => self.minter(address).is_some(),
+            Authority::Pause => self.pause.contains(&address),
+            Authority::ManageList => self.list.contains(&address),
+            Authority::UpdateMetadata => self.metadata.contains(&address),
+            Authority::Bridge => self.bridge_mint.contains(&address),
+        }
+    }
+
+    /// Every authority `address` holds, in declaration order.
+    pub fn held_by(&self, address: Address) -> Vec<Authority> {
+        [
+            Authority::MasterMintBurn,
+            Authority::MintBurnTokens,
+            Authority::Pause,
+            Authority::ManageList,
+            Authority::UpdateMetadata,
+            Authority::Bridge,
+        ]
+        .into_iter()
+        .filter(|authority| self.has(*authority, address))
+        .collect()
+    }
+
+    /// Remaining mint allowance of `address`, or zero if it is not a minter.
+    pub fn mint_allowance(&self, address: Address) -> U256 {
+        self.minter(address)
+            .map_or(U256::ZERO, |authority| authority.allowance)
+    }
+
+    /// Whether `address` may mint `amount` now.
+    pub fn can_mint(&self, address: Address, amount: U256) -> bool {
+        self.minter(address)
+            .is_some_and(|authority| authority.allowance >= amount)
+    }
+
+    /// Whether `address` may burn tokens.
+    pub fn can_burn(&self, address: Address) -> bool {
+        self.minter(address).is_some()
+    }
+
+    /// Whether `address` may pause or unpause the token.
+    pub fn can_pause(&self, address: Address) -> bool {
+        self.has(Authority::Pause, address)
+    }
+
+    /// Whether `address` may change the blacklist or whitelist.
+    pub fn can_manage_lists(&self, address: Address) -> bool {
+        self.has(Authority::ManageList, address)
+    }
+
+    /// Whether `address` may update the token metadata.
+    pub fn can_update_metadata(&self, address: Address) -> bool {
+        self.has(Authority::UpdateMetadata, address)
+    }
+
+    /// Whether `address` may mint bridged tokens.
+    pub fn can_bridge_mint(&self, address: Address) -> bool {
+        self.has(Authority::Bridge, address)
+    }
+
+    /// Why `signer` may not perform `operation`, or `None` if it may.
+    ///
+    /// Granting or revoking `MintBurnTokens` needs the master or the master
+    /// mint/burn authority; every other authority is managed by the master.
+    pub fn denial(
+        &self,
+        signer: Address,
+        operation: TokenOperation,
+    ) -> Option<AuthorizationDenial> {
+        let required = match operation {
+            TokenOperation::Mint(requested) => {
+                let Some(minter) = self.minter(signer) else {
+                    return Some(AuthorizationDenial::MissingAuthority {
+                        signer,
+                        required: Authority::MintBurnTokens,
+                    });
+                };
+                return (minter.allowance < requested).then_some(
+                    AuthorizationDenial::InsufficientAllowance {
+                        signer,
+                        requested,
+                        remaining: minter.allowance,
+                    },
+                );
+            }
+            TokenOperation::ManageAuthority(Authority::MintBurnTokens)
+                if self.is_master(signer) =>
+            {
+                return None;
+            }
+            TokenOperation::ManageAuthority(Authority::MintBurnTokens) => Authority::MasterMintBurn,
+            TokenOperation::ManageAuthority(_) => {
+                return (!self.is_master(signer)).then_some(AuthorizationDenial::NotMaster {
+                    signer,
+                    master: self.master,
+                });
+            }
+            TokenOperation::Burn => Authority::MintBurnTokens,
+            TokenOperation::Pause => Authority::Pause,
+            TokenOperation::ManageList => Authority::ManageList,
+            TokenOperation::UpdateMetadata => Authority::UpdateMetadata,
+            TokenOperation::BridgeMint => Authority::Bridge,
+        };
+        (!self.has(required, signer))
+            .then_some(AuthorizationDenial::MissingAuthority { signer, required })
+    }
+
+    fn minter(&self, address: Address) -> Option<&MintBurnAuthority> {
+        self.mint_burn
+            .iter()
+            .find(|authority| authority.minter == address)
+    }
+}
+
+impl Display for TokenAuthorities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Token Authorities of {}:", self.token)?;
+        writeln!(f, "  Master: {}", self.master)?;
+        writeln!(f, "  Master Mint/Burn: {}", self.master_mint_burn)?;
+        for authority in &self.mint_burn {
+            writeln!(
+                f,
+                "  Mint/Burn: {} (allowance {})",
+                authority.minter, authority.allowance
+            )?;
+        }
+        for (label, addresses) in [
+            ("Pause", &self.pause),
+            ("List", &self.list),
+            ("Metadata", &self.metadata),
+            ("Bridge Mint", &self.bridge_mint),
+        ] {
+            for address in addresses {
+                writeln!(f, "  {}: {}", label, address)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A token operation whose signer can be checked against
+/// [`TokenAuthorities`] before it is submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenOperation {
+    /// Mint this many base units.
+    Mint(U256),
+    /// Burn tokens; burning is not limited by the allowance.
+    Burn,
+    /// Pause or unpause the token.
+    Pause,
+    /// Add to or remove from the blacklist or whitelist.
+    ManageList,
+    /// Update the token metadata.
+    UpdateMetadata,
+    /// Mint tokens bridged in from another chain.
+    BridgeMint,
+    /// Grant or revoke this authority.
+    ManageAuthority(Authority),
+}
+
+impl Display for TokenOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            TokenOperation::Mint(amount) => write!(f, "mint {}", amount),
+            TokenOperation::Burn => write!(f, "burn"),
+            TokenOperation::Pause => write!(f, "pause"),
+            TokenOperation::ManageList => write!(f, "manage lists"),
+            TokenOperation::UpdateMetadata => write!(f, "update metadata"),
+            TokenOperation::BridgeMint => write!(f, "bridge mint"),
+            TokenOperation::ManageAuthority(authority) => {
+                write!(f, "grant or revoke {}", authority)
+            }
+        }
+    }
+}
+
+/// Why a signer may not perform a [`TokenOperation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthorizationDenial {
+    /// The signer does not hold the authority the operation needs.
+    MissingAuthority {
+        /// The signer that was checked.
+        signer: Address,
+        /// The authority the operation needs.
+        required: Authority,
+    },
+    /// Only the token's master authority may perform the operation.
+    NotMaster {
+        /// The signer that was checked.
+        signer: Address,
+        /// The token's master authority.
+        master: Address,
+    },
+    /// The signer is a minter but its allowance does not cover the amount.
+    InsufficientAllowance {
+        /// The signer that was checked.
+        signer: Address,
+        /// Amount the operation would mint.
+        requested: U256,
+        /// Amount the signer may still mint.
+        remaining: U256,
+    },
+}
+
+impl Display for AuthorizationDenial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AuthorizationDenial::MissingAuthority { signer, required } => {
+                write!(f, "{} does not hold the {} authority", signer, required)
+            }
+            AuthorizationDenial::NotMaster { signer, master } => {
+                write!(f, "{} is not the master authority {}", signer, master)
+            }
+            AuthorizationDenial::InsufficientAllowance {
+                signer,
+                requested,
+                remaining,
+            } => write!(
+                f,
+                "{} may mint {} more but {} was requested",
+                signer, remaining, requested
+            ),
+        }
+    }
+}
+
+impl From<AuthorizationDenial> for Error {
+    fn from(denial: AuthorizationDenial) -> Self {
+        Error::authorization(denial.to_string())
+    }
+}
+
+/// Circulating supply of a token as of a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenSupply {
+    /// The token mint address.
+    pub token: Address,
+    /// Tokens in existence, in base units.
+    pub circulating: U256,
+    /// Number of decimals the token uses.
+    pub decimals: u8,
+    /// Checkpoint the supply was observed at.
+    pub checkpoint: u64,
+}
+
+impl TokenSupply {
+    /// Format the supply as a decimal string scaled by the token's decimals.
+    pub fn to_decimal_string(&self) -> String {
+        format_units(self.circulating, self.decimals)
+    }
+}
+
+impl Display for TokenSupply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Supply of {} at checkpoint {}: {}",
+            self.token,
+            self.checkpoint,
+            self.to_decimal_string()
+        )
+    }
+}
+
+/// Supply of a token at a past checkpoint and the mints and burns since.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SupplyHistory {
+    /// Supply at the requested checkpoint.
+    pub at: TokenSupply,
+    /// Supply at the latest checkpoint.
+    pub current: TokenSupply,
+    /// Tokens minted after `at`, including bridge mints.
+    pub minted: U256,
+    /// Tokens burned after `at`, including burns for bridging.
+    pub burned: U256,
+}
+
+impl Display for SupplyHistory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Supply History:\n  Checkpoint {}: {}\n  Checkpoint {}: {}\n  Minted: {}\n  Burned: {}",
+            self.at.checkpoint,
+            self.at.to_decimal_string(),
+            self.current.checkpoint,
+            self.current.to_decimal_string(),
+            format_units(self.minted, self.at.decimals),
+            format_units(self.burned, self.at.decimals)
+        )
+    }
+}
+
 impl Display for MintInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_labeled(f, &AddressBook::new())
+    }
+}
+
+impl LabeledDisplay for MintInfo {
+    fn fmt_labeled(&self, f: &mut Formatter<'_>, book: &AddressBook) -> FmtResult {
         write!(
             f,
             "Token Info:\n  Symbol: {}\n  Master Authority: {}\n  Supply: {}\n  Decimals: {}\n  Paused: {}\n  Private: {}",
             self.symbol,
-            self.master_authority,
+            book.name(&self.master_authority),
             self.supply,
             self.decimals,
             self.is_paused,
@@ -90,6 +535,15 @@ pub struct MinterAllowance {
     pub allowance: String,
 }
 
+impl MinterAllowance {
+    /// The allowance as an amount in the token's smallest unit.
+    pub fn amount(&self) -> Result<U256> {
+        U256::from_str_radix(&self.allowance, 10).map_err(|e| {
+            Error::response_deserialization("decimal", e.to_string(), self.allowance.as_str())
+        })
+    }
+}
+
 impl Display for MinterAllowance {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "Minter: {} (Allowance: {})", self.minter, self.allowance)
@@ -106,6 +560,7 @@ pub struct TokenMetadata {
     pub uri: String,
 
     /// must avoid storing the same key twice
+    #[serde(default)]
     pub additional_metadata: Vec<MetadataKVPair>,
 }
 
@@ -136,23 +591,16 @@ impl Display for MetadataKVPair {
     }
 }
 
-impl alloy_rlp::Encodable for MetadataKVPair {
-    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
-        // Calculate the actual payload length by encoding to a temporary buffer first
-        let mut temp_buf = Vec::new();
-
-        self.key.encode(&mut temp_buf);
-        self.value.encode(&mut temp_buf);
+crate::requests::rlp::rlp_list!(MetadataKVPair { key, value });
 
-        // Now encode the proper header with correct payload length
-        alloy_rlp::Header {
-            list: true,
-            payload_length: temp_buf.len(),
-        }
-        .encode(out);
-
-        // Write the actual payload
-        out.put_slice(&temp_buf);
+impl alloy_rlp::Decodable for MetadataKVPair {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        crate::requests::rlp::decode_list(buf, |payload| {
+            Ok(Self {
+                key: alloy_rlp::Decodable::decode(payload)?,
+                value: alloy_rlp::Decodable::decode(payload)?,
+            })
+        })
     }
 }
 
@@ -294,6 +742,55 @@ mod tests {
         assert_eq!(mint_info1, cloned);
     }
 
+    #[test]
+    fn test_mint_allowance_lookup() {
+        let minter = Address::repeat_byte(0x01);
+        let info = MintInfo {
+            mint_burn_authorities: vec![MinterAllowance {
+                minter,
+                allowance: "2500".to_string(),
+            }],
+            ..MintInfo::default()
+        };
+
+        assert_eq!(
+            info.mint_allowance(minter).expect("Allowance should parse"),
+            U256::from(2500u64)
+        );
+        assert_eq!(
+            info.mint_allowance(Address::repeat_byte(0x02))
+                .expect("Non-minters have no allowance"),
+            U256::ZERO
+        );
+
+        let malformed = MinterAllowance {
+            minter,
+            allowance: "lots".to_string(),
+        };
+        assert!(malformed.amount().is_err());
+    }
+
+    #[test]
+    fn test_list_membership() {
+        let blocked = Address::repeat_byte(0x01);
+        let listed = Address::repeat_byte(0x02);
+        let outsider = Address::repeat_byte(0x03);
+        let mut info = MintInfo {
+            black_list: vec![blocked],
+            white_list: vec![listed],
+            ..MintInfo::default()
+        };
+
+        assert!(info.list_membership(blocked).blacklisted);
+        assert!(!info.list_membership(blocked).is_allowed());
+        assert!(info.list_membership(outsider).is_allowed());
+
+        info.is_private = true;
+        assert!(info.list_membership(listed).whitelisted);
+        assert!(info.list_membership(listed).is_allowed());
+        assert!(!info.list_membership(outsider).is_allowed());
+    }
+
     #[test]
     fn test_minter_allowance_structure() {
         let address =
@@ -611,6 +1108,58 @@ mod tests {
         assert_eq!(deserialized.supply, "0");
     }
 
+    #[test]
+    fn test_token_authorities_denial() {
+        let master = Address::repeat_byte(0x01);
+        let master_mint_burn = Address::repeat_byte(0x02);
+        let minter = Address::repeat_byte(0x03);
+        let mint_info = MintInfo {
+            master_authority: master,
+            master_mint_burn_authority: master_mint_burn,
+            mint_burn_authorities: vec![MinterAllowance {
+                minter,
+                allowance: "500".to_string(),
+            }],
+            ..MintInfo::default()
+        };
+        let authorities = TokenAuthorities::from_mint_info(Address::repeat_byte(0x22), &mint_info)
+            .expect("Allowances should parse");
+
+        assert_eq!(
+            authorities.denial(minter, TokenOperation::Mint(U256::from(500u64))),
+            None
+        );
+        assert_eq!(
+            authorities.denial(minter, TokenOperation::Mint(U256::from(501u64))),
+            Some(AuthorizationDenial::InsufficientAllowance {
+                signer: minter,
+                requested: U256::from(501u64),
+                remaining: U256::from(500u64),
+            })
+        );
+        assert_eq!(
+            authorities.denial(minter, TokenOperation::Pause),
+            Some(AuthorizationDenial::MissingAuthority {
+                signer: minter,
+                required: Authority::Pause,
+            })
+        );
+
+        let grant_minter = TokenOperation::ManageAuthority(Authority::MintBurnTokens);
+        assert_eq!(authorities.denial(master, grant_minter), None);
+        assert_eq!(authorities.denial(master_mint_burn, grant_minter), None);
+        let denial = authorities
+            .denial(
+                master_mint_burn,
+                TokenOperation::ManageAuthority(Authority::Pause),
+            )
+            .expect("Only the master manages Pause");
+        assert!(matches!(
+            Error::from(denial),
+            Error::Authorization(message) if message.contains("is not the master authority")
+        ));
+    }
+
     #[test]
     fn test_mint_info_debug_formatting() {
         let mint_info = MintInfo {
@@ -670,4 +1219,89 @@ mod tests {
         assert_eq!(deserialized.supply, "1000000");
         assert_eq!(deserialized.decimals, 18);
     }
+
+    #[test]
+    fn test_mint_info_has_authority() {
+        let holder = Address::from([0x11; 20]);
+        let other = Address::from([0x22; 20]);
+        let info = MintInfo {
+            master_mint_burn_authority: holder,
+            mint_burn_authorities: vec![MinterAllowance {
+                minter: holder,
+                allowance: "100".to_string(),
+            }],
+            pause_authorities: vec![holder],
+            list_authorities: vec![holder],
+            metadata_update_authorities: vec![holder],
+            bridge_mint_authorities: vec![holder],
+            ..Default::default()
+        };
+
+        for authority in [
+            Authority::MasterMintBurn,
+            Authority::MintBurnTokens,
+            Authority::Pause,
+            Authority::ManageList,
+            Authority::UpdateMetadata,
+            Authority::Bridge,
+        ] {
+            assert!(
+                info.has_authority(authority, holder),
+                "{authority} for holder"
+            );
+            assert!(
+                !info.has_authority(authority, other),
+                "{authority} for other"
+            );
+        }
+    }
+
+    #[test]
+    fn test_token_authorities_predicates() {
+        let master = Address::repeat_byte(0x01);
+        let minter = Address::repeat_byte(0x02);
+        let pauser = Address::repeat_byte(0x03);
+        let outsider = Address::repeat_byte(0x04);
+        let mint_info = MintInfo {
+            master_authority: master,
+            master_mint_burn_authority: master,
+            mint_burn_authorities: vec![MinterAllowance {
+                minter,
+                allowance: "500".to_string(),
+            }],
+            pause_authorities: vec![pauser],
+            list_authorities: vec![pauser],
+            ..MintInfo::default()
+        };
+
+        let token = Address::repeat_byte(0x22);
+        let authorities =
+            TokenAuthorities::from_mint_info(token, &mint_info).expect("Allowances should parse");
+        assert_eq!(authorities.token, token);
+        assert!(authorities.is_master(master));
+        assert!(authorities.can_mint(minter, U256::from(500u64)));
+        assert!(!authorities.can_mint(minter, U256::from(501u64)));
+        assert!(authorities.can_burn(minter));
+        assert!(!authorities.can_mint(master, U256::from(1u64)));
+        assert_eq!(authorities.mint_allowance(minter), U256::from(500u64));
+        assert_eq!(authorities.mint_allowance(outsider), U256::ZERO);
+        assert!(authorities.can_pause(pauser) && authorities.can_manage_lists(pauser));
+        assert!(!authorities.can_update_metadata(pauser) && !authorities.can_bridge_mint(pauser));
+        assert_eq!(
+            authorities.held_by(pauser),
+            vec![Authority::Pause, Authority::ManageList]
+        );
+        assert_eq!(authorities.held_by(master), vec![Authority::MasterMintBurn]);
+        assert!(authorities.held_by(outsider).is_empty());
+        assert!(authorities.to_string().contains("allowance 500"));
+
+        let invalid = MintInfo {
+            mint_burn_authorities: vec![MinterAllowance {
+                minter,
+                allowance: "lots".to_string(),
+            }],
+            ..MintInfo::default()
+        };
+        assert!(TokenAuthorities::from_mint_info(token, &invalid).is_err());
+    }
 }
@@ -6,6 +6,7 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use super::{accounts::Nonce, tokens::TokenMetadata};
 use crate::Signature;
+use crate::registry::{AddressBook, LabeledDisplay};
 
 /// Bridge-specific information for BurnAndBridge operations.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -29,15 +30,17 @@ pub struct SuccessInfo {
     /// Receiver address
     pub receiver: Address,
     /// Whether the transaction is private
+    #[serde(default)]
     pub is_private: bool,
     /// Message associated with the transaction
+    #[serde(default)]
     pub message: String,
     /// Bridge-specific information for BurnAndBridge operations
     pub bridge_info: Option<BridgeInfo>,
 }
 
 /// Custom serialization for u128 as string
-mod u128_as_string {
+pub(crate) mod u128_as_string {
     use serde::{Deserialize, Deserializer, Serializer};
 
     pub fn serialize<S>(value: &u128, serializer: S) -> Result<S::Ok, S::Error>
@@ -108,16 +111,17 @@ pub struct Transaction {
     pub hash: B256,
 
     /// Checkpoint hash
-    #[serde(default)]
+    #[serde(default, alias = "checkpointHash")]
     pub checkpoint_hash: Option<B256>,
     /// Checkpoint number
-    #[serde(default)]
+    #[serde(default, alias = "checkpoint", alias = "checkpointNumber")]
     pub checkpoint_number: Option<u64>,
     /// Transaction Index
-    #[serde(default)]
+    #[serde(default, alias = "transactionIndex")]
     pub transaction_index: Option<u64>,
 
     /// The chain id of the transaction, if any.
+    #[serde(alias = "chainId")]
     pub chain_id: ChainId,
     /// Sender
     pub from: Address,
@@ -134,11 +138,17 @@ pub struct Transaction {
 
 impl Display for Transaction {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_labeled(f, &AddressBook::new())
+    }
+}
+
+impl LabeledDisplay for Transaction {
+    fn fmt_labeled(&self, f: &mut Formatter<'_>, book: &AddressBook) -> FmtResult {
         write!(
             f,
             "Transaction {}: from {} at checkpoint {} (nonce: {})",
             self.hash,
-            self.from,
+            book.name(&self.from),
             self.checkpoint_number.unwrap_or_default(),
             self.nonce
         )?;
@@ -158,6 +168,7 @@ pub struct FinalizedTransaction {
     #[serde(flatten)]
     pub receipt: TransactionReceipt,
     /// Counter-signatures from validators confirming finalization.
+    #[serde(default)]
     pub counter_signatures: Vec<Signature>,
 }
 
@@ -168,15 +179,19 @@ pub struct TransactionReceipt {
     /// If transaction is executed successfully.
     pub success: bool,
     /// Transaction Hash.
+    #[serde(alias = "transactionHash")]
     pub transaction_hash: B256,
     /// Index within the block.
+    #[serde(alias = "transactionIndex")]
     pub transaction_index: Option<u64>,
     /// Hash of the checkpoint this transaction was included within.
+    #[serde(alias = "checkpointHash")]
     pub checkpoint_hash: Option<B256>,
     /// Number of the checkpoint this transaction was included within.
+    #[serde(alias = "checkpoint", alias = "checkpointNumber")]
     pub checkpoint_number: Option<u64>,
     /// Fee used.
-    #[serde(with = "u128_as_string")]
+    #[serde(with = "u128_as_string", alias = "feeUsed")]
     pub fee_used: u128,
     /// Address of the sender.
     pub from: Address,
@@ -186,14 +201,21 @@ pub struct TransactionReceipt {
     /// Address of the recipient. None when its a contract creation transaction.
     pub recipient: Option<Address>,
     /// The token address.
+    #[serde(alias = "tokenAddress")]
     pub token_address: Option<Address>,
     /// Success information for token transactions.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "successInfo")]
     pub success_info: Option<SuccessInfo>,
 }
 
 impl Display for TransactionReceipt {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_labeled(f, &AddressBook::new())
+    }
+}
+
+impl LabeledDisplay for TransactionReceipt {
+    fn fmt_labeled(&self, f: &mut Formatter<'_>, book: &AddressBook) -> FmtResult {
         writeln!(f, "Transaction Receipt:")?;
         writeln!(f, "  Success: {}", self.success)?;
         writeln!(f, "  Transaction Hash: {}", self.transaction_hash)?;
@@ -207,12 +229,12 @@ impl Display for TransactionReceipt {
         if let Some(num) = self.checkpoint_number {
             writeln!(f, "  Checkpoint Number: {}", num)?;
         }
-        writeln!(f, "  From: {}", self.from)?;
+        writeln!(f, "  From: {}", book.name(&self.from))?;
         if let Some(recipient) = &self.recipient {
-            writeln!(f, "  Recipient: {}", recipient)?;
+            writeln!(f, "  Recipient: {}", book.name(recipient))?;
         }
         if let Some(token) = &self.token_address {
-            write!(f, "  Token Address: {}", token)?;
+            write!(f, "  Token Address: {}", book.name(token))?;
         }
         Ok(())
     }
@@ -950,4 +972,51 @@ mod tests {
         let expected = "Transaction Receipt:\n  Success: false\n  Transaction Hash: 0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777\n  Fee Used: 500000000000000000\n  From: 0x742d35Cc6634c0532925a3b8D91D6f4a81B8cbc0\n";
         assert_eq!(display_str, expected);
     }
+
+    #[test]
+    fn test_transaction_receipt_accepts_legacy_field_names() {
+        let json = r#"{
+            "success": true,
+            "transactionHash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+            "transactionIndex": 3,
+            "checkpointHash": "0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f",
+            "checkpoint": 42,
+            "feeUsed": "1000",
+            "from": "0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0",
+            "recipient": null,
+            "tokenAddress": null
+        }"#;
+
+        let receipt: TransactionReceipt =
+            serde_json::from_str(json).expect("Legacy receipt fields should deserialize");
+        assert_eq!(receipt.transaction_index, Some(3));
+        assert_eq!(receipt.checkpoint_number, Some(42));
+        assert_eq!(receipt.fee_used, 1000);
+        assert!(receipt.checkpoint_hash.is_some());
+
+        // Serialization always uses the canonical snake_case names.
+        let value = serde_json::to_value(&receipt).expect("Receipt should serialize");
+        assert_eq!(value["checkpoint_number"], 42);
+        assert!(value.get("checkpoint").is_none());
+    }
+
+    #[test]
+    fn test_transaction_accepts_checkpoint_alias() {
+        let transaction = Transaction {
+            checkpoint_number: Some(7),
+            ..Default::default()
+        };
+        let mut value = serde_json::to_value(&transaction).expect("Transaction should serialize");
+        let object = value
+            .as_object_mut()
+            .expect("Transaction should be an object");
+        let number = object
+            .remove("checkpoint_number")
+            .expect("checkpoint_number should be present");
+        object.insert("checkpoint".to_string(), number);
+
+        let parsed: Transaction =
+            serde_json::from_value(value).expect("Aliased transaction should deserialize");
+        assert_eq!(parsed.checkpoint_number, Some(7));
+    }
 }
@@ -0,0 +1,241 @@
+//! Types for resubmitting transactions that never landed.
+
+use crate::responses::TransactionReceipt;
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::Duration;
+
+/// Default number of submissions before giving up.
+pub const DEFAULT_RESUBMIT_ATTEMPTS: u32 = 3;
+
+/// Default number of checkpoints to wait for a receipt before resubmitting.
+pub const DEFAULT_RESUBMIT_WAIT_CHECKPOINTS: u64 = 10;
+
+/// Default interval between receipt polls while waiting.
+pub const DEFAULT_RESUBMIT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Policy for [`Client::resubmit`](crate::Client::resubmit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResubmitPolicy {
+    /// Maximum number of submissions, including the first.
+    pub max_attempts: u32,
+    /// Checkpoints to wait for a receipt before treating a submission as dropped.
+    pub wait_checkpoints: u64,
+    /// Interval between receipt polls.
+    pub poll_interval: Duration,
+}
+
+impl ResubmitPolicy {
+    /// Create a policy with the default limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of submissions.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set how many checkpoints to wait before resubmitting.
+    pub fn wait_checkpoints(mut self, checkpoints: u64) -> Self {
+        self.wait_checkpoints = checkpoints;
+        self
+    }
+
+    /// Set the interval between receipt polls.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+}
+
+impl Default for ResubmitPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_RESUBMIT_ATTEMPTS,
+            wait_checkpoints: DEFAULT_RESUBMIT_WAIT_CHECKPOINTS,
+            poll_interval: DEFAULT_RESUBMIT_POLL_INTERVAL,
+        }
+    }
+}
+
+/// How one submission attempt ended.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    /// The transaction landed.
+    Landed {
+        /// Receipt of the transaction.
+        receipt: Box<TransactionReceipt>,
+    },
+    /// No receipt appeared within the policy's checkpoint window.
+    Dropped {
+        /// Checkpoints observed while waiting.
+        waited_checkpoints: u64,
+    },
+    /// The submission or receipt lookup failed.
+    Failed {
+        /// Error message.
+        reason: String,
+        /// Whether the failure was transient.
+        retryable: bool,
+    },
+    /// The account nonce moved past the payload's nonce without a receipt.
+    ///
+    /// Another transaction used the nonce, so the payload is not resubmitted.
+    NonceConsumed {
+        /// Nonce the payload is signed with.
+        nonce: u64,
+        /// Nonce the account expects next.
+        account_nonce: u64,
+    },
+    /// Earlier nonces of the account are missing, so the payload cannot land yet.
+    NonceGap {
+        /// Nonce the payload is signed with.
+        nonce: u64,
+        /// Nonce the account expects next.
+        account_nonce: u64,
+    },
+}
+
+impl Display for AttemptOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AttemptOutcome::Landed { receipt } => {
+                write!(f, "landed as {}", receipt.transaction_hash)
+            }
+            AttemptOutcome::Dropped { waited_checkpoints } => {
+                write!(f, "dropped after {} checkpoints", waited_checkpoints)
+            }
+            AttemptOutcome::Failed { reason, .. } => write!(f, "failed: {}", reason),
+            AttemptOutcome::NonceConsumed {
+                nonce,
+                account_nonce,
+            } => write!(
+                f,
+                "nonce {} already consumed (account at {})",
+                nonce, account_nonce
+            ),
+            AttemptOutcome::NonceGap {
+                nonce,
+                account_nonce,
+            } => write!(
+                f,
+                "nonce {} blocked by missing nonces from {}",
+                nonce, account_nonce
+            ),
+        }
+    }
+}
+
+/// One submission made while resubmitting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResubmitAttempt {
+    /// Attempt number, starting at 1.
+    pub attempt: u32,
+    /// Nonce the submitted payload carried.
+    pub nonce: u64,
+    /// Hash reported by the server, when the submission was accepted.
+    pub hash: Option<B256>,
+    /// How the attempt ended.
+    pub outcome: AttemptOutcome,
+}
+
+/// Every attempt made by [`Client::resubmit`](crate::Client::resubmit), in order.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResubmitReport {
+    /// The attempts made.
+    pub attempts: Vec<ResubmitAttempt>,
+}
+
+impl ResubmitReport {
+    /// Receipt of the transaction, if an attempt landed.
+    pub fn receipt(&self) -> Option<&TransactionReceipt> {
+        self.attempts
+            .iter()
+            .find_map(|attempt| match &attempt.outcome {
+                AttemptOutcome::Landed { receipt } => Some(receipt.as_ref()),
+                _ => None,
+            })
+    }
+
+    /// Whether the transaction landed.
+    pub fn is_landed(&self) -> bool {
+        self.receipt().is_some()
+    }
+
+    /// The final attempt.
+    pub fn last(&self) -> Option<&ResubmitAttempt> {
+        self.attempts.last()
+    }
+}
+
+impl Display for ResubmitReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Resubmission ({} attempts):", self.attempts.len())?;
+        for attempt in &self.attempts {
+            writeln!(
+                f,
+                "  #{} nonce {}: {}",
+                attempt.attempt, attempt.nonce, attempt.outcome
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resubmit_policy_builder() {
+        let policy = ResubmitPolicy::new()
+            .max_attempts(0)
+            .wait_checkpoints(4)
+            .poll_interval(Duration::from_millis(20));
+        assert_eq!(policy.max_attempts, 1);
+        assert_eq!(policy.wait_checkpoints, 4);
+        assert_eq!(policy.poll_interval, Duration::from_millis(20));
+        assert_eq!(
+            ResubmitPolicy::default().max_attempts,
+            DEFAULT_RESUBMIT_ATTEMPTS
+        );
+    }
+
+    #[test]
+    fn test_resubmit_report_display_and_serde() {
+        let report = ResubmitReport {
+            attempts: vec![
+                ResubmitAttempt {
+                    attempt: 1,
+                    nonce: 5,
+                    hash: Some(B256::repeat_byte(1)),
+                    outcome: AttemptOutcome::Dropped {
+                        waited_checkpoints: 10,
+                    },
+                },
+                ResubmitAttempt {
+                    attempt: 2,
+                    nonce: 5,
+                    hash: None,
+                    outcome: AttemptOutcome::NonceConsumed {
+                        nonce: 5,
+                        account_nonce: 6,
+                    },
+                },
+            ],
+        };
+        assert!(!report.is_landed());
+        assert_eq!(report.last().map(|attempt| attempt.attempt), Some(2));
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("#1 nonce 5: dropped after 10 checkpoints"));
+        assert!(rendered.contains("#2 nonce 5: nonce 5 already consumed (account at 6)"));
+
+        let json = serde_json::to_value(&report).expect("Report should serialize");
+        assert_eq!(json["attempts"][1]["outcome"]["outcome"], "nonce_consumed");
+    }
+}
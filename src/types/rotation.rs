@@ -0,0 +1,332 @@
+//! Types describing an operational signer rotation and the rotation of a
+//! single token authority.
+
+use crate::requests::authorities::{Authority, AuthorityAction};
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::Duration;
+
+#[cfg(feature = "wallet")]
+use crate::Result;
+#[cfg(feature = "wallet")]
+use crate::utils::EvmWallet;
+
+/// An authority the old signer holds that must be moved to the new signer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleAssignment {
+    /// The authority to transfer.
+    pub authority: Authority,
+    /// Allowance to grant alongside the authority (used by `MintBurnTokens`).
+    pub value: U256,
+}
+
+/// Plan for moving a token's roles from one signer address to another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerRotation {
+    /// Token the roles belong to.
+    pub token: Address,
+    /// Signer being retired.
+    pub old_signer: Address,
+    /// Signer taking over the roles.
+    pub new_signer: Address,
+    /// Roles to transfer, in the order they are granted and revoked.
+    pub roles: Vec<RoleAssignment>,
+}
+
+impl SignerRotation {
+    /// Create an empty rotation plan between two existing addresses.
+    pub fn new(token: Address, old_signer: Address, new_signer: Address) -> Self {
+        Self {
+            token,
+            old_signer,
+            new_signer,
+            roles: Vec::new(),
+        }
+    }
+
+    /// Create a rotation plan towards a freshly generated signer.
+    ///
+    /// The returned wallet holds the new signer's key and must be stored by the
+    /// caller before the rotation is executed.
+    #[cfg(feature = "wallet")]
+    pub fn generate(token: Address, old_signer: Address) -> Result<(Self, EvmWallet)> {
+        let wallet = EvmWallet::generate_random()?;
+        Ok((Self::new(token, old_signer, wallet.address), wallet))
+    }
+
+    /// Add a role to transfer.
+    pub fn role(mut self, authority: Authority, value: U256) -> Self {
+        self.roles.push(RoleAssignment { authority, value });
+        self
+    }
+}
+
+/// A single authority change submitted during a rotation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RotationStep {
+    /// Whether the authority was granted or revoked.
+    pub action: AuthorityAction,
+    /// The authority that changed.
+    pub authority: Authority,
+    /// The address the authority was granted to or revoked from.
+    pub address: Address,
+    /// Hash of the confirmed authority transaction.
+    pub transaction_hash: B256,
+}
+
+impl Display for RotationStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} for {} ({})",
+            self.action, self.authority, self.address, self.transaction_hash
+        )
+    }
+}
+
+/// Outcome of [`Client::rotate_signer`](crate::Client::rotate_signer).
+///
+/// Once a transaction has been submitted the rotation reports failures here
+/// rather than as an error, so the caller always learns which steps landed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignerRotationReport {
+    /// Token the roles belong to.
+    pub token: Address,
+    /// Signer the roles are moved away from.
+    pub retired_signer: Address,
+    /// Signer the roles are moved to.
+    pub new_signer: Address,
+    /// Every confirmed authority change in submission order.
+    pub steps: Vec<RotationStep>,
+    /// Set when the rotation stopped before completing.
+    pub failure: Option<RotationFailure>,
+}
+
+impl SignerRotationReport {
+    /// Whether every phase completed.
+    pub fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl Display for SignerRotationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Signer Rotation for token {}:", self.token)?;
+        writeln!(f, "  Retired: {}", self.retired_signer)?;
+        writeln!(f, "  New: {}", self.new_signer)?;
+        for step in &self.steps {
+            writeln!(f, "  {}", step)?;
+        }
+        if let Some(failure) = &self.failure {
+            writeln!(f, "  Failed during {}: {}", failure.phase, failure.reason)?;
+            writeln!(f, "  Recovery: {}", failure.guidance)?;
+        }
+        Ok(())
+    }
+}
+
+/// How long [`Client::rotate_authority`](crate::Client::rotate_authority)
+/// waits for each authority transaction to be confirmed by default.
+pub const DEFAULT_ROTATION_RECEIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Options for [`Client::rotate_authority`](crate::Client::rotate_authority).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RotationOptions {
+    /// Allowance granted alongside the authority (used by `MintBurnTokens`).
+    pub value: U256,
+    /// Whether to revoke the authority from the old address once the new
+    /// one holds it.
+    pub revoke_old: bool,
+    /// How long to wait for each authority transaction to be confirmed.
+    pub receipt_timeout: Duration,
+}
+
+impl RotationOptions {
+    /// Grant with no allowance, revoke the old address and wait up to
+    /// [`DEFAULT_ROTATION_RECEIPT_TIMEOUT`] per step.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the allowance granted with the authority.
+    pub fn value(mut self, value: U256) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Leave the authority with the old address after granting it, so both
+    /// hold it until the caller revokes it.
+    pub fn keep_old(mut self) -> Self {
+        self.revoke_old = false;
+        self
+    }
+
+    /// Set how long to wait for each authority transaction.
+    pub fn receipt_timeout(mut self, timeout: Duration) -> Self {
+        self.receipt_timeout = timeout;
+        self
+    }
+}
+
+impl Default for RotationOptions {
+    fn default() -> Self {
+        Self {
+            value: U256::ZERO,
+            revoke_old: true,
+            receipt_timeout: DEFAULT_ROTATION_RECEIPT_TIMEOUT,
+        }
+    }
+}
+
+/// Phase of an authority rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationPhase {
+    /// Granting the authority to the new address and confirming it holds it.
+    Grant,
+    /// Revoking the authority from the old address and confirming it no
+    /// longer holds it.
+    Revoke,
+}
+
+impl Display for RotationPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            RotationPhase::Grant => write!(f, "grant"),
+            RotationPhase::Revoke => write!(f, "revoke"),
+        }
+    }
+}
+
+/// Why an authority rotation stopped and how to recover from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RotationFailure {
+    /// Phase that failed.
+    pub phase: RotationPhase,
+    /// The error that stopped the rotation.
+    pub reason: String,
+    /// What holds the authority now and which transaction finishes or
+    /// undoes the rotation.
+    pub guidance: String,
+}
+
+/// Outcome of [`Client::rotate_authority`](crate::Client::rotate_authority).
+///
+/// Once a transaction has been submitted the rotation reports failures here
+/// rather than as an error, so the caller always learns which steps landed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorityRotationReport {
+    /// Token the authority belongs to.
+    pub token: Address,
+    /// The rotated authority.
+    pub authority: Authority,
+    /// Address the authority is moved away from.
+    pub old_authority: Address,
+    /// Address the authority is moved to.
+    pub new_authority: Address,
+    /// Every confirmed authority change in submission order.
+    pub steps: Vec<RotationStep>,
+    /// Set when the rotation stopped before completing.
+    pub failure: Option<RotationFailure>,
+}
+
+impl AuthorityRotationReport {
+    /// Whether every phase completed.
+    pub fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl Display for AuthorityRotationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{} Rotation for token {}:", self.authority, self.token)?;
+        writeln!(f, "  Old: {}", self.old_authority)?;
+        writeln!(f, "  New: {}", self.new_authority)?;
+        for step in &self.steps {
+            writeln!(f, "  {}", step)?;
+        }
+        if let Some(failure) = &self.failure {
+            writeln!(f, "  Failed during {}: {}", failure.phase, failure.reason)?;
+            writeln!(f, "  Recovery: {}", failure.guidance)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signer_rotation_builder() {
+        let token = Address::from([0x01; 20]);
+        let old = Address::from([0x02; 20]);
+        let new = Address::from([0x03; 20]);
+
+        let rotation = SignerRotation::new(token, old, new)
+            .role(Authority::Pause, U256::ZERO)
+            .role(Authority::MintBurnTokens, U256::from(500u64));
+
+        assert_eq!(rotation.roles.len(), 2);
+        assert_eq!(rotation.roles[1].authority, Authority::MintBurnTokens);
+        assert_eq!(rotation.roles[1].value, U256::from(500u64));
+    }
+
+    #[cfg(feature = "wallet")]
+    #[test]
+    fn test_signer_rotation_generate() {
+        let token = Address::from([0x01; 20]);
+        let old = Address::from([0x02; 20]);
+
+        let (rotation, wallet) =
+            SignerRotation::generate(token, old).expect("Key generation should succeed");
+        assert_eq!(rotation.new_signer, wallet.address);
+        assert_ne!(rotation.new_signer, old);
+        assert!(rotation.roles.is_empty());
+    }
+
+    #[test]
+    fn test_rotation_report_display() {
+        let report = SignerRotationReport {
+            token: Address::ZERO,
+            retired_signer: Address::from([0x02; 20]),
+            new_signer: Address::from([0x03; 20]),
+            steps: vec![RotationStep {
+                action: AuthorityAction::Grant,
+                authority: Authority::Pause,
+                address: Address::from([0x03; 20]),
+                transaction_hash: B256::ZERO,
+            }],
+            failure: None,
+        };
+
+        assert!(report.is_complete());
+        let display = report.to_string();
+        assert!(display.contains("Signer Rotation for token"));
+        assert!(display.contains("Grant Pause for 0x0303030303030303030303030303030303030303"));
+    }
+
+    #[test]
+    fn test_authority_rotation_report_failure() {
+        let report = AuthorityRotationReport {
+            token: Address::ZERO,
+            authority: Authority::MasterMintBurn,
+            old_authority: Address::from([0x02; 20]),
+            new_authority: Address::from([0x03; 20]),
+            steps: Vec::new(),
+            failure: Some(RotationFailure {
+                phase: RotationPhase::Revoke,
+                reason: "timed out".to_string(),
+                guidance: "Revoke it from the old address".to_string(),
+            }),
+        };
+
+        assert!(!report.is_complete());
+        let display = report.to_string();
+        assert!(display.contains("MasterMintBurn Rotation for token"));
+        assert!(display.contains("Failed during revoke: timed out"));
+        assert!(RotationOptions::new().revoke_old);
+        assert!(!RotationOptions::new().keep_old().revoke_old);
+    }
+}
@@ -0,0 +1,210 @@
+//! Lifecycle status of a submitted transaction.
+
+use crate::responses::TransactionReceipt;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Checkpoints that must follow a transaction's checkpoint before
+/// [`TxStatus`] reports it as finalized.
+pub const DEFAULT_FINALITY_DEPTH: u64 = 3;
+
+/// Where a transaction stands, as returned by
+/// [`Client::get_transaction_status`](crate::Client::get_transaction_status).
+///
+/// A transaction moves from [`Pending`](TxStatus::Pending) to
+/// [`Included`](TxStatus::Included) once its receipt names a checkpoint, and
+/// to [`Finalized`](TxStatus::Finalized) once enough checkpoints follow it.
+/// An execution failure ends in [`Failed`](TxStatus::Failed) instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TxStatus {
+    /// The node knows neither the transaction nor a receipt for it; it may
+    /// not have propagated yet or may have been dropped. The gateway has no
+    /// pending pool to query, so the two cases cannot be told apart.
+    Unknown,
+    /// The transaction is known but not yet part of a checkpoint.
+    Pending,
+    /// The transaction succeeded in `checkpoint`, followed by `depth`
+    /// checkpoints, fewer than the finality depth.
+    Included {
+        /// Checkpoint the transaction was included in.
+        checkpoint: u64,
+        /// Checkpoints made since, not counting `checkpoint` itself.
+        depth: u64,
+    },
+    /// The transaction succeeded and is buried deep enough to be final.
+    Finalized {
+        /// Checkpoint the transaction was included in.
+        checkpoint: u64,
+        /// Checkpoints made since, not counting `checkpoint` itself.
+        depth: u64,
+    },
+    /// The transaction was executed and failed.
+    Failed {
+        /// Checkpoint the transaction was included in, if reported.
+        checkpoint: Option<u64>,
+    },
+}
+
+impl TxStatus {
+    /// Derive the status from a receipt, the latest checkpoint number and
+    /// the finality depth.
+    ///
+    /// `None` for `receipt` means no receipt exists yet; `known` tells
+    /// whether the node knows the transaction itself.
+    pub fn from_receipt(
+        receipt: Option<&TransactionReceipt>,
+        known: bool,
+        latest_checkpoint: u64,
+        finality_depth: u64,
+    ) -> Self {
+        let Some(receipt) = receipt else {
+            return if known { Self::Pending } else { Self::Unknown };
+        };
+        if !receipt.success {
+            return Self::Failed {
+                checkpoint: receipt.checkpoint_number,
+            };
+        }
+        match receipt.checkpoint_number {
+            None => Self::Pending,
+            Some(checkpoint) => {
+                let depth = latest_checkpoint.saturating_sub(checkpoint);
+                if depth >= finality_depth {
+                    Self::Finalized { checkpoint, depth }
+                } else {
+                    Self::Included { checkpoint, depth }
+                }
+            }
+        }
+    }
+
+    /// Whether the status can no longer change: the transaction is
+    /// finalized or failed.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Finalized { .. } | Self::Failed { .. })
+    }
+
+    /// Whether the status is final when `finality_depth` checkpoints are
+    /// required instead of the depth it was derived with.
+    pub fn is_final_at(&self, finality_depth: u64) -> bool {
+        match self {
+            Self::Included { depth, .. } | Self::Finalized { depth, .. } => {
+                *depth >= finality_depth
+            }
+            Self::Failed { .. } => true,
+            Self::Unknown | Self::Pending => false,
+        }
+    }
+
+    /// Whether the transaction executed successfully, final or not.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Included { .. } | Self::Finalized { .. })
+    }
+
+    /// Checkpoint the transaction was included in, if known.
+    pub fn checkpoint(&self) -> Option<u64> {
+        match self {
+            Self::Included { checkpoint, .. } | Self::Finalized { checkpoint, .. } => {
+                Some(*checkpoint)
+            }
+            Self::Failed { checkpoint } => *checkpoint,
+            Self::Unknown | Self::Pending => None,
+        }
+    }
+}
+
+impl Display for TxStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Unknown => write!(f, "Unknown"),
+            Self::Pending => write!(f, "Pending"),
+            Self::Included { checkpoint, depth } => {
+                write!(f, "Included in checkpoint #{} ({} deep)", checkpoint, depth)
+            }
+            Self::Finalized { checkpoint, depth } => {
+                write!(
+                    f,
+                    "Finalized in checkpoint #{} ({} deep)",
+                    checkpoint, depth
+                )
+            }
+            Self::Failed {
+                checkpoint: Some(checkpoint),
+            } => write!(f, "Failed in checkpoint #{}", checkpoint),
+            Self::Failed { checkpoint: None } => write!(f, "Failed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256};
+
+    fn receipt(success: bool, checkpoint_number: Option<u64>) -> TransactionReceipt {
+        TransactionReceipt {
+            success,
+            transaction_hash: B256::ZERO,
+            transaction_index: Some(0),
+            checkpoint_hash: None,
+            checkpoint_number,
+            fee_used: 0,
+            from: Address::ZERO,
+            recipient: None,
+            token_address: None,
+            success_info: None,
+        }
+    }
+
+    #[test]
+    fn test_status_from_receipt() {
+        assert_eq!(
+            TxStatus::from_receipt(None, false, 10, 3),
+            TxStatus::Unknown
+        );
+        assert_eq!(TxStatus::from_receipt(None, true, 10, 3), TxStatus::Pending);
+        assert_eq!(
+            TxStatus::from_receipt(Some(&receipt(true, None)), true, 10, 3),
+            TxStatus::Pending
+        );
+        assert_eq!(
+            TxStatus::from_receipt(Some(&receipt(true, Some(8))), true, 10, 3),
+            TxStatus::Included {
+                checkpoint: 8,
+                depth: 2
+            }
+        );
+        assert_eq!(
+            TxStatus::from_receipt(Some(&receipt(true, Some(7))), true, 10, 3),
+            TxStatus::Finalized {
+                checkpoint: 7,
+                depth: 3
+            }
+        );
+        assert_eq!(
+            TxStatus::from_receipt(Some(&receipt(false, Some(9))), true, 10, 3),
+            TxStatus::Failed {
+                checkpoint: Some(9)
+            }
+        );
+    }
+
+    #[test]
+    fn test_status_finality_helpers() {
+        let included = TxStatus::Included {
+            checkpoint: 8,
+            depth: 2,
+        };
+        assert!(!included.is_final());
+        assert!(included.is_final_at(2));
+        assert!(included.is_success());
+        assert_eq!(included.checkpoint(), Some(8));
+        assert_eq!(included.to_string(), "Included in checkpoint #8 (2 deep)");
+
+        let failed = TxStatus::Failed { checkpoint: None };
+        assert!(failed.is_final() && failed.is_final_at(100));
+        assert!(!failed.is_success());
+        assert!(!TxStatus::Pending.is_final_at(0));
+    }
+}
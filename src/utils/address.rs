@@ -1,8 +1,10 @@
 //! Address utilities and validation functions.
 
-use crate::{CryptoError, Result};
-use alloy_primitives::Address;
-use alloy_primitives::keccak256;
+use crate::crypto::{SecretKeyMaterial, recover_signer, sign_hash};
+use crate::{CryptoError, Result, Signature};
+use alloy_primitives::{Address, B256, eip191_hash_message, keccak256};
+use k256::PublicKey;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 
 /// Convert a public key to an Ethereum address.
 ///
@@ -43,6 +45,90 @@ pub fn public_key_to_address(public_key_hex: &str) -> Result<Address> {
     Ok(address)
 }
 
+/// Derive the address of a secp256k1 public key given as bytes.
+///
+/// Accepts the SEC1 compressed (33 bytes) and uncompressed (65 bytes)
+/// encodings as well as the bare 64-byte coordinates. Unlike
+/// [`public_key_to_address`], the key is checked to be a point on the curve.
+pub fn address_from_public_key(public_key: &[u8]) -> Result<Address> {
+    let key = if public_key.len() == 64 {
+        let mut uncompressed = [0u8; 65];
+        uncompressed[0] = 0x04;
+        uncompressed[1..].copy_from_slice(public_key);
+        PublicKey::from_sec1_bytes(&uncompressed)
+    } else {
+        PublicKey::from_sec1_bytes(public_key)
+    }
+    .map_err(|_| {
+        CryptoError::invalid_public_key(format!(
+            "Not a secp256k1 public key ({} bytes)",
+            public_key.len()
+        ))
+    })?;
+
+    let point = key.to_encoded_point(false);
+    Ok(Address::from_slice(
+        &keccak256(&point.as_bytes()[1..])[12..],
+    ))
+}
+
+/// Recover the address that signed `digest`.
+///
+/// The same check as [`recover_signer`]: `v` may be 0 or 1, 27 or 28, or
+/// EIP-155 encoded, and high-`s` signatures are rejected.
+pub fn address_from_signature(digest: &B256, signature: &Signature) -> Result<Address> {
+    recover_signer(digest, signature)
+}
+
+/// Sign a message as an EIP-191 personal message.
+///
+/// The digest is the keccak-256 of `"\x19Ethereum Signed Message:\n"`, the
+/// message length in decimal and the message, as Ethereum wallets compute
+/// it for `personal_sign`. Because of that prefix the signature can never
+/// be replayed as a transaction signature, which makes it safe for
+/// off-chain login with a OneMoney key.
+///
+/// # Example
+///
+/// ```rust
+/// use onemoney_protocol::{private_key_to_address, sign_message, verify_message};
+///
+/// let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+/// let address = private_key_to_address(key)?.parse()?;
+///
+/// let challenge = b"Sign in to example.com, nonce 8f3a";
+/// let signature = sign_message(challenge, key)?;
+/// verify_message(challenge, &signature, address)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn sign_message<K: Into<SecretKeyMaterial>>(
+    message: &[u8],
+    private_key: K,
+) -> Result<Signature> {
+    sign_hash(&eip191_hash_message(message), private_key)
+}
+
+/// Check that `signature` is `address`'s EIP-191 signature of `message`.
+///
+/// Signatures from Ethereum wallets, with `v` of 27 or 28, are accepted as
+/// well as those from [`sign_message`].
+///
+/// # Returns
+///
+/// `Ok(())` when `address` signed the message, otherwise
+/// [`CryptoError::VerificationFailed`] naming the address that did.
+pub fn verify_message(message: &[u8], signature: &Signature, address: Address) -> Result<()> {
+    let signer = recover_signer(&eip191_hash_message(message), signature)?;
+    if signer != address {
+        return Err(CryptoError::verification_failed(format!(
+            "Message was signed by {}, not {}",
+            signer, address
+        ))
+        .into());
+    }
+    Ok(())
+}
+
 /// Validate if a string is a valid Ethereum address format.
 ///
 /// # Arguments
@@ -107,6 +193,53 @@ mod tests {
         assert_ne!(address, Address::ZERO);
     }
 
+    #[test]
+    fn test_message_signing_matches_ethereum_wallets() {
+        // Known EIP-191 vector for this key and message, as produced by
+        // web3.js `accounts.sign`.
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let address: Address = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"
+            .parse()
+            .expect("Valid address");
+        assert_eq!(
+            eip191_hash_message(b"Some data").to_string(),
+            "0x1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655"
+        );
+        let wallet_signature = Signature::new(
+            "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd"
+                .parse()
+                .expect("Valid r"),
+            "0x6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029"
+                .parse()
+                .expect("Valid s"),
+            28,
+        );
+        verify_message(b"Some data", &wallet_signature, address).expect("Wallet signature");
+
+        let signature = sign_message(b"Some data", key).expect("Signing should succeed");
+        assert_eq!(
+            (signature.r, signature.s),
+            (wallet_signature.r, wallet_signature.s)
+        );
+        assert!(verify_message(b"Other data", &signature, address).is_err());
+
+        let public_key = SecretKeyMaterial::from(key)
+            .signing_key()
+            .expect("Valid key")
+            .verifying_key()
+            .to_encoded_point(true);
+        assert_eq!(
+            address_from_public_key(public_key.as_bytes()).expect("Compressed key"),
+            address
+        );
+        assert_eq!(
+            address_from_signature(&eip191_hash_message(b"Some data"), &signature)
+                .expect("Recovery should succeed"),
+            address
+        );
+        assert!(address_from_public_key(&[0x04; 10]).is_err());
+    }
+
     #[test]
     fn test_public_key_to_address_error_cases() {
         // Test invalid format (compressed)
@@ -0,0 +1,161 @@
+//! Conversions accepted by the payload constructors.
+//!
+//! Payload constructors take amounts and addresses through [`IntoAmount`]
+//! and [`IntoAddress`], so callers can pass typed values directly or parse
+//! user input in the same call and get a validation error that names the
+//! offending field.
+
+use crate::{Error, Result};
+use alloy_primitives::{Address, U256};
+use std::str::FromStr;
+
+/// A value usable as a base-unit token amount.
+///
+/// Strings must be base-10 integers in the token's smallest unit; decimal
+/// points, signs, separators and hex are rejected.
+pub trait IntoAmount {
+    /// Convert to an amount, reporting failures against `field`.
+    fn into_amount(self, field: &str) -> Result<U256>;
+}
+
+macro_rules! amount_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoAmount for $ty {
+                fn into_amount(self, _field: &str) -> Result<U256> {
+                    Ok(U256::from(self))
+                }
+            }
+        )*
+    };
+}
+
+amount_from_integer!(U256, u8, u16, u32, u64, u128, usize);
+
+impl IntoAmount for &str {
+    fn into_amount(self, field: &str) -> Result<U256> {
+        if self.is_empty() || !self.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(Error::validation(
+                field,
+                format!("'{}' is not a base-10 integer amount", self),
+            ));
+        }
+        U256::from_str_radix(self, 10).map_err(|e| {
+            Error::validation(field, format!("Amount '{}' is out of range: {}", self, e))
+        })
+    }
+}
+
+impl IntoAmount for &String {
+    fn into_amount(self, field: &str) -> Result<U256> {
+        self.as_str().into_amount(field)
+    }
+}
+
+impl IntoAmount for String {
+    fn into_amount(self, field: &str) -> Result<U256> {
+        self.as_str().into_amount(field)
+    }
+}
+
+/// A value usable as an address.
+///
+/// Mixed-case strings must carry a valid EIP-55 checksum; all-lowercase and
+/// all-uppercase hex is accepted as-is.
+pub trait IntoAddress {
+    /// Convert to an address, reporting failures against `field`.
+    fn into_address(self, field: &str) -> Result<Address>;
+}
+
+impl IntoAddress for Address {
+    fn into_address(self, _field: &str) -> Result<Address> {
+        Ok(self)
+    }
+}
+
+impl IntoAddress for &Address {
+    fn into_address(self, _field: &str) -> Result<Address> {
+        Ok(*self)
+    }
+}
+
+impl IntoAddress for &str {
+    fn into_address(self, field: &str) -> Result<Address> {
+        let address = Address::from_str(self)
+            .map_err(|e| Error::validation(field, format!("Invalid address '{}': {}", self, e)))?;
+        let digits = self.strip_prefix("0x").unwrap_or(self);
+        let mixed_case = digits.bytes().any(|byte| byte.is_ascii_lowercase())
+            && digits.bytes().any(|byte| byte.is_ascii_uppercase());
+        if mixed_case && address.to_checksum(None)[2..] != *digits {
+            return Err(Error::validation(
+                field,
+                format!("Address '{}' has an invalid checksum", self),
+            ));
+        }
+        Ok(address)
+    }
+}
+
+impl IntoAddress for &String {
+    fn into_address(self, field: &str) -> Result<Address> {
+        self.as_str().into_address(field)
+    }
+}
+
+impl IntoAddress for String {
+    fn into_address(self, field: &str) -> Result<Address> {
+        self.as_str().into_address(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_amount() {
+        assert_eq!(7u64.into_amount("value").ok(), Some(U256::from(7u64)));
+        assert_eq!(
+            u128::MAX.into_amount("value").ok(),
+            Some(U256::from(u128::MAX))
+        );
+        assert_eq!(
+            "1000000".into_amount("value").ok(),
+            Some(U256::from(1_000_000u64))
+        );
+
+        for invalid in ["", "1.5", "-1", "0x10", "1_000", " 1"] {
+            let error = invalid
+                .into_amount("value")
+                .expect_err("Should be rejected");
+            assert!(error.to_string().contains("value"), "{}", error);
+        }
+        let too_large = format!("{}0", U256::MAX);
+        assert!(too_large.into_amount("value").is_err());
+    }
+
+    #[test]
+    fn test_into_address_checks_checksum() {
+        let checksummed = "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf";
+        let expected = Address::from_str(checksummed).expect("Test data should be valid");
+
+        assert_eq!(checksummed.into_address("recipient").ok(), Some(expected));
+        assert_eq!(
+            checksummed.to_lowercase().into_address("recipient").ok(),
+            Some(expected)
+        );
+        assert_eq!(
+            format!("0x{}", checksummed[2..].to_uppercase())
+                .into_address("recipient")
+                .ok(),
+            Some(expected)
+        );
+
+        let bad_checksum = "0x7e5F4552091A69125d5DfCb7b8C2659029395Bdf";
+        let error = bad_checksum
+            .into_address("recipient")
+            .expect_err("Bad checksum should be rejected");
+        assert!(error.to_string().contains("checksum"), "{}", error);
+        assert!("0x1234".into_address("token").is_err());
+    }
+}
@@ -0,0 +1,805 @@
+//! Password-encrypted keystore files for [`Wallet`]s.
+//!
+//! Keystores use the Web3 Secret Storage format (version 3) with scrypt and
+//! AES-128-CTR, so files written here open in other Ethereum tooling and the
+//! other way round. Audit metadata lives in an `x-onemoney` extension object
+//! that other tools ignore.
+//!
+//! A keystore is re-encrypted, never edited in place: [`Keystore::rotate`]
+//! and [`Wallet::rotate`] change the password and [`Keystore::upgrade`]
+//! raises the scrypt cost, all with a fresh salt and IV. [`migrate_keystores`] upgrades every
+//! keystore in a directory whose cost is below a target.
+
+use super::wallet::Wallet;
+use crate::crypto::SecretKeyMaterial;
+use crate::error::CryptoError;
+use crate::{Error, Result};
+use aes::Aes128;
+use alloy_primitives::{Address, keccak256};
+use ctr::Ctr128BE;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use hex::{decode as hex_decode, encode as hex_encode};
+use k256::elliptic_curve::rand_core::{OsRng, RngCore};
+use k256::elliptic_curve::subtle::ConstantTimeEq;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroizing;
+
+const KEYSTORE_VERSION: u8 = 3;
+const CIPHER: &str = "aes-128-ctr";
+const KDF: &str = "scrypt";
+const DERIVED_KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const IV_LEN: usize = 16;
+const TEMP_SUFFIX: &str = ".tmp";
+
+type Aes128Ctr = Ctr128BE<Aes128>;
+
+/// Cost parameters of the scrypt key derivation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScryptParams {
+    /// Base-two logarithm of the CPU/memory cost `N`.
+    pub log_n: u8,
+    /// Block size `r`.
+    pub r: u32,
+    /// Parallelism `p`.
+    pub p: u32,
+}
+
+impl ScryptParams {
+    /// The cost geth and most wallets use for new keystores.
+    pub const STANDARD: Self = Self {
+        log_n: 18,
+        r: 8,
+        p: 1,
+    };
+
+    /// Validate a set of parameters.
+    ///
+    /// Combinations outside RFC 7914 are rejected, including a cost `N` of
+    /// `2^(16 * r)` or more.
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self> {
+        let params = Self { log_n, r, p };
+        params.to_scrypt().map(|_| params)
+    }
+
+    /// The cost `N`.
+    pub fn n(&self) -> u64 {
+        1u64.checked_shl(u32::from(self.log_n)).unwrap_or(u64::MAX)
+    }
+
+    /// Whether any parameter is below the one in `target`, so deriving with
+    /// these parameters is cheaper for an attacker than with `target`.
+    pub fn is_weaker_than(&self, target: &ScryptParams) -> bool {
+        self.log_n < target.log_n || self.r < target.r || self.p < target.p
+    }
+
+    /// The larger of each parameter in `self` and `floor`, so raising one
+    /// parameter to `floor` never lowers another.
+    pub fn at_least(&self, floor: &ScryptParams) -> Result<Self> {
+        Self::new(
+            self.log_n.max(floor.log_n),
+            self.r.max(floor.r),
+            self.p.max(floor.p),
+        )
+    }
+
+    fn to_scrypt(self) -> Result<scrypt::Params> {
+        scrypt::Params::new(self.log_n, self.r, self.p, DERIVED_KEY_LEN).map_err(|e| {
+            Error::validation(
+                "kdfparams",
+                format!("Invalid scrypt parameters {}: {}", self, e),
+            )
+        })
+    }
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Display for ScryptParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "N=2^{}, r={}, p={}", self.log_n, self.r, self.p)
+    }
+}
+
+/// Audit trail kept in the keystore's `x-onemoney` extension object.
+///
+/// Times are Unix seconds as read from the local clock.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreMetadata {
+    /// When the key was first encrypted by this SDK.
+    pub created_at: u64,
+    /// When the keystore was last unlocked with [`Keystore::unlock`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_used: Option<u64>,
+    /// When the password was last changed or the keystore re-encrypted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotated_at: Option<u64>,
+}
+
+/// A private key encrypted under a password, in Web3 Secret Storage form.
+///
+/// The JSON form round-trips through `serde`; [`Keystore::load`] and
+/// [`Keystore::save`] read and write it as a file.
+///
+/// # Example
+///
+/// ```rust
+/// use onemoney_protocol::utils::{Keystore, ScryptParams, Wallet};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let wallet = Wallet::random();
+/// // Tests use a low cost; keep the standard cost for real keys.
+/// let params = ScryptParams::new(10, 8, 1)?;
+/// let keystore = Keystore::encrypt(&wallet, "correct horse", params)?;
+///
+/// let rotated = keystore.rotate("correct horse", "battery staple")?;
+/// assert!(rotated.decrypt("correct horse").is_err());
+/// assert_eq!(rotated.decrypt("battery staple")?.address(), wallet.address());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    version: u8,
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    crypto: CryptoSection,
+    #[serde(
+        rename = "x-onemoney",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    metadata: Option<KeystoreMetadata>,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CryptoSection {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct KdfParams {
+    dklen: usize,
+    n: u64,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+impl Keystore {
+    /// Encrypt `wallet`'s private key under `password`.
+    pub fn encrypt(wallet: &Wallet, password: &str, params: ScryptParams) -> Result<Self> {
+        let metadata = KeystoreMetadata {
+            created_at: unix_now(),
+            ..KeystoreMetadata::default()
+        };
+        Self::seal(wallet, password, params, random_id(), metadata)
+    }
+
+    /// Decrypt the private key with `password`.
+    ///
+    /// A wrong password fails with a verification error before anything is
+    /// decrypted.
+    pub fn decrypt(&self, password: &str) -> Result<Wallet> {
+        self.check_format()?;
+        let params = self.scrypt_params()?;
+        let salt = decode_field("salt", &self.crypto.kdfparams.salt)?;
+        let iv = decode_field("iv", &self.crypto.cipherparams.iv)?;
+        let ciphertext = decode_field("ciphertext", &self.crypto.ciphertext)?;
+        let mac = decode_field("mac", &self.crypto.mac)?;
+        if self.crypto.kdfparams.dklen != DERIVED_KEY_LEN {
+            return Err(Error::validation(
+                "dklen",
+                format!("Expected a {}-byte derived key", DERIVED_KEY_LEN),
+            ));
+        }
+        if iv.len() != IV_LEN || ciphertext.len() != 32 {
+            return Err(Error::validation(
+                "crypto",
+                "Keystore does not hold a 32-byte secp256k1 key",
+            ));
+        }
+
+        let derived = derive_key(password, &salt, params)?;
+        let expected = keystore_mac(&derived, &ciphertext);
+        if !bool::from(expected.as_slice().ct_eq(&mac)) {
+            return Err(CryptoError::verification_failed("Keystore password is incorrect").into());
+        }
+
+        let mut secret = Zeroizing::new([0u8; 32]);
+        secret.copy_from_slice(&ciphertext);
+        apply_cipher(&derived, &iv, &mut secret[..]);
+        let wallet = Wallet::from_private_key(SecretKeyMaterial::from_bytes(&secret))?;
+        if let Some(address) = self.address()?
+            && address != wallet.address()
+        {
+            return Err(CryptoError::KeyMismatch {
+                expected: address.to_string(),
+                actual: wallet.address().to_string(),
+            }
+            .into());
+        }
+        Ok(wallet)
+    }
+
+    /// Decrypt the private key and record the use in the metadata.
+    ///
+    /// Save the keystore afterwards to keep the updated `last_used`.
+    pub fn unlock(&mut self, password: &str) -> Result<Wallet> {
+        let wallet = self.decrypt(password)?;
+        let mut metadata = self.metadata.unwrap_or_default();
+        metadata.last_used = Some(unix_now());
+        self.metadata = Some(metadata);
+        Ok(wallet)
+    }
+
+    /// Re-encrypt the key under `new_password` with the current scrypt cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The current password
+    /// * `new_password` - The password the returned keystore opens with
+    ///
+    /// # Returns
+    ///
+    /// A keystore with the same ID and creation time, a fresh salt and IV,
+    /// and `rotated_at` set to now.
+    pub fn rotate(&self, password: &str, new_password: &str) -> Result<Self> {
+        let wallet = self.decrypt(password)?;
+        self.reseal(&wallet, new_password, self.scrypt_params()?)
+    }
+
+    /// Re-encrypt the key under the same password with at least the cost
+    /// in `params`.
+    ///
+    /// Each scrypt parameter is raised to the one in `params` and none is
+    /// lowered, so a keystore stronger than `params` in one parameter keeps
+    /// it.
+    pub fn upgrade(&self, password: &str, params: ScryptParams) -> Result<Self> {
+        let wallet = self.decrypt(password)?;
+        let params = self.scrypt_params()?.at_least(&params)?;
+        self.reseal(&wallet, password, params)
+    }
+
+    /// The keystore's UUID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The address recorded in the keystore, if any.
+    ///
+    /// The address is stored unencrypted, so it is only trustworthy once
+    /// [`decrypt`](Self::decrypt) has checked it against the key.
+    pub fn address(&self) -> Result<Option<Address>> {
+        self.address
+            .as_deref()
+            .map(|address| {
+                let address = address.strip_prefix("0x").unwrap_or(address);
+                address.parse::<Address>().map_err(|e| {
+                    Error::validation("address", format!("Invalid keystore address: {}", e))
+                })
+            })
+            .transpose()
+    }
+
+    /// The scrypt cost the key is encrypted with.
+    pub fn scrypt_params(&self) -> Result<ScryptParams> {
+        let kdf = &self.crypto.kdfparams;
+        if !kdf.n.is_power_of_two() || kdf.n < 2 {
+            return Err(Error::validation(
+                "n",
+                format!("Scrypt cost {} is not a power of two", kdf.n),
+            ));
+        }
+        let log_n = u8::try_from(kdf.n.trailing_zeros())
+            .map_err(|_| Error::validation("n", "Scrypt cost is out of range"))?;
+        ScryptParams::new(log_n, kdf.r, kdf.p)
+    }
+
+    /// Audit metadata, absent for keystores written by other tools.
+    pub fn metadata(&self) -> Option<&KeystoreMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Parse a keystore from its JSON form.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let keystore: Self = serde_json::from_str(json)?;
+        keystore.check_format()?;
+        Ok(keystore)
+    }
+
+    /// The keystore's JSON form.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Read a keystore file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|e| io_error("read", path, e))?;
+        Self::from_json(&json)
+    }
+
+    /// Write the keystore to `path`, replacing any previous file.
+    ///
+    /// The JSON is written to a temporary file next to `path` and renamed
+    /// into place, so an interrupted write never leaves a truncated keystore.
+    /// On Unix the file is readable and writable by its owner only.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(TEMP_SUFFIX);
+        let temp_path = PathBuf::from(temp_path);
+        let json = self.to_json()?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options
+            .open(&temp_path)
+            .map_err(|e| io_error("create", &temp_path, e))?;
+        // A temporary file left by an earlier interrupted save keeps its mode.
+        #[cfg(unix)]
+        file.set_permissions(fs::Permissions::from_mode(0o600))
+            .map_err(|e| io_error("restrict", &temp_path, e))?;
+        file.write_all(json.as_bytes())
+            .and_then(|()| file.sync_all())
+            .map_err(|e| io_error("write", &temp_path, e))?;
+        drop(file);
+        fs::rename(&temp_path, path).map_err(|e| io_error("rename", path, e))
+    }
+
+    /// Encrypt `wallet` under `new_password` as a successor of this
+    /// keystore, keeping its ID and creation time.
+    pub(crate) fn reseal(
+        &self,
+        wallet: &Wallet,
+        new_password: &str,
+        params: ScryptParams,
+    ) -> Result<Self> {
+        if let Some(address) = self.address()?
+            && address != wallet.address()
+        {
+            return Err(CryptoError::KeyMismatch {
+                expected: address.to_string(),
+                actual: wallet.address().to_string(),
+            }
+            .into());
+        }
+        let now = unix_now();
+        let metadata = KeystoreMetadata {
+            rotated_at: Some(now),
+            ..self.metadata.unwrap_or(KeystoreMetadata {
+                created_at: now,
+                ..KeystoreMetadata::default()
+            })
+        };
+        Self::seal(wallet, new_password, params, self.id.clone(), metadata)
+    }
+
+    fn seal(
+        wallet: &Wallet,
+        password: &str,
+        params: ScryptParams,
+        id: String,
+        metadata: KeystoreMetadata,
+    ) -> Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut iv = [0u8; IV_LEN];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut iv);
+
+        let derived = derive_key(password, &salt, params)?;
+        let mut ciphertext = wallet.secret_bytes();
+        apply_cipher(&derived, &iv, &mut ciphertext[..]);
+        let mac = keystore_mac(&derived, &ciphertext[..]);
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            id,
+            address: Some(hex_encode(wallet.address())),
+            crypto: CryptoSection {
+                cipher: CIPHER.to_string(),
+                cipherparams: CipherParams { iv: hex_encode(iv) },
+                ciphertext: hex_encode(&ciphertext[..]),
+                kdf: KDF.to_string(),
+                kdfparams: KdfParams {
+                    dklen: DERIVED_KEY_LEN,
+                    n: params.n(),
+                    r: params.r,
+                    p: params.p,
+                    salt: hex_encode(salt),
+                },
+                mac: hex_encode(mac),
+            },
+            metadata: Some(metadata),
+        })
+    }
+
+    fn check_format(&self) -> Result<()> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(Error::validation(
+                "version",
+                format!("Unsupported keystore version {}", self.version),
+            ));
+        }
+        if !self.crypto.cipher.eq_ignore_ascii_case(CIPHER) {
+            return Err(Error::validation(
+                "cipher",
+                format!("Unsupported keystore cipher {}", self.crypto.cipher),
+            ));
+        }
+        if !self.crypto.kdf.eq_ignore_ascii_case(KDF) {
+            return Err(Error::validation(
+                "kdf",
+                format!("Unsupported keystore KDF {}", self.crypto.kdf),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Keystore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Keystore")
+            .field("id", &self.id)
+            .field("address", &self.address)
+            .field("metadata", &self.metadata)
+            .finish_non_exhaustive()
+    }
+}
+
+/// What [`migrate_keystores`] did with each file.
+#[derive(Debug, Default)]
+pub struct KeystoreMigration {
+    /// Files re-encrypted with the target cost.
+    pub migrated: Vec<PathBuf>,
+    /// Files already at or above the target cost.
+    pub up_to_date: Vec<PathBuf>,
+    /// Files left alone because no password was supplied for them.
+    pub skipped: Vec<PathBuf>,
+    /// Files that could not be read, decrypted or written, with the error.
+    pub failed: Vec<(PathBuf, Error)>,
+}
+
+impl KeystoreMigration {
+    /// Whether no file failed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl Display for KeystoreMigration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(
+            f,
+            "Keystore migration: {} migrated, {} up to date, {} skipped, {} failed",
+            self.migrated.len(),
+            self.up_to_date.len(),
+            self.skipped.len(),
+            self.failed.len()
+        )?;
+        for (path, error) in &self.failed {
+            writeln!(f, "  {}: {}", path.display(), error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Re-encrypt every keystore in `directory` whose scrypt cost is below
+/// `target`.
+///
+/// Files ending in `.json` are treated as keystores and visited in name
+/// order. `password` is asked for each keystore that needs migrating and
+/// may return `None` to skip it. A failure is recorded and the migration
+/// moves on to the next file; a migrated file is replaced atomically, so an
+/// interrupted migration leaves each keystore either old or new.
+///
+/// # Arguments
+///
+/// * `directory` - The directory holding the keystore files
+/// * `target` - The minimum scrypt cost after migration
+/// * `password` - Supplies the password for a file and its keystore
+///
+/// # Returns
+///
+/// Which files were migrated, already up to date, skipped or failed. Only a
+/// directory that cannot be listed fails the whole call.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::utils::{ScryptParams, migrate_keystores};
+/// use zeroize::Zeroizing;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let report = migrate_keystores("./keystores", ScryptParams::STANDARD, |_path, _keystore| {
+///     std::env::var("KEYSTORE_PASSWORD").ok().map(Zeroizing::new)
+/// })?;
+/// print!("{}", report);
+/// # Ok(())
+/// # }
+/// ```
+pub fn migrate_keystores<P, F>(
+    directory: P,
+    target: ScryptParams,
+    mut password: F,
+) -> Result<KeystoreMigration>
+where
+    P: AsRef<Path>,
+    F: FnMut(&Path, &Keystore) -> Option<Zeroizing<String>>,
+{
+    let directory = directory.as_ref();
+    let entries = fs::read_dir(directory).map_err(|e| io_error("list", directory, e))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| io_error("list", directory, e))?.path();
+        if path.is_file()
+            && path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut report = KeystoreMigration::default();
+    for path in paths {
+        let keystore = match Keystore::load(&path) {
+            Ok(keystore) => keystore,
+            Err(error) => {
+                report.failed.push((path, error));
+                continue;
+            }
+        };
+        match keystore.scrypt_params() {
+            Ok(params) if !params.is_weaker_than(&target) => {
+                report.up_to_date.push(path);
+                continue;
+            }
+            Ok(_) => {}
+            Err(error) => {
+                report.failed.push((path, error));
+                continue;
+            }
+        }
+        let Some(secret) = password(&path, &keystore) else {
+            report.skipped.push(path);
+            continue;
+        };
+        match keystore
+            .upgrade(&secret, target)
+            .and_then(|upgraded| upgraded.save(&path))
+        {
+            Ok(()) => report.migrated.push(path),
+            Err(error) => report.failed.push((path, error)),
+        }
+    }
+    Ok(report)
+}
+
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    params: ScryptParams,
+) -> Result<Zeroizing<[u8; DERIVED_KEY_LEN]>> {
+    let mut derived = Zeroizing::new([0u8; DERIVED_KEY_LEN]);
+    scrypt::scrypt(
+        password.as_bytes(),
+        salt,
+        &params.to_scrypt()?,
+        &mut derived[..],
+    )
+    .map_err(|e| CryptoError::key_derivation(format!("scrypt failed: {}", e)))?;
+    Ok(derived)
+}
+
+fn apply_cipher(derived: &[u8; DERIVED_KEY_LEN], iv: &[u8], data: &mut [u8]) {
+    let mut cipher = Aes128Ctr::new(derived[..16].into(), iv.into());
+    cipher.apply_keystream(data);
+}
+
+fn keystore_mac(derived: &[u8; DERIVED_KEY_LEN], ciphertext: &[u8]) -> [u8; 32] {
+    let mut input = Zeroizing::new(Vec::with_capacity(16 + ciphertext.len()));
+    input.extend_from_slice(&derived[16..]);
+    input.extend_from_slice(ciphertext);
+    keccak256(&input[..]).0
+}
+
+fn decode_field(field: &str, value: &str) -> Result<Vec<u8>> {
+    hex_decode(value.strip_prefix("0x").unwrap_or(value))
+        .map_err(|e| Error::validation(field, format!("Invalid hex: {}", e)))
+}
+
+/// A random version 4 UUID.
+fn random_id() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex_encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn io_error(operation: &str, path: &Path, error: std::io::Error) -> Error {
+    Error::storage(format!(
+        "Failed to {} keystore {}: {}",
+        operation,
+        path.display(),
+        error
+    ))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const LIGHT: ScryptParams = ScryptParams {
+        log_n: 4,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn test_keystore_rotate_and_unlock() {
+        let wallet = Wallet::random();
+        let keystore = Keystore::encrypt(&wallet, "first", LIGHT).expect("Encrypts");
+        let json = keystore.to_json().expect("Serializes");
+        assert!(json.contains(r#""cipher": "aes-128-ctr""#));
+        assert!(json.contains(r#""n": 16"#));
+        assert!(json.contains("x-onemoney"));
+
+        let mut loaded = Keystore::from_json(&json).expect("Parses");
+        assert_eq!(loaded.scrypt_params().expect("Params parse"), LIGHT);
+        assert_eq!(
+            loaded.address().expect("Address parses"),
+            Some(wallet.address())
+        );
+        assert!(loaded.decrypt("wrong").is_err());
+        assert_eq!(
+            loaded.unlock("first").expect("Unlocks").address(),
+            wallet.address()
+        );
+        let metadata = *loaded.metadata().expect("Metadata is kept");
+        assert!(metadata.last_used.is_some());
+        assert!(metadata.rotated_at.is_none());
+
+        let rotated = loaded.rotate("first", "second").expect("Rotates");
+        assert_eq!(rotated.id(), keystore.id());
+        assert_ne!(
+            rotated.crypto.kdfparams.salt,
+            keystore.crypto.kdfparams.salt
+        );
+        assert!(rotated.decrypt("first").is_err());
+        assert_eq!(
+            rotated
+                .decrypt("second")
+                .expect("New password opens")
+                .address(),
+            wallet.address()
+        );
+        let rotated_metadata = rotated.metadata().expect("Metadata is kept");
+        assert_eq!(rotated_metadata.created_at, metadata.created_at);
+        assert!(rotated_metadata.rotated_at.is_some());
+
+        // An unlocked wallet rotates without the old password.
+        let by_wallet = wallet.rotate(&rotated, "third").expect("Rotates");
+        assert_eq!(by_wallet.id(), keystore.id());
+        assert!(by_wallet.decrypt("third").is_ok());
+        assert!(Wallet::random().rotate(&rotated, "third").is_err());
+
+        // A keystore written by another tool has no extension object.
+        let mut foreign: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        if let Some(object) = foreign.as_object_mut() {
+            object.remove("x-onemoney");
+        }
+        let foreign = Keystore::from_json(&foreign.to_string()).expect("Parses");
+        assert!(foreign.metadata().is_none());
+        assert!(foreign.decrypt("first").is_ok());
+    }
+
+    #[test]
+    fn test_migrate_keystores_upgrades_weak_files() {
+        let directory = env::temp_dir().join(format!(
+            "onemoney-keystores-{}-{}",
+            std::process::id(),
+            random_id()
+        ));
+        fs::create_dir_all(&directory).expect("Directory is created");
+        let target = ScryptParams::new(5, 8, 1).expect("Params are valid");
+        let weak = Wallet::random();
+        let strong = Wallet::random();
+        let unknown = Wallet::random();
+        Keystore::encrypt(&weak, "pw", LIGHT)
+            .and_then(|keystore| keystore.save(directory.join("a.json")))
+            .expect("Saves");
+        Keystore::encrypt(&strong, "pw", target)
+            .and_then(|keystore| keystore.save(directory.join("b.json")))
+            .expect("Saves");
+        Keystore::encrypt(&unknown, "other", LIGHT)
+            .and_then(|keystore| keystore.save(directory.join("c.json")))
+            .expect("Saves");
+        fs::write(directory.join("d.json"), "not a keystore").expect("Writes");
+
+        let report = migrate_keystores(&directory, target, |path, _| {
+            (!path.ends_with("c.json")).then(|| Zeroizing::new("pw".to_string()))
+        })
+        .expect("Directory lists");
+        assert_eq!(report.migrated, vec![directory.join("a.json")]);
+        assert_eq!(report.up_to_date, vec![directory.join("b.json")]);
+        assert_eq!(report.skipped, vec![directory.join("c.json")]);
+        assert_eq!(report.failed.len(), 1);
+        assert!(!report.is_complete());
+
+        let migrated = Keystore::load(directory.join("a.json")).expect("Loads");
+        assert_eq!(migrated.scrypt_params().expect("Params parse"), target);
+        assert_eq!(
+            migrated
+                .decrypt("pw")
+                .expect("Password is unchanged")
+                .address(),
+            weak.address()
+        );
+        assert!(!directory.join("a.json.tmp").exists());
+        #[cfg(unix)]
+        assert_eq!(
+            fs::metadata(directory.join("a.json"))
+                .expect("File exists")
+                .permissions()
+                .mode()
+                & 0o777,
+            0o600
+        );
+
+        // Raising one parameter keeps a stronger other one.
+        let mixed = ScryptParams::new(6, 8, 1).expect("Params are valid");
+        let floor = ScryptParams::new(5, 9, 1).expect("Params are valid");
+        let upgraded = Keystore::encrypt(&weak, "pw", mixed)
+            .and_then(|keystore| keystore.upgrade("pw", floor))
+            .expect("Upgrades");
+        assert_eq!(
+            upgraded.scrypt_params().expect("Params parse"),
+            ScryptParams::new(6, 9, 1).expect("Params are valid")
+        );
+
+        fs::remove_dir_all(&directory).expect("Directory is removed");
+    }
+}
@@ -1,8 +1,18 @@
 //! Utility functions and helper types.
 
 pub mod address;
+pub mod convert;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod units;
+#[cfg(feature = "wallet")]
 pub mod wallet;
 
 // Re-export public interfaces
 pub use address::*;
+pub use convert::*;
+#[cfg(feature = "keystore")]
+pub use keystore::*;
+pub use units::*;
+#[cfg(feature = "wallet")]
 pub use wallet::*;
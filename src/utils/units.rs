@@ -0,0 +1,107 @@
+//! Conversion between base-unit amounts and decimal strings.
+
+use crate::{Error, Result};
+use alloy_primitives::U256;
+
+/// Format a base-unit amount as a decimal string.
+///
+/// Trailing fractional zeros are trimmed, so `1500000` with 6 decimals
+/// formats as `1.5`.
+pub fn format_units(amount: U256, decimals: u8) -> String {
+    let digits = amount.to_string();
+    let decimals = usize::from(decimals);
+    if decimals == 0 {
+        return digits;
+    }
+
+    let padded = if digits.len() <= decimals {
+        format!("{}{}", "0".repeat(decimals + 1 - digits.len()), digits)
+    } else {
+        digits
+    };
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Parse a decimal string such as `1.5` into base units.
+///
+/// The inverse of [`format_units`]. Fractions with more digits than
+/// `decimals` are rejected rather than rounded, as are signs, exponents and
+/// separators.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<U256> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return Err(Error::validation(
+            "amount",
+            format!("'{}' is not a decimal amount", amount),
+        ));
+    }
+    if fraction.len() > usize::from(decimals) {
+        return Err(Error::validation(
+            "amount",
+            format!("'{}' has more than {} fractional digits", amount, decimals),
+        ));
+    }
+
+    let digits = format!(
+        "{}{}{}",
+        integer,
+        fraction,
+        "0".repeat(usize::from(decimals) - fraction.len())
+    );
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(U256::ZERO);
+    }
+    U256::from_str_radix(digits, 10).map_err(|e| {
+        Error::validation(
+            "amount",
+            format!("Amount '{}' is out of range: {}", amount, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_units() {
+        let cases = [
+            (0u64, 6u8, "0"),
+            (1, 6, "0.000001"),
+            (1_500_000, 6, "1.5"),
+            (42, 0, "42"),
+            (1_000_000_000_000_000_000, 18, "1"),
+            (123_456_789, 3, "123456.789"),
+        ];
+        for (amount, decimals, expected) in cases {
+            assert_eq!(format_units(U256::from(amount), decimals), expected);
+            assert_eq!(
+                parse_units(expected, decimals).expect("Formatted amount should parse"),
+                U256::from(amount)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_units_rejects_malformed_amounts() {
+        assert_eq!(
+            parse_units(".5", 2).expect("Leading point is accepted"),
+            U256::from(50u64)
+        );
+        for invalid in ["", ".", "1.2.3", "-1", "1e6", "1,000", "0.0000001"] {
+            assert!(
+                parse_units(invalid, 6).is_err(),
+                "{:?} should be rejected",
+                invalid
+            );
+        }
+    }
+}
@@ -1,16 +1,21 @@
 //! EVM wallet utilities for key generation.
 
 use super::address::public_key_to_address;
-use crate::Result;
+use crate::crypto::{SecretKeyMaterial, Signable, sign_hash_with_key, signing_key_address};
+use crate::{Result, Signature};
 use alloy_primitives::Address;
 use hex::encode as hex_encode;
 use k256::ecdsa::{SigningKey, VerifyingKey};
 use k256::elliptic_curve::rand_core::OsRng;
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use zeroize::Zeroizing;
 
 /// A complete EVM wallet containing private key, public key, and address.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` and `Display` redact the private key; read the field directly to
+/// export it.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct EvmWallet {
     /// Private key as hex string (with 0x prefix).
     pub private_key: String,
@@ -24,12 +29,22 @@ impl Display for EvmWallet {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(
             f,
-            "EVM Wallet:\n  address: {}\n  private_key: {}\n  public_key: {}",
-            self.address, self.private_key, self.public_key
+            "EVM Wallet:\n  address: {}\n  private_key: [REDACTED]\n  public_key: {}",
+            self.address, self.public_key
         )
     }
 }
 
+impl Debug for EvmWallet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("EvmWallet")
+            .field("private_key", &"[REDACTED]")
+            .field("public_key", &self.public_key)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
 impl EvmWallet {
     /// Generate a new random EVM wallet.
     ///
@@ -78,11 +93,138 @@ impl EvmWallet {
     }
 }
 
+/// A secp256k1 account: private key plus the address it controls.
+///
+/// The secret scalar is wiped from memory when the wallet is dropped, and
+/// every hex rendering of it is returned in a [`Zeroizing`] buffer. The
+/// `Debug` output shows only the address.
+#[derive(Clone)]
+pub struct Wallet {
+    signing_key: SigningKey,
+    address: Address,
+}
+
+impl Wallet {
+    /// Generate a new account from the operating system's random source.
+    pub fn random() -> Self {
+        Self::from_signing_key(SigningKey::random(&mut OsRng))
+    }
+
+    /// Load an account from a hex private key (with or without 0x prefix) or
+    /// a [`SecretKeyMaterial`].
+    pub fn from_private_key<K: Into<SecretKeyMaterial>>(private_key_hex: K) -> Result<Self> {
+        Ok(Self::from_signing_key(
+            private_key_hex.into().signing_key()?,
+        ))
+    }
+
+    fn from_signing_key(signing_key: SigningKey) -> Self {
+        let address = signing_key_address(&signing_key);
+        Self {
+            signing_key,
+            address,
+        }
+    }
+
+    /// The address controlled by this account.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// The 33-byte compressed public key as `0x`-prefixed hex.
+    pub fn public_key_compressed(&self) -> String {
+        self.public_key(true)
+    }
+
+    /// The 65-byte uncompressed public key (`0x04` prefix) as `0x`-prefixed hex.
+    pub fn public_key_uncompressed(&self) -> String {
+        self.public_key(false)
+    }
+
+    /// The private key as `0x`-prefixed hex, for export or the signing
+    /// functions that take a key string.
+    pub fn private_key_hex(&self) -> Zeroizing<String> {
+        let bytes = Zeroizing::new(self.signing_key.to_bytes());
+        Zeroizing::new(format!("0x{}", hex_encode(&bytes[..])))
+    }
+
+    /// The private key in zeroizing storage, for the signing functions.
+    pub fn secret_key(&self) -> SecretKeyMaterial {
+        SecretKeyMaterial::from_bytes(&Zeroizing::new(self.signing_key.to_bytes().into()))
+    }
+
+    /// The raw 32-byte private key, for encrypting it into a keystore.
+    #[cfg(feature = "keystore")]
+    pub(crate) fn secret_bytes(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.signing_key.to_bytes().into())
+    }
+
+    /// Sign a payload with this account.
+    pub fn sign<T: Signable>(&self, payload: &T) -> Result<Signature> {
+        sign_hash_with_key(&payload.signature_hash(), self.signing_key.clone())
+    }
+
+    /// Encrypt the account into a [`Keystore`](super::keystore::Keystore)
+    /// opened with `password`.
+    #[cfg(feature = "keystore")]
+    pub fn encrypt(
+        &self,
+        password: &str,
+        params: super::keystore::ScryptParams,
+    ) -> Result<super::keystore::Keystore> {
+        super::keystore::Keystore::encrypt(self, password, params)
+    }
+
+    /// Re-encrypt `keystore`, the keystore this wallet was unlocked from,
+    /// under `new_password`.
+    ///
+    /// The unlocked key stands in for the current password. The keystore's
+    /// ID, creation time and scrypt cost are kept and `rotated_at` is set to
+    /// now; a keystore recording another address is refused.
+    #[cfg(feature = "keystore")]
+    pub fn rotate(
+        &self,
+        keystore: &super::keystore::Keystore,
+        new_password: &str,
+    ) -> Result<super::keystore::Keystore> {
+        keystore.reseal(self, new_password, keystore.scrypt_params()?)
+    }
+
+    /// Export the account in the plain-string [`EvmWallet`] form.
+    ///
+    /// The copy is not zeroized; prefer the accessors on `Wallet` for keys
+    /// that stay in memory.
+    pub fn to_evm_wallet(&self) -> EvmWallet {
+        EvmWallet {
+            private_key: self.private_key_hex().to_string(),
+            public_key: self.public_key_uncompressed(),
+            address: self.address,
+        }
+    }
+
+    fn public_key(&self, compressed: bool) -> String {
+        let point = VerifyingKey::from(&self.signing_key).to_encoded_point(compressed);
+        format!("0x{}", hex_encode(point.as_bytes()))
+    }
+}
+
+impl Debug for Wallet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Wallet")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::is_valid_address_format;
 
     use super::*;
+    use crate::PaymentPayload;
+    use crate::crypto::recover_signer;
+    use alloy_primitives::U256;
+    use std::str::FromStr;
 
     #[test]
     fn test_generate_random_wallet() {
@@ -144,6 +286,8 @@ mod tests {
         assert!(display_str.contains("private_key:"));
         assert!(display_str.contains("public_key:"));
         assert!(display_str.contains(&wallet.address.to_string()));
+        assert!(!display_str.contains(&wallet.private_key[2..]));
+        assert!(!format!("{:?}", wallet).contains(&wallet.private_key[2..]));
     }
 
     #[test]
@@ -159,4 +303,98 @@ mod tests {
         assert_eq!(wallet.public_key, deserialized.public_key);
         assert_eq!(wallet.address, deserialized.address);
     }
+
+    /// (private key, compressed public key, address)
+    const VECTORS: [(&str, &str, &str); 2] = [
+        (
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+        ),
+        (
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+            "0x02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            "0x2B5AD5c4795c026514f8317c7a215E218DcCD6cF",
+        ),
+    ];
+
+    #[test]
+    fn test_wallet_vectors() {
+        for (private_key, compressed, address) in VECTORS {
+            let wallet = Wallet::from_private_key(private_key).expect("Vector key is valid");
+            assert_eq!(
+                wallet.address(),
+                Address::from_str(address).expect("Vector address is valid")
+            );
+            assert_eq!(wallet.public_key_compressed(), compressed);
+            assert_eq!(wallet.private_key_hex().as_str(), private_key);
+
+            let uncompressed = wallet.public_key_uncompressed();
+            assert_eq!(uncompressed.len(), 2 + 130);
+            assert_eq!(
+                public_key_to_address(&uncompressed).expect("Uncompressed key derives address"),
+                wallet.address()
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_wallets_differ_and_round_trip() {
+        let first = Wallet::random();
+        let second = Wallet::random();
+        assert_ne!(first.address(), second.address());
+
+        let restored =
+            Wallet::from_private_key(first.private_key_hex()).expect("Exported key reloads");
+        assert_eq!(restored.address(), first.address());
+        assert_eq!(
+            restored.public_key_compressed(),
+            first.public_key_compressed()
+        );
+    }
+
+    #[test]
+    fn test_wallet_sign_and_debug() {
+        let wallet = Wallet::random();
+        let payload = PaymentPayload {
+            chain_id: 1212101,
+            nonce: 0,
+            recipient: Address::repeat_byte(0x11),
+            value: U256::from(1u64),
+            token: Address::repeat_byte(0x22),
+        };
+        let signature = wallet.sign(&payload).expect("Signing should succeed");
+        let signer = recover_signer(&payload.signature_hash(), &signature)
+            .expect("Signature should recover");
+        assert_eq!(signer, wallet.address());
+
+        let debug = format!("{:?}", wallet);
+        assert!(debug.contains("address"));
+        assert!(!debug.contains(&wallet.private_key_hex()[2..]));
+
+        assert!(Wallet::from_private_key("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_wallet_secret_key_round_trip() {
+        let wallet = Wallet::random();
+        let secret = wallet.secret_key();
+        assert_eq!(secret.expose_secret(), &wallet.private_key_hex()[2..]);
+
+        let restored = Wallet::from_private_key(&secret).expect("Secret reloads");
+        assert_eq!(restored.address(), wallet.address());
+        assert_eq!(
+            crate::crypto::private_key_to_address(secret).expect("Secret derives address"),
+            wallet.address().to_checksum(None)
+        );
+    }
+
+    #[test]
+    fn test_wallet_to_evm_wallet() {
+        let wallet = Wallet::random();
+        let exported = wallet.to_evm_wallet();
+        assert_eq!(exported.address, wallet.address());
+        assert_eq!(exported.private_key, wallet.private_key_hex().as_str());
+        assert_eq!(exported.public_key, wallet.public_key_uncompressed());
+    }
 }
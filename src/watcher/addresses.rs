@@ -0,0 +1,644 @@
+//! Tracking of transactions that touch a set of addresses.
+
+use crate::Result;
+use crate::client::Client;
+use crate::decode::{PartyRole, TransactionSummary};
+use crate::responses::Transaction;
+use crate::storage::{Storage, StorageExt, namespaces};
+use crate::watcher::reorg::{DEFAULT_REORG_WINDOW, Reorg, ReorgDetector};
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Default number of checkpoints scanned by one [`AddressWatcher::poll`].
+pub const DEFAULT_MAX_CHECKPOINTS_PER_POLL: u64 = 100;
+
+/// Whether a watched address sent or received in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityDirection {
+    /// The address received tokens or was the subject of the operation.
+    Incoming,
+    /// The address signed the transaction or had tokens taken from it.
+    Outgoing,
+}
+
+impl Display for ActivityDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ActivityDirection::Incoming => write!(f, "Incoming"),
+            ActivityDirection::Outgoing => write!(f, "Outgoing"),
+        }
+    }
+}
+
+/// A transaction touching a watched address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressActivity {
+    /// Checkpoint holding the transaction.
+    pub checkpoint: u64,
+    /// The watched address involved.
+    pub address: Address,
+    /// How the address is involved.
+    pub direction: ActivityDirection,
+    /// Decoded description of the transaction.
+    pub summary: TransactionSummary,
+    /// The transaction itself.
+    pub transaction: Transaction,
+}
+
+impl Display for AddressActivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} {} for {} at checkpoint {} ({})",
+            self.direction, self.summary.kind, self.address, self.checkpoint, self.transaction.hash
+        )
+    }
+}
+
+/// Resume point of an address watcher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressCursor {
+    /// Last checkpoint fully scanned.
+    pub checkpoint: u64,
+}
+
+type ActivityCallback<'a> = Box<dyn FnMut(&AddressActivity) + Send + 'a>;
+type ReorgCallback<'a> = Box<dyn FnMut(&Reorg) + Send + 'a>;
+
+/// Scans new checkpoints for transactions touching watched addresses.
+///
+/// The watcher keeps a cursor on the last checkpoint it scanned. With
+/// [`AddressWatcher::persist_to`] the cursor is saved after every checkpoint,
+/// so a restarted watcher continues after the last one it finished and no
+/// activity is skipped. Without a saved cursor or
+/// [`AddressWatcher::start_after`], scanning begins at the latest checkpoint.
+///
+/// With [`AddressWatcher::detect_reorgs`] the watcher also checks that each
+/// checkpoint builds on the one scanned before it. When earlier checkpoints
+/// were replaced, the `on_reorg` callbacks run, cached responses for the
+/// replaced range are dropped and the cursor moves back so the next poll
+/// rescans the new fork. Activity reported from the replaced checkpoints may
+/// then be reported again, or not at all if its transaction was dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::Client;
+/// use onemoney_protocol::storage::FileStorage;
+/// use onemoney_protocol::watcher::AddressWatcher;
+/// use alloy_primitives::Address;
+/// use std::str::FromStr;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::testnet()?;
+///     let storage = FileStorage::new("./watcher-state")?;
+///     let treasury = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+///
+///     let mut watcher = AddressWatcher::new(&client)
+///         .watch_address(treasury)
+///         .on_incoming(|activity| println!("{}", activity))
+///         .persist_to(&storage, "treasury")?;
+///     watcher.run(Duration::from_secs(2)).await?;
+///     Ok(())
+/// }
+/// ```
+pub struct AddressWatcher<'a> {
+    client: &'a Client,
+    addresses: BTreeSet<Address>,
+    tokens: BTreeSet<Address>,
+    cursor: Option<u64>,
+    store: Option<(&'a dyn Storage, String)>,
+    max_checkpoints_per_poll: u64,
+    on_incoming: Vec<ActivityCallback<'a>>,
+    on_outgoing: Vec<ActivityCallback<'a>>,
+    reorgs: Option<ReorgDetector>,
+    on_reorg: Vec<ReorgCallback<'a>>,
+}
+
+impl Debug for AddressWatcher<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("AddressWatcher")
+            .field("addresses", &self.addresses)
+            .field("tokens", &self.tokens)
+            .field("cursor", &self.cursor)
+            .field("store", &self.store.as_ref().map(|(_, name)| name))
+            .field("max_checkpoints_per_poll", &self.max_checkpoints_per_poll)
+            .field("on_incoming", &self.on_incoming.len())
+            .field("on_outgoing", &self.on_outgoing.len())
+            .field("reorgs", &self.reorgs)
+            .field("on_reorg", &self.on_reorg.len())
+            .finish()
+    }
+}
+
+impl<'a> AddressWatcher<'a> {
+    /// Create a watcher with nothing to watch.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            addresses: BTreeSet::new(),
+            tokens: BTreeSet::new(),
+            cursor: None,
+            store: None,
+            max_checkpoints_per_poll: DEFAULT_MAX_CHECKPOINTS_PER_POLL,
+            on_incoming: Vec::new(),
+            on_outgoing: Vec::new(),
+            reorgs: None,
+            on_reorg: Vec::new(),
+        }
+    }
+
+    /// Report transactions involving `address`.
+    pub fn watch_address(mut self, address: Address) -> Self {
+        self.addresses.insert(address);
+        self
+    }
+
+    /// Only report transactions for `token`.
+    ///
+    /// Watching no token reports transactions for every token, including
+    /// native transfers.
+    pub fn watch_token(mut self, token: Address) -> Self {
+        self.tokens.insert(token);
+        self
+    }
+
+    /// Call `callback` for every incoming activity.
+    pub fn on_incoming<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&AddressActivity) + Send + 'a,
+    {
+        self.on_incoming.push(Box::new(callback));
+        self
+    }
+
+    /// Call `callback` for every outgoing activity.
+    pub fn on_outgoing<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&AddressActivity) + Send + 'a,
+    {
+        self.on_outgoing.push(Box::new(callback));
+        self
+    }
+
+    /// Check every scanned checkpoint against the last `window` scanned
+    /// before it, rewinding when they were replaced.
+    ///
+    /// Costs one extra header fetch per checkpoint.
+    pub fn detect_reorgs(mut self, window: usize) -> Self {
+        self.reorgs = Some(ReorgDetector::new(window));
+        self
+    }
+
+    /// Call `callback` for every detected reorg.
+    ///
+    /// Turns on reorg detection with [`DEFAULT_REORG_WINDOW`] if
+    /// [`AddressWatcher::detect_reorgs`] has not.
+    pub fn on_reorg<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&Reorg) + Send + 'a,
+    {
+        self.reorgs
+            .get_or_insert_with(|| ReorgDetector::new(DEFAULT_REORG_WINDOW));
+        self.on_reorg.push(Box::new(callback));
+        self
+    }
+
+    /// Scan from the checkpoint after `checkpoint`.
+    ///
+    /// A cursor loaded by a later [`AddressWatcher::persist_to`] takes
+    /// precedence.
+    pub fn start_after(mut self, checkpoint: u64) -> Self {
+        self.cursor = Some(checkpoint);
+        self
+    }
+
+    /// Limit how many checkpoints one poll scans.
+    pub fn max_checkpoints_per_poll(mut self, max: u64) -> Self {
+        self.max_checkpoints_per_poll = max.max(1);
+        self
+    }
+
+    /// Persist the cursor under `name` and resume from a previously saved one.
+    pub fn persist_to(mut self, storage: &'a dyn Storage, name: &str) -> Result<Self> {
+        let saved: Option<AddressCursor> = storage.get_json(namespaces::CURSORS, name)?;
+        if let Some(saved) = saved {
+            self.cursor = Some(saved.checkpoint);
+        }
+        self.store = Some((storage, name.to_string()));
+        Ok(self)
+    }
+
+    /// Last checkpoint fully scanned, if any.
+    pub fn cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    /// Scan the checkpoints produced since the last poll.
+    ///
+    /// At most [`AddressWatcher::max_checkpoints_per_poll`] checkpoints are
+    /// scanned; the rest are picked up by the next poll. Callbacks run as
+    /// activity is found, and the matching activity is also returned.
+    ///
+    /// On error the cursor stays on the last checkpoint scanned completely.
+    /// If checkpoints were completed before the error, the poll returns
+    /// their activity instead of the error, and the next poll starts again
+    /// at the checkpoint that failed; activity found in that checkpoint is
+    /// reported to the callbacks again then.
+    ///
+    /// A detected reorg ends the poll early, returning the activity found
+    /// before it, with the cursor moved back to the last checkpoint that
+    /// was not replaced.
+    pub async fn poll(&mut self) -> Result<Vec<AddressActivity>> {
+        let latest = self.client.get_checkpoint_number().await?.number;
+        let first = match self.cursor {
+            Some(cursor) => cursor.saturating_add(1),
+            None => latest,
+        };
+        if first > latest {
+            return Ok(Vec::new());
+        }
+        let last = latest.min(first.saturating_add(self.max_checkpoints_per_poll - 1));
+
+        let mut found = Vec::new();
+        for number in first..=last {
+            let completed = found.len();
+            match self.scan(number, &mut found).await {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(error) if number == first => return Err(error),
+                Err(_) => {
+                    found.truncate(completed);
+                    break;
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// Scan one checkpoint and move the cursor past it, returning `false`
+    /// when a reorg rewound the cursor instead.
+    async fn scan(&mut self, number: u64, found: &mut Vec<AddressActivity>) -> Result<bool> {
+        if let Some(detector) = self.reorgs.as_mut() {
+            let header = self.client.get_checkpoint_by_number(number, false).await?;
+            if let Some(reorg) = detector.observe(self.client, &header).await? {
+                self.rewind(&reorg)?;
+                return Ok(false);
+            }
+        }
+        let mut transactions = self.client.stream_checkpoint_transactions(number).await?;
+        while let Some(transaction) = transactions.next().await {
+            let transaction = transaction?;
+            if let Some(detector) = self.reorgs.as_mut() {
+                detector.record_transaction(transaction.hash);
+            }
+            self.inspect(number, transaction, found)?;
+        }
+        self.advance(number)?;
+        Ok(true)
+    }
+
+    /// Poll every `interval` until an error occurs.
+    pub async fn run(&mut self, interval: Duration) -> Result<()> {
+        loop {
+            self.poll().await?;
+            sleep(interval).await;
+        }
+    }
+
+    fn inspect(
+        &mut self,
+        checkpoint: u64,
+        transaction: Transaction,
+        found: &mut Vec<AddressActivity>,
+    ) -> Result<()> {
+        let summary = TransactionSummary::from_transaction(&transaction)?;
+        if !self.tokens.is_empty()
+            && !summary
+                .token
+                .is_some_and(|token| self.tokens.contains(&token))
+        {
+            return Ok(());
+        }
+
+        for &address in &self.addresses {
+            for direction in directions(&summary, address) {
+                let activity = AddressActivity {
+                    checkpoint,
+                    address,
+                    direction,
+                    summary: summary.clone(),
+                    transaction: transaction.clone(),
+                };
+                let callbacks = match direction {
+                    ActivityDirection::Incoming => &mut self.on_incoming,
+                    ActivityDirection::Outgoing => &mut self.on_outgoing,
+                };
+                for callback in callbacks.iter_mut() {
+                    callback(&activity);
+                }
+                found.push(activity);
+            }
+        }
+        Ok(())
+    }
+
+    fn rewind(&mut self, reorg: &Reorg) -> Result<()> {
+        self.client.invalidate_reorg(reorg);
+        for callback in self.on_reorg.iter_mut() {
+            callback(reorg);
+        }
+        self.advance(reorg.first_replaced().saturating_sub(1))
+    }
+
+    fn advance(&mut self, checkpoint: u64) -> Result<()> {
+        self.cursor = Some(checkpoint);
+        if let Some((storage, name)) = &self.store {
+            storage.put_json(namespaces::CURSORS, name, &AddressCursor { checkpoint })?;
+        }
+        Ok(())
+    }
+}
+
+/// How `address` is involved in `summary`, outgoing before incoming.
+///
+/// Empty when the address takes no part; both directions when it is, say,
+/// the signer and the recipient of a transfer to itself.
+pub(crate) fn directions(summary: &TransactionSummary, address: Address) -> Vec<ActivityDirection> {
+    let mut incoming = false;
+    let mut outgoing = false;
+    for party in summary.parties.iter().filter(|p| p.address == address) {
+        match party.role {
+            PartyRole::Recipient | PartyRole::Subject | PartyRole::MasterAuthority => {
+                incoming = true
+            }
+            PartyRole::Signer | PartyRole::Source => outgoing = true,
+        }
+    }
+    [
+        (outgoing, ActivityDirection::Outgoing),
+        (incoming, ActivityDirection::Incoming),
+    ]
+    .into_iter()
+    .filter_map(|(hit, direction)| hit.then_some(direction))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::responses::TxPayload;
+    use crate::storage::MemoryStorage;
+    use crate::watcher::reorg::tests::header as reorg_header;
+    use alloy_primitives::B256;
+    use std::sync::{Arc, Mutex};
+
+    const WATCHED: Address = Address::repeat_byte(0xaa);
+    const OTHER: Address = Address::repeat_byte(0xbb);
+    const TOKEN: Address = Address::repeat_byte(0xcc);
+
+    fn transfer(byte: u8, from: Address, recipient: Address) -> Transaction {
+        Transaction {
+            hash: B256::repeat_byte(byte),
+            from,
+            data: TxPayload::TokenTransfer {
+                value: "5".to_string(),
+                recipient,
+                token: Some(TOKEN),
+            },
+            ..Transaction::default()
+        }
+    }
+
+    async fn checkpoint(server: &mut mockito::ServerGuard, number: u64, txs: &[Transaction]) {
+        server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("number".into(), number.to_string()),
+                mockito::Matcher::UrlEncoded("full".into(), "true".into()),
+            ]))
+            .with_status(200)
+            .with_body(format!(
+                r#"{{"number":{number},"timestamp":0,"transactions":{},"size":null}}"#,
+                serde_json::to_string(txs).expect("Transactions should serialize")
+            ))
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_activity_and_persists_cursor() {
+        let mut server = mockito::Server::new_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 5}"#)
+            .create_async()
+            .await;
+        checkpoint(&mut server, 4, &[transfer(1, OTHER, WATCHED)]).await;
+        checkpoint(
+            &mut server,
+            5,
+            &[transfer(2, WATCHED, OTHER), transfer(3, OTHER, OTHER)],
+        )
+        .await;
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let storage = MemoryStorage::new();
+        let incoming = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&incoming);
+
+        let mut watcher = AddressWatcher::new(&client)
+            .watch_address(WATCHED)
+            .watch_token(TOKEN)
+            .on_incoming(move |activity| {
+                seen.lock()
+                    .expect("Lock should not be poisoned")
+                    .push(activity.transaction.hash)
+            })
+            .start_after(3)
+            .persist_to(&storage, "treasury")
+            .expect("Cursor should load");
+
+        let found = watcher.poll().await.expect("Poll should succeed");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].direction, ActivityDirection::Incoming);
+        assert_eq!(found[0].checkpoint, 4);
+        assert_eq!(found[1].direction, ActivityDirection::Outgoing);
+        assert_eq!(watcher.cursor(), Some(5));
+        drop(watcher);
+        assert_eq!(
+            *incoming.lock().expect("Lock should not be poisoned"),
+            vec![B256::repeat_byte(1)]
+        );
+
+        let mut resumed = AddressWatcher::new(&client)
+            .watch_address(WATCHED)
+            .persist_to(&storage, "treasury")
+            .expect("Cursor should load");
+        assert_eq!(resumed.cursor(), Some(5));
+        assert!(
+            resumed
+                .poll()
+                .await
+                .expect("Poll should succeed")
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_keeps_activity_of_completed_checkpoints() {
+        let mut server = mockito::Server::new_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 5}"#)
+            .create_async()
+            .await;
+        checkpoint(&mut server, 4, &[transfer(1, OTHER, WATCHED)]).await;
+        let _failing = server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::UrlEncoded("number".into(), "5".into()))
+            .with_status(400)
+            .with_body(r#"{"error_code":"validation_number","message":"unavailable"}"#)
+            .create_async()
+            .await;
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let storage = MemoryStorage::new();
+        let mut watcher = AddressWatcher::new(&client)
+            .watch_address(WATCHED)
+            .start_after(3)
+            .persist_to(&storage, "treasury")
+            .expect("Cursor should load");
+
+        let found = watcher
+            .poll()
+            .await
+            .expect("Completed checkpoints are returned");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].checkpoint, 4);
+        assert_eq!(watcher.cursor(), Some(4));
+        assert!(watcher.poll().await.is_err());
+        assert_eq!(watcher.cursor(), Some(4));
+    }
+
+    async fn header(
+        server: &mut mockito::ServerGuard,
+        number: u64,
+        hash: u8,
+        parent: u8,
+    ) -> mockito::Mock {
+        server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("number".into(), number.to_string()),
+                mockito::Matcher::UrlEncoded("full".into(), "false".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&reorg_header(number, hash, parent))
+                    .expect("Checkpoint should serialize"),
+            )
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_poll_rewinds_on_reorg() {
+        let mut server = mockito::Server::new_async().await;
+        let number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 5}"#)
+            .create_async()
+            .await;
+        checkpoint(&mut server, 4, &[]).await;
+        checkpoint(&mut server, 5, &[transfer(1, OTHER, WATCHED)]).await;
+        let _four = header(&mut server, 4, 0xa4, 0xa3).await;
+        let five = header(&mut server, 5, 0xa5, 0xa4).await;
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let reorgs = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&reorgs);
+        let mut watcher = AddressWatcher::new(&client)
+            .watch_address(WATCHED)
+            .on_reorg(move |reorg| {
+                seen.lock()
+                    .expect("Lock should not be poisoned")
+                    .push(reorg.clone())
+            })
+            .start_after(3);
+        assert_eq!(watcher.poll().await.expect("Poll should succeed").len(), 1);
+        assert_eq!(watcher.cursor(), Some(5));
+
+        // Checkpoint 5 is replaced and 6 builds on the replacement.
+        number.remove_async().await;
+        five.remove_async().await;
+        let _number = server
+            .mock("GET", "/v1/checkpoints/number")
+            .with_status(200)
+            .with_body(r#"{"number": 6}"#)
+            .create_async()
+            .await;
+        let _five = header(&mut server, 5, 0xb5, 0xa4).await;
+        let _six = header(&mut server, 6, 0xb6, 0xb5).await;
+
+        assert!(
+            watcher
+                .poll()
+                .await
+                .expect("Poll should succeed")
+                .is_empty()
+        );
+        assert_eq!(watcher.cursor(), Some(4));
+        {
+            let reorgs = reorgs.lock().expect("Lock should not be poisoned");
+            assert_eq!(reorgs.len(), 1);
+            assert_eq!(reorgs[0].depth, 1);
+            assert_eq!(reorgs[0].old, B256::repeat_byte(0xa5));
+            assert_eq!(reorgs[0].new, B256::repeat_byte(0xb5));
+            assert_eq!(
+                reorgs[0].orphaned().collect::<Vec<_>>(),
+                vec![B256::repeat_byte(1)]
+            );
+        }
+
+        // The rescan follows the new fork without reporting another reorg.
+        checkpoint(&mut server, 6, &[]).await;
+        assert_eq!(watcher.poll().await.expect("Poll should succeed").len(), 1);
+        assert_eq!(watcher.cursor(), Some(6));
+        assert_eq!(reorgs.lock().expect("Lock should not be poisoned").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_token_filter_skips_other_tokens() {
+        let client = Client::local().expect("Client should build");
+        let mut watcher = AddressWatcher::new(&client)
+            .watch_address(WATCHED)
+            .watch_token(Address::repeat_byte(0x01));
+        let mut found = Vec::new();
+        watcher
+            .inspect(1, transfer(1, OTHER, WATCHED), &mut found)
+            .expect("Transfer should decode");
+        assert!(found.is_empty());
+    }
+}
@@ -0,0 +1,395 @@
+//! Bulk download of historical checkpoints for indexers.
+
+use crate::client::Client;
+use crate::storage::{Storage, StorageExt, namespaces};
+use crate::transport::{BackoffStrategy, ErrorClass, ExponentialBackoff};
+use crate::{Checkpoint, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tokio::time::sleep;
+
+/// Default number of checkpoints a [`Backfill`] downloads at once.
+pub const DEFAULT_BACKFILL_CONCURRENCY: usize = 16;
+
+/// Default number of times a failed checkpoint download is retried.
+pub const DEFAULT_BACKFILL_RETRIES: u32 = 3;
+
+/// Default wait before the first retry of a failed download; each further
+/// retry waits twice as long.
+pub const DEFAULT_BACKFILL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct BackfillCursor {
+    checkpoint: u64,
+}
+
+/// Outcome of a [`Backfill::for_each`] run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BackfillReport {
+    /// Checkpoints passed to the callback.
+    pub checkpoints: u64,
+    /// Downloads that failed and were retried.
+    pub retries: u64,
+    /// Last checkpoint passed to the callback, if any.
+    pub last: Option<u64>,
+}
+
+impl Display for BackfillReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.last {
+            Some(last) => write!(
+                f,
+                "Backfilled {} checkpoints up to #{} ({} retries)",
+                self.checkpoints, last, self.retries
+            ),
+            None => write!(f, "Nothing to backfill"),
+        }
+    }
+}
+
+/// Downloads a range of checkpoints with their transactions, in parallel,
+/// and hands them to a callback in order.
+///
+/// Up to [`Backfill::concurrency`] checkpoints are in flight or waiting for
+/// an earlier one at any time, so memory stays bounded however far behind a
+/// slow download leaves the others. A download failing with a transient
+/// error is retried before the run gives up. With [`Backfill::persist_to`], the last checkpoint handed
+/// to the callback is saved as it goes, and a restarted run resumes after
+/// it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::Client;
+/// use onemoney_protocol::storage::FileStorage;
+/// use onemoney_protocol::watcher::Backfill;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::mainnet()?;
+///     let storage = FileStorage::new("./indexer-state")?;
+///
+///     let report = Backfill::new(&client)
+///         .range(1_000..2_000)
+///         .concurrency(16)
+///         .persist_to(&storage, "history")?
+///         .for_each(|checkpoint| {
+///             println!("Indexing checkpoint #{}", checkpoint.number);
+///             Ok(())
+///         })
+///         .await?;
+///     println!("{}", report);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Backfill<'a> {
+    client: &'a Client,
+    range: (Bound<u64>, Bound<u64>),
+    concurrency: usize,
+    retries: u32,
+    backoff: Arc<dyn BackoffStrategy>,
+    cursor: Option<u64>,
+    store: Option<(&'a dyn Storage, String)>,
+}
+
+impl Debug for Backfill<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Backfill")
+            .field("range", &self.range)
+            .field("concurrency", &self.concurrency)
+            .field("retries", &self.retries)
+            .field("cursor", &self.cursor)
+            .field("store", &self.store.as_ref().map(|(_, name)| name.as_str()))
+            .finish()
+    }
+}
+
+impl<'a> Backfill<'a> {
+    /// Create a backfill from the first checkpoint to the latest.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            range: (Bound::Unbounded, Bound::Unbounded),
+            concurrency: DEFAULT_BACKFILL_CONCURRENCY,
+            retries: DEFAULT_BACKFILL_RETRIES,
+            backoff: Arc::new(ExponentialBackoff::new(DEFAULT_BACKFILL_RETRY_DELAY)),
+            cursor: None,
+            store: None,
+        }
+    }
+
+    /// Download the checkpoints numbered in `range`.
+    ///
+    /// An open end stops at the latest checkpoint when the run starts.
+    pub fn range(mut self, range: impl RangeBounds<u64>) -> Self {
+        self.range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self
+    }
+
+    /// Limit how many checkpoints are downloaded or buffered at once.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set how many times a failed download is retried before the run fails.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Set the delay schedule between retries of a failed download.
+    pub fn backoff<B: BackoffStrategy + 'static>(mut self, backoff: B) -> Self {
+        self.backoff = Arc::new(backoff);
+        self
+    }
+
+    /// Persist progress under `name` and resume after a previously saved
+    /// checkpoint.
+    pub fn persist_to(mut self, storage: &'a dyn Storage, name: &str) -> Result<Self> {
+        let saved: Option<BackfillCursor> = storage.get_json(namespaces::CURSORS, name)?;
+        if let Some(saved) = saved {
+            self.cursor = Some(saved.checkpoint);
+        }
+        self.store = Some((storage, name.to_string()));
+        Ok(self)
+    }
+
+    /// Last checkpoint handed to the callback, including by an earlier run
+    /// loaded with [`Backfill::persist_to`].
+    pub fn cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    /// Download the range and call `callback` for each checkpoint, in
+    /// ascending order.
+    ///
+    /// Checkpoints carry their full transactions. The run stops at the first
+    /// error, whether a download that failed on every retry or one returned
+    /// by the callback; the cursor then stays on the last checkpoint the
+    /// callback accepted.
+    pub async fn for_each<F>(&mut self, mut callback: F) -> Result<BackfillReport>
+    where
+        F: FnMut(Checkpoint) -> Result<()>,
+    {
+        let mut report = BackfillReport::default();
+        let Some((first, last)) = self.resolve_range().await? else {
+            return Ok(report);
+        };
+
+        // Checkpoints from `next_deliver` up to `next_spawn` are either in
+        // flight or downloaded and waiting for an earlier one.
+        let window = self.concurrency as u64;
+        let mut next_spawn = first;
+        let mut next_deliver = first;
+        let mut pending = JoinSet::new();
+        let mut ready = BTreeMap::new();
+        while next_deliver <= last {
+            while next_spawn <= last && next_spawn - next_deliver < window {
+                pending.spawn(download(
+                    self.client.clone(),
+                    next_spawn,
+                    self.retries,
+                    Arc::clone(&self.backoff),
+                ));
+                next_spawn += 1;
+            }
+
+            if let Some(checkpoint) = ready.remove(&next_deliver) {
+                callback(checkpoint)?;
+                self.advance(next_deliver)?;
+                report.checkpoints += 1;
+                report.last = Some(next_deliver);
+                next_deliver += 1;
+                continue;
+            }
+
+            let Some(joined) = pending.join_next().await else {
+                return Err(Error::business_logic(
+                    "backfill",
+                    format!("checkpoint #{} was never downloaded", next_deliver),
+                ));
+            };
+            let (number, checkpoint, retries) = joined
+                .map_err(|e| Error::custom(format!("Checkpoint download task failed: {}", e)))??;
+            if checkpoint.number != number {
+                return Err(Error::business_logic(
+                    "backfill",
+                    format!(
+                        "requested checkpoint #{} but the server returned #{}",
+                        number, checkpoint.number
+                    ),
+                ));
+            }
+            report.retries += u64::from(retries);
+            ready.insert(number, checkpoint);
+        }
+        Ok(report)
+    }
+
+    /// The inclusive range still to download, `None` when it is empty.
+    async fn resolve_range(&self) -> Result<Option<(u64, u64)>> {
+        let mut first = match self.range.0 {
+            Bound::Included(first) => first,
+            Bound::Excluded(first) => first.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        if let Some(cursor) = self.cursor {
+            first = first.max(cursor.saturating_add(1));
+        }
+        let last = match self.range.1 {
+            Bound::Included(last) => last,
+            Bound::Excluded(0) => return Ok(None),
+            Bound::Excluded(end) => end - 1,
+            Bound::Unbounded => self.client.get_checkpoint_number().await?.number,
+        };
+        Ok((first <= last).then_some((first, last)))
+    }
+
+    fn advance(&mut self, checkpoint: u64) -> Result<()> {
+        self.cursor = Some(checkpoint);
+        if let Some((storage, name)) = &self.store {
+            storage.put_json(namespaces::CURSORS, name, &BackfillCursor { checkpoint })?;
+        }
+        Ok(())
+    }
+}
+
+/// Download one checkpoint, retrying transient failures along `backoff`.
+///
+/// Returns the requested number, the checkpoint and the number of retries
+/// it took.
+async fn download(
+    client: Client,
+    number: u64,
+    retries: u32,
+    backoff: Arc<dyn BackoffStrategy>,
+) -> Result<(u64, Checkpoint, u32)> {
+    let mut attempt = 0;
+    loop {
+        match client.get_checkpoint_by_number(number, true).await {
+            Ok(checkpoint) => return Ok((number, checkpoint, attempt)),
+            Err(error) if attempt < retries && ErrorClass::of(&error).is_transient() => {
+                attempt += 1;
+                sleep(backoff.delay(attempt)).await;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::storage::MemoryStorage;
+    use crate::watcher::reorg::tests::header;
+
+    #[tokio::test]
+    async fn test_backfill_delivers_in_order_and_resumes() {
+        let mut server = mockito::Server::new_async().await;
+        for number in 1u64..=6 {
+            server
+                .mock("GET", "/v1/checkpoints/by_number")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("number".into(), number.to_string()),
+                    mockito::Matcher::UrlEncoded("full".into(), "true".into()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    serde_json::to_string(&header(number, number as u8, 0))
+                        .expect("Checkpoint should serialize"),
+                )
+                .create_async()
+                .await;
+        }
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let storage = MemoryStorage::new();
+
+        let mut seen = Vec::new();
+        let mut backfill = Backfill::new(&client)
+            .range(1..=6)
+            .concurrency(3)
+            .persist_to(&storage, "index")
+            .expect("Fresh cursor should load");
+        let error = backfill
+            .for_each(|checkpoint| {
+                if checkpoint.number == 4 {
+                    return Err(Error::custom("Index unavailable"));
+                }
+                seen.push(checkpoint.number);
+                Ok(())
+            })
+            .await
+            .expect_err("Callback error should stop the run");
+        assert!(error.to_string().contains("Index unavailable"));
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert_eq!(backfill.cursor(), Some(3));
+
+        let mut resumed = Backfill::new(&client)
+            .range(1..=6)
+            .persist_to(&storage, "index")
+            .expect("Saved cursor should load");
+        let report = resumed
+            .for_each(|checkpoint| {
+                seen.push(checkpoint.number);
+                Ok(())
+            })
+            .await
+            .expect("Resumed run should finish");
+        assert_eq!(seen, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(report.checkpoints, 3);
+        assert_eq!(report.last, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_backfill_rejects_mismatched_checkpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let not_found = server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::UrlEncoded("number".into(), "1".into()))
+            .with_status(404)
+            .with_body(r#"{"error_code":"resource_not_found","message":"Checkpoint not found"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::UrlEncoded("number".into(), "2".into()))
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&header(5, 5, 4)).expect("Checkpoint should serialize"),
+            )
+            .create_async()
+            .await;
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+
+        let missing = Backfill::new(&client)
+            .range(1..=1)
+            .for_each(|_| Ok(()))
+            .await
+            .expect_err("A missing checkpoint should not be retried");
+        assert!(matches!(missing.root(), Error::ResourceNotFound { .. }));
+        not_found.assert_async().await;
+
+        let error = Backfill::new(&client)
+            .range(2..=2)
+            .for_each(|_| Ok(()))
+            .await
+            .expect_err("A mismatched checkpoint should fail the run");
+        assert!(error.to_string().contains("returned #5"));
+    }
+}
@@ -0,0 +1,319 @@
+//! Notifications of changes to one token balance.
+
+use super::addresses::AddressWatcher;
+use crate::Result;
+use crate::client::Client;
+use crate::responses::TokenBalance;
+use crate::utils::format_units;
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Default delay between checkpoint polls of a [`BalanceStream`].
+pub const DEFAULT_BALANCE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A change to a watched balance and the transaction that caused it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    /// Owner of the balance.
+    pub owner: Address,
+    /// Balance before the change.
+    pub old: TokenBalance,
+    /// Balance after the change.
+    pub new: TokenBalance,
+    /// Checkpoint holding the causing transaction.
+    pub checkpoint: u64,
+    /// Hash of the causing transaction.
+    pub cause_tx: B256,
+}
+
+impl BalanceChange {
+    /// Amount the balance grew by, if it grew.
+    pub fn increase(&self) -> Option<U256> {
+        (self.new.amount > self.old.amount).then(|| self.new.amount - self.old.amount)
+    }
+
+    /// Amount the balance shrank by, if it shrank.
+    pub fn decrease(&self) -> Option<U256> {
+        (self.old.amount > self.new.amount).then(|| self.old.amount - self.new.amount)
+    }
+}
+
+impl Display for BalanceChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (sign, delta) = match (self.increase(), self.decrease()) {
+            (Some(delta), _) => ("+", delta),
+            (_, Some(delta)) => ("-", delta),
+            _ => ("", U256::ZERO),
+        };
+        write!(
+            f,
+            "Balance of {} changed from {} to {} ({}{}) by {} at checkpoint {}",
+            self.owner,
+            self.old,
+            self.new.to_decimal_string(),
+            sign,
+            format_units(delta, self.new.decimals),
+            self.cause_tx,
+            self.checkpoint
+        )
+    }
+}
+
+/// Changes to one owner's balance of one token, found by watching the
+/// transactions that touch the owner.
+///
+/// The first poll reads the starting balance; from then on, every poll that
+/// sees a transaction moving the token in or out of the owner's account
+/// reads the balance again and reports the difference. Balances are read
+/// when the transaction is seen rather than at its checkpoint, so when
+/// several transactions land between two polls the change covers all of
+/// them and names the last one as its cause.
+///
+/// Created with [`Client::watch_balance`].
+#[derive(Debug)]
+pub struct BalanceStream<'a> {
+    client: &'a Client,
+    owner: Address,
+    token: Address,
+    poll_interval: Duration,
+    watcher: Option<AddressWatcher<'a>>,
+    balance: Option<TokenBalance>,
+}
+
+impl<'a> BalanceStream<'a> {
+    fn new(client: &'a Client, owner: Address, token: Address) -> Self {
+        Self {
+            client,
+            owner,
+            token,
+            poll_interval: DEFAULT_BALANCE_POLL_INTERVAL,
+            watcher: None,
+            balance: None,
+        }
+    }
+
+    /// Set the delay between checkpoint polls made by [`BalanceStream::next`].
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// The balance as of the last poll, once the starting balance is known.
+    pub fn balance(&self) -> Option<&TokenBalance> {
+        self.balance.as_ref()
+    }
+
+    /// Wait for the next change, polling every
+    /// [`BalanceStream::poll_interval`].
+    ///
+    /// An error ends only this call; calling again resumes after the last
+    /// checkpoint scanned completely.
+    pub async fn next(&mut self) -> Result<BalanceChange> {
+        loop {
+            if let Some(change) = self.poll().await? {
+                return Ok(change);
+            }
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Scan the checkpoints produced since the last poll and report the
+    /// change they made, if any.
+    pub async fn poll(&mut self) -> Result<Option<BalanceChange>> {
+        let (watcher, old) = match (&mut self.watcher, &self.balance) {
+            (Some(watcher), Some(balance)) => (watcher, balance.clone()),
+            _ => {
+                // Read the checkpoint first: a transaction landing in
+                // between is then both in the starting balance and scanned,
+                // and its unchanged balance is not reported.
+                let checkpoint = self.client.get_checkpoint_number().await?.number;
+                let balance = self.client.get_balance(self.owner, self.token).await?;
+                self.balance = Some(balance.clone());
+                let watcher = self.watcher.insert(
+                    AddressWatcher::new(self.client)
+                        .watch_address(self.owner)
+                        .watch_token(self.token)
+                        .start_after(checkpoint),
+                );
+                (watcher, balance)
+            }
+        };
+
+        let activity = watcher.poll().await?;
+        let Some(cause) = activity.last() else {
+            return Ok(None);
+        };
+        let new = self.client.get_balance(self.owner, self.token).await?;
+        if new.amount == old.amount {
+            return Ok(None);
+        }
+        self.balance = Some(new.clone());
+        Ok(Some(BalanceChange {
+            owner: self.owner,
+            old,
+            new,
+            checkpoint: cause.checkpoint,
+            cause_tx: cause.transaction.hash,
+        }))
+    }
+}
+
+impl Client {
+    /// Watch `owner`'s balance of `token` for changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The wallet address
+    /// * `token` - The token mint address
+    ///
+    /// # Returns
+    ///
+    /// A [`BalanceStream`] yielding a [`BalanceChange`] for every transaction
+    /// that moves the balance.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use onemoney_protocol::Client;
+    /// use alloy_primitives::Address;
+    /// use std::str::FromStr;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::testnet()?;
+    ///     let owner = Address::from_str("0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0")?;
+    ///     let token = Address::from_str("0x1234567890abcdef1234567890abcdef12345678")?;
+    ///
+    ///     let mut changes = client.watch_balance(owner, token);
+    ///     loop {
+    ///         let change = changes.next().await?;
+    ///         println!("{}", change);
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_balance(&self, owner: Address, token: Address) -> BalanceStream<'_> {
+        BalanceStream::new(self, owner, token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::responses::{MintInfo, Transaction, TxPayload};
+
+    const OWNER: Address = Address::repeat_byte(0xaa);
+    const OTHER: Address = Address::repeat_byte(0xbb);
+    const TOKEN: Address = Address::repeat_byte(0xcc);
+
+    async fn mock_once(server: &mut mockito::ServerGuard, path: &str, body: &str) {
+        server
+            .mock("GET", mockito::Matcher::Regex(format!("^{}", path)))
+            .with_status(200)
+            .with_body(body)
+            .expect(1)
+            .create_async()
+            .await;
+    }
+
+    #[test]
+    fn test_balance_change_delta() {
+        let balance = |amount: u64| TokenBalance::new(TOKEN, U256::from(amount), 2);
+        let change = BalanceChange {
+            owner: OWNER,
+            old: balance(100),
+            new: balance(150),
+            checkpoint: 4,
+            cause_tx: B256::repeat_byte(1),
+        };
+        assert_eq!(change.increase(), Some(U256::from(50u64)));
+        assert_eq!(change.decrease(), None);
+        assert!(change.to_string().contains("to 1.5 (+0.5)"));
+
+        let spent = BalanceChange {
+            old: balance(150),
+            new: balance(100),
+            ..change
+        };
+        assert_eq!(spent.decrease(), Some(U256::from(50u64)));
+        assert!(spent.to_string().contains("(-0.5)"));
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_change_with_cause() {
+        let mut server = mockito::Server::new_async().await;
+        mock_once(&mut server, "/v1/checkpoints/number", r#"{"number": 3}"#).await;
+        mock_once(&mut server, "/v1/checkpoints/number", r#"{"number": 4}"#).await;
+        mock_once(
+            &mut server,
+            "/v1/accounts/token_account",
+            r#"{"balance": "100", "nonce": 0}"#,
+        )
+        .await;
+        mock_once(
+            &mut server,
+            "/v1/accounts/token_account",
+            r#"{"balance": "150", "nonce": 0}"#,
+        )
+        .await;
+        let _metadata = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex("^/v1/tokens/token_metadata".into()),
+            )
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&MintInfo {
+                    decimals: 2,
+                    ..MintInfo::default()
+                })
+                .expect("Metadata should serialize"),
+            )
+            .create_async()
+            .await;
+        let incoming = Transaction {
+            hash: B256::repeat_byte(7),
+            from: OTHER,
+            data: TxPayload::TokenTransfer {
+                value: "50".to_string(),
+                recipient: OWNER,
+                token: Some(TOKEN),
+            },
+            ..Transaction::default()
+        };
+        mock_once(
+            &mut server,
+            "/v1/checkpoints/by_number\\?number=4",
+            &format!(
+                r#"{{"number":4,"timestamp":0,"transactions":{},"size":null}}"#,
+                serde_json::to_string(&[incoming]).expect("Transaction should serialize")
+            ),
+        )
+        .await;
+
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+        let mut stream = client.watch_balance(OWNER, TOKEN);
+
+        // The starting balance is read at checkpoint 3 and checkpoint 4
+        // then moves it.
+        let change = stream
+            .poll()
+            .await
+            .expect("Poll should succeed")
+            .expect("Balance should have changed");
+        assert_eq!(change.old.amount, U256::from(100u64));
+        assert_eq!(change.new.amount, U256::from(150u64));
+        assert_eq!(change.checkpoint, 4);
+        assert_eq!(change.cause_tx, B256::repeat_byte(7));
+        assert_eq!(stream.balance(), Some(&change.new));
+
+        assert_eq!(stream.poll().await.expect("Poll should succeed"), None);
+    }
+}
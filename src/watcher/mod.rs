@@ -0,0 +1,14 @@
+//! Watchers that track on-chain state on behalf of the caller.
+
+pub mod addresses;
+pub mod backfill;
+pub mod balances;
+pub mod reorg;
+pub mod transactions;
+
+// Re-export public interfaces
+pub use addresses::*;
+pub use backfill::*;
+pub use balances::*;
+pub use reorg::*;
+pub use transactions::*;
@@ -0,0 +1,294 @@
+//! Detection of checkpoints replaced after they were observed.
+
+use crate::client::Client;
+use crate::{Checkpoint, Error, Result};
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Default number of recent checkpoints a [`ReorgDetector`] remembers.
+pub const DEFAULT_REORG_WINDOW: usize = 64;
+
+/// A checkpoint as a watcher observed it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservedCheckpoint {
+    /// Checkpoint number.
+    pub number: u64,
+    /// Hash of the checkpoint when it was observed.
+    pub hash: B256,
+    /// Hashes of the transactions seen in it.
+    pub transactions: Vec<B256>,
+}
+
+/// Previously observed checkpoints were replaced by a different fork.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reorg {
+    /// Number of observed checkpoints that were replaced.
+    ///
+    /// When every remembered checkpoint was replaced the fork may be deeper
+    /// than the detector's window, and this is the window's length.
+    pub depth: u64,
+    /// Highest replaced checkpoint number.
+    pub checkpoint: u64,
+    /// Hash observed for that checkpoint before the reorg.
+    pub old: B256,
+    /// Hash the network now reports for it.
+    pub new: B256,
+    /// The replaced checkpoints as they were observed, highest first.
+    pub replaced: Vec<ObservedCheckpoint>,
+}
+
+impl Reorg {
+    /// Lowest replaced checkpoint number.
+    pub fn first_replaced(&self) -> u64 {
+        (self.checkpoint + 1).saturating_sub(self.depth)
+    }
+
+    /// Transactions seen in the replaced checkpoints. They may reappear on
+    /// the new fork or be dropped from the chain.
+    pub fn orphaned(&self) -> impl Iterator<Item = B256> + '_ {
+        self.replaced
+            .iter()
+            .flat_map(|checkpoint| checkpoint.transactions.iter().copied())
+    }
+}
+
+impl Display for Reorg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Reorg of depth {} at checkpoint #{}: {} replaced by {}",
+            self.depth, self.checkpoint, self.old, self.new
+        )
+    }
+}
+
+/// Remembers recent checkpoints and notices when one is replaced.
+///
+/// Each checkpoint passed to [`ReorgDetector::observe`] must name the last
+/// one observed as its parent. On a mismatch the detector walks back through
+/// the checkpoints it remembers, comparing each with what the network now
+/// reports, until it finds the common ancestor. The replaced checkpoints are
+/// forgotten, so observing resumes after the ancestor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReorgDetector {
+    window: usize,
+    recent: VecDeque<ObservedCheckpoint>,
+}
+
+impl ReorgDetector {
+    /// Remember up to `window` checkpoints.
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// The most recently observed checkpoint, if any.
+    pub fn last(&self) -> Option<&ObservedCheckpoint> {
+        self.recent.back()
+    }
+
+    /// Observe the next checkpoint's header, returning the reorg it reveals.
+    ///
+    /// A checkpoint that does not directly follow the last one observed
+    /// starts a new chain of observations. When a reorg is returned, the
+    /// header itself is not recorded: observing has to restart after
+    /// [`Reorg::first_replaced`].
+    ///
+    /// A header whose parent is not the last observed checkpoint, while the
+    /// network still reports that checkpoint unchanged, is inconsistent with
+    /// the chain and is rejected without being recorded.
+    pub async fn observe(&mut self, client: &Client, header: &Checkpoint) -> Result<Option<Reorg>> {
+        let follows = self
+            .recent
+            .back()
+            .is_some_and(|last| last.number + 1 == header.number);
+        if !follows {
+            self.recent.clear();
+        }
+        if !follows || self.is_parent(header) {
+            self.push(header);
+            return Ok(None);
+        }
+
+        let tip = self.recent.back().map(|last| (last.number, last.hash));
+        let mut replaced = Vec::new();
+        let mut new = B256::ZERO;
+        let mut depth = 0;
+        while let Some(observed) = self.recent.back() {
+            let current = client
+                .get_checkpoint_by_number(observed.number, false)
+                .await?;
+            if current.hash.hash == observed.hash {
+                break;
+            }
+            if depth == 0 {
+                new = current.hash.hash;
+            }
+            depth += 1;
+            replaced.extend(self.recent.pop_back());
+        }
+        if depth == 0 {
+            return Err(Error::business_logic(
+                "observe checkpoint",
+                format!(
+                    "checkpoint #{} does not descend from the current checkpoint #{}",
+                    header.number,
+                    header.number - 1
+                ),
+            ));
+        }
+
+        Ok(tip.map(|(checkpoint, old)| Reorg {
+            depth,
+            checkpoint,
+            old,
+            new,
+            replaced,
+        }))
+    }
+
+    /// Note a transaction seen in the most recently observed checkpoint.
+    pub fn record_transaction(&mut self, hash: B256) {
+        if let Some(last) = self.recent.back_mut() {
+            last.transactions.push(hash);
+        }
+    }
+
+    fn is_parent(&self, header: &Checkpoint) -> bool {
+        self.recent
+            .back()
+            .is_some_and(|last| last.hash == header.parent_hash.hash)
+    }
+
+    fn push(&mut self, header: &Checkpoint) {
+        if self.recent.len() == self.window {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(ObservedCheckpoint {
+            number: header.number,
+            hash: header.hash.hash,
+            transactions: Vec::new(),
+        });
+    }
+}
+
+impl Default for ReorgDetector {
+    fn default() -> Self {
+        Self::new(DEFAULT_REORG_WINDOW)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::responses::{CheckpointTransactions, Hash};
+
+    pub(crate) fn header(number: u64, hash: u8, parent: u8) -> Checkpoint {
+        let filled = |byte: u8| Hash {
+            hash: B256::repeat_byte(byte),
+        };
+        Checkpoint {
+            hash: filled(hash),
+            parent_hash: filled(parent),
+            state_root: Hash::default(),
+            transactions_root: Hash::default(),
+            receipts_root: Hash::default(),
+            number,
+            timestamp: 0,
+            extra_data: String::new(),
+            transactions: CheckpointTransactions::Hashes(Vec::new()),
+            size: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detector_walks_back_to_common_ancestor() {
+        let mut server = mockito::Server::new_async().await;
+        // The network now has 0x11 at #1 as observed, but 0xb2 at #2 and 0xb3 at #3.
+        for (number, hash, parent) in [(1u64, 0x11u8, 0x10u8), (2, 0xb2, 0x11), (3, 0xb3, 0xb2)] {
+            server
+                .mock("GET", "/v1/checkpoints/by_number")
+                .match_query(mockito::Matcher::AllOf(vec![
+                    mockito::Matcher::UrlEncoded("number".into(), number.to_string()),
+                    mockito::Matcher::UrlEncoded("full".into(), "false".into()),
+                ]))
+                .with_status(200)
+                .with_body(
+                    serde_json::to_string(&header(number, hash, parent))
+                        .expect("Checkpoint should serialize"),
+                )
+                .create_async()
+                .await;
+        }
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+
+        let mut detector = ReorgDetector::new(8);
+        for (number, hash, parent) in [(1u64, 0x11u8, 0x10u8), (2, 0xa2, 0x11), (3, 0xa3, 0xa2)] {
+            let reorg = detector
+                .observe(&client, &header(number, hash, parent))
+                .await
+                .expect("Observation should succeed");
+            assert!(reorg.is_none());
+            detector.record_transaction(B256::repeat_byte(hash));
+        }
+
+        let reorg = detector
+            .observe(&client, &header(4, 0xb4, 0xb3))
+            .await
+            .expect("Walk back should succeed")
+            .expect("Parent mismatch should be a reorg");
+        assert_eq!(reorg.depth, 2);
+        assert_eq!(reorg.checkpoint, 3);
+        assert_eq!(reorg.first_replaced(), 2);
+        assert_eq!(reorg.old, B256::repeat_byte(0xa3));
+        assert_eq!(reorg.new, B256::repeat_byte(0xb3));
+        assert_eq!(
+            reorg.orphaned().collect::<Vec<_>>(),
+            vec![B256::repeat_byte(0xa3), B256::repeat_byte(0xa2)]
+        );
+        assert_eq!(detector.last().map(|last| last.number), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_detector_rejects_header_off_unchanged_tip() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v1/checkpoints/by_number")
+            .match_query(mockito::Matcher::UrlEncoded("number".into(), "2".into()))
+            .with_status(200)
+            .with_body(
+                serde_json::to_string(&header(2, 0xa2, 0x11)).expect("Checkpoint should serialize"),
+            )
+            .create_async()
+            .await;
+        let client = ClientBuilder::new()
+            .network(Network::custom(server.url()))
+            .build()
+            .expect("Client should build");
+
+        let mut detector = ReorgDetector::new(8);
+        for (number, hash, parent) in [(1u64, 0x11u8, 0x10u8), (2, 0xa2, 0x11)] {
+            let reorg = detector
+                .observe(&client, &header(number, hash, parent))
+                .await
+                .expect("Observation should succeed");
+            assert!(reorg.is_none());
+        }
+
+        let result = detector.observe(&client, &header(3, 0xc3, 0xc2)).await;
+        assert!(matches!(result, Err(Error::BusinessLogic { .. })));
+        assert_eq!(
+            detector.last().map(|last| last.hash),
+            Some(B256::repeat_byte(0xa2))
+        );
+    }
+}
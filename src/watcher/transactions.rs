@@ -0,0 +1,324 @@
+//! Tracking of submitted transactions until their receipts are available.
+
+use crate::Result;
+use crate::client::Client;
+use crate::error::Error;
+use crate::responses::TransactionReceipt;
+use crate::storage::{Storage, StorageExt, namespaces};
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A transaction that is being watched but has no receipt yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTransaction {
+    /// Transaction hash.
+    pub hash: B256,
+    /// Unix timestamp (seconds) at which watching started.
+    pub watched_since: u64,
+}
+
+/// Serializable state of a watcher, used to persist pending work across restarts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatcherSnapshot {
+    /// Transactions that were still unresolved when the snapshot was taken.
+    pub pending: Vec<PendingTransaction>,
+}
+
+impl WatcherSnapshot {
+    /// Whether the snapshot holds no pending transactions.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Persist the snapshot under `name` in the cursors namespace.
+    pub fn save(&self, storage: &dyn Storage, name: &str) -> Result<()> {
+        storage.put_json(namespaces::CURSORS, name, self)
+    }
+
+    /// Load a snapshot saved under `name`, or an empty one if none exists.
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self> {
+        Ok(storage
+            .get_json(namespaces::CURSORS, name)?
+            .unwrap_or_default())
+    }
+}
+
+impl Display for WatcherSnapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Watcher Snapshot: {} pending", self.pending.len())
+    }
+}
+
+/// Watches submitted transactions and resolves them to receipts.
+///
+/// [`TransactionWatcher::shutdown`] hands back a [`WatcherSnapshot`] of every
+/// transaction still awaiting a receipt; persisting it and passing it to
+/// [`TransactionWatcher::from_snapshot`] after a restart resumes monitoring
+/// where it left off.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use onemoney_protocol::Client;
+/// use onemoney_protocol::watcher::TransactionWatcher;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::testnet()?;
+///     let mut watcher = TransactionWatcher::new(&client);
+///     watcher.watch("0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777".parse()?);
+///
+///     for receipt in watcher.poll().await? {
+///         println!("{}", receipt);
+///     }
+///
+///     let snapshot = watcher.shutdown();
+///     println!("{}", serde_json::to_string(&snapshot)?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TransactionWatcher<'a> {
+    client: &'a Client,
+    pending: BTreeMap<B256, PendingTransaction>,
+}
+
+impl<'a> TransactionWatcher<'a> {
+    /// Create a watcher with nothing to watch.
+    pub fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    /// Re-arm a watcher from a previously taken snapshot.
+    pub fn from_snapshot(client: &'a Client, snapshot: WatcherSnapshot) -> Self {
+        let pending = snapshot
+            .pending
+            .into_iter()
+            .map(|entry| (entry.hash, entry))
+            .collect();
+        Self { client, pending }
+    }
+
+    /// Start watching a transaction. Watching an already watched hash is a no-op.
+    pub fn watch(&mut self, hash: B256) {
+        self.pending
+            .entry(hash)
+            .or_insert_with(|| PendingTransaction {
+                hash,
+                watched_since: unix_now(),
+            });
+    }
+
+    /// Stop watching a transaction, returning whether it was being watched.
+    pub fn unwatch(&mut self, hash: &B256) -> bool {
+        self.pending.remove(hash).is_some()
+    }
+
+    /// Transactions that are still awaiting a receipt.
+    pub fn pending(&self) -> impl Iterator<Item = &PendingTransaction> {
+        self.pending.values()
+    }
+
+    /// Number of transactions still awaiting a receipt.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether there is nothing left to watch.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Check every pending transaction once and return the receipts that became available.
+    ///
+    /// Resolved transactions stop being watched. Transactions the server does
+    /// not know about yet stay pending; any other error aborts the poll and
+    /// leaves every entry pending, including those resolved earlier in the
+    /// same poll, so their receipts are returned by the next poll.
+    pub async fn poll(&mut self) -> Result<Vec<TransactionReceipt>> {
+        let mut resolved = Vec::new();
+        for hash in self.pending.keys() {
+            match self
+                .client
+                .get_transaction_receipt_by_hash(&hash.to_string())
+                .await
+            {
+                Ok(receipt) => resolved.push((*hash, receipt)),
+                Err(err) if matches!(err.root(), Error::ResourceNotFound { .. }) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(resolved
+            .into_iter()
+            .map(|(hash, receipt)| {
+                self.pending.remove(&hash);
+                receipt
+            })
+            .collect())
+    }
+
+    /// Take a snapshot of the pending transactions without stopping the watcher.
+    pub fn snapshot(&self) -> WatcherSnapshot {
+        WatcherSnapshot {
+            pending: self.pending.values().cloned().collect(),
+        }
+    }
+
+    /// Stop watching and return everything that was still unresolved.
+    pub fn shutdown(self) -> WatcherSnapshot {
+        WatcherSnapshot {
+            pending: self.pending.into_values().collect(),
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network;
+    use crate::client::ClientBuilder;
+    use crate::storage::MemoryStorage;
+
+    const RESOLVED: &str = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    const UNRESOLVED: &str = "0x20e081da293ae3b81e30f864f38f6911663d7f2cf98337fca38db3cf5bbe7a8f";
+
+    fn hash(value: &str) -> B256 {
+        value.parse().expect("Test hash should be valid")
+    }
+
+    fn client_for(url: String) -> Client {
+        ClientBuilder::new()
+            .network(Network::custom(url))
+            .build()
+            .expect("Client should build")
+    }
+
+    #[test]
+    fn test_watch_and_shutdown_snapshot() {
+        let client = Client::local().expect("Client should build");
+        let mut watcher = TransactionWatcher::new(&client);
+        watcher.watch(hash(RESOLVED));
+        watcher.watch(hash(UNRESOLVED));
+        watcher.watch(hash(RESOLVED));
+        assert_eq!(watcher.len(), 2);
+
+        assert!(watcher.unwatch(&hash(RESOLVED)));
+        assert!(!watcher.unwatch(&hash(RESOLVED)));
+
+        let snapshot = watcher.shutdown();
+        assert_eq!(snapshot.pending.len(), 1);
+        assert_eq!(snapshot.pending[0].hash, hash(UNRESOLVED));
+    }
+
+    #[test]
+    fn test_snapshot_save_and_load() {
+        let storage = MemoryStorage::new();
+        assert!(
+            WatcherSnapshot::load(&storage, "payments")
+                .expect("Missing snapshot should load empty")
+                .is_empty()
+        );
+
+        let client = Client::local().expect("Client should build");
+        let mut watcher = TransactionWatcher::new(&client);
+        watcher.watch(hash(UNRESOLVED));
+        let snapshot = watcher.snapshot();
+        snapshot
+            .save(&storage, "payments")
+            .expect("Snapshot should save");
+
+        let loaded = WatcherSnapshot::load(&storage, "payments").expect("Snapshot should load");
+        assert_eq!(loaded, snapshot);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_rearms_watcher() {
+        let client = Client::local().expect("Client should build");
+        let mut watcher = TransactionWatcher::new(&client);
+        watcher.watch(hash(UNRESOLVED));
+        let snapshot = watcher.snapshot();
+
+        let json = serde_json::to_string(&snapshot).expect("Snapshot should serialize");
+        let restored: WatcherSnapshot =
+            serde_json::from_str(&json).expect("Snapshot should deserialize");
+        assert_eq!(restored, snapshot);
+
+        let rearmed = TransactionWatcher::from_snapshot(&client, restored);
+        assert_eq!(rearmed.len(), 1);
+        assert_eq!(
+            rearmed.pending().next().map(|p| p.watched_since),
+            snapshot.pending.first().map(|p| p.watched_since)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_resolves_available_receipts() {
+        let mut server = mockito::Server::new_async().await;
+        let _resolved = server
+            .mock(
+                "GET",
+                format!("/v1/transactions/receipt/by_hash?hash={RESOLVED}").as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"success":true,"transaction_hash":"{RESOLVED}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":10,"fee_used":"0","from":"0x742d35Cc6634C0532925a3b8D91D6F4A81B8Cbc0","recipient":null,"token_address":null}}"#
+            ))
+            .create_async()
+            .await;
+        let _unresolved = server
+            .mock(
+                "GET",
+                format!("/v1/transactions/receipt/by_hash?hash={UNRESOLVED}").as_str(),
+            )
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error_code":"resource_transaction","message":"not found"}"#)
+            .create_async()
+            .await;
+
+        let client = client_for(server.url());
+        let mut watcher = TransactionWatcher::new(&client);
+        watcher.watch(hash(RESOLVED));
+        watcher.watch(hash(UNRESOLVED));
+
+        let receipts = watcher.poll().await.expect("Poll should succeed");
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].transaction_hash, hash(RESOLVED));
+
+        let snapshot = watcher.shutdown();
+        assert_eq!(snapshot.pending.len(), 1);
+        assert_eq!(snapshot.pending[0].hash, hash(UNRESOLVED));
+
+        // A failure later in the poll keeps the receipts fetched before it.
+        let failing = format!("0x{}", "ff".repeat(32));
+        let _failing = server
+            .mock(
+                "GET",
+                format!("/v1/transactions/receipt/by_hash?hash={failing}").as_str(),
+            )
+            .with_status(400)
+            .with_body(r#"{"error_code":"validation_hash","message":"bad hash"}"#)
+            .create_async()
+            .await;
+        let mut watcher = TransactionWatcher::new(&client);
+        watcher.watch(hash(RESOLVED));
+        watcher.watch(hash(&failing));
+        assert!(watcher.poll().await.is_err());
+        assert_eq!(watcher.len(), 2);
+    }
+}
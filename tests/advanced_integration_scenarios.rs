@@ -7,6 +7,8 @@
 //! - Production-like usage scenarios
 //! - Advanced client configuration patterns
 
+#![cfg(feature = "tokens")]
+
 use alloy_primitives::{Address, U256};
 use onemoney_protocol::client::builder::ClientBuilder;
 use onemoney_protocol::client::config::Network;
@@ -373,7 +375,7 @@ fn create_test_client(name: &str) -> Result<Client, Box<dyn Error>> {
 fn create_unreachable_test_client(name: &str) -> Result<Client, Box<dyn Error>> {
     // Use a port that's guaranteed to be unreachable
     let client = ClientBuilder::new()
-        .network(Network::Custom("http://127.0.0.1:19999".into()))  // Different port that should be unreachable
+        .network(Network::custom("http://127.0.0.1:19999"))  // Different port that should be unreachable
         .timeout(Duration::from_secs(2))     // Shorter timeout for faster test
         .build()?;
     println!(
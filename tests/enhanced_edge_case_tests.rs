@@ -8,6 +8,8 @@
 //! - Network interruption simulation
 //! - Performance boundary testing
 
+#![cfg(feature = "client")]
+
 use alloy_primitives::{Address, U256};
 use onemoney_protocol::TokenMintPayload;
 use onemoney_protocol::client::builder::ClientBuilder;
@@ -8,6 +8,8 @@
 //! - Cross-error-type compatibility
 //! - Error serialization and deserialization
 
+#![cfg(feature = "client")]
+
 use alloy_primitives::Address;
 use onemoney_protocol::client::builder::ClientBuilder;
 use onemoney_protocol::{Network, error::*};
@@ -600,7 +602,7 @@ fn test_error_types() {
 async fn test_api_error_responses() {
     // Test how we handle HTTP error responses
     let client = ClientBuilder::new()
-        .network(Network::Custom("http://httpbin.org/status/404".into())) // Returns 404
+        .network(Network::custom("http://httpbin.org/status/404")) // Returns 404
         .timeout(Duration::from_secs(5))
         .build()
         .expect("Client should build");
@@ -7,6 +7,8 @@
 //! - Network configuration and client builder functionality
 //! - HTTP transport error handling
 
+#![cfg(feature = "client")]
+
 use onemoney_protocol::client::builder::ClientBuilder;
 use onemoney_protocol::client::config::Network;
 use onemoney_protocol::client::http::Client;
@@ -3,6 +3,8 @@
 //! These tests verify the complete functionality of the SDK against
 //! a real or mocked OneMoney API server.
 
+#![cfg(feature = "client")]
+
 use alloy_primitives::Address;
 use onemoney_protocol::{Client, ClientBuilder, Network};
 use std::error::Error;
@@ -46,7 +48,7 @@ async fn test_client_creation() -> std::result::Result<(), Box<dyn Error>> {
 
     // Test custom URL
     let _custom_client = ClientBuilder::new()
-        .network(Network::Custom("http://localhost:8080".into()))
+        .network(Network::custom("http://localhost:8080"))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -115,7 +117,7 @@ async fn test_address_validation() -> Result<(), Box<dyn Error>> {
 async fn test_error_handling() -> Result<(), Box<dyn Error>> {
     // Test error handling with unreachable endpoint
     let client = ClientBuilder::new()
-        .network(Network::Custom("http://127.0.0.1:1".into())) // Invalid port
+        .network(Network::custom("http://127.0.0.1:1")) // Invalid port
         .timeout(Duration::from_secs(1))
         .build()?;
 
@@ -143,7 +145,7 @@ async fn test_error_handling() -> Result<(), Box<dyn Error>> {
 async fn test_timeout_handling() -> Result<(), Box<dyn Error>> {
     // Test very short timeout
     let client = ClientBuilder::new()
-        .network(Network::Custom("http://httpbin.org/delay/10".into())) // Delayed response
+        .network(Network::custom("http://httpbin.org/delay/10")) // Delayed response
         .timeout(Duration::from_millis(100))     // Very short timeout
         .build()?;
 
@@ -9,13 +9,18 @@
 //! - Concurrent request handling
 //! - Large response and edge case handling
 
+#![cfg(all(feature = "tokens", feature = "checkpoints", feature = "wallet"))]
+
 use alloy_primitives::{Address, B256, U256};
 use mockito::ServerGuard;
+use onemoney_protocol::client::HttpPutStore;
 use onemoney_protocol::client::builder::ClientBuilder;
-use onemoney_protocol::responses::TransactionResponse;
+use onemoney_protocol::decode::OperationKind;
+use onemoney_protocol::responses::{Transaction, TransactionResponse, TxPayload};
 use onemoney_protocol::{
-    Authority, AuthorityAction, BlacklistAction, Client, MetadataKVPair, Network, PauseAction,
-    Signable, TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload,
+    Authority, AuthorityAction, BlacklistAction, Client, ConfigError, MetadataKVPair, Network,
+    PauseAction, PaymentPayload, RotationOptions, RotationPhase, Signable, SignerRotation,
+    TokenAuthorityPayload, TokenBlacklistPayload, TokenBurnPayload, TokenMetadataDocument,
     TokenMetadataUpdatePayload, TokenMintPayload, TokenPausePayload, TokenWhitelistPayload,
     WhitelistAction,
 };
@@ -46,7 +51,7 @@ mod mock_utils {
     /// Create a test client for mock testing
     pub fn create_mock_client() -> Result<Client, Box<dyn std::error::Error>> {
         Ok(ClientBuilder::new()
-            .network(Network::Custom("http://127.0.0.1:1".into())) // Intentionally unreachable for mock testing
+            .network(Network::custom("http://127.0.0.1:1")) // Intentionally unreachable for mock testing
             .timeout(TEST_TIMEOUT)
             .build()?)
     }
@@ -140,7 +145,7 @@ async fn test_chain_id_mock() -> Result<(), Box<dyn Error>> {
 
     // Create client pointing to mock server
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -151,6 +156,164 @@ async fn test_chain_id_mock() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_requests_move_off_failing_endpoint() -> Result<(), Box<dyn Error>> {
+    let mut primary = setup_mock_server().await;
+    let mut replica = setup_mock_server().await;
+
+    let _failing = primary
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(503)
+        .with_body("unavailable")
+        .expect(1)
+        .create_async()
+        .await;
+    let _healthy = replica
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 12345}"#)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(primary.url()))
+        .endpoint(replica.url())
+        .endpoint_probe_interval(Duration::from_secs(3600))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    // The primary is tried first, then its failure routes traffic to the replica.
+    assert!(client.fetch_chain_id_from_network().await.is_err());
+    for _ in 0..3 {
+        assert_eq!(client.fetch_chain_id_from_network().await?, 12345);
+    }
+
+    let stats = client.endpoint_stats();
+    assert_eq!(stats[0].error_rate, 1.0);
+    assert_eq!(stats[1].samples, 3);
+    _failing.assert_async().await;
+    _healthy.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_response_bytes_rejects_large_bodies() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let _mock = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"chain_id": 12345, "padding": "{}"}}"#,
+            "x".repeat(256)
+        ))
+        .create_async()
+        .await;
+
+    let limited = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .max_response_bytes(64)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let result = limited.fetch_chain_id_from_network().await;
+    assert!(matches!(
+        result,
+        Err(onemoney_protocol::Error::ResponseTooLarge { limit: 64, .. })
+    ));
+
+    let roomy = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .max_response_bytes(4096)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(roomy.fetch_chain_id_from_network().await?, 12345);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_checkpoint_transactions() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let transactions: Vec<Transaction> = (0..3u64)
+        .map(|index| Transaction {
+            hash: B256::repeat_byte(index as u8 + 1),
+            transaction_index: Some(index),
+            ..Transaction::default()
+        })
+        .collect();
+    let body = format!(
+        r#"{{"number":7,"timestamp":0,"transactions":{},"size":null}}"#,
+        serde_json::to_string(&transactions)?
+    );
+    assert!(body.len() > 1024);
+    let _mock = server
+        .mock("GET", "/v1/checkpoints/by_number")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("number".into(), "7".into()),
+            mockito::Matcher::UrlEncoded("full".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_body(body)
+        .create_async()
+        .await;
+
+    // The body exceeds the limit, but each transaction fits within it.
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .max_response_bytes(1024)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let streamed = client
+        .stream_checkpoint_transactions(7)
+        .await?
+        .collect()
+        .await?;
+    assert_eq!(streamed, transactions);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chain_state_refreshes_after_staleness_window() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let epoch = server
+        .mock("GET", "/v1/governances/epoch")
+        .with_status(200)
+        .with_body(
+            r#"{"epoch_id": 4, "certificate_hash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "certificate": {"type": "Epoch"}}"#,
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let checkpoint = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body(r#"{"number": 812}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .state_max_staleness(Duration::from_millis(100))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let state = client.chain_state().await?;
+    assert_eq!((state.epoch, state.checkpoint), (4, 812));
+    // Served from the cache inside the staleness window.
+    assert_eq!(client.chain_state().await?, state);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(client.chain_state().await?, state);
+
+    epoch.assert_async().await;
+    checkpoint.assert_async().await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_account_nonce_mock() -> Result<(), Box<dyn Error>> {
     let mut server = setup_mock_server().await;
@@ -169,7 +332,7 @@ async fn test_account_nonce_mock() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -182,6 +345,49 @@ async fn test_account_nonce_mock() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_account_nonces_prefetch_seeds_nonce_manager() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let accounts = [Address::repeat_byte(0x11), Address::repeat_byte(0x22)];
+    let mut lookups = Vec::new();
+    for (account, nonce) in accounts.iter().zip([3u64, 9]) {
+        lookups.push(
+            server
+                .mock("GET", "/v1/accounts/nonce")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "address".into(),
+                    account.to_string(),
+                ))
+                .with_status(200)
+                .with_body(format!(r#"{{"nonce": {nonce}}}"#))
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let nonces = client
+        .get_account_nonces_with_concurrency(vec![accounts[0], accounts[1], accounts[0]], 2)
+        .await?;
+    assert_eq!(nonces.len(), 2);
+    assert_eq!(nonces[&accounts[0]], 3);
+    assert_eq!(nonces[&accounts[1]], 9);
+
+    // Prefetched nonces are handed out without another lookup.
+    assert_eq!(client.next_nonce(accounts[1]).await?, 9);
+    assert_eq!(client.next_nonce(accounts[1]).await?, 10);
+    for lookup in lookups {
+        lookup.assert_async().await;
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_token_metadata_mock() -> Result<(), Box<dyn Error>> {
     let mut server = setup_mock_server().await;
@@ -218,7 +424,7 @@ async fn test_token_metadata_mock() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -247,7 +453,7 @@ async fn test_latest_state_mock() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -276,7 +482,7 @@ async fn test_http_error_responses() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -301,7 +507,7 @@ async fn test_api_rate_limiting_simulation() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -325,7 +531,7 @@ async fn test_invalid_json_response() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -363,7 +569,7 @@ async fn test_missing_fields_in_response() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -373,6 +579,48 @@ async fn test_missing_fields_in_response() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_strict_responses_reject_unknown_fields() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::Error as SdkError;
+
+    let mut server = setup_mock_server().await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 7, "pending_nonce": 9}"#)
+        .create_async()
+        .await;
+    let address = Address::from_str("0x1111111111111111111111111111111111111111")?;
+
+    let tolerant = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(tolerant.get_account_nonce(address).await?.nonce, 7);
+
+    let strict = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .strict_responses(true)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let error = strict
+        .get_account_nonce(address)
+        .await
+        .expect_err("Unknown field should be rejected");
+    match error {
+        SdkError::UnknownFields { endpoint, fields } => {
+            assert_eq!(endpoint, "/v1/accounts/nonce");
+            assert_eq!(fields, vec!["pending_nonce"]);
+        }
+        other => panic!("Expected unknown fields, got {other:?}"),
+    }
+
+    Ok(())
+}
+
 //
 // ============================================================================
 // NETWORK AND TIMEOUT MOCK TESTS
@@ -394,7 +642,7 @@ async fn test_network_timeout_mock() -> Result<(), Box<dyn Error>> {
 
     // Create client with very short timeout
     let client = ClientBuilder::new()
-        .network(Network::Custom("http://127.0.0.1:1".into())) // Connect to nothing
+        .network(Network::custom("http://127.0.0.1:1")) // Connect to nothing
         .timeout(Duration::from_millis(100))
         .build()?;
 
@@ -417,7 +665,7 @@ async fn test_content_type_validation() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -447,7 +695,7 @@ async fn test_large_response_handling() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(10)) // Longer timeout for large response
         .build()?;
 
@@ -486,18 +734,15 @@ async fn test_multiple_concurrent_requests() -> Result<(), Box<dyn Error>> {
         .expect_at_least(3) // Expect at least 3 calls
         .create();
 
-    let _client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
-    // Make multiple concurrent requests
+    // Make multiple concurrent requests through clones of one client
     let mut handles = Vec::new();
     for i in 0..5 {
-        let client_for_task = ClientBuilder::new()
-            .network(Network::Custom(server.url().into()))
-            .timeout(Duration::from_secs(5))
-            .build()?;
+        let client_for_task = client.clone();
         let handle = tokio::spawn(async move {
             println!("Starting request {}", i);
             client_for_task.fetch_chain_id_from_network().await
@@ -903,7 +1148,7 @@ async fn test_mock_response_consistency() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -944,7 +1189,7 @@ async fn test_mock_error_response_formats() -> Result<(), Box<dyn Error>> {
             .create();
 
         let client = ClientBuilder::new()
-            .network(Network::Custom(server.url().into()))
+            .network(Network::custom(server.url()))
             .timeout(Duration::from_secs(5))
             .build()?;
 
@@ -970,7 +1215,7 @@ async fn test_mock_server_edge_cases() -> Result<(), Box<dyn Error>> {
         .create();
 
     let client = ClientBuilder::new()
-        .network(Network::Custom(server.url().into()))
+        .network(Network::custom(server.url()))
         .timeout(Duration::from_secs(5))
         .build()?;
 
@@ -980,3 +1225,3047 @@ async fn test_mock_server_edge_cases() -> Result<(), Box<dyn Error>> {
     println!("Empty response error (expected): {:?}", result.unwrap_err());
     Ok(())
 }
+
+#[tokio::test]
+async fn test_payment_idempotency_key_header() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 3,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let expected_key = payload.signature_hash().to_string();
+
+    let mock = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_header("idempotency-key", expected_key.as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"hash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777"}"#,
+        )
+        .create();
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .idempotency_keys(true)
+        .build()?;
+
+    client
+        .send_payment(payload, mock_utils::test_private_key())
+        .await?;
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_recover_journal_resumes_signed_entry() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::storage::MemoryStorage;
+    use onemoney_protocol::{JournalEntry, JournalState, sign_transaction_payload};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let payment = |nonce| PaymentPayload {
+        chain_id: 1212101,
+        nonce,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+
+    // A previous process signed order-1 and order-3 and recorded order-2
+    // before crashing.
+    let journal = MemoryStorage::new();
+    for (key, nonce) in [("order-1", 3), ("order-3", 5)] {
+        let mut signed = JournalEntry::intent(key, &payment(nonce))?;
+        signed.mark_signed(sign_transaction_payload(
+            &payment(nonce),
+            mock_utils::test_private_key(),
+        )?);
+        signed.save(&journal)?;
+    }
+    JournalEntry::intent("order-2", &payment(4))?.save(&journal)?;
+
+    let submission = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"nonce": 3}"#.to_string(),
+        ))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+    // order-3 already landed, but the server does not say as what.
+    let _duplicate = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"nonce": 5}"#.to_string(),
+        ))
+        .with_status(409)
+        .with_body(r#"{"error_code": "duplicate_submission", "message": "Already submitted"}"#)
+        .create_async()
+        .await;
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{tx_hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"{}","recipient":"{}","token_address":"{}"}}"#,
+            addresses.authority_address, addresses.recipient, addresses.token_mint
+        ))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let recovery = client.recover_journal(&journal).await?;
+    assert!(!recovery.is_complete());
+    assert!(recovery.pending.is_empty());
+    assert_eq!(recovery.confirmed.len(), 1);
+    assert_eq!(recovery.confirmed[0].key, "order-1");
+    assert_eq!(recovery.unresolved.len(), 1);
+    assert_eq!(recovery.unresolved[0].key, "order-3");
+    assert_eq!(recovery.unsigned.len(), 1);
+    assert_eq!(recovery.unsigned[0].key, "order-2");
+
+    // Submitting order-1 again finds it confirmed and sends nothing.
+    let receipt = client
+        .submit_journaled(
+            &journal,
+            "order-1",
+            payment(3),
+            mock_utils::test_private_key(),
+        )
+        .await?;
+    assert!(receipt.success);
+    let entry = JournalEntry::load(&journal, "order-1")?.expect("Entry is kept");
+    assert_eq!(entry.state, JournalState::Confirmed);
+    assert_eq!(entry.transaction_hash, Some(B256::from_str(tx_hash)?));
+    submission.assert_async().await;
+
+    // A signed key cannot be reused for another payload.
+    let reused = client
+        .submit_journaled(
+            &journal,
+            "order-1",
+            payment(6),
+            mock_utils::test_private_key(),
+        )
+        .await;
+    assert!(matches!(
+        reused,
+        Err(onemoney_protocol::Error::Validation { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_payment_reference_recorded_and_resolved() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::PaymentReference;
+    use onemoney_protocol::storage::MemoryStorage;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 3,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let signature_hash = payload.signature_hash();
+
+    let _payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .create_async()
+        .await;
+    let _transaction = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"hash":"{tx_hash}","checkpoint_hash":null,"checkpoint_number":null,"transaction_index":null,"chain_id":1212101,"from":"0x1111111111111111111111111111111111111111","nonce":3,"fee":"0","signature":{{"r":"0x1","s":"0x1","v":0}},"transaction_type":"TokenTransfer","data":{{"value":"1000","recipient":"{}","token":"{}"}}}}"#,
+            addresses.recipient, addresses.token_mint
+        ))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let storage = MemoryStorage::new();
+
+    let invalid = client
+        .send_payment_with_reference(
+            payload.clone(),
+            "",
+            &storage,
+            mock_utils::test_private_key(),
+        )
+        .await;
+    assert!(invalid.is_err());
+    assert!(PaymentReference::load(&storage, signature_hash)?.is_none());
+
+    let response = client
+        .send_payment_with_reference(
+            payload,
+            "INV-2024-0042",
+            &storage,
+            mock_utils::test_private_key(),
+        )
+        .await?;
+    let record =
+        PaymentReference::load(&storage, signature_hash)?.ok_or("Reference should be stored")?;
+    assert_eq!(record.reference, "INV-2024-0042");
+    assert_eq!(record.transaction_hash, Some(response.hash));
+
+    let transaction = client.get_transaction_by_hash(tx_hash).await?;
+    assert_eq!(
+        PaymentReference::for_transaction(&storage, &transaction)?,
+        Some(record)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_payment_duplicate_submission() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 4,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let expected_key = payload.signature_hash().to_string();
+
+    let _mock = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_header("idempotency-key", expected_key.as_str())
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error_code": "duplicate_submission", "message": "already submitted"}"#)
+        .create();
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .idempotency_keys(true)
+        .build()?;
+
+    let error = client
+        .send_payment(payload, mock_utils::test_private_key())
+        .await
+        .expect_err("Duplicate submission should fail");
+
+    match error {
+        onemoney_protocol::Error::DuplicateSubmission { original_hash } => {
+            // The server message names no hash, so none is reported.
+            assert_eq!(original_hash, None);
+        }
+        other => panic!("Expected DuplicateSubmission, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rotate_signer_flow() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let token = "0xabcdef1234567890abcdef1234567890abcdef12";
+    let old_signer = "0x1111111111111111111111111111111111111111";
+    let new_signer = "0x2222222222222222222222222222222222222222";
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let metadata = |pause_authorities: &str| {
+        format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{new_signer}","mint_burn_authorities":[],"pause_authorities":[{pause_authorities}],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#
+        )
+    };
+
+    let _chain = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 7}"#)
+        .create_async()
+        .await;
+    let authority = server
+        .mock("POST", "/v1/tokens/grant_authority")
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(3)
+        .create_async()
+        .await;
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{tx_hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"{old_signer}","recipient":null,"token_address":"{token}"}}"#
+        ))
+        .create_async()
+        .await;
+    // The master mint/burn authority moves with its grant. After the grants
+    // both signers hold the pause role; after the revoke only the new one does.
+    let after_grant = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(metadata(&format!(r#""{old_signer}","{new_signer}""#)))
+        .expect(1)
+        .create_async()
+        .await;
+    let _after_revoke = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(metadata(&format!(r#""{new_signer}""#)))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let rotation = SignerRotation::new(
+        Address::from_str(token)?,
+        Address::from_str(old_signer)?,
+        Address::from_str(new_signer)?,
+    )
+    .role(Authority::Pause, U256::ZERO)
+    .role(Authority::MasterMintBurn, U256::ZERO);
+
+    let report = client
+        .rotate_signer(&rotation, mock_utils::test_private_key())
+        .await?;
+
+    authority.assert_async().await;
+    after_grant.assert_async().await;
+    assert!(report.is_complete());
+    assert_eq!(report.steps.len(), 3);
+    assert_eq!(report.steps[0].action, AuthorityAction::Grant);
+    assert_eq!(report.steps[0].address, Address::from_str(new_signer)?);
+    assert_eq!(report.steps[2].action, AuthorityAction::Revoke);
+    assert_eq!(report.steps[2].authority, Authority::Pause);
+    assert_eq!(report.retired_signer, Address::from_str(old_signer)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rotate_authority_reports_failed_revoke() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let token = "0xabcdef1234567890abcdef1234567890abcdef12";
+    let old_authority = "0x1111111111111111111111111111111111111111";
+    let new_authority = "0x2222222222222222222222222222222222222222";
+    let grant_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let revoke_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd8888";
+
+    let metadata = |pause_authorities: &str| {
+        format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[{pause_authorities}],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#
+        )
+    };
+    let receipt = |hash: &str, success: bool| {
+        format!(
+            r#"{{"success":{success},"transaction_hash":"{hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"{old_authority}","recipient":null,"token_address":"{token}"}}"#
+        )
+    };
+
+    let _chain = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 7}"#)
+        .create_async()
+        .await;
+    let grant = server
+        .mock("POST", "/v1/tokens/grant_authority")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"action":"Grant","nonce":7}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{grant_hash}"}}"#))
+        .create_async()
+        .await;
+    let revoke = server
+        .mock("POST", "/v1/tokens/grant_authority")
+        .match_body(mockito::Matcher::PartialJsonString(
+            r#"{"action":"Revoke","nonce":8}"#.into(),
+        ))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{revoke_hash}"}}"#))
+        .create_async()
+        .await;
+    for (hash, success) in [(grant_hash, true), (revoke_hash, false)] {
+        server
+            .mock("GET", "/v1/transactions/receipt/by_hash")
+            .match_query(mockito::Matcher::UrlEncoded("hash".into(), hash.into()))
+            .with_status(200)
+            .with_body(receipt(hash, success))
+            .create_async()
+            .await;
+    }
+    // Before the grant only the old address holds the role, afterwards both do.
+    let _before = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(metadata(&format!(r#""{old_authority}""#)))
+        .expect(1)
+        .create_async()
+        .await;
+    let _after_grant = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(metadata(&format!(r#""{old_authority}","{new_authority}""#)))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let report = client
+        .rotate_authority(
+            Address::from_str(token)?,
+            Address::from_str(old_authority)?,
+            Address::from_str(new_authority)?,
+            Authority::Pause,
+            RotationOptions::new().receipt_timeout(TEST_TIMEOUT),
+            mock_utils::test_private_key(),
+        )
+        .await?;
+
+    grant.assert_async().await;
+    revoke.assert_async().await;
+    assert!(!report.is_complete());
+    assert_eq!(report.steps.len(), 1);
+    assert_eq!(report.steps[0].action, AuthorityAction::Grant);
+    let failure = report.failure.ok_or("Revoke failure should be reported")?;
+    assert_eq!(failure.phase, RotationPhase::Revoke);
+    assert!(failure.guidance.contains(&format!(
+        "Revoke it from {}",
+        Address::from_str(old_authority)?
+    )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_token_metadata_flow() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let token = "0xabcdef1234567890abcdef1234567890abcdef12";
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let document =
+        TokenMetadataDocument::new("Dollar", "USD1").image("https://example.com/usd1.png");
+    let document_hash = document.content_hash()?;
+    let path = format!("/metadata/{}.json", hex::encode(document_hash));
+
+    let stored = server
+        .mock("PUT", path.as_str())
+        .match_header("content-type", "application/json")
+        .match_body(mockito::Matcher::JsonString(serde_json::to_string(
+            &document,
+        )?))
+        .with_status(201)
+        .create_async()
+        .await;
+    let _chain = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 3}"#)
+        .create_async()
+        .await;
+    let _metadata = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"USD1","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":{{"name":"Dollar","uri":"","additional_metadata":[{{"key":"issuer","value":"1Money"}}]}}}}"#
+        ))
+        .create_async()
+        .await;
+    let uri = format!("{}{}", server.url(), path);
+    let update = server
+        .mock("POST", "/v1/tokens/update_metadata")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(hex::encode(document_hash)),
+            mockito::Matcher::Regex("issuer".into()),
+        ]))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .metadata_store(HttpPutStore::new(&format!("{}/metadata", server.url()))?)
+        .build()?;
+
+    let upload = client
+        .upload_token_metadata(
+            Address::from_str(token)?,
+            &document,
+            mock_utils::test_private_key(),
+        )
+        .await?;
+
+    stored.assert_async().await;
+    update.assert_async().await;
+    assert_eq!(upload.uri, uri);
+    assert_eq!(upload.document_hash, document_hash);
+    assert_eq!(upload.hash, B256::from_str(tx_hash)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upload_token_metadata_requires_store() -> Result<(), Box<dyn Error>> {
+    let client = mock_utils::create_mock_client()?;
+    let result = client
+        .upload_token_metadata(
+            Address::ZERO,
+            &TokenMetadataDocument::new("Dollar", "USD1"),
+            mock_utils::test_private_key(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(onemoney_protocol::Error::Config(
+            ConfigError::MissingConfig(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_cache_serves_repeated_lookups() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let checkpoint = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/checkpoints/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"hash":"{hash}","parent_hash":"{hash}","state_root":"{hash}","transactions_root":"{hash}","receipts_root":"{hash}","number":5,"timestamp":1,"extra_data":"","transactions":[],"size":null}}"#
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .cache_ttl(Duration::from_secs(60))
+        .build()?;
+
+    let first = client.get_checkpoint_by_hash(hash, false).await?;
+    let second = client.get_checkpoint_by_hash(hash, false).await?;
+    assert_eq!(first.number, second.number);
+    checkpoint.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_checkpoint_range_and_diff() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::reporting::CheckpointDiff;
+    use onemoney_protocol::{Signature, Transaction};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let mint = Transaction {
+        hash: B256::from_str(hash)?,
+        chain_id: 1212101,
+        from: addresses.authority_address,
+        data: TxPayload::TokenMint {
+            value: "250".to_string(),
+            recipient: addresses.recipient,
+            token: addresses.token_mint,
+        },
+        signature: Signature::default(),
+        ..Transaction::default()
+    };
+
+    let mut mocks = Vec::new();
+    for number in 11..=13u64 {
+        let transactions = if number == 12 {
+            serde_json::to_string(&[&mint])?
+        } else {
+            "[]".to_string()
+        };
+        mocks.push(
+            server
+                .mock("GET", "/v1/checkpoints/by_number")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "number".into(),
+                    number.to_string(),
+                ))
+                .with_status(200)
+                .with_body(format!(
+                    r#"{{"hash":"{hash}","parent_hash":"{hash}","state_root":"{hash}","transactions_root":"{hash}","receipts_root":"{hash}","number":{number},"timestamp":1,"extra_data":"","transactions":{transactions},"size":null}}"#
+                ))
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let diff = CheckpointDiff::between(&client, 10, 13).await?;
+    assert_eq!((diff.from, diff.to, diff.transactions), (11, 13, 1));
+    let change = diff
+        .supply_change(addresses.token_mint)
+        .expect("Mint should be counted");
+    assert_eq!(change.minted, U256::from(250u64));
+    assert!(diff.authority_changes.is_empty());
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    let numbers: Vec<u64> = client
+        .get_checkpoints_range_with_concurrency(11, 13, true, 2)
+        .await?
+        .iter()
+        .map(|checkpoint| checkpoint.number)
+        .collect();
+    assert_eq!(numbers, vec![11, 12, 13]);
+    assert!(client.get_checkpoints_range(13, 11, false).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rate_limited_response_pauses_bucket() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{EndpointClass, RateLimit, RateLimitConfig};
+
+    let mut server = setup_mock_server().await;
+    let _mock = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(429)
+        .with_header("retry-after", "3")
+        .with_body(r#"{"error_code": "rate_limit_exceeded", "message": "slow down"}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .rate_limit(RateLimitConfig::new().reads(RateLimit::per_second(50)))
+        .build()?;
+
+    let error = client
+        .fetch_chain_id_from_network()
+        .await
+        .expect_err("Rate limited request should fail");
+    assert!(matches!(
+        error,
+        onemoney_protocol::Error::RateLimitExceeded {
+            retry_after_seconds: Some(3)
+        }
+    ));
+
+    let state = client
+        .rate_limit_state(EndpointClass::Read)
+        .expect("Read bucket should exist");
+    assert!(state.available < 1.0);
+    assert!(state.paused_for > Duration::from_secs(2));
+
+    Ok(())
+}
+
+#[cfg(feature = "testkit")]
+#[tokio::test]
+async fn test_network_recorder_captures_mint() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::testkit::NetworkRecorder;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let _mock = server
+        .mock("POST", "/v1/tokens/mint")
+        .with_status(200)
+        .with_body(
+            r#"{"hash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777"}"#,
+        )
+        .create_async()
+        .await;
+
+    let recorder = NetworkRecorder::new();
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .hook(recorder.clone())
+        .build()?;
+
+    let payload = TokenMintPayload {
+        chain_id: 1212101,
+        nonce: 9,
+        recipient: addresses.recipient,
+        value: U256::from(42u64),
+        token: addresses.token_mint,
+    };
+    client
+        .mint_token(payload.clone(), mock_utils::test_private_key())
+        .await?;
+
+    recorder.assert_submitted::<TokenMintPayload>(1);
+    recorder.assert_submitted::<PaymentPayload>(0);
+    assert_eq!(recorder.submitted::<TokenMintPayload>()[0].payload, payload);
+
+    Ok(())
+}
+
+#[cfg(feature = "testkit")]
+#[tokio::test]
+async fn test_mock_l1_happy_path() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::MintInfo;
+    use onemoney_protocol::crypto::private_key_to_address;
+    use onemoney_protocol::testkit::MockL1;
+
+    let l1 = MockL1::start().await?;
+    let addresses = mock_utils::MockAddresses::new();
+    let sender = Address::from_str(&private_key_to_address(mock_utils::test_private_key())?)?;
+    l1.add_token(
+        addresses.token_mint,
+        MintInfo {
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            ..MintInfo::default()
+        },
+    );
+    l1.set_balance(sender, addresses.token_mint, U256::from(1000u64));
+    let client = l1.client()?;
+
+    assert_eq!(client.fetch_chain_id_from_network().await?, 1212101);
+    assert_eq!(
+        client
+            .get_token_metadata(addresses.token_mint)
+            .await?
+            .symbol,
+        "TEST"
+    );
+
+    for _ in 0..2 {
+        let nonce = client.get_account_nonce(sender).await?.nonce;
+        let payload = PaymentPayload {
+            chain_id: 1212101,
+            nonce,
+            recipient: addresses.recipient,
+            value: U256::from(300u64),
+            token: addresses.token_mint,
+        };
+        let response = client
+            .send_payment(payload, mock_utils::test_private_key())
+            .await?;
+        let receipt = client
+            .get_transaction_receipt_by_hash(&response.hash.to_string())
+            .await?;
+        assert!(receipt.success);
+        assert_eq!(receipt.from, sender);
+    }
+
+    let account = client
+        .get_associated_token_account(addresses.recipient, addresses.token_mint)
+        .await?;
+    assert_eq!(account.balance, "600");
+    assert_eq!(l1.balance(sender, addresses.token_mint), U256::from(400u64));
+    assert_eq!(l1.nonce(sender), 2);
+    assert_eq!(client.get_checkpoint_number().await?.number, 2);
+
+    // Overspending is rejected and consumes no nonce.
+    let overspend = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 2,
+        recipient: addresses.recipient,
+        value: U256::from(401u64),
+        token: addresses.token_mint,
+    };
+    assert!(
+        client
+            .send_payment(overspend, mock_utils::test_private_key())
+            .await
+            .is_err()
+    );
+    assert_eq!(l1.nonce(sender), 2);
+    assert_eq!(l1.transactions().len(), 2);
+
+    Ok(())
+}
+
+#[cfg(feature = "testkit")]
+#[tokio::test]
+async fn test_auto_recover_resigns_with_current_nonce() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::client::Hook;
+    use onemoney_protocol::crypto::private_key_to_address;
+    use onemoney_protocol::testkit::MockL1;
+    use onemoney_protocol::{MintInfo, RecoveryEvent, RecoveryPolicy};
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<RecoveryEvent>>>);
+    impl Hook for Recorder {
+        fn before_request(&self, _method: &str, _url: &str, _body: Option<&str>) {}
+        fn after_response(&self, _method: &str, _url: &str, _status: u16, _body: Option<&str>) {}
+        fn on_recovery(&self, event: &RecoveryEvent) {
+            self.0
+                .lock()
+                .expect("Lock should not be poisoned")
+                .push(event.clone());
+        }
+    }
+
+    let l1 = MockL1::start().await?;
+    let addresses = mock_utils::MockAddresses::new();
+    let sender = Address::from_str(&private_key_to_address(mock_utils::test_private_key())?)?;
+    l1.add_token(addresses.token_mint, MintInfo::default());
+    l1.set_balance(sender, addresses.token_mint, U256::from(1000u64));
+    let stale = |nonce| PaymentPayload {
+        chain_id: 1212101,
+        nonce,
+        recipient: addresses.recipient,
+        value: U256::from(100u64),
+        token: addresses.token_mint,
+    };
+
+    // Without a policy the rejection surfaces as a typed error.
+    let plain = l1.client()?;
+    let first = PaymentPayload {
+        value: U256::from(50u64),
+        ..stale(0)
+    };
+    plain
+        .send_payment(first, mock_utils::test_private_key())
+        .await?;
+    let rejected = plain
+        .send_payment(stale(0), mock_utils::test_private_key())
+        .await
+        .expect_err("A reused nonce should be rejected");
+    assert!(rejected.is_nonce_mismatch());
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let client = ClientBuilder::new()
+        .network(Network::custom(l1.url().to_string()))
+        .auto_recover(RecoveryPolicy::new().refresh_nonce(true))
+        .hook(Recorder(events.clone()))
+        .build()?;
+    client
+        .send_payment(stale(0), mock_utils::test_private_key())
+        .await?;
+
+    assert_eq!(l1.nonce(sender), 2);
+    assert_eq!(l1.balance(sender, addresses.token_mint), U256::from(850u64));
+    assert_eq!(
+        *events.lock().expect("Lock should not be poisoned"),
+        vec![RecoveryEvent::NonceRefreshed {
+            endpoint: "/transactions/payment",
+            rejected: 0,
+            current: 1,
+        }]
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "bridge")]
+#[tokio::test]
+async fn test_bridge_withdraw() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::TokenBurnAndBridgePayload;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let submit_mock = server
+        .mock("POST", "/v1/tokens/burn_and_bridge")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "destination_chain_id": 1,
+            "destination_address": "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+        })))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payload = TokenBurnAndBridgePayload {
+        chain_id: 1212101,
+        nonce: 2,
+        sender: addresses.authority_address,
+        value: U256::from(3000000u64),
+        token: addresses.token_mint,
+        destination_chain_id: 1,
+        destination_address: "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd".to_string(),
+        escrow_fee: U256::from(1000u64),
+        bridge_metadata: None,
+        bridge_param: None,
+    };
+
+    let response = client
+        .bridge_withdraw(payload.clone(), mock_utils::test_private_key())
+        .await?;
+    assert_eq!(response.hash.to_string(), hash);
+
+    // A withdrawal back to the L1 itself is rejected before anything is sent.
+    let looped = TokenBurnAndBridgePayload {
+        destination_chain_id: 1212101,
+        ..payload
+    };
+    assert!(
+        client
+            .bridge_withdraw(looped, mock_utils::test_private_key())
+            .await
+            .is_err()
+    );
+
+    submit_mock.assert_async().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_payment_and_wait_fills_nonce_and_confirms() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{PaymentOptions, PaymentOutcome};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let _balance = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/token_account.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"balance": "5000", "nonce": 11}"#)
+        .create_async()
+        .await;
+    let _chain = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 11}"#)
+        .create_async()
+        .await;
+    let payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "chain_id": 1212101,
+            "nonce": 11,
+            "value": "1000",
+        })))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{tx_hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":1,"fee_used":"0","from":"{from}","recipient":"{to}","token_address":"{token}"}}"#,
+            from = addresses.authority_address,
+            to = addresses.recipient,
+            token = addresses.token_mint
+        ))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let outcome = client
+        .send_payment_and_wait(
+            addresses.recipient,
+            addresses.token_mint,
+            U256::from(1000u64),
+            mock_utils::test_private_key(),
+            PaymentOptions::new().poll_interval(Duration::from_millis(10)),
+        )
+        .await?;
+
+    assert!(matches!(outcome, PaymentOutcome::Confirmed { .. }));
+    assert_eq!(outcome.hash(), B256::from_str(tx_hash)?);
+    assert_eq!(outcome.payload().nonce, 11);
+    payment.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_payment_and_wait_reports_pending_when_polling_fails()
+-> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{PaymentOptions, PaymentOutcome};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let _payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .create_async()
+        .await;
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(400)
+        .with_body(r#"{"error_code": "validation_error", "message": "Bad request"}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    // The payment went out, so losing the receipt must not lose its hash.
+    let outcome = client
+        .send_payment_and_wait(
+            addresses.recipient,
+            addresses.token_mint,
+            U256::from(1000u64),
+            mock_utils::test_private_key(),
+            PaymentOptions::new()
+                .chain_id(1212101)
+                .nonce(0)
+                .check_balance(false),
+        )
+        .await?;
+    assert!(matches!(outcome, PaymentOutcome::Pending { .. }));
+    assert_eq!(outcome.hash(), B256::from_str(tx_hash)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_payment_and_wait_rejects_insufficient_balance() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::PaymentOptions;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let _balance = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/token_account.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"balance": "10", "nonce": 0}"#)
+        .create_async()
+        .await;
+    let payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let error = client
+        .send_payment_and_wait(
+            addresses.recipient,
+            addresses.token_mint,
+            U256::from(1000u64),
+            mock_utils::test_private_key(),
+            PaymentOptions::new().chain_id(1212101).nonce(0),
+        )
+        .await
+        .expect_err("Payment exceeding the balance should fail preflight");
+    assert!(matches!(
+        error,
+        onemoney_protocol::Error::BusinessLogic { .. }
+    ));
+    payment.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_status_lifecycle() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::TxStatus;
+
+    let mut server = setup_mock_server().await;
+    let hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let not_found = r#"{"error_code": "resource_transaction", "message": "Transaction not found"}"#;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .finality_depth(5)
+        .build()?;
+
+    let missing_receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(404)
+        .with_body(not_found)
+        .expect(2)
+        .create_async()
+        .await;
+    let missing_transaction = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/by_hash.*".into()),
+        )
+        .with_status(404)
+        .with_body(not_found)
+        .expect(1)
+        .create_async()
+        .await;
+    assert_eq!(
+        client.get_transaction_status(hash).await?,
+        TxStatus::Unknown
+    );
+    missing_transaction.assert_async().await;
+
+    let _transaction = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"hash":"{hash}","checkpoint_hash":null,"checkpoint_number":null,"transaction_index":null,"chain_id":1212101,"from":"0x1111111111111111111111111111111111111111","nonce":0,"fee":"0","signature":{{"r":"0x1","s":"0x1","v":0}},"transaction_type":"TokenTransfer","data":{{"value":"1","recipient":"0x2222222222222222222222222222222222222222","token":"0x3333333333333333333333333333333333333333"}}}}"#
+        ))
+        .create_async()
+        .await;
+    assert_eq!(
+        client.get_transaction_status(hash).await?,
+        TxStatus::Pending
+    );
+    missing_receipt.assert_async().await;
+
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":10,"fee_used":"0","from":"0x1111111111111111111111111111111111111111","recipient":null,"token_address":null}}"#
+        ))
+        .create_async()
+        .await;
+    let _latest = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body(r#"{"number": 13}"#)
+        .create_async()
+        .await;
+    let status = client.get_transaction_status(hash).await?;
+    assert_eq!(
+        status,
+        TxStatus::Included {
+            checkpoint: 10,
+            depth: 3
+        }
+    );
+    assert!(!status.is_final());
+    assert!(status.is_final_at(3));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dry_run_signs_without_submitting() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 4}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .dry_run(true)
+        .build()?;
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: client.get_account_nonce(addresses.recipient).await?.nonce,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let error = client
+        .send_payment(payload.clone(), mock_utils::test_private_key())
+        .await
+        .expect_err("Dry run should not submit");
+    let dry_run = error
+        .dry_run_result()
+        .expect("Error should carry the request");
+    assert_eq!(dry_run.endpoint, "/v1/transactions/payment");
+    assert_eq!(dry_run.signature_hash, payload.signature_hash());
+    assert_eq!(dry_run.body["nonce"], 4);
+    assert!(dry_run.body.get("signature").is_some());
+
+    nonce.assert_async().await;
+    payment.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pay_many_reports_partial_failures() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let responses = [
+        (
+            0,
+            200,
+            r#"{"hash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777"}"#,
+        ),
+        (
+            1,
+            503,
+            r#"{"error_code": "system_unavailable", "message": "try later"}"#,
+        ),
+        (
+            2,
+            422,
+            r#"{"error_code": "business_insufficient_funds", "message": "balance too low"}"#,
+        ),
+    ];
+    let mut mocks = Vec::new();
+    for (nonce, status, body) in responses {
+        mocks.push(
+            server
+                .mock("POST", "/v1/transactions/payment")
+                .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                    "nonce": nonce,
+                })))
+                .with_status(status)
+                .with_body(body)
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payments = (0..3)
+        .map(|nonce| PaymentPayload {
+            chain_id: 1212101,
+            nonce,
+            recipient: addresses.recipient,
+            value: U256::from(1000u64),
+            token: addresses.token_mint,
+        })
+        .collect();
+    let mut report = client
+        .pay_many(payments, mock_utils::test_private_key())
+        .await;
+
+    assert_eq!(report.successes.len(), 1);
+    assert_eq!(report.permanent.len(), 1);
+    assert!(report.permanent[0].reason.contains("balance too low"));
+    let retry = report.take_retryable();
+    assert_eq!(retry.len(), 1);
+    assert_eq!(retry[0].nonce, 1);
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_submission_rejected_for_other_chain() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .chain_id(21210)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 0,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let result = client
+        .send_payment(payload, mock_utils::test_private_key())
+        .await;
+    assert!(matches!(
+        result,
+        Err(onemoney_protocol::Error::ChainIdMismatch {
+            expected: 21210,
+            actual: 1212101
+        })
+    ));
+    payment.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_mint_allowance() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let minter = addresses.authority_address;
+    let _mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[{{"minter":"{minter}","allowance":"750000"}}],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#,
+            token = addresses.token_mint
+        ))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let allowance = client
+        .get_mint_allowance(addresses.token_mint, minter)
+        .await?;
+    assert_eq!(allowance, U256::from(750000u64));
+
+    let none = client
+        .get_mint_allowance(addresses.token_mint, addresses.recipient)
+        .await?;
+    assert_eq!(none, U256::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explain_transaction_formats_amount_with_token_decimals() -> Result<(), Box<dyn Error>>
+{
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let _mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#,
+            token = addresses.token_mint
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let transaction = Transaction {
+        from: addresses.authority_address,
+        data: TxPayload::TokenMint {
+            value: "2500000".to_string(),
+            recipient: addresses.recipient,
+            token: addresses.token_mint,
+        },
+        ..Transaction::default()
+    };
+    let summary = client.explain_transaction(&transaction).await?;
+    assert_eq!(summary.kind, OperationKind::Mint);
+    let amount = summary.amount.ok_or("Mint should carry an amount")?;
+    assert_eq!(amount.to_string(), "2.5");
+
+    // Operations without an amount never fetch token metadata.
+    let pause = Transaction {
+        data: TxPayload::TokenPause {
+            token: addresses.token_mint,
+        },
+        ..Transaction::default()
+    };
+    let summary = client.explain_transaction(&pause).await?;
+    assert_eq!(summary.kind, OperationKind::Pause);
+    assert!(summary.amount.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_transaction_detailed_resolves_token() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let transaction = Transaction {
+        from: addresses.authority_address,
+        data: TxPayload::TokenMint {
+            value: "2500000".to_string(),
+            recipient: addresses.recipient,
+            token: addresses.token_mint,
+        },
+        ..Transaction::default()
+    };
+    let _transaction_mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/by_hash\?hash=0xabc$".into()),
+        )
+        .with_status(200)
+        .with_body(serde_json::to_string(&transaction)?)
+        .expect(1)
+        .create_async()
+        .await;
+    let _metadata_mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#,
+            token = addresses.token_mint
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let detailed = client.get_transaction_detailed("0xabc").await?;
+    assert_eq!(detailed.transaction, transaction);
+    assert_eq!(detailed.summary.kind, OperationKind::Mint);
+    let token = detailed
+        .token
+        .as_ref()
+        .ok_or("Mint should resolve its token")?;
+    assert_eq!(token.address, addresses.token_mint);
+    assert_eq!(token.decimals, 6);
+    assert_eq!(detailed.display_amount().as_deref(), Some("2.5 TEST"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resubmit_recovers_dropped_payment() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{AttemptOutcome, PaymentPayload, ResubmitPolicy};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let _epoch = server
+        .mock("GET", "/v1/governances/epoch")
+        .with_status(200)
+        .with_body(
+            r#"{"epoch_id": 4, "certificate_hash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "certificate": {"type": "Epoch"}}"#,
+        )
+        .create_async()
+        .await;
+    // Every checkpoint lookup observes the chain one checkpoint further along.
+    let checkpoints = Arc::new(AtomicU64::new(100));
+    let _checkpoint = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body_from_request(move |_| {
+            let number = checkpoints.fetch_add(1, Ordering::SeqCst);
+            format!(r#"{{"number": {number}}}"#).into_bytes()
+        })
+        .create_async()
+        .await;
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 7}"#)
+        .create_async()
+        .await;
+    let payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "nonce": 7,
+        })))
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(2)
+        .create_async()
+        .await;
+    let _missing = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(404)
+        .with_body(r#"{"error_code": "resource_transaction", "message": "Transaction not found"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let _receipt = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{tx_hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":103,"fee_used":"0","from":"{from}","recipient":"{to}","token_address":"{token}"}}"#,
+            from = addresses.authority_address,
+            to = addresses.recipient,
+            token = addresses.token_mint
+        ))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .state_max_staleness(Duration::ZERO)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 7,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let policy = ResubmitPolicy::new()
+        .wait_checkpoints(2)
+        .poll_interval(Duration::from_millis(10));
+    let report = client
+        .resubmit(payload, mock_utils::test_private_key(), policy)
+        .await?;
+
+    assert_eq!(report.attempts.len(), 2);
+    assert!(matches!(
+        report.attempts[0].outcome,
+        AttemptOutcome::Dropped {
+            waited_checkpoints: 2
+        }
+    ));
+    assert!(report.is_landed());
+    let receipt = report.receipt().expect("Second attempt should land");
+    assert_eq!(receipt.checkpoint_number, Some(103));
+    payment.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resubmit_keeps_outcome_open_when_final_receipt_check_fails()
+-> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{AttemptOutcome, PaymentPayload, ResubmitPolicy, RetryPolicy};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let _epoch = server
+        .mock("GET", "/v1/governances/epoch")
+        .with_status(200)
+        .with_body(
+            r#"{"epoch_id": 4, "certificate_hash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "certificate": {"type": "Epoch"}}"#,
+        )
+        .create_async()
+        .await;
+    let checkpoints = Arc::new(AtomicU64::new(100));
+    let _checkpoint = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body_from_request(move |_| {
+            let number = checkpoints.fetch_add(1, Ordering::SeqCst);
+            format!(r#"{{"number": {number}}}"#).into_bytes()
+        })
+        .create_async()
+        .await;
+    // Another transaction used nonce 7 while the payment was pending.
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_body(r#"{"nonce": 8}"#)
+        .create_async()
+        .await;
+    let _payment = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+    let _missing = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(404)
+        .with_body(r#"{"error_code": "resource_transaction", "message": "Transaction not found"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let _unavailable = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(503)
+        .with_body(r#"{"error_code": "system_unavailable", "message": "Try again later"}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .state_max_staleness(Duration::ZERO)
+        .retry_policy(RetryPolicy::none())
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 7,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    let policy = ResubmitPolicy::new()
+        .max_attempts(2)
+        .wait_checkpoints(2)
+        .poll_interval(Duration::from_millis(10));
+    let report = client
+        .resubmit(payload, mock_utils::test_private_key(), policy)
+        .await?;
+
+    assert_eq!(report.attempts.len(), 2);
+    assert!(matches!(
+        report.attempts[1].outcome,
+        AttemptOutcome::Failed {
+            retryable: true,
+            ..
+        }
+    ));
+    assert!(!report.is_landed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_token_resolves_by_symbol() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::KnownToken;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let _metadata = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol": "EUR1", "master_authority": "{authority}", "master_mint_burn_authority": "{authority}", "mint_burn_authorities": [], "pause_authorities": [], "list_authorities": [], "black_list": [], "white_list": [], "metadata_update_authorities": [], "bridge_mint_authorities": [], "supply": "0", "decimals": 6, "is_paused": false, "is_private": false, "meta": null}}"#,
+            authority = addresses.authority_address
+        ))
+        .create_async()
+        .await;
+
+    let usd = Address::repeat_byte(0x11);
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .token(KnownToken::new("USD1", usd, 18))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    assert_eq!(client.resolve_token("usd1")?.address, usd);
+    assert!(client.resolve_token("EUR1").is_err());
+
+    let registered = client.register_token(addresses.token_mint).await?;
+    assert_eq!(registered.symbol, "EUR1");
+    let resolved = client.resolve_token("EUR1")?;
+    assert_eq!(
+        (resolved.address, resolved.decimals),
+        (addresses.token_mint, 6)
+    );
+    assert_eq!(client.token_registry().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_membership_checks() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let blocked = Address::repeat_byte(0x0b);
+    let metadata = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol": "TEST", "master_authority": "{authority}", "master_mint_burn_authority": "{authority}", "mint_burn_authorities": [], "pause_authorities": [], "list_authorities": [], "black_list": ["{blocked}"], "white_list": ["{recipient}"], "metadata_update_authorities": [], "bridge_mint_authorities": [], "supply": "0", "decimals": 6, "is_paused": false, "is_private": true, "meta": null}}"#,
+            authority = addresses.authority_address,
+            recipient = addresses.recipient,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    // With token metadata cached, the three lookups share one request.
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .cache_ttl(Duration::from_secs(60))
+        .cache_endpoint(onemoney_protocol::client::endpoints::tokens::TOKEN_METADATA)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    assert!(client.is_blacklisted(addresses.token_mint, blocked).await?);
+    assert!(
+        client
+            .is_whitelisted(addresses.token_mint, addresses.recipient)
+            .await?
+    );
+    let membership = client
+        .get_list_membership(addresses.token_mint, addresses.authority_address)
+        .await?;
+    assert!(!membership.blacklisted && !membership.whitelisted);
+    assert!(!membership.is_allowed());
+    metadata.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_policy_retries_transient_failures() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{ConstantBackoff, ErrorClass, RetryPolicy};
+
+    let mut server = setup_mock_server().await;
+    let unavailable = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(503)
+        .with_body(r#"{"error_code": "system_unavailable", "message": "Try again"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let _available = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+
+    let policy = RetryPolicy::new().backoff(ConstantBackoff::new(Duration::from_millis(5)));
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .retry_policy(policy.clone())
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(client.fetch_chain_id_from_network().await?, 1212101);
+    unavailable.assert_async().await;
+
+    // Server errors are no longer retried once the class is opted out.
+    server.reset();
+    let unavailable = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(503)
+        .with_body(r#"{"error_code": "system_unavailable", "message": "Try again"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .retry_policy(policy.never_retry(ErrorClass::Server))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert!(client.fetch_chain_id_from_network().await.is_err());
+    unavailable.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_server_timeout_retries_unprocessed_writes() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{ConstantBackoff, RetryPolicy};
+
+    let mut server = setup_mock_server().await;
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+    let timed_out = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(408)
+        .with_body(r#"{"error_code": "request_timeout", "message": "Request body not received"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let accepted = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(200)
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+
+    // Writes are not retried by default, except after a 408 the server
+    // never processed.
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .retry_policy(RetryPolicy::new().backoff(ConstantBackoff::new(Duration::from_millis(5))))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let addresses = mock_utils::MockAddresses::new();
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 0,
+        recipient: addresses.recipient,
+        value: U256::from(1u64),
+        token: addresses.token_mint,
+    };
+    let response = client
+        .send_payment(payload, mock_utils::test_private_key())
+        .await?;
+
+    assert_eq!(response.hash, B256::from_str(tx_hash)?);
+    timed_out.assert_async().await;
+    accepted.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_and_retry_budget_limit_load() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{
+        CircuitBreakerConfig, CircuitState, ConstantBackoff, RetryBudget, RetryPolicy,
+    };
+
+    let mut server = setup_mock_server().await;
+    // Two requests with three retries each would make eight attempts; the
+    // budget allows two retries in total and the breaker opens at three
+    // failures.
+    let unavailable = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(503)
+        .with_body(r#"{"error_code": "system_unavailable", "message": "Try again"}"#)
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .retry_policy(RetryPolicy::new().backoff(ConstantBackoff::new(Duration::from_millis(1))))
+        .retry_budget(RetryBudget::new(2, Duration::from_secs(60)))
+        .circuit_breaker(
+            CircuitBreakerConfig::new()
+                .failure_threshold(3)
+                .open_for(Duration::from_secs(60)),
+        )
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let first = client
+        .fetch_chain_id_from_network()
+        .await
+        .expect_err("Upstream is down");
+    assert!(!first.is_circuit_open());
+    assert!(matches!(
+        client.circuit_state(),
+        Some(CircuitState::Open { .. })
+    ));
+
+    let second = client
+        .fetch_chain_id_from_network()
+        .await
+        .expect_err("Circuit is open");
+    assert!(matches!(
+        second,
+        onemoney_protocol::Error::CircuitOpen { .. }
+    ));
+    unavailable.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_context_carries_request_metadata() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::Error as SdkError;
+
+    let mut server = setup_mock_server().await;
+    let _missing = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/by_hash.*".into()),
+        )
+        .with_status(404)
+        .with_header("x-request-id", "req-7f3a")
+        .with_body(r#"{"error_code": "resource_transaction", "message": "Transaction not found"}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .error_context(true)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let error = client
+        .get_transaction_by_hash(
+            "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777",
+        )
+        .await
+        .expect_err("Transaction should be missing");
+    let context = error.context().expect("Context should be attached");
+    assert_eq!(context.method, "GET");
+    assert!(context.path.starts_with("/v1/transactions/by_hash"));
+    assert_eq!(context.status_code, Some(404));
+    assert_eq!(context.request_id.as_deref(), Some("req-7f3a"));
+    assert_eq!(context.attempts, 1);
+    assert!(matches!(error.root(), SdkError::ResourceNotFound { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_response_meta_reaches_hooks() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::client::{Hook, ResponseMeta};
+    use std::sync::{Arc, Mutex};
+
+    struct QuotaMonitor(Arc<Mutex<Vec<ResponseMeta>>>);
+    impl Hook for QuotaMonitor {
+        fn before_request(&self, _method: &str, _url: &str, _body: Option<&str>) {}
+        fn after_response(&self, _method: &str, _url: &str, _status: u16, _body: Option<&str>) {}
+        fn on_response_meta(&self, _method: &str, _url: &str, meta: &ResponseMeta) {
+            self.0
+                .lock()
+                .expect("Lock should not be poisoned")
+                .push(meta.clone());
+        }
+    }
+
+    let mut server = setup_mock_server().await;
+    let _chain = server
+        .mock("GET", "/v1/chains/chain_id")
+        .with_status(200)
+        .with_header("x-request-id", "req-91c2")
+        .with_header("x-ratelimit-limit", "600")
+        .with_header("x-ratelimit-remaining", "599")
+        .with_header("x-ratelimit-reset", "60")
+        .with_header("x-server-version", "1.4.2")
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .create_async()
+        .await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .hook(QuotaMonitor(seen.clone()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    client.fetch_chain_id_from_network().await?;
+
+    let seen = seen.lock().expect("Lock should not be poisoned");
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].status, 200);
+    assert_eq!(seen[0].request_id.as_deref(), Some("req-91c2"));
+    assert_eq!(seen[0].rate_limit_limit, Some(600));
+    assert_eq!(seen[0].rate_limit_remaining, Some(599));
+    assert_eq!(seen[0].rate_limit_reset, Some(60));
+    assert_eq!(seen[0].server_version.as_deref(), Some("1.4.2"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_usable_through_api_trait() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::OneMoneyApi;
+
+    async fn checkpoint_and_epoch<A: OneMoneyApi>(
+        api: &A,
+    ) -> onemoney_protocol::Result<(u64, u64)> {
+        let checkpoint = api.get_checkpoint_number().await?;
+        let epoch = api.get_current_epoch().await?;
+        Ok((checkpoint.number, epoch.epoch_id))
+    }
+
+    let mut server = setup_mock_server().await;
+    let _epoch = server
+        .mock("GET", "/v1/governances/epoch")
+        .with_status(200)
+        .with_body(
+            r#"{"epoch_id": 4, "certificate_hash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "certificate": {"type": "Epoch"}}"#,
+        )
+        .create_async()
+        .await;
+    let _checkpoint = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body(r#"{"number": 812}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    // Trait futures are Send, so generic code can run on spawned tasks.
+    let spawned = tokio::spawn({
+        let client = client.clone();
+        async move { checkpoint_and_epoch(&client).await }
+    });
+    assert_eq!(spawned.await??, (812, 4));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auth_headers_attached_to_requests() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::AuthScheme;
+
+    let mut server = setup_mock_server().await;
+    let bearer = server
+        .mock("GET", "/v1/chains/chain_id")
+        .match_header("authorization", "Bearer tok-123")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .auth(AuthScheme::bearer("tok-123"))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(client.fetch_chain_id_from_network().await?, 1212101);
+    bearer.assert_async().await;
+
+    let keyed = server
+        .mock("GET", "/v1/checkpoints/number")
+        .match_header("x-gateway-key", "key-1")
+        .with_status(200)
+        .with_body(r#"{"number": 812}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .auth(AuthScheme::api_key_header("X-Gateway-Key", "key-1"))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(client.get_checkpoint_number().await?.number, 812);
+    keyed.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_api_version_probe_and_rewrite() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::ApiVersion;
+
+    let mut server = setup_mock_server().await;
+    let v2_missing = server
+        .mock("GET", "/v2/chains/chain_id")
+        .with_status(404)
+        .with_body("Not Found")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(client.api_version(), ApiVersion::V1);
+    assert_eq!(client.get_api_version().await?, ApiVersion::V1);
+    v2_missing.assert_async().await;
+
+    server.reset();
+    let v2_chain_id = server
+        .mock("GET", "/v2/chains/chain_id")
+        .with_status(200)
+        .with_body(r#"{"chain_id": 1212101}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    assert_eq!(client.get_api_version().await?, ApiVersion::V2);
+
+    let v2_client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .api_version(ApiVersion::V2)
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    assert_eq!(v2_client.fetch_chain_id_from_network().await?, 1212101);
+    v2_chain_id.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_paginate_custom_follows_cursors() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let holding = |byte: u8| format!(r#"{{"balance": "{}", "nonce": 0}}"#, byte);
+
+    let _second = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/holders\?token=0x01&cursor=page2$".into()),
+        )
+        .with_status(200)
+        .with_body(format!(r#"{{"items": [{}], "total": 3}}"#, holding(3)))
+        .expect(1)
+        .create_async()
+        .await;
+    let _first = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/holders\?token=0x01$".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"items": [{}, {}], "next_cursor": "page2", "total": 3}}"#,
+            holding(1),
+            holding(2)
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let mut pages = client.paginate_custom::<onemoney_protocol::AssociatedTokenAccount>(
+        "/tokens/holders",
+        &[("token", "0x01")],
+    )?;
+    let mut balances = Vec::new();
+    while let Some(holding) = pages.next().await {
+        balances.push(holding?.balance);
+    }
+    assert_eq!(balances, ["1", "2", "3"]);
+    assert_eq!(pages.pages_fetched(), 2);
+    assert_eq!(pages.total(), Some(3));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_token_supply_at_checkpoint() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let token = addresses.token_mint;
+    let transaction = |byte: u8, data: TxPayload| Transaction {
+        hash: B256::repeat_byte(byte),
+        data,
+        ..Transaction::default()
+    };
+    let transactions = vec![
+        transaction(
+            1,
+            TxPayload::TokenMint {
+                value: "700".to_string(),
+                recipient: addresses.recipient,
+                token,
+            },
+        ),
+        transaction(
+            2,
+            TxPayload::TokenBurn {
+                value: "200".to_string(),
+                recipient: addresses.recipient,
+                token,
+            },
+        ),
+        transaction(
+            3,
+            TxPayload::TokenMint {
+                value: "999".to_string(),
+                recipient: addresses.recipient,
+                token: Address::repeat_byte(0x99),
+            },
+        ),
+    ];
+
+    let _number = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_body(r#"{"number": 11}"#)
+        .create_async()
+        .await;
+    let _metadata = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"1500","decimals":2,"is_paused":false,"is_private":false,"meta":null}}"#
+        ))
+        .create_async()
+        .await;
+    let _checkpoint = server
+        .mock("GET", "/v1/checkpoints/by_number")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("number".into(), "11".into()),
+            mockito::Matcher::UrlEncoded("full".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"number":11,"timestamp":0,"transactions":{},"size":null}}"#,
+            serde_json::to_string(&transactions)?
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let receipts = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/transactions/receipt/by_hash.*".into()),
+        )
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"success":true,"transaction_hash":"{hash}","transaction_index":0,"checkpoint_hash":null,"checkpoint_number":11,"fee_used":"0","from":"{from}","recipient":null,"token_address":"{token}"}}"#,
+            hash = B256::repeat_byte(1),
+            from = addresses.authority_address
+        ))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let supply = client.get_token_supply(token).await?;
+    assert_eq!(supply.circulating, U256::from(1500u64));
+    assert_eq!(supply.checkpoint, 11);
+    assert_eq!(supply.to_decimal_string(), "15");
+
+    let history = client.get_token_supply_at(token, 10).await?;
+    assert_eq!(history.minted, U256::from(700u64));
+    assert_eq!(history.burned, U256::from(200u64));
+    assert_eq!(history.at.circulating, U256::from(1000u64));
+    assert_eq!(history.at.checkpoint, 10);
+    assert_eq!(history.current, supply);
+    receipts.assert_async().await;
+
+    assert!(client.get_token_supply_at(token, 12).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_audit_sink_records_submissions() -> Result<(), Box<dyn Error>> {
+    use alloy_primitives::keccak256;
+    use onemoney_protocol::client::{AuditOutcome, AuditRecord, AuditSink};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct CapturingSink(Arc<Mutex<Vec<AuditRecord>>>);
+
+    impl AuditSink for CapturingSink {
+        fn record(&self, record: &AuditRecord) -> onemoney_protocol::Result<()> {
+            self.0
+                .lock()
+                .expect("Sink lock should not be poisoned")
+                .push(record.clone());
+            Ok(())
+        }
+    }
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let accepted = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let sink = CapturingSink::default();
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .audit_sink(sink.clone())
+        .build()?;
+
+    let payload = PaymentPayload::new(
+        1212101,
+        5,
+        addresses.recipient,
+        1000u64,
+        addresses.token_mint,
+    )?;
+    client
+        .send_payment(payload.clone(), mock_utils::test_private_key())
+        .await?;
+    accepted.assert_async().await;
+
+    let _rejected = server
+        .mock("POST", "/v1/tokens/mint")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error_code": "validation_error", "message": "bad nonce"}"#)
+        .create_async()
+        .await;
+    let mint = TokenMintPayload::new(1212101, 6, addresses.recipient, 10u64, addresses.token_mint)?;
+    assert!(
+        client
+            .mint_token(mint, mock_utils::test_private_key())
+            .await
+            .is_err()
+    );
+
+    let records = sink.0.lock().expect("Sink lock should not be poisoned");
+    assert_eq!(records.len(), 2);
+
+    let record = &records[0];
+    assert_eq!(record.endpoint, "/v1/transactions/payment");
+    assert_eq!(record.signature_hash, payload.signature_hash());
+    assert_eq!(keccak256(&record.signed_bytes), record.signature_hash);
+    assert_eq!(record.signatures.len(), 1);
+    assert_eq!(record.payload["nonce"], 5);
+    assert_eq!(record.outcome.hash(), Some(B256::from_str(tx_hash)?));
+
+    assert_eq!(records[1].endpoint, "/v1/tokens/mint");
+    assert!(matches!(records[1].outcome, AuditOutcome::Failed { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_timeout_profile_reports_endpoint_and_limit() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::Error as SdkError;
+    use onemoney_protocol::TimeoutProfile;
+
+    let mut server = setup_mock_server().await;
+    let _slow = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(Duration::from_millis(400));
+            writer.write_all(br#"{"number": 812}"#)
+        })
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeouts(TimeoutProfile {
+            read: Duration::from_millis(100),
+            ..TimeoutProfile::default()
+        })
+        .build()?;
+    assert_eq!(client.timeouts().read, Duration::from_millis(100));
+
+    match client.get_checkpoint_number().await {
+        Err(SdkError::ClientTimeout {
+            endpoint,
+            elapsed_ms,
+            timeout_ms,
+        }) => {
+            assert_eq!(endpoint, "/v1/checkpoints/number");
+            assert_eq!(timeout_ms, 100);
+            assert!(elapsed_ms >= 100);
+        }
+        other => panic!("Expected ClientTimeout, got {other:?}"),
+    }
+
+    let number = client
+        .with_timeout(Duration::from_secs(5))
+        .get_checkpoint_number()
+        .await?;
+    assert_eq!(number.number, 812);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_native_omits_token() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::NativePaymentPayload;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let mock = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_body(mockito::Matcher::PartialJsonString(format!(
+            r#"{{"chain_id": 1212101, "nonce": 2, "recipient": "{:?}", "value": "750"}}"#,
+            addresses.recipient
+        )))
+        .match_request(|request| {
+            request
+                .utf8_lossy_body()
+                .is_ok_and(|body| !body.contains("\"token\""))
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let payload = NativePaymentPayload::new(1212101, 2, addresses.recipient, 750u64)?;
+    let response = client
+        .send_native(payload, mock_utils::test_private_key())
+        .await?;
+    assert_eq!(response.hash, B256::from_str(tx_hash)?);
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_authorities_parses_allowances() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let token = Address::repeat_byte(0xab);
+    let master = Address::repeat_byte(0x01);
+    let minter = Address::repeat_byte(0x02);
+
+    let _metadata = server
+        .mock("GET", "/v1/tokens/token_metadata")
+        .match_query(mockito::Matcher::UrlEncoded("token".into(), token.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{master}","master_mint_burn_authority":"{master}","mint_burn_authorities":[{{"minter":"{minter}","allowance":"2500"}}],"pause_authorities":["{master}"],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#
+        ))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let authorities = client.get_authorities(token).await?;
+    assert_eq!(authorities.token, token);
+    assert!(authorities.is_master(master));
+    assert!(authorities.can_pause(master));
+    assert_eq!(authorities.mint_allowance(minter), U256::from(2500u64));
+    assert!(authorities.can_mint(minter, U256::from(2500u64)));
+    assert!(!authorities.can_mint(minter, U256::from(2501u64)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_check_authorization_reports_allowance() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::responses::{AuthorizationDenial, TokenOperation};
+
+    let mut server = setup_mock_server().await;
+    let token = Address::repeat_byte(0xab);
+    let master = Address::repeat_byte(0x01);
+    let minter = Address::repeat_byte(0x02);
+
+    let _metadata = server
+        .mock("GET", "/v1/tokens/token_metadata")
+        .match_query(mockito::Matcher::UrlEncoded("token".into(), token.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"symbol":"TEST","master_authority":"{master}","master_mint_burn_authority":"{master}","mint_burn_authorities":[{{"minter":"{minter}","allowance":"2500"}}],"pause_authorities":["{master}"],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#
+        ))
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let mint = TokenOperation::Mint(U256::from(3000u64));
+    assert_eq!(
+        client.check_authorization(token, minter, mint).await?,
+        Some(AuthorizationDenial::InsufficientAllowance {
+            signer: minter,
+            requested: U256::from(3000u64),
+            remaining: U256::from(2500u64),
+        })
+    );
+    assert_eq!(
+        client
+            .check_authorization(token, master, TokenOperation::Pause)
+            .await?,
+        None
+    );
+    assert!(matches!(
+        client
+            .check_authorization(token, minter, TokenOperation::ManageList)
+            .await?,
+        Some(AuthorizationDenial::MissingAuthority { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_send_raw_transaction_routes_by_endpoint() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{RawTransaction, TokenMintPayload, sign_transaction_payload};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let tx_hash = "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777";
+
+    let payload = TokenMintPayload {
+        chain_id: 1212101,
+        nonce: 4,
+        recipient: addresses.recipient,
+        value: U256::from(300u64),
+        token: addresses.token_mint,
+    };
+    // Signed elsewhere, with a legacy v the client passes through as-is.
+    let mut signature = sign_transaction_payload(&payload, mock_utils::test_private_key())?;
+    signature.v += 27;
+    let blob = RawTransaction::new(&payload, signature.clone()).to_rlp();
+
+    let mock = server
+        .mock("POST", "/v1/tokens/mint")
+        .match_body(mockito::Matcher::PartialJsonString(format!(
+            r#"{{"chain_id": 1212101, "nonce": 4, "value": "300", "signature": {{"v": {}}}}}"#,
+            signature.v
+        )))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(r#"{{"hash": "{tx_hash}"}}"#))
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let response = client.send_raw_transaction(&blob).await?;
+    assert_eq!(response.hash, B256::from_str(tx_hash)?);
+    mock.assert_async().await;
+
+    let mut unknown = RawTransaction::from_rlp(&blob)?;
+    unknown.endpoint = "/tokens/create".to_string();
+    assert!(
+        client
+            .send_raw_transaction(&unknown.to_rlp())
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn test_gzip_response_and_request_compression() -> Result<(), Box<dyn Error>> {
+    use flate2::Compression;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use onemoney_protocol::responses::MintInfo;
+    use std::io::{Read, Write};
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+
+    let info = MintInfo {
+        symbol: "TEST".to_string(),
+        decimals: 6,
+        ..MintInfo::default()
+    };
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(serde_json::to_string(&info)?.as_bytes())?;
+    let compressed = encoder.finish()?;
+    let metadata_mock = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/tokens/token_metadata.*".to_string()),
+        )
+        .match_header(
+            "accept-encoding",
+            mockito::Matcher::Regex("gzip".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("content-encoding", "gzip")
+        .with_body(compressed)
+        .create_async()
+        .await;
+
+    let payment_mock = server
+        .mock("POST", "/v1/transactions/payment")
+        .match_header("content-encoding", "gzip")
+        .match_request(|request| {
+            let mut body = String::new();
+            request.body().is_ok_and(|bytes| {
+                GzDecoder::new(bytes.as_slice())
+                    .read_to_string(&mut body)
+                    .is_ok()
+                    && body.contains("\"signature\"")
+            })
+        })
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"hash": "0x902006665c369834a0cf52eea2780f934a90b3c86a3918fb57371ac1fbbd7777"}"#,
+        )
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .gzip(true)
+        .compress_requests(0)
+        .build()?;
+
+    let fetched = client.get_token_metadata(addresses.token_mint).await?;
+    assert_eq!(fetched.symbol, "TEST");
+    metadata_mock.assert_async().await;
+
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 0,
+        recipient: addresses.recipient,
+        value: U256::from(1000u64),
+        token: addresses.token_mint,
+    };
+    client
+        .send_payment(payload, mock_utils::test_private_key())
+        .await?;
+    payment_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_endpoints_reuse_client_pipeline() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+
+    let get_mock = server
+        .mock("GET", "/v1/tokens/holders")
+        .match_query(mockito::Matcher::UrlEncoded("memo".into(), "a b&c".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"holders": 3}"#)
+        .create_async()
+        .await;
+    let post_mock = server
+        .mock("POST", "/v1/tokens/simulate")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"nonce": 7})))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error_code": "validation_value", "message": "Value too low"}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let holders: serde_json::Value = client
+        .get_custom("/tokens/holders", &[("memo", "a b&c")])
+        .await?;
+    assert_eq!(holders["holders"], 3);
+    get_mock.assert_async().await;
+
+    let rejected = client
+        .post_custom::<_, serde_json::Value>("/tokens/simulate", &serde_json::json!({"nonce": 7}))
+        .await;
+    assert!(matches!(
+        rejected,
+        Err(onemoney_protocol::Error::InvalidParameter { ref parameter, .. }) if parameter == "value"
+    ));
+    post_mock.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_amount_helpers_fetch_token_metadata_once() -> Result<(), Box<dyn Error>> {
+    let mut server = setup_mock_server().await;
+    let token = Address::repeat_byte(0x33);
+    let metadata = server
+        .mock("GET", "/v1/tokens/token_metadata")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body(format!(
+            r#"{{"symbol":"USD1","master_authority":"{token}","master_mint_burn_authority":"{token}","mint_burn_authorities":[],"pause_authorities":[],"list_authorities":[],"black_list":[],"white_list":[],"metadata_update_authorities":[],"bridge_mint_authorities":[],"supply":"0","decimals":6,"is_paused":false,"is_private":false,"meta":null}}"#
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let amount = client.parse_amount(token, "12.5").await?;
+    assert_eq!(amount, U256::from(12_500_000u64));
+    assert_eq!(client.format_amount(token, amount).await?, "12.5");
+    assert_eq!(client.token_info(token).await?.symbol, "USD1");
+    assert!(client.parse_amount(token, "0.0000001").await.is_err());
+    metadata.assert_async().await;
+
+    client.token_info_cache().invalidate(&token);
+    let uncached = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .token_info_ttl(Duration::ZERO)
+        .build()?;
+    uncached.format_amount(token, amount).await?;
+    uncached.format_amount(token, amount).await?;
+    metadata.expect(3).assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_submit_with_options_returns_stale_checkpoint() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::SubmitOptions;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+
+    let epoch = server
+        .mock("GET", "/v1/governances/epoch")
+        .expect(0)
+        .create_async()
+        .await;
+    let stale = server
+        .mock("POST", "/v1/transactions/payment")
+        .with_status(422)
+        .with_body(r#"{"error_code": "business_rejected", "message": "Checkpoint too old"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+    let payload = PaymentPayload {
+        chain_id: 1212101,
+        nonce: 0,
+        recipient: addresses.recipient,
+        value: U256::from(100u64),
+        token: addresses.token_mint,
+    };
+
+    // Resubmitting would send the same signed bytes, so nothing is retried.
+    let error = client
+        .submit_with_options(
+            payload,
+            mock_utils::test_private_key(),
+            SubmitOptions::new(),
+        )
+        .await
+        .expect_err("Stale rejection should surface");
+    assert!(error.is_stale_checkpoint(), "Unexpected error: {}", error);
+    stale.assert_async().await;
+    epoch.assert_async().await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_health_check_reports_clock_skew() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::client::{ClientHealth, Hook};
+    use std::sync::{Arc, Mutex};
+    use std::time::SystemTime;
+
+    struct SkewMonitor(Arc<Mutex<Vec<ClientHealth>>>);
+    impl Hook for SkewMonitor {
+        fn before_request(&self, _method: &str, _url: &str, _body: Option<&str>) {}
+        fn after_response(&self, _method: &str, _url: &str, _status: u16, _body: Option<&str>) {}
+        fn on_clock_skew(&self, health: &ClientHealth) {
+            self.0
+                .lock()
+                .expect("Lock should not be poisoned")
+                .push(*health);
+        }
+    }
+
+    let mut server = setup_mock_server().await;
+    let server_clock = SystemTime::now() - Duration::from_secs(120);
+    let _number = server
+        .mock("GET", "/v1/checkpoints/number")
+        .with_status(200)
+        .with_header("date", &httpdate::fmt_http_date(server_clock))
+        .with_body(r#"{"number": 9}"#)
+        .create_async()
+        .await;
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .hook(SkewMonitor(warnings.clone()))
+        .build()?;
+
+    // The server's clock trails by two minutes, well past the default limit.
+    let health = client.health_check().await?;
+    assert_eq!(health.checkpoint, 9);
+    assert!(
+        health.skew_ms.is_some_and(|skew_ms| skew_ms < -110_000),
+        "Unexpected skew: {}",
+        health
+    );
+    assert!(health.is_skewed());
+    assert_eq!(
+        warnings
+            .lock()
+            .expect("Lock should not be poisoned")
+            .as_slice(),
+        &[health]
+    );
+
+    let tolerant = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .max_clock_skew(Duration::from_secs(600))
+        .build()?;
+    assert!(!tolerant.health_check().await?.is_skewed());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_with_warnings_reports_deprecation_and_unknown_fields() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::client::Warning;
+
+    let mut server = setup_mock_server().await;
+    let addresses = mock_utils::MockAddresses::new();
+    let _nonce = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/v1/accounts/nonce.*".into()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("deprecation", "true")
+        .with_header("sunset", "Wed, 31 Dec 2026 23:59:59 GMT")
+        .with_body(r#"{"nonce": 5, "pending_nonce": 6}"#)
+        .create_async()
+        .await;
+
+    let client = ClientBuilder::new()
+        .network(Network::custom(server.url()))
+        .timeout(TEST_TIMEOUT)
+        .build()?;
+
+    let result = client
+        .with_warnings(client.get_account_nonce(addresses.recipient))
+        .await?;
+    assert_eq!(result.value.nonce, 5);
+    assert_eq!(result.warnings.len(), 2);
+    assert!(matches!(
+        &result.warnings[0],
+        Warning::DeprecatedEndpoint { sunset: Some(sunset), .. } if sunset.contains("2026")
+    ));
+    assert_eq!(
+        result.warnings[1],
+        Warning::UnknownFields {
+            endpoint: "/v1/accounts/nonce".to_string(),
+            fields: vec!["pending_nonce".to_string()],
+        }
+    );
+
+    // Outside with_warnings and without hooks nothing is collected or tracked.
+    assert_eq!(
+        client.get_account_nonce(addresses.recipient).await?.nonce,
+        5
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_custom_transport_serves_requests() -> Result<(), Box<dyn Error>> {
+    use onemoney_protocol::{HttpTransport, HttpTransportFuture};
+    use std::sync::{Arc, Mutex};
+
+    /// Answers every request with a fixed nonce and records what was asked.
+    #[derive(Default)]
+    struct CannedTransport {
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl HttpTransport for CannedTransport {
+        fn execute(&self, request: reqwest::Request) -> HttpTransportFuture<'_> {
+            self.requests
+                .lock()
+                .expect("Request log lock")
+                .push(format!("{} {}", request.method(), request.url()));
+            Box::pin(async {
+                let response = http::Response::builder()
+                    .status(200)
+                    .header("content-type", "application/json")
+                    .body(r#"{"nonce": 3}"#)
+                    .expect("Canned response should build");
+                Ok(reqwest::Response::from(response))
+            })
+        }
+    }
+
+    let transport = Arc::new(CannedTransport::default());
+    let addresses = mock_utils::MockAddresses::new();
+    let client = ClientBuilder::new()
+        .network(Network::custom("http://transport.invalid".to_string()))
+        .timeout(TEST_TIMEOUT)
+        .transport(transport.clone())
+        .build()?;
+
+    assert_eq!(
+        client.get_account_nonce(addresses.recipient).await?.nonce,
+        3
+    );
+    let requests = transport.requests.lock().expect("Request log lock");
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET http://transport.invalid/v1/accounts/nonce?"));
+
+    let conflicting = ClientBuilder::new()
+        .network(Network::Testnet)
+        .transport(transport.clone())
+        .http_client(reqwest::Client::new())
+        .build();
+    assert!(matches!(
+        conflicting,
+        Err(onemoney_protocol::Error::Config(_))
+    ));
+
+    Ok(())
+}
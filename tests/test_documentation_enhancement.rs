@@ -35,6 +35,8 @@
 //! └── test_documentation_enhancement.rs  # This file - testing standards
 //! ```
 
+#![cfg(feature = "client")]
+
 use onemoney_protocol::client::builder::ClientBuilder;
 use onemoney_protocol::client::config::Network;
 use std::time::{Duration, Instant};